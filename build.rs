@@ -0,0 +1,16 @@
+//! Sets the `has_errno_table` cfg where the `os_error` module's `errno` codes
+//! are right: Linux, but not MIPS or SPARC, which number them differently.
+
+use std::env;
+
+fn main() {
+  println!("cargo:rerun-if-changed=build.rs");
+  println!("cargo:rustc-check-cfg=cfg(has_errno_table)");
+  let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+  let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+  let own_errno =
+    ["mips", "mips64", "mips32r6", "mips64r6", "sparc", "sparc64"];
+  if os == "linux" && !own_errno.contains(&arch.as_str()) {
+    println!("cargo:rustc-cfg=has_errno_table");
+  }
+}
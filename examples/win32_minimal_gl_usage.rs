@@ -3,8 +3,10 @@
 #[allow(unused)]
 use core::ptr::null_mut;
 
+#[cfg(windows)]
 use triangle_from_scratch::{c_str, gl::*, win32::*};
 
+#[cfg(windows)]
 struct WindowData {
   hdc: HDC,
   hglrc: HGLRC,
@@ -12,11 +14,13 @@ struct WindowData {
   gl_clear: glClear_t,
   gl_clear_color: glClearColor_t,
 }
+#[cfg(windows)]
 impl Default for WindowData {
   fn default() -> Self {
     unsafe { core::mem::zeroed() }
   }
 }
+#[cfg(windows)]
 impl WindowData {
   pub fn gl_get_proc_address(&self, name: &[u8]) -> *mut c_void {
     assert!(*name.last().unwrap() == 0);
@@ -35,6 +39,7 @@ impl WindowData {
   }
 }
 
+#[cfg(windows)]
 fn main() {
   let instance = get_process_handle();
 
@@ -42,6 +47,7 @@ fn main() {
   let (
     wgl_extensions,
     wglChoosePixelFormatARB,
+    wglGetPixelFormatAttribivARB,
     wglCreateContextAttribsARB,
     wglSwapIntervalEXT,
   ) = get_wgl_basics().unwrap();
@@ -75,32 +81,30 @@ fn main() {
   unsafe { (*lparam).hdc = hdc };
 
   // base criteria
-  let mut int_attribs = vec![
-    [WGL_DRAW_TO_WINDOW_ARB, true as _],
-    [WGL_SUPPORT_OPENGL_ARB, true as _],
-    [WGL_DOUBLE_BUFFER_ARB, true as _],
-    [WGL_PIXEL_TYPE_ARB, WGL_TYPE_RGBA_ARB],
-    [WGL_COLOR_BITS_ARB, 32],
-    [WGL_DEPTH_BITS_ARB, 24],
-    [WGL_STENCIL_BITS_ARB, 8],
-  ];
+  let mut attribs = PixelFormatAttribs::default()
+    .double_buffer(true)
+    .color_bits(32)
+    .depth_bits(24)
+    .stencil_bits(8);
   // if sRGB is supported, ask for that
   if wgl_extensions.iter().any(|s| s == "WGL_EXT_framebuffer_sRGB") {
-    int_attribs.push([WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT, true as _]);
+    attribs = attribs.srgb(true);
   };
   // let's have some multisample if we can get it
   if wgl_extensions.iter().any(|s| s == "WGL_ARB_multisample") {
-    int_attribs.push([WGL_SAMPLE_BUFFERS_ARB, 1]);
+    attribs = attribs.sample_buffers(1);
   };
-  // finalize our list
-  int_attribs.push([0, 0]);
   // choose a format, get the PIXELFORMATDESCRIPTOR, and set it.
   let pix_format = unsafe {
-    do_wglChoosePixelFormatARB(wglChoosePixelFormatARB, hdc, &int_attribs, &[])
+    do_wglChoosePixelFormatARB(wglChoosePixelFormatARB, hdc, &attribs)
   }
   .unwrap();
   let pfd = unsafe { describe_pixel_format(hdc, pix_format) }.unwrap();
-  println!("> Selected Pixel Format: {:?}", pfd);
+  let info = unsafe {
+    describe_pixel_format_info(hdc, pix_format, wglGetPixelFormatAttribivARB)
+  }
+  .unwrap();
+  println!("> Selected Pixel Format: {}", info);
   unsafe { set_pixel_format(hdc, pix_format, &pfd) }.unwrap();
 
   // now we create a context.
@@ -153,6 +157,7 @@ fn main() {
   }
 }
 
+#[cfg(windows)]
 pub unsafe extern "system" fn window_procedure(
   hwnd: HWND, msg: UINT, wparam: WPARAM, lparam: LPARAM,
 ) -> LRESULT {
@@ -212,3 +217,8 @@ pub unsafe extern "system" fn window_procedure(
   }
   0
 }
+
+#[cfg(not(windows))]
+fn main() {
+  eprintln!("This example only runs on Windows.");
+}
@@ -1,7 +1,9 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+#[cfg(windows)]
 use triangle_from_scratch::win32::*;
 
+#[cfg(windows)]
 fn main() {
   let sample_window_class = "Sample Window Class";
   let sample_window_class_wn = wide_null(sample_window_class);
@@ -43,6 +45,7 @@ fn main() {
   }
 }
 
+#[cfg(windows)]
 pub unsafe extern "system" fn window_procedure(
   hwnd: HWND, msg: UINT, wparam: WPARAM, lparam: LPARAM,
 ) -> LRESULT {
@@ -108,3 +111,8 @@ pub unsafe extern "system" fn window_procedure(
   }
   0
 }
+
+#[cfg(not(windows))]
+fn main() {
+  eprintln!("This example only runs on Windows.");
+}
@@ -1,11 +1,16 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
+#![allow(clippy::upper_case_acronyms)]
+#![cfg_attr(not(windows), allow(dead_code))]
 
+#[cfg(windows)]
 use core::ptr::{null, null_mut};
 
 macro_rules! unsafe_impl_default_zeroed {
   ($t:ty) => {
+    // newer compilers ignore `must_use` on trait impl methods.
+    #[allow(unused_attributes)]
     impl Default for $t {
       #[inline]
       #[must_use]
@@ -16,6 +21,7 @@ macro_rules! unsafe_impl_default_zeroed {
   };
 }
 
+#[cfg(windows)]
 fn main() {
   let hInstance = unsafe { GetModuleHandleW(null()) };
   let sample_window_class_wn = wide_null("Sample Window Class");
@@ -72,6 +78,7 @@ fn main() {
   }
 }
 
+#[cfg(windows)]
 pub unsafe extern "system" fn window_procedure(
   hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM,
 ) -> LRESULT {
@@ -261,6 +268,7 @@ const MB_OKCANCEL: u32 = 1;
 const IDOK: c_int = 1;
 const GWLP_USERDATA: c_int = -21;
 
+#[cfg(windows)]
 #[link(name = "Kernel32")]
 extern "system" {
   /// [`GetModuleHandleW`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getmodulehandlew)
@@ -270,6 +278,7 @@ extern "system" {
   pub fn GetLastError() -> DWORD;
 }
 
+#[cfg(windows)]
 #[link(name = "User32")]
 extern "system" {
   /// [`RegisterClassW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassw)
@@ -345,3 +354,8 @@ const fn MAKEINTRESOURCEW(i: WORD) -> LPWSTR {
 pub fn wide_null(s: &str) -> Vec<u16> {
   s.encode_utf16().chain(Some(0)).collect()
 }
+
+#[cfg(not(windows))]
+fn main() {
+  eprintln!("This example only runs on Windows.");
+}
@@ -0,0 +1,983 @@
+//! Turns a [`Selection`] into Rust source.
+
+use crate::registry::{Command, EnumDef, Param, Selection, Spec, TypeDef};
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  fmt::Write,
+};
+
+/// Words that can't be used as a parameter name in Rust.
+const RUST_KEYWORDS: &[&str] = &[
+  "abstract", "as", "async", "await", "become", "box", "break", "const",
+  "continue", "crate", "do", "dyn", "else", "enum", "extern", "false", "final",
+  "fn", "for", "if", "impl", "in", "let", "loop", "macro", "match", "mod",
+  "move", "mut", "override", "priv", "pub", "ref", "return", "self", "Self",
+  "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+  "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The `core::ffi` types that [`rust_base_type`] can give.
+const C_TYPES: &[&str] = &[
+  "c_char", "c_double", "c_float", "c_int", "c_long", "c_schar", "c_short",
+  "c_uchar", "c_uint", "c_ulong", "c_ushort", "c_void",
+];
+
+/// Gives the Rust name for a C base type.
+///
+/// Names that aren't C or `khrplatform.h` types (such as `GLenum`) are kept.
+fn rust_base_type(c: &str) -> &str {
+  match c {
+    "void" => "c_void",
+    "char" => "c_char",
+    "signed char" => "c_schar",
+    "unsigned char" => "c_uchar",
+    "short" => "c_short",
+    "unsigned short" => "c_ushort",
+    "int" => "c_int",
+    "unsigned int" => "c_uint",
+    "long" => "c_long",
+    "unsigned long" => "c_ulong",
+    "float" => "c_float",
+    "double" => "c_double",
+    "khronos_float_t" => "c_float",
+    "khronos_int8_t" => "i8",
+    "khronos_uint8_t" => "u8",
+    "khronos_int16_t" => "i16",
+    "khronos_uint16_t" => "u16",
+    "khronos_int32_t" => "i32",
+    "khronos_uint32_t" => "u32",
+    "khronos_int64_t" => "i64",
+    "khronos_uint64_t" => "u64",
+    "khronos_intptr_t" | "khronos_ssize_t" => "isize",
+    "khronos_uintptr_t" | "khronos_usize_t" => "usize",
+    "int32_t" => "i32",
+    "uint32_t" => "u32",
+    "int64_t" => "i64",
+    "uint64_t" => "u64",
+    "ptrdiff_t" => "isize",
+    other => other.strip_prefix("struct ").unwrap_or(other),
+  }
+}
+
+/// A C type, split into the parts that matter to Rust.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CType {
+  /// The base type, such as `unsigned int` or `struct __GLsync`.
+  base: String,
+  /// If the base type is `const`.
+  base_const: bool,
+  /// One entry per `*`, which is `true` if that pointer is itself `const`.
+  pointers: Vec<bool>,
+}
+impl CType {
+  fn parse(c: &str) -> Self {
+    let spaced = c.replace('*', " * ");
+    let mut base_words: Vec<&str> = Vec::new();
+    let mut base_const = false;
+    let mut pointers: Vec<bool> = Vec::new();
+    for word in spaced.split_whitespace() {
+      match word {
+        "*" => pointers.push(false),
+        "const" => match pointers.last_mut() {
+          Some(p) => *p = true,
+          None => base_const = true,
+        },
+        _ => base_words.push(word),
+      }
+    }
+    Self { base: base_words.join(" "), base_const, pointers }
+  }
+
+  /// The opaque struct name, if the base is a `struct`.
+  fn opaque_struct(&self) -> Option<&str> {
+    self.base.strip_prefix("struct ")
+  }
+
+  fn to_rust(&self) -> String {
+    let mut out = rust_base_type(&self.base).to_string();
+    let mut pointee_const = self.base_const;
+    for &pointer_const in self.pointers.iter() {
+      let kind = if pointee_const { "*const" } else { "*mut" };
+      out = format!("{} {}", kind, out);
+      pointee_const = pointer_const;
+    }
+    out
+  }
+}
+
+/// The Rust type of a command parameter.
+fn param_type(p: &Param) -> String {
+  let mut c = CType::parse(&p.c_type);
+  if p.is_array {
+    // array parameters decay to a pointer, like in C.
+    c.pointers.insert(0, false);
+  }
+  c.to_rust()
+}
+
+/// The Rust type of a command return value, or `None` for `void`.
+fn return_type(c: &str) -> Option<String> {
+  let c = CType::parse(c);
+  if c.base == "void" && c.pointers.is_empty() {
+    None
+  } else {
+    Some(c.to_rust())
+  }
+}
+
+fn param_name(name: &str) -> String {
+  if RUST_KEYWORDS.contains(&name) {
+    format!("{}_", name)
+  } else {
+    name.to_string()
+  }
+}
+
+/// Formats `Option<unsafe extern "system" fn(..) -> ..>`.
+///
+/// It goes on one line if that fits within 80 columns after the `prefix`,
+/// otherwise each parameter goes on its own line.
+fn fn_pointer_type(
+  prefix: &str, params: &[(String, String)], ret: Option<&str>, suffix: &str,
+) -> String {
+  let ret = ret.map(|r| format!(" -> {}", r)).unwrap_or_default();
+  let one_line_params: Vec<String> =
+    params.iter().map(|(name, ty)| format!("{}: {}", name, ty)).collect();
+  let one_line = format!(
+    "{}Option<unsafe extern \"system\" fn({}){}>{}",
+    prefix,
+    one_line_params.join(", "),
+    ret,
+    suffix
+  );
+  if one_line.len() <= 80 {
+    return one_line;
+  }
+  let mut out = format!("{}Option<\n  unsafe extern \"system\" fn(\n", prefix);
+  for p in one_line_params {
+    writeln!(out, "    {},", p).unwrap();
+  }
+  write!(out, "  ){},\n>{}", ret, suffix).unwrap();
+  out
+}
+
+/// The C signature of a command, for the docs.
+fn c_signature(c: &Command) -> String {
+  let params: Vec<String> = c
+    .params
+    .iter()
+    .map(|p| {
+      let space = if p.c_type.ends_with('*') { "" } else { " " };
+      let array = if p.is_array { "[]" } else { "" };
+      format!("{}{}{}{}", p.c_type, space, p.name, array)
+    })
+    .collect();
+  let space = if c.return_type.ends_with('*') { "" } else { " " };
+  format!("{}{}{}({})", c.return_type, space, c.name, params.join(", "))
+}
+
+/// The Rust type for an enum's constant.
+fn enum_type(e: &EnumDef) -> &'static str {
+  match e.suffix.as_deref() {
+    Some("ull") => "GLuint64",
+    Some("u") => "GLuint",
+    _ if e.value.starts_with('-') => "GLint",
+    _ if e.bitmask => "GLbitfield",
+    _ => "GLenum",
+  }
+}
+
+/// Writes the Rust item for a `typedef`.
+fn write_type(
+  out: &mut String, t: &TypeDef, opaque: &mut BTreeSet<String>,
+) -> Result<(), String> {
+  let bad = || format!("can't translate `{}`", t.c_decl);
+  let decl = t.c_decl.strip_prefix("typedef ").ok_or_else(bad)?;
+  let decl = decl.trim_end().strip_suffix(';').ok_or_else(bad)?;
+  writeln!(out, "/// From `gl.xml`").unwrap();
+  if let Some(open) = decl.find("(") {
+    // function pointer: `RET (*NAME)(PARAMS)`
+    let ret = return_type(&decl[..open]);
+    let params_start = decl.rfind('(').ok_or_else(bad)?;
+    let params_end = decl.rfind(')').ok_or_else(bad)?;
+    let mut params = Vec::new();
+    for p in decl[params_start + 1..params_end].split(',') {
+      let p = p.trim();
+      if p == "void" {
+        continue;
+      }
+      let split = p
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .ok_or_else(bad)?;
+      let c_type = CType::parse(&p[..=split]);
+      params.push((param_name(&p[split + 1..]), c_type.to_rust()));
+    }
+    let prefix = format!("pub type {} = ", t.name);
+    writeln!(out, "{}", fn_pointer_type(&prefix, &params, ret.as_deref(), ";"))
+      .unwrap();
+  } else {
+    let c_type = decl.strip_suffix(t.name.as_str()).ok_or_else(bad)?;
+    let c_type = CType::parse(c_type);
+    if let Some(s) = c_type.opaque_struct() {
+      opaque.insert(s.to_string());
+    }
+    writeln!(out, "pub type {} = {};", t.name, c_type.to_rust()).unwrap();
+  }
+  writeln!(out).unwrap();
+  Ok(())
+}
+
+/// Writes the `//!` header that says what the file was generated from.
+fn write_header(o: &mut String, spec: &Spec, registry_name: &str) {
+  writeln!(
+    o,
+    "//! Generated from `{}` by the `gl_generator` crate.",
+    registry_name
+  )
+  .unwrap();
+  writeln!(o, "//!").unwrap();
+  writeln!(o, "//! * API: `{}`", spec.api).unwrap();
+  writeln!(o, "//! * Version: `{}.{}`", spec.version.0, spec.version.1)
+    .unwrap();
+  writeln!(o, "//! * Profile: `{}`", spec.profile).unwrap();
+  for e in spec.extensions.iter() {
+    writeln!(o, "//! * Extension: `{}`", e).unwrap();
+  }
+  writeln!(o, "//!").unwrap();
+  writeln!(
+    o,
+    "//! Don't edit this by hand, run `cargo run -p gl_generator` instead."
+  )
+  .unwrap();
+  writeln!(o).unwrap();
+}
+
+/// The cargo feature that makes the methods of a table check for errors.
+pub const CHECKED_FEATURE: &str = "checked_gl";
+
+/// The cargo feature that makes the methods of a table record a trace.
+pub const TRACE_FEATURE: &str = "trace_gl";
+
+/// How a parameter goes into a `TraceValue`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TraceKind {
+  Int,
+  UInt,
+  Enum,
+  Float,
+  Pointer,
+  OutPointer,
+  Handle,
+  Callback,
+}
+impl TraceKind {
+  /// The kind for a Rust type from [`param_type`] or [`return_type`].
+  fn of(rust_type: &str) -> Result<Self, String> {
+    Ok(if rust_type.starts_with("*const") {
+      Self::Pointer
+    } else if rust_type.starts_with("*mut") {
+      Self::OutPointer
+    } else {
+      match rust_type {
+        "GLenum" => Self::Enum,
+        "GLfloat" | "GLclampf" | "GLdouble" | "GLclampd" => Self::Float,
+        "GLsync" => Self::Handle,
+        "GLDEBUGPROC" | "GLDEBUGPROCARB" | "GLDEBUGPROCKHR" => Self::Callback,
+        "GLbyte" | "GLshort" | "GLint" | "GLsizei" | "GLintptr"
+        | "GLsizeiptr" | "GLint64" | "GLfixed" | "c_int" => Self::Int,
+        "GLubyte" | "GLushort" | "GLuint" | "GLbitfield" | "GLboolean"
+        | "GLuint64" | "c_uint" => Self::UInt,
+        other => return Err(format!("can't trace the type `{}`", other)),
+      }
+    })
+  }
+
+  /// The expression that records a variable of the Rust type.
+  fn record(self, var: &str, rust_type: &str) -> String {
+    match self {
+      Self::Int => {
+        format!("TraceValue::Int({}{})", var, cast(rust_type, "i64"))
+      }
+      Self::UInt => {
+        format!("TraceValue::UInt({}{})", var, cast(rust_type, "u64"))
+      }
+      Self::Enum => format!("TraceValue::Enum({})", var),
+      Self::Float => {
+        format!("TraceValue::Float({}{})", var, cast(rust_type, "f64"))
+      }
+      Self::Pointer => format!("TraceValue::Pointer({} as usize as u64)", var),
+      Self::OutPointer => {
+        format!("TraceValue::OutPointer({} as usize as u64)", var)
+      }
+      Self::Handle => format!("TraceValue::Handle({} as usize as u64)", var),
+      Self::Callback => {
+        format!("TraceValue::Pointer({}.map_or(0, |f| f as usize) as u64)", var)
+      }
+    }
+  }
+
+  /// The expression that gives argument `i` of a replayed call.
+  fn replay(self, i: usize, rust_type: &str) -> String {
+    match self {
+      Self::Int | Self::UInt | Self::Enum => {
+        format!("args[{}].replay_int(){}", i, cast("i64", rust_type))
+      }
+      Self::Float => {
+        format!("args[{}].replay_float(){}", i, cast("f64", rust_type))
+      }
+      Self::Pointer | Self::OutPointer | Self::Handle => {
+        format!("args[{}].replay_ptr(){}", i, cast("*mut c_void", rust_type))
+      }
+      Self::Callback => "None".to_string(),
+    }
+  }
+}
+
+/// An `as` cast between the types, or nothing if they're the same.
+fn cast(from: &str, to: &str) -> String {
+  let same = |a: &str, b: &str| {
+    a == b
+      || matches!(
+        (a, b),
+        ("GLint64", "i64")
+          | ("GLuint64", "u64")
+          | ("GLdouble", "f64")
+          | ("GLclampd", "f64")
+      )
+  };
+  if same(from, to) || same(to, from) {
+    String::new()
+  } else {
+    format!(" as {}", to)
+  }
+}
+
+/// An argument for [`format_call`].
+enum CallArg {
+  Plain(String),
+  /// Written as `&[a, b, c]`.
+  Array(Vec<String>),
+}
+
+/// Formats a call and a line end, on one line if it fits in 80 columns.
+///
+/// Otherwise each argument goes on its own line, as do the array elements.
+fn format_call(
+  indent: &str, callee: &str, args: &[CallArg], end: &str,
+) -> String {
+  let one_line_args: Vec<String> = args
+    .iter()
+    .map(|a| match a {
+      CallArg::Plain(s) => s.clone(),
+      CallArg::Array(v) => format!("&[{}]", v.join(", ")),
+    })
+    .collect();
+  let one_line =
+    format!("{}{}({}){}\n", indent, callee, one_line_args.join(", "), end);
+  if one_line.len() <= 81 {
+    return one_line;
+  }
+  let mut out = format!("{}{}(\n", indent, callee);
+  for a in args.iter() {
+    match a {
+      CallArg::Plain(s) => writeln!(out, "{}  {},", indent, s).unwrap(),
+      CallArg::Array(v)
+        if indent.len() + v.iter().map(|e| e.len() + 2).sum::<usize>() + 6
+          <= 80 =>
+      {
+        writeln!(out, "{}  &[{}],", indent, v.join(", ")).unwrap()
+      }
+      CallArg::Array(v) => {
+        writeln!(out, "{}  &[", indent).unwrap();
+        for e in v.iter() {
+          writeln!(out, "{}    {},", indent, e).unwrap();
+        }
+        writeln!(out, "{}  ],", indent).unwrap();
+      }
+    }
+  }
+  writeln!(out, "{}){}", indent, end).unwrap();
+  out
+}
+
+/// Generates the Rust source for a spec.
+///
+/// The output starts with a `//!` header, so it should be its own module.
+pub fn generate(
+  selection: &Selection<'_>, spec: &Spec, registry_name: &str,
+) -> Result<String, String> {
+  let mut out = String::new();
+  let o = &mut out;
+  write_header(o, spec, registry_name);
+  writeln!(o, "#![allow(non_camel_case_types)]").unwrap();
+  writeln!(o, "#![allow(non_snake_case)]").unwrap();
+  writeln!(o, "#![allow(non_upper_case_globals)]").unwrap();
+  writeln!(o).unwrap();
+
+  let mut items = String::new();
+  let o = &mut items;
+  let mut types = String::new();
+  let mut opaque = BTreeSet::new();
+  for t in selection.types.iter() {
+    write_type(&mut types, t, &mut opaque)?;
+  }
+  for s in opaque.iter() {
+    writeln!(o, "/// Opaque type from `gl.xml`").unwrap();
+    writeln!(o, "#[repr(C)]").unwrap();
+    writeln!(o, "pub struct {} {{\n  _private: [u8; 0],\n}}\n", s).unwrap();
+  }
+  o.push_str(&types);
+
+  for e in selection.enums.iter() {
+    writeln!(o, "pub const {}: {} = {};", e.name, enum_type(e), e.value)
+      .unwrap();
+  }
+  writeln!(o).unwrap();
+
+  // names for the values that just one `GLenum` has, for printing.
+  let mut names_by_value: BTreeMap<u64, Vec<&str>> = BTreeMap::new();
+  for e in selection.enums.iter().filter(|e| enum_type(e) == "GLenum") {
+    let value = match e.value.strip_prefix("0x") {
+      Some(hex) => u64::from_str_radix(hex, 16),
+      None => e.value.parse(),
+    };
+    if let Ok(value) = value {
+      names_by_value.entry(value).or_default().push(&e.name);
+    }
+  }
+  writeln!(
+    o,
+    "/// Gets the name of a `GLenum` value, if just one enum has it."
+  )
+  .unwrap();
+  writeln!(
+    o,
+    "pub const fn gl_enum_name(value: GLenum) -> Option<&'static str> {{"
+  )
+  .unwrap();
+  writeln!(o, "  Some(match value {{").unwrap();
+  for (value, names) in names_by_value.iter() {
+    if let [name] = names.as_slice() {
+      writeln!(o, "    0x{:04X} => \"{}\",", value, name).unwrap();
+    }
+  }
+  writeln!(o, "    _ => return None,").unwrap();
+  writeln!(o, "  }})").unwrap();
+  writeln!(o, "}}").unwrap();
+  writeln!(o).unwrap();
+
+  for c in selection.commands.iter() {
+    let params: Vec<(String, String)> =
+      c.params.iter().map(|p| (param_name(&p.name), param_type(p))).collect();
+    let opaque_params = c
+      .params
+      .iter()
+      .map(|p| CType::parse(&p.c_type))
+      .filter_map(|t| t.opaque_struct().map(str::to_string));
+    for s in opaque_params {
+      if opaque.insert(s.clone()) {
+        return Err(format!(
+          "`{}` uses `struct {}`, which has no typedef",
+          c.name, s
+        ));
+      }
+    }
+    writeln!(o, "/// From `gl.xml`: `{}`", c_signature(c)).unwrap();
+    let prefix = format!("pub type {}_t = ", c.name);
+    let ret = return_type(&c.return_type);
+    writeln!(o, "{}\n", fn_pointer_type(&prefix, &params, ret.as_deref(), ";"))
+      .unwrap();
+  }
+
+  writeln!(o, "/// Every function pointer of the generated API.").unwrap();
+  writeln!(o, "///").unwrap();
+  writeln!(o, "/// Any function that's not available is `None`.").unwrap();
+  writeln!(o, "#[derive(Clone, Copy, Default)]").unwrap();
+  writeln!(o, "pub struct GlFns {{").unwrap();
+  for c in selection.commands.iter() {
+    writeln!(o, "  pub {0}: {0}_t,", c.name).unwrap();
+  }
+  writeln!(o, "}}").unwrap();
+  writeln!(o, "impl GlFns {{").unwrap();
+  writeln!(o, "  /// Loads every function pointer using the loader given.")
+    .unwrap();
+  writeln!(o, "  ///").unwrap();
+  writeln!(
+    o,
+    "  /// The loader gets each null-terminated name (eg: `b\"glClear\\0\"`), and"
+  )
+  .unwrap();
+  writeln!(o, "  /// gives the address of that function, or null.").unwrap();
+  writeln!(o, "  ///").unwrap();
+  writeln!(o, "  /// ## Safety").unwrap();
+  writeln!(
+    o,
+    "  /// * The non-null addresses must be of functions with the signature from"
+  )
+  .unwrap();
+  writeln!(o, "  ///   `gl.xml`.").unwrap();
+  writeln!(
+    o,
+    "  pub unsafe fn load<F: Fn(&[u8]) -> *const c_void>(loader: F) -> Self {{"
+  )
+  .unwrap();
+  writeln!(o, "    Self {{").unwrap();
+  for c in selection.commands.iter() {
+    writeln!(
+      o,
+      "      {0}: core::mem::transmute::<*const c_void, {0}_t>(loader(\
+       b\"{0}\\0\")),",
+      c.name
+    )
+    .unwrap();
+  }
+  writeln!(o, "    }}").unwrap();
+  writeln!(o, "  }}").unwrap();
+  writeln!(o, "}}").unwrap();
+
+  // import just the C types that the items use.
+  let c_types: Vec<&str> = C_TYPES
+    .iter()
+    .copied()
+    .filter(|c| {
+      items.match_indices(c).any(|(i, _)| {
+        !items[i + c.len()..]
+          .starts_with(|ch: char| ch.is_alphanumeric() || ch == '_')
+      })
+    })
+    .collect();
+  let one_line = format!("use core::ffi::{{{}}};", c_types.join(", "));
+  if one_line.len() <= 80 {
+    writeln!(out, "{}", one_line).unwrap();
+  } else {
+    // wrapped the way rustfmt does it, so that formatting leaves it alone.
+    writeln!(out, "use core::ffi::{{").unwrap();
+    let mut line = String::from(" ");
+    for c in c_types.iter() {
+      if line.len() + c.len() + 2 > 80 {
+        writeln!(out, "{}", line).unwrap();
+        line = String::from(" ");
+      }
+      write!(line, " {},", c).unwrap();
+    }
+    writeln!(out, "{}", line).unwrap();
+    writeln!(out, "}};").unwrap();
+  }
+  writeln!(out).unwrap();
+  out.push_str(&items);
+  Ok(out)
+}
+
+/// Generates a function table struct for a spec.
+///
+/// The table's fields are private, and each function has a method without the
+/// `gl` prefix (eg: `glClear` is `Clear`) that panics if it wasn't loaded. The
+/// types and enums are expected to be in scope from `super`.
+pub fn generate_table(
+  selection: &Selection<'_>, spec: &Spec, registry_name: &str,
+  struct_name: &str,
+) -> Result<String, String> {
+  let mut out = String::new();
+  let o = &mut out;
+  write_header(o, spec, registry_name);
+  writeln!(o, "#![allow(non_snake_case)]").unwrap();
+  writeln!(o, "#![allow(clippy::too_many_arguments)]").unwrap();
+  // the safety rules of every method are the ones on the struct.
+  writeln!(o, "#![allow(clippy::missing_safety_doc)]").unwrap();
+  writeln!(o).unwrap();
+  writeln!(o, "use super::*;").unwrap();
+  writeln!(o, "use core::ffi::c_void;").unwrap();
+  writeln!(o).unwrap();
+  writeln!(o, "/// Every function of the API, loaded at runtime.").unwrap();
+  writeln!(o, "///").unwrap();
+  writeln!(
+    o,
+    "/// The methods are `unsafe` because GL needs a current context, and many"
+  )
+  .unwrap();
+  writeln!(
+    o,
+    "/// take pointers. Calling a method of a function that wasn't loaded panics"
+  )
+  .unwrap();
+  writeln!(o, "/// with the function's name.").unwrap();
+  writeln!(o, "///").unwrap();
+  writeln!(
+    o,
+    "/// With the `{}` feature every method but `GetError` calls",
+    CHECKED_FEATURE
+  )
+  .unwrap();
+  writeln!(
+    o,
+    "/// `check_gl_error` afterwards, which the parent module must provide."
+  )
+  .unwrap();
+  writeln!(o, "#[derive(Clone, Copy)]").unwrap();
+  writeln!(o, "pub struct {} {{", struct_name).unwrap();
+  for c in selection.commands.iter() {
+    writeln!(o, "  {0}: {0}_t,", c.name).unwrap();
+  }
+  writeln!(o, "}}").unwrap();
+  writeln!(o, "impl {} {{", struct_name).unwrap();
+  writeln!(o, "  /// Loads every function using the loader given.").unwrap();
+  writeln!(o, "  ///").unwrap();
+  writeln!(
+    o,
+    "  /// The loader gets each null-terminated name (eg: `b\"glClear\\0\"`), and"
+  )
+  .unwrap();
+  writeln!(o, "  /// gives the address of that function, or null.").unwrap();
+  writeln!(o, "  ///").unwrap();
+  writeln!(
+    o,
+    "  /// **Returns:** The table, and the names of the functions of the version"
+  )
+  .unwrap();
+  writeln!(o, "  /// that the loader gave null for.").unwrap();
+  if !selection.extension_commands.is_empty() {
+    writeln!(o, "  ///").unwrap();
+    writeln!(
+      o,
+      "  /// Functions that only an extension adds aren't in the list, since"
+    )
+    .unwrap();
+    writeln!(
+      o,
+      "  /// the driver might not have the extension. Check for them with the"
+    )
+    .unwrap();
+    writeln!(o, "  /// `loaded_` method of the extension instead.").unwrap();
+  }
+  writeln!(o, "  ///").unwrap();
+  writeln!(o, "  /// ## Safety").unwrap();
+  writeln!(
+    o,
+    "  /// * The non-null addresses must be of functions with the signature from"
+  )
+  .unwrap();
+  writeln!(o, "  ///   `gl.xml`.").unwrap();
+  writeln!(o, "  pub unsafe fn load<F: Fn(&[u8]) -> *const c_void>(").unwrap();
+  writeln!(o, "    loader: F,").unwrap();
+  writeln!(o, "  ) -> (Self, Vec<&'static str>) {{").unwrap();
+  writeln!(o, "    let mut missing = Vec::new();").unwrap();
+  writeln!(o, "    let mut get = |name: &'static str| {{").unwrap();
+  writeln!(o, "      let p = loader(name.as_bytes());").unwrap();
+  writeln!(o, "      if p.is_null() {{").unwrap();
+  writeln!(o, "        missing.push(name.trim_end_matches('\\0'));").unwrap();
+  writeln!(o, "      }}").unwrap();
+  writeln!(o, "      p").unwrap();
+  writeln!(o, "    }};").unwrap();
+  let from_extension = |name: &str| {
+    selection.extension_commands.iter().any(|(_, added)| added.contains(&name))
+  };
+  if selection.commands.iter().any(|c| from_extension(&c.name)) {
+    writeln!(
+      o,
+      "    let get_extension = |name: &'static str| loader(name.as_bytes());"
+    )
+    .unwrap();
+  }
+  writeln!(o, "    let table = Self {{").unwrap();
+  for c in selection.commands.iter() {
+    let get = if from_extension(&c.name) { "get_extension" } else { "get" };
+    writeln!(
+      o,
+      "      {0}: core::mem::transmute::<*const c_void, {0}_t>({1}(\
+       \"{0}\\0\")),",
+      c.name, get
+    )
+    .unwrap();
+  }
+  writeln!(o, "    }};").unwrap();
+  writeln!(o, "    (table, missing)").unwrap();
+  writeln!(o, "  }}").unwrap();
+  for (extension, added) in selection.extension_commands.iter() {
+    writeln!(o).unwrap();
+    writeln!(
+      o,
+      "  /// If every function that `{}` adds to the version was loaded.",
+      extension
+    )
+    .unwrap();
+    writeln!(o, "  pub fn loaded_{}(&self) -> bool {{", extension).unwrap();
+    if added.is_empty() {
+      writeln!(o, "    true").unwrap();
+    }
+    for (i, name) in added.iter().enumerate() {
+      let and = if i == 0 { "" } else { "  && " };
+      writeln!(o, "    {}self.{}.is_some()", and, name).unwrap();
+    }
+    writeln!(o, "  }}").unwrap();
+  }
+  let mut replay_arms = Vec::new();
+  for c in selection.commands.iter() {
+    let method = c.name.strip_prefix("gl").unwrap_or(&c.name);
+    let params: Vec<String> = c
+      .params
+      .iter()
+      .map(|p| format!("{}: {}", param_name(&p.name), param_type(p)))
+      .collect();
+    let args: Vec<String> =
+      c.params.iter().map(|p| param_name(&p.name)).collect();
+    let ret = return_type(&c.return_type)
+      .map(|r| format!(" -> {}", r))
+      .unwrap_or_default();
+    writeln!(o).unwrap();
+    writeln!(o, "  /// From `gl.xml`: `{}`", c_signature(c)).unwrap();
+    writeln!(o, "  #[inline]").unwrap();
+    if c.name != "glGetError" {
+      // so that a failed check points at the caller's line.
+      writeln!(
+        o,
+        "  #[cfg_attr(feature = \"{}\", track_caller)]",
+        CHECKED_FEATURE
+      )
+      .unwrap();
+    }
+    let mut one_line = vec!["&self".to_string()];
+    one_line.extend(params.iter().cloned());
+    let signature =
+      format!("  pub unsafe fn {}({}){} {{", method, one_line.join(", "), ret);
+    if signature.len() <= 80 {
+      writeln!(o, "{}", signature).unwrap();
+    } else {
+      writeln!(o, "  pub unsafe fn {}(", method).unwrap();
+      writeln!(o, "    &self,").unwrap();
+      for p in params.iter() {
+        writeln!(o, "    {},", p).unwrap();
+      }
+      writeln!(o, "  ){} {{", ret).unwrap();
+    }
+    let has_ret = !ret.is_empty();
+    let binding = if has_ret { "let out = " } else { "" };
+    writeln!(o, "    {}match self.{} {{", binding, c.name).unwrap();
+    writeln!(o, "      Some(fn_ptr) => fn_ptr({}),", args.join(", ")).unwrap();
+    writeln!(o, "      None => not_loaded(\"{}\"),", c.name).unwrap();
+    writeln!(o, "    }}{}", if has_ret { ";" } else { "" }).unwrap();
+    let kinds = c
+      .params
+      .iter()
+      .map(|p| TraceKind::of(&param_type(p)))
+      .collect::<Result<Vec<_>, _>>()?;
+    let ret_type = return_type(&c.return_type);
+    let ret_kind = ret_type.as_deref().map(TraceKind::of).transpose()?;
+    writeln!(o, "    #[cfg(feature = \"{}\")]", TRACE_FEATURE).unwrap();
+    let trace = format_call(
+      "    ",
+      "trace_gl_call",
+      &[
+        CallArg::Plain("self.glGetIntegerv".to_string()),
+        CallArg::Plain(format!("\"{}\"", c.name)),
+        CallArg::Array(
+          c.params.iter().map(|p| format!("\"{}\"", p.name)).collect(),
+        ),
+        CallArg::Array(
+          kinds
+            .iter()
+            .zip(c.params.iter())
+            .map(|(k, p)| k.record(&param_name(&p.name), &param_type(p)))
+            .collect(),
+        ),
+        CallArg::Plain(match (&ret_kind, &ret_type) {
+          (Some(k), Some(r)) => k.record("out", r),
+          _ => "TraceValue::Void".to_string(),
+        }),
+      ],
+      ";",
+    );
+    o.push_str(&trace);
+    // checking `glGetError` would eat the error it's meant to get.
+    if c.name != "glGetError" {
+      writeln!(o, "    #[cfg(feature = \"{}\")]", CHECKED_FEATURE).unwrap();
+      let check = format_call(
+        "    ",
+        "check_gl_error",
+        &[
+          CallArg::Plain("self.glGetError".to_string()),
+          CallArg::Plain(format!("\"{}\"", c.name)),
+          CallArg::Array(
+            args.iter().map(|a| format!("(\"{0}\", &{0})", a)).collect(),
+          ),
+        ],
+        ";",
+      );
+      o.push_str(&check);
+    }
+    if has_ret {
+      writeln!(o, "    out").unwrap();
+    }
+    writeln!(o, "  }}").unwrap();
+    replay_arms.push((c, method, kinds, ret_type));
+  }
+
+  writeln!(o).unwrap();
+  writeln!(o, "  /// Calls the method for `name` with arguments from a trace.")
+    .unwrap();
+  writeln!(o, "  ///").unwrap();
+  writeln!(
+    o,
+    "  /// Gives `None` if there's no such method, or the wrong number of"
+  )
+  .unwrap();
+  writeln!(o, "  /// arguments.").unwrap();
+  writeln!(o, "  #[cfg(feature = \"{}\")]", TRACE_FEATURE).unwrap();
+  writeln!(o, "  pub(crate) unsafe fn replay_call(").unwrap();
+  writeln!(o, "    &self, name: &str, args: &mut [TraceValue],").unwrap();
+  writeln!(o, "  ) -> Option<TraceValue> {{").unwrap();
+  writeln!(o, "    Some(match (name, args.len()) {{").unwrap();
+  for (c, method, kinds, ret_type) in replay_arms.iter() {
+    writeln!(o, "      (\"{}\", {}) => {{", c.name, kinds.len()).unwrap();
+    let values: Vec<CallArg> = kinds
+      .iter()
+      .zip(c.params.iter())
+      .enumerate()
+      .map(|(i, (k, p))| CallArg::Plain(k.replay(i, &param_type(p))))
+      .collect();
+    let callee = match ret_type {
+      Some(_) => format!("let out = self.{}", method),
+      None => format!("self.{}", method),
+    };
+    o.push_str(&format_call("        ", &callee, &values, ";"));
+    match ret_type {
+      Some(r) => {
+        let record = TraceKind::of(r)?.record("out", r);
+        writeln!(o, "        {}", record).unwrap()
+      }
+      None => writeln!(o, "        TraceValue::Void").unwrap(),
+    }
+    writeln!(o, "      }}").unwrap();
+  }
+  writeln!(o, "      _ => return None,").unwrap();
+  writeln!(o, "    }})").unwrap();
+  writeln!(o, "  }}").unwrap();
+  writeln!(o, "}}").unwrap();
+  writeln!(o).unwrap();
+  writeln!(o, "#[cold]").unwrap();
+  writeln!(o, "#[inline(never)]").unwrap();
+  writeln!(o, "fn not_loaded(name: &str) -> ! {{").unwrap();
+  writeln!(o, "  panic!(\"`{{}}` wasn't loaded\", name)").unwrap();
+  writeln!(o, "}}").unwrap();
+  Ok(out)
+}
+
+#[test]
+fn test_c_types() {
+  let rust = |c: &str| CType::parse(c).to_rust();
+  assert_eq!(rust("GLenum"), "GLenum");
+  assert_eq!(rust("unsigned int"), "c_uint");
+  assert_eq!(rust("const GLubyte *"), "*const GLubyte");
+  assert_eq!(rust("void **"), "*mut *mut c_void");
+  assert_eq!(rust("const GLchar *const*"), "*const *const GLchar");
+  assert_eq!(rust("const void *const*"), "*const *const c_void");
+  assert_eq!(rust("GLchar *const*"), "*const *mut GLchar");
+  assert_eq!(rust("struct __GLsync *"), "*mut __GLsync");
+  assert_eq!(rust("khronos_ssize_t"), "isize");
+  let array =
+    Param { name: "v".into(), c_type: "GLuint".into(), is_array: true };
+  assert_eq!(param_type(&array), "*mut GLuint");
+  assert_eq!(return_type("void"), None);
+  assert_eq!(return_type("void *").as_deref(), Some("*mut c_void"));
+  assert_eq!(param_name("type"), "type_");
+}
+
+#[test]
+fn test_generate_from_registry() {
+  let registry = crate::registry::vendored();
+  let gen = |api: &str, version, profile: &str| {
+    let spec = Spec {
+      api: api.into(),
+      version,
+      profile: profile.into(),
+      extensions: vec!["GL_KHR_debug".into()],
+    };
+    generate(&registry.select(&spec).unwrap(), &spec, "gl.xml").unwrap()
+  };
+  // the values are from the GL spec, and the signatures from `glcorearb.h`.
+  let out = gen("gl", (3, 3), "core");
+  let expected = [
+    "pub type GLbitfield = c_uint;\n",
+    "pub type GLfloat = c_float;\n",
+    "pub type GLint64 = i64;\n",
+    "pub type GLsizeiptr = isize;\n",
+    "pub type GLsync = *mut __GLsync;\n",
+    "pub const GL_COLOR_BUFFER_BIT: GLbitfield = 0x00004000;\n",
+    "pub const GL_TIMEOUT_IGNORED: GLuint64 = 0xFFFFFFFFFFFFFFFF;\n",
+    "pub const GL_TRIANGLES: GLenum = 0x0004;\n",
+    "pub const GL_INVALID_INDEX: GLuint = 0xFFFFFFFF;\n",
+    "    0x0500 => \"GL_INVALID_ENUM\",\n",
+    "/// From `gl.xml`: `void glClear(GLbitfield mask)`\n",
+    "pub type glClear_t = Option<unsafe extern \"system\" fn(mask: GLbitfield)>;\n",
+    ") -> *const GLubyte,\n>;\n",
+    "  pub glDebugMessageCallback: glDebugMessageCallback_t,\n",
+    "      glClear: core::mem::transmute::<*const c_void, \
+     glClear_t>(loader(b\"glClear\\0\")),\n",
+  ];
+  for e in expected.iter() {
+    assert!(out.contains(e), "missing: {}", e);
+  }
+  assert!(out.contains(
+    "pub type glShaderSource_t = Option<\n  unsafe extern \"system\" fn(\n    \
+     shader: GLuint,\n    count: GLsizei,\n    string: *const *const GLchar,\n"
+  ));
+  assert!(!out.contains("glDispatchCompute"));
+  assert!(!out.contains("glBegin_t"));
+
+  let compat = gen("gl", (4, 6), "compatibility");
+  assert!(compat.contains(
+    "pub type glBegin_t = Option<unsafe extern \"system\" fn(mode: GLenum)>;\n"
+  ));
+  assert!(compat.contains("pub const GL_QUADS: GLenum = 0x0007;\n"));
+
+  // ES has its own typedefs for some of the types, which replace the GL ones.
+  let es = gen("gles2", (3, 0), "");
+  assert_eq!(es.matches("pub type GLbyte = ").count(), 1);
+  assert!(es.contains("pub type GLbyte = i8;\n"));
+  assert!(es.contains("pub type GLfixed = i32;\n"));
+  assert!(es.contains("pub glClearDepthf: glClearDepthf_t,\n"));
+  assert!(es.contains("pub glDebugMessageCallbackKHR:"));
+  assert!(!es.contains("glBegin_t"));
+}
+
+#[test]
+fn test_generate_table() {
+  let registry = crate::registry::vendored();
+  let spec = Spec {
+    api: "gl".into(),
+    version: (3, 3),
+    profile: "core".into(),
+    extensions: vec!["GL_KHR_debug".into()],
+  };
+  let selection = registry.select(&spec).unwrap();
+  let out = generate_table(&selection, &spec, "gl.xml", "Gl33").unwrap();
+  let expected = [
+    "pub struct Gl33 {\n",
+    "  glClear: glClear_t,\n",
+    "      glClear: core::mem::transmute::<*const c_void, \
+     glClear_t>(get(\"glClear\\0\")),\n",
+    "  pub unsafe fn Clear(&self, mask: GLbitfield) {\n    match self.glClear \
+     {\n      Some(fn_ptr) => fn_ptr(mask),\n      None => \
+     not_loaded(\"glClear\"),\n    }\n    #[cfg(feature = \"trace_gl\")]\n    \
+     trace_gl_call(\n      self.glGetIntegerv,\n      \"glClear\",\n      \
+     &[\"mask\"],\n      &[TraceValue::UInt(mask as u64)],\n      \
+     TraceValue::Void,\n    );\n    #[cfg(feature = \"checked_gl\")]\n    \
+     check_gl_error(self.glGetError, \"glClear\", &[(\"mask\", &mask)]);\n  \
+     }\n",
+    "  pub unsafe fn GetError(&self) -> GLenum {\n    let out = match \
+     self.glGetError {\n",
+    "    let out = match self.glCreateShader {\n",
+    "  pub unsafe fn DebugMessageCallback(\n",
+    "      glDebugMessageCallback: \
+     core::mem::transmute::<*const c_void, glDebugMessageCallback_t>(\
+     get_extension(\"glDebugMessageCallback\\0\")),\n",
+    "  pub fn loaded_GL_KHR_debug(&self) -> bool {\n    \
+     self.glDebugMessageCallback.is_some()\n      && \
+     self.glDebugMessageControl.is_some()\n",
+    "      (\"glClear\", 1) => {\n        \
+     self.Clear(args[0].replay_int() as GLbitfield);\n",
+  ];
+  for e in expected.iter() {
+    assert!(out.contains(e), "missing: {}", e);
+  }
+  assert!(!out.contains("DispatchCompute"));
+}
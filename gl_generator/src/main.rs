@@ -0,0 +1,195 @@
+//! Generates the GL bindings of `triangle-from-scratch` from `gl.xml`.
+//!
+//! With no arguments this rewrites every file in [`CHECKED_IN`], which is what
+//! you want after changing the registry or the generator. With `--check` the
+//! files are compared instead, and it exits with an error if any differ.
+//!
+//! Other outputs can be picked with the flags:
+//!
+//! ```txt
+//! gl_generator [--registry PATH] [--api gl] [--version 4.6]
+//!   [--profile core] [--extensions GL_A,GL_B] [--table NAME] [--out PATH]
+//! ```
+//!
+//! No `--out` prints to stdout. With `--table` the output is a function table
+//! struct of that name, which uses the types and enums from its parent module.
+//! With the `checked_gl` feature the table also calls a `check_gl_error`
+//! function from its parent module after each GL call.
+
+mod gen;
+mod registry;
+mod xml;
+
+use registry::{Registry, Spec};
+
+/// The vendored registry file, an unchanged copy of `xml/gl.xml` from
+/// <https://github.com/KhronosGroup/OpenGL-Registry> (Apache-2.0, see the
+/// comment at its top).
+const REGISTRY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/gl.xml");
+
+/// One output file that's checked in to the repository.
+struct Target {
+  /// Relative to the workspace root.
+  path: &'static str,
+  api: &'static str,
+  version: (u32, u32),
+  profile: &'static str,
+  extensions: &'static [&'static str],
+  /// Generate a function table struct with this name, rather than the types,
+  /// enums, and `GlFns`.
+  table: Option<&'static str>,
+}
+impl Target {
+  fn spec(&self) -> Spec {
+    Spec {
+      api: self.api.to_string(),
+      version: self.version,
+      profile: self.profile.to_string(),
+      extensions: self.extensions.iter().map(|s| s.to_string()).collect(),
+    }
+  }
+  fn full_path(&self) -> String {
+    format!("{}/../{}", env!("CARGO_MANIFEST_DIR"), self.path)
+  }
+}
+
+/// The files that `cargo run -p gl_generator` writes.
+const CHECKED_IN: &[Target] = &[
+  Target {
+    path: "src/gl/generated.rs",
+    api: "gl",
+    version: (4, 6),
+    profile: "core",
+    extensions: &["GL_KHR_debug"],
+    table: None,
+  },
+  Target {
+    path: "src/gl/gl33.rs",
+    api: "gl",
+    version: (3, 3),
+    profile: "core",
+    extensions: &["GL_KHR_debug"],
+    table: Some("Gl33"),
+  },
+];
+
+fn generate(
+  registry: &Registry, spec: &Spec, table: Option<&str>,
+) -> Result<String, String> {
+  let selection = registry.select(spec)?;
+  match table {
+    Some(name) => gen::generate_table(&selection, spec, "gl.xml", name),
+    None => gen::generate(&selection, spec, "gl.xml"),
+  }
+}
+
+fn read_registry(path: &str) -> Result<Registry, String> {
+  let src = std::fs::read_to_string(path)
+    .map_err(|e| format!("couldn't read `{}`: {}", path, e))?;
+  Registry::parse(&src).map_err(|e| format!("{}: {}", path, e))
+}
+
+/// Writes (or checks) all of the [`CHECKED_IN`] files.
+fn run_checked_in(check: bool) -> Result<(), String> {
+  let registry = read_registry(REGISTRY)?;
+  let mut stale = Vec::new();
+  for target in CHECKED_IN.iter() {
+    let text = generate(&registry, &target.spec(), target.table)?;
+    let path = target.full_path();
+    if check {
+      if std::fs::read_to_string(&path).ok().as_deref() != Some(text.as_str()) {
+        stale.push(target.path);
+      }
+    } else {
+      std::fs::write(&path, text)
+        .map_err(|e| format!("couldn't write `{}`: {}", path, e))?;
+      println!("wrote {}", target.path);
+    }
+  }
+  if stale.is_empty() {
+    Ok(())
+  } else {
+    Err(format!("out of date: {}", stale.join(", ")))
+  }
+}
+
+fn run_custom(args: &[String]) -> Result<(), String> {
+  let mut registry_path = REGISTRY.to_string();
+  let mut spec = Spec {
+    api: "gl".to_string(),
+    version: (4, 6),
+    profile: "core".to_string(),
+    extensions: Vec::new(),
+  };
+  let mut out_path = None;
+  let mut table = None;
+  let mut it = args.iter();
+  while let Some(flag) = it.next() {
+    let value = it.next().ok_or_else(|| format!("`{}` needs a value", flag))?;
+    match flag.as_str() {
+      "--registry" => registry_path = value.clone(),
+      "--api" => spec.api = value.clone(),
+      "--profile" => spec.profile = value.clone(),
+      "--out" => out_path = Some(value.clone()),
+      "--table" => table = Some(value.clone()),
+      "--extensions" => {
+        spec.extensions = value
+          .split(',')
+          .filter(|s| !s.is_empty())
+          .map(str::to_string)
+          .collect()
+      }
+      "--version" => {
+        let (major, minor) = value
+          .split_once('.')
+          .ok_or_else(|| format!("bad version `{}`", value))?;
+        spec.version = (
+          major.parse().map_err(|_| format!("bad version `{}`", value))?,
+          minor.parse().map_err(|_| format!("bad version `{}`", value))?,
+        );
+      }
+      _ => return Err(format!("unknown flag `{}`", flag)),
+    }
+  }
+  let text =
+    generate(&read_registry(&registry_path)?, &spec, table.as_deref())?;
+  match out_path {
+    Some(path) => std::fs::write(&path, text)
+      .map_err(|e| format!("couldn't write `{}`: {}", path, e)),
+    None => {
+      print!("{}", text);
+      Ok(())
+    }
+  }
+}
+
+fn main() {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  let result = match args.as_slice() {
+    [] => run_checked_in(false),
+    [check] if check == "--check" => run_checked_in(true),
+    _ => run_custom(&args),
+  };
+  if let Err(e) = result {
+    eprintln!("error: {}", e);
+    std::process::exit(1);
+  }
+}
+
+#[test]
+fn test_checked_in_files_are_current() {
+  // The output must only depend on the registry and the target, so running
+  // the generator again gives the same bytes as what's checked in.
+  let registry = read_registry(REGISTRY).unwrap();
+  for target in CHECKED_IN.iter() {
+    let first = generate(&registry, &target.spec(), target.table).unwrap();
+    let second = generate(&registry, &target.spec(), target.table).unwrap();
+    assert!(first == second, "{} isn't deterministic", target.path);
+    let checked_in = std::fs::read_to_string(target.full_path()).unwrap();
+    assert!(
+      first == checked_in,
+      "{} is out of date, run `cargo run -p gl_generator`",
+      target.path
+    );
+  }
+}
@@ -0,0 +1,540 @@
+//! The parts of the registry that the generator uses.
+
+use crate::xml::{Element, Node};
+use std::collections::BTreeSet;
+
+/// A `<type>` from the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDef {
+  pub name: String,
+  pub api: Option<String>,
+  /// The C declaration, such as `typedef unsigned int GLenum;`
+  ///
+  /// This is empty for types that aren't a `typedef`, such as `khrplatform`.
+  pub c_decl: String,
+}
+
+/// An `<enum>` from the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumDef {
+  pub name: String,
+  pub api: Option<String>,
+  /// The value as written in the registry, such as `0x00004000`.
+  pub value: String,
+  /// The registry's `type` attribute: `u` or `ull` when it's given.
+  pub suffix: Option<String>,
+  /// If the enum came from a `type="bitmask"` block.
+  pub bitmask: bool,
+}
+
+/// A `<param>` of a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+  pub name: String,
+  /// The C type, such as `const GLchar *const*`.
+  pub c_type: String,
+  /// If the parameter is declared as an array, such as `GLuint v[2]`.
+  pub is_array: bool,
+}
+
+/// A `<command>` from the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+  pub name: String,
+  /// The C return type, such as `const GLubyte *`.
+  pub return_type: String,
+  pub params: Vec<Param>,
+}
+
+/// A `<require>` or `<remove>` block.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Interface {
+  pub api: Option<String>,
+  pub profile: Option<String>,
+  pub types: Vec<String>,
+  pub enums: Vec<String>,
+  pub commands: Vec<String>,
+}
+
+/// A `<feature>` from the registry, which is one version of an API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feature {
+  pub api: String,
+  pub name: String,
+  pub number: (u32, u32),
+  pub requires: Vec<Interface>,
+  pub removes: Vec<Interface>,
+}
+
+/// An `<extension>` from the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extension {
+  pub name: String,
+  /// The `|` separated list of APIs, such as `gl|glcore|gles2`.
+  pub supported: String,
+  pub requires: Vec<Interface>,
+}
+
+/// Everything that the generator uses from the registry file.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Registry {
+  pub types: Vec<TypeDef>,
+  pub enums: Vec<EnumDef>,
+  pub commands: Vec<Command>,
+  pub features: Vec<Feature>,
+  pub extensions: Vec<Extension>,
+}
+
+/// What to pick out of the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spec {
+  /// The API name, such as `gl` or `gles2`.
+  pub api: String,
+  /// The highest version to include.
+  pub version: (u32, u32),
+  /// `core` or `compatibility`.
+  pub profile: String,
+  /// Extension names, such as `GL_KHR_debug`.
+  pub extensions: Vec<String>,
+}
+
+/// The parts of the registry that a [`Spec`] picks.
+///
+/// The enums and commands are sorted by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection<'r> {
+  pub types: Vec<&'r TypeDef>,
+  pub enums: Vec<&'r EnumDef>,
+  pub commands: Vec<&'r Command>,
+  /// For each extension of the spec, the commands that it adds which the
+  /// version itself doesn't have, sorted by name.
+  pub extension_commands: Vec<(&'r str, Vec<&'r str>)>,
+}
+
+fn attr(e: &Element, name: &str) -> Option<String> {
+  e.attr(name).map(str::to_string)
+}
+
+fn required_attr(e: &Element, name: &str) -> Result<String, String> {
+  attr(e, name).ok_or_else(|| format!("`<{}>` needs a `{}`", e.name, name))
+}
+
+fn parse_interface(e: &Element) -> Interface {
+  let mut out = Interface {
+    api: attr(e, "api"),
+    profile: attr(e, "profile"),
+    ..Default::default()
+  };
+  for item in e.elements() {
+    let name = match item.attr("name") {
+      Some(name) => name.to_string(),
+      None => continue,
+    };
+    match item.name.as_str() {
+      "type" => out.types.push(name),
+      "enum" => out.enums.push(name),
+      "command" => out.commands.push(name),
+      _ => (),
+    }
+  }
+  out
+}
+
+fn parse_version(number: &str) -> Result<(u32, u32), String> {
+  let mut it = number.split('.').map(str::parse::<u32>);
+  match (it.next(), it.next(), it.next()) {
+    (Some(Ok(major)), Some(Ok(minor)), None) => Ok((major, minor)),
+    _ => Err(format!("bad version number `{}`", number)),
+  }
+}
+
+/// Splits a `<proto>` or `<param>` into the C type and the name.
+///
+/// **Returns:** `(c_type, name, text_after_name)`
+fn split_declaration(e: &Element) -> Result<(String, String, String), String> {
+  let mut c_type = String::new();
+  let mut name = None;
+  let mut after = String::new();
+  for node in e.children.iter() {
+    let text = match node {
+      Node::Element(child) if child.name == "name" => {
+        name = Some(child.text());
+        continue;
+      }
+      Node::Element(child) => child.text(),
+      Node::Text(t) => t.clone(),
+    };
+    if name.is_none() {
+      c_type.push_str(&text);
+    } else {
+      after.push_str(&text);
+    }
+  }
+  let name =
+    name.ok_or_else(|| format!("`<{}>` without a `<name>`", e.name))?;
+  Ok((c_type.trim().to_string(), name, after.trim().to_string()))
+}
+
+fn parse_command(e: &Element) -> Result<Command, String> {
+  let proto = e.first("proto").ok_or("`<command>` without a `<proto>`")?;
+  let (return_type, name, _) = split_declaration(proto)?;
+  let mut params = Vec::new();
+  for p in e.elements_named("param") {
+    let (c_type, name, after) = split_declaration(p)?;
+    params.push(Param { name, c_type, is_array: after.starts_with('[') });
+  }
+  Ok(Command { name, return_type, params })
+}
+
+fn parse_type(e: &Element) -> Option<TypeDef> {
+  let mut name = attr(e, "name");
+  let mut c_decl = String::new();
+  for node in e.children.iter() {
+    match node {
+      Node::Element(child) if child.name == "name" => {
+        name = Some(child.text());
+        c_decl.push_str(&child.text());
+      }
+      // `<apientry/>` is the calling convention macro, and it has no text.
+      Node::Element(child) => c_decl.push_str(&child.text()),
+      Node::Text(t) => c_decl.push_str(t),
+    }
+  }
+  if !c_decl.starts_with("typedef") {
+    c_decl.clear();
+  }
+  Some(TypeDef { name: name?, api: attr(e, "api"), c_decl })
+}
+
+impl Registry {
+  /// Parses the text of a registry file, such as `gl.xml`.
+  pub fn parse(src: &str) -> Result<Self, String> {
+    let root = crate::xml::parse(src).map_err(|e| e.to_string())?;
+    if root.name != "registry" {
+      return Err(format!("the root is `<{}>`, not `<registry>`", root.name));
+    }
+    let mut out = Self::default();
+    for block in root.elements() {
+      match block.name.as_str() {
+        "types" => {
+          out.types.extend(block.elements_named("type").filter_map(parse_type))
+        }
+        "enums" => {
+          let bitmask = block.attr("type") == Some("bitmask");
+          for e in block.elements_named("enum") {
+            out.enums.push(EnumDef {
+              name: required_attr(e, "name")?,
+              api: attr(e, "api"),
+              value: required_attr(e, "value")?,
+              suffix: attr(e, "type"),
+              bitmask,
+            });
+          }
+        }
+        "commands" => {
+          for e in block.elements_named("command") {
+            out.commands.push(parse_command(e)?);
+          }
+        }
+        "feature" => out.features.push(Feature {
+          api: required_attr(block, "api")?,
+          name: required_attr(block, "name")?,
+          number: parse_version(&required_attr(block, "number")?)?,
+          requires: block
+            .elements_named("require")
+            .map(parse_interface)
+            .collect(),
+          removes: block
+            .elements_named("remove")
+            .map(parse_interface)
+            .collect(),
+        }),
+        "extensions" => {
+          for e in block.elements_named("extension") {
+            out.extensions.push(Extension {
+              name: required_attr(e, "name")?,
+              supported: attr(e, "supported").unwrap_or_default(),
+              requires: e
+                .elements_named("require")
+                .map(parse_interface)
+                .collect(),
+            });
+          }
+        }
+        _ => (),
+      }
+    }
+    Ok(out)
+  }
+
+  /// Picks the types, enums, and commands for a [`Spec`].
+  ///
+  /// * Features of the API are applied in version order, up to the version
+  ///   of the spec. `<remove>` blocks take away what earlier versions added.
+  /// * Each extension must exist and support the API, or this is an error.
+  /// * All `typedef` types for the API are included, used or not.
+  /// * When a type or enum has an entry just for the API (eg: `api="gles2"`),
+  ///   that's used instead of the entry without an `api`.
+  pub fn select(&self, spec: &Spec) -> Result<Selection<'_>, String> {
+    let api_matches = |api: &Option<String>| match api {
+      Some(api) => *api == spec.api,
+      None => true,
+    };
+    let for_this_api = |api: &Option<String>| api.as_ref() == Some(&spec.api);
+    let applies = |i: &&Interface| {
+      api_matches(&i.api)
+        && match &i.profile {
+          Some(profile) => *profile == spec.profile,
+          None => true,
+        }
+    };
+    let mut enums = BTreeSet::new();
+    let mut commands = BTreeSet::new();
+
+    let mut features: Vec<&Feature> = self
+      .features
+      .iter()
+      .filter(|f| f.api == spec.api && f.number <= spec.version)
+      .collect();
+    if features.is_empty() {
+      return Err(format!(
+        "no features of `{}` up to {:?}",
+        spec.api, spec.version
+      ));
+    }
+    features.sort_by_key(|f| f.number);
+    for feature in features {
+      for i in feature.requires.iter().filter(applies) {
+        enums.extend(i.enums.iter().map(String::as_str));
+        commands.extend(i.commands.iter().map(String::as_str));
+      }
+      for i in feature.removes.iter().filter(applies) {
+        for e in i.enums.iter() {
+          enums.remove(e.as_str());
+        }
+        for c in i.commands.iter() {
+          commands.remove(c.as_str());
+        }
+      }
+    }
+
+    let core_api = format!("{}core", spec.api);
+    let version_commands = commands.clone();
+    let mut extension_commands = Vec::new();
+    for name in spec.extensions.iter() {
+      let ext = self
+        .extensions
+        .iter()
+        .find(|e| e.name == *name)
+        .ok_or_else(|| format!("no extension named `{}`", name))?;
+      if !ext
+        .supported
+        .split('|')
+        .any(|s| s == spec.api || (spec.profile == "core" && s == core_api))
+      {
+        return Err(format!("`{}` doesn't support `{}`", name, spec.api));
+      }
+      let mut added = BTreeSet::new();
+      for i in ext.requires.iter().filter(applies) {
+        enums.extend(i.enums.iter().map(String::as_str));
+        commands.extend(i.commands.iter().map(String::as_str));
+        added.extend(i.commands.iter().map(String::as_str));
+      }
+      let added = added.difference(&version_commands).copied().collect();
+      extension_commands.push((ext.name.as_str(), added));
+    }
+
+    let find_enum = |name: &str| {
+      let mut defs = self.enums.iter().filter(|e| e.name == name);
+      let api_specific = defs.clone().find(|e| for_this_api(&e.api));
+      api_specific
+        .or_else(|| defs.find(|e| e.api.is_none()))
+        .ok_or_else(|| format!("`{}` is required but not defined", name))
+    };
+    let find_command = |name: &str| {
+      self
+        .commands
+        .iter()
+        .find(|c| c.name == name)
+        .ok_or_else(|| format!("`{}` is required but not defined", name))
+    };
+    Ok(Selection {
+      types: self
+        .types
+        .iter()
+        .filter(|t| !t.c_decl.is_empty())
+        .filter(|t| match &t.api {
+          Some(_) => for_this_api(&t.api),
+          None => !self.types.iter().any(|other| {
+            other.name == t.name
+              && !other.c_decl.is_empty()
+              && for_this_api(&other.api)
+          }),
+        })
+        .collect(),
+      enums: enums.into_iter().map(find_enum).collect::<Result<_, _>>()?,
+      commands: commands
+        .into_iter()
+        .map(find_command)
+        .collect::<Result<_, _>>()?,
+      extension_commands,
+    })
+  }
+}
+
+#[cfg(test)]
+pub(crate) fn vendored() -> Registry {
+  let path = concat!(env!("CARGO_MANIFEST_DIR"), "/gl.xml");
+  Registry::parse(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn test_parse_registry_markup() {
+  // the upstream file puts `group` and `len` on `<proto>` and `<param>`, and
+  // `<alias>`, `<vecequiv>`, and `<glx>` in a `<command>`, none of which
+  // should change the declarations.
+  let registry = vendored();
+  let command =
+    |name: &str| registry.commands.iter().find(|c| c.name == name).unwrap();
+  let param_types = |name: &str| -> Vec<String> {
+    command(name).params.iter().map(|p| p.c_type.clone()).collect()
+  };
+  let param_names = |name: &str| -> Vec<String> {
+    command(name).params.iter().map(|p| p.name.clone()).collect()
+  };
+  assert_eq!(command("glGetString").return_type, "const GLubyte *");
+  assert_eq!(
+    param_names("glShaderSource"),
+    ["shader", "count", "string", "length"]
+  );
+  assert_eq!(
+    param_types("glShaderSource"),
+    ["GLuint", "GLsizei", "const GLchar *const*", "const GLint *"]
+  );
+  assert_eq!(param_types("glActiveTextureARB"), ["GLenum"]);
+  assert_eq!(param_types("glColor3fv"), ["const GLfloat *"]);
+  assert_eq!(param_types("glClear"), ["GLbitfield"]);
+  assert!(command("glGetUniformIndices").params[2].c_type.ends_with("*const*"));
+  assert!(command("glPathGlyphIndexRangeNV").params[5].is_array);
+
+  // each command and `<feature>` is only defined once.
+  let mut names: Vec<&str> =
+    registry.commands.iter().map(|c| c.name.as_str()).collect();
+  names.sort_unstable();
+  assert!(names.windows(2).all(|w| w[0] != w[1]));
+  let features: Vec<(&str, &str)> = registry
+    .features
+    .iter()
+    .map(|f| (f.api.as_str(), f.name.as_str()))
+    .collect();
+  assert_eq!(features.iter().filter(|(api, _)| *api == "gl").count(), 19);
+  assert!(features.contains(&("gles2", "GL_ES_VERSION_3_2")));
+  assert!(features.contains(&("gles1", "GL_VERSION_ES_CM_1_0")));
+
+  // profiles start at GL 3.2, which takes away the old fixed function API.
+  let v32 = registry.features.iter().find(|f| f.number == (3, 2)).unwrap();
+  assert!(v32.removes.iter().all(|r| r.profile.as_deref() == Some("core")));
+  assert!(v32
+    .removes
+    .iter()
+    .any(|r| r.commands.iter().any(|c| c == "glBegin")));
+
+  // the two `GL_ACTIVE_PROGRAM_EXT` are for different APIs.
+  let active_program: Vec<(Option<&str>, &str)> = registry
+    .enums
+    .iter()
+    .filter(|e| e.name == "GL_ACTIVE_PROGRAM_EXT")
+    .map(|e| (e.api.as_deref(), e.value.as_str()))
+    .collect();
+  assert_eq!(
+    active_program,
+    [(Some("gles2"), "0x8259"), (Some("gl"), "0x8B8D")]
+  );
+}
+
+#[test]
+fn test_select() {
+  let registry = vendored();
+  let spec = |api: &str, version, profile: &str, extensions: &[&str]| Spec {
+    api: api.into(),
+    version,
+    profile: profile.into(),
+    extensions: extensions.iter().map(|s| s.to_string()).collect(),
+  };
+  let gl = |version, extensions: &[&str]| {
+    registry.select(&spec("gl", version, "core", extensions)).unwrap()
+  };
+  let has =
+    |s: &Selection, name: &str| s.commands.iter().any(|c| c.name == name);
+
+  // these counts are the functions that `glcorearb.h` declares for each
+  // version, except that it has `glGetPointerv` with GL 1.1, when core GL 3.2
+  // removed it and GL 4.3 added it back.
+  assert_eq!(gl((4, 6), &[]).commands.len(), 657);
+  assert_eq!(gl((3, 3), &[]).commands.len(), 345 - 1);
+  assert!(!has(&gl((3, 3), &[]), "glGetPointerv"));
+  assert!(has(&gl((4, 3), &[]), "glGetPointerv"));
+
+  let gl20 = gl((2, 0), &[]);
+  assert!(has(&gl20, "glCreateShader"));
+  assert!(!has(&gl20, "glBindVertexArray"));
+  let gl20_debug = gl((2, 0), &["GL_KHR_debug"]);
+  assert!(has(&gl20_debug, "glDebugMessageCallback"));
+  assert!(!has(&gl20_debug, "glDebugMessageCallbackKHR"));
+  assert!(gl20_debug.enums.windows(2).all(|w| w[0].name < w[1].name));
+  // 4.3 made `KHR_debug` core, and with 3.3 it adds all of its functions.
+  let debug_commands =
+    |version| gl(version, &["GL_KHR_debug"]).extension_commands;
+  assert_eq!(debug_commands((4, 3)), [("GL_KHR_debug", vec![])]);
+  let (name, added) = &debug_commands((3, 3))[0];
+  assert_eq!(*name, "GL_KHR_debug");
+  assert_eq!(added.len(), 11);
+  assert!(added.contains(&"glDebugMessageCallback"));
+  assert!(added.contains(&"glGetPointerv"));
+
+  assert!(has(&gl((3, 1), &[]), "glBegin"));
+  assert!(!has(&gl((3, 2), &[]), "glBegin"));
+  let compat =
+    registry.select(&spec("gl", (4, 6), "compatibility", &[])).unwrap();
+  assert!(has(&compat, "glBegin"));
+  assert!(has(&compat, "glSpecializeShader"));
+  assert!(compat.commands.len() > 657);
+
+  let gles20 =
+    registry.select(&spec("gles2", (2, 0), "", &["GL_KHR_debug"])).unwrap();
+  assert!(has(&gles20, "glClearDepthf"));
+  assert!(!has(&gles20, "glClearDepth"));
+  assert!(!has(&gles20, "glBegin"));
+  assert!(!has(&gles20, "glBindVertexArray"));
+  assert!(has(&gles20, "glDebugMessageCallbackKHR"));
+  assert!(!has(&gles20, "glDebugMessageCallback"));
+  let gles30 = registry.select(&spec("gles2", (3, 0), "", &[])).unwrap();
+  assert!(has(&gles30, "glBindVertexArray"));
+
+  // an entry just for the API replaces the general one.
+  let byte_types = |s: &Selection| -> Vec<String> {
+    s.types
+      .iter()
+      .filter(|t| t.name == "GLbyte")
+      .map(|t| t.c_decl.clone())
+      .collect()
+  };
+  assert_eq!(byte_types(&gl20), ["typedef signed char GLbyte;"]);
+  assert_eq!(byte_types(&gles20), ["typedef khronos_int8_t GLbyte;"]);
+  let active_program = |api: &str| {
+    let s = spec(api, (3, 0), "", &["GL_EXT_separate_shader_objects"]);
+    let selection = registry.select(&s).unwrap();
+    let e = selection.enums.iter().find(|e| e.name == "GL_ACTIVE_PROGRAM_EXT");
+    e.unwrap().value.clone()
+  };
+  assert_eq!(active_program("gl"), "0x8B8D");
+  assert_eq!(active_program("gles2"), "0x8259");
+
+  assert!(registry
+    .select(&spec("gl", (3, 3), "core", &["GL_not_real"]))
+    .is_err());
+  assert!(registry.select(&spec("gl", (0, 9), "core", &[])).is_err());
+  assert!(registry
+    .select(&spec("gles2", (2, 0), "", &["GL_ARB_sync"]))
+    .is_err());
+}
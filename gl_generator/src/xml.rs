@@ -0,0 +1,290 @@
+//! A tiny XML parser, which handles just what the Khronos registry files use.
+//!
+//! There's no DTD or namespace support. Comments, processing instructions, and
+//! `<!DOCTYPE>` are skipped, and the five standard entities plus numeric
+//! character references are decoded.
+
+/// Any node of an element's content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+  Element(Element),
+  Text(String),
+}
+
+/// An element, with its attributes and content.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Element {
+  pub name: String,
+  pub attrs: Vec<(String, String)>,
+  pub children: Vec<Node>,
+}
+impl Element {
+  /// Gets an attribute's value, if the element has it.
+  pub fn attr(&self, name: &str) -> Option<&str> {
+    self.attrs.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+  }
+
+  /// Iterates the child elements, skipping any text.
+  pub fn elements(&self) -> impl Iterator<Item = &Element> {
+    self.children.iter().filter_map(|node| match node {
+      Node::Element(e) => Some(e),
+      Node::Text(_) => None,
+    })
+  }
+
+  /// Iterates the child elements with the name given.
+  pub fn elements_named<'a>(
+    &'a self, name: &'a str,
+  ) -> impl Iterator<Item = &'a Element> {
+    self.elements().filter(move |e| e.name == name)
+  }
+
+  /// Gets the first child element with the name given.
+  pub fn first(&self, name: &str) -> Option<&Element> {
+    self.elements().find(|e| e.name == name)
+  }
+
+  /// All the text within this element, including that of any child elements.
+  pub fn text(&self) -> String {
+    let mut out = String::new();
+    self.push_text(&mut out);
+    out
+  }
+  fn push_text(&self, out: &mut String) {
+    for node in self.children.iter() {
+      match node {
+        Node::Element(e) => e.push_text(out),
+        Node::Text(t) => out.push_str(t),
+      }
+    }
+  }
+}
+
+/// An error from parsing, with the 1-based line where it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlError {
+  pub line: usize,
+  pub message: String,
+}
+impl core::fmt::Display for XmlError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "line {}: {}", self.line, self.message)
+  }
+}
+impl std::error::Error for XmlError {}
+
+/// Parses a document, giving the root element.
+pub fn parse(src: &str) -> Result<Element, XmlError> {
+  let mut p = Parser { src, pos: 0 };
+  p.skip_misc()?;
+  let root = p.element()?;
+  p.skip_misc()?;
+  if p.pos < src.len() {
+    return Err(p.error("content after the root element"));
+  }
+  Ok(root)
+}
+
+struct Parser<'s> {
+  src: &'s str,
+  pos: usize,
+}
+impl<'s> Parser<'s> {
+  fn rest(&self) -> &'s str {
+    &self.src[self.pos..]
+  }
+
+  fn error(&self, message: &str) -> XmlError {
+    let line = 1 + self.src[..self.pos].bytes().filter(|&b| b == b'\n').count();
+    XmlError { line, message: message.to_string() }
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+    self.pos += rest.len() - rest.trim_start().len();
+  }
+
+  /// Skips past the `end` marker, erroring if there isn't one.
+  fn skip_past(&mut self, end: &str) -> Result<(), XmlError> {
+    match self.rest().find(end) {
+      Some(i) => {
+        self.pos += i + end.len();
+        Ok(())
+      }
+      None => Err(self.error(&format!("missing `{}`", end))),
+    }
+  }
+
+  /// Skips whitespace, comments, processing instructions, and doctypes.
+  fn skip_misc(&mut self) -> Result<(), XmlError> {
+    loop {
+      self.skip_whitespace();
+      let rest = self.rest();
+      if rest.starts_with("<!--") {
+        self.skip_past("-->")?;
+      } else if rest.starts_with("<?") {
+        self.skip_past("?>")?;
+      } else if rest.starts_with("<!") {
+        self.skip_past(">")?;
+      } else {
+        return Ok(());
+      }
+    }
+  }
+
+  fn name(&mut self) -> Result<&'s str, XmlError> {
+    let rest = self.rest();
+    let len = rest
+      .find(|c: char| !(c.is_alphanumeric() || "_-.:".contains(c)))
+      .unwrap_or(rest.len());
+    if len == 0 {
+      return Err(self.error("expected a name"));
+    }
+    self.pos += len;
+    Ok(&rest[..len])
+  }
+
+  fn expect(&mut self, s: &str) -> Result<(), XmlError> {
+    if self.rest().starts_with(s) {
+      self.pos += s.len();
+      Ok(())
+    } else {
+      Err(self.error(&format!("expected `{}`", s)))
+    }
+  }
+
+  fn element(&mut self) -> Result<Element, XmlError> {
+    self.expect("<")?;
+    let mut element =
+      Element { name: self.name()?.to_string(), ..Element::default() };
+    loop {
+      self.skip_whitespace();
+      if self.rest().starts_with("/>") {
+        self.pos += 2;
+        return Ok(element);
+      } else if self.rest().starts_with('>') {
+        self.pos += 1;
+        break;
+      }
+      let key = self.name()?.to_string();
+      self.skip_whitespace();
+      self.expect("=")?;
+      self.skip_whitespace();
+      let quote = if self.rest().starts_with('"') { "\"" } else { "'" };
+      self.expect(quote)?;
+      let len = match self.rest().find(quote) {
+        Some(len) => len,
+        None => return Err(self.error("unterminated attribute value")),
+      };
+      let value = unescape(&self.rest()[..len]).map_err(|m| self.error(m))?;
+      self.pos += len + 1;
+      element.attrs.push((key, value));
+    }
+    // content
+    loop {
+      let rest = self.rest();
+      if rest.starts_with("</") {
+        self.pos += 2;
+        let name = self.name()?;
+        if name != element.name {
+          return Err(self.error(&format!(
+            "`</{}>` doesn't match `<{}>`",
+            name, element.name
+          )));
+        }
+        self.skip_whitespace();
+        self.expect(">")?;
+        return Ok(element);
+      } else if rest.starts_with("<!--") {
+        self.skip_past("-->")?;
+      } else if rest.starts_with("<![CDATA[") {
+        self.pos += "<![CDATA[".len();
+        let len = match self.rest().find("]]>") {
+          Some(len) => len,
+          None => return Err(self.error("missing `]]>`")),
+        };
+        let text = self.rest()[..len].to_string();
+        self.pos += len + 3;
+        element.children.push(Node::Text(text));
+      } else if rest.starts_with('<') {
+        let child = self.element()?;
+        element.children.push(Node::Element(child));
+      } else if rest.is_empty() {
+        return Err(self.error(&format!("missing `</{}>`", element.name)));
+      } else {
+        let len = rest.find('<').unwrap_or(rest.len());
+        let text = unescape(&rest[..len]).map_err(|m| self.error(m))?;
+        self.pos += len;
+        element.children.push(Node::Text(text));
+      }
+    }
+  }
+}
+
+/// Decodes the entity and character references in some text.
+fn unescape(s: &str) -> Result<String, &'static str> {
+  let mut out = String::with_capacity(s.len());
+  let mut rest = s;
+  while let Some(i) = rest.find('&') {
+    out.push_str(&rest[..i]);
+    rest = &rest[i + 1..];
+    let end = rest.find(';').ok_or("unterminated entity reference")?;
+    let entity = &rest[..end];
+    let c = match entity {
+      "lt" => '<',
+      "gt" => '>',
+      "amp" => '&',
+      "quot" => '"',
+      "apos" => '\'',
+      _ => {
+        let code = if let Some(hex) = entity.strip_prefix("#x") {
+          u32::from_str_radix(hex, 16).ok()
+        } else if let Some(dec) = entity.strip_prefix('#') {
+          dec.parse().ok()
+        } else {
+          None
+        };
+        code.and_then(char::from_u32).ok_or("unknown entity reference")?
+      }
+    };
+    out.push(c);
+    rest = &rest[end + 1..];
+  }
+  out.push_str(rest);
+  Ok(out)
+}
+
+#[test]
+fn test_parse() {
+  let doc = parse(
+    r#"<?xml version="1.0"?>
+<!-- a comment -->
+<registry>
+  <type name='khrplatform'>#include &lt;KHR/khrplatform.h&gt;</type>
+  <!-- another comment -->
+  <command><proto>void <name>glClear</name></proto><empty/></command>
+</registry>
+"#,
+  )
+  .unwrap();
+  assert_eq!(doc.name, "registry");
+  let ty = doc.first("type").unwrap();
+  assert_eq!(ty.attr("name"), Some("khrplatform"));
+  assert_eq!(ty.text(), "#include <KHR/khrplatform.h>");
+  let command = doc.first("command").unwrap();
+  assert_eq!(command.text(), "void glClear");
+  assert_eq!(
+    command.first("proto").unwrap().first("name").unwrap().text(),
+    "glClear"
+  );
+  assert!(command.first("empty").unwrap().children.is_empty());
+}
+
+#[test]
+fn test_parse_errors() {
+  assert_eq!(parse("<a>\n<b></a>").unwrap_err().line, 2);
+  assert!(parse("<a>&bogus;</a>").is_err());
+  assert!(parse("<a>").is_err());
+  assert!(parse("<a/><b/>").is_err());
+  assert_eq!(parse("<a>&#x41;&#66;</a>").unwrap().text(), "AB");
+}
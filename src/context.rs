@@ -0,0 +1,495 @@
+//! Module for asking for a GL context the same way on every backend.
+//!
+//! A [`ContextRequest`] has a list of [`ContextVersion`] to try in order, and
+//! the settings that every attempt uses. Each backend turns it into its own
+//! attribute list and tries the versions until one works:
+//! `create_wgl_context` with WGL, `create_glx_context` with GLX, and
+//! `create_egl_context` with EGL. WGL and GLX build their lists the same way,
+//! with [`ArbContextEnums`].
+//!
+//! ```
+//! use triangle_from_scratch::context::*;
+//! let request: ContextRequest<()> = ContextRequest::default().debug(true);
+//! let created = request
+//!   .try_each(|version| match version.major {
+//!     4 => Err(AttemptError::Failed("too new")),
+//!     _ => Ok(version.to_string()),
+//!   })
+//!   .unwrap();
+//! assert_eq!(created.context, "GL 3.3 core");
+//! assert_eq!(created.failed.len(), 2);
+//! ```
+
+use core::ffi::c_int;
+
+/// The API of a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Api {
+  Gl,
+  Gles,
+}
+
+/// The profile of a desktop GL context, which needs GL 3.2 or later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+  Core,
+  Compatibility,
+}
+
+/// One API, version, and profile to try.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContextVersion {
+  pub api: Api,
+  pub major: u8,
+  pub minor: u8,
+  /// `None` doesn't ask for a profile, which is what GLES and GL before 3.2
+  /// need.
+  pub profile: Option<Profile>,
+}
+impl ContextVersion {
+  pub const fn gl_core(major: u8, minor: u8) -> Self {
+    Self { api: Api::Gl, major, minor, profile: Some(Profile::Core) }
+  }
+
+  pub const fn gl_compatibility(major: u8, minor: u8) -> Self {
+    Self { api: Api::Gl, major, minor, profile: Some(Profile::Compatibility) }
+  }
+
+  /// Desktop GL without a profile.
+  pub const fn gl(major: u8, minor: u8) -> Self {
+    Self { api: Api::Gl, major, minor, profile: None }
+  }
+
+  pub const fn gles(major: u8, minor: u8) -> Self {
+    Self { api: Api::Gles, major, minor, profile: None }
+  }
+}
+impl core::fmt::Display for ContextVersion {
+  /// eg: `GL 4.6 core`, `GL 3.0`, or `GLES 3.2`
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let api = match self.api {
+      Api::Gl => "GL",
+      Api::Gles => "GLES",
+    };
+    write!(f, "{} {}.{}", api, self.major, self.minor)?;
+    match self.profile {
+      Some(Profile::Core) => write!(f, " core"),
+      Some(Profile::Compatibility) => write!(f, " compatibility"),
+      None => Ok(()),
+    }
+  }
+}
+
+/// What a robust context does when the GPU is reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResetNotification {
+  /// The context keeps going, and isn't told about the reset.
+  NoResetNotification,
+  /// The context is lost, and `glGetGraphicsResetStatus` says why.
+  LoseContextOnReset,
+}
+
+/// The context to make, with the versions to fall back to.
+///
+/// `S` is the backend's context type, for the context to share objects with.
+///
+/// The default tries GL 4.6 core, then 4.1 core, then 3.3 core, then 3.0,
+/// and asks for nothing else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextRequest<S> {
+  /// The versions to try, in order.
+  pub versions: Vec<ContextVersion>,
+  /// Leave out the deprecated parts of desktop GL 3.0 and later. Other
+  /// versions ignore this.
+  pub forward_compatible: bool,
+  pub debug: bool,
+  /// `Some` asks for robust buffer access, with this reset behavior.
+  pub robustness: Option<ResetNotification>,
+  /// Errors are undefined behavior instead of being reported. This can't be
+  /// combined with `debug` or `robustness`, see [`conflict`](Self::conflict).
+  pub no_error: bool,
+  pub share: Option<S>,
+}
+impl<S> Default for ContextRequest<S> {
+  fn default() -> Self {
+    Self {
+      versions: Self::DEFAULT_VERSIONS.to_vec(),
+      forward_compatible: false,
+      debug: false,
+      robustness: None,
+      no_error: false,
+      share: None,
+    }
+  }
+}
+impl<S> ContextRequest<S> {
+  pub const DEFAULT_VERSIONS: [ContextVersion; 4] = [
+    ContextVersion::gl_core(4, 6),
+    ContextVersion::gl_core(4, 1),
+    ContextVersion::gl_core(3, 3),
+    ContextVersion::gl(3, 0),
+  ];
+
+  /// Replaces the versions to try.
+  pub fn versions(self, versions: &[ContextVersion]) -> Self {
+    Self { versions: versions.to_vec(), ..self }
+  }
+
+  pub fn forward_compatible(self, forward_compatible: bool) -> Self {
+    Self { forward_compatible, ..self }
+  }
+
+  pub fn debug(self, debug: bool) -> Self {
+    Self { debug, ..self }
+  }
+
+  pub fn robustness(self, robustness: Option<ResetNotification>) -> Self {
+    Self { robustness, ..self }
+  }
+
+  pub fn no_error(self, no_error: bool) -> Self {
+    Self { no_error, ..self }
+  }
+
+  pub fn share(self, share: Option<S>) -> Self {
+    Self { share, ..self }
+  }
+
+  /// If the forward compatible flag applies to the version.
+  pub fn is_forward_compatible(&self, version: &ContextVersion) -> bool {
+    self.forward_compatible && version.api == Api::Gl && version.major >= 3
+  }
+
+  /// Why the settings can't be used together, if they can't.
+  pub fn conflict(&self) -> Option<&'static str> {
+    if self.no_error && self.debug {
+      Some("no_error can't be combined with debug")
+    } else if self.no_error && self.robustness.is_some() {
+      Some("no_error can't be combined with robustness")
+    } else {
+      None
+    }
+  }
+
+  /// Calls `f` with each version in order, until one gives a context.
+  ///
+  /// The backends use this, and `f` is the backend making one context. If
+  /// the request has a [`conflict`](Self::conflict), `f` isn't called, and
+  /// every version fails with [`AttemptError::Conflict`].
+  pub fn try_each<C, E>(
+    &self, mut f: impl FnMut(&ContextVersion) -> Result<C, AttemptError<E>>,
+  ) -> Result<CreatedContext<C, E>, ContextError<E>> {
+    if let Some(why) = self.conflict() {
+      let failed = self
+        .versions
+        .iter()
+        .map(|&version| FailedAttempt {
+          version,
+          error: AttemptError::Conflict(why),
+        })
+        .collect();
+      return Err(ContextError { failed });
+    }
+    let mut failed = Vec::new();
+    for version in &self.versions {
+      match f(version) {
+        Ok(context) => {
+          return Ok(CreatedContext { context, version: *version, failed })
+        }
+        Err(error) => failed.push(FailedAttempt { version: *version, error }),
+      }
+    }
+    Err(ContextError { failed })
+  }
+}
+
+/// Why one attempt didn't make a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttemptError<E> {
+  /// The attempt needs an extension that isn't there, so it wasn't made.
+  MissingExtension(&'static str),
+  /// The request asks for settings that can't go together, so it wasn't
+  /// made.
+  Conflict(&'static str),
+  /// The backend tried, and gave this error.
+  Failed(E),
+}
+impl<E: core::fmt::Display> core::fmt::Display for AttemptError<E> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self {
+      Self::MissingExtension(name) => write!(f, "needs {}", name),
+      Self::Conflict(why) => write!(f, "{}", why),
+      Self::Failed(e) => write!(f, "{}", e),
+    }
+  }
+}
+
+/// One version that didn't work, and why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailedAttempt<E> {
+  pub version: ContextVersion,
+  pub error: AttemptError<E>,
+}
+impl<E: core::fmt::Display> core::fmt::Display for FailedAttempt<E> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{}: {}", self.version, self.error)
+  }
+}
+
+/// A context that was made, with the version that worked and the attempts
+/// before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedContext<C, E> {
+  pub context: C,
+  pub version: ContextVersion,
+  pub failed: Vec<FailedAttempt<E>>,
+}
+
+/// Every version of a [`ContextRequest`] failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextError<E> {
+  /// Each attempt in order, which is empty if there were no versions.
+  pub failed: Vec<FailedAttempt<E>>,
+}
+impl<E: core::fmt::Display> core::fmt::Display for ContextError<E> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if self.failed.is_empty() {
+      return write!(f, "no context versions to try");
+    }
+    write!(f, "no context could be made (")?;
+    for (i, attempt) in self.failed.iter().enumerate() {
+      let sep = if i > 0 { "; " } else { "" };
+      write!(f, "{}{}", sep, attempt)?;
+    }
+    write!(f, ")")
+  }
+}
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error
+  for ContextError<E>
+{
+}
+
+/// The extensions that a backend needs for the parts of a
+/// [`ContextRequest`]. An empty name is something the backend always has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtensionNames {
+  /// For every attempt, when the attributes themselves come from an
+  /// extension.
+  pub create_context: &'static str,
+  /// For a [`Profile`].
+  pub profile: &'static str,
+  /// For [`Api::Gles`].
+  pub es_profile: &'static str,
+  pub robustness: &'static str,
+  pub no_error: &'static str,
+}
+impl ExtensionNames {
+  /// The first extension that the request needs for the version, and that
+  /// isn't in `extensions`.
+  pub fn missing<S>(
+    &self, request: &ContextRequest<S>, version: &ContextVersion,
+    extensions: &[String],
+  ) -> Option<&'static str> {
+    let needs = [
+      (true, self.create_context),
+      (version.profile.is_some(), self.profile),
+      (version.api == Api::Gles, self.es_profile),
+      (request.robustness.is_some(), self.robustness),
+      (request.no_error, self.no_error),
+    ];
+    needs
+      .iter()
+      .filter(|(needed, name)| *needed && !name.is_empty())
+      .find(|(_, name)| !extensions.iter().any(|s| s == name))
+      .map(|(_, name)| *name)
+  }
+}
+
+/// The enums of `ARB_create_context` and the extensions that go with it,
+/// which WGL and GLX each have with their own prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArbContextEnums {
+  pub major_version: c_int,
+  pub minor_version: c_int,
+  pub flags: c_int,
+  pub debug_bit: c_int,
+  pub forward_compatible_bit: c_int,
+  pub robust_access_bit: c_int,
+  pub profile_mask: c_int,
+  pub core_profile_bit: c_int,
+  pub compatibility_profile_bit: c_int,
+  pub es_profile_bit: c_int,
+  pub reset_notification_strategy: c_int,
+  pub no_reset_notification: c_int,
+  pub lose_context_on_reset: c_int,
+  pub no_error: c_int,
+}
+impl ArbContextEnums {
+  /// The `CreateContextAttribsARB` attributes for one version of a request,
+  /// ending with `[0, 0]`.
+  ///
+  /// Check the backend's [`ExtensionNames`] first, since drivers without them
+  /// reject the attributes.
+  pub fn attribs<S>(
+    &self, request: &ContextRequest<S>, version: &ContextVersion,
+  ) -> Vec<[c_int; 2]> {
+    let mut attribs = vec![
+      [self.major_version, version.major.into()],
+      [self.minor_version, version.minor.into()],
+    ];
+    let mut flags = 0;
+    if request.debug {
+      flags |= self.debug_bit;
+    }
+    if request.is_forward_compatible(version) {
+      flags |= self.forward_compatible_bit;
+    }
+    if request.robustness.is_some() {
+      flags |= self.robust_access_bit;
+    }
+    if flags != 0 {
+      attribs.push([self.flags, flags]);
+    }
+    let profile = match (version.api, version.profile) {
+      (Api::Gles, _) => Some(self.es_profile_bit),
+      (Api::Gl, Some(Profile::Core)) => Some(self.core_profile_bit),
+      (Api::Gl, Some(Profile::Compatibility)) => {
+        Some(self.compatibility_profile_bit)
+      }
+      (Api::Gl, None) => None,
+    };
+    if let Some(profile) = profile {
+      attribs.push([self.profile_mask, profile]);
+    }
+    match request.robustness {
+      Some(ResetNotification::NoResetNotification) => attribs
+        .push([self.reset_notification_strategy, self.no_reset_notification]),
+      Some(ResetNotification::LoseContextOnReset) => attribs
+        .push([self.reset_notification_strategy, self.lose_context_on_reset]),
+      None => (),
+    }
+    if request.no_error {
+      attribs.push([self.no_error, 1]);
+    }
+    attribs.push([0, 0]);
+    attribs
+  }
+}
+
+#[test]
+fn test_context_request() {
+  let request: ContextRequest<u32> = ContextRequest::default();
+  assert_eq!(request.versions, ContextRequest::<u32>::DEFAULT_VERSIONS);
+  let names: Vec<String> =
+    request.versions.iter().map(|v| v.to_string()).collect();
+  assert_eq!(names, ["GL 4.6 core", "GL 4.1 core", "GL 3.3 core", "GL 3.0"]);
+  assert_eq!(ContextVersion::gles(3, 2).to_string(), "GLES 3.2");
+  assert_eq!(
+    ContextVersion::gl_compatibility(3, 2).to_string(),
+    "GL 3.2 compatibility"
+  );
+
+  let request = request.forward_compatible(true).share(Some(7));
+  assert!(request.is_forward_compatible(&ContextVersion::gl(3, 0)));
+  assert!(!request.is_forward_compatible(&ContextVersion::gl(2, 1)));
+  assert!(!request.is_forward_compatible(&ContextVersion::gles(3, 0)));
+
+  // each failure is kept in order, up to the one that worked.
+  let mut tried = Vec::new();
+  let created = request
+    .try_each(|version| {
+      tried.push(*version);
+      match (version.major, version.minor) {
+        (4, 6) => Err(AttemptError::MissingExtension("GLX_ARB_whatever")),
+        (4, 1) => Err(AttemptError::Failed("BadMatch")),
+        _ => Ok(request.share.unwrap() + 1),
+      }
+    })
+    .unwrap();
+  assert_eq!(tried, &request.versions[..3]);
+  assert_eq!(created.context, 8);
+  assert_eq!(created.version, ContextVersion::gl_core(3, 3));
+  let failed: Vec<String> =
+    created.failed.iter().map(|a| a.to_string()).collect();
+  assert_eq!(
+    failed,
+    ["GL 4.6 core: needs GLX_ARB_whatever", "GL 4.1 core: BadMatch"]
+  );
+
+  let error = request
+    .versions(&[ContextVersion::gles(2, 0), ContextVersion::gl(2, 1)])
+    .try_each(|_| Err::<(), _>(AttemptError::Failed("nope")))
+    .unwrap_err();
+  assert_eq!(
+    error.to_string(),
+    "no context could be made (GLES 2.0: nope; GL 2.1: nope)"
+  );
+  let error = ContextRequest::<()>::default()
+    .versions(&[])
+    .try_each(|_| Ok::<(), AttemptError<&str>>(()))
+    .unwrap_err();
+  assert_eq!(error.to_string(), "no context versions to try");
+
+  // a request that can't work isn't tried at all.
+  let request = ContextRequest::<()>::default()
+    .versions(&[ContextVersion::gl_core(4, 6), ContextVersion::gl(3, 0)])
+    .no_error(true);
+  assert_eq!(request.conflict(), None);
+  let robust =
+    request.clone().robustness(Some(ResetNotification::NoResetNotification));
+  assert_eq!(
+    robust.conflict(),
+    Some("no_error can't be combined with robustness")
+  );
+  let error = request
+    .debug(true)
+    .try_each(|_| -> Result<(), AttemptError<&str>> { unreachable!() })
+    .unwrap_err();
+  assert_eq!(
+    error.to_string(),
+    "no context could be made (\
+     GL 4.6 core: no_error can't be combined with debug; \
+     GL 3.0: no_error can't be combined with debug)"
+  );
+}
+
+#[test]
+fn test_extension_names() {
+  let names = ExtensionNames {
+    create_context: "",
+    profile: "X_profile",
+    es_profile: "X_es_profile",
+    robustness: "X_robustness",
+    no_error: "",
+  };
+  let extensions: Vec<String> = vec!["X_profile".into(), "X_robustness".into()];
+  let request: ContextRequest<()> = ContextRequest::default();
+  let check = |request: &ContextRequest<()>, version, extensions: &[String]| {
+    names.missing(request, &version, extensions)
+  };
+  assert_eq!(check(&request, ContextVersion::gl(3, 0), &[]), None);
+  assert_eq!(
+    check(&request, ContextVersion::gl_core(3, 3), &[]),
+    Some("X_profile")
+  );
+  assert_eq!(check(&request, ContextVersion::gl_core(3, 3), &extensions), None);
+  assert_eq!(
+    check(&request, ContextVersion::gles(3, 0), &extensions),
+    Some("X_es_profile")
+  );
+  let robust =
+    request.clone().robustness(Some(ResetNotification::LoseContextOnReset));
+  assert_eq!(
+    check(&robust, ContextVersion::gl(3, 0), &extensions[..1]),
+    Some("X_robustness")
+  );
+  // an empty name is always there.
+  let no_error = request.clone().no_error(true);
+  assert_eq!(check(&no_error, ContextVersion::gl(3, 0), &[]), None);
+  // and `create_context` is needed by every version.
+  let names = ExtensionNames { create_context: "X_create_context", ..names };
+  assert_eq!(
+    names.missing(&request, &ContextVersion::gl(3, 0), &extensions),
+    Some("X_create_context")
+  );
+}
@@ -0,0 +1,228 @@
+#![cfg(any(windows, unix))]
+
+//! Module for loading dynamic libraries at runtime.
+//!
+//! On Windows this uses `LoadLibraryW`, `GetProcAddress`, and `FreeLibrary`.
+//! On Unix it uses `dlopen`, `dlsym`, and `dlclose`.
+//!
+//! ```no_run
+//! use triangle_from_scratch::{c_str, dylib::Library, gl::glClear_t};
+//! let lib = Library::open("libGL.so.1").unwrap();
+//! let gl_clear: glClear_t = unsafe { lib.get(c_str!("glClear")) };
+//! ```
+
+pub use core::ffi::c_void;
+
+#[cfg(windows)]
+use crate::win32::{FreeLibrary, GetProcAddress, Win32Error, HMODULE};
+
+#[cfg(unix)]
+pub use std::os::raw::{c_char, c_int};
+
+#[cfg(unix)]
+pub const RTLD_LAZY: c_int = 0x0001;
+#[cfg(unix)]
+pub const RTLD_NOW: c_int = 0x0002;
+#[cfg(all(unix, not(target_os = "macos")))]
+pub const RTLD_GLOBAL: c_int = 0x0100;
+#[cfg(target_os = "macos")]
+pub const RTLD_GLOBAL: c_int = 0x0008;
+#[cfg(unix)]
+pub const RTLD_LOCAL: c_int = 0x0000;
+
+#[cfg(unix)]
+#[cfg_attr(target_os = "linux", link(name = "dl"))]
+extern "C" {
+  /// [`dlopen`](https://man7.org/linux/man-pages/man3/dlopen.3.html)
+  pub fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+
+  /// [`dlsym`](https://man7.org/linux/man-pages/man3/dlsym.3.html)
+  pub fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+
+  /// [`dlclose`](https://man7.org/linux/man-pages/man3/dlclose.3.html)
+  pub fn dlclose(handle: *mut c_void) -> c_int;
+
+  /// [`dlerror`](https://man7.org/linux/man-pages/man3/dlerror.3.html)
+  pub fn dlerror() -> *mut c_char;
+}
+
+/// The error from trying to open a library.
+///
+/// On Windows this is the [`Win32Error`] code.
+#[cfg(windows)]
+pub type LibraryError = Win32Error;
+
+/// The error from trying to open a library.
+///
+/// On Unix this is the message from [`dlerror`].
+#[cfg(unix)]
+pub type LibraryError = DlError;
+
+/// The message from [`dlerror`], which is all the info that `dl` gives.
+#[cfg(unix)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct DlError(pub String);
+#[cfg(unix)]
+impl DlError {
+  /// Takes the current `dlerror` message of this thread.
+  ///
+  /// Reading the message also clears it.
+  pub fn take() -> Self {
+    let p: *const u8 = unsafe { dlerror() }.cast();
+    if p.is_null() {
+      Self(String::from("unknown dl error"))
+    } else {
+      let bytes = unsafe { crate::gather_null_terminated_bytes(p) };
+      Self(crate::min_alloc_lossy_into_string(bytes))
+    }
+  }
+}
+#[cfg(unix)]
+impl std::error::Error for DlError {}
+#[cfg(unix)]
+impl core::fmt::Debug for DlError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if f.alternate() {
+      write!(f, "DlError({:?})", self.0)
+    } else {
+      write!(f, "{}", self.0)
+    }
+  }
+}
+#[cfg(unix)]
+impl core::fmt::Display for DlError {
+  /// Same as `Debug` impl
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+
+/// A dynamic library that's loaded into the process.
+///
+/// The library is freed when this value drops, so any function pointers you
+/// got out of it must not be used after that.
+pub struct Library {
+  #[cfg(windows)]
+  handle: HMODULE,
+  #[cfg(unix)]
+  handle: *mut c_void,
+}
+impl Library {
+  /// Opens a library by name.
+  ///
+  /// * Windows: Searches the usual DLL paths. See
+  ///   [`LoadLibraryW`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryw)
+  /// * Unix: All symbols are resolved right away (`RTLD_NOW`), and they're not
+  ///   made available to later loaded libraries (`RTLD_LOCAL`). See
+  ///   [`dlopen`](https://man7.org/linux/man-pages/man3/dlopen.3.html)
+  #[cfg(windows)]
+  pub fn open(name: &str) -> Result<Self, LibraryError> {
+    crate::win32::load_library(name).map(|handle| Self { handle })
+  }
+
+  /// Opens a library by name.
+  ///
+  /// * Windows: Searches the usual DLL paths. See
+  ///   [`LoadLibraryW`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryw)
+  /// * Unix: All symbols are resolved right away (`RTLD_NOW`), and they're not
+  ///   made available to later loaded libraries (`RTLD_LOCAL`). See
+  ///   [`dlopen`](https://man7.org/linux/man-pages/man3/dlopen.3.html)
+  #[cfg(unix)]
+  pub fn open(name: &str) -> Result<Self, LibraryError> {
+    let name_null: Vec<u8> = name.bytes().chain(Some(0)).collect();
+    // Safety: the input pointer is to a null-terminated string
+    let handle =
+      unsafe { dlopen(name_null.as_ptr().cast(), RTLD_NOW | RTLD_LOCAL) };
+    if handle.is_null() {
+      Err(DlError::take())
+    } else {
+      Ok(Self { handle })
+    }
+  }
+
+  /// Gets the raw address of a symbol in the library.
+  ///
+  /// The input should be a null-terminated symbol name string. Use the
+  /// [`c_str!`](crate::c_str) macro for assistance.
+  ///
+  /// **Returns:** A null pointer if the name isn't null-terminated or the
+  /// symbol isn't found.
+  pub fn get_raw(&self, name: &[u8]) -> *mut c_void {
+    // check that we end the slice with a \0 as expected.
+    match name.last() {
+      Some(b'\0') => (),
+      _ => return core::ptr::null_mut(),
+    }
+    // Safety: we've checked that the end of the slice is null-terminated.
+    #[cfg(windows)]
+    let p = unsafe { GetProcAddress(self.handle, name.as_ptr().cast()) };
+    #[cfg(unix)]
+    let p = unsafe { dlsym(self.handle, name.as_ptr().cast()) };
+    p
+  }
+
+  /// Gets a function pointer out of the library.
+  ///
+  /// `F` should be the bare `unsafe extern fn` type, which makes the output
+  /// the same as one of the `_t` types (such as
+  /// [`glClear_t`](crate::gl::glClear_t)), so usually you can let inference
+  /// pick `F` for you.
+  ///
+  /// **Returns:** `None` if the name isn't null-terminated or the symbol isn't
+  /// found.
+  ///
+  /// ## Safety
+  /// * `F` must be a function pointer type, with the signature that the
+  ///   symbol actually has.
+  /// * The function pointer must not be called after the library drops.
+  ///
+  /// ## Panics
+  /// * If `F` isn't the size of a pointer.
+  pub unsafe fn get<F: Copy>(&self, name: &[u8]) -> Option<F> {
+    assert_eq!(
+      core::mem::size_of::<F>(),
+      core::mem::size_of::<*mut c_void>(),
+      "`F` must be a function pointer type"
+    );
+    let p = self.get_raw(name);
+    if p.is_null() {
+      None
+    } else {
+      Some(core::mem::transmute_copy(&p))
+    }
+  }
+}
+impl Drop for Library {
+  /// Frees the library.
+  fn drop(&mut self) {
+    #[cfg(windows)]
+    let _ = unsafe { FreeLibrary(self.handle) };
+    #[cfg(unix)]
+    let _ = unsafe { dlclose(self.handle) };
+  }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_library_get() {
+  let libc = Library::open("libc.so.6").unwrap();
+  let strlen: Option<unsafe extern "C" fn(*const c_char) -> usize> =
+    unsafe { libc.get(crate::c_str!("strlen")) };
+  assert_eq!(
+    unsafe { (strlen.unwrap())(crate::c_str!("abc").as_ptr().cast()) },
+    3
+  );
+  let missing: Option<unsafe extern "C" fn()> =
+    unsafe { libc.get(crate::c_str!("not_a_real_symbol_name")) };
+  assert!(missing.is_none());
+  let not_null_terminated: Option<unsafe extern "C" fn()> =
+    unsafe { libc.get(b"strlen") };
+  assert!(not_null_terminated.is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_library_open_error() {
+  let e = Library::open("libnot_a_real_library.so").err().unwrap();
+  assert!(e.0.contains("libnot_a_real_library.so"), "{}", e);
+}
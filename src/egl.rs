@@ -0,0 +1,1197 @@
+#![cfg(target_os = "linux")]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+//! Module for EGL, which can make GL contexts without any window system.
+//!
+//! With the `EGL_MESA_platform_surfaceless` or `EGL_EXT_platform_device`
+//! client extensions you can render into a pbuffer surface on a machine that
+//! has no X server at all, which is what you want for CI and servers.
+
+pub use core::ffi::c_void;
+pub use std::os::raw::{c_char, c_int, c_uint};
+
+use core::ptr::{null, null_mut};
+
+use crate::{
+  c_str,
+  context::*,
+  format::FormatDesc,
+  gather_null_terminated_bytes,
+  guard::{OwnedEglContext, OwnedEglDisplay, OwnedEglSurface},
+  min_alloc_lossy_into_string,
+};
+
+pub type EGLint = i32;
+pub type EGLBoolean = c_uint;
+pub type EGLenum = c_uint;
+pub type EGLAttrib = isize;
+pub type EGLDisplay = *mut c_void;
+pub type EGLConfig = *mut c_void;
+pub type EGLContext = *mut c_void;
+pub type EGLSurface = *mut c_void;
+pub type EGLDeviceEXT = *mut c_void;
+pub type EGLNativeDisplayType = *mut c_void;
+
+/// Pointer to a procedure of unknown type.
+pub type __eglMustCastToProperFunctionPointerType =
+  Option<unsafe extern "C" fn()>;
+
+/// Type for [eglGetPlatformDisplayEXT](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_platform_base.txt)
+pub type eglGetPlatformDisplayEXT_t = Option<
+  unsafe extern "C" fn(
+    platform: EGLenum,
+    native_display: *mut c_void,
+    attrib_list: *const EGLint,
+  ) -> EGLDisplay,
+>;
+
+/// Type for [eglQueryDevicesEXT](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_device_enumeration.txt)
+pub type eglQueryDevicesEXT_t = Option<
+  unsafe extern "C" fn(
+    max_devices: EGLint,
+    devices: *mut EGLDeviceEXT,
+    num_devices: *mut EGLint,
+  ) -> EGLBoolean,
+>;
+
+pub const EGL_FALSE: EGLBoolean = 0;
+pub const EGL_TRUE: EGLBoolean = 1;
+pub const EGL_DEFAULT_DISPLAY: EGLNativeDisplayType = null_mut();
+pub const EGL_NO_DISPLAY: EGLDisplay = null_mut();
+pub const EGL_NO_CONTEXT: EGLContext = null_mut();
+pub const EGL_NO_SURFACE: EGLSurface = null_mut();
+pub const EGL_DONT_CARE: EGLint = -1;
+
+/// Terminates all EGL attribute lists.
+pub const EGL_NONE: EGLint = 0x3038;
+
+pub const EGL_SUCCESS: EGLint = 0x3000;
+pub const EGL_NOT_INITIALIZED: EGLint = 0x3001;
+pub const EGL_BAD_ACCESS: EGLint = 0x3002;
+pub const EGL_BAD_ALLOC: EGLint = 0x3003;
+pub const EGL_BAD_ATTRIBUTE: EGLint = 0x3004;
+pub const EGL_BAD_CONFIG: EGLint = 0x3005;
+pub const EGL_BAD_CONTEXT: EGLint = 0x3006;
+pub const EGL_BAD_CURRENT_SURFACE: EGLint = 0x3007;
+pub const EGL_BAD_DISPLAY: EGLint = 0x3008;
+pub const EGL_BAD_MATCH: EGLint = 0x3009;
+pub const EGL_BAD_NATIVE_PIXMAP: EGLint = 0x300A;
+pub const EGL_BAD_NATIVE_WINDOW: EGLint = 0x300B;
+pub const EGL_BAD_PARAMETER: EGLint = 0x300C;
+pub const EGL_BAD_SURFACE: EGLint = 0x300D;
+pub const EGL_CONTEXT_LOST: EGLint = 0x300E;
+/// Defined in [EGL_EXT_device_base](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_device_base.txt)
+pub const EGL_BAD_DEVICE_EXT: EGLint = 0x322B;
+
+/// [`egl_query_string`] name
+pub const EGL_VENDOR: EGLint = 0x3053;
+/// [`egl_query_string`] name
+pub const EGL_VERSION: EGLint = 0x3054;
+/// [`egl_query_string`] name
+pub const EGL_EXTENSIONS: EGLint = 0x3055;
+/// [`egl_query_string`] name
+pub const EGL_CLIENT_APIS: EGLint = 0x308D;
+
+pub const EGL_BUFFER_SIZE: EGLint = 0x3020;
+pub const EGL_ALPHA_SIZE: EGLint = 0x3021;
+pub const EGL_BLUE_SIZE: EGLint = 0x3022;
+pub const EGL_GREEN_SIZE: EGLint = 0x3023;
+pub const EGL_RED_SIZE: EGLint = 0x3024;
+pub const EGL_DEPTH_SIZE: EGLint = 0x3025;
+pub const EGL_STENCIL_SIZE: EGLint = 0x3026;
+pub const EGL_CONFIG_CAVEAT: EGLint = 0x3027;
+pub const EGL_CONFIG_ID: EGLint = 0x3028;
+pub const EGL_SAMPLES: EGLint = 0x3031;
+pub const EGL_SAMPLE_BUFFERS: EGLint = 0x3032;
+pub const EGL_SURFACE_TYPE: EGLint = 0x3033;
+pub const EGL_MIN_SWAP_INTERVAL: EGLint = 0x303B;
+pub const EGL_MAX_SWAP_INTERVAL: EGLint = 0x303C;
+pub const EGL_COLOR_BUFFER_TYPE: EGLint = 0x303F;
+pub const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+pub const EGL_CONFORMANT: EGLint = 0x3042;
+pub const EGL_SLOW_CONFIG: EGLint = 0x3050;
+pub const EGL_HEIGHT: EGLint = 0x3056;
+pub const EGL_WIDTH: EGLint = 0x3057;
+pub const EGL_RGB_BUFFER: EGLint = 0x308E;
+
+/// [`EGL_SURFACE_TYPE`] bit
+pub const EGL_PBUFFER_BIT: EGLint = 0x0001;
+/// [`EGL_SURFACE_TYPE`] bit
+pub const EGL_WINDOW_BIT: EGLint = 0x0004;
+/// [`EGL_RENDERABLE_TYPE`] bit
+pub const EGL_OPENGL_ES2_BIT: EGLint = 0x0004;
+/// [`EGL_RENDERABLE_TYPE`] bit
+pub const EGL_OPENGL_BIT: EGLint = 0x0008;
+/// [`EGL_RENDERABLE_TYPE`] bit
+pub const EGL_OPENGL_ES3_BIT: EGLint = 0x0040;
+
+/// [`egl_bind_api`] value
+pub const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
+/// [`egl_bind_api`] value
+pub const EGL_OPENGL_API: EGLenum = 0x30A2;
+
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x3098;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x30FD;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x00000001;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT: EGLint = 0x00000002;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_OPENGL_DEBUG: EGLint = 0x31B0;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE: EGLint = 0x31B1;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_OPENGL_ROBUST_ACCESS: EGLint = 0x31B2;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY: EGLint = 0x31BD;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_NO_RESET_NOTIFICATION: EGLint = 0x31BE;
+/// Defined in [EGL 1.5](https://www.khronos.org/registry/EGL/specs/eglspec.1.5.pdf)
+pub const EGL_LOSE_CONTEXT_ON_RESET: EGLint = 0x31BF;
+/// Defined in [EGL_KHR_create_context](https://www.khronos.org/registry/EGL/extensions/KHR/EGL_KHR_create_context.txt)
+pub const EGL_CONTEXT_FLAGS_KHR: EGLint = 0x30FC;
+/// [`EGL_CONTEXT_FLAGS_KHR`] bit
+pub const EGL_CONTEXT_OPENGL_DEBUG_BIT_KHR: EGLint = 0x00000001;
+/// [`EGL_CONTEXT_FLAGS_KHR`] bit
+pub const EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE_BIT_KHR: EGLint = 0x00000002;
+/// Defined in [EGL_EXT_create_context_robustness](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_create_context_robustness.txt)
+pub const EGL_CONTEXT_OPENGL_ROBUST_ACCESS_EXT: EGLint = 0x30BF;
+/// Defined in [EGL_EXT_create_context_robustness](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_create_context_robustness.txt)
+pub const EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY_EXT: EGLint = 0x3138;
+/// Defined in [EGL_KHR_create_context_no_error](https://www.khronos.org/registry/EGL/extensions/KHR/EGL_KHR_create_context_no_error.txt)
+pub const EGL_CONTEXT_OPENGL_NO_ERROR_KHR: EGLint = 0x31B3;
+
+/// Defined in [EGL_EXT_platform_device](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_platform_device.txt)
+pub const EGL_PLATFORM_DEVICE_EXT: EGLenum = 0x313F;
+/// Defined in [EGL_MESA_platform_surfaceless](https://www.khronos.org/registry/EGL/extensions/MESA/EGL_MESA_platform_surfaceless.txt)
+pub const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+
+#[link(name = "EGL")]
+extern "C" {
+  /// [`eglGetError`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetError.xhtml)
+  pub fn eglGetError() -> EGLint;
+
+  /// [`eglGetDisplay`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetDisplay.xhtml)
+  pub fn eglGetDisplay(display_id: EGLNativeDisplayType) -> EGLDisplay;
+
+  /// [`eglInitialize`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglInitialize.xhtml)
+  pub fn eglInitialize(
+    dpy: EGLDisplay, major: *mut EGLint, minor: *mut EGLint,
+  ) -> EGLBoolean;
+
+  /// [`eglTerminate`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglTerminate.xhtml)
+  pub fn eglTerminate(dpy: EGLDisplay) -> EGLBoolean;
+
+  /// [`eglQueryString`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglQueryString.xhtml)
+  pub fn eglQueryString(dpy: EGLDisplay, name: EGLint) -> *const c_char;
+
+  /// [`eglChooseConfig`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglChooseConfig.xhtml)
+  pub fn eglChooseConfig(
+    dpy: EGLDisplay, attrib_list: *const EGLint, configs: *mut EGLConfig,
+    config_size: EGLint, num_config: *mut EGLint,
+  ) -> EGLBoolean;
+
+  /// [`eglGetConfigAttrib`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetConfigAttrib.xhtml)
+  pub fn eglGetConfigAttrib(
+    dpy: EGLDisplay, config: EGLConfig, attribute: EGLint, value: *mut EGLint,
+  ) -> EGLBoolean;
+
+  /// [`eglBindAPI`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglBindAPI.xhtml)
+  pub fn eglBindAPI(api: EGLenum) -> EGLBoolean;
+
+  /// [`eglCreateContext`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglCreateContext.xhtml)
+  pub fn eglCreateContext(
+    dpy: EGLDisplay, config: EGLConfig, share_context: EGLContext,
+    attrib_list: *const EGLint,
+  ) -> EGLContext;
+
+  /// [`eglDestroyContext`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglDestroyContext.xhtml)
+  pub fn eglDestroyContext(dpy: EGLDisplay, ctx: EGLContext) -> EGLBoolean;
+
+  /// [`eglCreatePbufferSurface`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglCreatePbufferSurface.xhtml)
+  pub fn eglCreatePbufferSurface(
+    dpy: EGLDisplay, config: EGLConfig, attrib_list: *const EGLint,
+  ) -> EGLSurface;
+
+  /// [`eglDestroySurface`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglDestroySurface.xhtml)
+  pub fn eglDestroySurface(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+
+  /// [`eglMakeCurrent`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglMakeCurrent.xhtml)
+  pub fn eglMakeCurrent(
+    dpy: EGLDisplay, draw: EGLSurface, read: EGLSurface, ctx: EGLContext,
+  ) -> EGLBoolean;
+
+  /// [`eglSwapBuffers`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglSwapBuffers.xhtml)
+  pub fn eglSwapBuffers(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+
+  /// [`eglGetProcAddress`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetProcAddress.xhtml)
+  pub fn eglGetProcAddress(
+    procname: *const c_char,
+  ) -> __eglMustCastToProperFunctionPointerType;
+
+  /// [`eglReleaseThread`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglReleaseThread.xhtml)
+  pub fn eglReleaseThread() -> EGLBoolean;
+}
+
+/// Gets the thread-local last-error code value.
+///
+/// Reading the error also resets it to [`EGL_SUCCESS`].
+///
+/// See [`eglGetError`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetError.xhtml)
+pub fn get_last_error() -> EglError {
+  EglError(unsafe { eglGetError() })
+}
+
+/// Newtype wrapper for an EGL error code.
+///
+/// If bit 29 is set, it's an application error.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct EglError(pub EGLint);
+impl EglError {
+  pub const APPLICATION_ERROR_BIT: EGLint = 1 << 29;
+
+  /// The name of the error code (eg: `"EGL_BAD_CONFIG"`).
+  #[must_use]
+  pub const fn name(&self) -> Option<&'static str> {
+    Some(match self.0 {
+      EGL_SUCCESS => "EGL_SUCCESS",
+      EGL_NOT_INITIALIZED => "EGL_NOT_INITIALIZED",
+      EGL_BAD_ACCESS => "EGL_BAD_ACCESS",
+      EGL_BAD_ALLOC => "EGL_BAD_ALLOC",
+      EGL_BAD_ATTRIBUTE => "EGL_BAD_ATTRIBUTE",
+      EGL_BAD_CONFIG => "EGL_BAD_CONFIG",
+      EGL_BAD_CONTEXT => "EGL_BAD_CONTEXT",
+      EGL_BAD_CURRENT_SURFACE => "EGL_BAD_CURRENT_SURFACE",
+      EGL_BAD_DISPLAY => "EGL_BAD_DISPLAY",
+      EGL_BAD_MATCH => "EGL_BAD_MATCH",
+      EGL_BAD_NATIVE_PIXMAP => "EGL_BAD_NATIVE_PIXMAP",
+      EGL_BAD_NATIVE_WINDOW => "EGL_BAD_NATIVE_WINDOW",
+      EGL_BAD_PARAMETER => "EGL_BAD_PARAMETER",
+      EGL_BAD_SURFACE => "EGL_BAD_SURFACE",
+      EGL_CONTEXT_LOST => "EGL_CONTEXT_LOST",
+      EGL_BAD_DEVICE_EXT => "EGL_BAD_DEVICE_EXT",
+      _ => return None,
+    })
+  }
+}
+impl std::error::Error for EglError {}
+
+impl core::fmt::Debug for EglError {
+  /// Displays the error using the names from the EGL spec.
+  ///
+  /// ```
+  /// use triangle_from_scratch::egl::*;
+  /// let s = format!("{:?}", EglError(EGL_BAD_MATCH));
+  /// assert_eq!("EGL_BAD_MATCH", s);
+  /// let app_error = format!("{:?}", EglError(1 << 29));
+  /// assert_eq!("EGL Application Error (536870912)", app_error);
+  /// ```
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if f.alternate() {
+      return write!(f, "EglError({:#X})", self.0);
+    }
+
+    if self.0 & Self::APPLICATION_ERROR_BIT > 0 {
+      return write!(f, "EGL Application Error ({})", self.0);
+    }
+    match self.name() {
+      Some(name) => write!(f, "{}", name),
+      None => write!(f, "EGL Unknown Error ({:#X})", self.0),
+    }
+  }
+}
+impl core::fmt::Display for EglError {
+  /// Same as `Debug` impl
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+
+#[test]
+fn test_egl_error_names() {
+  assert_eq!(EglError(EGL_SUCCESS).name(), Some("EGL_SUCCESS"));
+  assert_eq!(EglError(EGL_CONTEXT_LOST).name(), Some("EGL_CONTEXT_LOST"));
+  assert_eq!(EglError(EGL_BAD_DEVICE_EXT).name(), Some("EGL_BAD_DEVICE_EXT"));
+  assert_eq!(EglError(0x300F).name(), None);
+  assert_eq!(format!("{}", EglError(0x300F)), "EGL Unknown Error (0x300F)");
+  assert_eq!(format!("{:#?}", EglError(EGL_BAD_ALLOC)), "EglError(0x3003)");
+}
+
+/// Checks an attribute list and gives the pointer to pass to EGL.
+///
+/// * The input slice consists of [key, value] pairs.
+/// * The input slice **can** be empty.
+/// * Any non-empty input must have [`EGL_NONE`] as the key value of the last
+///   position.
+fn attrib_list_ptr(
+  attrib_list: &[[EGLint; 2]],
+) -> Result<*const EGLint, EglError> {
+  match attrib_list.last() {
+    Some([k, _v]) => {
+      if *k == EGL_NONE {
+        Ok(attrib_list.as_ptr().cast())
+      } else {
+        Err(EglError(EglError::APPLICATION_ERROR_BIT))
+      }
+    }
+    None => Ok(null()),
+  }
+}
+
+/// Gets a GL or EGL function address.
+///
+/// The input should be a null-terminated function name string. Use the
+/// [`c_str!`] macro for assistance.
+///
+/// * This doesn't need a current context.
+/// * With EGL 1.5 (or `EGL_KHR_get_all_proc_addresses`) this works for core
+///   GL functions too, so it's all you need to load GL.
+///
+/// See [`eglGetProcAddress`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetProcAddress.xhtml)
+pub fn egl_get_proc_address(func_name: &[u8]) -> Result<*mut c_void, EglError> {
+  // check that we end the slice with a \0 as expected.
+  match func_name.last() {
+    Some(b'\0') => (),
+    _ => return Err(EglError(EglError::APPLICATION_ERROR_BIT)),
+  }
+  // Safety: we've checked that the end of the slice is null-terminated.
+  match unsafe { eglGetProcAddress(func_name.as_ptr().cast()) } {
+    Some(f) => Ok(f as *mut c_void),
+    None => Err(EglError(EglError::APPLICATION_ERROR_BIT)),
+  }
+}
+
+/// Gets a string describing the EGL implementation.
+///
+/// * Use [`EGL_NO_DISPLAY`] with [`EGL_EXTENSIONS`] to get the client
+///   extensions, which tell you what platforms are available.
+///
+/// ## Safety
+///
+/// `display` must be [`EGL_NO_DISPLAY`] or a display from EGL.
+///
+/// See [`eglQueryString`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglQueryString.xhtml)
+pub unsafe fn egl_query_string(
+  display: EGLDisplay, name: EGLint,
+) -> Result<String, EglError> {
+  let p: *const u8 = eglQueryString(display, name).cast();
+  if p.is_null() {
+    Err(get_last_error())
+  } else {
+    let bytes = gather_null_terminated_bytes(p);
+    Ok(min_alloc_lossy_into_string(bytes))
+  }
+}
+
+/// Gets the list of extensions for the display given.
+///
+/// Use [`EGL_NO_DISPLAY`] to get the client extensions. If there's an error
+/// the list is empty.
+///
+/// ## Safety
+///
+/// `display` must be [`EGL_NO_DISPLAY`] or a display from EGL.
+pub unsafe fn egl_get_extensions(display: EGLDisplay) -> Vec<String> {
+  egl_query_string(display, EGL_EXTENSIONS)
+    .map(|s| {
+      s.split(' ').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Gets the display for the native display given.
+///
+/// Usually you'd pass [`EGL_DEFAULT_DISPLAY`], which picks a platform based on
+/// the environment (eg: X11 if `DISPLAY` is set).
+///
+/// ## Safety
+///
+/// `native_display` must be [`EGL_DEFAULT_DISPLAY`] or an open connection of
+/// the native window system.
+///
+/// See [`eglGetDisplay`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetDisplay.xhtml)
+pub unsafe fn egl_get_display(
+  native_display: EGLNativeDisplayType,
+) -> Result<EGLDisplay, EglError> {
+  let display = eglGetDisplay(native_display);
+  if display.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(display)
+  }
+}
+
+/// Gets the display for a specific platform.
+///
+/// * Needs the `EGL_EXT_platform_base` client extension.
+/// * The attribute list follows the rules of [`egl_choose_config`].
+///
+/// ## Safety
+///
+/// `native_display` must be null or an open connection of the platform's
+/// window system.
+///
+/// See [EGL_EXT_platform_base](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_platform_base.txt)
+pub unsafe fn egl_get_platform_display(
+  platform: EGLenum, native_display: *mut c_void, attrib_list: &[[EGLint; 2]],
+) -> Result<EGLDisplay, EglError> {
+  let app_err = EglError(EglError::APPLICATION_ERROR_BIT);
+  let i_ptr = attrib_list_ptr(attrib_list)?;
+  let f: eglGetPlatformDisplayEXT_t = core::mem::transmute(
+    egl_get_proc_address(c_str!("eglGetPlatformDisplayEXT"))?,
+  );
+  let display = (f.ok_or(app_err)?)(platform, native_display, i_ptr);
+  if display.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(display)
+  }
+}
+
+/// Gets the surfaceless display, which has no window system at all.
+///
+/// Needs the `EGL_MESA_platform_surfaceless` client extension.
+///
+/// ## Safety
+///
+/// The EGL implementation must follow its client extensions.
+pub unsafe fn egl_get_surfaceless_display() -> Result<EGLDisplay, EglError> {
+  if !egl_get_extensions(EGL_NO_DISPLAY)
+    .iter()
+    .any(|s| s == "EGL_MESA_platform_surfaceless")
+  {
+    return Err(EglError(EglError::APPLICATION_ERROR_BIT));
+  }
+  egl_get_platform_display(EGL_PLATFORM_SURFACELESS_MESA, null_mut(), &[])
+}
+
+/// Gets all the devices that EGL knows about.
+///
+/// Needs the `EGL_EXT_device_enumeration` client extension.
+///
+/// ## Safety
+///
+/// The EGL implementation must follow its client extensions.
+///
+/// See [EGL_EXT_device_enumeration](https://www.khronos.org/registry/EGL/extensions/EXT/EGL_EXT_device_enumeration.txt)
+pub unsafe fn egl_query_devices() -> Result<Vec<EGLDeviceEXT>, EglError> {
+  let app_err = EglError(EglError::APPLICATION_ERROR_BIT);
+  let f: eglQueryDevicesEXT_t =
+    core::mem::transmute(egl_get_proc_address(c_str!("eglQueryDevicesEXT"))?);
+  let f = f.ok_or(app_err)?;
+  let mut count = 0;
+  if f(0, null_mut(), &mut count) == EGL_FALSE {
+    return Err(get_last_error());
+  }
+  let mut devices = vec![null_mut(); count as usize];
+  if f(count, devices.as_mut_ptr(), &mut count) == EGL_FALSE {
+    return Err(get_last_error());
+  }
+  devices.truncate(count as usize);
+  Ok(devices)
+}
+
+/// Gets the display of a device from [`egl_query_devices`].
+///
+/// Needs the `EGL_EXT_platform_device` client extension.
+///
+/// ## Safety
+///
+/// `device` must be from [`egl_query_devices`].
+pub unsafe fn egl_get_device_display(
+  device: EGLDeviceEXT,
+) -> Result<EGLDisplay, EglError> {
+  if !egl_get_extensions(EGL_NO_DISPLAY)
+    .iter()
+    .any(|s| s == "EGL_EXT_platform_device")
+  {
+    return Err(EglError(EglError::APPLICATION_ERROR_BIT));
+  }
+  egl_get_platform_display(EGL_PLATFORM_DEVICE_EXT, device, &[])
+}
+
+/// Initializes a display.
+///
+/// **Returns:** The `(major, minor)` EGL version of the display.
+///
+/// ## Safety
+///
+/// `display` must be a display from EGL.
+///
+/// See [`eglInitialize`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglInitialize.xhtml)
+pub unsafe fn egl_initialize(
+  display: EGLDisplay,
+) -> Result<(EGLint, EGLint), EglError> {
+  let mut major = 0;
+  let mut minor = 0;
+  if eglInitialize(display, &mut major, &mut minor) != EGL_FALSE {
+    Ok((major, minor))
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Releases the resources of a display.
+///
+/// Contexts and surfaces that are still current aren't destroyed until they
+/// stop being current.
+///
+/// ## Safety
+///
+/// `display` must be a display from EGL. Handles from the display aren't
+/// usable afterwards.
+///
+/// See [`eglTerminate`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglTerminate.xhtml)
+pub unsafe fn egl_terminate(display: EGLDisplay) -> Result<(), EglError> {
+  if eglTerminate(display) != EGL_FALSE {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Gets all configs that match the attributes given.
+///
+/// * The input slice consists of [key, value] pairs.
+/// * The input slice **can** be empty.
+/// * Any non-empty input must have [`EGL_NONE`] as the key value of the last
+///   position. Unlike with WGL and GLX, zero is a valid key in EGL.
+/// * The output is sorted by the EGL rules, so usually you want the first one.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display.
+///
+/// See [`eglChooseConfig`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglChooseConfig.xhtml)
+pub unsafe fn egl_choose_config(
+  display: EGLDisplay, attrib_list: &[[EGLint; 2]],
+) -> Result<Vec<EGLConfig>, EglError> {
+  let i_ptr = attrib_list_ptr(attrib_list)?;
+  let mut count = 0;
+  if eglChooseConfig(display, i_ptr, null_mut(), 0, &mut count) == EGL_FALSE {
+    return Err(get_last_error());
+  }
+  let mut configs = vec![null_mut(); count as usize];
+  if eglChooseConfig(display, i_ptr, configs.as_mut_ptr(), count, &mut count)
+    == EGL_FALSE
+  {
+    return Err(get_last_error());
+  }
+  configs.truncate(count as usize);
+  Ok(configs)
+}
+
+/// Gets one attribute value of a config.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `config` one of its configs.
+///
+/// See [`eglGetConfigAttrib`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglGetConfigAttrib.xhtml)
+pub unsafe fn egl_get_config_attrib(
+  display: EGLDisplay, config: EGLConfig, attribute: EGLint,
+) -> Result<EGLint, EglError> {
+  let mut value = 0;
+  if eglGetConfigAttrib(display, config, attribute, &mut value) != EGL_FALSE {
+    Ok(value)
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Describes a config, to rank it with a
+/// [`FormatRequest`](crate::format::FormatRequest).
+///
+/// EGL picks the colorspace of each surface when it's made (with
+/// `EGL_GL_COLORSPACE`) rather than in the config, so `srgb` is always false.
+/// Window surfaces are always double buffered and pbuffer surfaces never are,
+/// so `double_buffer` is if the config can make window surfaces.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `config` one of its configs.
+pub unsafe fn egl_describe_config(
+  display: EGLDisplay, config: EGLConfig,
+) -> Result<FormatDesc, EglError> {
+  let get = |attribute| egl_get_config_attrib(display, config, attribute);
+  let bits = |attribute| get(attribute).map(|v| v.clamp(0, 255) as u8);
+  Ok(FormatDesc {
+    id: get(EGL_CONFIG_ID)?,
+    red_bits: bits(EGL_RED_SIZE)?,
+    green_bits: bits(EGL_GREEN_SIZE)?,
+    blue_bits: bits(EGL_BLUE_SIZE)?,
+    alpha_bits: bits(EGL_ALPHA_SIZE)?,
+    depth_bits: bits(EGL_DEPTH_SIZE)?,
+    stencil_bits: bits(EGL_STENCIL_SIZE)?,
+    samples: bits(EGL_SAMPLES)?,
+    srgb: false,
+    double_buffer: get(EGL_SURFACE_TYPE)? & EGL_WINDOW_BIT != 0,
+    accelerated: get(EGL_CONFIG_CAVEAT)? != EGL_SLOW_CONFIG,
+  })
+}
+
+/// Sets the client API that later context calls on this thread will use.
+///
+/// The default is [`EGL_OPENGL_ES_API`], so you need to bind
+/// [`EGL_OPENGL_API`] before you can make a desktop GL context.
+///
+/// See [`eglBindAPI`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglBindAPI.xhtml)
+pub fn egl_bind_api(api: EGLenum) -> Result<(), EglError> {
+  if unsafe { eglBindAPI(api) } != EGL_FALSE {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Creates a context for the bound client API.
+///
+/// * The attribute list follows the rules of [`egl_choose_config`].
+/// * Use [`EGL_CONTEXT_MAJOR_VERSION`], [`EGL_CONTEXT_MINOR_VERSION`], and
+///   [`EGL_CONTEXT_OPENGL_PROFILE_MASK`] to get a core profile context.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `config` one of its configs.
+/// `share_context` must be [`EGL_NO_CONTEXT`] or a context of the display.
+///
+/// See [`eglCreateContext`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglCreateContext.xhtml)
+pub unsafe fn egl_create_context(
+  display: EGLDisplay, config: EGLConfig, share_context: EGLContext,
+  attrib_list: &[[EGLint; 2]],
+) -> Result<EGLContext, EglError> {
+  let i_ptr = attrib_list_ptr(attrib_list)?;
+  let ctx = eglCreateContext(display, config, share_context, i_ptr);
+  if ctx.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(ctx)
+  }
+}
+
+/// The EGL extensions for the parts of a [`ContextRequest`] on EGL 1.5, which
+/// has the rest.
+pub const EGL_CONTEXT_EXTENSIONS: ExtensionNames = ExtensionNames {
+  create_context: "",
+  profile: "",
+  es_profile: "",
+  robustness: "",
+  no_error: "EGL_KHR_create_context_no_error",
+};
+
+/// The EGL extensions for the parts of a [`ContextRequest`] before EGL 1.5,
+/// where even the minor version and the flags are from an extension.
+pub const EGL_KHR_CONTEXT_EXTENSIONS: ExtensionNames = ExtensionNames {
+  create_context: "EGL_KHR_create_context",
+  profile: "",
+  es_profile: "",
+  robustness: "EGL_EXT_create_context_robustness",
+  no_error: "EGL_KHR_create_context_no_error",
+};
+
+/// If a display's EGL version (from [`egl_initialize`]) is 1.5 or later.
+pub const fn is_egl_1_5(egl_version: (EGLint, EGLint)) -> bool {
+  egl_version.0 > 1 || (egl_version.0 == 1 && egl_version.1 >= 5)
+}
+
+/// The extensions that a display of the EGL version given needs for a
+/// [`ContextRequest`].
+pub const fn egl_context_extensions(
+  egl_version: (EGLint, EGLint),
+) -> ExtensionNames {
+  if is_egl_1_5(egl_version) {
+    EGL_CONTEXT_EXTENSIONS
+  } else {
+    EGL_KHR_CONTEXT_EXTENSIONS
+  }
+}
+
+/// The [`egl_create_context`] attributes for one version of a request,
+/// ending with [`EGL_NONE`].
+///
+/// Before EGL 1.5 the flags and robustness use the attributes of
+/// [`EGL_KHR_CONTEXT_EXTENSIONS`] instead, so check those first.
+pub fn egl_context_attribs<S>(
+  request: &ContextRequest<S>, version: &ContextVersion,
+  egl_version: (EGLint, EGLint),
+) -> Vec<[EGLint; 2]> {
+  let mut attribs = vec![
+    [EGL_CONTEXT_MAJOR_VERSION, version.major.into()],
+    [EGL_CONTEXT_MINOR_VERSION, version.minor.into()],
+  ];
+  match version.profile {
+    Some(Profile::Core) if version.api == Api::Gl => attribs.push([
+      EGL_CONTEXT_OPENGL_PROFILE_MASK,
+      EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+    ]),
+    Some(Profile::Compatibility) if version.api == Api::Gl => attribs.push([
+      EGL_CONTEXT_OPENGL_PROFILE_MASK,
+      EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT,
+    ]),
+    _ => (),
+  }
+  let forward_compatible = request.is_forward_compatible(version);
+  let reset_strategy = request.robustness.map(|reset| match reset {
+    ResetNotification::NoResetNotification => EGL_NO_RESET_NOTIFICATION,
+    ResetNotification::LoseContextOnReset => EGL_LOSE_CONTEXT_ON_RESET,
+  });
+  if is_egl_1_5(egl_version) {
+    if forward_compatible {
+      attribs.push([EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE, EGL_TRUE as _]);
+    }
+    if request.debug {
+      attribs.push([EGL_CONTEXT_OPENGL_DEBUG, EGL_TRUE as _]);
+    }
+    if let Some(strategy) = reset_strategy {
+      attribs.push([EGL_CONTEXT_OPENGL_ROBUST_ACCESS, EGL_TRUE as _]);
+      attribs.push([EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY, strategy]);
+    }
+  } else {
+    let mut flags = 0;
+    if forward_compatible {
+      flags |= EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE_BIT_KHR;
+    }
+    if request.debug {
+      flags |= EGL_CONTEXT_OPENGL_DEBUG_BIT_KHR;
+    }
+    if flags != 0 {
+      attribs.push([EGL_CONTEXT_FLAGS_KHR, flags]);
+    }
+    if let Some(strategy) = reset_strategy {
+      attribs.push([EGL_CONTEXT_OPENGL_ROBUST_ACCESS_EXT, EGL_TRUE as _]);
+      attribs
+        .push([EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY_EXT, strategy]);
+    }
+  }
+  if request.no_error {
+    attribs.push([EGL_CONTEXT_OPENGL_NO_ERROR_KHR, EGL_TRUE as _]);
+  }
+  attribs.push([EGL_NONE, 0]);
+  attribs
+}
+
+/// Makes a context with the first version of the request that works.
+///
+/// * Each attempt binds the client API of its version with [`egl_bind_api`],
+///   and the config needs the matching bit in its [`EGL_RENDERABLE_TYPE`].
+/// * Versions that need an EGL extension that isn't in `egl_extensions` (the
+///   extensions of the display) aren't tried, and say which one they needed.
+///   Which extensions are needed depends on `egl_version`, the version of the
+///   display from [`egl_initialize`].
+/// * The context isn't made current.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `config` one of its configs.
+/// The share context of the request must be a context of the display.
+pub unsafe fn create_egl_context(
+  display: EGLDisplay, config: EGLConfig, egl_version: (EGLint, EGLint),
+  egl_extensions: &[String], request: &ContextRequest<EGLContext>,
+) -> Result<CreatedContext<EGLContext, EglError>, ContextError<EglError>> {
+  let share = request.share.unwrap_or(EGL_NO_CONTEXT);
+  let extension_names = egl_context_extensions(egl_version);
+  request.try_each(|version| {
+    if let Some(name) =
+      extension_names.missing(request, version, egl_extensions)
+    {
+      return Err(AttemptError::MissingExtension(name));
+    }
+    egl_bind_api(match version.api {
+      Api::Gl => EGL_OPENGL_API,
+      Api::Gles => EGL_OPENGL_ES_API,
+    })
+    .map_err(AttemptError::Failed)?;
+    let attribs = egl_context_attribs(request, version, egl_version);
+    egl_create_context(display, config, share, &attribs)
+      .map_err(AttemptError::Failed)
+  })
+}
+
+/// Destroys a context.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `ctx` a context of it that
+/// isn't used afterwards.
+///
+/// See [`eglDestroyContext`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglDestroyContext.xhtml)
+pub unsafe fn egl_destroy_context(
+  display: EGLDisplay, ctx: EGLContext,
+) -> Result<(), EglError> {
+  if eglDestroyContext(display, ctx) != EGL_FALSE {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Creates an off-screen pbuffer surface of the given size.
+///
+/// The config must have [`EGL_PBUFFER_BIT`] in its [`EGL_SURFACE_TYPE`].
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `config` one of its configs.
+///
+/// See [`eglCreatePbufferSurface`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglCreatePbufferSurface.xhtml)
+pub unsafe fn egl_create_pbuffer_surface(
+  display: EGLDisplay, config: EGLConfig, [width, height]: [i32; 2],
+) -> Result<EGLSurface, EglError> {
+  let attribs = [[EGL_WIDTH, width], [EGL_HEIGHT, height], [EGL_NONE, 0]];
+  let surface =
+    eglCreatePbufferSurface(display, config, attribs.as_ptr().cast());
+  if surface.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(surface)
+  }
+}
+
+/// Destroys a surface.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `surface` a surface of it
+/// that isn't used afterwards.
+///
+/// See [`eglDestroySurface`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglDestroySurface.xhtml)
+pub unsafe fn egl_destroy_surface(
+  display: EGLDisplay, surface: EGLSurface,
+) -> Result<(), EglError> {
+  if eglDestroySurface(display, surface) != EGL_FALSE {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Makes a given context current in the thread and targets it at the surface
+/// given.
+///
+/// * You can pass [`EGL_NO_SURFACE`] and [`EGL_NO_CONTEXT`] to make no context
+///   current in the thread.
+/// * With `EGL_KHR_surfaceless_context` you can pass [`EGL_NO_SURFACE`] with
+///   a real context, and then render to framebuffer objects only.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `surface` and `ctx` must be
+/// its handles (or the `EGL_NO_` values).
+///
+/// See [`eglMakeCurrent`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglMakeCurrent.xhtml)
+pub unsafe fn egl_make_current(
+  display: EGLDisplay, surface: EGLSurface, ctx: EGLContext,
+) -> Result<(), EglError> {
+  if eglMakeCurrent(display, surface, surface, ctx) != EGL_FALSE {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Shows the back buffer of a surface.
+///
+/// Pbuffer surfaces are single buffered, so for them this does nothing.
+///
+/// ## Safety
+///
+/// `display` must be an initialized display, and `surface` a surface of it.
+///
+/// See [`eglSwapBuffers`](https://www.khronos.org/registry/EGL/sdk/docs/man/html/eglSwapBuffers.xhtml)
+pub unsafe fn egl_swap_buffers(
+  display: EGLDisplay, surface: EGLSurface,
+) -> Result<(), EglError> {
+  if eglSwapBuffers(display, surface) != EGL_FALSE {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// If EGL has a platform without a window system, which is the
+/// `EGL_MESA_platform_surfaceless` or `EGL_EXT_platform_device` client
+/// extension.
+///
+/// Without one, [`egl_get_headless_display`] can only try the default
+/// display, which usually needs a window system.
+pub fn has_headless_platform() -> bool {
+  unsafe { egl_get_extensions(EGL_NO_DISPLAY) }.iter().any(|s| {
+    s == "EGL_MESA_platform_surfaceless" || s == "EGL_EXT_platform_device"
+  })
+}
+
+/// Gets an initialized display that doesn't need a window system.
+///
+/// This tries, in order: the surfaceless platform, each device platform
+/// display, and finally the default display.
+///
+/// ## Safety
+///
+/// The EGL implementation must follow its client extensions.
+pub unsafe fn egl_get_headless_display() -> Result<EGLDisplay, EglError> {
+  if let Ok(display) = egl_get_surfaceless_display() {
+    if egl_initialize(display).is_ok() {
+      return Ok(display);
+    }
+  }
+  for device in egl_query_devices().unwrap_or_default() {
+    if let Ok(display) = egl_get_device_display(device) {
+      if egl_initialize(display).is_ok() {
+        return Ok(display);
+      }
+    }
+  }
+  let display = egl_get_display(EGL_DEFAULT_DISPLAY)?;
+  egl_initialize(display)?;
+  Ok(display)
+}
+
+/// Makes an off-screen GL core profile context, and makes it current.
+///
+/// * The context renders into an RGBA8 pbuffer of the size given, with a
+///   24-bit depth buffer and 8-bit stencil buffer.
+/// * In debug builds the context is a debug context, when the display's EGL
+///   version or extensions allow one.
+/// * On an error, whatever was made so far is cleaned up, including the
+///   display.
+///
+/// **Returns:** The display, surface, and context. Clean them up with
+/// [`egl_destroy_context`], [`egl_destroy_surface`], and [`egl_terminate`].
+///
+/// ## Safety
+///
+/// The EGL implementation must follow its client extensions.
+pub unsafe fn create_headless_context(
+  (major, minor): (EGLint, EGLint), size: [i32; 2],
+) -> Result<(EGLDisplay, EGLSurface, EGLContext), EglError> {
+  let display = OwnedEglDisplay::from_raw(egl_get_headless_display()?);
+  egl_bind_api(EGL_OPENGL_API)?;
+  let configs = egl_choose_config(
+    display.as_raw(),
+    &[
+      [EGL_SURFACE_TYPE, EGL_PBUFFER_BIT],
+      [EGL_RENDERABLE_TYPE, EGL_OPENGL_BIT],
+      [EGL_RED_SIZE, 8],
+      [EGL_GREEN_SIZE, 8],
+      [EGL_BLUE_SIZE, 8],
+      [EGL_ALPHA_SIZE, 8],
+      [EGL_DEPTH_SIZE, 24],
+      [EGL_STENCIL_SIZE, 8],
+      [EGL_NONE, 0],
+    ],
+  )?;
+  let config = *configs.first().ok_or(EglError(EGL_BAD_CONFIG))?;
+  let surface = OwnedEglSurface::from_raw(
+    display.as_raw(),
+    egl_create_pbuffer_surface(display.as_raw(), config, size)?,
+  );
+  // initializing again just gives the version.
+  let egl_version = egl_initialize(display.as_raw())?;
+  let egl_extensions = egl_get_extensions(display.as_raw());
+  let version = ContextVersion::gl_core(major as u8, minor as u8);
+  let mut request =
+    ContextRequest::<EGLContext>::default().debug(cfg!(debug_assertions));
+  let names = egl_context_extensions(egl_version);
+  if names.missing(&request, &version, &egl_extensions).is_some() {
+    request = request.debug(false);
+  }
+  let ctx = egl_create_context(
+    display.as_raw(),
+    config,
+    EGL_NO_CONTEXT,
+    &egl_context_attribs(&request, &version, egl_version),
+  )?;
+  let ctx = OwnedEglContext::from_raw(display.as_raw(), ctx);
+  egl_make_current(display.as_raw(), surface.as_raw(), ctx.as_raw())?;
+  let (_, surface) = surface.into_raw();
+  let (_, ctx) = ctx.into_raw();
+  Ok((display.into_raw(), surface, ctx))
+}
+
+#[test]
+fn test_egl_context_attribs() {
+  let request: ContextRequest<()> = ContextRequest::default()
+    .debug(true)
+    .forward_compatible(true)
+    .robustness(Some(ResetNotification::LoseContextOnReset));
+  let version = ContextVersion::gl_core(3, 3);
+  assert_eq!(
+    egl_context_attribs(&request, &version, (1, 5)),
+    [
+      [EGL_CONTEXT_MAJOR_VERSION, 3],
+      [EGL_CONTEXT_MINOR_VERSION, 3],
+      [EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT],
+      [EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE, EGL_TRUE as _],
+      [EGL_CONTEXT_OPENGL_DEBUG, EGL_TRUE as _],
+      [EGL_CONTEXT_OPENGL_ROBUST_ACCESS, EGL_TRUE as _],
+      [
+        EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY,
+        EGL_LOSE_CONTEXT_ON_RESET
+      ],
+      [EGL_NONE, 0],
+    ]
+  );
+  // EGL 1.4 has none of the 1.5 attributes.
+  assert_eq!(
+    egl_context_attribs(&request, &version, (1, 4)),
+    [
+      [EGL_CONTEXT_MAJOR_VERSION, 3],
+      [EGL_CONTEXT_MINOR_VERSION, 3],
+      [EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT],
+      [
+        EGL_CONTEXT_FLAGS_KHR,
+        EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE_BIT_KHR
+          | EGL_CONTEXT_OPENGL_DEBUG_BIT_KHR,
+      ],
+      [EGL_CONTEXT_OPENGL_ROBUST_ACCESS_EXT, EGL_TRUE as _],
+      [
+        EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY_EXT,
+        EGL_LOSE_CONTEXT_ON_RESET,
+      ],
+      [EGL_NONE, 0],
+    ]
+  );
+  let plain = ContextRequest::<()>::default();
+  assert_eq!(
+    egl_context_attribs(&plain, &ContextVersion::gl(3, 0), (1, 4)).len(),
+    3
+  );
+  // and needs the extensions for them.
+  let names = egl_context_extensions((1, 4));
+  let khr: Vec<String> = vec!["EGL_KHR_create_context".into()];
+  assert_eq!(
+    names.missing(&plain, &ContextVersion::gl(3, 0), &[]),
+    Some("EGL_KHR_create_context")
+  );
+  assert_eq!(
+    names.missing(&request, &version, &khr),
+    Some("EGL_EXT_create_context_robustness")
+  );
+  assert_eq!(
+    egl_context_extensions((1, 5)).missing(&request, &version, &[]),
+    None
+  );
+}
+
+#[test]
+fn test_egl_headless_context() {
+  crate::test_util::with_headless_gl([32, 32], |gl| unsafe {
+    let version = gl.GetString(crate::gl::GL_VERSION);
+    assert!(!version.is_null());
+  });
+}
+
+#[test]
+fn test_egl_describe_config() {
+  use crate::format::FormatRequest;
+  let display = match crate::test_util::headless_display() {
+    Some(display) => display,
+    None => return,
+  };
+  unsafe {
+    let configs = egl_choose_config(
+      display.as_raw(),
+      &[
+        [EGL_SURFACE_TYPE, EGL_PBUFFER_BIT],
+        [EGL_RENDERABLE_TYPE, EGL_OPENGL_BIT],
+        [EGL_NONE, 0],
+      ],
+    )
+    .unwrap();
+    let candidates: Vec<FormatDesc> = configs
+      .iter()
+      .map(|&config| egl_describe_config(display.as_raw(), config).unwrap())
+      .collect();
+    let request =
+      FormatRequest::default().color(24, 24).depth(24, 24).stencil(8, 8);
+    let ranked = request.rank(&candidates);
+    assert_eq!(ranked.len(), candidates.len());
+    let best = request.best(&candidates).unwrap();
+    assert_eq!(best, ranked[0].format);
+    assert!(best.color_bits() >= 24 && best.depth_bits >= 24);
+    assert!(best.stencil_bits >= 8);
+    // only the configs that can make window surfaces are double buffered.
+    for (&config, desc) in configs.iter().zip(candidates.iter()) {
+      let surface_type =
+        egl_get_config_attrib(display.as_raw(), config, EGL_SURFACE_TYPE)
+          .unwrap();
+      assert_eq!(desc.double_buffer, surface_type & EGL_WINDOW_BIT != 0);
+    }
+  }
+}
+
+#[test]
+fn test_create_egl_context() {
+  let display = match crate::test_util::headless_display() {
+    Some(display) => display,
+    None => return,
+  };
+  unsafe {
+    let config = egl_choose_config(
+      display.as_raw(),
+      &[
+        [EGL_SURFACE_TYPE, EGL_PBUFFER_BIT],
+        [EGL_RENDERABLE_TYPE, EGL_OPENGL_BIT],
+        [EGL_NONE, 0],
+      ],
+    )
+    .unwrap()[0];
+    let extensions = egl_get_extensions(display.as_raw());
+    // initializing again just gives the version.
+    let egl_version = egl_initialize(display.as_raw()).unwrap();
+    // no driver has GL 9.9, so this falls back to 3.3.
+    let request = ContextRequest::default()
+      .versions(&[ContextVersion::gl_core(9, 9), ContextVersion::gl_core(3, 3)])
+      .forward_compatible(true)
+      .debug(true);
+    let created = create_egl_context(
+      display.as_raw(),
+      config,
+      egl_version,
+      &extensions,
+      &request,
+    )
+    .unwrap();
+    assert_eq!(created.version, ContextVersion::gl_core(3, 3));
+    assert_eq!(created.failed.len(), 1);
+    assert!(matches!(created.failed[0].error, AttemptError::Failed(_)));
+    // sharing with the first context.
+    let shared = create_egl_context(
+      display.as_raw(),
+      config,
+      egl_version,
+      &extensions,
+      &request.clone().share(Some(created.context)),
+    )
+    .unwrap();
+    egl_destroy_context(display.as_raw(), shared.context).unwrap();
+    egl_destroy_context(display.as_raw(), created.context).unwrap();
+    // the extension is checked before the driver is asked.
+    let request = request.debug(false).no_error(true);
+    let error =
+      create_egl_context(display.as_raw(), config, egl_version, &[], &request)
+        .unwrap_err();
+    let missing = if is_egl_1_5(egl_version) {
+      "EGL_KHR_create_context_no_error"
+    } else {
+      "EGL_KHR_create_context"
+    };
+    assert_eq!(error.failed[1].error, AttemptError::MissingExtension(missing));
+    // and so is a no error context that also asks for debug.
+    let error = create_egl_context(
+      display.as_raw(),
+      config,
+      egl_version,
+      &extensions,
+      &request.debug(true),
+    )
+    .unwrap_err();
+    assert!(matches!(error.failed[1].error, AttemptError::Conflict(_)));
+  }
+}
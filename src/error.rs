@@ -0,0 +1,135 @@
+//! Module for one error type that the OS errors of the crate convert into.
+//!
+//! Each OS API has its own error type, with its own names and messages. When
+//! code uses more than one of them, `?` can convert each into an [`Error`],
+//! which keeps the original as its `source`.
+//!
+//! ```
+//! # #[cfg(has_errno_table)] {
+//! use triangle_from_scratch::{error::Error, os_error::*};
+//! fn open_thing() -> Result<(), Error> {
+//!   Err(OsError(ENOENT))?
+//! }
+//! let e = open_thing().unwrap_err();
+//! assert_eq!(e.to_string(), "ENOENT (No such file or directory)");
+//! # }
+//! ```
+
+#[cfg(unix)]
+use crate::dylib::DlError;
+#[cfg(has_errno_table)]
+use crate::os_error::OsError;
+#[cfg(windows)]
+use crate::win32::Win32Error;
+#[cfg(target_os = "linux")]
+use crate::{egl::EglError, x11::X11Error};
+
+/// An error from any of the OS APIs of the crate.
+///
+/// Each variant only exists on the platforms that have its API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+  #[cfg(windows)]
+  Win32(Win32Error),
+  /// A code from `errno`.
+  #[cfg(has_errno_table)]
+  Os(OsError),
+  #[cfg(target_os = "linux")]
+  X11(X11Error),
+  #[cfg(target_os = "linux")]
+  Egl(EglError),
+  /// A message from `dlerror`.
+  #[cfg(unix)]
+  Dl(DlError),
+}
+impl core::fmt::Display for Error {
+  /// The `Display` of the error inside.
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self {
+      #[cfg(windows)]
+      Self::Win32(e) => core::fmt::Display::fmt(e, f),
+      #[cfg(has_errno_table)]
+      Self::Os(e) => core::fmt::Display::fmt(e, f),
+      #[cfg(target_os = "linux")]
+      Self::X11(e) => core::fmt::Display::fmt(e, f),
+      #[cfg(target_os = "linux")]
+      Self::Egl(e) => core::fmt::Display::fmt(e, f),
+      #[cfg(unix)]
+      Self::Dl(e) => core::fmt::Display::fmt(e, f),
+    }
+  }
+}
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(match self {
+      #[cfg(windows)]
+      Self::Win32(e) => e,
+      #[cfg(has_errno_table)]
+      Self::Os(e) => e,
+      #[cfg(target_os = "linux")]
+      Self::X11(e) => e,
+      #[cfg(target_os = "linux")]
+      Self::Egl(e) => e,
+      #[cfg(unix)]
+      Self::Dl(e) => e,
+    })
+  }
+}
+
+#[cfg(windows)]
+impl From<Win32Error> for Error {
+  fn from(e: Win32Error) -> Self {
+    Self::Win32(e)
+  }
+}
+#[cfg(has_errno_table)]
+impl From<OsError> for Error {
+  fn from(e: OsError) -> Self {
+    Self::Os(e)
+  }
+}
+#[cfg(target_os = "linux")]
+impl From<X11Error> for Error {
+  fn from(e: X11Error) -> Self {
+    Self::X11(e)
+  }
+}
+#[cfg(target_os = "linux")]
+impl From<EglError> for Error {
+  fn from(e: EglError) -> Self {
+    Self::Egl(e)
+  }
+}
+#[cfg(unix)]
+impl From<DlError> for Error {
+  fn from(e: DlError) -> Self {
+    Self::Dl(e)
+  }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_error() {
+  use crate::{egl::EGL_BAD_MATCH, x11::BadWindow};
+  #[cfg(has_errno_table)]
+  {
+    use crate::os_error::EACCES;
+    use std::error::Error as _;
+    let e = Error::from(OsError(EACCES));
+    assert_eq!(e, Error::Os(OsError(EACCES)));
+    assert_eq!(e.to_string(), "EACCES (Permission denied)");
+    let source = e.source().unwrap().downcast_ref::<OsError>();
+    assert_eq!(source, Some(&OsError(EACCES)));
+  }
+  assert_eq!(
+    Error::from(X11Error(BadWindow)).to_string(),
+    "BadWindow (invalid Window parameter)"
+  );
+  assert_eq!(Error::from(EglError(EGL_BAD_MATCH)).to_string(), "EGL_BAD_MATCH");
+  let e: Error = crate::dylib::Library::open("libnot-a-real-library.so")
+    .map(|_| ())
+    .unwrap_err()
+    .into();
+  assert!(matches!(e, Error::Dl(_)));
+  assert!(e.to_string().contains("libnot-a-real-library.so"));
+}
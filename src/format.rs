@@ -0,0 +1,430 @@
+//! Module for picking a framebuffer format the same way on every backend.
+//!
+//! `ChoosePixelFormat`, `glXChooseFBConfig`, and friends sort the formats by
+//! their own rules, which can put a format with a 16-bit depth buffer or
+//! without sRGB first. Instead, describe each candidate as a [`FormatDesc`]
+//! and rank them with a [`FormatRequest`], which always ranks the same
+//! candidates the same way.
+//!
+//! ```
+//! use triangle_from_scratch::format::*;
+//! let request = FormatRequest::default().depth(16, 24).srgb(Preference::Preferred);
+//! let base = FormatDesc { red_bits: 8, green_bits: 8, blue_bits: 8, ..FormatDesc::default() };
+//! let candidates = [
+//!   FormatDesc { id: 1, depth_bits: 16, ..base },
+//!   FormatDesc { id: 2, depth_bits: 24, srgb: true, ..base },
+//!   FormatDesc { id: 3, depth_bits: 0, ..base },
+//! ];
+//! let ranked = request.rank(&candidates);
+//! assert_eq!(ranked.iter().map(|r| r.format.id).collect::<Vec<_>>(), [2, 1, 3]);
+//! assert_eq!(ranked[2].rejections, [Rejection::TooFewBits { buffer: Buffer::Depth, have: 0, min: 16 }]);
+//! ```
+
+/// A framebuffer format of any backend, in the terms that a [`FormatRequest`]
+/// uses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FormatDesc {
+  /// The backend's id of the format, eg: the pixel format index on WGL or
+  /// the `GLX_FBCONFIG_ID`.
+  pub id: i32,
+  pub red_bits: u8,
+  pub green_bits: u8,
+  pub blue_bits: u8,
+  pub alpha_bits: u8,
+  pub depth_bits: u8,
+  pub stencil_bits: u8,
+  /// Samples per pixel, which is 0 without multisampling.
+  pub samples: u8,
+  pub srgb: bool,
+  pub double_buffer: bool,
+  /// Drawn by the GPU, rather than a software renderer.
+  pub accelerated: bool,
+}
+impl FormatDesc {
+  /// Bits of color, not counting alpha.
+  pub fn color_bits(&self) -> u32 {
+    u32::from(self.red_bits)
+      + u32::from(self.green_bits)
+      + u32::from(self.blue_bits)
+  }
+
+  /// The bits of one of the buffers.
+  pub fn bits(&self, buffer: Buffer) -> u32 {
+    match buffer {
+      Buffer::Color => self.color_bits(),
+      Buffer::Alpha => self.alpha_bits.into(),
+      Buffer::Depth => self.depth_bits.into(),
+      Buffer::Stencil => self.stencil_bits.into(),
+      Buffer::Samples => self.samples.into(),
+    }
+  }
+
+  /// If the format has one of the features.
+  pub fn has(&self, feature: Feature) -> bool {
+    match feature {
+      Feature::Acceleration => self.accelerated,
+      Feature::Srgb => self.srgb,
+      Feature::DoubleBuffer => self.double_buffer,
+    }
+  }
+}
+
+/// The part of a format that has a size in bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Buffer {
+  /// The red, green, and blue bits together.
+  Color,
+  Alpha,
+  Depth,
+  Stencil,
+  /// Samples per pixel, rather than bits.
+  Samples,
+}
+impl Buffer {
+  pub const ALL: [Self; 5] =
+    [Self::Color, Self::Alpha, Self::Depth, Self::Stencil, Self::Samples];
+}
+
+/// The parts of a format that it has or doesn't have, in order of importance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+  Acceleration,
+  Srgb,
+  DoubleBuffer,
+}
+impl Feature {
+  pub const ALL: [Self; 3] =
+    [Self::Acceleration, Self::Srgb, Self::DoubleBuffer];
+}
+
+/// The size that a [`FormatRequest`] wants of a [`Buffer`].
+///
+/// Less than `min` rejects the format, and the closer to `preferred` the
+/// better. A `preferred` under `min` counts as `min`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Bits {
+  pub min: u8,
+  pub preferred: u8,
+}
+
+/// How much a [`FormatRequest`] wants a [`Feature`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Preference {
+  #[default]
+  DontCare,
+  /// Formats with it rank higher.
+  Preferred,
+  /// Formats without it are rejected.
+  Required,
+}
+
+/// Why a [`FormatRequest`] rejected a format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rejection {
+  TooFewBits { buffer: Buffer, have: u32, min: u8 },
+  Missing(Feature),
+}
+impl core::fmt::Display for Rejection {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self {
+      Self::TooFewBits { buffer: Buffer::Samples, have, min } => {
+        write!(f, "{} samples, needs {}", have, min)
+      }
+      Self::TooFewBits { buffer, have, min } => {
+        write!(f, "{} {:?} bits, needs {}", have, buffer, min)
+      }
+      Self::Missing(Feature::Acceleration) => write!(f, "not accelerated"),
+      Self::Missing(Feature::Srgb) => write!(f, "no sRGB"),
+      Self::Missing(Feature::DoubleBuffer) => write!(f, "not double buffered"),
+    }
+  }
+}
+
+/// How well a format fits a [`FormatRequest`]. Lower is better, and the
+/// fields are compared in order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Score {
+  /// A bit for each preferred [`Feature`] that's missing, with the more
+  /// important features in the higher bits.
+  pub missing: u8,
+  /// How many bits short of the preferred sizes, in total.
+  pub shortfall: u32,
+  /// How many bits over the preferred sizes, in total, since bits that
+  /// aren't used still cost memory and bandwidth.
+  pub excess: u32,
+}
+
+/// A format, with how it fits a [`FormatRequest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedFormat {
+  pub format: FormatDesc,
+  pub score: Score,
+  /// Every reason the format was rejected, or empty if it wasn't.
+  pub rejections: Vec<Rejection>,
+}
+impl RankedFormat {
+  pub fn is_accepted(&self) -> bool {
+    self.rejections.is_empty()
+  }
+}
+
+/// What's wanted of a framebuffer format.
+///
+/// The default wants nothing, so every format is accepted and the fewest bits
+/// win. Use the builder methods to ask for more.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FormatRequest {
+  pub color: Bits,
+  pub alpha: Bits,
+  pub depth: Bits,
+  pub stencil: Bits,
+  pub samples: Bits,
+  pub accelerated: Preference,
+  pub srgb: Preference,
+  pub double_buffer: Preference,
+}
+impl FormatRequest {
+  /// Bits of color, not counting alpha.
+  pub fn color(self, min: u8, preferred: u8) -> Self {
+    Self { color: Bits { min, preferred }, ..self }
+  }
+
+  pub fn alpha(self, min: u8, preferred: u8) -> Self {
+    Self { alpha: Bits { min, preferred }, ..self }
+  }
+
+  pub fn depth(self, min: u8, preferred: u8) -> Self {
+    Self { depth: Bits { min, preferred }, ..self }
+  }
+
+  pub fn stencil(self, min: u8, preferred: u8) -> Self {
+    Self { stencil: Bits { min, preferred }, ..self }
+  }
+
+  /// Samples per pixel of multisampling.
+  pub fn samples(self, min: u8, preferred: u8) -> Self {
+    Self { samples: Bits { min, preferred }, ..self }
+  }
+
+  pub fn accelerated(self, accelerated: Preference) -> Self {
+    Self { accelerated, ..self }
+  }
+
+  pub fn srgb(self, srgb: Preference) -> Self {
+    Self { srgb, ..self }
+  }
+
+  pub fn double_buffer(self, double_buffer: Preference) -> Self {
+    Self { double_buffer, ..self }
+  }
+
+  pub fn bits(&self, buffer: Buffer) -> Bits {
+    match buffer {
+      Buffer::Color => self.color,
+      Buffer::Alpha => self.alpha,
+      Buffer::Depth => self.depth,
+      Buffer::Stencil => self.stencil,
+      Buffer::Samples => self.samples,
+    }
+  }
+
+  pub fn preference(&self, feature: Feature) -> Preference {
+    match feature {
+      Feature::Acceleration => self.accelerated,
+      Feature::Srgb => self.srgb,
+      Feature::DoubleBuffer => self.double_buffer,
+    }
+  }
+
+  /// Scores one format, and gives any reasons to reject it.
+  pub fn check(&self, format: &FormatDesc) -> RankedFormat {
+    let mut score = Score::default();
+    let mut rejections = Vec::new();
+    for buffer in Buffer::ALL {
+      let Bits { min, preferred } = self.bits(buffer);
+      let preferred = u32::from(preferred.max(min));
+      let have = format.bits(buffer);
+      if have < u32::from(min) {
+        rejections.push(Rejection::TooFewBits { buffer, have, min });
+      }
+      score.shortfall += preferred.saturating_sub(have);
+      score.excess += have.saturating_sub(preferred);
+    }
+    for (i, &feature) in Feature::ALL.iter().enumerate() {
+      if format.has(feature) {
+        continue;
+      }
+      match self.preference(feature) {
+        Preference::DontCare => (),
+        Preference::Preferred => {
+          score.missing |= 1 << (Feature::ALL.len() - 1 - i)
+        }
+        Preference::Required => rejections.push(Rejection::Missing(feature)),
+      }
+    }
+    RankedFormat { format: *format, score, rejections }
+  }
+
+  /// Ranks the candidates, best first.
+  ///
+  /// The accepted formats come first, sorted by [`Score`]. Formats with the
+  /// same score keep their order, so the backend's order breaks ties. The
+  /// rejected formats come after, in their order.
+  pub fn rank(&self, candidates: &[FormatDesc]) -> Vec<RankedFormat> {
+    let (mut accepted, rejected): (Vec<_>, Vec<_>) = candidates
+      .iter()
+      .map(|format| self.check(format))
+      .partition(RankedFormat::is_accepted);
+    accepted.sort_by_key(|ranked| ranked.score);
+    accepted.extend(rejected);
+    accepted
+  }
+
+  /// The best of the candidates, if any is accepted.
+  pub fn best(&self, candidates: &[FormatDesc]) -> Option<FormatDesc> {
+    self
+      .rank(candidates)
+      .into_iter()
+      .next()
+      .filter(RankedFormat::is_accepted)
+      .map(|ranked| ranked.format)
+  }
+}
+
+#[test]
+fn test_format_check() {
+  let request = FormatRequest::default()
+    .color(24, 24)
+    .alpha(0, 8)
+    .depth(16, 24)
+    .stencil(8, 8)
+    .samples(0, 4)
+    .accelerated(Preference::Required)
+    .srgb(Preference::Preferred)
+    .double_buffer(Preference::Preferred);
+  let exact = FormatDesc {
+    id: 1,
+    red_bits: 8,
+    green_bits: 8,
+    blue_bits: 8,
+    alpha_bits: 8,
+    depth_bits: 24,
+    stencil_bits: 8,
+    samples: 4,
+    srgb: true,
+    double_buffer: true,
+    accelerated: true,
+  };
+  assert_eq!(
+    request.check(&exact),
+    RankedFormat { format: exact, score: Score::default(), rejections: vec![] }
+  );
+
+  // short of the preferred sizes, and over them.
+  let ranked = request.check(&FormatDesc {
+    alpha_bits: 0,
+    depth_bits: 32,
+    samples: 0,
+    red_bits: 10,
+    green_bits: 10,
+    blue_bits: 10,
+    ..exact
+  });
+  assert!(ranked.is_accepted());
+  assert_eq!(
+    ranked.score,
+    Score { missing: 0, shortfall: 8 + 4, excess: 6 + 8 }
+  );
+
+  // missing preferred features set bits, with acceleration the highest (but
+  // it's required here).
+  let ranked =
+    request.check(&FormatDesc { srgb: false, double_buffer: false, ..exact });
+  assert_eq!(ranked.score.missing, 0b011);
+  let ranked = request.check(&FormatDesc { double_buffer: false, ..exact });
+  assert_eq!(ranked.score.missing, 0b001);
+  let preferred = request.accelerated(Preference::Preferred);
+  let ranked = preferred.check(&FormatDesc { accelerated: false, ..exact });
+  assert_eq!((ranked.score.missing, ranked.is_accepted()), (0b100, true));
+
+  // every reason is given.
+  let ranked = request.check(&FormatDesc {
+    red_bits: 5,
+    green_bits: 6,
+    blue_bits: 5,
+    depth_bits: 0,
+    stencil_bits: 0,
+    accelerated: false,
+    ..exact
+  });
+  assert_eq!(
+    ranked.rejections,
+    [
+      Rejection::TooFewBits { buffer: Buffer::Color, have: 16, min: 24 },
+      Rejection::TooFewBits { buffer: Buffer::Depth, have: 0, min: 16 },
+      Rejection::TooFewBits { buffer: Buffer::Stencil, have: 0, min: 8 },
+      Rejection::Missing(Feature::Acceleration),
+    ]
+  );
+  let reasons: Vec<String> =
+    ranked.rejections.iter().map(|r| r.to_string()).collect();
+  assert_eq!(
+    reasons,
+    [
+      "16 Color bits, needs 24",
+      "0 Depth bits, needs 16",
+      "0 Stencil bits, needs 8",
+      "not accelerated"
+    ]
+  );
+  let samples =
+    Rejection::TooFewBits { buffer: Buffer::Samples, have: 0, min: 4 };
+  assert_eq!(samples.to_string(), "0 samples, needs 4");
+}
+
+#[test]
+fn test_format_rank() {
+  let request = FormatRequest::default()
+    .color(24, 24)
+    .depth(16, 24)
+    .stencil(0, 8)
+    .srgb(Preference::Preferred)
+    .double_buffer(Preference::Required);
+  let base = FormatDesc {
+    red_bits: 8,
+    green_bits: 8,
+    blue_bits: 8,
+    alpha_bits: 8,
+    depth_bits: 24,
+    stencil_bits: 8,
+    double_buffer: true,
+    accelerated: true,
+    ..FormatDesc::default()
+  };
+  let candidates = [
+    // what a driver might put first: 16-bit depth, no sRGB.
+    FormatDesc { id: 1, depth_bits: 16, stencil_bits: 0, ..base },
+    FormatDesc { id: 2, ..base },
+    FormatDesc { id: 3, double_buffer: false, srgb: true, ..base },
+    FormatDesc { id: 4, srgb: true, ..base },
+    FormatDesc { id: 5, srgb: true, samples: 8, ..base },
+    // the same as 4, so the order of the candidates breaks the tie.
+    FormatDesc { id: 6, srgb: true, ..base },
+    FormatDesc { id: 7, srgb: true, depth_bits: 32, ..base },
+    FormatDesc { id: 8, red_bits: 5, green_bits: 6, blue_bits: 5, ..base },
+  ];
+  let ranked = request.rank(&candidates);
+  let ids: Vec<i32> = ranked.iter().map(|r| r.format.id).collect();
+  assert_eq!(ids, [4, 6, 5, 7, 2, 1, 3, 8]);
+  let accepted = ranked.iter().filter(|r| r.is_accepted()).count();
+  assert_eq!(accepted, 6);
+  assert_eq!(ranked[6].rejections, [Rejection::Missing(Feature::DoubleBuffer)]);
+  // the ranking doesn't depend on anything but the candidates.
+  assert_eq!(request.rank(&candidates), ranked);
+  assert_eq!(request.best(&candidates), Some(candidates[3]));
+  assert_eq!(request.best(&candidates[2..3]), None);
+  assert_eq!(request.best(&[]), None);
+  assert!(request.rank(&[]).is_empty());
+  // with no request, the fewest bits win.
+  assert_eq!(FormatRequest::default().best(&candidates), Some(candidates[0]));
+}
@@ -1,24 +1,102 @@
-#![allow(non_camel_case_types)]
+//! Module for OpenGL types, constants, and function pointers.
+//!
+//! These are generated from the `gl.xml` registry by the `gl_generator` crate
+//! of the workspace. Load the functions into a [`GlFns`] or a [`Gl33`] with
+//! whatever the platform's `GetProcAddress` style function is.
+//!
+//! Shaders can be compiled and linked with [`compile_shader`] and
+//! [`link_program`], which give a [`ShaderError`] with the parsed info log.
+//! The messages of a debug context can be sent somewhere with a
+//! [`DebugCallback`]. With the `checked_gl` feature every call is followed by a
+//! `glGetError` check, see [`set_gl_error_mode`]. With the `trace_gl` feature
+//! the calls can be recorded into a [`Trace`] and replayed. Pixels can be
+//! read back with [`Gl33::read_rgba8`] or, over many frames, a [`PboReader`].
 
-use super::*;
+#[rustfmt::skip]
+mod generated;
+pub use generated::*;
 
-/// From `gl.xml`
-pub type GLbitfield = c_uint;
+#[rustfmt::skip]
+mod gl33;
+pub use gl33::Gl33;
 
-/// From `gl.xml`
-pub type GLfloat = c_float;
+mod info_log;
+pub use info_log::*;
 
-/// See [`glClear`](https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClear.xhtml)
-pub type glClear_t = Option<unsafe extern "system" fn(mask: GLbitfield)>;
+mod shader;
+pub use shader::*;
 
-/// See [`glClearColor`](https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearColor.xhtml)
-pub type glClearColor_t = Option<
-  unsafe extern "system" fn(
-    red: GLfloat,
-    green: GLfloat,
-    blue: GLfloat,
-    alpha: GLfloat,
-  ),
->;
+mod debug;
+pub use debug::*;
 
-pub const GL_COLOR_BUFFER_BIT: GLbitfield = 0x00004000;
+mod checked;
+pub use checked::*;
+
+mod trace;
+pub use trace::*;
+
+mod readback;
+pub use readback::*;
+
+#[test]
+fn test_gl_fns_load() {
+  let names = core::cell::RefCell::new(Vec::new());
+  let fns = unsafe {
+    GlFns::load(|name| {
+      names.borrow_mut().push(name.to_vec());
+      core::ptr::null()
+    })
+  };
+  assert!(fns.glClear.is_none());
+  let names = names.into_inner();
+  assert!(names.iter().all(|name| name.last() == Some(&0)));
+  assert!(names.iter().any(|name| name == b"glClearColor\0"));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_gl_fns_load_egl() {
+  use crate::egl::egl_get_proc_address;
+  // the helper loads a `Gl33`, and checks that nothing is missing.
+  crate::test_util::with_headless_gl([32, 32], |gl33| unsafe {
+    let gl = GlFns::load(|name| {
+      egl_get_proc_address(name).unwrap_or(core::ptr::null_mut())
+    });
+    let version = (gl.glGetString.unwrap())(GL_VERSION);
+    assert!(!version.is_null());
+    (gl.glClearColor.unwrap())(0.6, 0.7, 0.8, 1.0);
+    (gl.glClear.unwrap())(GL_COLOR_BUFFER_BIT);
+    assert_eq!((gl.glGetError.unwrap())(), GL_NO_ERROR);
+    assert_eq!(gl33.GetError(), GL_NO_ERROR);
+  });
+}
+
+#[test]
+fn test_gl33_load_reports_missing() {
+  let (gl, missing) = unsafe {
+    Gl33::load(|name| {
+      if name == b"glClear\0" {
+        test_gl33_load_reports_missing as *const core::ffi::c_void
+      } else {
+        core::ptr::null()
+      }
+    })
+  };
+  assert!(missing.contains(&"glClearColor"));
+  assert!(!missing.contains(&"glClear"));
+  // GL 3.3 only has these with `KHR_debug`, so they're checked separately.
+  assert!(!missing.contains(&"glDebugMessageCallback"));
+  assert!(!gl.loaded_GL_KHR_debug());
+  let (all, missing) = unsafe {
+    Gl33::load(|_| test_gl33_load_reports_missing as *const core::ffi::c_void)
+  };
+  assert!(missing.is_empty());
+  assert!(all.loaded_GL_KHR_debug());
+  let result =
+    std::panic::catch_unwind(|| unsafe { gl.ClearColor(0.0, 0.0, 0.0, 1.0) });
+  let message = result.unwrap_err();
+  assert_eq!(
+    message.downcast_ref::<String>().unwrap(),
+    "`glClearColor` wasn't loaded"
+  );
+}
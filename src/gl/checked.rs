@@ -0,0 +1,131 @@
+//! Checking `glGetError` after every call.
+//!
+//! With the `checked_gl` feature each method of [`Gl33`] (other than
+//! `GetError`) calls `glGetError` afterwards. Any error is reported with the
+//! function's name and arguments, by panicking or by printing to stderr, see
+//! [`set_gl_error_mode`]. Without the feature the check isn't compiled at all.
+
+use super::*;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Gets the name of an error from `glGetError`, eg: `"GL_INVALID_ENUM"`.
+///
+/// ```
+/// # use triangle_from_scratch::gl::*;
+/// assert_eq!(gl_error_name(GL_INVALID_ENUM), Some("GL_INVALID_ENUM"));
+/// assert_eq!(gl_error_name(0x1234), None);
+/// ```
+pub const fn gl_error_name(error: GLenum) -> Option<&'static str> {
+  Some(match error {
+    GL_NO_ERROR => "GL_NO_ERROR",
+    GL_INVALID_ENUM => "GL_INVALID_ENUM",
+    GL_INVALID_VALUE => "GL_INVALID_VALUE",
+    GL_INVALID_OPERATION => "GL_INVALID_OPERATION",
+    GL_STACK_OVERFLOW => "GL_STACK_OVERFLOW",
+    GL_STACK_UNDERFLOW => "GL_STACK_UNDERFLOW",
+    GL_OUT_OF_MEMORY => "GL_OUT_OF_MEMORY",
+    GL_INVALID_FRAMEBUFFER_OPERATION => "GL_INVALID_FRAMEBUFFER_OPERATION",
+    GL_CONTEXT_LOST => "GL_CONTEXT_LOST",
+    _ => return None,
+  })
+}
+
+/// What the `checked_gl` feature does with an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlErrorMode {
+  /// Panic with the message. This is the default.
+  Panic,
+  /// Print the message to stderr and keep going.
+  Log,
+}
+
+static LOG_GL_ERRORS: AtomicBool = AtomicBool::new(false);
+
+/// Sets what to do with errors found by the `checked_gl` feature.
+///
+/// This is for the whole program. Without the feature it does nothing.
+pub fn set_gl_error_mode(mode: GlErrorMode) {
+  LOG_GL_ERRORS.store(mode == GlErrorMode::Log, Ordering::Relaxed);
+}
+
+/// Gets the current [`GlErrorMode`].
+pub fn gl_error_mode() -> GlErrorMode {
+  if LOG_GL_ERRORS.load(Ordering::Relaxed) {
+    GlErrorMode::Log
+  } else {
+    GlErrorMode::Panic
+  }
+}
+
+/// Formats a failed call, eg: `glEnable(cap: 65535) gave GL_INVALID_ENUM`.
+#[cfg(any(test, feature = "checked_gl"))]
+fn describe_gl_error(
+  name: &str, args: &[(&str, &dyn core::fmt::Debug)], errors: &[GLenum],
+) -> String {
+  let args: Vec<String> =
+    args.iter().map(|(arg, value)| format!("{}: {:?}", arg, value)).collect();
+  let errors: Vec<String> = errors
+    .iter()
+    .map(|&e| match gl_error_name(e) {
+      Some(name) => name.to_string(),
+      None => format!("0x{:04X}", e),
+    })
+    .collect();
+  format!("{}({}) gave {}", name, args.join(", "), errors.join(", "))
+}
+
+#[test]
+fn test_describe_gl_error() {
+  let cap: GLenum = 0xFFFF;
+  assert_eq!(
+    describe_gl_error("glEnable", &[("cap", &cap)], &[GL_INVALID_ENUM]),
+    "glEnable(cap: 65535) gave GL_INVALID_ENUM"
+  );
+  assert_eq!(
+    describe_gl_error("glFinish", &[], &[GL_OUT_OF_MEMORY, 0x1234]),
+    "glFinish() gave GL_OUT_OF_MEMORY, 0x1234"
+  );
+}
+
+/// Called by every method of [`Gl33`] with the `checked_gl` feature.
+#[cfg(feature = "checked_gl")]
+#[track_caller]
+pub(crate) fn check_gl_error(
+  get_error: glGetError_t, name: &str, args: &[(&str, &dyn core::fmt::Debug)],
+) {
+  let get_error = match get_error {
+    Some(get_error) => get_error,
+    None => return,
+  };
+  let mut error = unsafe { get_error() };
+  if error == GL_NO_ERROR {
+    return;
+  }
+  // there can be more than one error flag set, but a lost context gives
+  // `GL_CONTEXT_LOST` forever, so only take a few.
+  let mut errors = Vec::new();
+  while error != GL_NO_ERROR && errors.len() < 8 {
+    errors.push(error);
+    error = unsafe { get_error() };
+  }
+  let message = describe_gl_error(name, args, &errors);
+  match gl_error_mode() {
+    GlErrorMode::Panic => panic!("{}", message),
+    GlErrorMode::Log => eprintln!("{}", message),
+  }
+}
+
+#[test]
+#[cfg(all(target_os = "linux", feature = "checked_gl"))]
+fn test_checked_gl_panics() {
+  crate::test_util::with_headless_gl([8, 8], |gl| unsafe {
+    gl.Enable(GL_BLEND);
+    let result = std::panic::catch_unwind(|| gl.Enable(0xFFFF));
+    let message = result.unwrap_err();
+    assert_eq!(
+      message.downcast_ref::<String>().unwrap(),
+      "glEnable(cap: 65535) gave GL_INVALID_ENUM"
+    );
+    assert_eq!(gl.GetError(), GL_NO_ERROR);
+  });
+}
@@ -0,0 +1,405 @@
+//! Getting messages from a debug context, with `KHR_debug`.
+//!
+//! Install a [`DebugOutput`] with [`DebugCallback::install`] once the context
+//! is current and the functions are loaded. Every message the driver sends is
+//! turned into a [`DebugMessage`] and given to the output's sink, which prints
+//! to stderr by default.
+//!
+//! The callback is called by the driver, so it can't unwind back into it. When
+//! [`DebugOutput::panic_on_error`] is set, error messages are saved instead,
+//! and the panic happens at the next [`DebugCallback::check`] or
+//! [`DebugCallback::remove`].
+
+use super::*;
+use core::{ffi::c_void, ptr::NonNull};
+use std::sync::Mutex;
+
+/// Where a debug message came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugSource {
+  Api,
+  WindowSystem,
+  ShaderCompiler,
+  ThirdParty,
+  Application,
+  Other,
+  /// A value that isn't in the spec.
+  Unknown(GLenum),
+}
+impl DebugSource {
+  pub const fn from_raw(source: GLenum) -> Self {
+    match source {
+      GL_DEBUG_SOURCE_API => Self::Api,
+      GL_DEBUG_SOURCE_WINDOW_SYSTEM => Self::WindowSystem,
+      GL_DEBUG_SOURCE_SHADER_COMPILER => Self::ShaderCompiler,
+      GL_DEBUG_SOURCE_THIRD_PARTY => Self::ThirdParty,
+      GL_DEBUG_SOURCE_APPLICATION => Self::Application,
+      GL_DEBUG_SOURCE_OTHER => Self::Other,
+      other => Self::Unknown(other),
+    }
+  }
+}
+
+/// What sort of debug message it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugType {
+  Error,
+  DeprecatedBehavior,
+  UndefinedBehavior,
+  Portability,
+  Performance,
+  Marker,
+  PushGroup,
+  PopGroup,
+  Other,
+  /// A value that isn't in the spec.
+  Unknown(GLenum),
+}
+impl DebugType {
+  pub const fn from_raw(type_: GLenum) -> Self {
+    match type_ {
+      GL_DEBUG_TYPE_ERROR => Self::Error,
+      GL_DEBUG_TYPE_DEPRECATED_BEHAVIOR => Self::DeprecatedBehavior,
+      GL_DEBUG_TYPE_UNDEFINED_BEHAVIOR => Self::UndefinedBehavior,
+      GL_DEBUG_TYPE_PORTABILITY => Self::Portability,
+      GL_DEBUG_TYPE_PERFORMANCE => Self::Performance,
+      GL_DEBUG_TYPE_MARKER => Self::Marker,
+      GL_DEBUG_TYPE_PUSH_GROUP => Self::PushGroup,
+      GL_DEBUG_TYPE_POP_GROUP => Self::PopGroup,
+      GL_DEBUG_TYPE_OTHER => Self::Other,
+      other => Self::Unknown(other),
+    }
+  }
+}
+
+/// How important a debug message is, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DebugSeverity {
+  Notification,
+  Low,
+  Medium,
+  High,
+}
+impl DebugSeverity {
+  /// Unknown values are treated as `High`, so they aren't filtered out.
+  pub const fn from_raw(severity: GLenum) -> Self {
+    match severity {
+      GL_DEBUG_SEVERITY_NOTIFICATION => Self::Notification,
+      GL_DEBUG_SEVERITY_LOW => Self::Low,
+      GL_DEBUG_SEVERITY_MEDIUM => Self::Medium,
+      _ => Self::High,
+    }
+  }
+  pub const fn to_raw(self) -> GLenum {
+    match self {
+      Self::Notification => GL_DEBUG_SEVERITY_NOTIFICATION,
+      Self::Low => GL_DEBUG_SEVERITY_LOW,
+      Self::Medium => GL_DEBUG_SEVERITY_MEDIUM,
+      Self::High => GL_DEBUG_SEVERITY_HIGH,
+    }
+  }
+}
+
+#[test]
+fn test_debug_enums_from_raw() {
+  assert_eq!(DebugSource::from_raw(GL_DEBUG_SOURCE_API), DebugSource::Api);
+  assert_eq!(DebugSource::from_raw(0), DebugSource::Unknown(0));
+  assert_eq!(DebugType::from_raw(GL_DEBUG_TYPE_ERROR), DebugType::Error);
+  assert_eq!(DebugType::from_raw(7), DebugType::Unknown(7));
+  for severity in [
+    DebugSeverity::Notification,
+    DebugSeverity::Low,
+    DebugSeverity::Medium,
+    DebugSeverity::High,
+  ] {
+    assert_eq!(DebugSeverity::from_raw(severity.to_raw()), severity);
+  }
+  assert!(DebugSeverity::Notification < DebugSeverity::Low);
+  assert!(DebugSeverity::Medium < DebugSeverity::High);
+}
+
+/// One message from the debug output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugMessage {
+  pub source: DebugSource,
+  pub type_: DebugType,
+  pub id: GLuint,
+  pub severity: DebugSeverity,
+  pub message: String,
+}
+impl core::fmt::Display for DebugMessage {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(
+      f,
+      "[GL {:?} {:?} {:?} {}] {}",
+      self.severity, self.source, self.type_, self.id, self.message
+    )
+  }
+}
+
+/// Something that gets the debug messages.
+pub type DebugSink = Box<dyn FnMut(&DebugMessage) + Send>;
+
+/// Prints the message to stderr.
+pub fn stderr_sink(message: &DebugMessage) {
+  eprintln!("{}", message);
+}
+
+/// What to do with the debug messages.
+pub struct DebugOutput {
+  /// Messages less severe than this are filtered out, both in the driver and
+  /// in the callback.
+  pub min_severity: DebugSeverity,
+  pub sink: DebugSink,
+  /// Save messages of [`DebugType::Error`] so that [`DebugCallback::check`]
+  /// panics with them. This is meant for tests.
+  pub panic_on_error: bool,
+}
+impl Default for DebugOutput {
+  /// Everything but notifications goes to [`stderr_sink`].
+  fn default() -> Self {
+    Self {
+      min_severity: DebugSeverity::Low,
+      sink: Box::new(stderr_sink),
+      panic_on_error: false,
+    }
+  }
+}
+
+/// The state that the callback's `userParam` points to.
+struct DebugState {
+  min_severity: DebugSeverity,
+  sink: Mutex<DebugSink>,
+  panic_on_error: bool,
+  errors: Mutex<Vec<DebugMessage>>,
+}
+
+unsafe extern "system" fn debug_callback(
+  source: GLenum, type_: GLenum, id: GLuint, severity: GLenum, length: GLsizei,
+  message: *const GLchar, user_param: *const c_void,
+) {
+  let state = &*user_param.cast::<DebugState>();
+  let severity = DebugSeverity::from_raw(severity);
+  if severity < state.min_severity {
+    return;
+  }
+  let bytes = if message.is_null() {
+    &[][..]
+  } else if length < 0 {
+    core::ffi::CStr::from_ptr(message).to_bytes()
+  } else {
+    core::slice::from_raw_parts(message.cast::<u8>(), length as usize)
+  };
+  let message = DebugMessage {
+    source: DebugSource::from_raw(source),
+    type_: DebugType::from_raw(type_),
+    id,
+    severity,
+    message: String::from_utf8_lossy(bytes).trim_end().to_string(),
+  };
+  // a panicking sink poisons the lock, but we can't unwind into the driver.
+  let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    if let Ok(mut sink) = state.sink.lock() {
+      sink(&message);
+    }
+  }));
+  if state.panic_on_error && message.type_ == DebugType::Error {
+    if let Ok(mut errors) = state.errors.lock() {
+      errors.push(message);
+    }
+  }
+}
+
+/// If the current context can do `KHR_debug`, by version or by extension.
+///
+/// ## Safety
+///
+/// `gl` must be loaded from the context that's current on this thread.
+pub unsafe fn has_khr_debug(gl: &Gl33) -> bool {
+  let (mut major, mut minor) = (0, 0);
+  gl.GetIntegerv(GL_MAJOR_VERSION, &mut major);
+  gl.GetIntegerv(GL_MINOR_VERSION, &mut minor);
+  if (major, minor) >= (4, 3) {
+    return true;
+  }
+  let mut count = 0;
+  gl.GetIntegerv(GL_NUM_EXTENSIONS, &mut count);
+  (0..count.max(0) as GLuint).any(|i| {
+    let name = gl.GetStringi(GL_EXTENSIONS, i);
+    !name.is_null()
+      && core::ffi::CStr::from_ptr(name.cast()).to_bytes() == b"GL_KHR_debug"
+  })
+}
+
+/// If the current context was made with the debug flag.
+///
+/// ## Safety
+///
+/// `gl` must be loaded from the context that's current on this thread.
+pub unsafe fn is_debug_context(gl: &Gl33) -> bool {
+  let mut flags = 0;
+  gl.GetIntegerv(GL_CONTEXT_FLAGS, &mut flags);
+  (flags as GLbitfield & GL_CONTEXT_FLAG_DEBUG_BIT) != 0
+}
+
+#[track_caller]
+fn panic_if_any(errors: Vec<DebugMessage>) {
+  if !errors.is_empty() {
+    let lines: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    panic!("GL debug errors:\n{}", lines.join("\n"));
+  }
+}
+
+/// A debug callback that's installed in a context.
+///
+/// The state stays allocated until [`remove`](Self::remove) is called with the
+/// same context current. If this is just dropped the state is leaked, since
+/// the driver might still call the callback.
+#[repr(transparent)]
+pub struct DebugCallback {
+  state: NonNull<DebugState>,
+}
+impl DebugCallback {
+  /// Installs the output as the debug callback of the current context.
+  ///
+  /// This enables `GL_DEBUG_OUTPUT` and `GL_DEBUG_OUTPUT_SYNCHRONOUS`, so
+  /// messages come during the GL call that caused them. Gives `None` if the
+  /// context doesn't have `KHR_debug`, or its functions weren't loaded.
+  /// Messages only reliably come from a context made with the debug flag, see
+  /// [`is_debug_context`].
+  ///
+  /// ## Safety
+  ///
+  /// `gl` must be loaded from the context that's current on this thread.
+  pub unsafe fn install(gl: &Gl33, output: DebugOutput) -> Option<Self> {
+    if !gl.loaded_GL_KHR_debug() || !has_khr_debug(gl) {
+      return None;
+    }
+    let state = Box::new(DebugState {
+      min_severity: output.min_severity,
+      sink: Mutex::new(output.sink),
+      panic_on_error: output.panic_on_error,
+      errors: Mutex::new(Vec::new()),
+    });
+    let state = NonNull::from(Box::leak(state));
+    gl.Enable(GL_DEBUG_OUTPUT);
+    gl.Enable(GL_DEBUG_OUTPUT_SYNCHRONOUS);
+    gl.DebugMessageCallback(
+      Some(debug_callback),
+      state.as_ptr().cast_const().cast(),
+    );
+    // turn everything on, then turn off what's below the minimum.
+    gl.DebugMessageControl(
+      GL_DONT_CARE,
+      GL_DONT_CARE,
+      GL_DONT_CARE,
+      0,
+      core::ptr::null(),
+      GL_TRUE as GLboolean,
+    );
+    for severity in
+      [DebugSeverity::Notification, DebugSeverity::Low, DebugSeverity::Medium]
+    {
+      if severity < output.min_severity {
+        gl.DebugMessageControl(
+          GL_DONT_CARE,
+          GL_DONT_CARE,
+          severity.to_raw(),
+          0,
+          core::ptr::null(),
+          GL_FALSE as GLboolean,
+        );
+      }
+    }
+    Some(Self { state })
+  }
+
+  /// Takes the saved error messages, if `panic_on_error` was set.
+  pub fn take_errors(&self) -> Vec<DebugMessage> {
+    let state = unsafe { self.state.as_ref() };
+    match state.errors.lock() {
+      Ok(mut errors) => core::mem::take(&mut *errors),
+      Err(_) => Vec::new(),
+    }
+  }
+
+  /// Panics if any errors were saved since the last check.
+  #[track_caller]
+  pub fn check(&self) {
+    panic_if_any(self.take_errors());
+  }
+
+  /// Uninstalls the callback from the current context and frees the state.
+  ///
+  /// This panics like [`check`](Self::check) if there are saved errors.
+  ///
+  /// ## Safety
+  ///
+  /// `gl` must be loaded from the context that's current on this thread.
+  /// It must be the context that the callback was installed into.
+  #[track_caller]
+  pub unsafe fn remove(self, gl: &Gl33) {
+    gl.DebugMessageCallback(None, core::ptr::null());
+    gl.Disable(GL_DEBUG_OUTPUT_SYNCHRONOUS);
+    gl.Disable(GL_DEBUG_OUTPUT);
+    let state = Box::from_raw(self.state.as_ptr());
+    panic_if_any(state.errors.into_inner().unwrap_or_default());
+  }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_debug_callback() {
+  use std::sync::Arc;
+  crate::test_util::with_headless_gl([8, 8], |gl| unsafe {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink_seen = seen.clone();
+    let callback = DebugCallback::install(
+      gl,
+      DebugOutput {
+        min_severity: DebugSeverity::Notification,
+        sink: Box::new(move |m| sink_seen.lock().unwrap().push(m.clone())),
+        panic_on_error: true,
+      },
+    )
+    .unwrap();
+
+    let text = "from the test";
+    gl.DebugMessageInsert(
+      GL_DEBUG_SOURCE_APPLICATION,
+      GL_DEBUG_TYPE_MARKER,
+      7,
+      GL_DEBUG_SEVERITY_NOTIFICATION,
+      text.len() as GLsizei,
+      text.as_ptr().cast(),
+    );
+    assert_eq!(
+      seen.lock().unwrap().last(),
+      Some(&DebugMessage {
+        source: DebugSource::Application,
+        type_: DebugType::Marker,
+        id: 7,
+        severity: DebugSeverity::Notification,
+        message: text.to_string(),
+      })
+    );
+    callback.check();
+
+    // any message of the error type is saved, and `check` panics with it.
+    let text = "an error";
+    gl.DebugMessageInsert(
+      GL_DEBUG_SOURCE_APPLICATION,
+      GL_DEBUG_TYPE_ERROR,
+      8,
+      GL_DEBUG_SEVERITY_HIGH,
+      text.len() as GLsizei,
+      text.as_ptr().cast(),
+    );
+    let result = std::panic::catch_unwind(|| callback.check());
+    let message = result.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("GL debug errors:"), "{}", message);
+    assert!(seen.lock().unwrap().iter().any(|m| m.type_ == DebugType::Error));
+    callback.remove(gl);
+  });
+}
@@ -0,0 +1,10293 @@
+//! Generated from `gl.xml` by the `gl_generator` crate.
+//!
+//! * API: `gl`
+//! * Version: `4.6`
+//! * Profile: `core`
+//! * Extension: `GL_KHR_debug`
+//!
+//! Don't edit this by hand, run `cargo run -p gl_generator` instead.
+
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use core::ffi::{
+  c_char, c_double, c_float, c_int, c_schar, c_short, c_uchar, c_uint, c_ushort,
+  c_void,
+};
+
+/// Opaque type from `gl.xml`
+#[repr(C)]
+pub struct __GLsync {
+  _private: [u8; 0],
+}
+
+/// From `gl.xml`
+pub type GLenum = c_uint;
+
+/// From `gl.xml`
+pub type GLboolean = c_uchar;
+
+/// From `gl.xml`
+pub type GLbitfield = c_uint;
+
+/// From `gl.xml`
+pub type GLvoid = c_void;
+
+/// From `gl.xml`
+pub type GLbyte = c_schar;
+
+/// From `gl.xml`
+pub type GLshort = c_short;
+
+/// From `gl.xml`
+pub type GLint = c_int;
+
+/// From `gl.xml`
+pub type GLclampx = c_int;
+
+/// From `gl.xml`
+pub type GLubyte = c_uchar;
+
+/// From `gl.xml`
+pub type GLushort = c_ushort;
+
+/// From `gl.xml`
+pub type GLuint = c_uint;
+
+/// From `gl.xml`
+pub type GLsizei = c_int;
+
+/// From `gl.xml`
+pub type GLfloat = c_float;
+
+/// From `gl.xml`
+pub type GLclampf = c_float;
+
+/// From `gl.xml`
+pub type GLdouble = c_double;
+
+/// From `gl.xml`
+pub type GLclampd = c_double;
+
+/// From `gl.xml`
+pub type GLeglClientBufferEXT = *mut c_void;
+
+/// From `gl.xml`
+pub type GLeglImageOES = *mut c_void;
+
+/// From `gl.xml`
+pub type GLchar = c_char;
+
+/// From `gl.xml`
+pub type GLcharARB = c_char;
+
+/// From `gl.xml`
+pub type GLhalfARB = c_ushort;
+
+/// From `gl.xml`
+pub type GLhalf = c_ushort;
+
+/// From `gl.xml`
+pub type GLfixed = GLint;
+
+/// From `gl.xml`
+pub type GLintptr = isize;
+
+/// From `gl.xml`
+pub type GLsizeiptr = isize;
+
+/// From `gl.xml`
+pub type GLint64 = i64;
+
+/// From `gl.xml`
+pub type GLuint64 = u64;
+
+/// From `gl.xml`
+pub type GLintptrARB = isize;
+
+/// From `gl.xml`
+pub type GLsizeiptrARB = isize;
+
+/// From `gl.xml`
+pub type GLint64EXT = i64;
+
+/// From `gl.xml`
+pub type GLuint64EXT = u64;
+
+/// From `gl.xml`
+pub type GLsync = *mut __GLsync;
+
+/// From `gl.xml`
+pub type GLDEBUGPROC = Option<
+  unsafe extern "system" fn(
+    source: GLenum,
+    type_: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    userParam: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`
+pub type GLDEBUGPROCARB = Option<
+  unsafe extern "system" fn(
+    source: GLenum,
+    type_: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    userParam: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`
+pub type GLDEBUGPROCKHR = Option<
+  unsafe extern "system" fn(
+    source: GLenum,
+    type_: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    userParam: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`
+pub type GLDEBUGPROCAMD = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    category: GLenum,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    userParam: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`
+pub type GLhalfNV = c_ushort;
+
+/// From `gl.xml`
+pub type GLvdpauSurfaceNV = GLintptr;
+
+/// From `gl.xml`
+pub type GLVULKANPROCNV = Option<unsafe extern "system" fn()>;
+
+pub const GL_ACTIVE_ATOMIC_COUNTER_BUFFERS: GLenum = 0x92D9;
+pub const GL_ACTIVE_ATTRIBUTES: GLenum = 0x8B89;
+pub const GL_ACTIVE_ATTRIBUTE_MAX_LENGTH: GLenum = 0x8B8A;
+pub const GL_ACTIVE_PROGRAM: GLenum = 0x8259;
+pub const GL_ACTIVE_RESOURCES: GLenum = 0x92F5;
+pub const GL_ACTIVE_SUBROUTINES: GLenum = 0x8DE5;
+pub const GL_ACTIVE_SUBROUTINE_MAX_LENGTH: GLenum = 0x8E48;
+pub const GL_ACTIVE_SUBROUTINE_UNIFORMS: GLenum = 0x8DE6;
+pub const GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS: GLenum = 0x8E47;
+pub const GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH: GLenum = 0x8E49;
+pub const GL_ACTIVE_TEXTURE: GLenum = 0x84E0;
+pub const GL_ACTIVE_UNIFORMS: GLenum = 0x8B86;
+pub const GL_ACTIVE_UNIFORM_BLOCKS: GLenum = 0x8A36;
+pub const GL_ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH: GLenum = 0x8A35;
+pub const GL_ACTIVE_UNIFORM_MAX_LENGTH: GLenum = 0x8B87;
+pub const GL_ACTIVE_VARIABLES: GLenum = 0x9305;
+pub const GL_ALIASED_LINE_WIDTH_RANGE: GLenum = 0x846E;
+pub const GL_ALL_BARRIER_BITS: GLbitfield = 0xFFFFFFFF;
+pub const GL_ALL_SHADER_BITS: GLbitfield = 0xFFFFFFFF;
+pub const GL_ALPHA: GLenum = 0x1906;
+pub const GL_ALREADY_SIGNALED: GLenum = 0x911A;
+pub const GL_ALWAYS: GLenum = 0x0207;
+pub const GL_AND: GLenum = 0x1501;
+pub const GL_AND_INVERTED: GLenum = 0x1504;
+pub const GL_AND_REVERSE: GLenum = 0x1502;
+pub const GL_ANY_SAMPLES_PASSED: GLenum = 0x8C2F;
+pub const GL_ANY_SAMPLES_PASSED_CONSERVATIVE: GLenum = 0x8D6A;
+pub const GL_ARRAY_BUFFER: GLenum = 0x8892;
+pub const GL_ARRAY_BUFFER_BINDING: GLenum = 0x8894;
+pub const GL_ARRAY_SIZE: GLenum = 0x92FB;
+pub const GL_ARRAY_STRIDE: GLenum = 0x92FE;
+pub const GL_ATOMIC_COUNTER_BARRIER_BIT: GLbitfield = 0x00001000;
+pub const GL_ATOMIC_COUNTER_BUFFER: GLenum = 0x92C0;
+pub const GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS: GLenum = 0x92C5;
+pub const GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTER_INDICES: GLenum = 0x92C6;
+pub const GL_ATOMIC_COUNTER_BUFFER_BINDING: GLenum = 0x92C1;
+pub const GL_ATOMIC_COUNTER_BUFFER_DATA_SIZE: GLenum = 0x92C4;
+pub const GL_ATOMIC_COUNTER_BUFFER_INDEX: GLenum = 0x9301;
+pub const GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER: GLenum = 0x90ED;
+pub const GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_FRAGMENT_SHADER: GLenum = 0x92CB;
+pub const GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_GEOMETRY_SHADER: GLenum = 0x92CA;
+pub const GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_CONTROL_SHADER: GLenum = 0x92C8;
+pub const GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_EVALUATION_SHADER: GLenum = 0x92C9;
+pub const GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_VERTEX_SHADER: GLenum = 0x92C7;
+pub const GL_ATOMIC_COUNTER_BUFFER_SIZE: GLenum = 0x92C3;
+pub const GL_ATOMIC_COUNTER_BUFFER_START: GLenum = 0x92C2;
+pub const GL_ATTACHED_SHADERS: GLenum = 0x8B85;
+pub const GL_AUTO_GENERATE_MIPMAP: GLenum = 0x8295;
+pub const GL_BACK: GLenum = 0x0405;
+pub const GL_BACK_LEFT: GLenum = 0x0402;
+pub const GL_BACK_RIGHT: GLenum = 0x0403;
+pub const GL_BGR: GLenum = 0x80E0;
+pub const GL_BGRA: GLenum = 0x80E1;
+pub const GL_BGRA_INTEGER: GLenum = 0x8D9B;
+pub const GL_BGR_INTEGER: GLenum = 0x8D9A;
+pub const GL_BLEND: GLenum = 0x0BE2;
+pub const GL_BLEND_COLOR: GLenum = 0x8005;
+pub const GL_BLEND_DST: GLenum = 0x0BE0;
+pub const GL_BLEND_DST_ALPHA: GLenum = 0x80CA;
+pub const GL_BLEND_DST_RGB: GLenum = 0x80C8;
+pub const GL_BLEND_EQUATION: GLenum = 0x8009;
+pub const GL_BLEND_EQUATION_ALPHA: GLenum = 0x883D;
+pub const GL_BLEND_EQUATION_RGB: GLenum = 0x8009;
+pub const GL_BLEND_SRC: GLenum = 0x0BE1;
+pub const GL_BLEND_SRC_ALPHA: GLenum = 0x80CB;
+pub const GL_BLEND_SRC_RGB: GLenum = 0x80C9;
+pub const GL_BLOCK_INDEX: GLenum = 0x92FD;
+pub const GL_BLUE: GLenum = 0x1905;
+pub const GL_BLUE_INTEGER: GLenum = 0x8D96;
+pub const GL_BOOL: GLenum = 0x8B56;
+pub const GL_BOOL_VEC2: GLenum = 0x8B57;
+pub const GL_BOOL_VEC3: GLenum = 0x8B58;
+pub const GL_BOOL_VEC4: GLenum = 0x8B59;
+pub const GL_BUFFER: GLenum = 0x82E0;
+pub const GL_BUFFER_ACCESS: GLenum = 0x88BB;
+pub const GL_BUFFER_ACCESS_FLAGS: GLenum = 0x911F;
+pub const GL_BUFFER_BINDING: GLenum = 0x9302;
+pub const GL_BUFFER_DATA_SIZE: GLenum = 0x9303;
+pub const GL_BUFFER_IMMUTABLE_STORAGE: GLenum = 0x821F;
+pub const GL_BUFFER_MAPPED: GLenum = 0x88BC;
+pub const GL_BUFFER_MAP_LENGTH: GLenum = 0x9120;
+pub const GL_BUFFER_MAP_OFFSET: GLenum = 0x9121;
+pub const GL_BUFFER_MAP_POINTER: GLenum = 0x88BD;
+pub const GL_BUFFER_SIZE: GLenum = 0x8764;
+pub const GL_BUFFER_STORAGE_FLAGS: GLenum = 0x8220;
+pub const GL_BUFFER_UPDATE_BARRIER_BIT: GLbitfield = 0x00000200;
+pub const GL_BUFFER_USAGE: GLenum = 0x8765;
+pub const GL_BUFFER_VARIABLE: GLenum = 0x92E5;
+pub const GL_BYTE: GLenum = 0x1400;
+pub const GL_CAVEAT_SUPPORT: GLenum = 0x82B8;
+pub const GL_CCW: GLenum = 0x0901;
+pub const GL_CLAMP_READ_COLOR: GLenum = 0x891C;
+pub const GL_CLAMP_TO_BORDER: GLenum = 0x812D;
+pub const GL_CLAMP_TO_EDGE: GLenum = 0x812F;
+pub const GL_CLEAR: GLenum = 0x1500;
+pub const GL_CLEAR_BUFFER: GLenum = 0x82B4;
+pub const GL_CLEAR_TEXTURE: GLenum = 0x9365;
+pub const GL_CLIENT_MAPPED_BUFFER_BARRIER_BIT: GLbitfield = 0x00004000;
+pub const GL_CLIENT_STORAGE_BIT: GLbitfield = 0x0200;
+pub const GL_CLIPPING_INPUT_PRIMITIVES: GLenum = 0x82F6;
+pub const GL_CLIPPING_OUTPUT_PRIMITIVES: GLenum = 0x82F7;
+pub const GL_CLIP_DEPTH_MODE: GLenum = 0x935D;
+pub const GL_CLIP_DISTANCE0: GLenum = 0x3000;
+pub const GL_CLIP_DISTANCE1: GLenum = 0x3001;
+pub const GL_CLIP_DISTANCE2: GLenum = 0x3002;
+pub const GL_CLIP_DISTANCE3: GLenum = 0x3003;
+pub const GL_CLIP_DISTANCE4: GLenum = 0x3004;
+pub const GL_CLIP_DISTANCE5: GLenum = 0x3005;
+pub const GL_CLIP_DISTANCE6: GLenum = 0x3006;
+pub const GL_CLIP_DISTANCE7: GLenum = 0x3007;
+pub const GL_CLIP_ORIGIN: GLenum = 0x935C;
+pub const GL_COLOR: GLenum = 0x1800;
+pub const GL_COLOR_ATTACHMENT0: GLenum = 0x8CE0;
+pub const GL_COLOR_ATTACHMENT1: GLenum = 0x8CE1;
+pub const GL_COLOR_ATTACHMENT10: GLenum = 0x8CEA;
+pub const GL_COLOR_ATTACHMENT11: GLenum = 0x8CEB;
+pub const GL_COLOR_ATTACHMENT12: GLenum = 0x8CEC;
+pub const GL_COLOR_ATTACHMENT13: GLenum = 0x8CED;
+pub const GL_COLOR_ATTACHMENT14: GLenum = 0x8CEE;
+pub const GL_COLOR_ATTACHMENT15: GLenum = 0x8CEF;
+pub const GL_COLOR_ATTACHMENT16: GLenum = 0x8CF0;
+pub const GL_COLOR_ATTACHMENT17: GLenum = 0x8CF1;
+pub const GL_COLOR_ATTACHMENT18: GLenum = 0x8CF2;
+pub const GL_COLOR_ATTACHMENT19: GLenum = 0x8CF3;
+pub const GL_COLOR_ATTACHMENT2: GLenum = 0x8CE2;
+pub const GL_COLOR_ATTACHMENT20: GLenum = 0x8CF4;
+pub const GL_COLOR_ATTACHMENT21: GLenum = 0x8CF5;
+pub const GL_COLOR_ATTACHMENT22: GLenum = 0x8CF6;
+pub const GL_COLOR_ATTACHMENT23: GLenum = 0x8CF7;
+pub const GL_COLOR_ATTACHMENT24: GLenum = 0x8CF8;
+pub const GL_COLOR_ATTACHMENT25: GLenum = 0x8CF9;
+pub const GL_COLOR_ATTACHMENT26: GLenum = 0x8CFA;
+pub const GL_COLOR_ATTACHMENT27: GLenum = 0x8CFB;
+pub const GL_COLOR_ATTACHMENT28: GLenum = 0x8CFC;
+pub const GL_COLOR_ATTACHMENT29: GLenum = 0x8CFD;
+pub const GL_COLOR_ATTACHMENT3: GLenum = 0x8CE3;
+pub const GL_COLOR_ATTACHMENT30: GLenum = 0x8CFE;
+pub const GL_COLOR_ATTACHMENT31: GLenum = 0x8CFF;
+pub const GL_COLOR_ATTACHMENT4: GLenum = 0x8CE4;
+pub const GL_COLOR_ATTACHMENT5: GLenum = 0x8CE5;
+pub const GL_COLOR_ATTACHMENT6: GLenum = 0x8CE6;
+pub const GL_COLOR_ATTACHMENT7: GLenum = 0x8CE7;
+pub const GL_COLOR_ATTACHMENT8: GLenum = 0x8CE8;
+pub const GL_COLOR_ATTACHMENT9: GLenum = 0x8CE9;
+pub const GL_COLOR_BUFFER_BIT: GLbitfield = 0x00004000;
+pub const GL_COLOR_CLEAR_VALUE: GLenum = 0x0C22;
+pub const GL_COLOR_COMPONENTS: GLenum = 0x8283;
+pub const GL_COLOR_ENCODING: GLenum = 0x8296;
+pub const GL_COLOR_LOGIC_OP: GLenum = 0x0BF2;
+pub const GL_COLOR_RENDERABLE: GLenum = 0x8286;
+pub const GL_COLOR_WRITEMASK: GLenum = 0x0C23;
+pub const GL_COMMAND_BARRIER_BIT: GLbitfield = 0x00000040;
+pub const GL_COMPARE_REF_TO_TEXTURE: GLenum = 0x884E;
+pub const GL_COMPATIBLE_SUBROUTINES: GLenum = 0x8E4B;
+pub const GL_COMPILE_STATUS: GLenum = 0x8B81;
+pub const GL_COMPRESSED_R11_EAC: GLenum = 0x9270;
+pub const GL_COMPRESSED_RED: GLenum = 0x8225;
+pub const GL_COMPRESSED_RED_RGTC1: GLenum = 0x8DBB;
+pub const GL_COMPRESSED_RG: GLenum = 0x8226;
+pub const GL_COMPRESSED_RG11_EAC: GLenum = 0x9272;
+pub const GL_COMPRESSED_RGB: GLenum = 0x84ED;
+pub const GL_COMPRESSED_RGB8_ETC2: GLenum = 0x9274;
+pub const GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2: GLenum = 0x9276;
+pub const GL_COMPRESSED_RGBA: GLenum = 0x84EE;
+pub const GL_COMPRESSED_RGBA8_ETC2_EAC: GLenum = 0x9278;
+pub const GL_COMPRESSED_RGBA_BPTC_UNORM: GLenum = 0x8E8C;
+pub const GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT: GLenum = 0x8E8E;
+pub const GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT: GLenum = 0x8E8F;
+pub const GL_COMPRESSED_RG_RGTC2: GLenum = 0x8DBD;
+pub const GL_COMPRESSED_SIGNED_R11_EAC: GLenum = 0x9271;
+pub const GL_COMPRESSED_SIGNED_RED_RGTC1: GLenum = 0x8DBC;
+pub const GL_COMPRESSED_SIGNED_RG11_EAC: GLenum = 0x9273;
+pub const GL_COMPRESSED_SIGNED_RG_RGTC2: GLenum = 0x8DBE;
+pub const GL_COMPRESSED_SRGB: GLenum = 0x8C48;
+pub const GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC: GLenum = 0x9279;
+pub const GL_COMPRESSED_SRGB8_ETC2: GLenum = 0x9275;
+pub const GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2: GLenum = 0x9277;
+pub const GL_COMPRESSED_SRGB_ALPHA: GLenum = 0x8C49;
+pub const GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM: GLenum = 0x8E8D;
+pub const GL_COMPRESSED_TEXTURE_FORMATS: GLenum = 0x86A3;
+pub const GL_COMPUTE_SHADER: GLenum = 0x91B9;
+pub const GL_COMPUTE_SHADER_BIT: GLbitfield = 0x00000020;
+pub const GL_COMPUTE_SHADER_INVOCATIONS: GLenum = 0x82F5;
+pub const GL_COMPUTE_SUBROUTINE: GLenum = 0x92ED;
+pub const GL_COMPUTE_SUBROUTINE_UNIFORM: GLenum = 0x92F3;
+pub const GL_COMPUTE_TEXTURE: GLenum = 0x82A0;
+pub const GL_COMPUTE_WORK_GROUP_SIZE: GLenum = 0x8267;
+pub const GL_CONDITION_SATISFIED: GLenum = 0x911C;
+pub const GL_CONSTANT_ALPHA: GLenum = 0x8003;
+pub const GL_CONSTANT_COLOR: GLenum = 0x8001;
+pub const GL_CONTEXT_COMPATIBILITY_PROFILE_BIT: GLbitfield = 0x00000002;
+pub const GL_CONTEXT_CORE_PROFILE_BIT: GLbitfield = 0x00000001;
+pub const GL_CONTEXT_FLAGS: GLenum = 0x821E;
+pub const GL_CONTEXT_FLAG_DEBUG_BIT: GLbitfield = 0x00000002;
+pub const GL_CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT: GLbitfield = 0x00000001;
+pub const GL_CONTEXT_FLAG_NO_ERROR_BIT: GLbitfield = 0x00000008;
+pub const GL_CONTEXT_FLAG_ROBUST_ACCESS_BIT: GLbitfield = 0x00000004;
+pub const GL_CONTEXT_LOST: GLenum = 0x0507;
+pub const GL_CONTEXT_PROFILE_MASK: GLenum = 0x9126;
+pub const GL_CONTEXT_RELEASE_BEHAVIOR: GLenum = 0x82FB;
+pub const GL_CONTEXT_RELEASE_BEHAVIOR_FLUSH: GLenum = 0x82FC;
+pub const GL_COPY: GLenum = 0x1503;
+pub const GL_COPY_INVERTED: GLenum = 0x150C;
+pub const GL_COPY_READ_BUFFER: GLenum = 0x8F36;
+pub const GL_COPY_READ_BUFFER_BINDING: GLenum = 0x8F36;
+pub const GL_COPY_WRITE_BUFFER: GLenum = 0x8F37;
+pub const GL_COPY_WRITE_BUFFER_BINDING: GLenum = 0x8F37;
+pub const GL_CULL_FACE: GLenum = 0x0B44;
+pub const GL_CULL_FACE_MODE: GLenum = 0x0B45;
+pub const GL_CURRENT_PROGRAM: GLenum = 0x8B8D;
+pub const GL_CURRENT_QUERY: GLenum = 0x8865;
+pub const GL_CURRENT_VERTEX_ATTRIB: GLenum = 0x8626;
+pub const GL_CW: GLenum = 0x0900;
+pub const GL_DEBUG_CALLBACK_FUNCTION: GLenum = 0x8244;
+pub const GL_DEBUG_CALLBACK_USER_PARAM: GLenum = 0x8245;
+pub const GL_DEBUG_GROUP_STACK_DEPTH: GLenum = 0x826D;
+pub const GL_DEBUG_LOGGED_MESSAGES: GLenum = 0x9145;
+pub const GL_DEBUG_NEXT_LOGGED_MESSAGE_LENGTH: GLenum = 0x8243;
+pub const GL_DEBUG_OUTPUT: GLenum = 0x92E0;
+pub const GL_DEBUG_OUTPUT_SYNCHRONOUS: GLenum = 0x8242;
+pub const GL_DEBUG_SEVERITY_HIGH: GLenum = 0x9146;
+pub const GL_DEBUG_SEVERITY_LOW: GLenum = 0x9148;
+pub const GL_DEBUG_SEVERITY_MEDIUM: GLenum = 0x9147;
+pub const GL_DEBUG_SEVERITY_NOTIFICATION: GLenum = 0x826B;
+pub const GL_DEBUG_SOURCE_API: GLenum = 0x8246;
+pub const GL_DEBUG_SOURCE_APPLICATION: GLenum = 0x824A;
+pub const GL_DEBUG_SOURCE_OTHER: GLenum = 0x824B;
+pub const GL_DEBUG_SOURCE_SHADER_COMPILER: GLenum = 0x8248;
+pub const GL_DEBUG_SOURCE_THIRD_PARTY: GLenum = 0x8249;
+pub const GL_DEBUG_SOURCE_WINDOW_SYSTEM: GLenum = 0x8247;
+pub const GL_DEBUG_TYPE_DEPRECATED_BEHAVIOR: GLenum = 0x824D;
+pub const GL_DEBUG_TYPE_ERROR: GLenum = 0x824C;
+pub const GL_DEBUG_TYPE_MARKER: GLenum = 0x8268;
+pub const GL_DEBUG_TYPE_OTHER: GLenum = 0x8251;
+pub const GL_DEBUG_TYPE_PERFORMANCE: GLenum = 0x8250;
+pub const GL_DEBUG_TYPE_POP_GROUP: GLenum = 0x826A;
+pub const GL_DEBUG_TYPE_PORTABILITY: GLenum = 0x824F;
+pub const GL_DEBUG_TYPE_PUSH_GROUP: GLenum = 0x8269;
+pub const GL_DEBUG_TYPE_UNDEFINED_BEHAVIOR: GLenum = 0x824E;
+pub const GL_DECR: GLenum = 0x1E03;
+pub const GL_DECR_WRAP: GLenum = 0x8508;
+pub const GL_DELETE_STATUS: GLenum = 0x8B80;
+pub const GL_DEPTH: GLenum = 0x1801;
+pub const GL_DEPTH24_STENCIL8: GLenum = 0x88F0;
+pub const GL_DEPTH32F_STENCIL8: GLenum = 0x8CAD;
+pub const GL_DEPTH_ATTACHMENT: GLenum = 0x8D00;
+pub const GL_DEPTH_BUFFER_BIT: GLbitfield = 0x00000100;
+pub const GL_DEPTH_CLAMP: GLenum = 0x864F;
+pub const GL_DEPTH_CLEAR_VALUE: GLenum = 0x0B73;
+pub const GL_DEPTH_COMPONENT: GLenum = 0x1902;
+pub const GL_DEPTH_COMPONENT16: GLenum = 0x81A5;
+pub const GL_DEPTH_COMPONENT24: GLenum = 0x81A6;
+pub const GL_DEPTH_COMPONENT32: GLenum = 0x81A7;
+pub const GL_DEPTH_COMPONENT32F: GLenum = 0x8CAC;
+pub const GL_DEPTH_COMPONENTS: GLenum = 0x8284;
+pub const GL_DEPTH_FUNC: GLenum = 0x0B74;
+pub const GL_DEPTH_RANGE: GLenum = 0x0B70;
+pub const GL_DEPTH_RENDERABLE: GLenum = 0x8287;
+pub const GL_DEPTH_STENCIL: GLenum = 0x84F9;
+pub const GL_DEPTH_STENCIL_ATTACHMENT: GLenum = 0x821A;
+pub const GL_DEPTH_STENCIL_TEXTURE_MODE: GLenum = 0x90EA;
+pub const GL_DEPTH_TEST: GLenum = 0x0B71;
+pub const GL_DEPTH_WRITEMASK: GLenum = 0x0B72;
+pub const GL_DISPATCH_INDIRECT_BUFFER: GLenum = 0x90EE;
+pub const GL_DISPATCH_INDIRECT_BUFFER_BINDING: GLenum = 0x90EF;
+pub const GL_DITHER: GLenum = 0x0BD0;
+pub const GL_DONT_CARE: GLenum = 0x1100;
+pub const GL_DOUBLE: GLenum = 0x140A;
+pub const GL_DOUBLEBUFFER: GLenum = 0x0C32;
+pub const GL_DOUBLE_MAT2: GLenum = 0x8F46;
+pub const GL_DOUBLE_MAT2x3: GLenum = 0x8F49;
+pub const GL_DOUBLE_MAT2x4: GLenum = 0x8F4A;
+pub const GL_DOUBLE_MAT3: GLenum = 0x8F47;
+pub const GL_DOUBLE_MAT3x2: GLenum = 0x8F4B;
+pub const GL_DOUBLE_MAT3x4: GLenum = 0x8F4C;
+pub const GL_DOUBLE_MAT4: GLenum = 0x8F48;
+pub const GL_DOUBLE_MAT4x2: GLenum = 0x8F4D;
+pub const GL_DOUBLE_MAT4x3: GLenum = 0x8F4E;
+pub const GL_DOUBLE_VEC2: GLenum = 0x8FFC;
+pub const GL_DOUBLE_VEC3: GLenum = 0x8FFD;
+pub const GL_DOUBLE_VEC4: GLenum = 0x8FFE;
+pub const GL_DRAW_BUFFER: GLenum = 0x0C01;
+pub const GL_DRAW_BUFFER0: GLenum = 0x8825;
+pub const GL_DRAW_BUFFER1: GLenum = 0x8826;
+pub const GL_DRAW_BUFFER10: GLenum = 0x882F;
+pub const GL_DRAW_BUFFER11: GLenum = 0x8830;
+pub const GL_DRAW_BUFFER12: GLenum = 0x8831;
+pub const GL_DRAW_BUFFER13: GLenum = 0x8832;
+pub const GL_DRAW_BUFFER14: GLenum = 0x8833;
+pub const GL_DRAW_BUFFER15: GLenum = 0x8834;
+pub const GL_DRAW_BUFFER2: GLenum = 0x8827;
+pub const GL_DRAW_BUFFER3: GLenum = 0x8828;
+pub const GL_DRAW_BUFFER4: GLenum = 0x8829;
+pub const GL_DRAW_BUFFER5: GLenum = 0x882A;
+pub const GL_DRAW_BUFFER6: GLenum = 0x882B;
+pub const GL_DRAW_BUFFER7: GLenum = 0x882C;
+pub const GL_DRAW_BUFFER8: GLenum = 0x882D;
+pub const GL_DRAW_BUFFER9: GLenum = 0x882E;
+pub const GL_DRAW_FRAMEBUFFER: GLenum = 0x8CA9;
+pub const GL_DRAW_FRAMEBUFFER_BINDING: GLenum = 0x8CA6;
+pub const GL_DRAW_INDIRECT_BUFFER: GLenum = 0x8F3F;
+pub const GL_DRAW_INDIRECT_BUFFER_BINDING: GLenum = 0x8F43;
+pub const GL_DST_ALPHA: GLenum = 0x0304;
+pub const GL_DST_COLOR: GLenum = 0x0306;
+pub const GL_DYNAMIC_COPY: GLenum = 0x88EA;
+pub const GL_DYNAMIC_DRAW: GLenum = 0x88E8;
+pub const GL_DYNAMIC_READ: GLenum = 0x88E9;
+pub const GL_DYNAMIC_STORAGE_BIT: GLbitfield = 0x0100;
+pub const GL_ELEMENT_ARRAY_BARRIER_BIT: GLbitfield = 0x00000002;
+pub const GL_ELEMENT_ARRAY_BUFFER: GLenum = 0x8893;
+pub const GL_ELEMENT_ARRAY_BUFFER_BINDING: GLenum = 0x8895;
+pub const GL_EQUAL: GLenum = 0x0202;
+pub const GL_EQUIV: GLenum = 0x1509;
+pub const GL_EXTENSIONS: GLenum = 0x1F03;
+pub const GL_FALSE: GLenum = 0;
+pub const GL_FASTEST: GLenum = 0x1101;
+pub const GL_FILL: GLenum = 0x1B02;
+pub const GL_FILTER: GLenum = 0x829A;
+pub const GL_FIRST_VERTEX_CONVENTION: GLenum = 0x8E4D;
+pub const GL_FIXED: GLenum = 0x140C;
+pub const GL_FIXED_ONLY: GLenum = 0x891D;
+pub const GL_FLOAT: GLenum = 0x1406;
+pub const GL_FLOAT_32_UNSIGNED_INT_24_8_REV: GLenum = 0x8DAD;
+pub const GL_FLOAT_MAT2: GLenum = 0x8B5A;
+pub const GL_FLOAT_MAT2x3: GLenum = 0x8B65;
+pub const GL_FLOAT_MAT2x4: GLenum = 0x8B66;
+pub const GL_FLOAT_MAT3: GLenum = 0x8B5B;
+pub const GL_FLOAT_MAT3x2: GLenum = 0x8B67;
+pub const GL_FLOAT_MAT3x4: GLenum = 0x8B68;
+pub const GL_FLOAT_MAT4: GLenum = 0x8B5C;
+pub const GL_FLOAT_MAT4x2: GLenum = 0x8B69;
+pub const GL_FLOAT_MAT4x3: GLenum = 0x8B6A;
+pub const GL_FLOAT_VEC2: GLenum = 0x8B50;
+pub const GL_FLOAT_VEC3: GLenum = 0x8B51;
+pub const GL_FLOAT_VEC4: GLenum = 0x8B52;
+pub const GL_FRACTIONAL_EVEN: GLenum = 0x8E7C;
+pub const GL_FRACTIONAL_ODD: GLenum = 0x8E7B;
+pub const GL_FRAGMENT_INTERPOLATION_OFFSET_BITS: GLenum = 0x8E5D;
+pub const GL_FRAGMENT_SHADER: GLenum = 0x8B30;
+pub const GL_FRAGMENT_SHADER_BIT: GLbitfield = 0x00000002;
+pub const GL_FRAGMENT_SHADER_DERIVATIVE_HINT: GLenum = 0x8B8B;
+pub const GL_FRAGMENT_SHADER_INVOCATIONS: GLenum = 0x82F4;
+pub const GL_FRAGMENT_SUBROUTINE: GLenum = 0x92EC;
+pub const GL_FRAGMENT_SUBROUTINE_UNIFORM: GLenum = 0x92F2;
+pub const GL_FRAGMENT_TEXTURE: GLenum = 0x829F;
+pub const GL_FRAMEBUFFER: GLenum = 0x8D40;
+pub const GL_FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE: GLenum = 0x8215;
+pub const GL_FRAMEBUFFER_ATTACHMENT_BLUE_SIZE: GLenum = 0x8214;
+pub const GL_FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING: GLenum = 0x8210;
+pub const GL_FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE: GLenum = 0x8211;
+pub const GL_FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE: GLenum = 0x8216;
+pub const GL_FRAMEBUFFER_ATTACHMENT_GREEN_SIZE: GLenum = 0x8213;
+pub const GL_FRAMEBUFFER_ATTACHMENT_LAYERED: GLenum = 0x8DA7;
+pub const GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME: GLenum = 0x8CD1;
+pub const GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE: GLenum = 0x8CD0;
+pub const GL_FRAMEBUFFER_ATTACHMENT_RED_SIZE: GLenum = 0x8212;
+pub const GL_FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE: GLenum = 0x8217;
+pub const GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_CUBE_MAP_FACE: GLenum = 0x8CD3;
+pub const GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LAYER: GLenum = 0x8CD4;
+pub const GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL: GLenum = 0x8CD2;
+pub const GL_FRAMEBUFFER_BARRIER_BIT: GLbitfield = 0x00000400;
+pub const GL_FRAMEBUFFER_BINDING: GLenum = 0x8CA6;
+pub const GL_FRAMEBUFFER_BLEND: GLenum = 0x828B;
+pub const GL_FRAMEBUFFER_COMPLETE: GLenum = 0x8CD5;
+pub const GL_FRAMEBUFFER_DEFAULT: GLenum = 0x8218;
+pub const GL_FRAMEBUFFER_DEFAULT_FIXED_SAMPLE_LOCATIONS: GLenum = 0x9314;
+pub const GL_FRAMEBUFFER_DEFAULT_HEIGHT: GLenum = 0x9311;
+pub const GL_FRAMEBUFFER_DEFAULT_LAYERS: GLenum = 0x9312;
+pub const GL_FRAMEBUFFER_DEFAULT_SAMPLES: GLenum = 0x9313;
+pub const GL_FRAMEBUFFER_DEFAULT_WIDTH: GLenum = 0x9310;
+pub const GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT: GLenum = 0x8CD6;
+pub const GL_FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER: GLenum = 0x8CDB;
+pub const GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS: GLenum = 0x8DA8;
+pub const GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT: GLenum = 0x8CD7;
+pub const GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE: GLenum = 0x8D56;
+pub const GL_FRAMEBUFFER_INCOMPLETE_READ_BUFFER: GLenum = 0x8CDC;
+pub const GL_FRAMEBUFFER_RENDERABLE: GLenum = 0x8289;
+pub const GL_FRAMEBUFFER_RENDERABLE_LAYERED: GLenum = 0x828A;
+pub const GL_FRAMEBUFFER_SRGB: GLenum = 0x8DB9;
+pub const GL_FRAMEBUFFER_UNDEFINED: GLenum = 0x8219;
+pub const GL_FRAMEBUFFER_UNSUPPORTED: GLenum = 0x8CDD;
+pub const GL_FRONT: GLenum = 0x0404;
+pub const GL_FRONT_AND_BACK: GLenum = 0x0408;
+pub const GL_FRONT_FACE: GLenum = 0x0B46;
+pub const GL_FRONT_LEFT: GLenum = 0x0400;
+pub const GL_FRONT_RIGHT: GLenum = 0x0401;
+pub const GL_FULL_SUPPORT: GLenum = 0x82B7;
+pub const GL_FUNC_ADD: GLenum = 0x8006;
+pub const GL_FUNC_REVERSE_SUBTRACT: GLenum = 0x800B;
+pub const GL_FUNC_SUBTRACT: GLenum = 0x800A;
+pub const GL_GEOMETRY_INPUT_TYPE: GLenum = 0x8917;
+pub const GL_GEOMETRY_OUTPUT_TYPE: GLenum = 0x8918;
+pub const GL_GEOMETRY_SHADER: GLenum = 0x8DD9;
+pub const GL_GEOMETRY_SHADER_BIT: GLbitfield = 0x00000004;
+pub const GL_GEOMETRY_SHADER_INVOCATIONS: GLenum = 0x887F;
+pub const GL_GEOMETRY_SHADER_PRIMITIVES_EMITTED: GLenum = 0x82F3;
+pub const GL_GEOMETRY_SUBROUTINE: GLenum = 0x92EB;
+pub const GL_GEOMETRY_SUBROUTINE_UNIFORM: GLenum = 0x92F1;
+pub const GL_GEOMETRY_TEXTURE: GLenum = 0x829E;
+pub const GL_GEOMETRY_VERTICES_OUT: GLenum = 0x8916;
+pub const GL_GEQUAL: GLenum = 0x0206;
+pub const GL_GET_TEXTURE_IMAGE_FORMAT: GLenum = 0x8291;
+pub const GL_GET_TEXTURE_IMAGE_TYPE: GLenum = 0x8292;
+pub const GL_GREATER: GLenum = 0x0204;
+pub const GL_GREEN: GLenum = 0x1904;
+pub const GL_GREEN_INTEGER: GLenum = 0x8D95;
+pub const GL_GUILTY_CONTEXT_RESET: GLenum = 0x8253;
+pub const GL_HALF_FLOAT: GLenum = 0x140B;
+pub const GL_HIGH_FLOAT: GLenum = 0x8DF2;
+pub const GL_HIGH_INT: GLenum = 0x8DF5;
+pub const GL_IMAGE_1D: GLenum = 0x904C;
+pub const GL_IMAGE_1D_ARRAY: GLenum = 0x9052;
+pub const GL_IMAGE_2D: GLenum = 0x904D;
+pub const GL_IMAGE_2D_ARRAY: GLenum = 0x9053;
+pub const GL_IMAGE_2D_MULTISAMPLE: GLenum = 0x9055;
+pub const GL_IMAGE_2D_MULTISAMPLE_ARRAY: GLenum = 0x9056;
+pub const GL_IMAGE_2D_RECT: GLenum = 0x904F;
+pub const GL_IMAGE_3D: GLenum = 0x904E;
+pub const GL_IMAGE_BINDING_ACCESS: GLenum = 0x8F3E;
+pub const GL_IMAGE_BINDING_FORMAT: GLenum = 0x906E;
+pub const GL_IMAGE_BINDING_LAYER: GLenum = 0x8F3D;
+pub const GL_IMAGE_BINDING_LAYERED: GLenum = 0x8F3C;
+pub const GL_IMAGE_BINDING_LEVEL: GLenum = 0x8F3B;
+pub const GL_IMAGE_BINDING_NAME: GLenum = 0x8F3A;
+pub const GL_IMAGE_BUFFER: GLenum = 0x9051;
+pub const GL_IMAGE_CLASS_10_10_10_2: GLenum = 0x82C3;
+pub const GL_IMAGE_CLASS_11_11_10: GLenum = 0x82C2;
+pub const GL_IMAGE_CLASS_1_X_16: GLenum = 0x82BE;
+pub const GL_IMAGE_CLASS_1_X_32: GLenum = 0x82BB;
+pub const GL_IMAGE_CLASS_1_X_8: GLenum = 0x82C1;
+pub const GL_IMAGE_CLASS_2_X_16: GLenum = 0x82BD;
+pub const GL_IMAGE_CLASS_2_X_32: GLenum = 0x82BA;
+pub const GL_IMAGE_CLASS_2_X_8: GLenum = 0x82C0;
+pub const GL_IMAGE_CLASS_4_X_16: GLenum = 0x82BC;
+pub const GL_IMAGE_CLASS_4_X_32: GLenum = 0x82B9;
+pub const GL_IMAGE_CLASS_4_X_8: GLenum = 0x82BF;
+pub const GL_IMAGE_COMPATIBILITY_CLASS: GLenum = 0x82A8;
+pub const GL_IMAGE_CUBE: GLenum = 0x9050;
+pub const GL_IMAGE_CUBE_MAP_ARRAY: GLenum = 0x9054;
+pub const GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS: GLenum = 0x90C9;
+pub const GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE: GLenum = 0x90C8;
+pub const GL_IMAGE_FORMAT_COMPATIBILITY_TYPE: GLenum = 0x90C7;
+pub const GL_IMAGE_PIXEL_FORMAT: GLenum = 0x82A9;
+pub const GL_IMAGE_PIXEL_TYPE: GLenum = 0x82AA;
+pub const GL_IMAGE_TEXEL_SIZE: GLenum = 0x82A7;
+pub const GL_IMPLEMENTATION_COLOR_READ_FORMAT: GLenum = 0x8B9B;
+pub const GL_IMPLEMENTATION_COLOR_READ_TYPE: GLenum = 0x8B9A;
+pub const GL_INCR: GLenum = 0x1E02;
+pub const GL_INCR_WRAP: GLenum = 0x8507;
+pub const GL_INFO_LOG_LENGTH: GLenum = 0x8B84;
+pub const GL_INNOCENT_CONTEXT_RESET: GLenum = 0x8254;
+pub const GL_INT: GLenum = 0x1404;
+pub const GL_INTERLEAVED_ATTRIBS: GLenum = 0x8C8C;
+pub const GL_INTERNALFORMAT_ALPHA_SIZE: GLenum = 0x8274;
+pub const GL_INTERNALFORMAT_ALPHA_TYPE: GLenum = 0x827B;
+pub const GL_INTERNALFORMAT_BLUE_SIZE: GLenum = 0x8273;
+pub const GL_INTERNALFORMAT_BLUE_TYPE: GLenum = 0x827A;
+pub const GL_INTERNALFORMAT_DEPTH_SIZE: GLenum = 0x8275;
+pub const GL_INTERNALFORMAT_DEPTH_TYPE: GLenum = 0x827C;
+pub const GL_INTERNALFORMAT_GREEN_SIZE: GLenum = 0x8272;
+pub const GL_INTERNALFORMAT_GREEN_TYPE: GLenum = 0x8279;
+pub const GL_INTERNALFORMAT_PREFERRED: GLenum = 0x8270;
+pub const GL_INTERNALFORMAT_RED_SIZE: GLenum = 0x8271;
+pub const GL_INTERNALFORMAT_RED_TYPE: GLenum = 0x8278;
+pub const GL_INTERNALFORMAT_SHARED_SIZE: GLenum = 0x8277;
+pub const GL_INTERNALFORMAT_STENCIL_SIZE: GLenum = 0x8276;
+pub const GL_INTERNALFORMAT_STENCIL_TYPE: GLenum = 0x827D;
+pub const GL_INTERNALFORMAT_SUPPORTED: GLenum = 0x826F;
+pub const GL_INT_2_10_10_10_REV: GLenum = 0x8D9F;
+pub const GL_INT_IMAGE_1D: GLenum = 0x9057;
+pub const GL_INT_IMAGE_1D_ARRAY: GLenum = 0x905D;
+pub const GL_INT_IMAGE_2D: GLenum = 0x9058;
+pub const GL_INT_IMAGE_2D_ARRAY: GLenum = 0x905E;
+pub const GL_INT_IMAGE_2D_MULTISAMPLE: GLenum = 0x9060;
+pub const GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY: GLenum = 0x9061;
+pub const GL_INT_IMAGE_2D_RECT: GLenum = 0x905A;
+pub const GL_INT_IMAGE_3D: GLenum = 0x9059;
+pub const GL_INT_IMAGE_BUFFER: GLenum = 0x905C;
+pub const GL_INT_IMAGE_CUBE: GLenum = 0x905B;
+pub const GL_INT_IMAGE_CUBE_MAP_ARRAY: GLenum = 0x905F;
+pub const GL_INT_SAMPLER_1D: GLenum = 0x8DC9;
+pub const GL_INT_SAMPLER_1D_ARRAY: GLenum = 0x8DCE;
+pub const GL_INT_SAMPLER_2D: GLenum = 0x8DCA;
+pub const GL_INT_SAMPLER_2D_ARRAY: GLenum = 0x8DCF;
+pub const GL_INT_SAMPLER_2D_MULTISAMPLE: GLenum = 0x9109;
+pub const GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY: GLenum = 0x910C;
+pub const GL_INT_SAMPLER_2D_RECT: GLenum = 0x8DCD;
+pub const GL_INT_SAMPLER_3D: GLenum = 0x8DCB;
+pub const GL_INT_SAMPLER_BUFFER: GLenum = 0x8DD0;
+pub const GL_INT_SAMPLER_CUBE: GLenum = 0x8DCC;
+pub const GL_INT_SAMPLER_CUBE_MAP_ARRAY: GLenum = 0x900E;
+pub const GL_INT_VEC2: GLenum = 0x8B53;
+pub const GL_INT_VEC3: GLenum = 0x8B54;
+pub const GL_INT_VEC4: GLenum = 0x8B55;
+pub const GL_INVALID_ENUM: GLenum = 0x0500;
+pub const GL_INVALID_FRAMEBUFFER_OPERATION: GLenum = 0x0506;
+pub const GL_INVALID_INDEX: GLuint = 0xFFFFFFFF;
+pub const GL_INVALID_OPERATION: GLenum = 0x0502;
+pub const GL_INVALID_VALUE: GLenum = 0x0501;
+pub const GL_INVERT: GLenum = 0x150A;
+pub const GL_ISOLINES: GLenum = 0x8E7A;
+pub const GL_IS_PER_PATCH: GLenum = 0x92E7;
+pub const GL_IS_ROW_MAJOR: GLenum = 0x9300;
+pub const GL_KEEP: GLenum = 0x1E00;
+pub const GL_LAST_VERTEX_CONVENTION: GLenum = 0x8E4E;
+pub const GL_LAYER_PROVOKING_VERTEX: GLenum = 0x825E;
+pub const GL_LEFT: GLenum = 0x0406;
+pub const GL_LEQUAL: GLenum = 0x0203;
+pub const GL_LESS: GLenum = 0x0201;
+pub const GL_LINE: GLenum = 0x1B01;
+pub const GL_LINEAR: GLenum = 0x2601;
+pub const GL_LINEAR_MIPMAP_LINEAR: GLenum = 0x2703;
+pub const GL_LINEAR_MIPMAP_NEAREST: GLenum = 0x2701;
+pub const GL_LINES: GLenum = 0x0001;
+pub const GL_LINES_ADJACENCY: GLenum = 0x000A;
+pub const GL_LINE_LOOP: GLenum = 0x0002;
+pub const GL_LINE_SMOOTH: GLenum = 0x0B20;
+pub const GL_LINE_SMOOTH_HINT: GLenum = 0x0C52;
+pub const GL_LINE_STRIP: GLenum = 0x0003;
+pub const GL_LINE_STRIP_ADJACENCY: GLenum = 0x000B;
+pub const GL_LINE_WIDTH: GLenum = 0x0B21;
+pub const GL_LINE_WIDTH_GRANULARITY: GLenum = 0x0B23;
+pub const GL_LINE_WIDTH_RANGE: GLenum = 0x0B22;
+pub const GL_LINK_STATUS: GLenum = 0x8B82;
+pub const GL_LOCATION: GLenum = 0x930E;
+pub const GL_LOCATION_COMPONENT: GLenum = 0x934A;
+pub const GL_LOCATION_INDEX: GLenum = 0x930F;
+pub const GL_LOGIC_OP_MODE: GLenum = 0x0BF0;
+pub const GL_LOSE_CONTEXT_ON_RESET: GLenum = 0x8252;
+pub const GL_LOWER_LEFT: GLenum = 0x8CA1;
+pub const GL_LOW_FLOAT: GLenum = 0x8DF0;
+pub const GL_LOW_INT: GLenum = 0x8DF3;
+pub const GL_MAJOR_VERSION: GLenum = 0x821B;
+pub const GL_MANUAL_GENERATE_MIPMAP: GLenum = 0x8294;
+pub const GL_MAP_COHERENT_BIT: GLbitfield = 0x0080;
+pub const GL_MAP_FLUSH_EXPLICIT_BIT: GLbitfield = 0x0010;
+pub const GL_MAP_INVALIDATE_BUFFER_BIT: GLbitfield = 0x0008;
+pub const GL_MAP_INVALIDATE_RANGE_BIT: GLbitfield = 0x0004;
+pub const GL_MAP_PERSISTENT_BIT: GLbitfield = 0x0040;
+pub const GL_MAP_READ_BIT: GLbitfield = 0x0001;
+pub const GL_MAP_UNSYNCHRONIZED_BIT: GLbitfield = 0x0020;
+pub const GL_MAP_WRITE_BIT: GLbitfield = 0x0002;
+pub const GL_MATRIX_STRIDE: GLenum = 0x92FF;
+pub const GL_MAX: GLenum = 0x8008;
+pub const GL_MAX_3D_TEXTURE_SIZE: GLenum = 0x8073;
+pub const GL_MAX_ARRAY_TEXTURE_LAYERS: GLenum = 0x88FF;
+pub const GL_MAX_ATOMIC_COUNTER_BUFFER_BINDINGS: GLenum = 0x92DC;
+pub const GL_MAX_ATOMIC_COUNTER_BUFFER_SIZE: GLenum = 0x92D8;
+pub const GL_MAX_CLIP_DISTANCES: GLenum = 0x0D32;
+pub const GL_MAX_COLOR_ATTACHMENTS: GLenum = 0x8CDF;
+pub const GL_MAX_COLOR_TEXTURE_SAMPLES: GLenum = 0x910E;
+pub const GL_MAX_COMBINED_ATOMIC_COUNTERS: GLenum = 0x92D7;
+pub const GL_MAX_COMBINED_ATOMIC_COUNTER_BUFFERS: GLenum = 0x92D1;
+pub const GL_MAX_COMBINED_CLIP_AND_CULL_DISTANCES: GLenum = 0x82FA;
+pub const GL_MAX_COMBINED_COMPUTE_UNIFORM_COMPONENTS: GLenum = 0x8266;
+pub const GL_MAX_COMBINED_DIMENSIONS: GLenum = 0x8282;
+pub const GL_MAX_COMBINED_FRAGMENT_UNIFORM_COMPONENTS: GLenum = 0x8A33;
+pub const GL_MAX_COMBINED_GEOMETRY_UNIFORM_COMPONENTS: GLenum = 0x8A32;
+pub const GL_MAX_COMBINED_IMAGE_UNIFORMS: GLenum = 0x90CF;
+pub const GL_MAX_COMBINED_IMAGE_UNITS_AND_FRAGMENT_OUTPUTS: GLenum = 0x8F39;
+pub const GL_MAX_COMBINED_SHADER_OUTPUT_RESOURCES: GLenum = 0x8F39;
+pub const GL_MAX_COMBINED_SHADER_STORAGE_BLOCKS: GLenum = 0x90DC;
+pub const GL_MAX_COMBINED_TESS_CONTROL_UNIFORM_COMPONENTS: GLenum = 0x8E1E;
+pub const GL_MAX_COMBINED_TESS_EVALUATION_UNIFORM_COMPONENTS: GLenum = 0x8E1F;
+pub const GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS: GLenum = 0x8B4D;
+pub const GL_MAX_COMBINED_UNIFORM_BLOCKS: GLenum = 0x8A2E;
+pub const GL_MAX_COMBINED_VERTEX_UNIFORM_COMPONENTS: GLenum = 0x8A31;
+pub const GL_MAX_COMPUTE_ATOMIC_COUNTERS: GLenum = 0x8265;
+pub const GL_MAX_COMPUTE_ATOMIC_COUNTER_BUFFERS: GLenum = 0x8264;
+pub const GL_MAX_COMPUTE_IMAGE_UNIFORMS: GLenum = 0x91BD;
+pub const GL_MAX_COMPUTE_SHADER_STORAGE_BLOCKS: GLenum = 0x90DB;
+pub const GL_MAX_COMPUTE_SHARED_MEMORY_SIZE: GLenum = 0x8262;
+pub const GL_MAX_COMPUTE_TEXTURE_IMAGE_UNITS: GLenum = 0x91BC;
+pub const GL_MAX_COMPUTE_UNIFORM_BLOCKS: GLenum = 0x91BB;
+pub const GL_MAX_COMPUTE_UNIFORM_COMPONENTS: GLenum = 0x8263;
+pub const GL_MAX_COMPUTE_WORK_GROUP_COUNT: GLenum = 0x91BE;
+pub const GL_MAX_COMPUTE_WORK_GROUP_INVOCATIONS: GLenum = 0x90EB;
+pub const GL_MAX_COMPUTE_WORK_GROUP_SIZE: GLenum = 0x91BF;
+pub const GL_MAX_CUBE_MAP_TEXTURE_SIZE: GLenum = 0x851C;
+pub const GL_MAX_CULL_DISTANCES: GLenum = 0x82F9;
+pub const GL_MAX_DEBUG_GROUP_STACK_DEPTH: GLenum = 0x826C;
+pub const GL_MAX_DEBUG_LOGGED_MESSAGES: GLenum = 0x9144;
+pub const GL_MAX_DEBUG_MESSAGE_LENGTH: GLenum = 0x9143;
+pub const GL_MAX_DEPTH: GLenum = 0x8280;
+pub const GL_MAX_DEPTH_TEXTURE_SAMPLES: GLenum = 0x910F;
+pub const GL_MAX_DRAW_BUFFERS: GLenum = 0x8824;
+pub const GL_MAX_DUAL_SOURCE_DRAW_BUFFERS: GLenum = 0x88FC;
+pub const GL_MAX_ELEMENTS_INDICES: GLenum = 0x80E9;
+pub const GL_MAX_ELEMENTS_VERTICES: GLenum = 0x80E8;
+pub const GL_MAX_ELEMENT_INDEX: GLenum = 0x8D6B;
+pub const GL_MAX_FRAGMENT_ATOMIC_COUNTERS: GLenum = 0x92D6;
+pub const GL_MAX_FRAGMENT_ATOMIC_COUNTER_BUFFERS: GLenum = 0x92D0;
+pub const GL_MAX_FRAGMENT_IMAGE_UNIFORMS: GLenum = 0x90CE;
+pub const GL_MAX_FRAGMENT_INPUT_COMPONENTS: GLenum = 0x9125;
+pub const GL_MAX_FRAGMENT_INTERPOLATION_OFFSET: GLenum = 0x8E5C;
+pub const GL_MAX_FRAGMENT_SHADER_STORAGE_BLOCKS: GLenum = 0x90DA;
+pub const GL_MAX_FRAGMENT_UNIFORM_BLOCKS: GLenum = 0x8A2D;
+pub const GL_MAX_FRAGMENT_UNIFORM_COMPONENTS: GLenum = 0x8B49;
+pub const GL_MAX_FRAGMENT_UNIFORM_VECTORS: GLenum = 0x8DFD;
+pub const GL_MAX_FRAMEBUFFER_HEIGHT: GLenum = 0x9316;
+pub const GL_MAX_FRAMEBUFFER_LAYERS: GLenum = 0x9317;
+pub const GL_MAX_FRAMEBUFFER_SAMPLES: GLenum = 0x9318;
+pub const GL_MAX_FRAMEBUFFER_WIDTH: GLenum = 0x9315;
+pub const GL_MAX_GEOMETRY_ATOMIC_COUNTERS: GLenum = 0x92D5;
+pub const GL_MAX_GEOMETRY_ATOMIC_COUNTER_BUFFERS: GLenum = 0x92CF;
+pub const GL_MAX_GEOMETRY_IMAGE_UNIFORMS: GLenum = 0x90CD;
+pub const GL_MAX_GEOMETRY_INPUT_COMPONENTS: GLenum = 0x9123;
+pub const GL_MAX_GEOMETRY_OUTPUT_COMPONENTS: GLenum = 0x9124;
+pub const GL_MAX_GEOMETRY_OUTPUT_VERTICES: GLenum = 0x8DE0;
+pub const GL_MAX_GEOMETRY_SHADER_INVOCATIONS: GLenum = 0x8E5A;
+pub const GL_MAX_GEOMETRY_SHADER_STORAGE_BLOCKS: GLenum = 0x90D7;
+pub const GL_MAX_GEOMETRY_TEXTURE_IMAGE_UNITS: GLenum = 0x8C29;
+pub const GL_MAX_GEOMETRY_TOTAL_OUTPUT_COMPONENTS: GLenum = 0x8DE1;
+pub const GL_MAX_GEOMETRY_UNIFORM_BLOCKS: GLenum = 0x8A2C;
+pub const GL_MAX_GEOMETRY_UNIFORM_COMPONENTS: GLenum = 0x8DDF;
+pub const GL_MAX_HEIGHT: GLenum = 0x827F;
+pub const GL_MAX_IMAGE_SAMPLES: GLenum = 0x906D;
+pub const GL_MAX_IMAGE_UNITS: GLenum = 0x8F38;
+pub const GL_MAX_INTEGER_SAMPLES: GLenum = 0x9110;
+pub const GL_MAX_LABEL_LENGTH: GLenum = 0x82E8;
+pub const GL_MAX_LAYERS: GLenum = 0x8281;
+pub const GL_MAX_NAME_LENGTH: GLenum = 0x92F6;
+pub const GL_MAX_NUM_ACTIVE_VARIABLES: GLenum = 0x92F7;
+pub const GL_MAX_NUM_COMPATIBLE_SUBROUTINES: GLenum = 0x92F8;
+pub const GL_MAX_PATCH_VERTICES: GLenum = 0x8E7D;
+pub const GL_MAX_PROGRAM_TEXEL_OFFSET: GLenum = 0x8905;
+pub const GL_MAX_PROGRAM_TEXTURE_GATHER_OFFSET: GLenum = 0x8E5F;
+pub const GL_MAX_RECTANGLE_TEXTURE_SIZE: GLenum = 0x84F8;
+pub const GL_MAX_RENDERBUFFER_SIZE: GLenum = 0x84E8;
+pub const GL_MAX_SAMPLES: GLenum = 0x8D57;
+pub const GL_MAX_SAMPLE_MASK_WORDS: GLenum = 0x8E59;
+pub const GL_MAX_SERVER_WAIT_TIMEOUT: GLenum = 0x9111;
+pub const GL_MAX_SHADER_STORAGE_BLOCK_SIZE: GLenum = 0x90DE;
+pub const GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS: GLenum = 0x90DD;
+pub const GL_MAX_SUBROUTINES: GLenum = 0x8DE7;
+pub const GL_MAX_SUBROUTINE_UNIFORM_LOCATIONS: GLenum = 0x8DE8;
+pub const GL_MAX_TESS_CONTROL_ATOMIC_COUNTERS: GLenum = 0x92D3;
+pub const GL_MAX_TESS_CONTROL_ATOMIC_COUNTER_BUFFERS: GLenum = 0x92CD;
+pub const GL_MAX_TESS_CONTROL_IMAGE_UNIFORMS: GLenum = 0x90CB;
+pub const GL_MAX_TESS_CONTROL_INPUT_COMPONENTS: GLenum = 0x886C;
+pub const GL_MAX_TESS_CONTROL_OUTPUT_COMPONENTS: GLenum = 0x8E83;
+pub const GL_MAX_TESS_CONTROL_SHADER_STORAGE_BLOCKS: GLenum = 0x90D8;
+pub const GL_MAX_TESS_CONTROL_TEXTURE_IMAGE_UNITS: GLenum = 0x8E81;
+pub const GL_MAX_TESS_CONTROL_TOTAL_OUTPUT_COMPONENTS: GLenum = 0x8E85;
+pub const GL_MAX_TESS_CONTROL_UNIFORM_BLOCKS: GLenum = 0x8E89;
+pub const GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS: GLenum = 0x8E7F;
+pub const GL_MAX_TESS_EVALUATION_ATOMIC_COUNTERS: GLenum = 0x92D4;
+pub const GL_MAX_TESS_EVALUATION_ATOMIC_COUNTER_BUFFERS: GLenum = 0x92CE;
+pub const GL_MAX_TESS_EVALUATION_IMAGE_UNIFORMS: GLenum = 0x90CC;
+pub const GL_MAX_TESS_EVALUATION_INPUT_COMPONENTS: GLenum = 0x886D;
+pub const GL_MAX_TESS_EVALUATION_OUTPUT_COMPONENTS: GLenum = 0x8E86;
+pub const GL_MAX_TESS_EVALUATION_SHADER_STORAGE_BLOCKS: GLenum = 0x90D9;
+pub const GL_MAX_TESS_EVALUATION_TEXTURE_IMAGE_UNITS: GLenum = 0x8E82;
+pub const GL_MAX_TESS_EVALUATION_UNIFORM_BLOCKS: GLenum = 0x8E8A;
+pub const GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS: GLenum = 0x8E80;
+pub const GL_MAX_TESS_GEN_LEVEL: GLenum = 0x8E7E;
+pub const GL_MAX_TESS_PATCH_COMPONENTS: GLenum = 0x8E84;
+pub const GL_MAX_TEXTURE_BUFFER_SIZE: GLenum = 0x8C2B;
+pub const GL_MAX_TEXTURE_IMAGE_UNITS: GLenum = 0x8872;
+pub const GL_MAX_TEXTURE_LOD_BIAS: GLenum = 0x84FD;
+pub const GL_MAX_TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FF;
+pub const GL_MAX_TEXTURE_SIZE: GLenum = 0x0D33;
+pub const GL_MAX_TRANSFORM_FEEDBACK_BUFFERS: GLenum = 0x8E70;
+pub const GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS: GLenum = 0x8C8A;
+pub const GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS: GLenum = 0x8C8B;
+pub const GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS: GLenum = 0x8C80;
+pub const GL_MAX_UNIFORM_BLOCK_SIZE: GLenum = 0x8A30;
+pub const GL_MAX_UNIFORM_BUFFER_BINDINGS: GLenum = 0x8A2F;
+pub const GL_MAX_UNIFORM_LOCATIONS: GLenum = 0x826E;
+pub const GL_MAX_VARYING_COMPONENTS: GLenum = 0x8B4B;
+pub const GL_MAX_VARYING_FLOATS: GLenum = 0x8B4B;
+pub const GL_MAX_VARYING_VECTORS: GLenum = 0x8DFC;
+pub const GL_MAX_VERTEX_ATOMIC_COUNTERS: GLenum = 0x92D2;
+pub const GL_MAX_VERTEX_ATOMIC_COUNTER_BUFFERS: GLenum = 0x92CC;
+pub const GL_MAX_VERTEX_ATTRIBS: GLenum = 0x8869;
+pub const GL_MAX_VERTEX_ATTRIB_BINDINGS: GLenum = 0x82DA;
+pub const GL_MAX_VERTEX_ATTRIB_RELATIVE_OFFSET: GLenum = 0x82D9;
+pub const GL_MAX_VERTEX_ATTRIB_STRIDE: GLenum = 0x82E5;
+pub const GL_MAX_VERTEX_IMAGE_UNIFORMS: GLenum = 0x90CA;
+pub const GL_MAX_VERTEX_OUTPUT_COMPONENTS: GLenum = 0x9122;
+pub const GL_MAX_VERTEX_SHADER_STORAGE_BLOCKS: GLenum = 0x90D6;
+pub const GL_MAX_VERTEX_STREAMS: GLenum = 0x8E71;
+pub const GL_MAX_VERTEX_TEXTURE_IMAGE_UNITS: GLenum = 0x8B4C;
+pub const GL_MAX_VERTEX_UNIFORM_BLOCKS: GLenum = 0x8A2B;
+pub const GL_MAX_VERTEX_UNIFORM_COMPONENTS: GLenum = 0x8B4A;
+pub const GL_MAX_VERTEX_UNIFORM_VECTORS: GLenum = 0x8DFB;
+pub const GL_MAX_VIEWPORTS: GLenum = 0x825B;
+pub const GL_MAX_VIEWPORT_DIMS: GLenum = 0x0D3A;
+pub const GL_MAX_WIDTH: GLenum = 0x827E;
+pub const GL_MEDIUM_FLOAT: GLenum = 0x8DF1;
+pub const GL_MEDIUM_INT: GLenum = 0x8DF4;
+pub const GL_MIN: GLenum = 0x8007;
+pub const GL_MINOR_VERSION: GLenum = 0x821C;
+pub const GL_MIN_FRAGMENT_INTERPOLATION_OFFSET: GLenum = 0x8E5B;
+pub const GL_MIN_MAP_BUFFER_ALIGNMENT: GLenum = 0x90BC;
+pub const GL_MIN_PROGRAM_TEXEL_OFFSET: GLenum = 0x8904;
+pub const GL_MIN_PROGRAM_TEXTURE_GATHER_OFFSET: GLenum = 0x8E5E;
+pub const GL_MIN_SAMPLE_SHADING_VALUE: GLenum = 0x8C37;
+pub const GL_MIPMAP: GLenum = 0x8293;
+pub const GL_MIRRORED_REPEAT: GLenum = 0x8370;
+pub const GL_MIRROR_CLAMP_TO_EDGE: GLenum = 0x8743;
+pub const GL_MULTISAMPLE: GLenum = 0x809D;
+pub const GL_NAME_LENGTH: GLenum = 0x92F9;
+pub const GL_NAND: GLenum = 0x150E;
+pub const GL_NEAREST: GLenum = 0x2600;
+pub const GL_NEAREST_MIPMAP_LINEAR: GLenum = 0x2702;
+pub const GL_NEAREST_MIPMAP_NEAREST: GLenum = 0x2700;
+pub const GL_NEGATIVE_ONE_TO_ONE: GLenum = 0x935E;
+pub const GL_NEVER: GLenum = 0x0200;
+pub const GL_NICEST: GLenum = 0x1102;
+pub const GL_NONE: GLenum = 0;
+pub const GL_NOOP: GLenum = 0x1505;
+pub const GL_NOR: GLenum = 0x1508;
+pub const GL_NOTEQUAL: GLenum = 0x0205;
+pub const GL_NO_ERROR: GLenum = 0;
+pub const GL_NO_RESET_NOTIFICATION: GLenum = 0x8261;
+pub const GL_NUM_ACTIVE_VARIABLES: GLenum = 0x9304;
+pub const GL_NUM_COMPATIBLE_SUBROUTINES: GLenum = 0x8E4A;
+pub const GL_NUM_COMPRESSED_TEXTURE_FORMATS: GLenum = 0x86A2;
+pub const GL_NUM_EXTENSIONS: GLenum = 0x821D;
+pub const GL_NUM_PROGRAM_BINARY_FORMATS: GLenum = 0x87FE;
+pub const GL_NUM_SAMPLE_COUNTS: GLenum = 0x9380;
+pub const GL_NUM_SHADER_BINARY_FORMATS: GLenum = 0x8DF9;
+pub const GL_NUM_SHADING_LANGUAGE_VERSIONS: GLenum = 0x82E9;
+pub const GL_NUM_SPIR_V_EXTENSIONS: GLenum = 0x9554;
+pub const GL_OBJECT_TYPE: GLenum = 0x9112;
+pub const GL_OFFSET: GLenum = 0x92FC;
+pub const GL_ONE: GLenum = 1;
+pub const GL_ONE_MINUS_CONSTANT_ALPHA: GLenum = 0x8004;
+pub const GL_ONE_MINUS_CONSTANT_COLOR: GLenum = 0x8002;
+pub const GL_ONE_MINUS_DST_ALPHA: GLenum = 0x0305;
+pub const GL_ONE_MINUS_DST_COLOR: GLenum = 0x0307;
+pub const GL_ONE_MINUS_SRC1_ALPHA: GLenum = 0x88FB;
+pub const GL_ONE_MINUS_SRC1_COLOR: GLenum = 0x88FA;
+pub const GL_ONE_MINUS_SRC_ALPHA: GLenum = 0x0303;
+pub const GL_ONE_MINUS_SRC_COLOR: GLenum = 0x0301;
+pub const GL_OR: GLenum = 0x1507;
+pub const GL_OR_INVERTED: GLenum = 0x150D;
+pub const GL_OR_REVERSE: GLenum = 0x150B;
+pub const GL_OUT_OF_MEMORY: GLenum = 0x0505;
+pub const GL_PACK_ALIGNMENT: GLenum = 0x0D05;
+pub const GL_PACK_COMPRESSED_BLOCK_DEPTH: GLenum = 0x912D;
+pub const GL_PACK_COMPRESSED_BLOCK_HEIGHT: GLenum = 0x912C;
+pub const GL_PACK_COMPRESSED_BLOCK_SIZE: GLenum = 0x912E;
+pub const GL_PACK_COMPRESSED_BLOCK_WIDTH: GLenum = 0x912B;
+pub const GL_PACK_IMAGE_HEIGHT: GLenum = 0x806C;
+pub const GL_PACK_LSB_FIRST: GLenum = 0x0D01;
+pub const GL_PACK_ROW_LENGTH: GLenum = 0x0D02;
+pub const GL_PACK_SKIP_IMAGES: GLenum = 0x806B;
+pub const GL_PACK_SKIP_PIXELS: GLenum = 0x0D04;
+pub const GL_PACK_SKIP_ROWS: GLenum = 0x0D03;
+pub const GL_PACK_SWAP_BYTES: GLenum = 0x0D00;
+pub const GL_PARAMETER_BUFFER: GLenum = 0x80EE;
+pub const GL_PARAMETER_BUFFER_BINDING: GLenum = 0x80EF;
+pub const GL_PATCHES: GLenum = 0x000E;
+pub const GL_PATCH_DEFAULT_INNER_LEVEL: GLenum = 0x8E73;
+pub const GL_PATCH_DEFAULT_OUTER_LEVEL: GLenum = 0x8E74;
+pub const GL_PATCH_VERTICES: GLenum = 0x8E72;
+pub const GL_PIXEL_BUFFER_BARRIER_BIT: GLbitfield = 0x00000080;
+pub const GL_PIXEL_PACK_BUFFER: GLenum = 0x88EB;
+pub const GL_PIXEL_PACK_BUFFER_BINDING: GLenum = 0x88ED;
+pub const GL_PIXEL_UNPACK_BUFFER: GLenum = 0x88EC;
+pub const GL_PIXEL_UNPACK_BUFFER_BINDING: GLenum = 0x88EF;
+pub const GL_POINT: GLenum = 0x1B00;
+pub const GL_POINTS: GLenum = 0x0000;
+pub const GL_POINT_FADE_THRESHOLD_SIZE: GLenum = 0x8128;
+pub const GL_POINT_SIZE: GLenum = 0x0B11;
+pub const GL_POINT_SIZE_GRANULARITY: GLenum = 0x0B13;
+pub const GL_POINT_SIZE_RANGE: GLenum = 0x0B12;
+pub const GL_POINT_SPRITE_COORD_ORIGIN: GLenum = 0x8CA0;
+pub const GL_POLYGON_MODE: GLenum = 0x0B40;
+pub const GL_POLYGON_OFFSET_CLAMP: GLenum = 0x8E1B;
+pub const GL_POLYGON_OFFSET_FACTOR: GLenum = 0x8038;
+pub const GL_POLYGON_OFFSET_FILL: GLenum = 0x8037;
+pub const GL_POLYGON_OFFSET_LINE: GLenum = 0x2A02;
+pub const GL_POLYGON_OFFSET_POINT: GLenum = 0x2A01;
+pub const GL_POLYGON_OFFSET_UNITS: GLenum = 0x2A00;
+pub const GL_POLYGON_SMOOTH: GLenum = 0x0B41;
+pub const GL_POLYGON_SMOOTH_HINT: GLenum = 0x0C53;
+pub const GL_PRIMITIVES_GENERATED: GLenum = 0x8C87;
+pub const GL_PRIMITIVES_SUBMITTED: GLenum = 0x82EF;
+pub const GL_PRIMITIVE_RESTART: GLenum = 0x8F9D;
+pub const GL_PRIMITIVE_RESTART_FIXED_INDEX: GLenum = 0x8D69;
+pub const GL_PRIMITIVE_RESTART_FOR_PATCHES_SUPPORTED: GLenum = 0x8221;
+pub const GL_PRIMITIVE_RESTART_INDEX: GLenum = 0x8F9E;
+pub const GL_PROGRAM: GLenum = 0x82E2;
+pub const GL_PROGRAM_BINARY_FORMATS: GLenum = 0x87FF;
+pub const GL_PROGRAM_BINARY_LENGTH: GLenum = 0x8741;
+pub const GL_PROGRAM_BINARY_RETRIEVABLE_HINT: GLenum = 0x8257;
+pub const GL_PROGRAM_INPUT: GLenum = 0x92E3;
+pub const GL_PROGRAM_OUTPUT: GLenum = 0x92E4;
+pub const GL_PROGRAM_PIPELINE: GLenum = 0x82E4;
+pub const GL_PROGRAM_PIPELINE_BINDING: GLenum = 0x825A;
+pub const GL_PROGRAM_POINT_SIZE: GLenum = 0x8642;
+pub const GL_PROGRAM_SEPARABLE: GLenum = 0x8258;
+pub const GL_PROVOKING_VERTEX: GLenum = 0x8E4F;
+pub const GL_PROXY_TEXTURE_1D: GLenum = 0x8063;
+pub const GL_PROXY_TEXTURE_1D_ARRAY: GLenum = 0x8C19;
+pub const GL_PROXY_TEXTURE_2D: GLenum = 0x8064;
+pub const GL_PROXY_TEXTURE_2D_ARRAY: GLenum = 0x8C1B;
+pub const GL_PROXY_TEXTURE_2D_MULTISAMPLE: GLenum = 0x9101;
+pub const GL_PROXY_TEXTURE_2D_MULTISAMPLE_ARRAY: GLenum = 0x9103;
+pub const GL_PROXY_TEXTURE_3D: GLenum = 0x8070;
+pub const GL_PROXY_TEXTURE_CUBE_MAP: GLenum = 0x851B;
+pub const GL_PROXY_TEXTURE_CUBE_MAP_ARRAY: GLenum = 0x900B;
+pub const GL_PROXY_TEXTURE_RECTANGLE: GLenum = 0x84F7;
+pub const GL_QUADS: GLenum = 0x0007;
+pub const GL_QUADS_FOLLOW_PROVOKING_VERTEX_CONVENTION: GLenum = 0x8E4C;
+pub const GL_QUERY: GLenum = 0x82E3;
+pub const GL_QUERY_BUFFER: GLenum = 0x9192;
+pub const GL_QUERY_BUFFER_BARRIER_BIT: GLbitfield = 0x00008000;
+pub const GL_QUERY_BUFFER_BINDING: GLenum = 0x9193;
+pub const GL_QUERY_BY_REGION_NO_WAIT: GLenum = 0x8E16;
+pub const GL_QUERY_BY_REGION_NO_WAIT_INVERTED: GLenum = 0x8E1A;
+pub const GL_QUERY_BY_REGION_WAIT: GLenum = 0x8E15;
+pub const GL_QUERY_BY_REGION_WAIT_INVERTED: GLenum = 0x8E19;
+pub const GL_QUERY_COUNTER_BITS: GLenum = 0x8864;
+pub const GL_QUERY_NO_WAIT: GLenum = 0x8E14;
+pub const GL_QUERY_NO_WAIT_INVERTED: GLenum = 0x8E18;
+pub const GL_QUERY_RESULT: GLenum = 0x8866;
+pub const GL_QUERY_RESULT_AVAILABLE: GLenum = 0x8867;
+pub const GL_QUERY_RESULT_NO_WAIT: GLenum = 0x9194;
+pub const GL_QUERY_TARGET: GLenum = 0x82EA;
+pub const GL_QUERY_WAIT: GLenum = 0x8E13;
+pub const GL_QUERY_WAIT_INVERTED: GLenum = 0x8E17;
+pub const GL_R11F_G11F_B10F: GLenum = 0x8C3A;
+pub const GL_R16: GLenum = 0x822A;
+pub const GL_R16F: GLenum = 0x822D;
+pub const GL_R16I: GLenum = 0x8233;
+pub const GL_R16UI: GLenum = 0x8234;
+pub const GL_R16_SNORM: GLenum = 0x8F98;
+pub const GL_R32F: GLenum = 0x822E;
+pub const GL_R32I: GLenum = 0x8235;
+pub const GL_R32UI: GLenum = 0x8236;
+pub const GL_R3_G3_B2: GLenum = 0x2A10;
+pub const GL_R8: GLenum = 0x8229;
+pub const GL_R8I: GLenum = 0x8231;
+pub const GL_R8UI: GLenum = 0x8232;
+pub const GL_R8_SNORM: GLenum = 0x8F94;
+pub const GL_RASTERIZER_DISCARD: GLenum = 0x8C89;
+pub const GL_READ_BUFFER: GLenum = 0x0C02;
+pub const GL_READ_FRAMEBUFFER: GLenum = 0x8CA8;
+pub const GL_READ_FRAMEBUFFER_BINDING: GLenum = 0x8CAA;
+pub const GL_READ_ONLY: GLenum = 0x88B8;
+pub const GL_READ_PIXELS: GLenum = 0x828C;
+pub const GL_READ_PIXELS_FORMAT: GLenum = 0x828D;
+pub const GL_READ_PIXELS_TYPE: GLenum = 0x828E;
+pub const GL_READ_WRITE: GLenum = 0x88BA;
+pub const GL_RED: GLenum = 0x1903;
+pub const GL_RED_INTEGER: GLenum = 0x8D94;
+pub const GL_REFERENCED_BY_COMPUTE_SHADER: GLenum = 0x930B;
+pub const GL_REFERENCED_BY_FRAGMENT_SHADER: GLenum = 0x930A;
+pub const GL_REFERENCED_BY_GEOMETRY_SHADER: GLenum = 0x9309;
+pub const GL_REFERENCED_BY_TESS_CONTROL_SHADER: GLenum = 0x9307;
+pub const GL_REFERENCED_BY_TESS_EVALUATION_SHADER: GLenum = 0x9308;
+pub const GL_REFERENCED_BY_VERTEX_SHADER: GLenum = 0x9306;
+pub const GL_RENDERBUFFER: GLenum = 0x8D41;
+pub const GL_RENDERBUFFER_ALPHA_SIZE: GLenum = 0x8D53;
+pub const GL_RENDERBUFFER_BINDING: GLenum = 0x8CA7;
+pub const GL_RENDERBUFFER_BLUE_SIZE: GLenum = 0x8D52;
+pub const GL_RENDERBUFFER_DEPTH_SIZE: GLenum = 0x8D54;
+pub const GL_RENDERBUFFER_GREEN_SIZE: GLenum = 0x8D51;
+pub const GL_RENDERBUFFER_HEIGHT: GLenum = 0x8D43;
+pub const GL_RENDERBUFFER_INTERNAL_FORMAT: GLenum = 0x8D44;
+pub const GL_RENDERBUFFER_RED_SIZE: GLenum = 0x8D50;
+pub const GL_RENDERBUFFER_SAMPLES: GLenum = 0x8CAB;
+pub const GL_RENDERBUFFER_STENCIL_SIZE: GLenum = 0x8D55;
+pub const GL_RENDERBUFFER_WIDTH: GLenum = 0x8D42;
+pub const GL_RENDERER: GLenum = 0x1F01;
+pub const GL_REPEAT: GLenum = 0x2901;
+pub const GL_REPLACE: GLenum = 0x1E01;
+pub const GL_RESET_NOTIFICATION_STRATEGY: GLenum = 0x8256;
+pub const GL_RG: GLenum = 0x8227;
+pub const GL_RG16: GLenum = 0x822C;
+pub const GL_RG16F: GLenum = 0x822F;
+pub const GL_RG16I: GLenum = 0x8239;
+pub const GL_RG16UI: GLenum = 0x823A;
+pub const GL_RG16_SNORM: GLenum = 0x8F99;
+pub const GL_RG32F: GLenum = 0x8230;
+pub const GL_RG32I: GLenum = 0x823B;
+pub const GL_RG32UI: GLenum = 0x823C;
+pub const GL_RG8: GLenum = 0x822B;
+pub const GL_RG8I: GLenum = 0x8237;
+pub const GL_RG8UI: GLenum = 0x8238;
+pub const GL_RG8_SNORM: GLenum = 0x8F95;
+pub const GL_RGB: GLenum = 0x1907;
+pub const GL_RGB10: GLenum = 0x8052;
+pub const GL_RGB10_A2: GLenum = 0x8059;
+pub const GL_RGB10_A2UI: GLenum = 0x906F;
+pub const GL_RGB12: GLenum = 0x8053;
+pub const GL_RGB16: GLenum = 0x8054;
+pub const GL_RGB16F: GLenum = 0x881B;
+pub const GL_RGB16I: GLenum = 0x8D89;
+pub const GL_RGB16UI: GLenum = 0x8D77;
+pub const GL_RGB16_SNORM: GLenum = 0x8F9A;
+pub const GL_RGB32F: GLenum = 0x8815;
+pub const GL_RGB32I: GLenum = 0x8D83;
+pub const GL_RGB32UI: GLenum = 0x8D71;
+pub const GL_RGB4: GLenum = 0x804F;
+pub const GL_RGB5: GLenum = 0x8050;
+pub const GL_RGB565: GLenum = 0x8D62;
+pub const GL_RGB5_A1: GLenum = 0x8057;
+pub const GL_RGB8: GLenum = 0x8051;
+pub const GL_RGB8I: GLenum = 0x8D8F;
+pub const GL_RGB8UI: GLenum = 0x8D7D;
+pub const GL_RGB8_SNORM: GLenum = 0x8F96;
+pub const GL_RGB9_E5: GLenum = 0x8C3D;
+pub const GL_RGBA: GLenum = 0x1908;
+pub const GL_RGBA12: GLenum = 0x805A;
+pub const GL_RGBA16: GLenum = 0x805B;
+pub const GL_RGBA16F: GLenum = 0x881A;
+pub const GL_RGBA16I: GLenum = 0x8D88;
+pub const GL_RGBA16UI: GLenum = 0x8D76;
+pub const GL_RGBA16_SNORM: GLenum = 0x8F9B;
+pub const GL_RGBA2: GLenum = 0x8055;
+pub const GL_RGBA32F: GLenum = 0x8814;
+pub const GL_RGBA32I: GLenum = 0x8D82;
+pub const GL_RGBA32UI: GLenum = 0x8D70;
+pub const GL_RGBA4: GLenum = 0x8056;
+pub const GL_RGBA8: GLenum = 0x8058;
+pub const GL_RGBA8I: GLenum = 0x8D8E;
+pub const GL_RGBA8UI: GLenum = 0x8D7C;
+pub const GL_RGBA8_SNORM: GLenum = 0x8F97;
+pub const GL_RGBA_INTEGER: GLenum = 0x8D99;
+pub const GL_RGB_INTEGER: GLenum = 0x8D98;
+pub const GL_RG_INTEGER: GLenum = 0x8228;
+pub const GL_RIGHT: GLenum = 0x0407;
+pub const GL_SAMPLER: GLenum = 0x82E6;
+pub const GL_SAMPLER_1D: GLenum = 0x8B5D;
+pub const GL_SAMPLER_1D_ARRAY: GLenum = 0x8DC0;
+pub const GL_SAMPLER_1D_ARRAY_SHADOW: GLenum = 0x8DC3;
+pub const GL_SAMPLER_1D_SHADOW: GLenum = 0x8B61;
+pub const GL_SAMPLER_2D: GLenum = 0x8B5E;
+pub const GL_SAMPLER_2D_ARRAY: GLenum = 0x8DC1;
+pub const GL_SAMPLER_2D_ARRAY_SHADOW: GLenum = 0x8DC4;
+pub const GL_SAMPLER_2D_MULTISAMPLE: GLenum = 0x9108;
+pub const GL_SAMPLER_2D_MULTISAMPLE_ARRAY: GLenum = 0x910B;
+pub const GL_SAMPLER_2D_RECT: GLenum = 0x8B63;
+pub const GL_SAMPLER_2D_RECT_SHADOW: GLenum = 0x8B64;
+pub const GL_SAMPLER_2D_SHADOW: GLenum = 0x8B62;
+pub const GL_SAMPLER_3D: GLenum = 0x8B5F;
+pub const GL_SAMPLER_BINDING: GLenum = 0x8919;
+pub const GL_SAMPLER_BUFFER: GLenum = 0x8DC2;
+pub const GL_SAMPLER_CUBE: GLenum = 0x8B60;
+pub const GL_SAMPLER_CUBE_MAP_ARRAY: GLenum = 0x900C;
+pub const GL_SAMPLER_CUBE_MAP_ARRAY_SHADOW: GLenum = 0x900D;
+pub const GL_SAMPLER_CUBE_SHADOW: GLenum = 0x8DC5;
+pub const GL_SAMPLES: GLenum = 0x80A9;
+pub const GL_SAMPLES_PASSED: GLenum = 0x8914;
+pub const GL_SAMPLE_ALPHA_TO_COVERAGE: GLenum = 0x809E;
+pub const GL_SAMPLE_ALPHA_TO_ONE: GLenum = 0x809F;
+pub const GL_SAMPLE_BUFFERS: GLenum = 0x80A8;
+pub const GL_SAMPLE_COVERAGE: GLenum = 0x80A0;
+pub const GL_SAMPLE_COVERAGE_INVERT: GLenum = 0x80AB;
+pub const GL_SAMPLE_COVERAGE_VALUE: GLenum = 0x80AA;
+pub const GL_SAMPLE_MASK: GLenum = 0x8E51;
+pub const GL_SAMPLE_MASK_VALUE: GLenum = 0x8E52;
+pub const GL_SAMPLE_POSITION: GLenum = 0x8E50;
+pub const GL_SAMPLE_SHADING: GLenum = 0x8C36;
+pub const GL_SCISSOR_BOX: GLenum = 0x0C10;
+pub const GL_SCISSOR_TEST: GLenum = 0x0C11;
+pub const GL_SEPARATE_ATTRIBS: GLenum = 0x8C8D;
+pub const GL_SET: GLenum = 0x150F;
+pub const GL_SHADER: GLenum = 0x82E1;
+pub const GL_SHADER_BINARY_FORMATS: GLenum = 0x8DF8;
+pub const GL_SHADER_BINARY_FORMAT_SPIR_V: GLenum = 0x9551;
+pub const GL_SHADER_COMPILER: GLenum = 0x8DFA;
+pub const GL_SHADER_IMAGE_ACCESS_BARRIER_BIT: GLbitfield = 0x00000020;
+pub const GL_SHADER_IMAGE_ATOMIC: GLenum = 0x82A6;
+pub const GL_SHADER_IMAGE_LOAD: GLenum = 0x82A4;
+pub const GL_SHADER_IMAGE_STORE: GLenum = 0x82A5;
+pub const GL_SHADER_SOURCE_LENGTH: GLenum = 0x8B88;
+pub const GL_SHADER_STORAGE_BARRIER_BIT: GLbitfield = 0x00002000;
+pub const GL_SHADER_STORAGE_BLOCK: GLenum = 0x92E6;
+pub const GL_SHADER_STORAGE_BUFFER: GLenum = 0x90D2;
+pub const GL_SHADER_STORAGE_BUFFER_BINDING: GLenum = 0x90D3;
+pub const GL_SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT: GLenum = 0x90DF;
+pub const GL_SHADER_STORAGE_BUFFER_SIZE: GLenum = 0x90D5;
+pub const GL_SHADER_STORAGE_BUFFER_START: GLenum = 0x90D4;
+pub const GL_SHADER_TYPE: GLenum = 0x8B4F;
+pub const GL_SHADING_LANGUAGE_VERSION: GLenum = 0x8B8C;
+pub const GL_SHORT: GLenum = 0x1402;
+pub const GL_SIGNALED: GLenum = 0x9119;
+pub const GL_SIGNED_NORMALIZED: GLenum = 0x8F9C;
+pub const GL_SIMULTANEOUS_TEXTURE_AND_DEPTH_TEST: GLenum = 0x82AC;
+pub const GL_SIMULTANEOUS_TEXTURE_AND_DEPTH_WRITE: GLenum = 0x82AE;
+pub const GL_SIMULTANEOUS_TEXTURE_AND_STENCIL_TEST: GLenum = 0x82AD;
+pub const GL_SIMULTANEOUS_TEXTURE_AND_STENCIL_WRITE: GLenum = 0x82AF;
+pub const GL_SMOOTH_LINE_WIDTH_GRANULARITY: GLenum = 0x0B23;
+pub const GL_SMOOTH_LINE_WIDTH_RANGE: GLenum = 0x0B22;
+pub const GL_SMOOTH_POINT_SIZE_GRANULARITY: GLenum = 0x0B13;
+pub const GL_SMOOTH_POINT_SIZE_RANGE: GLenum = 0x0B12;
+pub const GL_SPIR_V_BINARY: GLenum = 0x9552;
+pub const GL_SPIR_V_EXTENSIONS: GLenum = 0x9553;
+pub const GL_SRC1_ALPHA: GLenum = 0x8589;
+pub const GL_SRC1_COLOR: GLenum = 0x88F9;
+pub const GL_SRC_ALPHA: GLenum = 0x0302;
+pub const GL_SRC_ALPHA_SATURATE: GLenum = 0x0308;
+pub const GL_SRC_COLOR: GLenum = 0x0300;
+pub const GL_SRGB: GLenum = 0x8C40;
+pub const GL_SRGB8: GLenum = 0x8C41;
+pub const GL_SRGB8_ALPHA8: GLenum = 0x8C43;
+pub const GL_SRGB_ALPHA: GLenum = 0x8C42;
+pub const GL_SRGB_READ: GLenum = 0x8297;
+pub const GL_SRGB_WRITE: GLenum = 0x8298;
+pub const GL_STACK_OVERFLOW: GLenum = 0x0503;
+pub const GL_STACK_UNDERFLOW: GLenum = 0x0504;
+pub const GL_STATIC_COPY: GLenum = 0x88E6;
+pub const GL_STATIC_DRAW: GLenum = 0x88E4;
+pub const GL_STATIC_READ: GLenum = 0x88E5;
+pub const GL_STENCIL: GLenum = 0x1802;
+pub const GL_STENCIL_ATTACHMENT: GLenum = 0x8D20;
+pub const GL_STENCIL_BACK_FAIL: GLenum = 0x8801;
+pub const GL_STENCIL_BACK_FUNC: GLenum = 0x8800;
+pub const GL_STENCIL_BACK_PASS_DEPTH_FAIL: GLenum = 0x8802;
+pub const GL_STENCIL_BACK_PASS_DEPTH_PASS: GLenum = 0x8803;
+pub const GL_STENCIL_BACK_REF: GLenum = 0x8CA3;
+pub const GL_STENCIL_BACK_VALUE_MASK: GLenum = 0x8CA4;
+pub const GL_STENCIL_BACK_WRITEMASK: GLenum = 0x8CA5;
+pub const GL_STENCIL_BUFFER_BIT: GLbitfield = 0x00000400;
+pub const GL_STENCIL_CLEAR_VALUE: GLenum = 0x0B91;
+pub const GL_STENCIL_COMPONENTS: GLenum = 0x8285;
+pub const GL_STENCIL_FAIL: GLenum = 0x0B94;
+pub const GL_STENCIL_FUNC: GLenum = 0x0B92;
+pub const GL_STENCIL_INDEX: GLenum = 0x1901;
+pub const GL_STENCIL_INDEX1: GLenum = 0x8D46;
+pub const GL_STENCIL_INDEX16: GLenum = 0x8D49;
+pub const GL_STENCIL_INDEX4: GLenum = 0x8D47;
+pub const GL_STENCIL_INDEX8: GLenum = 0x8D48;
+pub const GL_STENCIL_PASS_DEPTH_FAIL: GLenum = 0x0B95;
+pub const GL_STENCIL_PASS_DEPTH_PASS: GLenum = 0x0B96;
+pub const GL_STENCIL_REF: GLenum = 0x0B97;
+pub const GL_STENCIL_RENDERABLE: GLenum = 0x8288;
+pub const GL_STENCIL_TEST: GLenum = 0x0B90;
+pub const GL_STENCIL_VALUE_MASK: GLenum = 0x0B93;
+pub const GL_STENCIL_WRITEMASK: GLenum = 0x0B98;
+pub const GL_STEREO: GLenum = 0x0C33;
+pub const GL_STREAM_COPY: GLenum = 0x88E2;
+pub const GL_STREAM_DRAW: GLenum = 0x88E0;
+pub const GL_STREAM_READ: GLenum = 0x88E1;
+pub const GL_SUBPIXEL_BITS: GLenum = 0x0D50;
+pub const GL_SYNC_CONDITION: GLenum = 0x9113;
+pub const GL_SYNC_FENCE: GLenum = 0x9116;
+pub const GL_SYNC_FLAGS: GLenum = 0x9115;
+pub const GL_SYNC_FLUSH_COMMANDS_BIT: GLbitfield = 0x00000001;
+pub const GL_SYNC_GPU_COMMANDS_COMPLETE: GLenum = 0x9117;
+pub const GL_SYNC_STATUS: GLenum = 0x9114;
+pub const GL_TESS_CONTROL_OUTPUT_VERTICES: GLenum = 0x8E75;
+pub const GL_TESS_CONTROL_SHADER: GLenum = 0x8E88;
+pub const GL_TESS_CONTROL_SHADER_BIT: GLbitfield = 0x00000008;
+pub const GL_TESS_CONTROL_SHADER_PATCHES: GLenum = 0x82F1;
+pub const GL_TESS_CONTROL_SUBROUTINE: GLenum = 0x92E9;
+pub const GL_TESS_CONTROL_SUBROUTINE_UNIFORM: GLenum = 0x92EF;
+pub const GL_TESS_CONTROL_TEXTURE: GLenum = 0x829C;
+pub const GL_TESS_EVALUATION_SHADER: GLenum = 0x8E87;
+pub const GL_TESS_EVALUATION_SHADER_BIT: GLbitfield = 0x00000010;
+pub const GL_TESS_EVALUATION_SHADER_INVOCATIONS: GLenum = 0x82F2;
+pub const GL_TESS_EVALUATION_SUBROUTINE: GLenum = 0x92EA;
+pub const GL_TESS_EVALUATION_SUBROUTINE_UNIFORM: GLenum = 0x92F0;
+pub const GL_TESS_EVALUATION_TEXTURE: GLenum = 0x829D;
+pub const GL_TESS_GEN_MODE: GLenum = 0x8E76;
+pub const GL_TESS_GEN_POINT_MODE: GLenum = 0x8E79;
+pub const GL_TESS_GEN_SPACING: GLenum = 0x8E77;
+pub const GL_TESS_GEN_VERTEX_ORDER: GLenum = 0x8E78;
+pub const GL_TEXTURE: GLenum = 0x1702;
+pub const GL_TEXTURE0: GLenum = 0x84C0;
+pub const GL_TEXTURE1: GLenum = 0x84C1;
+pub const GL_TEXTURE10: GLenum = 0x84CA;
+pub const GL_TEXTURE11: GLenum = 0x84CB;
+pub const GL_TEXTURE12: GLenum = 0x84CC;
+pub const GL_TEXTURE13: GLenum = 0x84CD;
+pub const GL_TEXTURE14: GLenum = 0x84CE;
+pub const GL_TEXTURE15: GLenum = 0x84CF;
+pub const GL_TEXTURE16: GLenum = 0x84D0;
+pub const GL_TEXTURE17: GLenum = 0x84D1;
+pub const GL_TEXTURE18: GLenum = 0x84D2;
+pub const GL_TEXTURE19: GLenum = 0x84D3;
+pub const GL_TEXTURE2: GLenum = 0x84C2;
+pub const GL_TEXTURE20: GLenum = 0x84D4;
+pub const GL_TEXTURE21: GLenum = 0x84D5;
+pub const GL_TEXTURE22: GLenum = 0x84D6;
+pub const GL_TEXTURE23: GLenum = 0x84D7;
+pub const GL_TEXTURE24: GLenum = 0x84D8;
+pub const GL_TEXTURE25: GLenum = 0x84D9;
+pub const GL_TEXTURE26: GLenum = 0x84DA;
+pub const GL_TEXTURE27: GLenum = 0x84DB;
+pub const GL_TEXTURE28: GLenum = 0x84DC;
+pub const GL_TEXTURE29: GLenum = 0x84DD;
+pub const GL_TEXTURE3: GLenum = 0x84C3;
+pub const GL_TEXTURE30: GLenum = 0x84DE;
+pub const GL_TEXTURE31: GLenum = 0x84DF;
+pub const GL_TEXTURE4: GLenum = 0x84C4;
+pub const GL_TEXTURE5: GLenum = 0x84C5;
+pub const GL_TEXTURE6: GLenum = 0x84C6;
+pub const GL_TEXTURE7: GLenum = 0x84C7;
+pub const GL_TEXTURE8: GLenum = 0x84C8;
+pub const GL_TEXTURE9: GLenum = 0x84C9;
+pub const GL_TEXTURE_1D: GLenum = 0x0DE0;
+pub const GL_TEXTURE_1D_ARRAY: GLenum = 0x8C18;
+pub const GL_TEXTURE_2D: GLenum = 0x0DE1;
+pub const GL_TEXTURE_2D_ARRAY: GLenum = 0x8C1A;
+pub const GL_TEXTURE_2D_MULTISAMPLE: GLenum = 0x9100;
+pub const GL_TEXTURE_2D_MULTISAMPLE_ARRAY: GLenum = 0x9102;
+pub const GL_TEXTURE_3D: GLenum = 0x806F;
+pub const GL_TEXTURE_ALPHA_SIZE: GLenum = 0x805F;
+pub const GL_TEXTURE_ALPHA_TYPE: GLenum = 0x8C13;
+pub const GL_TEXTURE_BASE_LEVEL: GLenum = 0x813C;
+pub const GL_TEXTURE_BINDING_1D: GLenum = 0x8068;
+pub const GL_TEXTURE_BINDING_1D_ARRAY: GLenum = 0x8C1C;
+pub const GL_TEXTURE_BINDING_2D: GLenum = 0x8069;
+pub const GL_TEXTURE_BINDING_2D_ARRAY: GLenum = 0x8C1D;
+pub const GL_TEXTURE_BINDING_2D_MULTISAMPLE: GLenum = 0x9104;
+pub const GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY: GLenum = 0x9105;
+pub const GL_TEXTURE_BINDING_3D: GLenum = 0x806A;
+pub const GL_TEXTURE_BINDING_BUFFER: GLenum = 0x8C2C;
+pub const GL_TEXTURE_BINDING_CUBE_MAP: GLenum = 0x8514;
+pub const GL_TEXTURE_BINDING_CUBE_MAP_ARRAY: GLenum = 0x900A;
+pub const GL_TEXTURE_BINDING_RECTANGLE: GLenum = 0x84F6;
+pub const GL_TEXTURE_BLUE_SIZE: GLenum = 0x805E;
+pub const GL_TEXTURE_BLUE_TYPE: GLenum = 0x8C12;
+pub const GL_TEXTURE_BORDER_COLOR: GLenum = 0x1004;
+pub const GL_TEXTURE_BUFFER: GLenum = 0x8C2A;
+pub const GL_TEXTURE_BUFFER_BINDING: GLenum = 0x8C2A;
+pub const GL_TEXTURE_BUFFER_DATA_STORE_BINDING: GLenum = 0x8C2D;
+pub const GL_TEXTURE_BUFFER_OFFSET: GLenum = 0x919D;
+pub const GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT: GLenum = 0x919F;
+pub const GL_TEXTURE_BUFFER_SIZE: GLenum = 0x919E;
+pub const GL_TEXTURE_COMPARE_FUNC: GLenum = 0x884D;
+pub const GL_TEXTURE_COMPARE_MODE: GLenum = 0x884C;
+pub const GL_TEXTURE_COMPRESSED: GLenum = 0x86A1;
+pub const GL_TEXTURE_COMPRESSED_BLOCK_HEIGHT: GLenum = 0x82B2;
+pub const GL_TEXTURE_COMPRESSED_BLOCK_SIZE: GLenum = 0x82B3;
+pub const GL_TEXTURE_COMPRESSED_BLOCK_WIDTH: GLenum = 0x82B1;
+pub const GL_TEXTURE_COMPRESSED_IMAGE_SIZE: GLenum = 0x86A0;
+pub const GL_TEXTURE_COMPRESSION_HINT: GLenum = 0x84EF;
+pub const GL_TEXTURE_CUBE_MAP: GLenum = 0x8513;
+pub const GL_TEXTURE_CUBE_MAP_ARRAY: GLenum = 0x9009;
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_X: GLenum = 0x8516;
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Y: GLenum = 0x8518;
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Z: GLenum = 0x851A;
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_X: GLenum = 0x8515;
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Y: GLenum = 0x8517;
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Z: GLenum = 0x8519;
+pub const GL_TEXTURE_CUBE_MAP_SEAMLESS: GLenum = 0x884F;
+pub const GL_TEXTURE_DEPTH: GLenum = 0x8071;
+pub const GL_TEXTURE_DEPTH_SIZE: GLenum = 0x884A;
+pub const GL_TEXTURE_DEPTH_TYPE: GLenum = 0x8C16;
+pub const GL_TEXTURE_FETCH_BARRIER_BIT: GLbitfield = 0x00000008;
+pub const GL_TEXTURE_FIXED_SAMPLE_LOCATIONS: GLenum = 0x9107;
+pub const GL_TEXTURE_GATHER: GLenum = 0x82A2;
+pub const GL_TEXTURE_GATHER_SHADOW: GLenum = 0x82A3;
+pub const GL_TEXTURE_GREEN_SIZE: GLenum = 0x805D;
+pub const GL_TEXTURE_GREEN_TYPE: GLenum = 0x8C11;
+pub const GL_TEXTURE_HEIGHT: GLenum = 0x1001;
+pub const GL_TEXTURE_IMAGE_FORMAT: GLenum = 0x828F;
+pub const GL_TEXTURE_IMAGE_TYPE: GLenum = 0x8290;
+pub const GL_TEXTURE_IMMUTABLE_FORMAT: GLenum = 0x912F;
+pub const GL_TEXTURE_IMMUTABLE_LEVELS: GLenum = 0x82DF;
+pub const GL_TEXTURE_INTERNAL_FORMAT: GLenum = 0x1003;
+pub const GL_TEXTURE_LOD_BIAS: GLenum = 0x8501;
+pub const GL_TEXTURE_MAG_FILTER: GLenum = 0x2800;
+pub const GL_TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FE;
+pub const GL_TEXTURE_MAX_LEVEL: GLenum = 0x813D;
+pub const GL_TEXTURE_MAX_LOD: GLenum = 0x813B;
+pub const GL_TEXTURE_MIN_FILTER: GLenum = 0x2801;
+pub const GL_TEXTURE_MIN_LOD: GLenum = 0x813A;
+pub const GL_TEXTURE_RECTANGLE: GLenum = 0x84F5;
+pub const GL_TEXTURE_RED_SIZE: GLenum = 0x805C;
+pub const GL_TEXTURE_RED_TYPE: GLenum = 0x8C10;
+pub const GL_TEXTURE_SAMPLES: GLenum = 0x9106;
+pub const GL_TEXTURE_SHADOW: GLenum = 0x82A1;
+pub const GL_TEXTURE_SHARED_SIZE: GLenum = 0x8C3F;
+pub const GL_TEXTURE_STENCIL_SIZE: GLenum = 0x88F1;
+pub const GL_TEXTURE_SWIZZLE_A: GLenum = 0x8E45;
+pub const GL_TEXTURE_SWIZZLE_B: GLenum = 0x8E44;
+pub const GL_TEXTURE_SWIZZLE_G: GLenum = 0x8E43;
+pub const GL_TEXTURE_SWIZZLE_R: GLenum = 0x8E42;
+pub const GL_TEXTURE_SWIZZLE_RGBA: GLenum = 0x8E46;
+pub const GL_TEXTURE_TARGET: GLenum = 0x1006;
+pub const GL_TEXTURE_UPDATE_BARRIER_BIT: GLbitfield = 0x00000100;
+pub const GL_TEXTURE_VIEW: GLenum = 0x82B5;
+pub const GL_TEXTURE_VIEW_MIN_LAYER: GLenum = 0x82DD;
+pub const GL_TEXTURE_VIEW_MIN_LEVEL: GLenum = 0x82DB;
+pub const GL_TEXTURE_VIEW_NUM_LAYERS: GLenum = 0x82DE;
+pub const GL_TEXTURE_VIEW_NUM_LEVELS: GLenum = 0x82DC;
+pub const GL_TEXTURE_WIDTH: GLenum = 0x1000;
+pub const GL_TEXTURE_WRAP_R: GLenum = 0x8072;
+pub const GL_TEXTURE_WRAP_S: GLenum = 0x2802;
+pub const GL_TEXTURE_WRAP_T: GLenum = 0x2803;
+pub const GL_TIMEOUT_EXPIRED: GLenum = 0x911B;
+pub const GL_TIMEOUT_IGNORED: GLuint64 = 0xFFFFFFFFFFFFFFFF;
+pub const GL_TIMESTAMP: GLenum = 0x8E28;
+pub const GL_TIME_ELAPSED: GLenum = 0x88BF;
+pub const GL_TOP_LEVEL_ARRAY_SIZE: GLenum = 0x930C;
+pub const GL_TOP_LEVEL_ARRAY_STRIDE: GLenum = 0x930D;
+pub const GL_TRANSFORM_FEEDBACK: GLenum = 0x8E22;
+pub const GL_TRANSFORM_FEEDBACK_ACTIVE: GLenum = 0x8E24;
+pub const GL_TRANSFORM_FEEDBACK_BARRIER_BIT: GLbitfield = 0x00000800;
+pub const GL_TRANSFORM_FEEDBACK_BINDING: GLenum = 0x8E25;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER: GLenum = 0x8C8E;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_ACTIVE: GLenum = 0x8E24;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_BINDING: GLenum = 0x8C8F;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_INDEX: GLenum = 0x934B;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_MODE: GLenum = 0x8C7F;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_PAUSED: GLenum = 0x8E23;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_SIZE: GLenum = 0x8C85;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_START: GLenum = 0x8C84;
+pub const GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE: GLenum = 0x934C;
+pub const GL_TRANSFORM_FEEDBACK_OVERFLOW: GLenum = 0x82EC;
+pub const GL_TRANSFORM_FEEDBACK_PAUSED: GLenum = 0x8E23;
+pub const GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN: GLenum = 0x8C88;
+pub const GL_TRANSFORM_FEEDBACK_STREAM_OVERFLOW: GLenum = 0x82ED;
+pub const GL_TRANSFORM_FEEDBACK_VARYING: GLenum = 0x92F4;
+pub const GL_TRANSFORM_FEEDBACK_VARYINGS: GLenum = 0x8C83;
+pub const GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH: GLenum = 0x8C76;
+pub const GL_TRIANGLES: GLenum = 0x0004;
+pub const GL_TRIANGLES_ADJACENCY: GLenum = 0x000C;
+pub const GL_TRIANGLE_FAN: GLenum = 0x0006;
+pub const GL_TRIANGLE_STRIP: GLenum = 0x0005;
+pub const GL_TRIANGLE_STRIP_ADJACENCY: GLenum = 0x000D;
+pub const GL_TRUE: GLenum = 1;
+pub const GL_TYPE: GLenum = 0x92FA;
+pub const GL_UNDEFINED_VERTEX: GLenum = 0x8260;
+pub const GL_UNIFORM: GLenum = 0x92E1;
+pub const GL_UNIFORM_ARRAY_STRIDE: GLenum = 0x8A3C;
+pub const GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX: GLenum = 0x92DA;
+pub const GL_UNIFORM_BARRIER_BIT: GLbitfield = 0x00000004;
+pub const GL_UNIFORM_BLOCK: GLenum = 0x92E2;
+pub const GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS: GLenum = 0x8A42;
+pub const GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES: GLenum = 0x8A43;
+pub const GL_UNIFORM_BLOCK_BINDING: GLenum = 0x8A3F;
+pub const GL_UNIFORM_BLOCK_DATA_SIZE: GLenum = 0x8A40;
+pub const GL_UNIFORM_BLOCK_INDEX: GLenum = 0x8A3A;
+pub const GL_UNIFORM_BLOCK_NAME_LENGTH: GLenum = 0x8A41;
+pub const GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER: GLenum = 0x90EC;
+pub const GL_UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER: GLenum = 0x8A46;
+pub const GL_UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER: GLenum = 0x8A45;
+pub const GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER: GLenum = 0x84F0;
+pub const GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER: GLenum = 0x84F1;
+pub const GL_UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER: GLenum = 0x8A44;
+pub const GL_UNIFORM_BUFFER: GLenum = 0x8A11;
+pub const GL_UNIFORM_BUFFER_BINDING: GLenum = 0x8A28;
+pub const GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT: GLenum = 0x8A34;
+pub const GL_UNIFORM_BUFFER_SIZE: GLenum = 0x8A2A;
+pub const GL_UNIFORM_BUFFER_START: GLenum = 0x8A29;
+pub const GL_UNIFORM_IS_ROW_MAJOR: GLenum = 0x8A3E;
+pub const GL_UNIFORM_MATRIX_STRIDE: GLenum = 0x8A3D;
+pub const GL_UNIFORM_NAME_LENGTH: GLenum = 0x8A39;
+pub const GL_UNIFORM_OFFSET: GLenum = 0x8A3B;
+pub const GL_UNIFORM_SIZE: GLenum = 0x8A38;
+pub const GL_UNIFORM_TYPE: GLenum = 0x8A37;
+pub const GL_UNKNOWN_CONTEXT_RESET: GLenum = 0x8255;
+pub const GL_UNPACK_ALIGNMENT: GLenum = 0x0CF5;
+pub const GL_UNPACK_COMPRESSED_BLOCK_DEPTH: GLenum = 0x9129;
+pub const GL_UNPACK_COMPRESSED_BLOCK_HEIGHT: GLenum = 0x9128;
+pub const GL_UNPACK_COMPRESSED_BLOCK_SIZE: GLenum = 0x912A;
+pub const GL_UNPACK_COMPRESSED_BLOCK_WIDTH: GLenum = 0x9127;
+pub const GL_UNPACK_IMAGE_HEIGHT: GLenum = 0x806E;
+pub const GL_UNPACK_LSB_FIRST: GLenum = 0x0CF1;
+pub const GL_UNPACK_ROW_LENGTH: GLenum = 0x0CF2;
+pub const GL_UNPACK_SKIP_IMAGES: GLenum = 0x806D;
+pub const GL_UNPACK_SKIP_PIXELS: GLenum = 0x0CF4;
+pub const GL_UNPACK_SKIP_ROWS: GLenum = 0x0CF3;
+pub const GL_UNPACK_SWAP_BYTES: GLenum = 0x0CF0;
+pub const GL_UNSIGNALED: GLenum = 0x9118;
+pub const GL_UNSIGNED_BYTE: GLenum = 0x1401;
+pub const GL_UNSIGNED_BYTE_2_3_3_REV: GLenum = 0x8362;
+pub const GL_UNSIGNED_BYTE_3_3_2: GLenum = 0x8032;
+pub const GL_UNSIGNED_INT: GLenum = 0x1405;
+pub const GL_UNSIGNED_INT_10F_11F_11F_REV: GLenum = 0x8C3B;
+pub const GL_UNSIGNED_INT_10_10_10_2: GLenum = 0x8036;
+pub const GL_UNSIGNED_INT_24_8: GLenum = 0x84FA;
+pub const GL_UNSIGNED_INT_2_10_10_10_REV: GLenum = 0x8368;
+pub const GL_UNSIGNED_INT_5_9_9_9_REV: GLenum = 0x8C3E;
+pub const GL_UNSIGNED_INT_8_8_8_8: GLenum = 0x8035;
+pub const GL_UNSIGNED_INT_8_8_8_8_REV: GLenum = 0x8367;
+pub const GL_UNSIGNED_INT_ATOMIC_COUNTER: GLenum = 0x92DB;
+pub const GL_UNSIGNED_INT_IMAGE_1D: GLenum = 0x9062;
+pub const GL_UNSIGNED_INT_IMAGE_1D_ARRAY: GLenum = 0x9068;
+pub const GL_UNSIGNED_INT_IMAGE_2D: GLenum = 0x9063;
+pub const GL_UNSIGNED_INT_IMAGE_2D_ARRAY: GLenum = 0x9069;
+pub const GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE: GLenum = 0x906B;
+pub const GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY: GLenum = 0x906C;
+pub const GL_UNSIGNED_INT_IMAGE_2D_RECT: GLenum = 0x9065;
+pub const GL_UNSIGNED_INT_IMAGE_3D: GLenum = 0x9064;
+pub const GL_UNSIGNED_INT_IMAGE_BUFFER: GLenum = 0x9067;
+pub const GL_UNSIGNED_INT_IMAGE_CUBE: GLenum = 0x9066;
+pub const GL_UNSIGNED_INT_IMAGE_CUBE_MAP_ARRAY: GLenum = 0x906A;
+pub const GL_UNSIGNED_INT_SAMPLER_1D: GLenum = 0x8DD1;
+pub const GL_UNSIGNED_INT_SAMPLER_1D_ARRAY: GLenum = 0x8DD6;
+pub const GL_UNSIGNED_INT_SAMPLER_2D: GLenum = 0x8DD2;
+pub const GL_UNSIGNED_INT_SAMPLER_2D_ARRAY: GLenum = 0x8DD7;
+pub const GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE: GLenum = 0x910A;
+pub const GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY: GLenum = 0x910D;
+pub const GL_UNSIGNED_INT_SAMPLER_2D_RECT: GLenum = 0x8DD5;
+pub const GL_UNSIGNED_INT_SAMPLER_3D: GLenum = 0x8DD3;
+pub const GL_UNSIGNED_INT_SAMPLER_BUFFER: GLenum = 0x8DD8;
+pub const GL_UNSIGNED_INT_SAMPLER_CUBE: GLenum = 0x8DD4;
+pub const GL_UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY: GLenum = 0x900F;
+pub const GL_UNSIGNED_INT_VEC2: GLenum = 0x8DC6;
+pub const GL_UNSIGNED_INT_VEC3: GLenum = 0x8DC7;
+pub const GL_UNSIGNED_INT_VEC4: GLenum = 0x8DC8;
+pub const GL_UNSIGNED_NORMALIZED: GLenum = 0x8C17;
+pub const GL_UNSIGNED_SHORT: GLenum = 0x1403;
+pub const GL_UNSIGNED_SHORT_1_5_5_5_REV: GLenum = 0x8366;
+pub const GL_UNSIGNED_SHORT_4_4_4_4: GLenum = 0x8033;
+pub const GL_UNSIGNED_SHORT_4_4_4_4_REV: GLenum = 0x8365;
+pub const GL_UNSIGNED_SHORT_5_5_5_1: GLenum = 0x8034;
+pub const GL_UNSIGNED_SHORT_5_6_5: GLenum = 0x8363;
+pub const GL_UNSIGNED_SHORT_5_6_5_REV: GLenum = 0x8364;
+pub const GL_UPPER_LEFT: GLenum = 0x8CA2;
+pub const GL_VALIDATE_STATUS: GLenum = 0x8B83;
+pub const GL_VENDOR: GLenum = 0x1F00;
+pub const GL_VERSION: GLenum = 0x1F02;
+pub const GL_VERTEX_ARRAY: GLenum = 0x8074;
+pub const GL_VERTEX_ARRAY_BINDING: GLenum = 0x85B5;
+pub const GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT: GLbitfield = 0x00000001;
+pub const GL_VERTEX_ATTRIB_ARRAY_BUFFER_BINDING: GLenum = 0x889F;
+pub const GL_VERTEX_ATTRIB_ARRAY_DIVISOR: GLenum = 0x88FE;
+pub const GL_VERTEX_ATTRIB_ARRAY_ENABLED: GLenum = 0x8622;
+pub const GL_VERTEX_ATTRIB_ARRAY_INTEGER: GLenum = 0x88FD;
+pub const GL_VERTEX_ATTRIB_ARRAY_LONG: GLenum = 0x874E;
+pub const GL_VERTEX_ATTRIB_ARRAY_NORMALIZED: GLenum = 0x886A;
+pub const GL_VERTEX_ATTRIB_ARRAY_POINTER: GLenum = 0x8645;
+pub const GL_VERTEX_ATTRIB_ARRAY_SIZE: GLenum = 0x8623;
+pub const GL_VERTEX_ATTRIB_ARRAY_STRIDE: GLenum = 0x8624;
+pub const GL_VERTEX_ATTRIB_ARRAY_TYPE: GLenum = 0x8625;
+pub const GL_VERTEX_ATTRIB_BINDING: GLenum = 0x82D4;
+pub const GL_VERTEX_ATTRIB_RELATIVE_OFFSET: GLenum = 0x82D5;
+pub const GL_VERTEX_BINDING_BUFFER: GLenum = 0x8F4F;
+pub const GL_VERTEX_BINDING_DIVISOR: GLenum = 0x82D6;
+pub const GL_VERTEX_BINDING_OFFSET: GLenum = 0x82D7;
+pub const GL_VERTEX_BINDING_STRIDE: GLenum = 0x82D8;
+pub const GL_VERTEX_PROGRAM_POINT_SIZE: GLenum = 0x8642;
+pub const GL_VERTEX_SHADER: GLenum = 0x8B31;
+pub const GL_VERTEX_SHADER_BIT: GLbitfield = 0x00000001;
+pub const GL_VERTEX_SHADER_INVOCATIONS: GLenum = 0x82F0;
+pub const GL_VERTEX_SUBROUTINE: GLenum = 0x92E8;
+pub const GL_VERTEX_SUBROUTINE_UNIFORM: GLenum = 0x92EE;
+pub const GL_VERTEX_TEXTURE: GLenum = 0x829B;
+pub const GL_VERTICES_SUBMITTED: GLenum = 0x82EE;
+pub const GL_VIEWPORT: GLenum = 0x0BA2;
+pub const GL_VIEWPORT_BOUNDS_RANGE: GLenum = 0x825D;
+pub const GL_VIEWPORT_INDEX_PROVOKING_VERTEX: GLenum = 0x825F;
+pub const GL_VIEWPORT_SUBPIXEL_BITS: GLenum = 0x825C;
+pub const GL_VIEW_CLASS_128_BITS: GLenum = 0x82C4;
+pub const GL_VIEW_CLASS_16_BITS: GLenum = 0x82CA;
+pub const GL_VIEW_CLASS_24_BITS: GLenum = 0x82C9;
+pub const GL_VIEW_CLASS_32_BITS: GLenum = 0x82C8;
+pub const GL_VIEW_CLASS_48_BITS: GLenum = 0x82C7;
+pub const GL_VIEW_CLASS_64_BITS: GLenum = 0x82C6;
+pub const GL_VIEW_CLASS_8_BITS: GLenum = 0x82CB;
+pub const GL_VIEW_CLASS_96_BITS: GLenum = 0x82C5;
+pub const GL_VIEW_CLASS_BPTC_FLOAT: GLenum = 0x82D3;
+pub const GL_VIEW_CLASS_BPTC_UNORM: GLenum = 0x82D2;
+pub const GL_VIEW_CLASS_RGTC1_RED: GLenum = 0x82D0;
+pub const GL_VIEW_CLASS_RGTC2_RG: GLenum = 0x82D1;
+pub const GL_VIEW_CLASS_S3TC_DXT1_RGB: GLenum = 0x82CC;
+pub const GL_VIEW_CLASS_S3TC_DXT1_RGBA: GLenum = 0x82CD;
+pub const GL_VIEW_CLASS_S3TC_DXT3_RGBA: GLenum = 0x82CE;
+pub const GL_VIEW_CLASS_S3TC_DXT5_RGBA: GLenum = 0x82CF;
+pub const GL_VIEW_COMPATIBILITY_CLASS: GLenum = 0x82B6;
+pub const GL_WAIT_FAILED: GLenum = 0x911D;
+pub const GL_WRITE_ONLY: GLenum = 0x88B9;
+pub const GL_XOR: GLenum = 0x1506;
+pub const GL_ZERO: GLenum = 0;
+pub const GL_ZERO_TO_ONE: GLenum = 0x935F;
+
+/// Gets the name of a `GLenum` value, if just one enum has it.
+pub const fn gl_enum_name(value: GLenum) -> Option<&'static str> {
+  Some(match value {
+    0x0002 => "GL_LINE_LOOP",
+    0x0003 => "GL_LINE_STRIP",
+    0x0004 => "GL_TRIANGLES",
+    0x0005 => "GL_TRIANGLE_STRIP",
+    0x0006 => "GL_TRIANGLE_FAN",
+    0x0007 => "GL_QUADS",
+    0x000A => "GL_LINES_ADJACENCY",
+    0x000B => "GL_LINE_STRIP_ADJACENCY",
+    0x000C => "GL_TRIANGLES_ADJACENCY",
+    0x000D => "GL_TRIANGLE_STRIP_ADJACENCY",
+    0x000E => "GL_PATCHES",
+    0x0200 => "GL_NEVER",
+    0x0201 => "GL_LESS",
+    0x0202 => "GL_EQUAL",
+    0x0203 => "GL_LEQUAL",
+    0x0204 => "GL_GREATER",
+    0x0205 => "GL_NOTEQUAL",
+    0x0206 => "GL_GEQUAL",
+    0x0207 => "GL_ALWAYS",
+    0x0300 => "GL_SRC_COLOR",
+    0x0301 => "GL_ONE_MINUS_SRC_COLOR",
+    0x0302 => "GL_SRC_ALPHA",
+    0x0303 => "GL_ONE_MINUS_SRC_ALPHA",
+    0x0304 => "GL_DST_ALPHA",
+    0x0305 => "GL_ONE_MINUS_DST_ALPHA",
+    0x0306 => "GL_DST_COLOR",
+    0x0307 => "GL_ONE_MINUS_DST_COLOR",
+    0x0308 => "GL_SRC_ALPHA_SATURATE",
+    0x0400 => "GL_FRONT_LEFT",
+    0x0401 => "GL_FRONT_RIGHT",
+    0x0402 => "GL_BACK_LEFT",
+    0x0403 => "GL_BACK_RIGHT",
+    0x0404 => "GL_FRONT",
+    0x0405 => "GL_BACK",
+    0x0406 => "GL_LEFT",
+    0x0407 => "GL_RIGHT",
+    0x0408 => "GL_FRONT_AND_BACK",
+    0x0500 => "GL_INVALID_ENUM",
+    0x0501 => "GL_INVALID_VALUE",
+    0x0502 => "GL_INVALID_OPERATION",
+    0x0503 => "GL_STACK_OVERFLOW",
+    0x0504 => "GL_STACK_UNDERFLOW",
+    0x0505 => "GL_OUT_OF_MEMORY",
+    0x0506 => "GL_INVALID_FRAMEBUFFER_OPERATION",
+    0x0507 => "GL_CONTEXT_LOST",
+    0x0900 => "GL_CW",
+    0x0901 => "GL_CCW",
+    0x0B11 => "GL_POINT_SIZE",
+    0x0B20 => "GL_LINE_SMOOTH",
+    0x0B21 => "GL_LINE_WIDTH",
+    0x0B40 => "GL_POLYGON_MODE",
+    0x0B41 => "GL_POLYGON_SMOOTH",
+    0x0B44 => "GL_CULL_FACE",
+    0x0B45 => "GL_CULL_FACE_MODE",
+    0x0B46 => "GL_FRONT_FACE",
+    0x0B70 => "GL_DEPTH_RANGE",
+    0x0B71 => "GL_DEPTH_TEST",
+    0x0B72 => "GL_DEPTH_WRITEMASK",
+    0x0B73 => "GL_DEPTH_CLEAR_VALUE",
+    0x0B74 => "GL_DEPTH_FUNC",
+    0x0B90 => "GL_STENCIL_TEST",
+    0x0B91 => "GL_STENCIL_CLEAR_VALUE",
+    0x0B92 => "GL_STENCIL_FUNC",
+    0x0B93 => "GL_STENCIL_VALUE_MASK",
+    0x0B94 => "GL_STENCIL_FAIL",
+    0x0B95 => "GL_STENCIL_PASS_DEPTH_FAIL",
+    0x0B96 => "GL_STENCIL_PASS_DEPTH_PASS",
+    0x0B97 => "GL_STENCIL_REF",
+    0x0B98 => "GL_STENCIL_WRITEMASK",
+    0x0BA2 => "GL_VIEWPORT",
+    0x0BD0 => "GL_DITHER",
+    0x0BE0 => "GL_BLEND_DST",
+    0x0BE1 => "GL_BLEND_SRC",
+    0x0BE2 => "GL_BLEND",
+    0x0BF0 => "GL_LOGIC_OP_MODE",
+    0x0BF2 => "GL_COLOR_LOGIC_OP",
+    0x0C01 => "GL_DRAW_BUFFER",
+    0x0C02 => "GL_READ_BUFFER",
+    0x0C10 => "GL_SCISSOR_BOX",
+    0x0C11 => "GL_SCISSOR_TEST",
+    0x0C22 => "GL_COLOR_CLEAR_VALUE",
+    0x0C23 => "GL_COLOR_WRITEMASK",
+    0x0C32 => "GL_DOUBLEBUFFER",
+    0x0C33 => "GL_STEREO",
+    0x0C52 => "GL_LINE_SMOOTH_HINT",
+    0x0C53 => "GL_POLYGON_SMOOTH_HINT",
+    0x0CF0 => "GL_UNPACK_SWAP_BYTES",
+    0x0CF1 => "GL_UNPACK_LSB_FIRST",
+    0x0CF2 => "GL_UNPACK_ROW_LENGTH",
+    0x0CF3 => "GL_UNPACK_SKIP_ROWS",
+    0x0CF4 => "GL_UNPACK_SKIP_PIXELS",
+    0x0CF5 => "GL_UNPACK_ALIGNMENT",
+    0x0D00 => "GL_PACK_SWAP_BYTES",
+    0x0D01 => "GL_PACK_LSB_FIRST",
+    0x0D02 => "GL_PACK_ROW_LENGTH",
+    0x0D03 => "GL_PACK_SKIP_ROWS",
+    0x0D04 => "GL_PACK_SKIP_PIXELS",
+    0x0D05 => "GL_PACK_ALIGNMENT",
+    0x0D32 => "GL_MAX_CLIP_DISTANCES",
+    0x0D33 => "GL_MAX_TEXTURE_SIZE",
+    0x0D3A => "GL_MAX_VIEWPORT_DIMS",
+    0x0D50 => "GL_SUBPIXEL_BITS",
+    0x0DE0 => "GL_TEXTURE_1D",
+    0x0DE1 => "GL_TEXTURE_2D",
+    0x1000 => "GL_TEXTURE_WIDTH",
+    0x1001 => "GL_TEXTURE_HEIGHT",
+    0x1003 => "GL_TEXTURE_INTERNAL_FORMAT",
+    0x1004 => "GL_TEXTURE_BORDER_COLOR",
+    0x1006 => "GL_TEXTURE_TARGET",
+    0x1100 => "GL_DONT_CARE",
+    0x1101 => "GL_FASTEST",
+    0x1102 => "GL_NICEST",
+    0x1400 => "GL_BYTE",
+    0x1401 => "GL_UNSIGNED_BYTE",
+    0x1402 => "GL_SHORT",
+    0x1403 => "GL_UNSIGNED_SHORT",
+    0x1404 => "GL_INT",
+    0x1405 => "GL_UNSIGNED_INT",
+    0x1406 => "GL_FLOAT",
+    0x140A => "GL_DOUBLE",
+    0x140B => "GL_HALF_FLOAT",
+    0x140C => "GL_FIXED",
+    0x1500 => "GL_CLEAR",
+    0x1501 => "GL_AND",
+    0x1502 => "GL_AND_REVERSE",
+    0x1503 => "GL_COPY",
+    0x1504 => "GL_AND_INVERTED",
+    0x1505 => "GL_NOOP",
+    0x1506 => "GL_XOR",
+    0x1507 => "GL_OR",
+    0x1508 => "GL_NOR",
+    0x1509 => "GL_EQUIV",
+    0x150A => "GL_INVERT",
+    0x150B => "GL_OR_REVERSE",
+    0x150C => "GL_COPY_INVERTED",
+    0x150D => "GL_OR_INVERTED",
+    0x150E => "GL_NAND",
+    0x150F => "GL_SET",
+    0x1702 => "GL_TEXTURE",
+    0x1800 => "GL_COLOR",
+    0x1801 => "GL_DEPTH",
+    0x1802 => "GL_STENCIL",
+    0x1901 => "GL_STENCIL_INDEX",
+    0x1902 => "GL_DEPTH_COMPONENT",
+    0x1903 => "GL_RED",
+    0x1904 => "GL_GREEN",
+    0x1905 => "GL_BLUE",
+    0x1906 => "GL_ALPHA",
+    0x1907 => "GL_RGB",
+    0x1908 => "GL_RGBA",
+    0x1B00 => "GL_POINT",
+    0x1B01 => "GL_LINE",
+    0x1B02 => "GL_FILL",
+    0x1E00 => "GL_KEEP",
+    0x1E01 => "GL_REPLACE",
+    0x1E02 => "GL_INCR",
+    0x1E03 => "GL_DECR",
+    0x1F00 => "GL_VENDOR",
+    0x1F01 => "GL_RENDERER",
+    0x1F02 => "GL_VERSION",
+    0x1F03 => "GL_EXTENSIONS",
+    0x2600 => "GL_NEAREST",
+    0x2601 => "GL_LINEAR",
+    0x2700 => "GL_NEAREST_MIPMAP_NEAREST",
+    0x2701 => "GL_LINEAR_MIPMAP_NEAREST",
+    0x2702 => "GL_NEAREST_MIPMAP_LINEAR",
+    0x2703 => "GL_LINEAR_MIPMAP_LINEAR",
+    0x2800 => "GL_TEXTURE_MAG_FILTER",
+    0x2801 => "GL_TEXTURE_MIN_FILTER",
+    0x2802 => "GL_TEXTURE_WRAP_S",
+    0x2803 => "GL_TEXTURE_WRAP_T",
+    0x2901 => "GL_REPEAT",
+    0x2A00 => "GL_POLYGON_OFFSET_UNITS",
+    0x2A01 => "GL_POLYGON_OFFSET_POINT",
+    0x2A02 => "GL_POLYGON_OFFSET_LINE",
+    0x2A10 => "GL_R3_G3_B2",
+    0x3000 => "GL_CLIP_DISTANCE0",
+    0x3001 => "GL_CLIP_DISTANCE1",
+    0x3002 => "GL_CLIP_DISTANCE2",
+    0x3003 => "GL_CLIP_DISTANCE3",
+    0x3004 => "GL_CLIP_DISTANCE4",
+    0x3005 => "GL_CLIP_DISTANCE5",
+    0x3006 => "GL_CLIP_DISTANCE6",
+    0x3007 => "GL_CLIP_DISTANCE7",
+    0x8001 => "GL_CONSTANT_COLOR",
+    0x8002 => "GL_ONE_MINUS_CONSTANT_COLOR",
+    0x8003 => "GL_CONSTANT_ALPHA",
+    0x8004 => "GL_ONE_MINUS_CONSTANT_ALPHA",
+    0x8005 => "GL_BLEND_COLOR",
+    0x8006 => "GL_FUNC_ADD",
+    0x8007 => "GL_MIN",
+    0x8008 => "GL_MAX",
+    0x800A => "GL_FUNC_SUBTRACT",
+    0x800B => "GL_FUNC_REVERSE_SUBTRACT",
+    0x8032 => "GL_UNSIGNED_BYTE_3_3_2",
+    0x8033 => "GL_UNSIGNED_SHORT_4_4_4_4",
+    0x8034 => "GL_UNSIGNED_SHORT_5_5_5_1",
+    0x8035 => "GL_UNSIGNED_INT_8_8_8_8",
+    0x8036 => "GL_UNSIGNED_INT_10_10_10_2",
+    0x8037 => "GL_POLYGON_OFFSET_FILL",
+    0x8038 => "GL_POLYGON_OFFSET_FACTOR",
+    0x804F => "GL_RGB4",
+    0x8050 => "GL_RGB5",
+    0x8051 => "GL_RGB8",
+    0x8052 => "GL_RGB10",
+    0x8053 => "GL_RGB12",
+    0x8054 => "GL_RGB16",
+    0x8055 => "GL_RGBA2",
+    0x8056 => "GL_RGBA4",
+    0x8057 => "GL_RGB5_A1",
+    0x8058 => "GL_RGBA8",
+    0x8059 => "GL_RGB10_A2",
+    0x805A => "GL_RGBA12",
+    0x805B => "GL_RGBA16",
+    0x805C => "GL_TEXTURE_RED_SIZE",
+    0x805D => "GL_TEXTURE_GREEN_SIZE",
+    0x805E => "GL_TEXTURE_BLUE_SIZE",
+    0x805F => "GL_TEXTURE_ALPHA_SIZE",
+    0x8063 => "GL_PROXY_TEXTURE_1D",
+    0x8064 => "GL_PROXY_TEXTURE_2D",
+    0x8068 => "GL_TEXTURE_BINDING_1D",
+    0x8069 => "GL_TEXTURE_BINDING_2D",
+    0x806A => "GL_TEXTURE_BINDING_3D",
+    0x806B => "GL_PACK_SKIP_IMAGES",
+    0x806C => "GL_PACK_IMAGE_HEIGHT",
+    0x806D => "GL_UNPACK_SKIP_IMAGES",
+    0x806E => "GL_UNPACK_IMAGE_HEIGHT",
+    0x806F => "GL_TEXTURE_3D",
+    0x8070 => "GL_PROXY_TEXTURE_3D",
+    0x8071 => "GL_TEXTURE_DEPTH",
+    0x8072 => "GL_TEXTURE_WRAP_R",
+    0x8073 => "GL_MAX_3D_TEXTURE_SIZE",
+    0x8074 => "GL_VERTEX_ARRAY",
+    0x809D => "GL_MULTISAMPLE",
+    0x809E => "GL_SAMPLE_ALPHA_TO_COVERAGE",
+    0x809F => "GL_SAMPLE_ALPHA_TO_ONE",
+    0x80A0 => "GL_SAMPLE_COVERAGE",
+    0x80A8 => "GL_SAMPLE_BUFFERS",
+    0x80A9 => "GL_SAMPLES",
+    0x80AA => "GL_SAMPLE_COVERAGE_VALUE",
+    0x80AB => "GL_SAMPLE_COVERAGE_INVERT",
+    0x80C8 => "GL_BLEND_DST_RGB",
+    0x80C9 => "GL_BLEND_SRC_RGB",
+    0x80CA => "GL_BLEND_DST_ALPHA",
+    0x80CB => "GL_BLEND_SRC_ALPHA",
+    0x80E0 => "GL_BGR",
+    0x80E1 => "GL_BGRA",
+    0x80E8 => "GL_MAX_ELEMENTS_VERTICES",
+    0x80E9 => "GL_MAX_ELEMENTS_INDICES",
+    0x80EE => "GL_PARAMETER_BUFFER",
+    0x80EF => "GL_PARAMETER_BUFFER_BINDING",
+    0x8128 => "GL_POINT_FADE_THRESHOLD_SIZE",
+    0x812D => "GL_CLAMP_TO_BORDER",
+    0x812F => "GL_CLAMP_TO_EDGE",
+    0x813A => "GL_TEXTURE_MIN_LOD",
+    0x813B => "GL_TEXTURE_MAX_LOD",
+    0x813C => "GL_TEXTURE_BASE_LEVEL",
+    0x813D => "GL_TEXTURE_MAX_LEVEL",
+    0x81A5 => "GL_DEPTH_COMPONENT16",
+    0x81A6 => "GL_DEPTH_COMPONENT24",
+    0x81A7 => "GL_DEPTH_COMPONENT32",
+    0x8210 => "GL_FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING",
+    0x8211 => "GL_FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE",
+    0x8212 => "GL_FRAMEBUFFER_ATTACHMENT_RED_SIZE",
+    0x8213 => "GL_FRAMEBUFFER_ATTACHMENT_GREEN_SIZE",
+    0x8214 => "GL_FRAMEBUFFER_ATTACHMENT_BLUE_SIZE",
+    0x8215 => "GL_FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE",
+    0x8216 => "GL_FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE",
+    0x8217 => "GL_FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE",
+    0x8218 => "GL_FRAMEBUFFER_DEFAULT",
+    0x8219 => "GL_FRAMEBUFFER_UNDEFINED",
+    0x821A => "GL_DEPTH_STENCIL_ATTACHMENT",
+    0x821B => "GL_MAJOR_VERSION",
+    0x821C => "GL_MINOR_VERSION",
+    0x821D => "GL_NUM_EXTENSIONS",
+    0x821E => "GL_CONTEXT_FLAGS",
+    0x821F => "GL_BUFFER_IMMUTABLE_STORAGE",
+    0x8220 => "GL_BUFFER_STORAGE_FLAGS",
+    0x8221 => "GL_PRIMITIVE_RESTART_FOR_PATCHES_SUPPORTED",
+    0x8225 => "GL_COMPRESSED_RED",
+    0x8226 => "GL_COMPRESSED_RG",
+    0x8227 => "GL_RG",
+    0x8228 => "GL_RG_INTEGER",
+    0x8229 => "GL_R8",
+    0x822A => "GL_R16",
+    0x822B => "GL_RG8",
+    0x822C => "GL_RG16",
+    0x822D => "GL_R16F",
+    0x822E => "GL_R32F",
+    0x822F => "GL_RG16F",
+    0x8230 => "GL_RG32F",
+    0x8231 => "GL_R8I",
+    0x8232 => "GL_R8UI",
+    0x8233 => "GL_R16I",
+    0x8234 => "GL_R16UI",
+    0x8235 => "GL_R32I",
+    0x8236 => "GL_R32UI",
+    0x8237 => "GL_RG8I",
+    0x8238 => "GL_RG8UI",
+    0x8239 => "GL_RG16I",
+    0x823A => "GL_RG16UI",
+    0x823B => "GL_RG32I",
+    0x823C => "GL_RG32UI",
+    0x8242 => "GL_DEBUG_OUTPUT_SYNCHRONOUS",
+    0x8243 => "GL_DEBUG_NEXT_LOGGED_MESSAGE_LENGTH",
+    0x8244 => "GL_DEBUG_CALLBACK_FUNCTION",
+    0x8245 => "GL_DEBUG_CALLBACK_USER_PARAM",
+    0x8246 => "GL_DEBUG_SOURCE_API",
+    0x8247 => "GL_DEBUG_SOURCE_WINDOW_SYSTEM",
+    0x8248 => "GL_DEBUG_SOURCE_SHADER_COMPILER",
+    0x8249 => "GL_DEBUG_SOURCE_THIRD_PARTY",
+    0x824A => "GL_DEBUG_SOURCE_APPLICATION",
+    0x824B => "GL_DEBUG_SOURCE_OTHER",
+    0x824C => "GL_DEBUG_TYPE_ERROR",
+    0x824D => "GL_DEBUG_TYPE_DEPRECATED_BEHAVIOR",
+    0x824E => "GL_DEBUG_TYPE_UNDEFINED_BEHAVIOR",
+    0x824F => "GL_DEBUG_TYPE_PORTABILITY",
+    0x8250 => "GL_DEBUG_TYPE_PERFORMANCE",
+    0x8251 => "GL_DEBUG_TYPE_OTHER",
+    0x8252 => "GL_LOSE_CONTEXT_ON_RESET",
+    0x8253 => "GL_GUILTY_CONTEXT_RESET",
+    0x8254 => "GL_INNOCENT_CONTEXT_RESET",
+    0x8255 => "GL_UNKNOWN_CONTEXT_RESET",
+    0x8256 => "GL_RESET_NOTIFICATION_STRATEGY",
+    0x8257 => "GL_PROGRAM_BINARY_RETRIEVABLE_HINT",
+    0x8258 => "GL_PROGRAM_SEPARABLE",
+    0x8259 => "GL_ACTIVE_PROGRAM",
+    0x825A => "GL_PROGRAM_PIPELINE_BINDING",
+    0x825B => "GL_MAX_VIEWPORTS",
+    0x825C => "GL_VIEWPORT_SUBPIXEL_BITS",
+    0x825D => "GL_VIEWPORT_BOUNDS_RANGE",
+    0x825E => "GL_LAYER_PROVOKING_VERTEX",
+    0x825F => "GL_VIEWPORT_INDEX_PROVOKING_VERTEX",
+    0x8260 => "GL_UNDEFINED_VERTEX",
+    0x8261 => "GL_NO_RESET_NOTIFICATION",
+    0x8262 => "GL_MAX_COMPUTE_SHARED_MEMORY_SIZE",
+    0x8263 => "GL_MAX_COMPUTE_UNIFORM_COMPONENTS",
+    0x8264 => "GL_MAX_COMPUTE_ATOMIC_COUNTER_BUFFERS",
+    0x8265 => "GL_MAX_COMPUTE_ATOMIC_COUNTERS",
+    0x8266 => "GL_MAX_COMBINED_COMPUTE_UNIFORM_COMPONENTS",
+    0x8267 => "GL_COMPUTE_WORK_GROUP_SIZE",
+    0x8268 => "GL_DEBUG_TYPE_MARKER",
+    0x8269 => "GL_DEBUG_TYPE_PUSH_GROUP",
+    0x826A => "GL_DEBUG_TYPE_POP_GROUP",
+    0x826B => "GL_DEBUG_SEVERITY_NOTIFICATION",
+    0x826C => "GL_MAX_DEBUG_GROUP_STACK_DEPTH",
+    0x826D => "GL_DEBUG_GROUP_STACK_DEPTH",
+    0x826E => "GL_MAX_UNIFORM_LOCATIONS",
+    0x826F => "GL_INTERNALFORMAT_SUPPORTED",
+    0x8270 => "GL_INTERNALFORMAT_PREFERRED",
+    0x8271 => "GL_INTERNALFORMAT_RED_SIZE",
+    0x8272 => "GL_INTERNALFORMAT_GREEN_SIZE",
+    0x8273 => "GL_INTERNALFORMAT_BLUE_SIZE",
+    0x8274 => "GL_INTERNALFORMAT_ALPHA_SIZE",
+    0x8275 => "GL_INTERNALFORMAT_DEPTH_SIZE",
+    0x8276 => "GL_INTERNALFORMAT_STENCIL_SIZE",
+    0x8277 => "GL_INTERNALFORMAT_SHARED_SIZE",
+    0x8278 => "GL_INTERNALFORMAT_RED_TYPE",
+    0x8279 => "GL_INTERNALFORMAT_GREEN_TYPE",
+    0x827A => "GL_INTERNALFORMAT_BLUE_TYPE",
+    0x827B => "GL_INTERNALFORMAT_ALPHA_TYPE",
+    0x827C => "GL_INTERNALFORMAT_DEPTH_TYPE",
+    0x827D => "GL_INTERNALFORMAT_STENCIL_TYPE",
+    0x827E => "GL_MAX_WIDTH",
+    0x827F => "GL_MAX_HEIGHT",
+    0x8280 => "GL_MAX_DEPTH",
+    0x8281 => "GL_MAX_LAYERS",
+    0x8282 => "GL_MAX_COMBINED_DIMENSIONS",
+    0x8283 => "GL_COLOR_COMPONENTS",
+    0x8284 => "GL_DEPTH_COMPONENTS",
+    0x8285 => "GL_STENCIL_COMPONENTS",
+    0x8286 => "GL_COLOR_RENDERABLE",
+    0x8287 => "GL_DEPTH_RENDERABLE",
+    0x8288 => "GL_STENCIL_RENDERABLE",
+    0x8289 => "GL_FRAMEBUFFER_RENDERABLE",
+    0x828A => "GL_FRAMEBUFFER_RENDERABLE_LAYERED",
+    0x828B => "GL_FRAMEBUFFER_BLEND",
+    0x828C => "GL_READ_PIXELS",
+    0x828D => "GL_READ_PIXELS_FORMAT",
+    0x828E => "GL_READ_PIXELS_TYPE",
+    0x828F => "GL_TEXTURE_IMAGE_FORMAT",
+    0x8290 => "GL_TEXTURE_IMAGE_TYPE",
+    0x8291 => "GL_GET_TEXTURE_IMAGE_FORMAT",
+    0x8292 => "GL_GET_TEXTURE_IMAGE_TYPE",
+    0x8293 => "GL_MIPMAP",
+    0x8294 => "GL_MANUAL_GENERATE_MIPMAP",
+    0x8295 => "GL_AUTO_GENERATE_MIPMAP",
+    0x8296 => "GL_COLOR_ENCODING",
+    0x8297 => "GL_SRGB_READ",
+    0x8298 => "GL_SRGB_WRITE",
+    0x829A => "GL_FILTER",
+    0x829B => "GL_VERTEX_TEXTURE",
+    0x829C => "GL_TESS_CONTROL_TEXTURE",
+    0x829D => "GL_TESS_EVALUATION_TEXTURE",
+    0x829E => "GL_GEOMETRY_TEXTURE",
+    0x829F => "GL_FRAGMENT_TEXTURE",
+    0x82A0 => "GL_COMPUTE_TEXTURE",
+    0x82A1 => "GL_TEXTURE_SHADOW",
+    0x82A2 => "GL_TEXTURE_GATHER",
+    0x82A3 => "GL_TEXTURE_GATHER_SHADOW",
+    0x82A4 => "GL_SHADER_IMAGE_LOAD",
+    0x82A5 => "GL_SHADER_IMAGE_STORE",
+    0x82A6 => "GL_SHADER_IMAGE_ATOMIC",
+    0x82A7 => "GL_IMAGE_TEXEL_SIZE",
+    0x82A8 => "GL_IMAGE_COMPATIBILITY_CLASS",
+    0x82A9 => "GL_IMAGE_PIXEL_FORMAT",
+    0x82AA => "GL_IMAGE_PIXEL_TYPE",
+    0x82AC => "GL_SIMULTANEOUS_TEXTURE_AND_DEPTH_TEST",
+    0x82AD => "GL_SIMULTANEOUS_TEXTURE_AND_STENCIL_TEST",
+    0x82AE => "GL_SIMULTANEOUS_TEXTURE_AND_DEPTH_WRITE",
+    0x82AF => "GL_SIMULTANEOUS_TEXTURE_AND_STENCIL_WRITE",
+    0x82B1 => "GL_TEXTURE_COMPRESSED_BLOCK_WIDTH",
+    0x82B2 => "GL_TEXTURE_COMPRESSED_BLOCK_HEIGHT",
+    0x82B3 => "GL_TEXTURE_COMPRESSED_BLOCK_SIZE",
+    0x82B4 => "GL_CLEAR_BUFFER",
+    0x82B5 => "GL_TEXTURE_VIEW",
+    0x82B6 => "GL_VIEW_COMPATIBILITY_CLASS",
+    0x82B7 => "GL_FULL_SUPPORT",
+    0x82B8 => "GL_CAVEAT_SUPPORT",
+    0x82B9 => "GL_IMAGE_CLASS_4_X_32",
+    0x82BA => "GL_IMAGE_CLASS_2_X_32",
+    0x82BB => "GL_IMAGE_CLASS_1_X_32",
+    0x82BC => "GL_IMAGE_CLASS_4_X_16",
+    0x82BD => "GL_IMAGE_CLASS_2_X_16",
+    0x82BE => "GL_IMAGE_CLASS_1_X_16",
+    0x82BF => "GL_IMAGE_CLASS_4_X_8",
+    0x82C0 => "GL_IMAGE_CLASS_2_X_8",
+    0x82C1 => "GL_IMAGE_CLASS_1_X_8",
+    0x82C2 => "GL_IMAGE_CLASS_11_11_10",
+    0x82C3 => "GL_IMAGE_CLASS_10_10_10_2",
+    0x82C4 => "GL_VIEW_CLASS_128_BITS",
+    0x82C5 => "GL_VIEW_CLASS_96_BITS",
+    0x82C6 => "GL_VIEW_CLASS_64_BITS",
+    0x82C7 => "GL_VIEW_CLASS_48_BITS",
+    0x82C8 => "GL_VIEW_CLASS_32_BITS",
+    0x82C9 => "GL_VIEW_CLASS_24_BITS",
+    0x82CA => "GL_VIEW_CLASS_16_BITS",
+    0x82CB => "GL_VIEW_CLASS_8_BITS",
+    0x82CC => "GL_VIEW_CLASS_S3TC_DXT1_RGB",
+    0x82CD => "GL_VIEW_CLASS_S3TC_DXT1_RGBA",
+    0x82CE => "GL_VIEW_CLASS_S3TC_DXT3_RGBA",
+    0x82CF => "GL_VIEW_CLASS_S3TC_DXT5_RGBA",
+    0x82D0 => "GL_VIEW_CLASS_RGTC1_RED",
+    0x82D1 => "GL_VIEW_CLASS_RGTC2_RG",
+    0x82D2 => "GL_VIEW_CLASS_BPTC_UNORM",
+    0x82D3 => "GL_VIEW_CLASS_BPTC_FLOAT",
+    0x82D4 => "GL_VERTEX_ATTRIB_BINDING",
+    0x82D5 => "GL_VERTEX_ATTRIB_RELATIVE_OFFSET",
+    0x82D6 => "GL_VERTEX_BINDING_DIVISOR",
+    0x82D7 => "GL_VERTEX_BINDING_OFFSET",
+    0x82D8 => "GL_VERTEX_BINDING_STRIDE",
+    0x82D9 => "GL_MAX_VERTEX_ATTRIB_RELATIVE_OFFSET",
+    0x82DA => "GL_MAX_VERTEX_ATTRIB_BINDINGS",
+    0x82DB => "GL_TEXTURE_VIEW_MIN_LEVEL",
+    0x82DC => "GL_TEXTURE_VIEW_NUM_LEVELS",
+    0x82DD => "GL_TEXTURE_VIEW_MIN_LAYER",
+    0x82DE => "GL_TEXTURE_VIEW_NUM_LAYERS",
+    0x82DF => "GL_TEXTURE_IMMUTABLE_LEVELS",
+    0x82E0 => "GL_BUFFER",
+    0x82E1 => "GL_SHADER",
+    0x82E2 => "GL_PROGRAM",
+    0x82E3 => "GL_QUERY",
+    0x82E4 => "GL_PROGRAM_PIPELINE",
+    0x82E5 => "GL_MAX_VERTEX_ATTRIB_STRIDE",
+    0x82E6 => "GL_SAMPLER",
+    0x82E8 => "GL_MAX_LABEL_LENGTH",
+    0x82E9 => "GL_NUM_SHADING_LANGUAGE_VERSIONS",
+    0x82EA => "GL_QUERY_TARGET",
+    0x82EC => "GL_TRANSFORM_FEEDBACK_OVERFLOW",
+    0x82ED => "GL_TRANSFORM_FEEDBACK_STREAM_OVERFLOW",
+    0x82EE => "GL_VERTICES_SUBMITTED",
+    0x82EF => "GL_PRIMITIVES_SUBMITTED",
+    0x82F0 => "GL_VERTEX_SHADER_INVOCATIONS",
+    0x82F1 => "GL_TESS_CONTROL_SHADER_PATCHES",
+    0x82F2 => "GL_TESS_EVALUATION_SHADER_INVOCATIONS",
+    0x82F3 => "GL_GEOMETRY_SHADER_PRIMITIVES_EMITTED",
+    0x82F4 => "GL_FRAGMENT_SHADER_INVOCATIONS",
+    0x82F5 => "GL_COMPUTE_SHADER_INVOCATIONS",
+    0x82F6 => "GL_CLIPPING_INPUT_PRIMITIVES",
+    0x82F7 => "GL_CLIPPING_OUTPUT_PRIMITIVES",
+    0x82F9 => "GL_MAX_CULL_DISTANCES",
+    0x82FA => "GL_MAX_COMBINED_CLIP_AND_CULL_DISTANCES",
+    0x82FB => "GL_CONTEXT_RELEASE_BEHAVIOR",
+    0x82FC => "GL_CONTEXT_RELEASE_BEHAVIOR_FLUSH",
+    0x8362 => "GL_UNSIGNED_BYTE_2_3_3_REV",
+    0x8363 => "GL_UNSIGNED_SHORT_5_6_5",
+    0x8364 => "GL_UNSIGNED_SHORT_5_6_5_REV",
+    0x8365 => "GL_UNSIGNED_SHORT_4_4_4_4_REV",
+    0x8366 => "GL_UNSIGNED_SHORT_1_5_5_5_REV",
+    0x8367 => "GL_UNSIGNED_INT_8_8_8_8_REV",
+    0x8368 => "GL_UNSIGNED_INT_2_10_10_10_REV",
+    0x8370 => "GL_MIRRORED_REPEAT",
+    0x846E => "GL_ALIASED_LINE_WIDTH_RANGE",
+    0x84C0 => "GL_TEXTURE0",
+    0x84C1 => "GL_TEXTURE1",
+    0x84C2 => "GL_TEXTURE2",
+    0x84C3 => "GL_TEXTURE3",
+    0x84C4 => "GL_TEXTURE4",
+    0x84C5 => "GL_TEXTURE5",
+    0x84C6 => "GL_TEXTURE6",
+    0x84C7 => "GL_TEXTURE7",
+    0x84C8 => "GL_TEXTURE8",
+    0x84C9 => "GL_TEXTURE9",
+    0x84CA => "GL_TEXTURE10",
+    0x84CB => "GL_TEXTURE11",
+    0x84CC => "GL_TEXTURE12",
+    0x84CD => "GL_TEXTURE13",
+    0x84CE => "GL_TEXTURE14",
+    0x84CF => "GL_TEXTURE15",
+    0x84D0 => "GL_TEXTURE16",
+    0x84D1 => "GL_TEXTURE17",
+    0x84D2 => "GL_TEXTURE18",
+    0x84D3 => "GL_TEXTURE19",
+    0x84D4 => "GL_TEXTURE20",
+    0x84D5 => "GL_TEXTURE21",
+    0x84D6 => "GL_TEXTURE22",
+    0x84D7 => "GL_TEXTURE23",
+    0x84D8 => "GL_TEXTURE24",
+    0x84D9 => "GL_TEXTURE25",
+    0x84DA => "GL_TEXTURE26",
+    0x84DB => "GL_TEXTURE27",
+    0x84DC => "GL_TEXTURE28",
+    0x84DD => "GL_TEXTURE29",
+    0x84DE => "GL_TEXTURE30",
+    0x84DF => "GL_TEXTURE31",
+    0x84E0 => "GL_ACTIVE_TEXTURE",
+    0x84E8 => "GL_MAX_RENDERBUFFER_SIZE",
+    0x84ED => "GL_COMPRESSED_RGB",
+    0x84EE => "GL_COMPRESSED_RGBA",
+    0x84EF => "GL_TEXTURE_COMPRESSION_HINT",
+    0x84F0 => "GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER",
+    0x84F1 => "GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER",
+    0x84F5 => "GL_TEXTURE_RECTANGLE",
+    0x84F6 => "GL_TEXTURE_BINDING_RECTANGLE",
+    0x84F7 => "GL_PROXY_TEXTURE_RECTANGLE",
+    0x84F8 => "GL_MAX_RECTANGLE_TEXTURE_SIZE",
+    0x84F9 => "GL_DEPTH_STENCIL",
+    0x84FA => "GL_UNSIGNED_INT_24_8",
+    0x84FD => "GL_MAX_TEXTURE_LOD_BIAS",
+    0x84FE => "GL_TEXTURE_MAX_ANISOTROPY",
+    0x84FF => "GL_MAX_TEXTURE_MAX_ANISOTROPY",
+    0x8501 => "GL_TEXTURE_LOD_BIAS",
+    0x8507 => "GL_INCR_WRAP",
+    0x8508 => "GL_DECR_WRAP",
+    0x8513 => "GL_TEXTURE_CUBE_MAP",
+    0x8514 => "GL_TEXTURE_BINDING_CUBE_MAP",
+    0x8515 => "GL_TEXTURE_CUBE_MAP_POSITIVE_X",
+    0x8516 => "GL_TEXTURE_CUBE_MAP_NEGATIVE_X",
+    0x8517 => "GL_TEXTURE_CUBE_MAP_POSITIVE_Y",
+    0x8518 => "GL_TEXTURE_CUBE_MAP_NEGATIVE_Y",
+    0x8519 => "GL_TEXTURE_CUBE_MAP_POSITIVE_Z",
+    0x851A => "GL_TEXTURE_CUBE_MAP_NEGATIVE_Z",
+    0x851B => "GL_PROXY_TEXTURE_CUBE_MAP",
+    0x851C => "GL_MAX_CUBE_MAP_TEXTURE_SIZE",
+    0x8589 => "GL_SRC1_ALPHA",
+    0x85B5 => "GL_VERTEX_ARRAY_BINDING",
+    0x8622 => "GL_VERTEX_ATTRIB_ARRAY_ENABLED",
+    0x8623 => "GL_VERTEX_ATTRIB_ARRAY_SIZE",
+    0x8624 => "GL_VERTEX_ATTRIB_ARRAY_STRIDE",
+    0x8625 => "GL_VERTEX_ATTRIB_ARRAY_TYPE",
+    0x8626 => "GL_CURRENT_VERTEX_ATTRIB",
+    0x8645 => "GL_VERTEX_ATTRIB_ARRAY_POINTER",
+    0x864F => "GL_DEPTH_CLAMP",
+    0x86A0 => "GL_TEXTURE_COMPRESSED_IMAGE_SIZE",
+    0x86A1 => "GL_TEXTURE_COMPRESSED",
+    0x86A2 => "GL_NUM_COMPRESSED_TEXTURE_FORMATS",
+    0x86A3 => "GL_COMPRESSED_TEXTURE_FORMATS",
+    0x8741 => "GL_PROGRAM_BINARY_LENGTH",
+    0x8743 => "GL_MIRROR_CLAMP_TO_EDGE",
+    0x874E => "GL_VERTEX_ATTRIB_ARRAY_LONG",
+    0x8764 => "GL_BUFFER_SIZE",
+    0x8765 => "GL_BUFFER_USAGE",
+    0x87FE => "GL_NUM_PROGRAM_BINARY_FORMATS",
+    0x87FF => "GL_PROGRAM_BINARY_FORMATS",
+    0x8800 => "GL_STENCIL_BACK_FUNC",
+    0x8801 => "GL_STENCIL_BACK_FAIL",
+    0x8802 => "GL_STENCIL_BACK_PASS_DEPTH_FAIL",
+    0x8803 => "GL_STENCIL_BACK_PASS_DEPTH_PASS",
+    0x8814 => "GL_RGBA32F",
+    0x8815 => "GL_RGB32F",
+    0x881A => "GL_RGBA16F",
+    0x881B => "GL_RGB16F",
+    0x8824 => "GL_MAX_DRAW_BUFFERS",
+    0x8825 => "GL_DRAW_BUFFER0",
+    0x8826 => "GL_DRAW_BUFFER1",
+    0x8827 => "GL_DRAW_BUFFER2",
+    0x8828 => "GL_DRAW_BUFFER3",
+    0x8829 => "GL_DRAW_BUFFER4",
+    0x882A => "GL_DRAW_BUFFER5",
+    0x882B => "GL_DRAW_BUFFER6",
+    0x882C => "GL_DRAW_BUFFER7",
+    0x882D => "GL_DRAW_BUFFER8",
+    0x882E => "GL_DRAW_BUFFER9",
+    0x882F => "GL_DRAW_BUFFER10",
+    0x8830 => "GL_DRAW_BUFFER11",
+    0x8831 => "GL_DRAW_BUFFER12",
+    0x8832 => "GL_DRAW_BUFFER13",
+    0x8833 => "GL_DRAW_BUFFER14",
+    0x8834 => "GL_DRAW_BUFFER15",
+    0x883D => "GL_BLEND_EQUATION_ALPHA",
+    0x884A => "GL_TEXTURE_DEPTH_SIZE",
+    0x884C => "GL_TEXTURE_COMPARE_MODE",
+    0x884D => "GL_TEXTURE_COMPARE_FUNC",
+    0x884E => "GL_COMPARE_REF_TO_TEXTURE",
+    0x884F => "GL_TEXTURE_CUBE_MAP_SEAMLESS",
+    0x8864 => "GL_QUERY_COUNTER_BITS",
+    0x8865 => "GL_CURRENT_QUERY",
+    0x8866 => "GL_QUERY_RESULT",
+    0x8867 => "GL_QUERY_RESULT_AVAILABLE",
+    0x8869 => "GL_MAX_VERTEX_ATTRIBS",
+    0x886A => "GL_VERTEX_ATTRIB_ARRAY_NORMALIZED",
+    0x886C => "GL_MAX_TESS_CONTROL_INPUT_COMPONENTS",
+    0x886D => "GL_MAX_TESS_EVALUATION_INPUT_COMPONENTS",
+    0x8872 => "GL_MAX_TEXTURE_IMAGE_UNITS",
+    0x887F => "GL_GEOMETRY_SHADER_INVOCATIONS",
+    0x8892 => "GL_ARRAY_BUFFER",
+    0x8893 => "GL_ELEMENT_ARRAY_BUFFER",
+    0x8894 => "GL_ARRAY_BUFFER_BINDING",
+    0x8895 => "GL_ELEMENT_ARRAY_BUFFER_BINDING",
+    0x889F => "GL_VERTEX_ATTRIB_ARRAY_BUFFER_BINDING",
+    0x88B8 => "GL_READ_ONLY",
+    0x88B9 => "GL_WRITE_ONLY",
+    0x88BA => "GL_READ_WRITE",
+    0x88BB => "GL_BUFFER_ACCESS",
+    0x88BC => "GL_BUFFER_MAPPED",
+    0x88BD => "GL_BUFFER_MAP_POINTER",
+    0x88BF => "GL_TIME_ELAPSED",
+    0x88E0 => "GL_STREAM_DRAW",
+    0x88E1 => "GL_STREAM_READ",
+    0x88E2 => "GL_STREAM_COPY",
+    0x88E4 => "GL_STATIC_DRAW",
+    0x88E5 => "GL_STATIC_READ",
+    0x88E6 => "GL_STATIC_COPY",
+    0x88E8 => "GL_DYNAMIC_DRAW",
+    0x88E9 => "GL_DYNAMIC_READ",
+    0x88EA => "GL_DYNAMIC_COPY",
+    0x88EB => "GL_PIXEL_PACK_BUFFER",
+    0x88EC => "GL_PIXEL_UNPACK_BUFFER",
+    0x88ED => "GL_PIXEL_PACK_BUFFER_BINDING",
+    0x88EF => "GL_PIXEL_UNPACK_BUFFER_BINDING",
+    0x88F0 => "GL_DEPTH24_STENCIL8",
+    0x88F1 => "GL_TEXTURE_STENCIL_SIZE",
+    0x88F9 => "GL_SRC1_COLOR",
+    0x88FA => "GL_ONE_MINUS_SRC1_COLOR",
+    0x88FB => "GL_ONE_MINUS_SRC1_ALPHA",
+    0x88FC => "GL_MAX_DUAL_SOURCE_DRAW_BUFFERS",
+    0x88FD => "GL_VERTEX_ATTRIB_ARRAY_INTEGER",
+    0x88FE => "GL_VERTEX_ATTRIB_ARRAY_DIVISOR",
+    0x88FF => "GL_MAX_ARRAY_TEXTURE_LAYERS",
+    0x8904 => "GL_MIN_PROGRAM_TEXEL_OFFSET",
+    0x8905 => "GL_MAX_PROGRAM_TEXEL_OFFSET",
+    0x8914 => "GL_SAMPLES_PASSED",
+    0x8916 => "GL_GEOMETRY_VERTICES_OUT",
+    0x8917 => "GL_GEOMETRY_INPUT_TYPE",
+    0x8918 => "GL_GEOMETRY_OUTPUT_TYPE",
+    0x8919 => "GL_SAMPLER_BINDING",
+    0x891C => "GL_CLAMP_READ_COLOR",
+    0x891D => "GL_FIXED_ONLY",
+    0x8A11 => "GL_UNIFORM_BUFFER",
+    0x8A28 => "GL_UNIFORM_BUFFER_BINDING",
+    0x8A29 => "GL_UNIFORM_BUFFER_START",
+    0x8A2A => "GL_UNIFORM_BUFFER_SIZE",
+    0x8A2B => "GL_MAX_VERTEX_UNIFORM_BLOCKS",
+    0x8A2C => "GL_MAX_GEOMETRY_UNIFORM_BLOCKS",
+    0x8A2D => "GL_MAX_FRAGMENT_UNIFORM_BLOCKS",
+    0x8A2E => "GL_MAX_COMBINED_UNIFORM_BLOCKS",
+    0x8A2F => "GL_MAX_UNIFORM_BUFFER_BINDINGS",
+    0x8A30 => "GL_MAX_UNIFORM_BLOCK_SIZE",
+    0x8A31 => "GL_MAX_COMBINED_VERTEX_UNIFORM_COMPONENTS",
+    0x8A32 => "GL_MAX_COMBINED_GEOMETRY_UNIFORM_COMPONENTS",
+    0x8A33 => "GL_MAX_COMBINED_FRAGMENT_UNIFORM_COMPONENTS",
+    0x8A34 => "GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT",
+    0x8A35 => "GL_ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH",
+    0x8A36 => "GL_ACTIVE_UNIFORM_BLOCKS",
+    0x8A37 => "GL_UNIFORM_TYPE",
+    0x8A38 => "GL_UNIFORM_SIZE",
+    0x8A39 => "GL_UNIFORM_NAME_LENGTH",
+    0x8A3A => "GL_UNIFORM_BLOCK_INDEX",
+    0x8A3B => "GL_UNIFORM_OFFSET",
+    0x8A3C => "GL_UNIFORM_ARRAY_STRIDE",
+    0x8A3D => "GL_UNIFORM_MATRIX_STRIDE",
+    0x8A3E => "GL_UNIFORM_IS_ROW_MAJOR",
+    0x8A3F => "GL_UNIFORM_BLOCK_BINDING",
+    0x8A40 => "GL_UNIFORM_BLOCK_DATA_SIZE",
+    0x8A41 => "GL_UNIFORM_BLOCK_NAME_LENGTH",
+    0x8A42 => "GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS",
+    0x8A43 => "GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES",
+    0x8A44 => "GL_UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER",
+    0x8A45 => "GL_UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER",
+    0x8A46 => "GL_UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER",
+    0x8B30 => "GL_FRAGMENT_SHADER",
+    0x8B31 => "GL_VERTEX_SHADER",
+    0x8B49 => "GL_MAX_FRAGMENT_UNIFORM_COMPONENTS",
+    0x8B4A => "GL_MAX_VERTEX_UNIFORM_COMPONENTS",
+    0x8B4C => "GL_MAX_VERTEX_TEXTURE_IMAGE_UNITS",
+    0x8B4D => "GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS",
+    0x8B4F => "GL_SHADER_TYPE",
+    0x8B50 => "GL_FLOAT_VEC2",
+    0x8B51 => "GL_FLOAT_VEC3",
+    0x8B52 => "GL_FLOAT_VEC4",
+    0x8B53 => "GL_INT_VEC2",
+    0x8B54 => "GL_INT_VEC3",
+    0x8B55 => "GL_INT_VEC4",
+    0x8B56 => "GL_BOOL",
+    0x8B57 => "GL_BOOL_VEC2",
+    0x8B58 => "GL_BOOL_VEC3",
+    0x8B59 => "GL_BOOL_VEC4",
+    0x8B5A => "GL_FLOAT_MAT2",
+    0x8B5B => "GL_FLOAT_MAT3",
+    0x8B5C => "GL_FLOAT_MAT4",
+    0x8B5D => "GL_SAMPLER_1D",
+    0x8B5E => "GL_SAMPLER_2D",
+    0x8B5F => "GL_SAMPLER_3D",
+    0x8B60 => "GL_SAMPLER_CUBE",
+    0x8B61 => "GL_SAMPLER_1D_SHADOW",
+    0x8B62 => "GL_SAMPLER_2D_SHADOW",
+    0x8B63 => "GL_SAMPLER_2D_RECT",
+    0x8B64 => "GL_SAMPLER_2D_RECT_SHADOW",
+    0x8B65 => "GL_FLOAT_MAT2x3",
+    0x8B66 => "GL_FLOAT_MAT2x4",
+    0x8B67 => "GL_FLOAT_MAT3x2",
+    0x8B68 => "GL_FLOAT_MAT3x4",
+    0x8B69 => "GL_FLOAT_MAT4x2",
+    0x8B6A => "GL_FLOAT_MAT4x3",
+    0x8B80 => "GL_DELETE_STATUS",
+    0x8B81 => "GL_COMPILE_STATUS",
+    0x8B82 => "GL_LINK_STATUS",
+    0x8B83 => "GL_VALIDATE_STATUS",
+    0x8B84 => "GL_INFO_LOG_LENGTH",
+    0x8B85 => "GL_ATTACHED_SHADERS",
+    0x8B86 => "GL_ACTIVE_UNIFORMS",
+    0x8B87 => "GL_ACTIVE_UNIFORM_MAX_LENGTH",
+    0x8B88 => "GL_SHADER_SOURCE_LENGTH",
+    0x8B89 => "GL_ACTIVE_ATTRIBUTES",
+    0x8B8A => "GL_ACTIVE_ATTRIBUTE_MAX_LENGTH",
+    0x8B8B => "GL_FRAGMENT_SHADER_DERIVATIVE_HINT",
+    0x8B8C => "GL_SHADING_LANGUAGE_VERSION",
+    0x8B8D => "GL_CURRENT_PROGRAM",
+    0x8B9A => "GL_IMPLEMENTATION_COLOR_READ_TYPE",
+    0x8B9B => "GL_IMPLEMENTATION_COLOR_READ_FORMAT",
+    0x8C10 => "GL_TEXTURE_RED_TYPE",
+    0x8C11 => "GL_TEXTURE_GREEN_TYPE",
+    0x8C12 => "GL_TEXTURE_BLUE_TYPE",
+    0x8C13 => "GL_TEXTURE_ALPHA_TYPE",
+    0x8C16 => "GL_TEXTURE_DEPTH_TYPE",
+    0x8C17 => "GL_UNSIGNED_NORMALIZED",
+    0x8C18 => "GL_TEXTURE_1D_ARRAY",
+    0x8C19 => "GL_PROXY_TEXTURE_1D_ARRAY",
+    0x8C1A => "GL_TEXTURE_2D_ARRAY",
+    0x8C1B => "GL_PROXY_TEXTURE_2D_ARRAY",
+    0x8C1C => "GL_TEXTURE_BINDING_1D_ARRAY",
+    0x8C1D => "GL_TEXTURE_BINDING_2D_ARRAY",
+    0x8C29 => "GL_MAX_GEOMETRY_TEXTURE_IMAGE_UNITS",
+    0x8C2B => "GL_MAX_TEXTURE_BUFFER_SIZE",
+    0x8C2C => "GL_TEXTURE_BINDING_BUFFER",
+    0x8C2D => "GL_TEXTURE_BUFFER_DATA_STORE_BINDING",
+    0x8C2F => "GL_ANY_SAMPLES_PASSED",
+    0x8C36 => "GL_SAMPLE_SHADING",
+    0x8C37 => "GL_MIN_SAMPLE_SHADING_VALUE",
+    0x8C3A => "GL_R11F_G11F_B10F",
+    0x8C3B => "GL_UNSIGNED_INT_10F_11F_11F_REV",
+    0x8C3D => "GL_RGB9_E5",
+    0x8C3E => "GL_UNSIGNED_INT_5_9_9_9_REV",
+    0x8C3F => "GL_TEXTURE_SHARED_SIZE",
+    0x8C40 => "GL_SRGB",
+    0x8C41 => "GL_SRGB8",
+    0x8C42 => "GL_SRGB_ALPHA",
+    0x8C43 => "GL_SRGB8_ALPHA8",
+    0x8C48 => "GL_COMPRESSED_SRGB",
+    0x8C49 => "GL_COMPRESSED_SRGB_ALPHA",
+    0x8C76 => "GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH",
+    0x8C7F => "GL_TRANSFORM_FEEDBACK_BUFFER_MODE",
+    0x8C80 => "GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS",
+    0x8C83 => "GL_TRANSFORM_FEEDBACK_VARYINGS",
+    0x8C84 => "GL_TRANSFORM_FEEDBACK_BUFFER_START",
+    0x8C85 => "GL_TRANSFORM_FEEDBACK_BUFFER_SIZE",
+    0x8C87 => "GL_PRIMITIVES_GENERATED",
+    0x8C88 => "GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN",
+    0x8C89 => "GL_RASTERIZER_DISCARD",
+    0x8C8A => "GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS",
+    0x8C8B => "GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS",
+    0x8C8C => "GL_INTERLEAVED_ATTRIBS",
+    0x8C8D => "GL_SEPARATE_ATTRIBS",
+    0x8C8E => "GL_TRANSFORM_FEEDBACK_BUFFER",
+    0x8C8F => "GL_TRANSFORM_FEEDBACK_BUFFER_BINDING",
+    0x8CA0 => "GL_POINT_SPRITE_COORD_ORIGIN",
+    0x8CA1 => "GL_LOWER_LEFT",
+    0x8CA2 => "GL_UPPER_LEFT",
+    0x8CA3 => "GL_STENCIL_BACK_REF",
+    0x8CA4 => "GL_STENCIL_BACK_VALUE_MASK",
+    0x8CA5 => "GL_STENCIL_BACK_WRITEMASK",
+    0x8CA7 => "GL_RENDERBUFFER_BINDING",
+    0x8CA8 => "GL_READ_FRAMEBUFFER",
+    0x8CA9 => "GL_DRAW_FRAMEBUFFER",
+    0x8CAA => "GL_READ_FRAMEBUFFER_BINDING",
+    0x8CAB => "GL_RENDERBUFFER_SAMPLES",
+    0x8CAC => "GL_DEPTH_COMPONENT32F",
+    0x8CAD => "GL_DEPTH32F_STENCIL8",
+    0x8CD0 => "GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE",
+    0x8CD1 => "GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME",
+    0x8CD2 => "GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL",
+    0x8CD3 => "GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_CUBE_MAP_FACE",
+    0x8CD4 => "GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LAYER",
+    0x8CD5 => "GL_FRAMEBUFFER_COMPLETE",
+    0x8CD6 => "GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT",
+    0x8CD7 => "GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT",
+    0x8CDB => "GL_FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER",
+    0x8CDC => "GL_FRAMEBUFFER_INCOMPLETE_READ_BUFFER",
+    0x8CDD => "GL_FRAMEBUFFER_UNSUPPORTED",
+    0x8CDF => "GL_MAX_COLOR_ATTACHMENTS",
+    0x8CE0 => "GL_COLOR_ATTACHMENT0",
+    0x8CE1 => "GL_COLOR_ATTACHMENT1",
+    0x8CE2 => "GL_COLOR_ATTACHMENT2",
+    0x8CE3 => "GL_COLOR_ATTACHMENT3",
+    0x8CE4 => "GL_COLOR_ATTACHMENT4",
+    0x8CE5 => "GL_COLOR_ATTACHMENT5",
+    0x8CE6 => "GL_COLOR_ATTACHMENT6",
+    0x8CE7 => "GL_COLOR_ATTACHMENT7",
+    0x8CE8 => "GL_COLOR_ATTACHMENT8",
+    0x8CE9 => "GL_COLOR_ATTACHMENT9",
+    0x8CEA => "GL_COLOR_ATTACHMENT10",
+    0x8CEB => "GL_COLOR_ATTACHMENT11",
+    0x8CEC => "GL_COLOR_ATTACHMENT12",
+    0x8CED => "GL_COLOR_ATTACHMENT13",
+    0x8CEE => "GL_COLOR_ATTACHMENT14",
+    0x8CEF => "GL_COLOR_ATTACHMENT15",
+    0x8CF0 => "GL_COLOR_ATTACHMENT16",
+    0x8CF1 => "GL_COLOR_ATTACHMENT17",
+    0x8CF2 => "GL_COLOR_ATTACHMENT18",
+    0x8CF3 => "GL_COLOR_ATTACHMENT19",
+    0x8CF4 => "GL_COLOR_ATTACHMENT20",
+    0x8CF5 => "GL_COLOR_ATTACHMENT21",
+    0x8CF6 => "GL_COLOR_ATTACHMENT22",
+    0x8CF7 => "GL_COLOR_ATTACHMENT23",
+    0x8CF8 => "GL_COLOR_ATTACHMENT24",
+    0x8CF9 => "GL_COLOR_ATTACHMENT25",
+    0x8CFA => "GL_COLOR_ATTACHMENT26",
+    0x8CFB => "GL_COLOR_ATTACHMENT27",
+    0x8CFC => "GL_COLOR_ATTACHMENT28",
+    0x8CFD => "GL_COLOR_ATTACHMENT29",
+    0x8CFE => "GL_COLOR_ATTACHMENT30",
+    0x8CFF => "GL_COLOR_ATTACHMENT31",
+    0x8D00 => "GL_DEPTH_ATTACHMENT",
+    0x8D20 => "GL_STENCIL_ATTACHMENT",
+    0x8D40 => "GL_FRAMEBUFFER",
+    0x8D41 => "GL_RENDERBUFFER",
+    0x8D42 => "GL_RENDERBUFFER_WIDTH",
+    0x8D43 => "GL_RENDERBUFFER_HEIGHT",
+    0x8D44 => "GL_RENDERBUFFER_INTERNAL_FORMAT",
+    0x8D46 => "GL_STENCIL_INDEX1",
+    0x8D47 => "GL_STENCIL_INDEX4",
+    0x8D48 => "GL_STENCIL_INDEX8",
+    0x8D49 => "GL_STENCIL_INDEX16",
+    0x8D50 => "GL_RENDERBUFFER_RED_SIZE",
+    0x8D51 => "GL_RENDERBUFFER_GREEN_SIZE",
+    0x8D52 => "GL_RENDERBUFFER_BLUE_SIZE",
+    0x8D53 => "GL_RENDERBUFFER_ALPHA_SIZE",
+    0x8D54 => "GL_RENDERBUFFER_DEPTH_SIZE",
+    0x8D55 => "GL_RENDERBUFFER_STENCIL_SIZE",
+    0x8D56 => "GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE",
+    0x8D57 => "GL_MAX_SAMPLES",
+    0x8D62 => "GL_RGB565",
+    0x8D69 => "GL_PRIMITIVE_RESTART_FIXED_INDEX",
+    0x8D6A => "GL_ANY_SAMPLES_PASSED_CONSERVATIVE",
+    0x8D6B => "GL_MAX_ELEMENT_INDEX",
+    0x8D70 => "GL_RGBA32UI",
+    0x8D71 => "GL_RGB32UI",
+    0x8D76 => "GL_RGBA16UI",
+    0x8D77 => "GL_RGB16UI",
+    0x8D7C => "GL_RGBA8UI",
+    0x8D7D => "GL_RGB8UI",
+    0x8D82 => "GL_RGBA32I",
+    0x8D83 => "GL_RGB32I",
+    0x8D88 => "GL_RGBA16I",
+    0x8D89 => "GL_RGB16I",
+    0x8D8E => "GL_RGBA8I",
+    0x8D8F => "GL_RGB8I",
+    0x8D94 => "GL_RED_INTEGER",
+    0x8D95 => "GL_GREEN_INTEGER",
+    0x8D96 => "GL_BLUE_INTEGER",
+    0x8D98 => "GL_RGB_INTEGER",
+    0x8D99 => "GL_RGBA_INTEGER",
+    0x8D9A => "GL_BGR_INTEGER",
+    0x8D9B => "GL_BGRA_INTEGER",
+    0x8D9F => "GL_INT_2_10_10_10_REV",
+    0x8DA7 => "GL_FRAMEBUFFER_ATTACHMENT_LAYERED",
+    0x8DA8 => "GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS",
+    0x8DAD => "GL_FLOAT_32_UNSIGNED_INT_24_8_REV",
+    0x8DB9 => "GL_FRAMEBUFFER_SRGB",
+    0x8DBB => "GL_COMPRESSED_RED_RGTC1",
+    0x8DBC => "GL_COMPRESSED_SIGNED_RED_RGTC1",
+    0x8DBD => "GL_COMPRESSED_RG_RGTC2",
+    0x8DBE => "GL_COMPRESSED_SIGNED_RG_RGTC2",
+    0x8DC0 => "GL_SAMPLER_1D_ARRAY",
+    0x8DC1 => "GL_SAMPLER_2D_ARRAY",
+    0x8DC2 => "GL_SAMPLER_BUFFER",
+    0x8DC3 => "GL_SAMPLER_1D_ARRAY_SHADOW",
+    0x8DC4 => "GL_SAMPLER_2D_ARRAY_SHADOW",
+    0x8DC5 => "GL_SAMPLER_CUBE_SHADOW",
+    0x8DC6 => "GL_UNSIGNED_INT_VEC2",
+    0x8DC7 => "GL_UNSIGNED_INT_VEC3",
+    0x8DC8 => "GL_UNSIGNED_INT_VEC4",
+    0x8DC9 => "GL_INT_SAMPLER_1D",
+    0x8DCA => "GL_INT_SAMPLER_2D",
+    0x8DCB => "GL_INT_SAMPLER_3D",
+    0x8DCC => "GL_INT_SAMPLER_CUBE",
+    0x8DCD => "GL_INT_SAMPLER_2D_RECT",
+    0x8DCE => "GL_INT_SAMPLER_1D_ARRAY",
+    0x8DCF => "GL_INT_SAMPLER_2D_ARRAY",
+    0x8DD0 => "GL_INT_SAMPLER_BUFFER",
+    0x8DD1 => "GL_UNSIGNED_INT_SAMPLER_1D",
+    0x8DD2 => "GL_UNSIGNED_INT_SAMPLER_2D",
+    0x8DD3 => "GL_UNSIGNED_INT_SAMPLER_3D",
+    0x8DD4 => "GL_UNSIGNED_INT_SAMPLER_CUBE",
+    0x8DD5 => "GL_UNSIGNED_INT_SAMPLER_2D_RECT",
+    0x8DD6 => "GL_UNSIGNED_INT_SAMPLER_1D_ARRAY",
+    0x8DD7 => "GL_UNSIGNED_INT_SAMPLER_2D_ARRAY",
+    0x8DD8 => "GL_UNSIGNED_INT_SAMPLER_BUFFER",
+    0x8DD9 => "GL_GEOMETRY_SHADER",
+    0x8DDF => "GL_MAX_GEOMETRY_UNIFORM_COMPONENTS",
+    0x8DE0 => "GL_MAX_GEOMETRY_OUTPUT_VERTICES",
+    0x8DE1 => "GL_MAX_GEOMETRY_TOTAL_OUTPUT_COMPONENTS",
+    0x8DE5 => "GL_ACTIVE_SUBROUTINES",
+    0x8DE6 => "GL_ACTIVE_SUBROUTINE_UNIFORMS",
+    0x8DE7 => "GL_MAX_SUBROUTINES",
+    0x8DE8 => "GL_MAX_SUBROUTINE_UNIFORM_LOCATIONS",
+    0x8DF0 => "GL_LOW_FLOAT",
+    0x8DF1 => "GL_MEDIUM_FLOAT",
+    0x8DF2 => "GL_HIGH_FLOAT",
+    0x8DF3 => "GL_LOW_INT",
+    0x8DF4 => "GL_MEDIUM_INT",
+    0x8DF5 => "GL_HIGH_INT",
+    0x8DF8 => "GL_SHADER_BINARY_FORMATS",
+    0x8DF9 => "GL_NUM_SHADER_BINARY_FORMATS",
+    0x8DFA => "GL_SHADER_COMPILER",
+    0x8DFB => "GL_MAX_VERTEX_UNIFORM_VECTORS",
+    0x8DFC => "GL_MAX_VARYING_VECTORS",
+    0x8DFD => "GL_MAX_FRAGMENT_UNIFORM_VECTORS",
+    0x8E13 => "GL_QUERY_WAIT",
+    0x8E14 => "GL_QUERY_NO_WAIT",
+    0x8E15 => "GL_QUERY_BY_REGION_WAIT",
+    0x8E16 => "GL_QUERY_BY_REGION_NO_WAIT",
+    0x8E17 => "GL_QUERY_WAIT_INVERTED",
+    0x8E18 => "GL_QUERY_NO_WAIT_INVERTED",
+    0x8E19 => "GL_QUERY_BY_REGION_WAIT_INVERTED",
+    0x8E1A => "GL_QUERY_BY_REGION_NO_WAIT_INVERTED",
+    0x8E1B => "GL_POLYGON_OFFSET_CLAMP",
+    0x8E1E => "GL_MAX_COMBINED_TESS_CONTROL_UNIFORM_COMPONENTS",
+    0x8E1F => "GL_MAX_COMBINED_TESS_EVALUATION_UNIFORM_COMPONENTS",
+    0x8E22 => "GL_TRANSFORM_FEEDBACK",
+    0x8E25 => "GL_TRANSFORM_FEEDBACK_BINDING",
+    0x8E28 => "GL_TIMESTAMP",
+    0x8E42 => "GL_TEXTURE_SWIZZLE_R",
+    0x8E43 => "GL_TEXTURE_SWIZZLE_G",
+    0x8E44 => "GL_TEXTURE_SWIZZLE_B",
+    0x8E45 => "GL_TEXTURE_SWIZZLE_A",
+    0x8E46 => "GL_TEXTURE_SWIZZLE_RGBA",
+    0x8E47 => "GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS",
+    0x8E48 => "GL_ACTIVE_SUBROUTINE_MAX_LENGTH",
+    0x8E49 => "GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH",
+    0x8E4A => "GL_NUM_COMPATIBLE_SUBROUTINES",
+    0x8E4B => "GL_COMPATIBLE_SUBROUTINES",
+    0x8E4C => "GL_QUADS_FOLLOW_PROVOKING_VERTEX_CONVENTION",
+    0x8E4D => "GL_FIRST_VERTEX_CONVENTION",
+    0x8E4E => "GL_LAST_VERTEX_CONVENTION",
+    0x8E4F => "GL_PROVOKING_VERTEX",
+    0x8E50 => "GL_SAMPLE_POSITION",
+    0x8E51 => "GL_SAMPLE_MASK",
+    0x8E52 => "GL_SAMPLE_MASK_VALUE",
+    0x8E59 => "GL_MAX_SAMPLE_MASK_WORDS",
+    0x8E5A => "GL_MAX_GEOMETRY_SHADER_INVOCATIONS",
+    0x8E5B => "GL_MIN_FRAGMENT_INTERPOLATION_OFFSET",
+    0x8E5C => "GL_MAX_FRAGMENT_INTERPOLATION_OFFSET",
+    0x8E5D => "GL_FRAGMENT_INTERPOLATION_OFFSET_BITS",
+    0x8E5E => "GL_MIN_PROGRAM_TEXTURE_GATHER_OFFSET",
+    0x8E5F => "GL_MAX_PROGRAM_TEXTURE_GATHER_OFFSET",
+    0x8E70 => "GL_MAX_TRANSFORM_FEEDBACK_BUFFERS",
+    0x8E71 => "GL_MAX_VERTEX_STREAMS",
+    0x8E72 => "GL_PATCH_VERTICES",
+    0x8E73 => "GL_PATCH_DEFAULT_INNER_LEVEL",
+    0x8E74 => "GL_PATCH_DEFAULT_OUTER_LEVEL",
+    0x8E75 => "GL_TESS_CONTROL_OUTPUT_VERTICES",
+    0x8E76 => "GL_TESS_GEN_MODE",
+    0x8E77 => "GL_TESS_GEN_SPACING",
+    0x8E78 => "GL_TESS_GEN_VERTEX_ORDER",
+    0x8E79 => "GL_TESS_GEN_POINT_MODE",
+    0x8E7A => "GL_ISOLINES",
+    0x8E7B => "GL_FRACTIONAL_ODD",
+    0x8E7C => "GL_FRACTIONAL_EVEN",
+    0x8E7D => "GL_MAX_PATCH_VERTICES",
+    0x8E7E => "GL_MAX_TESS_GEN_LEVEL",
+    0x8E7F => "GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS",
+    0x8E80 => "GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS",
+    0x8E81 => "GL_MAX_TESS_CONTROL_TEXTURE_IMAGE_UNITS",
+    0x8E82 => "GL_MAX_TESS_EVALUATION_TEXTURE_IMAGE_UNITS",
+    0x8E83 => "GL_MAX_TESS_CONTROL_OUTPUT_COMPONENTS",
+    0x8E84 => "GL_MAX_TESS_PATCH_COMPONENTS",
+    0x8E85 => "GL_MAX_TESS_CONTROL_TOTAL_OUTPUT_COMPONENTS",
+    0x8E86 => "GL_MAX_TESS_EVALUATION_OUTPUT_COMPONENTS",
+    0x8E87 => "GL_TESS_EVALUATION_SHADER",
+    0x8E88 => "GL_TESS_CONTROL_SHADER",
+    0x8E89 => "GL_MAX_TESS_CONTROL_UNIFORM_BLOCKS",
+    0x8E8A => "GL_MAX_TESS_EVALUATION_UNIFORM_BLOCKS",
+    0x8E8C => "GL_COMPRESSED_RGBA_BPTC_UNORM",
+    0x8E8D => "GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM",
+    0x8E8E => "GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT",
+    0x8E8F => "GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT",
+    0x8F38 => "GL_MAX_IMAGE_UNITS",
+    0x8F3A => "GL_IMAGE_BINDING_NAME",
+    0x8F3B => "GL_IMAGE_BINDING_LEVEL",
+    0x8F3C => "GL_IMAGE_BINDING_LAYERED",
+    0x8F3D => "GL_IMAGE_BINDING_LAYER",
+    0x8F3E => "GL_IMAGE_BINDING_ACCESS",
+    0x8F3F => "GL_DRAW_INDIRECT_BUFFER",
+    0x8F43 => "GL_DRAW_INDIRECT_BUFFER_BINDING",
+    0x8F46 => "GL_DOUBLE_MAT2",
+    0x8F47 => "GL_DOUBLE_MAT3",
+    0x8F48 => "GL_DOUBLE_MAT4",
+    0x8F49 => "GL_DOUBLE_MAT2x3",
+    0x8F4A => "GL_DOUBLE_MAT2x4",
+    0x8F4B => "GL_DOUBLE_MAT3x2",
+    0x8F4C => "GL_DOUBLE_MAT3x4",
+    0x8F4D => "GL_DOUBLE_MAT4x2",
+    0x8F4E => "GL_DOUBLE_MAT4x3",
+    0x8F4F => "GL_VERTEX_BINDING_BUFFER",
+    0x8F94 => "GL_R8_SNORM",
+    0x8F95 => "GL_RG8_SNORM",
+    0x8F96 => "GL_RGB8_SNORM",
+    0x8F97 => "GL_RGBA8_SNORM",
+    0x8F98 => "GL_R16_SNORM",
+    0x8F99 => "GL_RG16_SNORM",
+    0x8F9A => "GL_RGB16_SNORM",
+    0x8F9B => "GL_RGBA16_SNORM",
+    0x8F9C => "GL_SIGNED_NORMALIZED",
+    0x8F9D => "GL_PRIMITIVE_RESTART",
+    0x8F9E => "GL_PRIMITIVE_RESTART_INDEX",
+    0x8FFC => "GL_DOUBLE_VEC2",
+    0x8FFD => "GL_DOUBLE_VEC3",
+    0x8FFE => "GL_DOUBLE_VEC4",
+    0x9009 => "GL_TEXTURE_CUBE_MAP_ARRAY",
+    0x900A => "GL_TEXTURE_BINDING_CUBE_MAP_ARRAY",
+    0x900B => "GL_PROXY_TEXTURE_CUBE_MAP_ARRAY",
+    0x900C => "GL_SAMPLER_CUBE_MAP_ARRAY",
+    0x900D => "GL_SAMPLER_CUBE_MAP_ARRAY_SHADOW",
+    0x900E => "GL_INT_SAMPLER_CUBE_MAP_ARRAY",
+    0x900F => "GL_UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY",
+    0x904C => "GL_IMAGE_1D",
+    0x904D => "GL_IMAGE_2D",
+    0x904E => "GL_IMAGE_3D",
+    0x904F => "GL_IMAGE_2D_RECT",
+    0x9050 => "GL_IMAGE_CUBE",
+    0x9051 => "GL_IMAGE_BUFFER",
+    0x9052 => "GL_IMAGE_1D_ARRAY",
+    0x9053 => "GL_IMAGE_2D_ARRAY",
+    0x9054 => "GL_IMAGE_CUBE_MAP_ARRAY",
+    0x9055 => "GL_IMAGE_2D_MULTISAMPLE",
+    0x9056 => "GL_IMAGE_2D_MULTISAMPLE_ARRAY",
+    0x9057 => "GL_INT_IMAGE_1D",
+    0x9058 => "GL_INT_IMAGE_2D",
+    0x9059 => "GL_INT_IMAGE_3D",
+    0x905A => "GL_INT_IMAGE_2D_RECT",
+    0x905B => "GL_INT_IMAGE_CUBE",
+    0x905C => "GL_INT_IMAGE_BUFFER",
+    0x905D => "GL_INT_IMAGE_1D_ARRAY",
+    0x905E => "GL_INT_IMAGE_2D_ARRAY",
+    0x905F => "GL_INT_IMAGE_CUBE_MAP_ARRAY",
+    0x9060 => "GL_INT_IMAGE_2D_MULTISAMPLE",
+    0x9061 => "GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY",
+    0x9062 => "GL_UNSIGNED_INT_IMAGE_1D",
+    0x9063 => "GL_UNSIGNED_INT_IMAGE_2D",
+    0x9064 => "GL_UNSIGNED_INT_IMAGE_3D",
+    0x9065 => "GL_UNSIGNED_INT_IMAGE_2D_RECT",
+    0x9066 => "GL_UNSIGNED_INT_IMAGE_CUBE",
+    0x9067 => "GL_UNSIGNED_INT_IMAGE_BUFFER",
+    0x9068 => "GL_UNSIGNED_INT_IMAGE_1D_ARRAY",
+    0x9069 => "GL_UNSIGNED_INT_IMAGE_2D_ARRAY",
+    0x906A => "GL_UNSIGNED_INT_IMAGE_CUBE_MAP_ARRAY",
+    0x906B => "GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE",
+    0x906C => "GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY",
+    0x906D => "GL_MAX_IMAGE_SAMPLES",
+    0x906E => "GL_IMAGE_BINDING_FORMAT",
+    0x906F => "GL_RGB10_A2UI",
+    0x90BC => "GL_MIN_MAP_BUFFER_ALIGNMENT",
+    0x90C7 => "GL_IMAGE_FORMAT_COMPATIBILITY_TYPE",
+    0x90C8 => "GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE",
+    0x90C9 => "GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS",
+    0x90CA => "GL_MAX_VERTEX_IMAGE_UNIFORMS",
+    0x90CB => "GL_MAX_TESS_CONTROL_IMAGE_UNIFORMS",
+    0x90CC => "GL_MAX_TESS_EVALUATION_IMAGE_UNIFORMS",
+    0x90CD => "GL_MAX_GEOMETRY_IMAGE_UNIFORMS",
+    0x90CE => "GL_MAX_FRAGMENT_IMAGE_UNIFORMS",
+    0x90CF => "GL_MAX_COMBINED_IMAGE_UNIFORMS",
+    0x90D2 => "GL_SHADER_STORAGE_BUFFER",
+    0x90D3 => "GL_SHADER_STORAGE_BUFFER_BINDING",
+    0x90D4 => "GL_SHADER_STORAGE_BUFFER_START",
+    0x90D5 => "GL_SHADER_STORAGE_BUFFER_SIZE",
+    0x90D6 => "GL_MAX_VERTEX_SHADER_STORAGE_BLOCKS",
+    0x90D7 => "GL_MAX_GEOMETRY_SHADER_STORAGE_BLOCKS",
+    0x90D8 => "GL_MAX_TESS_CONTROL_SHADER_STORAGE_BLOCKS",
+    0x90D9 => "GL_MAX_TESS_EVALUATION_SHADER_STORAGE_BLOCKS",
+    0x90DA => "GL_MAX_FRAGMENT_SHADER_STORAGE_BLOCKS",
+    0x90DB => "GL_MAX_COMPUTE_SHADER_STORAGE_BLOCKS",
+    0x90DC => "GL_MAX_COMBINED_SHADER_STORAGE_BLOCKS",
+    0x90DD => "GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS",
+    0x90DE => "GL_MAX_SHADER_STORAGE_BLOCK_SIZE",
+    0x90DF => "GL_SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT",
+    0x90EA => "GL_DEPTH_STENCIL_TEXTURE_MODE",
+    0x90EB => "GL_MAX_COMPUTE_WORK_GROUP_INVOCATIONS",
+    0x90EC => "GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER",
+    0x90ED => "GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER",
+    0x90EE => "GL_DISPATCH_INDIRECT_BUFFER",
+    0x90EF => "GL_DISPATCH_INDIRECT_BUFFER_BINDING",
+    0x9100 => "GL_TEXTURE_2D_MULTISAMPLE",
+    0x9101 => "GL_PROXY_TEXTURE_2D_MULTISAMPLE",
+    0x9102 => "GL_TEXTURE_2D_MULTISAMPLE_ARRAY",
+    0x9103 => "GL_PROXY_TEXTURE_2D_MULTISAMPLE_ARRAY",
+    0x9104 => "GL_TEXTURE_BINDING_2D_MULTISAMPLE",
+    0x9105 => "GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY",
+    0x9106 => "GL_TEXTURE_SAMPLES",
+    0x9107 => "GL_TEXTURE_FIXED_SAMPLE_LOCATIONS",
+    0x9108 => "GL_SAMPLER_2D_MULTISAMPLE",
+    0x9109 => "GL_INT_SAMPLER_2D_MULTISAMPLE",
+    0x910A => "GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE",
+    0x910B => "GL_SAMPLER_2D_MULTISAMPLE_ARRAY",
+    0x910C => "GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY",
+    0x910D => "GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY",
+    0x910E => "GL_MAX_COLOR_TEXTURE_SAMPLES",
+    0x910F => "GL_MAX_DEPTH_TEXTURE_SAMPLES",
+    0x9110 => "GL_MAX_INTEGER_SAMPLES",
+    0x9111 => "GL_MAX_SERVER_WAIT_TIMEOUT",
+    0x9112 => "GL_OBJECT_TYPE",
+    0x9113 => "GL_SYNC_CONDITION",
+    0x9114 => "GL_SYNC_STATUS",
+    0x9115 => "GL_SYNC_FLAGS",
+    0x9116 => "GL_SYNC_FENCE",
+    0x9117 => "GL_SYNC_GPU_COMMANDS_COMPLETE",
+    0x9118 => "GL_UNSIGNALED",
+    0x9119 => "GL_SIGNALED",
+    0x911A => "GL_ALREADY_SIGNALED",
+    0x911B => "GL_TIMEOUT_EXPIRED",
+    0x911C => "GL_CONDITION_SATISFIED",
+    0x911D => "GL_WAIT_FAILED",
+    0x911F => "GL_BUFFER_ACCESS_FLAGS",
+    0x9120 => "GL_BUFFER_MAP_LENGTH",
+    0x9121 => "GL_BUFFER_MAP_OFFSET",
+    0x9122 => "GL_MAX_VERTEX_OUTPUT_COMPONENTS",
+    0x9123 => "GL_MAX_GEOMETRY_INPUT_COMPONENTS",
+    0x9124 => "GL_MAX_GEOMETRY_OUTPUT_COMPONENTS",
+    0x9125 => "GL_MAX_FRAGMENT_INPUT_COMPONENTS",
+    0x9126 => "GL_CONTEXT_PROFILE_MASK",
+    0x9127 => "GL_UNPACK_COMPRESSED_BLOCK_WIDTH",
+    0x9128 => "GL_UNPACK_COMPRESSED_BLOCK_HEIGHT",
+    0x9129 => "GL_UNPACK_COMPRESSED_BLOCK_DEPTH",
+    0x912A => "GL_UNPACK_COMPRESSED_BLOCK_SIZE",
+    0x912B => "GL_PACK_COMPRESSED_BLOCK_WIDTH",
+    0x912C => "GL_PACK_COMPRESSED_BLOCK_HEIGHT",
+    0x912D => "GL_PACK_COMPRESSED_BLOCK_DEPTH",
+    0x912E => "GL_PACK_COMPRESSED_BLOCK_SIZE",
+    0x912F => "GL_TEXTURE_IMMUTABLE_FORMAT",
+    0x9143 => "GL_MAX_DEBUG_MESSAGE_LENGTH",
+    0x9144 => "GL_MAX_DEBUG_LOGGED_MESSAGES",
+    0x9145 => "GL_DEBUG_LOGGED_MESSAGES",
+    0x9146 => "GL_DEBUG_SEVERITY_HIGH",
+    0x9147 => "GL_DEBUG_SEVERITY_MEDIUM",
+    0x9148 => "GL_DEBUG_SEVERITY_LOW",
+    0x9192 => "GL_QUERY_BUFFER",
+    0x9193 => "GL_QUERY_BUFFER_BINDING",
+    0x9194 => "GL_QUERY_RESULT_NO_WAIT",
+    0x919D => "GL_TEXTURE_BUFFER_OFFSET",
+    0x919E => "GL_TEXTURE_BUFFER_SIZE",
+    0x919F => "GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT",
+    0x91B9 => "GL_COMPUTE_SHADER",
+    0x91BB => "GL_MAX_COMPUTE_UNIFORM_BLOCKS",
+    0x91BC => "GL_MAX_COMPUTE_TEXTURE_IMAGE_UNITS",
+    0x91BD => "GL_MAX_COMPUTE_IMAGE_UNIFORMS",
+    0x91BE => "GL_MAX_COMPUTE_WORK_GROUP_COUNT",
+    0x91BF => "GL_MAX_COMPUTE_WORK_GROUP_SIZE",
+    0x9270 => "GL_COMPRESSED_R11_EAC",
+    0x9271 => "GL_COMPRESSED_SIGNED_R11_EAC",
+    0x9272 => "GL_COMPRESSED_RG11_EAC",
+    0x9273 => "GL_COMPRESSED_SIGNED_RG11_EAC",
+    0x9274 => "GL_COMPRESSED_RGB8_ETC2",
+    0x9275 => "GL_COMPRESSED_SRGB8_ETC2",
+    0x9276 => "GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2",
+    0x9277 => "GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2",
+    0x9278 => "GL_COMPRESSED_RGBA8_ETC2_EAC",
+    0x9279 => "GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC",
+    0x92C0 => "GL_ATOMIC_COUNTER_BUFFER",
+    0x92C1 => "GL_ATOMIC_COUNTER_BUFFER_BINDING",
+    0x92C2 => "GL_ATOMIC_COUNTER_BUFFER_START",
+    0x92C3 => "GL_ATOMIC_COUNTER_BUFFER_SIZE",
+    0x92C4 => "GL_ATOMIC_COUNTER_BUFFER_DATA_SIZE",
+    0x92C5 => "GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS",
+    0x92C6 => "GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTER_INDICES",
+    0x92C7 => "GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_VERTEX_SHADER",
+    0x92C8 => "GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_CONTROL_SHADER",
+    0x92C9 => "GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_EVALUATION_SHADER",
+    0x92CA => "GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_GEOMETRY_SHADER",
+    0x92CB => "GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_FRAGMENT_SHADER",
+    0x92CC => "GL_MAX_VERTEX_ATOMIC_COUNTER_BUFFERS",
+    0x92CD => "GL_MAX_TESS_CONTROL_ATOMIC_COUNTER_BUFFERS",
+    0x92CE => "GL_MAX_TESS_EVALUATION_ATOMIC_COUNTER_BUFFERS",
+    0x92CF => "GL_MAX_GEOMETRY_ATOMIC_COUNTER_BUFFERS",
+    0x92D0 => "GL_MAX_FRAGMENT_ATOMIC_COUNTER_BUFFERS",
+    0x92D1 => "GL_MAX_COMBINED_ATOMIC_COUNTER_BUFFERS",
+    0x92D2 => "GL_MAX_VERTEX_ATOMIC_COUNTERS",
+    0x92D3 => "GL_MAX_TESS_CONTROL_ATOMIC_COUNTERS",
+    0x92D4 => "GL_MAX_TESS_EVALUATION_ATOMIC_COUNTERS",
+    0x92D5 => "GL_MAX_GEOMETRY_ATOMIC_COUNTERS",
+    0x92D6 => "GL_MAX_FRAGMENT_ATOMIC_COUNTERS",
+    0x92D7 => "GL_MAX_COMBINED_ATOMIC_COUNTERS",
+    0x92D8 => "GL_MAX_ATOMIC_COUNTER_BUFFER_SIZE",
+    0x92D9 => "GL_ACTIVE_ATOMIC_COUNTER_BUFFERS",
+    0x92DA => "GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX",
+    0x92DB => "GL_UNSIGNED_INT_ATOMIC_COUNTER",
+    0x92DC => "GL_MAX_ATOMIC_COUNTER_BUFFER_BINDINGS",
+    0x92E0 => "GL_DEBUG_OUTPUT",
+    0x92E1 => "GL_UNIFORM",
+    0x92E2 => "GL_UNIFORM_BLOCK",
+    0x92E3 => "GL_PROGRAM_INPUT",
+    0x92E4 => "GL_PROGRAM_OUTPUT",
+    0x92E5 => "GL_BUFFER_VARIABLE",
+    0x92E6 => "GL_SHADER_STORAGE_BLOCK",
+    0x92E7 => "GL_IS_PER_PATCH",
+    0x92E8 => "GL_VERTEX_SUBROUTINE",
+    0x92E9 => "GL_TESS_CONTROL_SUBROUTINE",
+    0x92EA => "GL_TESS_EVALUATION_SUBROUTINE",
+    0x92EB => "GL_GEOMETRY_SUBROUTINE",
+    0x92EC => "GL_FRAGMENT_SUBROUTINE",
+    0x92ED => "GL_COMPUTE_SUBROUTINE",
+    0x92EE => "GL_VERTEX_SUBROUTINE_UNIFORM",
+    0x92EF => "GL_TESS_CONTROL_SUBROUTINE_UNIFORM",
+    0x92F0 => "GL_TESS_EVALUATION_SUBROUTINE_UNIFORM",
+    0x92F1 => "GL_GEOMETRY_SUBROUTINE_UNIFORM",
+    0x92F2 => "GL_FRAGMENT_SUBROUTINE_UNIFORM",
+    0x92F3 => "GL_COMPUTE_SUBROUTINE_UNIFORM",
+    0x92F4 => "GL_TRANSFORM_FEEDBACK_VARYING",
+    0x92F5 => "GL_ACTIVE_RESOURCES",
+    0x92F6 => "GL_MAX_NAME_LENGTH",
+    0x92F7 => "GL_MAX_NUM_ACTIVE_VARIABLES",
+    0x92F8 => "GL_MAX_NUM_COMPATIBLE_SUBROUTINES",
+    0x92F9 => "GL_NAME_LENGTH",
+    0x92FA => "GL_TYPE",
+    0x92FB => "GL_ARRAY_SIZE",
+    0x92FC => "GL_OFFSET",
+    0x92FD => "GL_BLOCK_INDEX",
+    0x92FE => "GL_ARRAY_STRIDE",
+    0x92FF => "GL_MATRIX_STRIDE",
+    0x9300 => "GL_IS_ROW_MAJOR",
+    0x9301 => "GL_ATOMIC_COUNTER_BUFFER_INDEX",
+    0x9302 => "GL_BUFFER_BINDING",
+    0x9303 => "GL_BUFFER_DATA_SIZE",
+    0x9304 => "GL_NUM_ACTIVE_VARIABLES",
+    0x9305 => "GL_ACTIVE_VARIABLES",
+    0x9306 => "GL_REFERENCED_BY_VERTEX_SHADER",
+    0x9307 => "GL_REFERENCED_BY_TESS_CONTROL_SHADER",
+    0x9308 => "GL_REFERENCED_BY_TESS_EVALUATION_SHADER",
+    0x9309 => "GL_REFERENCED_BY_GEOMETRY_SHADER",
+    0x930A => "GL_REFERENCED_BY_FRAGMENT_SHADER",
+    0x930B => "GL_REFERENCED_BY_COMPUTE_SHADER",
+    0x930C => "GL_TOP_LEVEL_ARRAY_SIZE",
+    0x930D => "GL_TOP_LEVEL_ARRAY_STRIDE",
+    0x930E => "GL_LOCATION",
+    0x930F => "GL_LOCATION_INDEX",
+    0x9310 => "GL_FRAMEBUFFER_DEFAULT_WIDTH",
+    0x9311 => "GL_FRAMEBUFFER_DEFAULT_HEIGHT",
+    0x9312 => "GL_FRAMEBUFFER_DEFAULT_LAYERS",
+    0x9313 => "GL_FRAMEBUFFER_DEFAULT_SAMPLES",
+    0x9314 => "GL_FRAMEBUFFER_DEFAULT_FIXED_SAMPLE_LOCATIONS",
+    0x9315 => "GL_MAX_FRAMEBUFFER_WIDTH",
+    0x9316 => "GL_MAX_FRAMEBUFFER_HEIGHT",
+    0x9317 => "GL_MAX_FRAMEBUFFER_LAYERS",
+    0x9318 => "GL_MAX_FRAMEBUFFER_SAMPLES",
+    0x934A => "GL_LOCATION_COMPONENT",
+    0x934B => "GL_TRANSFORM_FEEDBACK_BUFFER_INDEX",
+    0x934C => "GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE",
+    0x935C => "GL_CLIP_ORIGIN",
+    0x935D => "GL_CLIP_DEPTH_MODE",
+    0x935E => "GL_NEGATIVE_ONE_TO_ONE",
+    0x935F => "GL_ZERO_TO_ONE",
+    0x9365 => "GL_CLEAR_TEXTURE",
+    0x9380 => "GL_NUM_SAMPLE_COUNTS",
+    0x9551 => "GL_SHADER_BINARY_FORMAT_SPIR_V",
+    0x9552 => "GL_SPIR_V_BINARY",
+    0x9553 => "GL_SPIR_V_EXTENSIONS",
+    0x9554 => "GL_NUM_SPIR_V_EXTENSIONS",
+    _ => return None,
+  })
+}
+
+/// From `gl.xml`: `void glActiveShaderProgram(GLuint pipeline, GLuint program)`
+pub type glActiveShaderProgram_t = Option<
+  unsafe extern "system" fn(
+    pipeline: GLuint,
+    program: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glActiveTexture(GLenum texture)`
+pub type glActiveTexture_t = Option<unsafe extern "system" fn(texture: GLenum)>;
+
+/// From `gl.xml`: `void glAttachShader(GLuint program, GLuint shader)`
+pub type glAttachShader_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shader: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBeginConditionalRender(GLuint id, GLenum mode)`
+pub type glBeginConditionalRender_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    mode: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBeginQuery(GLenum target, GLuint id)`
+pub type glBeginQuery_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    id: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBeginQueryIndexed(GLenum target, GLuint index, GLuint id)`
+pub type glBeginQueryIndexed_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    id: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBeginTransformFeedback(GLenum primitiveMode)`
+pub type glBeginTransformFeedback_t = Option<
+  unsafe extern "system" fn(
+    primitiveMode: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindAttribLocation(GLuint program, GLuint index, const GLchar *name)`
+pub type glBindAttribLocation_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    index: GLuint,
+    name: *const GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindBuffer(GLenum target, GLuint buffer)`
+pub type glBindBuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    buffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindBufferBase(GLenum target, GLuint index, GLuint buffer)`
+pub type glBindBufferBase_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    buffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindBufferRange(GLenum target, GLuint index, GLuint buffer, GLintptr offset, GLsizeiptr size)`
+pub type glBindBufferRange_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindBuffersBase(GLenum target, GLuint first, GLsizei count, const GLuint *buffers)`
+pub type glBindBuffersBase_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    first: GLuint,
+    count: GLsizei,
+    buffers: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindBuffersRange(GLenum target, GLuint first, GLsizei count, const GLuint *buffers, const GLintptr *offsets, const GLsizeiptr *sizes)`
+pub type glBindBuffersRange_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    first: GLuint,
+    count: GLsizei,
+    buffers: *const GLuint,
+    offsets: *const GLintptr,
+    sizes: *const GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindFragDataLocation(GLuint program, GLuint color, const GLchar *name)`
+pub type glBindFragDataLocation_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    color: GLuint,
+    name: *const GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindFragDataLocationIndexed(GLuint program, GLuint colorNumber, GLuint index, const GLchar *name)`
+pub type glBindFragDataLocationIndexed_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    colorNumber: GLuint,
+    index: GLuint,
+    name: *const GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindFramebuffer(GLenum target, GLuint framebuffer)`
+pub type glBindFramebuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    framebuffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindImageTexture(GLuint unit, GLuint texture, GLint level, GLboolean layered, GLint layer, GLenum access, GLenum format)`
+pub type glBindImageTexture_t = Option<
+  unsafe extern "system" fn(
+    unit: GLuint,
+    texture: GLuint,
+    level: GLint,
+    layered: GLboolean,
+    layer: GLint,
+    access: GLenum,
+    format: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindImageTextures(GLuint first, GLsizei count, const GLuint *textures)`
+pub type glBindImageTextures_t = Option<
+  unsafe extern "system" fn(
+    first: GLuint,
+    count: GLsizei,
+    textures: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindProgramPipeline(GLuint pipeline)`
+pub type glBindProgramPipeline_t = Option<
+  unsafe extern "system" fn(
+    pipeline: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindRenderbuffer(GLenum target, GLuint renderbuffer)`
+pub type glBindRenderbuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    renderbuffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindSampler(GLuint unit, GLuint sampler)`
+pub type glBindSampler_t = Option<
+  unsafe extern "system" fn(
+    unit: GLuint,
+    sampler: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindSamplers(GLuint first, GLsizei count, const GLuint *samplers)`
+pub type glBindSamplers_t = Option<
+  unsafe extern "system" fn(
+    first: GLuint,
+    count: GLsizei,
+    samplers: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindTexture(GLenum target, GLuint texture)`
+pub type glBindTexture_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    texture: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindTextureUnit(GLuint unit, GLuint texture)`
+pub type glBindTextureUnit_t = Option<
+  unsafe extern "system" fn(
+    unit: GLuint,
+    texture: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindTextures(GLuint first, GLsizei count, const GLuint *textures)`
+pub type glBindTextures_t = Option<
+  unsafe extern "system" fn(
+    first: GLuint,
+    count: GLsizei,
+    textures: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindTransformFeedback(GLenum target, GLuint id)`
+pub type glBindTransformFeedback_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    id: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindVertexArray(GLuint array)`
+pub type glBindVertexArray_t = Option<unsafe extern "system" fn(array: GLuint)>;
+
+/// From `gl.xml`: `void glBindVertexBuffer(GLuint bindingindex, GLuint buffer, GLintptr offset, GLsizei stride)`
+pub type glBindVertexBuffer_t = Option<
+  unsafe extern "system" fn(
+    bindingindex: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    stride: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glBindVertexBuffers(GLuint first, GLsizei count, const GLuint *buffers, const GLintptr *offsets, const GLsizei *strides)`
+pub type glBindVertexBuffers_t = Option<
+  unsafe extern "system" fn(
+    first: GLuint,
+    count: GLsizei,
+    buffers: *const GLuint,
+    offsets: *const GLintptr,
+    strides: *const GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendColor(GLfloat red, GLfloat green, GLfloat blue, GLfloat alpha)`
+pub type glBlendColor_t = Option<
+  unsafe extern "system" fn(
+    red: GLfloat,
+    green: GLfloat,
+    blue: GLfloat,
+    alpha: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendEquation(GLenum mode)`
+pub type glBlendEquation_t = Option<unsafe extern "system" fn(mode: GLenum)>;
+
+/// From `gl.xml`: `void glBlendEquationSeparate(GLenum modeRGB, GLenum modeAlpha)`
+pub type glBlendEquationSeparate_t = Option<
+  unsafe extern "system" fn(
+    modeRGB: GLenum,
+    modeAlpha: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendEquationSeparatei(GLuint buf, GLenum modeRGB, GLenum modeAlpha)`
+pub type glBlendEquationSeparatei_t = Option<
+  unsafe extern "system" fn(
+    buf: GLuint,
+    modeRGB: GLenum,
+    modeAlpha: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendEquationi(GLuint buf, GLenum mode)`
+pub type glBlendEquationi_t = Option<
+  unsafe extern "system" fn(
+    buf: GLuint,
+    mode: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendFunc(GLenum sfactor, GLenum dfactor)`
+pub type glBlendFunc_t = Option<
+  unsafe extern "system" fn(
+    sfactor: GLenum,
+    dfactor: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendFuncSeparate(GLenum sfactorRGB, GLenum dfactorRGB, GLenum sfactorAlpha, GLenum dfactorAlpha)`
+pub type glBlendFuncSeparate_t = Option<
+  unsafe extern "system" fn(
+    sfactorRGB: GLenum,
+    dfactorRGB: GLenum,
+    sfactorAlpha: GLenum,
+    dfactorAlpha: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendFuncSeparatei(GLuint buf, GLenum srcRGB, GLenum dstRGB, GLenum srcAlpha, GLenum dstAlpha)`
+pub type glBlendFuncSeparatei_t = Option<
+  unsafe extern "system" fn(
+    buf: GLuint,
+    srcRGB: GLenum,
+    dstRGB: GLenum,
+    srcAlpha: GLenum,
+    dstAlpha: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlendFunci(GLuint buf, GLenum src, GLenum dst)`
+pub type glBlendFunci_t = Option<
+  unsafe extern "system" fn(
+    buf: GLuint,
+    src: GLenum,
+    dst: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlitFramebuffer(GLint srcX0, GLint srcY0, GLint srcX1, GLint srcY1, GLint dstX0, GLint dstY0, GLint dstX1, GLint dstY1, GLbitfield mask, GLenum filter)`
+pub type glBlitFramebuffer_t = Option<
+  unsafe extern "system" fn(
+    srcX0: GLint,
+    srcY0: GLint,
+    srcX1: GLint,
+    srcY1: GLint,
+    dstX0: GLint,
+    dstY0: GLint,
+    dstX1: GLint,
+    dstY1: GLint,
+    mask: GLbitfield,
+    filter: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBlitNamedFramebuffer(GLuint readFramebuffer, GLuint drawFramebuffer, GLint srcX0, GLint srcY0, GLint srcX1, GLint srcY1, GLint dstX0, GLint dstY0, GLint dstX1, GLint dstY1, GLbitfield mask, GLenum filter)`
+pub type glBlitNamedFramebuffer_t = Option<
+  unsafe extern "system" fn(
+    readFramebuffer: GLuint,
+    drawFramebuffer: GLuint,
+    srcX0: GLint,
+    srcY0: GLint,
+    srcX1: GLint,
+    srcY1: GLint,
+    dstX0: GLint,
+    dstY0: GLint,
+    dstX1: GLint,
+    dstY1: GLint,
+    mask: GLbitfield,
+    filter: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBufferData(GLenum target, GLsizeiptr size, const void *data, GLenum usage)`
+pub type glBufferData_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    size: GLsizeiptr,
+    data: *const c_void,
+    usage: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glBufferStorage(GLenum target, GLsizeiptr size, const void *data, GLbitfield flags)`
+pub type glBufferStorage_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    size: GLsizeiptr,
+    data: *const c_void,
+    flags: GLbitfield,
+  ),
+>;
+
+/// From `gl.xml`: `void glBufferSubData(GLenum target, GLintptr offset, GLsizeiptr size, const void *data)`
+pub type glBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `GLenum glCheckFramebufferStatus(GLenum target)`
+pub type glCheckFramebufferStatus_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+  ) -> GLenum,
+>;
+
+/// From `gl.xml`: `GLenum glCheckNamedFramebufferStatus(GLuint framebuffer, GLenum target)`
+pub type glCheckNamedFramebufferStatus_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    target: GLenum,
+  ) -> GLenum,
+>;
+
+/// From `gl.xml`: `void glClampColor(GLenum target, GLenum clamp)`
+pub type glClampColor_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    clamp: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glClear(GLbitfield mask)`
+pub type glClear_t = Option<unsafe extern "system" fn(mask: GLbitfield)>;
+
+/// From `gl.xml`: `void glClearBufferData(GLenum target, GLenum internalformat, GLenum format, GLenum type, const void *data)`
+pub type glClearBufferData_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    internalformat: GLenum,
+    format: GLenum,
+    type_: GLenum,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearBufferSubData(GLenum target, GLenum internalformat, GLintptr offset, GLsizeiptr size, GLenum format, GLenum type, const void *data)`
+pub type glClearBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    internalformat: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    format: GLenum,
+    type_: GLenum,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearBufferfi(GLenum buffer, GLint drawbuffer, GLfloat depth, GLint stencil)`
+pub type glClearBufferfi_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLenum,
+    drawbuffer: GLint,
+    depth: GLfloat,
+    stencil: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearBufferfv(GLenum buffer, GLint drawbuffer, const GLfloat *value)`
+pub type glClearBufferfv_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearBufferiv(GLenum buffer, GLint drawbuffer, const GLint *value)`
+pub type glClearBufferiv_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearBufferuiv(GLenum buffer, GLint drawbuffer, const GLuint *value)`
+pub type glClearBufferuiv_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearColor(GLfloat red, GLfloat green, GLfloat blue, GLfloat alpha)`
+pub type glClearColor_t = Option<
+  unsafe extern "system" fn(
+    red: GLfloat,
+    green: GLfloat,
+    blue: GLfloat,
+    alpha: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearDepth(GLdouble depth)`
+pub type glClearDepth_t = Option<unsafe extern "system" fn(depth: GLdouble)>;
+
+/// From `gl.xml`: `void glClearDepthf(GLfloat d)`
+pub type glClearDepthf_t = Option<unsafe extern "system" fn(d: GLfloat)>;
+
+/// From `gl.xml`: `void glClearNamedBufferData(GLuint buffer, GLenum internalformat, GLenum format, GLenum type, const void *data)`
+pub type glClearNamedBufferData_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    internalformat: GLenum,
+    format: GLenum,
+    type_: GLenum,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearNamedBufferSubData(GLuint buffer, GLenum internalformat, GLintptr offset, GLsizeiptr size, GLenum format, GLenum type, const void *data)`
+pub type glClearNamedBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    internalformat: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    format: GLenum,
+    type_: GLenum,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearNamedFramebufferfi(GLuint framebuffer, GLenum buffer, GLint drawbuffer, GLfloat depth, GLint stencil)`
+pub type glClearNamedFramebufferfi_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    depth: GLfloat,
+    stencil: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearNamedFramebufferfv(GLuint framebuffer, GLenum buffer, GLint drawbuffer, const GLfloat *value)`
+pub type glClearNamedFramebufferfv_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearNamedFramebufferiv(GLuint framebuffer, GLenum buffer, GLint drawbuffer, const GLint *value)`
+pub type glClearNamedFramebufferiv_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearNamedFramebufferuiv(GLuint framebuffer, GLenum buffer, GLint drawbuffer, const GLuint *value)`
+pub type glClearNamedFramebufferuiv_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearStencil(GLint s)`
+pub type glClearStencil_t = Option<unsafe extern "system" fn(s: GLint)>;
+
+/// From `gl.xml`: `void glClearTexImage(GLuint texture, GLint level, GLenum format, GLenum type, const void *data)`
+pub type glClearTexImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    format: GLenum,
+    type_: GLenum,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glClearTexSubImage(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLenum type, const void *data)`
+pub type glClearTexSubImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `GLenum glClientWaitSync(GLsync sync, GLbitfield flags, GLuint64 timeout)`
+pub type glClientWaitSync_t = Option<
+  unsafe extern "system" fn(
+    sync: GLsync,
+    flags: GLbitfield,
+    timeout: GLuint64,
+  ) -> GLenum,
+>;
+
+/// From `gl.xml`: `void glClipControl(GLenum origin, GLenum depth)`
+pub type glClipControl_t = Option<
+  unsafe extern "system" fn(
+    origin: GLenum,
+    depth: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glColorMask(GLboolean red, GLboolean green, GLboolean blue, GLboolean alpha)`
+pub type glColorMask_t = Option<
+  unsafe extern "system" fn(
+    red: GLboolean,
+    green: GLboolean,
+    blue: GLboolean,
+    alpha: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glColorMaski(GLuint index, GLboolean r, GLboolean g, GLboolean b, GLboolean a)`
+pub type glColorMaski_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    r: GLboolean,
+    g: GLboolean,
+    b: GLboolean,
+    a: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompileShader(GLuint shader)`
+pub type glCompileShader_t = Option<unsafe extern "system" fn(shader: GLuint)>;
+
+/// From `gl.xml`: `void glCompressedTexImage1D(GLenum target, GLint level, GLenum internalformat, GLsizei width, GLint border, GLsizei imageSize, const void *data)`
+pub type glCompressedTexImage1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    width: GLsizei,
+    border: GLint,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTexImage2D(GLenum target, GLint level, GLenum internalformat, GLsizei width, GLsizei height, GLint border, GLsizei imageSize, const void *data)`
+pub type glCompressedTexImage2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTexImage3D(GLenum target, GLint level, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth, GLint border, GLsizei imageSize, const void *data)`
+pub type glCompressedTexImage3D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    border: GLint,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTexSubImage1D(GLenum target, GLint level, GLint xoffset, GLsizei width, GLenum format, GLsizei imageSize, const void *data)`
+pub type glCompressedTexSubImage1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    width: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTexSubImage2D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLsizei width, GLsizei height, GLenum format, GLsizei imageSize, const void *data)`
+pub type glCompressedTexSubImage2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTexSubImage3D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLsizei imageSize, const void *data)`
+pub type glCompressedTexSubImage3D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTextureSubImage1D(GLuint texture, GLint level, GLint xoffset, GLsizei width, GLenum format, GLsizei imageSize, const void *data)`
+pub type glCompressedTextureSubImage1D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    width: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTextureSubImage2D(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLsizei width, GLsizei height, GLenum format, GLsizei imageSize, const void *data)`
+pub type glCompressedTextureSubImage2D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCompressedTextureSubImage3D(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLsizei imageSize, const void *data)`
+pub type glCompressedTextureSubImage3D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyBufferSubData(GLenum readTarget, GLenum writeTarget, GLintptr readOffset, GLintptr writeOffset, GLsizeiptr size)`
+pub type glCopyBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    readTarget: GLenum,
+    writeTarget: GLenum,
+    readOffset: GLintptr,
+    writeOffset: GLintptr,
+    size: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyImageSubData(GLuint srcName, GLenum srcTarget, GLint srcLevel, GLint srcX, GLint srcY, GLint srcZ, GLuint dstName, GLenum dstTarget, GLint dstLevel, GLint dstX, GLint dstY, GLint dstZ, GLsizei srcWidth, GLsizei srcHeight, GLsizei srcDepth)`
+pub type glCopyImageSubData_t = Option<
+  unsafe extern "system" fn(
+    srcName: GLuint,
+    srcTarget: GLenum,
+    srcLevel: GLint,
+    srcX: GLint,
+    srcY: GLint,
+    srcZ: GLint,
+    dstName: GLuint,
+    dstTarget: GLenum,
+    dstLevel: GLint,
+    dstX: GLint,
+    dstY: GLint,
+    dstZ: GLint,
+    srcWidth: GLsizei,
+    srcHeight: GLsizei,
+    srcDepth: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyNamedBufferSubData(GLuint readBuffer, GLuint writeBuffer, GLintptr readOffset, GLintptr writeOffset, GLsizeiptr size)`
+pub type glCopyNamedBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    readBuffer: GLuint,
+    writeBuffer: GLuint,
+    readOffset: GLintptr,
+    writeOffset: GLintptr,
+    size: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTexImage1D(GLenum target, GLint level, GLenum internalformat, GLint x, GLint y, GLsizei width, GLint border)`
+pub type glCopyTexImage1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    border: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTexImage2D(GLenum target, GLint level, GLenum internalformat, GLint x, GLint y, GLsizei width, GLsizei height, GLint border)`
+pub type glCopyTexImage2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTexSubImage1D(GLenum target, GLint level, GLint xoffset, GLint x, GLint y, GLsizei width)`
+pub type glCopyTexSubImage1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTexSubImage2D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glCopyTexSubImage2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTexSubImage3D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glCopyTexSubImage3D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTextureSubImage1D(GLuint texture, GLint level, GLint xoffset, GLint x, GLint y, GLsizei width)`
+pub type glCopyTextureSubImage1D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTextureSubImage2D(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glCopyTextureSubImage2D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glCopyTextureSubImage3D(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glCopyTextureSubImage3D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glCreateBuffers(GLsizei n, GLuint *buffers)`
+pub type glCreateBuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    buffers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCreateFramebuffers(GLsizei n, GLuint *framebuffers)`
+pub type glCreateFramebuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    framebuffers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `GLuint glCreateProgram()`
+pub type glCreateProgram_t = Option<unsafe extern "system" fn() -> GLuint>;
+
+/// From `gl.xml`: `void glCreateProgramPipelines(GLsizei n, GLuint *pipelines)`
+pub type glCreateProgramPipelines_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    pipelines: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCreateQueries(GLenum target, GLsizei n, GLuint *ids)`
+pub type glCreateQueries_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    n: GLsizei,
+    ids: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCreateRenderbuffers(GLsizei n, GLuint *renderbuffers)`
+pub type glCreateRenderbuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    renderbuffers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCreateSamplers(GLsizei n, GLuint *samplers)`
+pub type glCreateSamplers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    samplers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `GLuint glCreateShader(GLenum type)`
+pub type glCreateShader_t = Option<
+  unsafe extern "system" fn(
+    type_: GLenum,
+  ) -> GLuint,
+>;
+
+/// From `gl.xml`: `GLuint glCreateShaderProgramv(GLenum type, GLsizei count, const GLchar *const*strings)`
+pub type glCreateShaderProgramv_t = Option<
+  unsafe extern "system" fn(
+    type_: GLenum,
+    count: GLsizei,
+    strings: *const *const GLchar,
+  ) -> GLuint,
+>;
+
+/// From `gl.xml`: `void glCreateTextures(GLenum target, GLsizei n, GLuint *textures)`
+pub type glCreateTextures_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    n: GLsizei,
+    textures: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCreateTransformFeedbacks(GLsizei n, GLuint *ids)`
+pub type glCreateTransformFeedbacks_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    ids: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCreateVertexArrays(GLsizei n, GLuint *arrays)`
+pub type glCreateVertexArrays_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    arrays: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glCullFace(GLenum mode)`
+pub type glCullFace_t = Option<unsafe extern "system" fn(mode: GLenum)>;
+
+/// From `gl.xml`: `void glDebugMessageCallback(GLDEBUGPROC callback, const void *userParam)`
+pub type glDebugMessageCallback_t = Option<
+  unsafe extern "system" fn(
+    callback: GLDEBUGPROC,
+    userParam: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glDebugMessageControl(GLenum source, GLenum type, GLenum severity, GLsizei count, const GLuint *ids, GLboolean enabled)`
+pub type glDebugMessageControl_t = Option<
+  unsafe extern "system" fn(
+    source: GLenum,
+    type_: GLenum,
+    severity: GLenum,
+    count: GLsizei,
+    ids: *const GLuint,
+    enabled: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glDebugMessageInsert(GLenum source, GLenum type, GLuint id, GLenum severity, GLsizei length, const GLchar *buf)`
+pub type glDebugMessageInsert_t = Option<
+  unsafe extern "system" fn(
+    source: GLenum,
+    type_: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    buf: *const GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteBuffers(GLsizei n, const GLuint *buffers)`
+pub type glDeleteBuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    buffers: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteFramebuffers(GLsizei n, const GLuint *framebuffers)`
+pub type glDeleteFramebuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    framebuffers: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteProgram(GLuint program)`
+pub type glDeleteProgram_t = Option<unsafe extern "system" fn(program: GLuint)>;
+
+/// From `gl.xml`: `void glDeleteProgramPipelines(GLsizei n, const GLuint *pipelines)`
+pub type glDeleteProgramPipelines_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    pipelines: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteQueries(GLsizei n, const GLuint *ids)`
+pub type glDeleteQueries_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    ids: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteRenderbuffers(GLsizei n, const GLuint *renderbuffers)`
+pub type glDeleteRenderbuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    renderbuffers: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteSamplers(GLsizei count, const GLuint *samplers)`
+pub type glDeleteSamplers_t = Option<
+  unsafe extern "system" fn(
+    count: GLsizei,
+    samplers: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteShader(GLuint shader)`
+pub type glDeleteShader_t = Option<unsafe extern "system" fn(shader: GLuint)>;
+
+/// From `gl.xml`: `void glDeleteSync(GLsync sync)`
+pub type glDeleteSync_t = Option<unsafe extern "system" fn(sync: GLsync)>;
+
+/// From `gl.xml`: `void glDeleteTextures(GLsizei n, const GLuint *textures)`
+pub type glDeleteTextures_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    textures: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteTransformFeedbacks(GLsizei n, const GLuint *ids)`
+pub type glDeleteTransformFeedbacks_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    ids: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDeleteVertexArrays(GLsizei n, const GLuint *arrays)`
+pub type glDeleteVertexArrays_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    arrays: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDepthFunc(GLenum func)`
+pub type glDepthFunc_t = Option<unsafe extern "system" fn(func: GLenum)>;
+
+/// From `gl.xml`: `void glDepthMask(GLboolean flag)`
+pub type glDepthMask_t = Option<unsafe extern "system" fn(flag: GLboolean)>;
+
+/// From `gl.xml`: `void glDepthRange(GLdouble n, GLdouble f)`
+pub type glDepthRange_t = Option<
+  unsafe extern "system" fn(
+    n: GLdouble,
+    f: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glDepthRangeArrayv(GLuint first, GLsizei count, const GLdouble *v)`
+pub type glDepthRangeArrayv_t = Option<
+  unsafe extern "system" fn(
+    first: GLuint,
+    count: GLsizei,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glDepthRangeIndexed(GLuint index, GLdouble n, GLdouble f)`
+pub type glDepthRangeIndexed_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    n: GLdouble,
+    f: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glDepthRangef(GLfloat n, GLfloat f)`
+pub type glDepthRangef_t = Option<
+  unsafe extern "system" fn(
+    n: GLfloat,
+    f: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glDetachShader(GLuint program, GLuint shader)`
+pub type glDetachShader_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shader: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDisable(GLenum cap)`
+pub type glDisable_t = Option<unsafe extern "system" fn(cap: GLenum)>;
+
+/// From `gl.xml`: `void glDisableVertexArrayAttrib(GLuint vaobj, GLuint index)`
+pub type glDisableVertexArrayAttrib_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDisableVertexAttribArray(GLuint index)`
+pub type glDisableVertexAttribArray_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDisablei(GLenum target, GLuint index)`
+pub type glDisablei_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDispatchCompute(GLuint num_groups_x, GLuint num_groups_y, GLuint num_groups_z)`
+pub type glDispatchCompute_t = Option<
+  unsafe extern "system" fn(
+    num_groups_x: GLuint,
+    num_groups_y: GLuint,
+    num_groups_z: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDispatchComputeIndirect(GLintptr indirect)`
+pub type glDispatchComputeIndirect_t = Option<
+  unsafe extern "system" fn(
+    indirect: GLintptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawArrays(GLenum mode, GLint first, GLsizei count)`
+pub type glDrawArrays_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawArraysIndirect(GLenum mode, const void *indirect)`
+pub type glDrawArraysIndirect_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    indirect: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawArraysInstanced(GLenum mode, GLint first, GLsizei count, GLsizei instancecount)`
+pub type glDrawArraysInstanced_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+    instancecount: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawArraysInstancedBaseInstance(GLenum mode, GLint first, GLsizei count, GLsizei instancecount, GLuint baseinstance)`
+pub type glDrawArraysInstancedBaseInstance_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+    instancecount: GLsizei,
+    baseinstance: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawBuffer(GLenum buf)`
+pub type glDrawBuffer_t = Option<unsafe extern "system" fn(buf: GLenum)>;
+
+/// From `gl.xml`: `void glDrawBuffers(GLsizei n, const GLenum *bufs)`
+pub type glDrawBuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    bufs: *const GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawElements(GLenum mode, GLsizei count, GLenum type, const void *indices)`
+pub type glDrawElements_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawElementsBaseVertex(GLenum mode, GLsizei count, GLenum type, const void *indices, GLint basevertex)`
+pub type glDrawElementsBaseVertex_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    basevertex: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawElementsIndirect(GLenum mode, GLenum type, const void *indirect)`
+pub type glDrawElementsIndirect_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    type_: GLenum,
+    indirect: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawElementsInstanced(GLenum mode, GLsizei count, GLenum type, const void *indices, GLsizei instancecount)`
+pub type glDrawElementsInstanced_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawElementsInstancedBaseInstance(GLenum mode, GLsizei count, GLenum type, const void *indices, GLsizei instancecount, GLuint baseinstance)`
+pub type glDrawElementsInstancedBaseInstance_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+    baseinstance: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawElementsInstancedBaseVertex(GLenum mode, GLsizei count, GLenum type, const void *indices, GLsizei instancecount, GLint basevertex)`
+pub type glDrawElementsInstancedBaseVertex_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+    basevertex: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawElementsInstancedBaseVertexBaseInstance(GLenum mode, GLsizei count, GLenum type, const void *indices, GLsizei instancecount, GLint basevertex, GLuint baseinstance)`
+pub type glDrawElementsInstancedBaseVertexBaseInstance_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+    basevertex: GLint,
+    baseinstance: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawRangeElements(GLenum mode, GLuint start, GLuint end, GLsizei count, GLenum type, const void *indices)`
+pub type glDrawRangeElements_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    start: GLuint,
+    end: GLuint,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawRangeElementsBaseVertex(GLenum mode, GLuint start, GLuint end, GLsizei count, GLenum type, const void *indices, GLint basevertex)`
+pub type glDrawRangeElementsBaseVertex_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    start: GLuint,
+    end: GLuint,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    basevertex: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawTransformFeedback(GLenum mode, GLuint id)`
+pub type glDrawTransformFeedback_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    id: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawTransformFeedbackInstanced(GLenum mode, GLuint id, GLsizei instancecount)`
+pub type glDrawTransformFeedbackInstanced_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    id: GLuint,
+    instancecount: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawTransformFeedbackStream(GLenum mode, GLuint id, GLuint stream)`
+pub type glDrawTransformFeedbackStream_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    id: GLuint,
+    stream: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glDrawTransformFeedbackStreamInstanced(GLenum mode, GLuint id, GLuint stream, GLsizei instancecount)`
+pub type glDrawTransformFeedbackStreamInstanced_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    id: GLuint,
+    stream: GLuint,
+    instancecount: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glEnable(GLenum cap)`
+pub type glEnable_t = Option<unsafe extern "system" fn(cap: GLenum)>;
+
+/// From `gl.xml`: `void glEnableVertexArrayAttrib(GLuint vaobj, GLuint index)`
+pub type glEnableVertexArrayAttrib_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glEnableVertexAttribArray(GLuint index)`
+pub type glEnableVertexAttribArray_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glEnablei(GLenum target, GLuint index)`
+pub type glEnablei_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glEndConditionalRender()`
+pub type glEndConditionalRender_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glEndQuery(GLenum target)`
+pub type glEndQuery_t = Option<unsafe extern "system" fn(target: GLenum)>;
+
+/// From `gl.xml`: `void glEndQueryIndexed(GLenum target, GLuint index)`
+pub type glEndQueryIndexed_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glEndTransformFeedback()`
+pub type glEndTransformFeedback_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `GLsync glFenceSync(GLenum condition, GLbitfield flags)`
+pub type glFenceSync_t = Option<
+  unsafe extern "system" fn(
+    condition: GLenum,
+    flags: GLbitfield,
+  ) -> GLsync,
+>;
+
+/// From `gl.xml`: `void glFinish()`
+pub type glFinish_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glFlush()`
+pub type glFlush_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glFlushMappedBufferRange(GLenum target, GLintptr offset, GLsizeiptr length)`
+pub type glFlushMappedBufferRange_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    offset: GLintptr,
+    length: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glFlushMappedNamedBufferRange(GLuint buffer, GLintptr offset, GLsizeiptr length)`
+pub type glFlushMappedNamedBufferRange_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    offset: GLintptr,
+    length: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glFramebufferParameteri(GLenum target, GLenum pname, GLint param)`
+pub type glFramebufferParameteri_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    param: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glFramebufferRenderbuffer(GLenum target, GLenum attachment, GLenum renderbuffertarget, GLuint renderbuffer)`
+pub type glFramebufferRenderbuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    attachment: GLenum,
+    renderbuffertarget: GLenum,
+    renderbuffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glFramebufferTexture(GLenum target, GLenum attachment, GLuint texture, GLint level)`
+pub type glFramebufferTexture_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    attachment: GLenum,
+    texture: GLuint,
+    level: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glFramebufferTexture1D(GLenum target, GLenum attachment, GLenum textarget, GLuint texture, GLint level)`
+pub type glFramebufferTexture1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glFramebufferTexture2D(GLenum target, GLenum attachment, GLenum textarget, GLuint texture, GLint level)`
+pub type glFramebufferTexture2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glFramebufferTexture3D(GLenum target, GLenum attachment, GLenum textarget, GLuint texture, GLint level, GLint zoffset)`
+pub type glFramebufferTexture3D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+    zoffset: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glFramebufferTextureLayer(GLenum target, GLenum attachment, GLuint texture, GLint level, GLint layer)`
+pub type glFramebufferTextureLayer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    attachment: GLenum,
+    texture: GLuint,
+    level: GLint,
+    layer: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glFrontFace(GLenum mode)`
+pub type glFrontFace_t = Option<unsafe extern "system" fn(mode: GLenum)>;
+
+/// From `gl.xml`: `void glGenBuffers(GLsizei n, GLuint *buffers)`
+pub type glGenBuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    buffers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenFramebuffers(GLsizei n, GLuint *framebuffers)`
+pub type glGenFramebuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    framebuffers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenProgramPipelines(GLsizei n, GLuint *pipelines)`
+pub type glGenProgramPipelines_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    pipelines: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenQueries(GLsizei n, GLuint *ids)`
+pub type glGenQueries_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    ids: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenRenderbuffers(GLsizei n, GLuint *renderbuffers)`
+pub type glGenRenderbuffers_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    renderbuffers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenSamplers(GLsizei count, GLuint *samplers)`
+pub type glGenSamplers_t = Option<
+  unsafe extern "system" fn(
+    count: GLsizei,
+    samplers: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenTextures(GLsizei n, GLuint *textures)`
+pub type glGenTextures_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    textures: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenTransformFeedbacks(GLsizei n, GLuint *ids)`
+pub type glGenTransformFeedbacks_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    ids: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenVertexArrays(GLsizei n, GLuint *arrays)`
+pub type glGenVertexArrays_t = Option<
+  unsafe extern "system" fn(
+    n: GLsizei,
+    arrays: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGenerateMipmap(GLenum target)`
+pub type glGenerateMipmap_t = Option<unsafe extern "system" fn(target: GLenum)>;
+
+/// From `gl.xml`: `void glGenerateTextureMipmap(GLuint texture)`
+pub type glGenerateTextureMipmap_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveAtomicCounterBufferiv(GLuint program, GLuint bufferIndex, GLenum pname, GLint *params)`
+pub type glGetActiveAtomicCounterBufferiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    bufferIndex: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveAttrib(GLuint program, GLuint index, GLsizei bufSize, GLsizei *length, GLint *size, GLenum *type, GLchar *name)`
+pub type glGetActiveAttrib_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    index: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    size: *mut GLint,
+    type_: *mut GLenum,
+    name: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveSubroutineName(GLuint program, GLenum shadertype, GLuint index, GLsizei bufsize, GLsizei *length, GLchar *name)`
+pub type glGetActiveSubroutineName_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shadertype: GLenum,
+    index: GLuint,
+    bufsize: GLsizei,
+    length: *mut GLsizei,
+    name: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveSubroutineUniformName(GLuint program, GLenum shadertype, GLuint index, GLsizei bufsize, GLsizei *length, GLchar *name)`
+pub type glGetActiveSubroutineUniformName_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shadertype: GLenum,
+    index: GLuint,
+    bufsize: GLsizei,
+    length: *mut GLsizei,
+    name: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveSubroutineUniformiv(GLuint program, GLenum shadertype, GLuint index, GLenum pname, GLint *values)`
+pub type glGetActiveSubroutineUniformiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shadertype: GLenum,
+    index: GLuint,
+    pname: GLenum,
+    values: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveUniform(GLuint program, GLuint index, GLsizei bufSize, GLsizei *length, GLint *size, GLenum *type, GLchar *name)`
+pub type glGetActiveUniform_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    index: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    size: *mut GLint,
+    type_: *mut GLenum,
+    name: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveUniformBlockName(GLuint program, GLuint uniformBlockIndex, GLsizei bufSize, GLsizei *length, GLchar *uniformBlockName)`
+pub type glGetActiveUniformBlockName_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    uniformBlockIndex: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    uniformBlockName: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveUniformBlockiv(GLuint program, GLuint uniformBlockIndex, GLenum pname, GLint *params)`
+pub type glGetActiveUniformBlockiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    uniformBlockIndex: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveUniformName(GLuint program, GLuint uniformIndex, GLsizei bufSize, GLsizei *length, GLchar *uniformName)`
+pub type glGetActiveUniformName_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    uniformIndex: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    uniformName: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetActiveUniformsiv(GLuint program, GLsizei uniformCount, const GLuint *uniformIndices, GLenum pname, GLint *params)`
+pub type glGetActiveUniformsiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    uniformCount: GLsizei,
+    uniformIndices: *const GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetAttachedShaders(GLuint program, GLsizei maxCount, GLsizei *count, GLuint *shaders)`
+pub type glGetAttachedShaders_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    maxCount: GLsizei,
+    count: *mut GLsizei,
+    shaders: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `GLint glGetAttribLocation(GLuint program, const GLchar *name)`
+pub type glGetAttribLocation_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint,
+>;
+
+/// From `gl.xml`: `void glGetBooleani_v(GLenum target, GLuint index, GLboolean *data)`
+pub type glGetBooleani_v_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetBooleanv(GLenum pname, GLboolean *data)`
+pub type glGetBooleanv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    data: *mut GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetBufferParameteri64v(GLenum target, GLenum pname, GLint64 *params)`
+pub type glGetBufferParameteri64v_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetBufferParameteriv(GLenum target, GLenum pname, GLint *params)`
+pub type glGetBufferParameteriv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetBufferPointerv(GLenum target, GLenum pname, void **params)`
+pub type glGetBufferPointerv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetBufferSubData(GLenum target, GLintptr offset, GLsizeiptr size, void *data)`
+pub type glGetBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetCompressedTexImage(GLenum target, GLint level, void *img)`
+pub type glGetCompressedTexImage_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    img: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetCompressedTextureImage(GLuint texture, GLint level, GLsizei bufSize, void *pixels)`
+pub type glGetCompressedTextureImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    bufSize: GLsizei,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetCompressedTextureSubImage(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLsizei bufSize, void *pixels)`
+pub type glGetCompressedTextureSubImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    bufSize: GLsizei,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `GLuint glGetDebugMessageLog(GLuint count, GLsizei bufSize, GLenum *sources, GLenum *types, GLuint *ids, GLenum *severities, GLsizei *lengths, GLchar *messageLog)`
+pub type glGetDebugMessageLog_t = Option<
+  unsafe extern "system" fn(
+    count: GLuint,
+    bufSize: GLsizei,
+    sources: *mut GLenum,
+    types: *mut GLenum,
+    ids: *mut GLuint,
+    severities: *mut GLenum,
+    lengths: *mut GLsizei,
+    messageLog: *mut GLchar,
+  ) -> GLuint,
+>;
+
+/// From `gl.xml`: `void glGetDoublei_v(GLenum target, GLuint index, GLdouble *data)`
+pub type glGetDoublei_v_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetDoublev(GLenum pname, GLdouble *data)`
+pub type glGetDoublev_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    data: *mut GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `GLenum glGetError()`
+pub type glGetError_t = Option<unsafe extern "system" fn() -> GLenum>;
+
+/// From `gl.xml`: `void glGetFloati_v(GLenum target, GLuint index, GLfloat *data)`
+pub type glGetFloati_v_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetFloatv(GLenum pname, GLfloat *data)`
+pub type glGetFloatv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    data: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `GLint glGetFragDataIndex(GLuint program, const GLchar *name)`
+pub type glGetFragDataIndex_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint,
+>;
+
+/// From `gl.xml`: `GLint glGetFragDataLocation(GLuint program, const GLchar *name)`
+pub type glGetFragDataLocation_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint,
+>;
+
+/// From `gl.xml`: `void glGetFramebufferAttachmentParameteriv(GLenum target, GLenum attachment, GLenum pname, GLint *params)`
+pub type glGetFramebufferAttachmentParameteriv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    attachment: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetFramebufferParameteriv(GLenum target, GLenum pname, GLint *params)`
+pub type glGetFramebufferParameteriv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `GLenum glGetGraphicsResetStatus()`
+pub type glGetGraphicsResetStatus_t = Option<
+  unsafe extern "system" fn(
+  ) -> GLenum,
+>;
+
+/// From `gl.xml`: `void glGetInteger64i_v(GLenum target, GLuint index, GLint64 *data)`
+pub type glGetInteger64i_v_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetInteger64v(GLenum pname, GLint64 *data)`
+pub type glGetInteger64v_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    data: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetIntegeri_v(GLenum target, GLuint index, GLint *data)`
+pub type glGetIntegeri_v_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetIntegerv(GLenum pname, GLint *data)`
+pub type glGetIntegerv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    data: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetInternalformati64v(GLenum target, GLenum internalformat, GLenum pname, GLsizei bufSize, GLint64 *params)`
+pub type glGetInternalformati64v_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    internalformat: GLenum,
+    pname: GLenum,
+    bufSize: GLsizei,
+    params: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetInternalformativ(GLenum target, GLenum internalformat, GLenum pname, GLsizei bufSize, GLint *params)`
+pub type glGetInternalformativ_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    internalformat: GLenum,
+    pname: GLenum,
+    bufSize: GLsizei,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetMultisamplefv(GLenum pname, GLuint index, GLfloat *val)`
+pub type glGetMultisamplefv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    index: GLuint,
+    val: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetNamedBufferParameteri64v(GLuint buffer, GLenum pname, GLint64 *params)`
+pub type glGetNamedBufferParameteri64v_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    pname: GLenum,
+    params: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetNamedBufferParameteriv(GLuint buffer, GLenum pname, GLint *params)`
+pub type glGetNamedBufferParameteriv_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetNamedBufferPointerv(GLuint buffer, GLenum pname, void **params)`
+pub type glGetNamedBufferPointerv_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    pname: GLenum,
+    params: *mut *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetNamedBufferSubData(GLuint buffer, GLintptr offset, GLsizeiptr size, void *data)`
+pub type glGetNamedBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetNamedFramebufferAttachmentParameteriv(GLuint framebuffer, GLenum attachment, GLenum pname, GLint *params)`
+pub type glGetNamedFramebufferAttachmentParameteriv_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    attachment: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetNamedFramebufferParameteriv(GLuint framebuffer, GLenum pname, GLint *param)`
+pub type glGetNamedFramebufferParameteriv_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    pname: GLenum,
+    param: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetNamedRenderbufferParameteriv(GLuint renderbuffer, GLenum pname, GLint *params)`
+pub type glGetNamedRenderbufferParameteriv_t = Option<
+  unsafe extern "system" fn(
+    renderbuffer: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetObjectLabel(GLenum identifier, GLuint name, GLsizei bufSize, GLsizei *length, GLchar *label)`
+pub type glGetObjectLabel_t = Option<
+  unsafe extern "system" fn(
+    identifier: GLenum,
+    name: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    label: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetObjectPtrLabel(const void *ptr, GLsizei bufSize, GLsizei *length, GLchar *label)`
+pub type glGetObjectPtrLabel_t = Option<
+  unsafe extern "system" fn(
+    ptr: *const c_void,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    label: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetPointerv(GLenum pname, void **params)`
+pub type glGetPointerv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    params: *mut *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramBinary(GLuint program, GLsizei bufSize, GLsizei *length, GLenum *binaryFormat, void *binary)`
+pub type glGetProgramBinary_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    binaryFormat: *mut GLenum,
+    binary: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramInfoLog(GLuint program, GLsizei bufSize, GLsizei *length, GLchar *infoLog)`
+pub type glGetProgramInfoLog_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    infoLog: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramInterfaceiv(GLuint program, GLenum programInterface, GLenum pname, GLint *params)`
+pub type glGetProgramInterfaceiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    programInterface: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramPipelineInfoLog(GLuint pipeline, GLsizei bufSize, GLsizei *length, GLchar *infoLog)`
+pub type glGetProgramPipelineInfoLog_t = Option<
+  unsafe extern "system" fn(
+    pipeline: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    infoLog: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramPipelineiv(GLuint pipeline, GLenum pname, GLint *params)`
+pub type glGetProgramPipelineiv_t = Option<
+  unsafe extern "system" fn(
+    pipeline: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `GLuint glGetProgramResourceIndex(GLuint program, GLenum programInterface, const GLchar *name)`
+pub type glGetProgramResourceIndex_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    programInterface: GLenum,
+    name: *const GLchar,
+  ) -> GLuint,
+>;
+
+/// From `gl.xml`: `GLint glGetProgramResourceLocation(GLuint program, GLenum programInterface, const GLchar *name)`
+pub type glGetProgramResourceLocation_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    programInterface: GLenum,
+    name: *const GLchar,
+  ) -> GLint,
+>;
+
+/// From `gl.xml`: `GLint glGetProgramResourceLocationIndex(GLuint program, GLenum programInterface, const GLchar *name)`
+pub type glGetProgramResourceLocationIndex_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    programInterface: GLenum,
+    name: *const GLchar,
+  ) -> GLint,
+>;
+
+/// From `gl.xml`: `void glGetProgramResourceName(GLuint program, GLenum programInterface, GLuint index, GLsizei bufSize, GLsizei *length, GLchar *name)`
+pub type glGetProgramResourceName_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    programInterface: GLenum,
+    index: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    name: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramResourceiv(GLuint program, GLenum programInterface, GLuint index, GLsizei propCount, const GLenum *props, GLsizei bufSize, GLsizei *length, GLint *params)`
+pub type glGetProgramResourceiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    programInterface: GLenum,
+    index: GLuint,
+    propCount: GLsizei,
+    props: *const GLenum,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramStageiv(GLuint program, GLenum shadertype, GLenum pname, GLint *values)`
+pub type glGetProgramStageiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shadertype: GLenum,
+    pname: GLenum,
+    values: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetProgramiv(GLuint program, GLenum pname, GLint *params)`
+pub type glGetProgramiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryBufferObjecti64v(GLuint id, GLuint buffer, GLenum pname, GLintptr offset)`
+pub type glGetQueryBufferObjecti64v_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    buffer: GLuint,
+    pname: GLenum,
+    offset: GLintptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryBufferObjectiv(GLuint id, GLuint buffer, GLenum pname, GLintptr offset)`
+pub type glGetQueryBufferObjectiv_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    buffer: GLuint,
+    pname: GLenum,
+    offset: GLintptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryBufferObjectui64v(GLuint id, GLuint buffer, GLenum pname, GLintptr offset)`
+pub type glGetQueryBufferObjectui64v_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    buffer: GLuint,
+    pname: GLenum,
+    offset: GLintptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryBufferObjectuiv(GLuint id, GLuint buffer, GLenum pname, GLintptr offset)`
+pub type glGetQueryBufferObjectuiv_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    buffer: GLuint,
+    pname: GLenum,
+    offset: GLintptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryIndexediv(GLenum target, GLuint index, GLenum pname, GLint *params)`
+pub type glGetQueryIndexediv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryObjecti64v(GLuint id, GLenum pname, GLint64 *params)`
+pub type glGetQueryObjecti64v_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryObjectiv(GLuint id, GLenum pname, GLint *params)`
+pub type glGetQueryObjectiv_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryObjectui64v(GLuint id, GLenum pname, GLuint64 *params)`
+pub type glGetQueryObjectui64v_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLuint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryObjectuiv(GLuint id, GLenum pname, GLuint *params)`
+pub type glGetQueryObjectuiv_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetQueryiv(GLenum target, GLenum pname, GLint *params)`
+pub type glGetQueryiv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetRenderbufferParameteriv(GLenum target, GLenum pname, GLint *params)`
+pub type glGetRenderbufferParameteriv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetSamplerParameterIiv(GLuint sampler, GLenum pname, GLint *params)`
+pub type glGetSamplerParameterIiv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetSamplerParameterIuiv(GLuint sampler, GLenum pname, GLuint *params)`
+pub type glGetSamplerParameterIuiv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetSamplerParameterfv(GLuint sampler, GLenum pname, GLfloat *params)`
+pub type glGetSamplerParameterfv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetSamplerParameteriv(GLuint sampler, GLenum pname, GLint *params)`
+pub type glGetSamplerParameteriv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetShaderInfoLog(GLuint shader, GLsizei bufSize, GLsizei *length, GLchar *infoLog)`
+pub type glGetShaderInfoLog_t = Option<
+  unsafe extern "system" fn(
+    shader: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    infoLog: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetShaderPrecisionFormat(GLenum shadertype, GLenum precisiontype, GLint *range, GLint *precision)`
+pub type glGetShaderPrecisionFormat_t = Option<
+  unsafe extern "system" fn(
+    shadertype: GLenum,
+    precisiontype: GLenum,
+    range: *mut GLint,
+    precision: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetShaderSource(GLuint shader, GLsizei bufSize, GLsizei *length, GLchar *source)`
+pub type glGetShaderSource_t = Option<
+  unsafe extern "system" fn(
+    shader: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    source: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetShaderiv(GLuint shader, GLenum pname, GLint *params)`
+pub type glGetShaderiv_t = Option<
+  unsafe extern "system" fn(
+    shader: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `const GLubyte *glGetString(GLenum name)`
+pub type glGetString_t = Option<
+  unsafe extern "system" fn(
+    name: GLenum,
+  ) -> *const GLubyte,
+>;
+
+/// From `gl.xml`: `const GLubyte *glGetStringi(GLenum name, GLuint index)`
+pub type glGetStringi_t = Option<
+  unsafe extern "system" fn(
+    name: GLenum,
+    index: GLuint,
+  ) -> *const GLubyte,
+>;
+
+/// From `gl.xml`: `GLuint glGetSubroutineIndex(GLuint program, GLenum shadertype, const GLchar *name)`
+pub type glGetSubroutineIndex_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shadertype: GLenum,
+    name: *const GLchar,
+  ) -> GLuint,
+>;
+
+/// From `gl.xml`: `GLint glGetSubroutineUniformLocation(GLuint program, GLenum shadertype, const GLchar *name)`
+pub type glGetSubroutineUniformLocation_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    shadertype: GLenum,
+    name: *const GLchar,
+  ) -> GLint,
+>;
+
+/// From `gl.xml`: `void glGetSynciv(GLsync sync, GLenum pname, GLsizei bufSize, GLsizei *length, GLint *values)`
+pub type glGetSynciv_t = Option<
+  unsafe extern "system" fn(
+    sync: GLsync,
+    pname: GLenum,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    values: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTexImage(GLenum target, GLint level, GLenum format, GLenum type, void *pixels)`
+pub type glGetTexImage_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTexLevelParameterfv(GLenum target, GLint level, GLenum pname, GLfloat *params)`
+pub type glGetTexLevelParameterfv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTexLevelParameteriv(GLenum target, GLint level, GLenum pname, GLint *params)`
+pub type glGetTexLevelParameteriv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTexParameterIiv(GLenum target, GLenum pname, GLint *params)`
+pub type glGetTexParameterIiv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTexParameterIuiv(GLenum target, GLenum pname, GLuint *params)`
+pub type glGetTexParameterIuiv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTexParameterfv(GLenum target, GLenum pname, GLfloat *params)`
+pub type glGetTexParameterfv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTexParameteriv(GLenum target, GLenum pname, GLint *params)`
+pub type glGetTexParameteriv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureImage(GLuint texture, GLint level, GLenum format, GLenum type, GLsizei bufSize, void *pixels)`
+pub type glGetTextureImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    format: GLenum,
+    type_: GLenum,
+    bufSize: GLsizei,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureLevelParameterfv(GLuint texture, GLint level, GLenum pname, GLfloat *params)`
+pub type glGetTextureLevelParameterfv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureLevelParameteriv(GLuint texture, GLint level, GLenum pname, GLint *params)`
+pub type glGetTextureLevelParameteriv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureParameterIiv(GLuint texture, GLenum pname, GLint *params)`
+pub type glGetTextureParameterIiv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureParameterIuiv(GLuint texture, GLenum pname, GLuint *params)`
+pub type glGetTextureParameterIuiv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureParameterfv(GLuint texture, GLenum pname, GLfloat *params)`
+pub type glGetTextureParameterfv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureParameteriv(GLuint texture, GLenum pname, GLint *params)`
+pub type glGetTextureParameteriv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTextureSubImage(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLenum type, GLsizei bufSize, void *pixels)`
+pub type glGetTextureSubImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    bufSize: GLsizei,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTransformFeedbackVarying(GLuint program, GLuint index, GLsizei bufSize, GLsizei *length, GLsizei *size, GLenum *type, GLchar *name)`
+pub type glGetTransformFeedbackVarying_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    index: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    size: *mut GLsizei,
+    type_: *mut GLenum,
+    name: *mut GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTransformFeedbacki64_v(GLuint xfb, GLenum pname, GLuint index, GLint64 *param)`
+pub type glGetTransformFeedbacki64_v_t = Option<
+  unsafe extern "system" fn(
+    xfb: GLuint,
+    pname: GLenum,
+    index: GLuint,
+    param: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTransformFeedbacki_v(GLuint xfb, GLenum pname, GLuint index, GLint *param)`
+pub type glGetTransformFeedbacki_v_t = Option<
+  unsafe extern "system" fn(
+    xfb: GLuint,
+    pname: GLenum,
+    index: GLuint,
+    param: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetTransformFeedbackiv(GLuint xfb, GLenum pname, GLint *param)`
+pub type glGetTransformFeedbackiv_t = Option<
+  unsafe extern "system" fn(
+    xfb: GLuint,
+    pname: GLenum,
+    param: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `GLuint glGetUniformBlockIndex(GLuint program, const GLchar *uniformBlockName)`
+pub type glGetUniformBlockIndex_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    uniformBlockName: *const GLchar,
+  ) -> GLuint,
+>;
+
+/// From `gl.xml`: `void glGetUniformIndices(GLuint program, GLsizei uniformCount, const GLchar *const*uniformNames, GLuint *uniformIndices)`
+pub type glGetUniformIndices_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    uniformCount: GLsizei,
+    uniformNames: *const *const GLchar,
+    uniformIndices: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `GLint glGetUniformLocation(GLuint program, const GLchar *name)`
+pub type glGetUniformLocation_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint,
+>;
+
+/// From `gl.xml`: `void glGetUniformSubroutineuiv(GLenum shadertype, GLint location, GLuint *params)`
+pub type glGetUniformSubroutineuiv_t = Option<
+  unsafe extern "system" fn(
+    shadertype: GLenum,
+    location: GLint,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetUniformdv(GLuint program, GLint location, GLdouble *params)`
+pub type glGetUniformdv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    params: *mut GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetUniformfv(GLuint program, GLint location, GLfloat *params)`
+pub type glGetUniformfv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetUniformiv(GLuint program, GLint location, GLint *params)`
+pub type glGetUniformiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetUniformuiv(GLuint program, GLint location, GLuint *params)`
+pub type glGetUniformuiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexArrayIndexed64iv(GLuint vaobj, GLuint index, GLenum pname, GLint64 *param)`
+pub type glGetVertexArrayIndexed64iv_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    index: GLuint,
+    pname: GLenum,
+    param: *mut GLint64,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexArrayIndexediv(GLuint vaobj, GLuint index, GLenum pname, GLint *param)`
+pub type glGetVertexArrayIndexediv_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    index: GLuint,
+    pname: GLenum,
+    param: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexArrayiv(GLuint vaobj, GLenum pname, GLint *param)`
+pub type glGetVertexArrayiv_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    pname: GLenum,
+    param: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexAttribIiv(GLuint index, GLenum pname, GLint *params)`
+pub type glGetVertexAttribIiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexAttribIuiv(GLuint index, GLenum pname, GLuint *params)`
+pub type glGetVertexAttribIuiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexAttribLdv(GLuint index, GLenum pname, GLdouble *params)`
+pub type glGetVertexAttribLdv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexAttribPointerv(GLuint index, GLenum pname, void **pointer)`
+pub type glGetVertexAttribPointerv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    pname: GLenum,
+    pointer: *mut *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexAttribdv(GLuint index, GLenum pname, GLdouble *params)`
+pub type glGetVertexAttribdv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexAttribfv(GLuint index, GLenum pname, GLfloat *params)`
+pub type glGetVertexAttribfv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetVertexAttribiv(GLuint index, GLenum pname, GLint *params)`
+pub type glGetVertexAttribiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetnCompressedTexImage(GLenum target, GLint lod, GLsizei bufSize, void *pixels)`
+pub type glGetnCompressedTexImage_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    lod: GLint,
+    bufSize: GLsizei,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetnTexImage(GLenum target, GLint level, GLenum format, GLenum type, GLsizei bufSize, void *pixels)`
+pub type glGetnTexImage_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    format: GLenum,
+    type_: GLenum,
+    bufSize: GLsizei,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetnUniformdv(GLuint program, GLint location, GLsizei bufSize, GLdouble *params)`
+pub type glGetnUniformdv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    bufSize: GLsizei,
+    params: *mut GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetnUniformfv(GLuint program, GLint location, GLsizei bufSize, GLfloat *params)`
+pub type glGetnUniformfv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    bufSize: GLsizei,
+    params: *mut GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetnUniformiv(GLuint program, GLint location, GLsizei bufSize, GLint *params)`
+pub type glGetnUniformiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    bufSize: GLsizei,
+    params: *mut GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glGetnUniformuiv(GLuint program, GLint location, GLsizei bufSize, GLuint *params)`
+pub type glGetnUniformuiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    bufSize: GLsizei,
+    params: *mut GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glHint(GLenum target, GLenum mode)`
+pub type glHint_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    mode: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateBufferData(GLuint buffer)`
+pub type glInvalidateBufferData_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateBufferSubData(GLuint buffer, GLintptr offset, GLsizeiptr length)`
+pub type glInvalidateBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    offset: GLintptr,
+    length: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateFramebuffer(GLenum target, GLsizei numAttachments, const GLenum *attachments)`
+pub type glInvalidateFramebuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    numAttachments: GLsizei,
+    attachments: *const GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateNamedFramebufferData(GLuint framebuffer, GLsizei numAttachments, const GLenum *attachments)`
+pub type glInvalidateNamedFramebufferData_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    numAttachments: GLsizei,
+    attachments: *const GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateNamedFramebufferSubData(GLuint framebuffer, GLsizei numAttachments, const GLenum *attachments, GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glInvalidateNamedFramebufferSubData_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    numAttachments: GLsizei,
+    attachments: *const GLenum,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateSubFramebuffer(GLenum target, GLsizei numAttachments, const GLenum *attachments, GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glInvalidateSubFramebuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    numAttachments: GLsizei,
+    attachments: *const GLenum,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateTexImage(GLuint texture, GLint level)`
+pub type glInvalidateTexImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glInvalidateTexSubImage(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth)`
+pub type glInvalidateTexSubImage_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `GLboolean glIsBuffer(GLuint buffer)`
+pub type glIsBuffer_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsEnabled(GLenum cap)`
+pub type glIsEnabled_t = Option<
+  unsafe extern "system" fn(
+    cap: GLenum,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsEnabledi(GLenum target, GLuint index)`
+pub type glIsEnabledi_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    index: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsFramebuffer(GLuint framebuffer)`
+pub type glIsFramebuffer_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsProgram(GLuint program)`
+pub type glIsProgram_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsProgramPipeline(GLuint pipeline)`
+pub type glIsProgramPipeline_t = Option<
+  unsafe extern "system" fn(
+    pipeline: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsQuery(GLuint id)`
+pub type glIsQuery_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsRenderbuffer(GLuint renderbuffer)`
+pub type glIsRenderbuffer_t = Option<
+  unsafe extern "system" fn(
+    renderbuffer: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsSampler(GLuint sampler)`
+pub type glIsSampler_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsShader(GLuint shader)`
+pub type glIsShader_t = Option<
+  unsafe extern "system" fn(
+    shader: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsSync(GLsync sync)`
+pub type glIsSync_t = Option<
+  unsafe extern "system" fn(
+    sync: GLsync,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsTexture(GLuint texture)`
+pub type glIsTexture_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsTransformFeedback(GLuint id)`
+pub type glIsTransformFeedback_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glIsVertexArray(GLuint array)`
+pub type glIsVertexArray_t = Option<
+  unsafe extern "system" fn(
+    array: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `void glLineWidth(GLfloat width)`
+pub type glLineWidth_t = Option<unsafe extern "system" fn(width: GLfloat)>;
+
+/// From `gl.xml`: `void glLinkProgram(GLuint program)`
+pub type glLinkProgram_t = Option<unsafe extern "system" fn(program: GLuint)>;
+
+/// From `gl.xml`: `void glLogicOp(GLenum opcode)`
+pub type glLogicOp_t = Option<unsafe extern "system" fn(opcode: GLenum)>;
+
+/// From `gl.xml`: `void *glMapBuffer(GLenum target, GLenum access)`
+pub type glMapBuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    access: GLenum,
+  ) -> *mut c_void,
+>;
+
+/// From `gl.xml`: `void *glMapBufferRange(GLenum target, GLintptr offset, GLsizeiptr length, GLbitfield access)`
+pub type glMapBufferRange_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    offset: GLintptr,
+    length: GLsizeiptr,
+    access: GLbitfield,
+  ) -> *mut c_void,
+>;
+
+/// From `gl.xml`: `void *glMapNamedBuffer(GLuint buffer, GLenum access)`
+pub type glMapNamedBuffer_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    access: GLenum,
+  ) -> *mut c_void,
+>;
+
+/// From `gl.xml`: `void *glMapNamedBufferRange(GLuint buffer, GLintptr offset, GLsizeiptr length, GLbitfield access)`
+pub type glMapNamedBufferRange_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    offset: GLintptr,
+    length: GLsizeiptr,
+    access: GLbitfield,
+  ) -> *mut c_void,
+>;
+
+/// From `gl.xml`: `void glMemoryBarrier(GLbitfield barriers)`
+pub type glMemoryBarrier_t = Option<
+  unsafe extern "system" fn(
+    barriers: GLbitfield,
+  ),
+>;
+
+/// From `gl.xml`: `void glMemoryBarrierByRegion(GLbitfield barriers)`
+pub type glMemoryBarrierByRegion_t = Option<
+  unsafe extern "system" fn(
+    barriers: GLbitfield,
+  ),
+>;
+
+/// From `gl.xml`: `void glMinSampleShading(GLfloat value)`
+pub type glMinSampleShading_t = Option<
+  unsafe extern "system" fn(
+    value: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glMultiDrawArrays(GLenum mode, const GLint *first, const GLsizei *count, GLsizei drawcount)`
+pub type glMultiDrawArrays_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    first: *const GLint,
+    count: *const GLsizei,
+    drawcount: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glMultiDrawArraysIndirect(GLenum mode, const void *indirect, GLsizei drawcount, GLsizei stride)`
+pub type glMultiDrawArraysIndirect_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    indirect: *const c_void,
+    drawcount: GLsizei,
+    stride: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glMultiDrawArraysIndirectCount(GLenum mode, const void *indirect, GLintptr drawcount, GLsizei maxdrawcount, GLsizei stride)`
+pub type glMultiDrawArraysIndirectCount_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    indirect: *const c_void,
+    drawcount: GLintptr,
+    maxdrawcount: GLsizei,
+    stride: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glMultiDrawElements(GLenum mode, const GLsizei *count, GLenum type, const void *const*indices, GLsizei drawcount)`
+pub type glMultiDrawElements_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: *const GLsizei,
+    type_: GLenum,
+    indices: *const *const c_void,
+    drawcount: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glMultiDrawElementsBaseVertex(GLenum mode, const GLsizei *count, GLenum type, const void *const*indices, GLsizei drawcount, const GLint *basevertex)`
+pub type glMultiDrawElementsBaseVertex_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    count: *const GLsizei,
+    type_: GLenum,
+    indices: *const *const c_void,
+    drawcount: GLsizei,
+    basevertex: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glMultiDrawElementsIndirect(GLenum mode, GLenum type, const void *indirect, GLsizei drawcount, GLsizei stride)`
+pub type glMultiDrawElementsIndirect_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    type_: GLenum,
+    indirect: *const c_void,
+    drawcount: GLsizei,
+    stride: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glMultiDrawElementsIndirectCount(GLenum mode, GLenum type, const void *indirect, GLintptr drawcount, GLsizei maxdrawcount, GLsizei stride)`
+pub type glMultiDrawElementsIndirectCount_t = Option<
+  unsafe extern "system" fn(
+    mode: GLenum,
+    type_: GLenum,
+    indirect: *const c_void,
+    drawcount: GLintptr,
+    maxdrawcount: GLsizei,
+    stride: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedBufferData(GLuint buffer, GLsizeiptr size, const void *data, GLenum usage)`
+pub type glNamedBufferData_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    size: GLsizeiptr,
+    data: *const c_void,
+    usage: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedBufferStorage(GLuint buffer, GLsizeiptr size, const void *data, GLbitfield flags)`
+pub type glNamedBufferStorage_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    size: GLsizeiptr,
+    data: *const c_void,
+    flags: GLbitfield,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedBufferSubData(GLuint buffer, GLintptr offset, GLsizeiptr size, const void *data)`
+pub type glNamedBufferSubData_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedFramebufferDrawBuffer(GLuint framebuffer, GLenum buf)`
+pub type glNamedFramebufferDrawBuffer_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    buf: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedFramebufferDrawBuffers(GLuint framebuffer, GLsizei n, const GLenum *bufs)`
+pub type glNamedFramebufferDrawBuffers_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    n: GLsizei,
+    bufs: *const GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedFramebufferParameteri(GLuint framebuffer, GLenum pname, GLint param)`
+pub type glNamedFramebufferParameteri_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    pname: GLenum,
+    param: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedFramebufferReadBuffer(GLuint framebuffer, GLenum src)`
+pub type glNamedFramebufferReadBuffer_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    src: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedFramebufferRenderbuffer(GLuint framebuffer, GLenum attachment, GLenum renderbuffertarget, GLuint renderbuffer)`
+pub type glNamedFramebufferRenderbuffer_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    attachment: GLenum,
+    renderbuffertarget: GLenum,
+    renderbuffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedFramebufferTexture(GLuint framebuffer, GLenum attachment, GLuint texture, GLint level)`
+pub type glNamedFramebufferTexture_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    attachment: GLenum,
+    texture: GLuint,
+    level: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedFramebufferTextureLayer(GLuint framebuffer, GLenum attachment, GLuint texture, GLint level, GLint layer)`
+pub type glNamedFramebufferTextureLayer_t = Option<
+  unsafe extern "system" fn(
+    framebuffer: GLuint,
+    attachment: GLenum,
+    texture: GLuint,
+    level: GLint,
+    layer: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedRenderbufferStorage(GLuint renderbuffer, GLenum internalformat, GLsizei width, GLsizei height)`
+pub type glNamedRenderbufferStorage_t = Option<
+  unsafe extern "system" fn(
+    renderbuffer: GLuint,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glNamedRenderbufferStorageMultisample(GLuint renderbuffer, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height)`
+pub type glNamedRenderbufferStorageMultisample_t = Option<
+  unsafe extern "system" fn(
+    renderbuffer: GLuint,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glObjectLabel(GLenum identifier, GLuint name, GLsizei length, const GLchar *label)`
+pub type glObjectLabel_t = Option<
+  unsafe extern "system" fn(
+    identifier: GLenum,
+    name: GLuint,
+    length: GLsizei,
+    label: *const GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glObjectPtrLabel(const void *ptr, GLsizei length, const GLchar *label)`
+pub type glObjectPtrLabel_t = Option<
+  unsafe extern "system" fn(
+    ptr: *const c_void,
+    length: GLsizei,
+    label: *const GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glPatchParameterfv(GLenum pname, const GLfloat *values)`
+pub type glPatchParameterfv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    values: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glPatchParameteri(GLenum pname, GLint value)`
+pub type glPatchParameteri_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    value: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glPauseTransformFeedback()`
+pub type glPauseTransformFeedback_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glPixelStoref(GLenum pname, GLfloat param)`
+pub type glPixelStoref_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    param: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glPixelStorei(GLenum pname, GLint param)`
+pub type glPixelStorei_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    param: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glPointParameterf(GLenum pname, GLfloat param)`
+pub type glPointParameterf_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    param: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glPointParameterfv(GLenum pname, const GLfloat *params)`
+pub type glPointParameterfv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    params: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glPointParameteri(GLenum pname, GLint param)`
+pub type glPointParameteri_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    param: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glPointParameteriv(GLenum pname, const GLint *params)`
+pub type glPointParameteriv_t = Option<
+  unsafe extern "system" fn(
+    pname: GLenum,
+    params: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glPointSize(GLfloat size)`
+pub type glPointSize_t = Option<unsafe extern "system" fn(size: GLfloat)>;
+
+/// From `gl.xml`: `void glPolygonMode(GLenum face, GLenum mode)`
+pub type glPolygonMode_t = Option<
+  unsafe extern "system" fn(
+    face: GLenum,
+    mode: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glPolygonOffset(GLfloat factor, GLfloat units)`
+pub type glPolygonOffset_t = Option<
+  unsafe extern "system" fn(
+    factor: GLfloat,
+    units: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glPolygonOffsetClamp(GLfloat factor, GLfloat units, GLfloat clamp)`
+pub type glPolygonOffsetClamp_t = Option<
+  unsafe extern "system" fn(
+    factor: GLfloat,
+    units: GLfloat,
+    clamp: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glPopDebugGroup()`
+pub type glPopDebugGroup_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glPrimitiveRestartIndex(GLuint index)`
+pub type glPrimitiveRestartIndex_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramBinary(GLuint program, GLenum binaryFormat, const void *binary, GLsizei length)`
+pub type glProgramBinary_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    binaryFormat: GLenum,
+    binary: *const c_void,
+    length: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramParameteri(GLuint program, GLenum pname, GLint value)`
+pub type glProgramParameteri_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    pname: GLenum,
+    value: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1d(GLuint program, GLint location, GLdouble v0)`
+pub type glProgramUniform1d_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1dv(GLuint program, GLint location, GLsizei count, const GLdouble *value)`
+pub type glProgramUniform1dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1f(GLuint program, GLint location, GLfloat v0)`
+pub type glProgramUniform1f_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1fv(GLuint program, GLint location, GLsizei count, const GLfloat *value)`
+pub type glProgramUniform1fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1i(GLuint program, GLint location, GLint v0)`
+pub type glProgramUniform1i_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1iv(GLuint program, GLint location, GLsizei count, const GLint *value)`
+pub type glProgramUniform1iv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1ui(GLuint program, GLint location, GLuint v0)`
+pub type glProgramUniform1ui_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform1uiv(GLuint program, GLint location, GLsizei count, const GLuint *value)`
+pub type glProgramUniform1uiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2d(GLuint program, GLint location, GLdouble v0, GLdouble v1)`
+pub type glProgramUniform2d_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLdouble,
+    v1: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2dv(GLuint program, GLint location, GLsizei count, const GLdouble *value)`
+pub type glProgramUniform2dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2f(GLuint program, GLint location, GLfloat v0, GLfloat v1)`
+pub type glProgramUniform2f_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2fv(GLuint program, GLint location, GLsizei count, const GLfloat *value)`
+pub type glProgramUniform2fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2i(GLuint program, GLint location, GLint v0, GLint v1)`
+pub type glProgramUniform2i_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2iv(GLuint program, GLint location, GLsizei count, const GLint *value)`
+pub type glProgramUniform2iv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2ui(GLuint program, GLint location, GLuint v0, GLuint v1)`
+pub type glProgramUniform2ui_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform2uiv(GLuint program, GLint location, GLsizei count, const GLuint *value)`
+pub type glProgramUniform2uiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3d(GLuint program, GLint location, GLdouble v0, GLdouble v1, GLdouble v2)`
+pub type glProgramUniform3d_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLdouble,
+    v1: GLdouble,
+    v2: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3dv(GLuint program, GLint location, GLsizei count, const GLdouble *value)`
+pub type glProgramUniform3dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3f(GLuint program, GLint location, GLfloat v0, GLfloat v1, GLfloat v2)`
+pub type glProgramUniform3f_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+    v2: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3fv(GLuint program, GLint location, GLsizei count, const GLfloat *value)`
+pub type glProgramUniform3fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3i(GLuint program, GLint location, GLint v0, GLint v1, GLint v2)`
+pub type glProgramUniform3i_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+    v2: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3iv(GLuint program, GLint location, GLsizei count, const GLint *value)`
+pub type glProgramUniform3iv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3ui(GLuint program, GLint location, GLuint v0, GLuint v1, GLuint v2)`
+pub type glProgramUniform3ui_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+    v2: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform3uiv(GLuint program, GLint location, GLsizei count, const GLuint *value)`
+pub type glProgramUniform3uiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4d(GLuint program, GLint location, GLdouble v0, GLdouble v1, GLdouble v2, GLdouble v3)`
+pub type glProgramUniform4d_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLdouble,
+    v1: GLdouble,
+    v2: GLdouble,
+    v3: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4dv(GLuint program, GLint location, GLsizei count, const GLdouble *value)`
+pub type glProgramUniform4dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4f(GLuint program, GLint location, GLfloat v0, GLfloat v1, GLfloat v2, GLfloat v3)`
+pub type glProgramUniform4f_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+    v2: GLfloat,
+    v3: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4fv(GLuint program, GLint location, GLsizei count, const GLfloat *value)`
+pub type glProgramUniform4fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4i(GLuint program, GLint location, GLint v0, GLint v1, GLint v2, GLint v3)`
+pub type glProgramUniform4i_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+    v2: GLint,
+    v3: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4iv(GLuint program, GLint location, GLsizei count, const GLint *value)`
+pub type glProgramUniform4iv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4ui(GLuint program, GLint location, GLuint v0, GLuint v1, GLuint v2, GLuint v3)`
+pub type glProgramUniform4ui_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+    v2: GLuint,
+    v3: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniform4uiv(GLuint program, GLint location, GLsizei count, const GLuint *value)`
+pub type glProgramUniform4uiv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix2dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix2dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix2fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix2fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix2x3dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix2x3dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix2x3fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix2x3fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix2x4dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix2x4dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix2x4fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix2x4fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix3dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix3dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix3fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix3fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix3x2dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix3x2dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix3x2fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix3x2fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix3x4dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix3x4dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix3x4fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix3x4fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix4dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix4dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix4fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix4fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix4x2dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix4x2dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix4x2fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix4x2fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix4x3dv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glProgramUniformMatrix4x3dv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glProgramUniformMatrix4x3fv(GLuint program, GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glProgramUniformMatrix4x3fv_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glProvokingVertex(GLenum mode)`
+pub type glProvokingVertex_t = Option<unsafe extern "system" fn(mode: GLenum)>;
+
+/// From `gl.xml`: `void glPushDebugGroup(GLenum source, GLuint id, GLsizei length, const GLchar *message)`
+pub type glPushDebugGroup_t = Option<
+  unsafe extern "system" fn(
+    source: GLenum,
+    id: GLuint,
+    length: GLsizei,
+    message: *const GLchar,
+  ),
+>;
+
+/// From `gl.xml`: `void glQueryCounter(GLuint id, GLenum target)`
+pub type glQueryCounter_t = Option<
+  unsafe extern "system" fn(
+    id: GLuint,
+    target: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glReadBuffer(GLenum src)`
+pub type glReadBuffer_t = Option<unsafe extern "system" fn(src: GLenum)>;
+
+/// From `gl.xml`: `void glReadPixels(GLint x, GLint y, GLsizei width, GLsizei height, GLenum format, GLenum type, void *pixels)`
+pub type glReadPixels_t = Option<
+  unsafe extern "system" fn(
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glReadnPixels(GLint x, GLint y, GLsizei width, GLsizei height, GLenum format, GLenum type, GLsizei bufSize, void *data)`
+pub type glReadnPixels_t = Option<
+  unsafe extern "system" fn(
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    bufSize: GLsizei,
+    data: *mut c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glReleaseShaderCompiler()`
+pub type glReleaseShaderCompiler_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glRenderbufferStorage(GLenum target, GLenum internalformat, GLsizei width, GLsizei height)`
+pub type glRenderbufferStorage_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glRenderbufferStorageMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height)`
+pub type glRenderbufferStorageMultisample_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glResumeTransformFeedback()`
+pub type glResumeTransformFeedback_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glSampleCoverage(GLfloat value, GLboolean invert)`
+pub type glSampleCoverage_t = Option<
+  unsafe extern "system" fn(
+    value: GLfloat,
+    invert: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glSampleMaski(GLuint maskNumber, GLbitfield mask)`
+pub type glSampleMaski_t = Option<
+  unsafe extern "system" fn(
+    maskNumber: GLuint,
+    mask: GLbitfield,
+  ),
+>;
+
+/// From `gl.xml`: `void glSamplerParameterIiv(GLuint sampler, GLenum pname, const GLint *param)`
+pub type glSamplerParameterIiv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glSamplerParameterIuiv(GLuint sampler, GLenum pname, const GLuint *param)`
+pub type glSamplerParameterIuiv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glSamplerParameterf(GLuint sampler, GLenum pname, GLfloat param)`
+pub type glSamplerParameterf_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    param: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glSamplerParameterfv(GLuint sampler, GLenum pname, const GLfloat *param)`
+pub type glSamplerParameterfv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glSamplerParameteri(GLuint sampler, GLenum pname, GLint param)`
+pub type glSamplerParameteri_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    param: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glSamplerParameteriv(GLuint sampler, GLenum pname, const GLint *param)`
+pub type glSamplerParameteriv_t = Option<
+  unsafe extern "system" fn(
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glScissor(GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glScissor_t = Option<
+  unsafe extern "system" fn(
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glScissorArrayv(GLuint first, GLsizei count, const GLint *v)`
+pub type glScissorArrayv_t = Option<
+  unsafe extern "system" fn(
+    first: GLuint,
+    count: GLsizei,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glScissorIndexed(GLuint index, GLint left, GLint bottom, GLsizei width, GLsizei height)`
+pub type glScissorIndexed_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    left: GLint,
+    bottom: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glScissorIndexedv(GLuint index, const GLint *v)`
+pub type glScissorIndexedv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glShaderBinary(GLsizei count, const GLuint *shaders, GLenum binaryformat, const void *binary, GLsizei length)`
+pub type glShaderBinary_t = Option<
+  unsafe extern "system" fn(
+    count: GLsizei,
+    shaders: *const GLuint,
+    binaryformat: GLenum,
+    binary: *const c_void,
+    length: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glShaderSource(GLuint shader, GLsizei count, const GLchar *const*string, const GLint *length)`
+pub type glShaderSource_t = Option<
+  unsafe extern "system" fn(
+    shader: GLuint,
+    count: GLsizei,
+    string: *const *const GLchar,
+    length: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glShaderStorageBlockBinding(GLuint program, GLuint storageBlockIndex, GLuint storageBlockBinding)`
+pub type glShaderStorageBlockBinding_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    storageBlockIndex: GLuint,
+    storageBlockBinding: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glSpecializeShader(GLuint shader, const GLchar *pEntryPoint, GLuint numSpecializationConstants, const GLuint *pConstantIndex, const GLuint *pConstantValue)`
+pub type glSpecializeShader_t = Option<
+  unsafe extern "system" fn(
+    shader: GLuint,
+    pEntryPoint: *const GLchar,
+    numSpecializationConstants: GLuint,
+    pConstantIndex: *const GLuint,
+    pConstantValue: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glStencilFunc(GLenum func, GLint ref, GLuint mask)`
+pub type glStencilFunc_t = Option<
+  unsafe extern "system" fn(
+    func: GLenum,
+    ref_: GLint,
+    mask: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glStencilFuncSeparate(GLenum face, GLenum func, GLint ref, GLuint mask)`
+pub type glStencilFuncSeparate_t = Option<
+  unsafe extern "system" fn(
+    face: GLenum,
+    func: GLenum,
+    ref_: GLint,
+    mask: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glStencilMask(GLuint mask)`
+pub type glStencilMask_t = Option<unsafe extern "system" fn(mask: GLuint)>;
+
+/// From `gl.xml`: `void glStencilMaskSeparate(GLenum face, GLuint mask)`
+pub type glStencilMaskSeparate_t = Option<
+  unsafe extern "system" fn(
+    face: GLenum,
+    mask: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glStencilOp(GLenum fail, GLenum zfail, GLenum zpass)`
+pub type glStencilOp_t = Option<
+  unsafe extern "system" fn(
+    fail: GLenum,
+    zfail: GLenum,
+    zpass: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glStencilOpSeparate(GLenum face, GLenum sfail, GLenum dpfail, GLenum dppass)`
+pub type glStencilOpSeparate_t = Option<
+  unsafe extern "system" fn(
+    face: GLenum,
+    sfail: GLenum,
+    dpfail: GLenum,
+    dppass: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexBuffer(GLenum target, GLenum internalformat, GLuint buffer)`
+pub type glTexBuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    internalformat: GLenum,
+    buffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexBufferRange(GLenum target, GLenum internalformat, GLuint buffer, GLintptr offset, GLsizeiptr size)`
+pub type glTexBufferRange_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    internalformat: GLenum,
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexImage1D(GLenum target, GLint level, GLint internalformat, GLsizei width, GLint border, GLenum format, GLenum type, const void *pixels)`
+pub type glTexImage1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    border: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexImage2D(GLenum target, GLint level, GLint internalformat, GLsizei width, GLsizei height, GLint border, GLenum format, GLenum type, const void *pixels)`
+pub type glTexImage2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexImage2DMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLboolean fixedsamplelocations)`
+pub type glTexImage2DMultisample_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexImage3D(GLenum target, GLint level, GLint internalformat, GLsizei width, GLsizei height, GLsizei depth, GLint border, GLenum format, GLenum type, const void *pixels)`
+pub type glTexImage3D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    border: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexImage3DMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth, GLboolean fixedsamplelocations)`
+pub type glTexImage3DMultisample_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexParameterIiv(GLenum target, GLenum pname, const GLint *params)`
+pub type glTexParameterIiv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexParameterIuiv(GLenum target, GLenum pname, const GLuint *params)`
+pub type glTexParameterIuiv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexParameterf(GLenum target, GLenum pname, GLfloat param)`
+pub type glTexParameterf_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    param: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexParameterfv(GLenum target, GLenum pname, const GLfloat *params)`
+pub type glTexParameterfv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexParameteri(GLenum target, GLenum pname, GLint param)`
+pub type glTexParameteri_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    param: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexParameteriv(GLenum target, GLenum pname, const GLint *params)`
+pub type glTexParameteriv_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexStorage1D(GLenum target, GLsizei levels, GLenum internalformat, GLsizei width)`
+pub type glTexStorage1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexStorage2D(GLenum target, GLsizei levels, GLenum internalformat, GLsizei width, GLsizei height)`
+pub type glTexStorage2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexStorage2DMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLboolean fixedsamplelocations)`
+pub type glTexStorage2DMultisample_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexStorage3D(GLenum target, GLsizei levels, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth)`
+pub type glTexStorage3D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexStorage3DMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth, GLboolean fixedsamplelocations)`
+pub type glTexStorage3DMultisample_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexSubImage1D(GLenum target, GLint level, GLint xoffset, GLsizei width, GLenum format, GLenum type, const void *pixels)`
+pub type glTexSubImage1D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    width: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexSubImage2D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLsizei width, GLsizei height, GLenum format, GLenum type, const void *pixels)`
+pub type glTexSubImage2D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTexSubImage3D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLenum type, const void *pixels)`
+pub type glTexSubImage3D_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureBarrier()`
+pub type glTextureBarrier_t = Option<unsafe extern "system" fn()>;
+
+/// From `gl.xml`: `void glTextureBuffer(GLuint texture, GLenum internalformat, GLuint buffer)`
+pub type glTextureBuffer_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    internalformat: GLenum,
+    buffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureBufferRange(GLuint texture, GLenum internalformat, GLuint buffer, GLintptr offset, GLsizeiptr size)`
+pub type glTextureBufferRange_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    internalformat: GLenum,
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureParameterIiv(GLuint texture, GLenum pname, const GLint *params)`
+pub type glTextureParameterIiv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    params: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureParameterIuiv(GLuint texture, GLenum pname, const GLuint *params)`
+pub type glTextureParameterIuiv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    params: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureParameterf(GLuint texture, GLenum pname, GLfloat param)`
+pub type glTextureParameterf_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    param: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureParameterfv(GLuint texture, GLenum pname, const GLfloat *param)`
+pub type glTextureParameterfv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    param: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureParameteri(GLuint texture, GLenum pname, GLint param)`
+pub type glTextureParameteri_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    param: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureParameteriv(GLuint texture, GLenum pname, const GLint *param)`
+pub type glTextureParameteriv_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    pname: GLenum,
+    param: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureStorage1D(GLuint texture, GLsizei levels, GLenum internalformat, GLsizei width)`
+pub type glTextureStorage1D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureStorage2D(GLuint texture, GLsizei levels, GLenum internalformat, GLsizei width, GLsizei height)`
+pub type glTextureStorage2D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureStorage2DMultisample(GLuint texture, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLboolean fixedsamplelocations)`
+pub type glTextureStorage2DMultisample_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureStorage3D(GLuint texture, GLsizei levels, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth)`
+pub type glTextureStorage3D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureStorage3DMultisample(GLuint texture, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth, GLboolean fixedsamplelocations)`
+pub type glTextureStorage3DMultisample_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureSubImage1D(GLuint texture, GLint level, GLint xoffset, GLsizei width, GLenum format, GLenum type, const void *pixels)`
+pub type glTextureSubImage1D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    width: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureSubImage2D(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLsizei width, GLsizei height, GLenum format, GLenum type, const void *pixels)`
+pub type glTextureSubImage2D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureSubImage3D(GLuint texture, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLenum type, const void *pixels)`
+pub type glTextureSubImage3D_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glTextureView(GLuint texture, GLenum target, GLuint origtexture, GLenum internalformat, GLuint minlevel, GLuint numlevels, GLuint minlayer, GLuint numlayers)`
+pub type glTextureView_t = Option<
+  unsafe extern "system" fn(
+    texture: GLuint,
+    target: GLenum,
+    origtexture: GLuint,
+    internalformat: GLenum,
+    minlevel: GLuint,
+    numlevels: GLuint,
+    minlayer: GLuint,
+    numlayers: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTransformFeedbackBufferBase(GLuint xfb, GLuint index, GLuint buffer)`
+pub type glTransformFeedbackBufferBase_t = Option<
+  unsafe extern "system" fn(
+    xfb: GLuint,
+    index: GLuint,
+    buffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glTransformFeedbackBufferRange(GLuint xfb, GLuint index, GLuint buffer, GLintptr offset, GLsizeiptr size)`
+pub type glTransformFeedbackBufferRange_t = Option<
+  unsafe extern "system" fn(
+    xfb: GLuint,
+    index: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+  ),
+>;
+
+/// From `gl.xml`: `void glTransformFeedbackVaryings(GLuint program, GLsizei count, const GLchar *const*varyings, GLenum bufferMode)`
+pub type glTransformFeedbackVaryings_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    count: GLsizei,
+    varyings: *const *const GLchar,
+    bufferMode: GLenum,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1d(GLint location, GLdouble x)`
+pub type glUniform1d_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    x: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1dv(GLint location, GLsizei count, const GLdouble *value)`
+pub type glUniform1dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1f(GLint location, GLfloat v0)`
+pub type glUniform1f_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1fv(GLint location, GLsizei count, const GLfloat *value)`
+pub type glUniform1fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1i(GLint location, GLint v0)`
+pub type glUniform1i_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1iv(GLint location, GLsizei count, const GLint *value)`
+pub type glUniform1iv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1ui(GLint location, GLuint v0)`
+pub type glUniform1ui_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform1uiv(GLint location, GLsizei count, const GLuint *value)`
+pub type glUniform1uiv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2d(GLint location, GLdouble x, GLdouble y)`
+pub type glUniform2d_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    x: GLdouble,
+    y: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2dv(GLint location, GLsizei count, const GLdouble *value)`
+pub type glUniform2dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2f(GLint location, GLfloat v0, GLfloat v1)`
+pub type glUniform2f_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2fv(GLint location, GLsizei count, const GLfloat *value)`
+pub type glUniform2fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2i(GLint location, GLint v0, GLint v1)`
+pub type glUniform2i_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2iv(GLint location, GLsizei count, const GLint *value)`
+pub type glUniform2iv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2ui(GLint location, GLuint v0, GLuint v1)`
+pub type glUniform2ui_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform2uiv(GLint location, GLsizei count, const GLuint *value)`
+pub type glUniform2uiv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3d(GLint location, GLdouble x, GLdouble y, GLdouble z)`
+pub type glUniform3d_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3dv(GLint location, GLsizei count, const GLdouble *value)`
+pub type glUniform3dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3f(GLint location, GLfloat v0, GLfloat v1, GLfloat v2)`
+pub type glUniform3f_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+    v2: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3fv(GLint location, GLsizei count, const GLfloat *value)`
+pub type glUniform3fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3i(GLint location, GLint v0, GLint v1, GLint v2)`
+pub type glUniform3i_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+    v2: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3iv(GLint location, GLsizei count, const GLint *value)`
+pub type glUniform3iv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3ui(GLint location, GLuint v0, GLuint v1, GLuint v2)`
+pub type glUniform3ui_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+    v2: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform3uiv(GLint location, GLsizei count, const GLuint *value)`
+pub type glUniform3uiv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4d(GLint location, GLdouble x, GLdouble y, GLdouble z, GLdouble w)`
+pub type glUniform4d_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+    w: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4dv(GLint location, GLsizei count, const GLdouble *value)`
+pub type glUniform4dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4f(GLint location, GLfloat v0, GLfloat v1, GLfloat v2, GLfloat v3)`
+pub type glUniform4f_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+    v2: GLfloat,
+    v3: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4fv(GLint location, GLsizei count, const GLfloat *value)`
+pub type glUniform4fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4i(GLint location, GLint v0, GLint v1, GLint v2, GLint v3)`
+pub type glUniform4i_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+    v2: GLint,
+    v3: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4iv(GLint location, GLsizei count, const GLint *value)`
+pub type glUniform4iv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4ui(GLint location, GLuint v0, GLuint v1, GLuint v2, GLuint v3)`
+pub type glUniform4ui_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+    v2: GLuint,
+    v3: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniform4uiv(GLint location, GLsizei count, const GLuint *value)`
+pub type glUniform4uiv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformBlockBinding(GLuint program, GLuint uniformBlockIndex, GLuint uniformBlockBinding)`
+pub type glUniformBlockBinding_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+    uniformBlockIndex: GLuint,
+    uniformBlockBinding: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix2dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix2dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix2fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix2fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix2x3dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix2x3dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix2x3fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix2x3fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix2x4dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix2x4dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix2x4fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix2x4fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix3dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix3dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix3fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix3fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix3x2dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix3x2dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix3x2fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix3x2fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix3x4dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix3x4dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix3x4fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix3x4fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix4dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix4dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix4fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix4fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix4x2dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix4x2dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix4x2fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix4x2fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix4x3dv(GLint location, GLsizei count, GLboolean transpose, const GLdouble *value)`
+pub type glUniformMatrix4x3dv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformMatrix4x3fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+pub type glUniformMatrix4x3fv_t = Option<
+  unsafe extern "system" fn(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glUniformSubroutinesuiv(GLenum shadertype, GLsizei count, const GLuint *indices)`
+pub type glUniformSubroutinesuiv_t = Option<
+  unsafe extern "system" fn(
+    shadertype: GLenum,
+    count: GLsizei,
+    indices: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `GLboolean glUnmapBuffer(GLenum target)`
+pub type glUnmapBuffer_t = Option<
+  unsafe extern "system" fn(
+    target: GLenum,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `GLboolean glUnmapNamedBuffer(GLuint buffer)`
+pub type glUnmapNamedBuffer_t = Option<
+  unsafe extern "system" fn(
+    buffer: GLuint,
+  ) -> GLboolean,
+>;
+
+/// From `gl.xml`: `void glUseProgram(GLuint program)`
+pub type glUseProgram_t = Option<unsafe extern "system" fn(program: GLuint)>;
+
+/// From `gl.xml`: `void glUseProgramStages(GLuint pipeline, GLbitfield stages, GLuint program)`
+pub type glUseProgramStages_t = Option<
+  unsafe extern "system" fn(
+    pipeline: GLuint,
+    stages: GLbitfield,
+    program: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glValidateProgram(GLuint program)`
+pub type glValidateProgram_t = Option<
+  unsafe extern "system" fn(
+    program: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glValidateProgramPipeline(GLuint pipeline)`
+pub type glValidateProgramPipeline_t = Option<
+  unsafe extern "system" fn(
+    pipeline: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayAttribBinding(GLuint vaobj, GLuint attribindex, GLuint bindingindex)`
+pub type glVertexArrayAttribBinding_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    bindingindex: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayAttribFormat(GLuint vaobj, GLuint attribindex, GLint size, GLenum type, GLboolean normalized, GLuint relativeoffset)`
+pub type glVertexArrayAttribFormat_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    size: GLint,
+    type_: GLenum,
+    normalized: GLboolean,
+    relativeoffset: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayAttribIFormat(GLuint vaobj, GLuint attribindex, GLint size, GLenum type, GLuint relativeoffset)`
+pub type glVertexArrayAttribIFormat_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    size: GLint,
+    type_: GLenum,
+    relativeoffset: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayAttribLFormat(GLuint vaobj, GLuint attribindex, GLint size, GLenum type, GLuint relativeoffset)`
+pub type glVertexArrayAttribLFormat_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    attribindex: GLuint,
+    size: GLint,
+    type_: GLenum,
+    relativeoffset: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayBindingDivisor(GLuint vaobj, GLuint bindingindex, GLuint divisor)`
+pub type glVertexArrayBindingDivisor_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    bindingindex: GLuint,
+    divisor: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayElementBuffer(GLuint vaobj, GLuint buffer)`
+pub type glVertexArrayElementBuffer_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    buffer: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayVertexBuffer(GLuint vaobj, GLuint bindingindex, GLuint buffer, GLintptr offset, GLsizei stride)`
+pub type glVertexArrayVertexBuffer_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    bindingindex: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    stride: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexArrayVertexBuffers(GLuint vaobj, GLuint first, GLsizei count, const GLuint *buffers, const GLintptr *offsets, const GLsizei *strides)`
+pub type glVertexArrayVertexBuffers_t = Option<
+  unsafe extern "system" fn(
+    vaobj: GLuint,
+    first: GLuint,
+    count: GLsizei,
+    buffers: *const GLuint,
+    offsets: *const GLintptr,
+    strides: *const GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib1d(GLuint index, GLdouble x)`
+pub type glVertexAttrib1d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib1dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttrib1dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib1f(GLuint index, GLfloat x)`
+pub type glVertexAttrib1f_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib1fv(GLuint index, const GLfloat *v)`
+pub type glVertexAttrib1fv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib1s(GLuint index, GLshort x)`
+pub type glVertexAttrib1s_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib1sv(GLuint index, const GLshort *v)`
+pub type glVertexAttrib1sv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib2d(GLuint index, GLdouble x, GLdouble y)`
+pub type glVertexAttrib2d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib2dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttrib2dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib2f(GLuint index, GLfloat x, GLfloat y)`
+pub type glVertexAttrib2f_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLfloat,
+    y: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib2fv(GLuint index, const GLfloat *v)`
+pub type glVertexAttrib2fv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib2s(GLuint index, GLshort x, GLshort y)`
+pub type glVertexAttrib2s_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLshort,
+    y: GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib2sv(GLuint index, const GLshort *v)`
+pub type glVertexAttrib2sv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib3d(GLuint index, GLdouble x, GLdouble y, GLdouble z)`
+pub type glVertexAttrib3d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib3dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttrib3dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib3f(GLuint index, GLfloat x, GLfloat y, GLfloat z)`
+pub type glVertexAttrib3f_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLfloat,
+    y: GLfloat,
+    z: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib3fv(GLuint index, const GLfloat *v)`
+pub type glVertexAttrib3fv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib3s(GLuint index, GLshort x, GLshort y, GLshort z)`
+pub type glVertexAttrib3s_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLshort,
+    y: GLshort,
+    z: GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib3sv(GLuint index, const GLshort *v)`
+pub type glVertexAttrib3sv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4Nbv(GLuint index, const GLbyte *v)`
+pub type glVertexAttrib4Nbv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLbyte,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4Niv(GLuint index, const GLint *v)`
+pub type glVertexAttrib4Niv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4Nsv(GLuint index, const GLshort *v)`
+pub type glVertexAttrib4Nsv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4Nub(GLuint index, GLubyte x, GLubyte y, GLubyte z, GLubyte w)`
+pub type glVertexAttrib4Nub_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLubyte,
+    y: GLubyte,
+    z: GLubyte,
+    w: GLubyte,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4Nubv(GLuint index, const GLubyte *v)`
+pub type glVertexAttrib4Nubv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLubyte,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4Nuiv(GLuint index, const GLuint *v)`
+pub type glVertexAttrib4Nuiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4Nusv(GLuint index, const GLushort *v)`
+pub type glVertexAttrib4Nusv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLushort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4bv(GLuint index, const GLbyte *v)`
+pub type glVertexAttrib4bv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLbyte,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4d(GLuint index, GLdouble x, GLdouble y, GLdouble z, GLdouble w)`
+pub type glVertexAttrib4d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+    w: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttrib4dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4f(GLuint index, GLfloat x, GLfloat y, GLfloat z, GLfloat w)`
+pub type glVertexAttrib4f_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLfloat,
+    y: GLfloat,
+    z: GLfloat,
+    w: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4fv(GLuint index, const GLfloat *v)`
+pub type glVertexAttrib4fv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4iv(GLuint index, const GLint *v)`
+pub type glVertexAttrib4iv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4s(GLuint index, GLshort x, GLshort y, GLshort z, GLshort w)`
+pub type glVertexAttrib4s_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLshort,
+    y: GLshort,
+    z: GLshort,
+    w: GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4sv(GLuint index, const GLshort *v)`
+pub type glVertexAttrib4sv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4ubv(GLuint index, const GLubyte *v)`
+pub type glVertexAttrib4ubv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLubyte,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4uiv(GLuint index, const GLuint *v)`
+pub type glVertexAttrib4uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttrib4usv(GLuint index, const GLushort *v)`
+pub type glVertexAttrib4usv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLushort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribBinding(GLuint attribindex, GLuint bindingindex)`
+pub type glVertexAttribBinding_t = Option<
+  unsafe extern "system" fn(
+    attribindex: GLuint,
+    bindingindex: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribDivisor(GLuint index, GLuint divisor)`
+pub type glVertexAttribDivisor_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    divisor: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribFormat(GLuint attribindex, GLint size, GLenum type, GLboolean normalized, GLuint relativeoffset)`
+pub type glVertexAttribFormat_t = Option<
+  unsafe extern "system" fn(
+    attribindex: GLuint,
+    size: GLint,
+    type_: GLenum,
+    normalized: GLboolean,
+    relativeoffset: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI1i(GLuint index, GLint x)`
+pub type glVertexAttribI1i_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI1iv(GLuint index, const GLint *v)`
+pub type glVertexAttribI1iv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI1ui(GLuint index, GLuint x)`
+pub type glVertexAttribI1ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI1uiv(GLuint index, const GLuint *v)`
+pub type glVertexAttribI1uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI2i(GLuint index, GLint x, GLint y)`
+pub type glVertexAttribI2i_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLint,
+    y: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI2iv(GLuint index, const GLint *v)`
+pub type glVertexAttribI2iv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI2ui(GLuint index, GLuint x, GLuint y)`
+pub type glVertexAttribI2ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLuint,
+    y: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI2uiv(GLuint index, const GLuint *v)`
+pub type glVertexAttribI2uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI3i(GLuint index, GLint x, GLint y, GLint z)`
+pub type glVertexAttribI3i_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLint,
+    y: GLint,
+    z: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI3iv(GLuint index, const GLint *v)`
+pub type glVertexAttribI3iv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI3ui(GLuint index, GLuint x, GLuint y, GLuint z)`
+pub type glVertexAttribI3ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLuint,
+    y: GLuint,
+    z: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI3uiv(GLuint index, const GLuint *v)`
+pub type glVertexAttribI3uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4bv(GLuint index, const GLbyte *v)`
+pub type glVertexAttribI4bv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLbyte,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4i(GLuint index, GLint x, GLint y, GLint z, GLint w)`
+pub type glVertexAttribI4i_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLint,
+    y: GLint,
+    z: GLint,
+    w: GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4iv(GLuint index, const GLint *v)`
+pub type glVertexAttribI4iv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4sv(GLuint index, const GLshort *v)`
+pub type glVertexAttribI4sv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLshort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4ubv(GLuint index, const GLubyte *v)`
+pub type glVertexAttribI4ubv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLubyte,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4ui(GLuint index, GLuint x, GLuint y, GLuint z, GLuint w)`
+pub type glVertexAttribI4ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLuint,
+    y: GLuint,
+    z: GLuint,
+    w: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4uiv(GLuint index, const GLuint *v)`
+pub type glVertexAttribI4uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribI4usv(GLuint index, const GLushort *v)`
+pub type glVertexAttribI4usv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLushort,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribIFormat(GLuint attribindex, GLint size, GLenum type, GLuint relativeoffset)`
+pub type glVertexAttribIFormat_t = Option<
+  unsafe extern "system" fn(
+    attribindex: GLuint,
+    size: GLint,
+    type_: GLenum,
+    relativeoffset: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribIPointer(GLuint index, GLint size, GLenum type, GLsizei stride, const void *pointer)`
+pub type glVertexAttribIPointer_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    size: GLint,
+    type_: GLenum,
+    stride: GLsizei,
+    pointer: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL1d(GLuint index, GLdouble x)`
+pub type glVertexAttribL1d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL1dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttribL1dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL2d(GLuint index, GLdouble x, GLdouble y)`
+pub type glVertexAttribL2d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL2dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttribL2dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL3d(GLuint index, GLdouble x, GLdouble y, GLdouble z)`
+pub type glVertexAttribL3d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL3dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttribL3dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL4d(GLuint index, GLdouble x, GLdouble y, GLdouble z, GLdouble w)`
+pub type glVertexAttribL4d_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+    w: GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribL4dv(GLuint index, const GLdouble *v)`
+pub type glVertexAttribL4dv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLdouble,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribLFormat(GLuint attribindex, GLint size, GLenum type, GLuint relativeoffset)`
+pub type glVertexAttribLFormat_t = Option<
+  unsafe extern "system" fn(
+    attribindex: GLuint,
+    size: GLint,
+    type_: GLenum,
+    relativeoffset: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribLPointer(GLuint index, GLint size, GLenum type, GLsizei stride, const void *pointer)`
+pub type glVertexAttribLPointer_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    size: GLint,
+    type_: GLenum,
+    stride: GLsizei,
+    pointer: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP1ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+pub type glVertexAttribP1ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP1uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+pub type glVertexAttribP1uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP2ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+pub type glVertexAttribP2ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP2uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+pub type glVertexAttribP2uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP3ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+pub type glVertexAttribP3ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP3uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+pub type glVertexAttribP3uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP4ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+pub type glVertexAttribP4ui_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribP4uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+pub type glVertexAttribP4uiv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexAttribPointer(GLuint index, GLint size, GLenum type, GLboolean normalized, GLsizei stride, const void *pointer)`
+pub type glVertexAttribPointer_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    size: GLint,
+    type_: GLenum,
+    normalized: GLboolean,
+    stride: GLsizei,
+    pointer: *const c_void,
+  ),
+>;
+
+/// From `gl.xml`: `void glVertexBindingDivisor(GLuint bindingindex, GLuint divisor)`
+pub type glVertexBindingDivisor_t = Option<
+  unsafe extern "system" fn(
+    bindingindex: GLuint,
+    divisor: GLuint,
+  ),
+>;
+
+/// From `gl.xml`: `void glViewport(GLint x, GLint y, GLsizei width, GLsizei height)`
+pub type glViewport_t = Option<
+  unsafe extern "system" fn(
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ),
+>;
+
+/// From `gl.xml`: `void glViewportArrayv(GLuint first, GLsizei count, const GLfloat *v)`
+pub type glViewportArrayv_t = Option<
+  unsafe extern "system" fn(
+    first: GLuint,
+    count: GLsizei,
+    v: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glViewportIndexedf(GLuint index, GLfloat x, GLfloat y, GLfloat w, GLfloat h)`
+pub type glViewportIndexedf_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    x: GLfloat,
+    y: GLfloat,
+    w: GLfloat,
+    h: GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glViewportIndexedfv(GLuint index, const GLfloat *v)`
+pub type glViewportIndexedfv_t = Option<
+  unsafe extern "system" fn(
+    index: GLuint,
+    v: *const GLfloat,
+  ),
+>;
+
+/// From `gl.xml`: `void glWaitSync(GLsync sync, GLbitfield flags, GLuint64 timeout)`
+pub type glWaitSync_t = Option<
+  unsafe extern "system" fn(
+    sync: GLsync,
+    flags: GLbitfield,
+    timeout: GLuint64,
+  ),
+>;
+
+/// Every function pointer of the generated API.
+///
+/// Any function that's not available is `None`.
+#[derive(Clone, Copy, Default)]
+pub struct GlFns {
+  pub glActiveShaderProgram: glActiveShaderProgram_t,
+  pub glActiveTexture: glActiveTexture_t,
+  pub glAttachShader: glAttachShader_t,
+  pub glBeginConditionalRender: glBeginConditionalRender_t,
+  pub glBeginQuery: glBeginQuery_t,
+  pub glBeginQueryIndexed: glBeginQueryIndexed_t,
+  pub glBeginTransformFeedback: glBeginTransformFeedback_t,
+  pub glBindAttribLocation: glBindAttribLocation_t,
+  pub glBindBuffer: glBindBuffer_t,
+  pub glBindBufferBase: glBindBufferBase_t,
+  pub glBindBufferRange: glBindBufferRange_t,
+  pub glBindBuffersBase: glBindBuffersBase_t,
+  pub glBindBuffersRange: glBindBuffersRange_t,
+  pub glBindFragDataLocation: glBindFragDataLocation_t,
+  pub glBindFragDataLocationIndexed: glBindFragDataLocationIndexed_t,
+  pub glBindFramebuffer: glBindFramebuffer_t,
+  pub glBindImageTexture: glBindImageTexture_t,
+  pub glBindImageTextures: glBindImageTextures_t,
+  pub glBindProgramPipeline: glBindProgramPipeline_t,
+  pub glBindRenderbuffer: glBindRenderbuffer_t,
+  pub glBindSampler: glBindSampler_t,
+  pub glBindSamplers: glBindSamplers_t,
+  pub glBindTexture: glBindTexture_t,
+  pub glBindTextureUnit: glBindTextureUnit_t,
+  pub glBindTextures: glBindTextures_t,
+  pub glBindTransformFeedback: glBindTransformFeedback_t,
+  pub glBindVertexArray: glBindVertexArray_t,
+  pub glBindVertexBuffer: glBindVertexBuffer_t,
+  pub glBindVertexBuffers: glBindVertexBuffers_t,
+  pub glBlendColor: glBlendColor_t,
+  pub glBlendEquation: glBlendEquation_t,
+  pub glBlendEquationSeparate: glBlendEquationSeparate_t,
+  pub glBlendEquationSeparatei: glBlendEquationSeparatei_t,
+  pub glBlendEquationi: glBlendEquationi_t,
+  pub glBlendFunc: glBlendFunc_t,
+  pub glBlendFuncSeparate: glBlendFuncSeparate_t,
+  pub glBlendFuncSeparatei: glBlendFuncSeparatei_t,
+  pub glBlendFunci: glBlendFunci_t,
+  pub glBlitFramebuffer: glBlitFramebuffer_t,
+  pub glBlitNamedFramebuffer: glBlitNamedFramebuffer_t,
+  pub glBufferData: glBufferData_t,
+  pub glBufferStorage: glBufferStorage_t,
+  pub glBufferSubData: glBufferSubData_t,
+  pub glCheckFramebufferStatus: glCheckFramebufferStatus_t,
+  pub glCheckNamedFramebufferStatus: glCheckNamedFramebufferStatus_t,
+  pub glClampColor: glClampColor_t,
+  pub glClear: glClear_t,
+  pub glClearBufferData: glClearBufferData_t,
+  pub glClearBufferSubData: glClearBufferSubData_t,
+  pub glClearBufferfi: glClearBufferfi_t,
+  pub glClearBufferfv: glClearBufferfv_t,
+  pub glClearBufferiv: glClearBufferiv_t,
+  pub glClearBufferuiv: glClearBufferuiv_t,
+  pub glClearColor: glClearColor_t,
+  pub glClearDepth: glClearDepth_t,
+  pub glClearDepthf: glClearDepthf_t,
+  pub glClearNamedBufferData: glClearNamedBufferData_t,
+  pub glClearNamedBufferSubData: glClearNamedBufferSubData_t,
+  pub glClearNamedFramebufferfi: glClearNamedFramebufferfi_t,
+  pub glClearNamedFramebufferfv: glClearNamedFramebufferfv_t,
+  pub glClearNamedFramebufferiv: glClearNamedFramebufferiv_t,
+  pub glClearNamedFramebufferuiv: glClearNamedFramebufferuiv_t,
+  pub glClearStencil: glClearStencil_t,
+  pub glClearTexImage: glClearTexImage_t,
+  pub glClearTexSubImage: glClearTexSubImage_t,
+  pub glClientWaitSync: glClientWaitSync_t,
+  pub glClipControl: glClipControl_t,
+  pub glColorMask: glColorMask_t,
+  pub glColorMaski: glColorMaski_t,
+  pub glCompileShader: glCompileShader_t,
+  pub glCompressedTexImage1D: glCompressedTexImage1D_t,
+  pub glCompressedTexImage2D: glCompressedTexImage2D_t,
+  pub glCompressedTexImage3D: glCompressedTexImage3D_t,
+  pub glCompressedTexSubImage1D: glCompressedTexSubImage1D_t,
+  pub glCompressedTexSubImage2D: glCompressedTexSubImage2D_t,
+  pub glCompressedTexSubImage3D: glCompressedTexSubImage3D_t,
+  pub glCompressedTextureSubImage1D: glCompressedTextureSubImage1D_t,
+  pub glCompressedTextureSubImage2D: glCompressedTextureSubImage2D_t,
+  pub glCompressedTextureSubImage3D: glCompressedTextureSubImage3D_t,
+  pub glCopyBufferSubData: glCopyBufferSubData_t,
+  pub glCopyImageSubData: glCopyImageSubData_t,
+  pub glCopyNamedBufferSubData: glCopyNamedBufferSubData_t,
+  pub glCopyTexImage1D: glCopyTexImage1D_t,
+  pub glCopyTexImage2D: glCopyTexImage2D_t,
+  pub glCopyTexSubImage1D: glCopyTexSubImage1D_t,
+  pub glCopyTexSubImage2D: glCopyTexSubImage2D_t,
+  pub glCopyTexSubImage3D: glCopyTexSubImage3D_t,
+  pub glCopyTextureSubImage1D: glCopyTextureSubImage1D_t,
+  pub glCopyTextureSubImage2D: glCopyTextureSubImage2D_t,
+  pub glCopyTextureSubImage3D: glCopyTextureSubImage3D_t,
+  pub glCreateBuffers: glCreateBuffers_t,
+  pub glCreateFramebuffers: glCreateFramebuffers_t,
+  pub glCreateProgram: glCreateProgram_t,
+  pub glCreateProgramPipelines: glCreateProgramPipelines_t,
+  pub glCreateQueries: glCreateQueries_t,
+  pub glCreateRenderbuffers: glCreateRenderbuffers_t,
+  pub glCreateSamplers: glCreateSamplers_t,
+  pub glCreateShader: glCreateShader_t,
+  pub glCreateShaderProgramv: glCreateShaderProgramv_t,
+  pub glCreateTextures: glCreateTextures_t,
+  pub glCreateTransformFeedbacks: glCreateTransformFeedbacks_t,
+  pub glCreateVertexArrays: glCreateVertexArrays_t,
+  pub glCullFace: glCullFace_t,
+  pub glDebugMessageCallback: glDebugMessageCallback_t,
+  pub glDebugMessageControl: glDebugMessageControl_t,
+  pub glDebugMessageInsert: glDebugMessageInsert_t,
+  pub glDeleteBuffers: glDeleteBuffers_t,
+  pub glDeleteFramebuffers: glDeleteFramebuffers_t,
+  pub glDeleteProgram: glDeleteProgram_t,
+  pub glDeleteProgramPipelines: glDeleteProgramPipelines_t,
+  pub glDeleteQueries: glDeleteQueries_t,
+  pub glDeleteRenderbuffers: glDeleteRenderbuffers_t,
+  pub glDeleteSamplers: glDeleteSamplers_t,
+  pub glDeleteShader: glDeleteShader_t,
+  pub glDeleteSync: glDeleteSync_t,
+  pub glDeleteTextures: glDeleteTextures_t,
+  pub glDeleteTransformFeedbacks: glDeleteTransformFeedbacks_t,
+  pub glDeleteVertexArrays: glDeleteVertexArrays_t,
+  pub glDepthFunc: glDepthFunc_t,
+  pub glDepthMask: glDepthMask_t,
+  pub glDepthRange: glDepthRange_t,
+  pub glDepthRangeArrayv: glDepthRangeArrayv_t,
+  pub glDepthRangeIndexed: glDepthRangeIndexed_t,
+  pub glDepthRangef: glDepthRangef_t,
+  pub glDetachShader: glDetachShader_t,
+  pub glDisable: glDisable_t,
+  pub glDisableVertexArrayAttrib: glDisableVertexArrayAttrib_t,
+  pub glDisableVertexAttribArray: glDisableVertexAttribArray_t,
+  pub glDisablei: glDisablei_t,
+  pub glDispatchCompute: glDispatchCompute_t,
+  pub glDispatchComputeIndirect: glDispatchComputeIndirect_t,
+  pub glDrawArrays: glDrawArrays_t,
+  pub glDrawArraysIndirect: glDrawArraysIndirect_t,
+  pub glDrawArraysInstanced: glDrawArraysInstanced_t,
+  pub glDrawArraysInstancedBaseInstance: glDrawArraysInstancedBaseInstance_t,
+  pub glDrawBuffer: glDrawBuffer_t,
+  pub glDrawBuffers: glDrawBuffers_t,
+  pub glDrawElements: glDrawElements_t,
+  pub glDrawElementsBaseVertex: glDrawElementsBaseVertex_t,
+  pub glDrawElementsIndirect: glDrawElementsIndirect_t,
+  pub glDrawElementsInstanced: glDrawElementsInstanced_t,
+  pub glDrawElementsInstancedBaseInstance: glDrawElementsInstancedBaseInstance_t,
+  pub glDrawElementsInstancedBaseVertex: glDrawElementsInstancedBaseVertex_t,
+  pub glDrawElementsInstancedBaseVertexBaseInstance: glDrawElementsInstancedBaseVertexBaseInstance_t,
+  pub glDrawRangeElements: glDrawRangeElements_t,
+  pub glDrawRangeElementsBaseVertex: glDrawRangeElementsBaseVertex_t,
+  pub glDrawTransformFeedback: glDrawTransformFeedback_t,
+  pub glDrawTransformFeedbackInstanced: glDrawTransformFeedbackInstanced_t,
+  pub glDrawTransformFeedbackStream: glDrawTransformFeedbackStream_t,
+  pub glDrawTransformFeedbackStreamInstanced: glDrawTransformFeedbackStreamInstanced_t,
+  pub glEnable: glEnable_t,
+  pub glEnableVertexArrayAttrib: glEnableVertexArrayAttrib_t,
+  pub glEnableVertexAttribArray: glEnableVertexAttribArray_t,
+  pub glEnablei: glEnablei_t,
+  pub glEndConditionalRender: glEndConditionalRender_t,
+  pub glEndQuery: glEndQuery_t,
+  pub glEndQueryIndexed: glEndQueryIndexed_t,
+  pub glEndTransformFeedback: glEndTransformFeedback_t,
+  pub glFenceSync: glFenceSync_t,
+  pub glFinish: glFinish_t,
+  pub glFlush: glFlush_t,
+  pub glFlushMappedBufferRange: glFlushMappedBufferRange_t,
+  pub glFlushMappedNamedBufferRange: glFlushMappedNamedBufferRange_t,
+  pub glFramebufferParameteri: glFramebufferParameteri_t,
+  pub glFramebufferRenderbuffer: glFramebufferRenderbuffer_t,
+  pub glFramebufferTexture: glFramebufferTexture_t,
+  pub glFramebufferTexture1D: glFramebufferTexture1D_t,
+  pub glFramebufferTexture2D: glFramebufferTexture2D_t,
+  pub glFramebufferTexture3D: glFramebufferTexture3D_t,
+  pub glFramebufferTextureLayer: glFramebufferTextureLayer_t,
+  pub glFrontFace: glFrontFace_t,
+  pub glGenBuffers: glGenBuffers_t,
+  pub glGenFramebuffers: glGenFramebuffers_t,
+  pub glGenProgramPipelines: glGenProgramPipelines_t,
+  pub glGenQueries: glGenQueries_t,
+  pub glGenRenderbuffers: glGenRenderbuffers_t,
+  pub glGenSamplers: glGenSamplers_t,
+  pub glGenTextures: glGenTextures_t,
+  pub glGenTransformFeedbacks: glGenTransformFeedbacks_t,
+  pub glGenVertexArrays: glGenVertexArrays_t,
+  pub glGenerateMipmap: glGenerateMipmap_t,
+  pub glGenerateTextureMipmap: glGenerateTextureMipmap_t,
+  pub glGetActiveAtomicCounterBufferiv: glGetActiveAtomicCounterBufferiv_t,
+  pub glGetActiveAttrib: glGetActiveAttrib_t,
+  pub glGetActiveSubroutineName: glGetActiveSubroutineName_t,
+  pub glGetActiveSubroutineUniformName: glGetActiveSubroutineUniformName_t,
+  pub glGetActiveSubroutineUniformiv: glGetActiveSubroutineUniformiv_t,
+  pub glGetActiveUniform: glGetActiveUniform_t,
+  pub glGetActiveUniformBlockName: glGetActiveUniformBlockName_t,
+  pub glGetActiveUniformBlockiv: glGetActiveUniformBlockiv_t,
+  pub glGetActiveUniformName: glGetActiveUniformName_t,
+  pub glGetActiveUniformsiv: glGetActiveUniformsiv_t,
+  pub glGetAttachedShaders: glGetAttachedShaders_t,
+  pub glGetAttribLocation: glGetAttribLocation_t,
+  pub glGetBooleani_v: glGetBooleani_v_t,
+  pub glGetBooleanv: glGetBooleanv_t,
+  pub glGetBufferParameteri64v: glGetBufferParameteri64v_t,
+  pub glGetBufferParameteriv: glGetBufferParameteriv_t,
+  pub glGetBufferPointerv: glGetBufferPointerv_t,
+  pub glGetBufferSubData: glGetBufferSubData_t,
+  pub glGetCompressedTexImage: glGetCompressedTexImage_t,
+  pub glGetCompressedTextureImage: glGetCompressedTextureImage_t,
+  pub glGetCompressedTextureSubImage: glGetCompressedTextureSubImage_t,
+  pub glGetDebugMessageLog: glGetDebugMessageLog_t,
+  pub glGetDoublei_v: glGetDoublei_v_t,
+  pub glGetDoublev: glGetDoublev_t,
+  pub glGetError: glGetError_t,
+  pub glGetFloati_v: glGetFloati_v_t,
+  pub glGetFloatv: glGetFloatv_t,
+  pub glGetFragDataIndex: glGetFragDataIndex_t,
+  pub glGetFragDataLocation: glGetFragDataLocation_t,
+  pub glGetFramebufferAttachmentParameteriv: glGetFramebufferAttachmentParameteriv_t,
+  pub glGetFramebufferParameteriv: glGetFramebufferParameteriv_t,
+  pub glGetGraphicsResetStatus: glGetGraphicsResetStatus_t,
+  pub glGetInteger64i_v: glGetInteger64i_v_t,
+  pub glGetInteger64v: glGetInteger64v_t,
+  pub glGetIntegeri_v: glGetIntegeri_v_t,
+  pub glGetIntegerv: glGetIntegerv_t,
+  pub glGetInternalformati64v: glGetInternalformati64v_t,
+  pub glGetInternalformativ: glGetInternalformativ_t,
+  pub glGetMultisamplefv: glGetMultisamplefv_t,
+  pub glGetNamedBufferParameteri64v: glGetNamedBufferParameteri64v_t,
+  pub glGetNamedBufferParameteriv: glGetNamedBufferParameteriv_t,
+  pub glGetNamedBufferPointerv: glGetNamedBufferPointerv_t,
+  pub glGetNamedBufferSubData: glGetNamedBufferSubData_t,
+  pub glGetNamedFramebufferAttachmentParameteriv: glGetNamedFramebufferAttachmentParameteriv_t,
+  pub glGetNamedFramebufferParameteriv: glGetNamedFramebufferParameteriv_t,
+  pub glGetNamedRenderbufferParameteriv: glGetNamedRenderbufferParameteriv_t,
+  pub glGetObjectLabel: glGetObjectLabel_t,
+  pub glGetObjectPtrLabel: glGetObjectPtrLabel_t,
+  pub glGetPointerv: glGetPointerv_t,
+  pub glGetProgramBinary: glGetProgramBinary_t,
+  pub glGetProgramInfoLog: glGetProgramInfoLog_t,
+  pub glGetProgramInterfaceiv: glGetProgramInterfaceiv_t,
+  pub glGetProgramPipelineInfoLog: glGetProgramPipelineInfoLog_t,
+  pub glGetProgramPipelineiv: glGetProgramPipelineiv_t,
+  pub glGetProgramResourceIndex: glGetProgramResourceIndex_t,
+  pub glGetProgramResourceLocation: glGetProgramResourceLocation_t,
+  pub glGetProgramResourceLocationIndex: glGetProgramResourceLocationIndex_t,
+  pub glGetProgramResourceName: glGetProgramResourceName_t,
+  pub glGetProgramResourceiv: glGetProgramResourceiv_t,
+  pub glGetProgramStageiv: glGetProgramStageiv_t,
+  pub glGetProgramiv: glGetProgramiv_t,
+  pub glGetQueryBufferObjecti64v: glGetQueryBufferObjecti64v_t,
+  pub glGetQueryBufferObjectiv: glGetQueryBufferObjectiv_t,
+  pub glGetQueryBufferObjectui64v: glGetQueryBufferObjectui64v_t,
+  pub glGetQueryBufferObjectuiv: glGetQueryBufferObjectuiv_t,
+  pub glGetQueryIndexediv: glGetQueryIndexediv_t,
+  pub glGetQueryObjecti64v: glGetQueryObjecti64v_t,
+  pub glGetQueryObjectiv: glGetQueryObjectiv_t,
+  pub glGetQueryObjectui64v: glGetQueryObjectui64v_t,
+  pub glGetQueryObjectuiv: glGetQueryObjectuiv_t,
+  pub glGetQueryiv: glGetQueryiv_t,
+  pub glGetRenderbufferParameteriv: glGetRenderbufferParameteriv_t,
+  pub glGetSamplerParameterIiv: glGetSamplerParameterIiv_t,
+  pub glGetSamplerParameterIuiv: glGetSamplerParameterIuiv_t,
+  pub glGetSamplerParameterfv: glGetSamplerParameterfv_t,
+  pub glGetSamplerParameteriv: glGetSamplerParameteriv_t,
+  pub glGetShaderInfoLog: glGetShaderInfoLog_t,
+  pub glGetShaderPrecisionFormat: glGetShaderPrecisionFormat_t,
+  pub glGetShaderSource: glGetShaderSource_t,
+  pub glGetShaderiv: glGetShaderiv_t,
+  pub glGetString: glGetString_t,
+  pub glGetStringi: glGetStringi_t,
+  pub glGetSubroutineIndex: glGetSubroutineIndex_t,
+  pub glGetSubroutineUniformLocation: glGetSubroutineUniformLocation_t,
+  pub glGetSynciv: glGetSynciv_t,
+  pub glGetTexImage: glGetTexImage_t,
+  pub glGetTexLevelParameterfv: glGetTexLevelParameterfv_t,
+  pub glGetTexLevelParameteriv: glGetTexLevelParameteriv_t,
+  pub glGetTexParameterIiv: glGetTexParameterIiv_t,
+  pub glGetTexParameterIuiv: glGetTexParameterIuiv_t,
+  pub glGetTexParameterfv: glGetTexParameterfv_t,
+  pub glGetTexParameteriv: glGetTexParameteriv_t,
+  pub glGetTextureImage: glGetTextureImage_t,
+  pub glGetTextureLevelParameterfv: glGetTextureLevelParameterfv_t,
+  pub glGetTextureLevelParameteriv: glGetTextureLevelParameteriv_t,
+  pub glGetTextureParameterIiv: glGetTextureParameterIiv_t,
+  pub glGetTextureParameterIuiv: glGetTextureParameterIuiv_t,
+  pub glGetTextureParameterfv: glGetTextureParameterfv_t,
+  pub glGetTextureParameteriv: glGetTextureParameteriv_t,
+  pub glGetTextureSubImage: glGetTextureSubImage_t,
+  pub glGetTransformFeedbackVarying: glGetTransformFeedbackVarying_t,
+  pub glGetTransformFeedbacki64_v: glGetTransformFeedbacki64_v_t,
+  pub glGetTransformFeedbacki_v: glGetTransformFeedbacki_v_t,
+  pub glGetTransformFeedbackiv: glGetTransformFeedbackiv_t,
+  pub glGetUniformBlockIndex: glGetUniformBlockIndex_t,
+  pub glGetUniformIndices: glGetUniformIndices_t,
+  pub glGetUniformLocation: glGetUniformLocation_t,
+  pub glGetUniformSubroutineuiv: glGetUniformSubroutineuiv_t,
+  pub glGetUniformdv: glGetUniformdv_t,
+  pub glGetUniformfv: glGetUniformfv_t,
+  pub glGetUniformiv: glGetUniformiv_t,
+  pub glGetUniformuiv: glGetUniformuiv_t,
+  pub glGetVertexArrayIndexed64iv: glGetVertexArrayIndexed64iv_t,
+  pub glGetVertexArrayIndexediv: glGetVertexArrayIndexediv_t,
+  pub glGetVertexArrayiv: glGetVertexArrayiv_t,
+  pub glGetVertexAttribIiv: glGetVertexAttribIiv_t,
+  pub glGetVertexAttribIuiv: glGetVertexAttribIuiv_t,
+  pub glGetVertexAttribLdv: glGetVertexAttribLdv_t,
+  pub glGetVertexAttribPointerv: glGetVertexAttribPointerv_t,
+  pub glGetVertexAttribdv: glGetVertexAttribdv_t,
+  pub glGetVertexAttribfv: glGetVertexAttribfv_t,
+  pub glGetVertexAttribiv: glGetVertexAttribiv_t,
+  pub glGetnCompressedTexImage: glGetnCompressedTexImage_t,
+  pub glGetnTexImage: glGetnTexImage_t,
+  pub glGetnUniformdv: glGetnUniformdv_t,
+  pub glGetnUniformfv: glGetnUniformfv_t,
+  pub glGetnUniformiv: glGetnUniformiv_t,
+  pub glGetnUniformuiv: glGetnUniformuiv_t,
+  pub glHint: glHint_t,
+  pub glInvalidateBufferData: glInvalidateBufferData_t,
+  pub glInvalidateBufferSubData: glInvalidateBufferSubData_t,
+  pub glInvalidateFramebuffer: glInvalidateFramebuffer_t,
+  pub glInvalidateNamedFramebufferData: glInvalidateNamedFramebufferData_t,
+  pub glInvalidateNamedFramebufferSubData: glInvalidateNamedFramebufferSubData_t,
+  pub glInvalidateSubFramebuffer: glInvalidateSubFramebuffer_t,
+  pub glInvalidateTexImage: glInvalidateTexImage_t,
+  pub glInvalidateTexSubImage: glInvalidateTexSubImage_t,
+  pub glIsBuffer: glIsBuffer_t,
+  pub glIsEnabled: glIsEnabled_t,
+  pub glIsEnabledi: glIsEnabledi_t,
+  pub glIsFramebuffer: glIsFramebuffer_t,
+  pub glIsProgram: glIsProgram_t,
+  pub glIsProgramPipeline: glIsProgramPipeline_t,
+  pub glIsQuery: glIsQuery_t,
+  pub glIsRenderbuffer: glIsRenderbuffer_t,
+  pub glIsSampler: glIsSampler_t,
+  pub glIsShader: glIsShader_t,
+  pub glIsSync: glIsSync_t,
+  pub glIsTexture: glIsTexture_t,
+  pub glIsTransformFeedback: glIsTransformFeedback_t,
+  pub glIsVertexArray: glIsVertexArray_t,
+  pub glLineWidth: glLineWidth_t,
+  pub glLinkProgram: glLinkProgram_t,
+  pub glLogicOp: glLogicOp_t,
+  pub glMapBuffer: glMapBuffer_t,
+  pub glMapBufferRange: glMapBufferRange_t,
+  pub glMapNamedBuffer: glMapNamedBuffer_t,
+  pub glMapNamedBufferRange: glMapNamedBufferRange_t,
+  pub glMemoryBarrier: glMemoryBarrier_t,
+  pub glMemoryBarrierByRegion: glMemoryBarrierByRegion_t,
+  pub glMinSampleShading: glMinSampleShading_t,
+  pub glMultiDrawArrays: glMultiDrawArrays_t,
+  pub glMultiDrawArraysIndirect: glMultiDrawArraysIndirect_t,
+  pub glMultiDrawArraysIndirectCount: glMultiDrawArraysIndirectCount_t,
+  pub glMultiDrawElements: glMultiDrawElements_t,
+  pub glMultiDrawElementsBaseVertex: glMultiDrawElementsBaseVertex_t,
+  pub glMultiDrawElementsIndirect: glMultiDrawElementsIndirect_t,
+  pub glMultiDrawElementsIndirectCount: glMultiDrawElementsIndirectCount_t,
+  pub glNamedBufferData: glNamedBufferData_t,
+  pub glNamedBufferStorage: glNamedBufferStorage_t,
+  pub glNamedBufferSubData: glNamedBufferSubData_t,
+  pub glNamedFramebufferDrawBuffer: glNamedFramebufferDrawBuffer_t,
+  pub glNamedFramebufferDrawBuffers: glNamedFramebufferDrawBuffers_t,
+  pub glNamedFramebufferParameteri: glNamedFramebufferParameteri_t,
+  pub glNamedFramebufferReadBuffer: glNamedFramebufferReadBuffer_t,
+  pub glNamedFramebufferRenderbuffer: glNamedFramebufferRenderbuffer_t,
+  pub glNamedFramebufferTexture: glNamedFramebufferTexture_t,
+  pub glNamedFramebufferTextureLayer: glNamedFramebufferTextureLayer_t,
+  pub glNamedRenderbufferStorage: glNamedRenderbufferStorage_t,
+  pub glNamedRenderbufferStorageMultisample: glNamedRenderbufferStorageMultisample_t,
+  pub glObjectLabel: glObjectLabel_t,
+  pub glObjectPtrLabel: glObjectPtrLabel_t,
+  pub glPatchParameterfv: glPatchParameterfv_t,
+  pub glPatchParameteri: glPatchParameteri_t,
+  pub glPauseTransformFeedback: glPauseTransformFeedback_t,
+  pub glPixelStoref: glPixelStoref_t,
+  pub glPixelStorei: glPixelStorei_t,
+  pub glPointParameterf: glPointParameterf_t,
+  pub glPointParameterfv: glPointParameterfv_t,
+  pub glPointParameteri: glPointParameteri_t,
+  pub glPointParameteriv: glPointParameteriv_t,
+  pub glPointSize: glPointSize_t,
+  pub glPolygonMode: glPolygonMode_t,
+  pub glPolygonOffset: glPolygonOffset_t,
+  pub glPolygonOffsetClamp: glPolygonOffsetClamp_t,
+  pub glPopDebugGroup: glPopDebugGroup_t,
+  pub glPrimitiveRestartIndex: glPrimitiveRestartIndex_t,
+  pub glProgramBinary: glProgramBinary_t,
+  pub glProgramParameteri: glProgramParameteri_t,
+  pub glProgramUniform1d: glProgramUniform1d_t,
+  pub glProgramUniform1dv: glProgramUniform1dv_t,
+  pub glProgramUniform1f: glProgramUniform1f_t,
+  pub glProgramUniform1fv: glProgramUniform1fv_t,
+  pub glProgramUniform1i: glProgramUniform1i_t,
+  pub glProgramUniform1iv: glProgramUniform1iv_t,
+  pub glProgramUniform1ui: glProgramUniform1ui_t,
+  pub glProgramUniform1uiv: glProgramUniform1uiv_t,
+  pub glProgramUniform2d: glProgramUniform2d_t,
+  pub glProgramUniform2dv: glProgramUniform2dv_t,
+  pub glProgramUniform2f: glProgramUniform2f_t,
+  pub glProgramUniform2fv: glProgramUniform2fv_t,
+  pub glProgramUniform2i: glProgramUniform2i_t,
+  pub glProgramUniform2iv: glProgramUniform2iv_t,
+  pub glProgramUniform2ui: glProgramUniform2ui_t,
+  pub glProgramUniform2uiv: glProgramUniform2uiv_t,
+  pub glProgramUniform3d: glProgramUniform3d_t,
+  pub glProgramUniform3dv: glProgramUniform3dv_t,
+  pub glProgramUniform3f: glProgramUniform3f_t,
+  pub glProgramUniform3fv: glProgramUniform3fv_t,
+  pub glProgramUniform3i: glProgramUniform3i_t,
+  pub glProgramUniform3iv: glProgramUniform3iv_t,
+  pub glProgramUniform3ui: glProgramUniform3ui_t,
+  pub glProgramUniform3uiv: glProgramUniform3uiv_t,
+  pub glProgramUniform4d: glProgramUniform4d_t,
+  pub glProgramUniform4dv: glProgramUniform4dv_t,
+  pub glProgramUniform4f: glProgramUniform4f_t,
+  pub glProgramUniform4fv: glProgramUniform4fv_t,
+  pub glProgramUniform4i: glProgramUniform4i_t,
+  pub glProgramUniform4iv: glProgramUniform4iv_t,
+  pub glProgramUniform4ui: glProgramUniform4ui_t,
+  pub glProgramUniform4uiv: glProgramUniform4uiv_t,
+  pub glProgramUniformMatrix2dv: glProgramUniformMatrix2dv_t,
+  pub glProgramUniformMatrix2fv: glProgramUniformMatrix2fv_t,
+  pub glProgramUniformMatrix2x3dv: glProgramUniformMatrix2x3dv_t,
+  pub glProgramUniformMatrix2x3fv: glProgramUniformMatrix2x3fv_t,
+  pub glProgramUniformMatrix2x4dv: glProgramUniformMatrix2x4dv_t,
+  pub glProgramUniformMatrix2x4fv: glProgramUniformMatrix2x4fv_t,
+  pub glProgramUniformMatrix3dv: glProgramUniformMatrix3dv_t,
+  pub glProgramUniformMatrix3fv: glProgramUniformMatrix3fv_t,
+  pub glProgramUniformMatrix3x2dv: glProgramUniformMatrix3x2dv_t,
+  pub glProgramUniformMatrix3x2fv: glProgramUniformMatrix3x2fv_t,
+  pub glProgramUniformMatrix3x4dv: glProgramUniformMatrix3x4dv_t,
+  pub glProgramUniformMatrix3x4fv: glProgramUniformMatrix3x4fv_t,
+  pub glProgramUniformMatrix4dv: glProgramUniformMatrix4dv_t,
+  pub glProgramUniformMatrix4fv: glProgramUniformMatrix4fv_t,
+  pub glProgramUniformMatrix4x2dv: glProgramUniformMatrix4x2dv_t,
+  pub glProgramUniformMatrix4x2fv: glProgramUniformMatrix4x2fv_t,
+  pub glProgramUniformMatrix4x3dv: glProgramUniformMatrix4x3dv_t,
+  pub glProgramUniformMatrix4x3fv: glProgramUniformMatrix4x3fv_t,
+  pub glProvokingVertex: glProvokingVertex_t,
+  pub glPushDebugGroup: glPushDebugGroup_t,
+  pub glQueryCounter: glQueryCounter_t,
+  pub glReadBuffer: glReadBuffer_t,
+  pub glReadPixels: glReadPixels_t,
+  pub glReadnPixels: glReadnPixels_t,
+  pub glReleaseShaderCompiler: glReleaseShaderCompiler_t,
+  pub glRenderbufferStorage: glRenderbufferStorage_t,
+  pub glRenderbufferStorageMultisample: glRenderbufferStorageMultisample_t,
+  pub glResumeTransformFeedback: glResumeTransformFeedback_t,
+  pub glSampleCoverage: glSampleCoverage_t,
+  pub glSampleMaski: glSampleMaski_t,
+  pub glSamplerParameterIiv: glSamplerParameterIiv_t,
+  pub glSamplerParameterIuiv: glSamplerParameterIuiv_t,
+  pub glSamplerParameterf: glSamplerParameterf_t,
+  pub glSamplerParameterfv: glSamplerParameterfv_t,
+  pub glSamplerParameteri: glSamplerParameteri_t,
+  pub glSamplerParameteriv: glSamplerParameteriv_t,
+  pub glScissor: glScissor_t,
+  pub glScissorArrayv: glScissorArrayv_t,
+  pub glScissorIndexed: glScissorIndexed_t,
+  pub glScissorIndexedv: glScissorIndexedv_t,
+  pub glShaderBinary: glShaderBinary_t,
+  pub glShaderSource: glShaderSource_t,
+  pub glShaderStorageBlockBinding: glShaderStorageBlockBinding_t,
+  pub glSpecializeShader: glSpecializeShader_t,
+  pub glStencilFunc: glStencilFunc_t,
+  pub glStencilFuncSeparate: glStencilFuncSeparate_t,
+  pub glStencilMask: glStencilMask_t,
+  pub glStencilMaskSeparate: glStencilMaskSeparate_t,
+  pub glStencilOp: glStencilOp_t,
+  pub glStencilOpSeparate: glStencilOpSeparate_t,
+  pub glTexBuffer: glTexBuffer_t,
+  pub glTexBufferRange: glTexBufferRange_t,
+  pub glTexImage1D: glTexImage1D_t,
+  pub glTexImage2D: glTexImage2D_t,
+  pub glTexImage2DMultisample: glTexImage2DMultisample_t,
+  pub glTexImage3D: glTexImage3D_t,
+  pub glTexImage3DMultisample: glTexImage3DMultisample_t,
+  pub glTexParameterIiv: glTexParameterIiv_t,
+  pub glTexParameterIuiv: glTexParameterIuiv_t,
+  pub glTexParameterf: glTexParameterf_t,
+  pub glTexParameterfv: glTexParameterfv_t,
+  pub glTexParameteri: glTexParameteri_t,
+  pub glTexParameteriv: glTexParameteriv_t,
+  pub glTexStorage1D: glTexStorage1D_t,
+  pub glTexStorage2D: glTexStorage2D_t,
+  pub glTexStorage2DMultisample: glTexStorage2DMultisample_t,
+  pub glTexStorage3D: glTexStorage3D_t,
+  pub glTexStorage3DMultisample: glTexStorage3DMultisample_t,
+  pub glTexSubImage1D: glTexSubImage1D_t,
+  pub glTexSubImage2D: glTexSubImage2D_t,
+  pub glTexSubImage3D: glTexSubImage3D_t,
+  pub glTextureBarrier: glTextureBarrier_t,
+  pub glTextureBuffer: glTextureBuffer_t,
+  pub glTextureBufferRange: glTextureBufferRange_t,
+  pub glTextureParameterIiv: glTextureParameterIiv_t,
+  pub glTextureParameterIuiv: glTextureParameterIuiv_t,
+  pub glTextureParameterf: glTextureParameterf_t,
+  pub glTextureParameterfv: glTextureParameterfv_t,
+  pub glTextureParameteri: glTextureParameteri_t,
+  pub glTextureParameteriv: glTextureParameteriv_t,
+  pub glTextureStorage1D: glTextureStorage1D_t,
+  pub glTextureStorage2D: glTextureStorage2D_t,
+  pub glTextureStorage2DMultisample: glTextureStorage2DMultisample_t,
+  pub glTextureStorage3D: glTextureStorage3D_t,
+  pub glTextureStorage3DMultisample: glTextureStorage3DMultisample_t,
+  pub glTextureSubImage1D: glTextureSubImage1D_t,
+  pub glTextureSubImage2D: glTextureSubImage2D_t,
+  pub glTextureSubImage3D: glTextureSubImage3D_t,
+  pub glTextureView: glTextureView_t,
+  pub glTransformFeedbackBufferBase: glTransformFeedbackBufferBase_t,
+  pub glTransformFeedbackBufferRange: glTransformFeedbackBufferRange_t,
+  pub glTransformFeedbackVaryings: glTransformFeedbackVaryings_t,
+  pub glUniform1d: glUniform1d_t,
+  pub glUniform1dv: glUniform1dv_t,
+  pub glUniform1f: glUniform1f_t,
+  pub glUniform1fv: glUniform1fv_t,
+  pub glUniform1i: glUniform1i_t,
+  pub glUniform1iv: glUniform1iv_t,
+  pub glUniform1ui: glUniform1ui_t,
+  pub glUniform1uiv: glUniform1uiv_t,
+  pub glUniform2d: glUniform2d_t,
+  pub glUniform2dv: glUniform2dv_t,
+  pub glUniform2f: glUniform2f_t,
+  pub glUniform2fv: glUniform2fv_t,
+  pub glUniform2i: glUniform2i_t,
+  pub glUniform2iv: glUniform2iv_t,
+  pub glUniform2ui: glUniform2ui_t,
+  pub glUniform2uiv: glUniform2uiv_t,
+  pub glUniform3d: glUniform3d_t,
+  pub glUniform3dv: glUniform3dv_t,
+  pub glUniform3f: glUniform3f_t,
+  pub glUniform3fv: glUniform3fv_t,
+  pub glUniform3i: glUniform3i_t,
+  pub glUniform3iv: glUniform3iv_t,
+  pub glUniform3ui: glUniform3ui_t,
+  pub glUniform3uiv: glUniform3uiv_t,
+  pub glUniform4d: glUniform4d_t,
+  pub glUniform4dv: glUniform4dv_t,
+  pub glUniform4f: glUniform4f_t,
+  pub glUniform4fv: glUniform4fv_t,
+  pub glUniform4i: glUniform4i_t,
+  pub glUniform4iv: glUniform4iv_t,
+  pub glUniform4ui: glUniform4ui_t,
+  pub glUniform4uiv: glUniform4uiv_t,
+  pub glUniformBlockBinding: glUniformBlockBinding_t,
+  pub glUniformMatrix2dv: glUniformMatrix2dv_t,
+  pub glUniformMatrix2fv: glUniformMatrix2fv_t,
+  pub glUniformMatrix2x3dv: glUniformMatrix2x3dv_t,
+  pub glUniformMatrix2x3fv: glUniformMatrix2x3fv_t,
+  pub glUniformMatrix2x4dv: glUniformMatrix2x4dv_t,
+  pub glUniformMatrix2x4fv: glUniformMatrix2x4fv_t,
+  pub glUniformMatrix3dv: glUniformMatrix3dv_t,
+  pub glUniformMatrix3fv: glUniformMatrix3fv_t,
+  pub glUniformMatrix3x2dv: glUniformMatrix3x2dv_t,
+  pub glUniformMatrix3x2fv: glUniformMatrix3x2fv_t,
+  pub glUniformMatrix3x4dv: glUniformMatrix3x4dv_t,
+  pub glUniformMatrix3x4fv: glUniformMatrix3x4fv_t,
+  pub glUniformMatrix4dv: glUniformMatrix4dv_t,
+  pub glUniformMatrix4fv: glUniformMatrix4fv_t,
+  pub glUniformMatrix4x2dv: glUniformMatrix4x2dv_t,
+  pub glUniformMatrix4x2fv: glUniformMatrix4x2fv_t,
+  pub glUniformMatrix4x3dv: glUniformMatrix4x3dv_t,
+  pub glUniformMatrix4x3fv: glUniformMatrix4x3fv_t,
+  pub glUniformSubroutinesuiv: glUniformSubroutinesuiv_t,
+  pub glUnmapBuffer: glUnmapBuffer_t,
+  pub glUnmapNamedBuffer: glUnmapNamedBuffer_t,
+  pub glUseProgram: glUseProgram_t,
+  pub glUseProgramStages: glUseProgramStages_t,
+  pub glValidateProgram: glValidateProgram_t,
+  pub glValidateProgramPipeline: glValidateProgramPipeline_t,
+  pub glVertexArrayAttribBinding: glVertexArrayAttribBinding_t,
+  pub glVertexArrayAttribFormat: glVertexArrayAttribFormat_t,
+  pub glVertexArrayAttribIFormat: glVertexArrayAttribIFormat_t,
+  pub glVertexArrayAttribLFormat: glVertexArrayAttribLFormat_t,
+  pub glVertexArrayBindingDivisor: glVertexArrayBindingDivisor_t,
+  pub glVertexArrayElementBuffer: glVertexArrayElementBuffer_t,
+  pub glVertexArrayVertexBuffer: glVertexArrayVertexBuffer_t,
+  pub glVertexArrayVertexBuffers: glVertexArrayVertexBuffers_t,
+  pub glVertexAttrib1d: glVertexAttrib1d_t,
+  pub glVertexAttrib1dv: glVertexAttrib1dv_t,
+  pub glVertexAttrib1f: glVertexAttrib1f_t,
+  pub glVertexAttrib1fv: glVertexAttrib1fv_t,
+  pub glVertexAttrib1s: glVertexAttrib1s_t,
+  pub glVertexAttrib1sv: glVertexAttrib1sv_t,
+  pub glVertexAttrib2d: glVertexAttrib2d_t,
+  pub glVertexAttrib2dv: glVertexAttrib2dv_t,
+  pub glVertexAttrib2f: glVertexAttrib2f_t,
+  pub glVertexAttrib2fv: glVertexAttrib2fv_t,
+  pub glVertexAttrib2s: glVertexAttrib2s_t,
+  pub glVertexAttrib2sv: glVertexAttrib2sv_t,
+  pub glVertexAttrib3d: glVertexAttrib3d_t,
+  pub glVertexAttrib3dv: glVertexAttrib3dv_t,
+  pub glVertexAttrib3f: glVertexAttrib3f_t,
+  pub glVertexAttrib3fv: glVertexAttrib3fv_t,
+  pub glVertexAttrib3s: glVertexAttrib3s_t,
+  pub glVertexAttrib3sv: glVertexAttrib3sv_t,
+  pub glVertexAttrib4Nbv: glVertexAttrib4Nbv_t,
+  pub glVertexAttrib4Niv: glVertexAttrib4Niv_t,
+  pub glVertexAttrib4Nsv: glVertexAttrib4Nsv_t,
+  pub glVertexAttrib4Nub: glVertexAttrib4Nub_t,
+  pub glVertexAttrib4Nubv: glVertexAttrib4Nubv_t,
+  pub glVertexAttrib4Nuiv: glVertexAttrib4Nuiv_t,
+  pub glVertexAttrib4Nusv: glVertexAttrib4Nusv_t,
+  pub glVertexAttrib4bv: glVertexAttrib4bv_t,
+  pub glVertexAttrib4d: glVertexAttrib4d_t,
+  pub glVertexAttrib4dv: glVertexAttrib4dv_t,
+  pub glVertexAttrib4f: glVertexAttrib4f_t,
+  pub glVertexAttrib4fv: glVertexAttrib4fv_t,
+  pub glVertexAttrib4iv: glVertexAttrib4iv_t,
+  pub glVertexAttrib4s: glVertexAttrib4s_t,
+  pub glVertexAttrib4sv: glVertexAttrib4sv_t,
+  pub glVertexAttrib4ubv: glVertexAttrib4ubv_t,
+  pub glVertexAttrib4uiv: glVertexAttrib4uiv_t,
+  pub glVertexAttrib4usv: glVertexAttrib4usv_t,
+  pub glVertexAttribBinding: glVertexAttribBinding_t,
+  pub glVertexAttribDivisor: glVertexAttribDivisor_t,
+  pub glVertexAttribFormat: glVertexAttribFormat_t,
+  pub glVertexAttribI1i: glVertexAttribI1i_t,
+  pub glVertexAttribI1iv: glVertexAttribI1iv_t,
+  pub glVertexAttribI1ui: glVertexAttribI1ui_t,
+  pub glVertexAttribI1uiv: glVertexAttribI1uiv_t,
+  pub glVertexAttribI2i: glVertexAttribI2i_t,
+  pub glVertexAttribI2iv: glVertexAttribI2iv_t,
+  pub glVertexAttribI2ui: glVertexAttribI2ui_t,
+  pub glVertexAttribI2uiv: glVertexAttribI2uiv_t,
+  pub glVertexAttribI3i: glVertexAttribI3i_t,
+  pub glVertexAttribI3iv: glVertexAttribI3iv_t,
+  pub glVertexAttribI3ui: glVertexAttribI3ui_t,
+  pub glVertexAttribI3uiv: glVertexAttribI3uiv_t,
+  pub glVertexAttribI4bv: glVertexAttribI4bv_t,
+  pub glVertexAttribI4i: glVertexAttribI4i_t,
+  pub glVertexAttribI4iv: glVertexAttribI4iv_t,
+  pub glVertexAttribI4sv: glVertexAttribI4sv_t,
+  pub glVertexAttribI4ubv: glVertexAttribI4ubv_t,
+  pub glVertexAttribI4ui: glVertexAttribI4ui_t,
+  pub glVertexAttribI4uiv: glVertexAttribI4uiv_t,
+  pub glVertexAttribI4usv: glVertexAttribI4usv_t,
+  pub glVertexAttribIFormat: glVertexAttribIFormat_t,
+  pub glVertexAttribIPointer: glVertexAttribIPointer_t,
+  pub glVertexAttribL1d: glVertexAttribL1d_t,
+  pub glVertexAttribL1dv: glVertexAttribL1dv_t,
+  pub glVertexAttribL2d: glVertexAttribL2d_t,
+  pub glVertexAttribL2dv: glVertexAttribL2dv_t,
+  pub glVertexAttribL3d: glVertexAttribL3d_t,
+  pub glVertexAttribL3dv: glVertexAttribL3dv_t,
+  pub glVertexAttribL4d: glVertexAttribL4d_t,
+  pub glVertexAttribL4dv: glVertexAttribL4dv_t,
+  pub glVertexAttribLFormat: glVertexAttribLFormat_t,
+  pub glVertexAttribLPointer: glVertexAttribLPointer_t,
+  pub glVertexAttribP1ui: glVertexAttribP1ui_t,
+  pub glVertexAttribP1uiv: glVertexAttribP1uiv_t,
+  pub glVertexAttribP2ui: glVertexAttribP2ui_t,
+  pub glVertexAttribP2uiv: glVertexAttribP2uiv_t,
+  pub glVertexAttribP3ui: glVertexAttribP3ui_t,
+  pub glVertexAttribP3uiv: glVertexAttribP3uiv_t,
+  pub glVertexAttribP4ui: glVertexAttribP4ui_t,
+  pub glVertexAttribP4uiv: glVertexAttribP4uiv_t,
+  pub glVertexAttribPointer: glVertexAttribPointer_t,
+  pub glVertexBindingDivisor: glVertexBindingDivisor_t,
+  pub glViewport: glViewport_t,
+  pub glViewportArrayv: glViewportArrayv_t,
+  pub glViewportIndexedf: glViewportIndexedf_t,
+  pub glViewportIndexedfv: glViewportIndexedfv_t,
+  pub glWaitSync: glWaitSync_t,
+}
+impl GlFns {
+  /// Loads every function pointer using the loader given.
+  ///
+  /// The loader gets each null-terminated name (eg: `b"glClear\0"`), and
+  /// gives the address of that function, or null.
+  ///
+  /// ## Safety
+  /// * The non-null addresses must be of functions with the signature from
+  ///   `gl.xml`.
+  pub unsafe fn load<F: Fn(&[u8]) -> *const c_void>(loader: F) -> Self {
+    Self {
+      glActiveShaderProgram: core::mem::transmute::<*const c_void, glActiveShaderProgram_t>(loader(b"glActiveShaderProgram\0")),
+      glActiveTexture: core::mem::transmute::<*const c_void, glActiveTexture_t>(loader(b"glActiveTexture\0")),
+      glAttachShader: core::mem::transmute::<*const c_void, glAttachShader_t>(loader(b"glAttachShader\0")),
+      glBeginConditionalRender: core::mem::transmute::<*const c_void, glBeginConditionalRender_t>(loader(b"glBeginConditionalRender\0")),
+      glBeginQuery: core::mem::transmute::<*const c_void, glBeginQuery_t>(loader(b"glBeginQuery\0")),
+      glBeginQueryIndexed: core::mem::transmute::<*const c_void, glBeginQueryIndexed_t>(loader(b"glBeginQueryIndexed\0")),
+      glBeginTransformFeedback: core::mem::transmute::<*const c_void, glBeginTransformFeedback_t>(loader(b"glBeginTransformFeedback\0")),
+      glBindAttribLocation: core::mem::transmute::<*const c_void, glBindAttribLocation_t>(loader(b"glBindAttribLocation\0")),
+      glBindBuffer: core::mem::transmute::<*const c_void, glBindBuffer_t>(loader(b"glBindBuffer\0")),
+      glBindBufferBase: core::mem::transmute::<*const c_void, glBindBufferBase_t>(loader(b"glBindBufferBase\0")),
+      glBindBufferRange: core::mem::transmute::<*const c_void, glBindBufferRange_t>(loader(b"glBindBufferRange\0")),
+      glBindBuffersBase: core::mem::transmute::<*const c_void, glBindBuffersBase_t>(loader(b"glBindBuffersBase\0")),
+      glBindBuffersRange: core::mem::transmute::<*const c_void, glBindBuffersRange_t>(loader(b"glBindBuffersRange\0")),
+      glBindFragDataLocation: core::mem::transmute::<*const c_void, glBindFragDataLocation_t>(loader(b"glBindFragDataLocation\0")),
+      glBindFragDataLocationIndexed: core::mem::transmute::<*const c_void, glBindFragDataLocationIndexed_t>(loader(b"glBindFragDataLocationIndexed\0")),
+      glBindFramebuffer: core::mem::transmute::<*const c_void, glBindFramebuffer_t>(loader(b"glBindFramebuffer\0")),
+      glBindImageTexture: core::mem::transmute::<*const c_void, glBindImageTexture_t>(loader(b"glBindImageTexture\0")),
+      glBindImageTextures: core::mem::transmute::<*const c_void, glBindImageTextures_t>(loader(b"glBindImageTextures\0")),
+      glBindProgramPipeline: core::mem::transmute::<*const c_void, glBindProgramPipeline_t>(loader(b"glBindProgramPipeline\0")),
+      glBindRenderbuffer: core::mem::transmute::<*const c_void, glBindRenderbuffer_t>(loader(b"glBindRenderbuffer\0")),
+      glBindSampler: core::mem::transmute::<*const c_void, glBindSampler_t>(loader(b"glBindSampler\0")),
+      glBindSamplers: core::mem::transmute::<*const c_void, glBindSamplers_t>(loader(b"glBindSamplers\0")),
+      glBindTexture: core::mem::transmute::<*const c_void, glBindTexture_t>(loader(b"glBindTexture\0")),
+      glBindTextureUnit: core::mem::transmute::<*const c_void, glBindTextureUnit_t>(loader(b"glBindTextureUnit\0")),
+      glBindTextures: core::mem::transmute::<*const c_void, glBindTextures_t>(loader(b"glBindTextures\0")),
+      glBindTransformFeedback: core::mem::transmute::<*const c_void, glBindTransformFeedback_t>(loader(b"glBindTransformFeedback\0")),
+      glBindVertexArray: core::mem::transmute::<*const c_void, glBindVertexArray_t>(loader(b"glBindVertexArray\0")),
+      glBindVertexBuffer: core::mem::transmute::<*const c_void, glBindVertexBuffer_t>(loader(b"glBindVertexBuffer\0")),
+      glBindVertexBuffers: core::mem::transmute::<*const c_void, glBindVertexBuffers_t>(loader(b"glBindVertexBuffers\0")),
+      glBlendColor: core::mem::transmute::<*const c_void, glBlendColor_t>(loader(b"glBlendColor\0")),
+      glBlendEquation: core::mem::transmute::<*const c_void, glBlendEquation_t>(loader(b"glBlendEquation\0")),
+      glBlendEquationSeparate: core::mem::transmute::<*const c_void, glBlendEquationSeparate_t>(loader(b"glBlendEquationSeparate\0")),
+      glBlendEquationSeparatei: core::mem::transmute::<*const c_void, glBlendEquationSeparatei_t>(loader(b"glBlendEquationSeparatei\0")),
+      glBlendEquationi: core::mem::transmute::<*const c_void, glBlendEquationi_t>(loader(b"glBlendEquationi\0")),
+      glBlendFunc: core::mem::transmute::<*const c_void, glBlendFunc_t>(loader(b"glBlendFunc\0")),
+      glBlendFuncSeparate: core::mem::transmute::<*const c_void, glBlendFuncSeparate_t>(loader(b"glBlendFuncSeparate\0")),
+      glBlendFuncSeparatei: core::mem::transmute::<*const c_void, glBlendFuncSeparatei_t>(loader(b"glBlendFuncSeparatei\0")),
+      glBlendFunci: core::mem::transmute::<*const c_void, glBlendFunci_t>(loader(b"glBlendFunci\0")),
+      glBlitFramebuffer: core::mem::transmute::<*const c_void, glBlitFramebuffer_t>(loader(b"glBlitFramebuffer\0")),
+      glBlitNamedFramebuffer: core::mem::transmute::<*const c_void, glBlitNamedFramebuffer_t>(loader(b"glBlitNamedFramebuffer\0")),
+      glBufferData: core::mem::transmute::<*const c_void, glBufferData_t>(loader(b"glBufferData\0")),
+      glBufferStorage: core::mem::transmute::<*const c_void, glBufferStorage_t>(loader(b"glBufferStorage\0")),
+      glBufferSubData: core::mem::transmute::<*const c_void, glBufferSubData_t>(loader(b"glBufferSubData\0")),
+      glCheckFramebufferStatus: core::mem::transmute::<*const c_void, glCheckFramebufferStatus_t>(loader(b"glCheckFramebufferStatus\0")),
+      glCheckNamedFramebufferStatus: core::mem::transmute::<*const c_void, glCheckNamedFramebufferStatus_t>(loader(b"glCheckNamedFramebufferStatus\0")),
+      glClampColor: core::mem::transmute::<*const c_void, glClampColor_t>(loader(b"glClampColor\0")),
+      glClear: core::mem::transmute::<*const c_void, glClear_t>(loader(b"glClear\0")),
+      glClearBufferData: core::mem::transmute::<*const c_void, glClearBufferData_t>(loader(b"glClearBufferData\0")),
+      glClearBufferSubData: core::mem::transmute::<*const c_void, glClearBufferSubData_t>(loader(b"glClearBufferSubData\0")),
+      glClearBufferfi: core::mem::transmute::<*const c_void, glClearBufferfi_t>(loader(b"glClearBufferfi\0")),
+      glClearBufferfv: core::mem::transmute::<*const c_void, glClearBufferfv_t>(loader(b"glClearBufferfv\0")),
+      glClearBufferiv: core::mem::transmute::<*const c_void, glClearBufferiv_t>(loader(b"glClearBufferiv\0")),
+      glClearBufferuiv: core::mem::transmute::<*const c_void, glClearBufferuiv_t>(loader(b"glClearBufferuiv\0")),
+      glClearColor: core::mem::transmute::<*const c_void, glClearColor_t>(loader(b"glClearColor\0")),
+      glClearDepth: core::mem::transmute::<*const c_void, glClearDepth_t>(loader(b"glClearDepth\0")),
+      glClearDepthf: core::mem::transmute::<*const c_void, glClearDepthf_t>(loader(b"glClearDepthf\0")),
+      glClearNamedBufferData: core::mem::transmute::<*const c_void, glClearNamedBufferData_t>(loader(b"glClearNamedBufferData\0")),
+      glClearNamedBufferSubData: core::mem::transmute::<*const c_void, glClearNamedBufferSubData_t>(loader(b"glClearNamedBufferSubData\0")),
+      glClearNamedFramebufferfi: core::mem::transmute::<*const c_void, glClearNamedFramebufferfi_t>(loader(b"glClearNamedFramebufferfi\0")),
+      glClearNamedFramebufferfv: core::mem::transmute::<*const c_void, glClearNamedFramebufferfv_t>(loader(b"glClearNamedFramebufferfv\0")),
+      glClearNamedFramebufferiv: core::mem::transmute::<*const c_void, glClearNamedFramebufferiv_t>(loader(b"glClearNamedFramebufferiv\0")),
+      glClearNamedFramebufferuiv: core::mem::transmute::<*const c_void, glClearNamedFramebufferuiv_t>(loader(b"glClearNamedFramebufferuiv\0")),
+      glClearStencil: core::mem::transmute::<*const c_void, glClearStencil_t>(loader(b"glClearStencil\0")),
+      glClearTexImage: core::mem::transmute::<*const c_void, glClearTexImage_t>(loader(b"glClearTexImage\0")),
+      glClearTexSubImage: core::mem::transmute::<*const c_void, glClearTexSubImage_t>(loader(b"glClearTexSubImage\0")),
+      glClientWaitSync: core::mem::transmute::<*const c_void, glClientWaitSync_t>(loader(b"glClientWaitSync\0")),
+      glClipControl: core::mem::transmute::<*const c_void, glClipControl_t>(loader(b"glClipControl\0")),
+      glColorMask: core::mem::transmute::<*const c_void, glColorMask_t>(loader(b"glColorMask\0")),
+      glColorMaski: core::mem::transmute::<*const c_void, glColorMaski_t>(loader(b"glColorMaski\0")),
+      glCompileShader: core::mem::transmute::<*const c_void, glCompileShader_t>(loader(b"glCompileShader\0")),
+      glCompressedTexImage1D: core::mem::transmute::<*const c_void, glCompressedTexImage1D_t>(loader(b"glCompressedTexImage1D\0")),
+      glCompressedTexImage2D: core::mem::transmute::<*const c_void, glCompressedTexImage2D_t>(loader(b"glCompressedTexImage2D\0")),
+      glCompressedTexImage3D: core::mem::transmute::<*const c_void, glCompressedTexImage3D_t>(loader(b"glCompressedTexImage3D\0")),
+      glCompressedTexSubImage1D: core::mem::transmute::<*const c_void, glCompressedTexSubImage1D_t>(loader(b"glCompressedTexSubImage1D\0")),
+      glCompressedTexSubImage2D: core::mem::transmute::<*const c_void, glCompressedTexSubImage2D_t>(loader(b"glCompressedTexSubImage2D\0")),
+      glCompressedTexSubImage3D: core::mem::transmute::<*const c_void, glCompressedTexSubImage3D_t>(loader(b"glCompressedTexSubImage3D\0")),
+      glCompressedTextureSubImage1D: core::mem::transmute::<*const c_void, glCompressedTextureSubImage1D_t>(loader(b"glCompressedTextureSubImage1D\0")),
+      glCompressedTextureSubImage2D: core::mem::transmute::<*const c_void, glCompressedTextureSubImage2D_t>(loader(b"glCompressedTextureSubImage2D\0")),
+      glCompressedTextureSubImage3D: core::mem::transmute::<*const c_void, glCompressedTextureSubImage3D_t>(loader(b"glCompressedTextureSubImage3D\0")),
+      glCopyBufferSubData: core::mem::transmute::<*const c_void, glCopyBufferSubData_t>(loader(b"glCopyBufferSubData\0")),
+      glCopyImageSubData: core::mem::transmute::<*const c_void, glCopyImageSubData_t>(loader(b"glCopyImageSubData\0")),
+      glCopyNamedBufferSubData: core::mem::transmute::<*const c_void, glCopyNamedBufferSubData_t>(loader(b"glCopyNamedBufferSubData\0")),
+      glCopyTexImage1D: core::mem::transmute::<*const c_void, glCopyTexImage1D_t>(loader(b"glCopyTexImage1D\0")),
+      glCopyTexImage2D: core::mem::transmute::<*const c_void, glCopyTexImage2D_t>(loader(b"glCopyTexImage2D\0")),
+      glCopyTexSubImage1D: core::mem::transmute::<*const c_void, glCopyTexSubImage1D_t>(loader(b"glCopyTexSubImage1D\0")),
+      glCopyTexSubImage2D: core::mem::transmute::<*const c_void, glCopyTexSubImage2D_t>(loader(b"glCopyTexSubImage2D\0")),
+      glCopyTexSubImage3D: core::mem::transmute::<*const c_void, glCopyTexSubImage3D_t>(loader(b"glCopyTexSubImage3D\0")),
+      glCopyTextureSubImage1D: core::mem::transmute::<*const c_void, glCopyTextureSubImage1D_t>(loader(b"glCopyTextureSubImage1D\0")),
+      glCopyTextureSubImage2D: core::mem::transmute::<*const c_void, glCopyTextureSubImage2D_t>(loader(b"glCopyTextureSubImage2D\0")),
+      glCopyTextureSubImage3D: core::mem::transmute::<*const c_void, glCopyTextureSubImage3D_t>(loader(b"glCopyTextureSubImage3D\0")),
+      glCreateBuffers: core::mem::transmute::<*const c_void, glCreateBuffers_t>(loader(b"glCreateBuffers\0")),
+      glCreateFramebuffers: core::mem::transmute::<*const c_void, glCreateFramebuffers_t>(loader(b"glCreateFramebuffers\0")),
+      glCreateProgram: core::mem::transmute::<*const c_void, glCreateProgram_t>(loader(b"glCreateProgram\0")),
+      glCreateProgramPipelines: core::mem::transmute::<*const c_void, glCreateProgramPipelines_t>(loader(b"glCreateProgramPipelines\0")),
+      glCreateQueries: core::mem::transmute::<*const c_void, glCreateQueries_t>(loader(b"glCreateQueries\0")),
+      glCreateRenderbuffers: core::mem::transmute::<*const c_void, glCreateRenderbuffers_t>(loader(b"glCreateRenderbuffers\0")),
+      glCreateSamplers: core::mem::transmute::<*const c_void, glCreateSamplers_t>(loader(b"glCreateSamplers\0")),
+      glCreateShader: core::mem::transmute::<*const c_void, glCreateShader_t>(loader(b"glCreateShader\0")),
+      glCreateShaderProgramv: core::mem::transmute::<*const c_void, glCreateShaderProgramv_t>(loader(b"glCreateShaderProgramv\0")),
+      glCreateTextures: core::mem::transmute::<*const c_void, glCreateTextures_t>(loader(b"glCreateTextures\0")),
+      glCreateTransformFeedbacks: core::mem::transmute::<*const c_void, glCreateTransformFeedbacks_t>(loader(b"glCreateTransformFeedbacks\0")),
+      glCreateVertexArrays: core::mem::transmute::<*const c_void, glCreateVertexArrays_t>(loader(b"glCreateVertexArrays\0")),
+      glCullFace: core::mem::transmute::<*const c_void, glCullFace_t>(loader(b"glCullFace\0")),
+      glDebugMessageCallback: core::mem::transmute::<*const c_void, glDebugMessageCallback_t>(loader(b"glDebugMessageCallback\0")),
+      glDebugMessageControl: core::mem::transmute::<*const c_void, glDebugMessageControl_t>(loader(b"glDebugMessageControl\0")),
+      glDebugMessageInsert: core::mem::transmute::<*const c_void, glDebugMessageInsert_t>(loader(b"glDebugMessageInsert\0")),
+      glDeleteBuffers: core::mem::transmute::<*const c_void, glDeleteBuffers_t>(loader(b"glDeleteBuffers\0")),
+      glDeleteFramebuffers: core::mem::transmute::<*const c_void, glDeleteFramebuffers_t>(loader(b"glDeleteFramebuffers\0")),
+      glDeleteProgram: core::mem::transmute::<*const c_void, glDeleteProgram_t>(loader(b"glDeleteProgram\0")),
+      glDeleteProgramPipelines: core::mem::transmute::<*const c_void, glDeleteProgramPipelines_t>(loader(b"glDeleteProgramPipelines\0")),
+      glDeleteQueries: core::mem::transmute::<*const c_void, glDeleteQueries_t>(loader(b"glDeleteQueries\0")),
+      glDeleteRenderbuffers: core::mem::transmute::<*const c_void, glDeleteRenderbuffers_t>(loader(b"glDeleteRenderbuffers\0")),
+      glDeleteSamplers: core::mem::transmute::<*const c_void, glDeleteSamplers_t>(loader(b"glDeleteSamplers\0")),
+      glDeleteShader: core::mem::transmute::<*const c_void, glDeleteShader_t>(loader(b"glDeleteShader\0")),
+      glDeleteSync: core::mem::transmute::<*const c_void, glDeleteSync_t>(loader(b"glDeleteSync\0")),
+      glDeleteTextures: core::mem::transmute::<*const c_void, glDeleteTextures_t>(loader(b"glDeleteTextures\0")),
+      glDeleteTransformFeedbacks: core::mem::transmute::<*const c_void, glDeleteTransformFeedbacks_t>(loader(b"glDeleteTransformFeedbacks\0")),
+      glDeleteVertexArrays: core::mem::transmute::<*const c_void, glDeleteVertexArrays_t>(loader(b"glDeleteVertexArrays\0")),
+      glDepthFunc: core::mem::transmute::<*const c_void, glDepthFunc_t>(loader(b"glDepthFunc\0")),
+      glDepthMask: core::mem::transmute::<*const c_void, glDepthMask_t>(loader(b"glDepthMask\0")),
+      glDepthRange: core::mem::transmute::<*const c_void, glDepthRange_t>(loader(b"glDepthRange\0")),
+      glDepthRangeArrayv: core::mem::transmute::<*const c_void, glDepthRangeArrayv_t>(loader(b"glDepthRangeArrayv\0")),
+      glDepthRangeIndexed: core::mem::transmute::<*const c_void, glDepthRangeIndexed_t>(loader(b"glDepthRangeIndexed\0")),
+      glDepthRangef: core::mem::transmute::<*const c_void, glDepthRangef_t>(loader(b"glDepthRangef\0")),
+      glDetachShader: core::mem::transmute::<*const c_void, glDetachShader_t>(loader(b"glDetachShader\0")),
+      glDisable: core::mem::transmute::<*const c_void, glDisable_t>(loader(b"glDisable\0")),
+      glDisableVertexArrayAttrib: core::mem::transmute::<*const c_void, glDisableVertexArrayAttrib_t>(loader(b"glDisableVertexArrayAttrib\0")),
+      glDisableVertexAttribArray: core::mem::transmute::<*const c_void, glDisableVertexAttribArray_t>(loader(b"glDisableVertexAttribArray\0")),
+      glDisablei: core::mem::transmute::<*const c_void, glDisablei_t>(loader(b"glDisablei\0")),
+      glDispatchCompute: core::mem::transmute::<*const c_void, glDispatchCompute_t>(loader(b"glDispatchCompute\0")),
+      glDispatchComputeIndirect: core::mem::transmute::<*const c_void, glDispatchComputeIndirect_t>(loader(b"glDispatchComputeIndirect\0")),
+      glDrawArrays: core::mem::transmute::<*const c_void, glDrawArrays_t>(loader(b"glDrawArrays\0")),
+      glDrawArraysIndirect: core::mem::transmute::<*const c_void, glDrawArraysIndirect_t>(loader(b"glDrawArraysIndirect\0")),
+      glDrawArraysInstanced: core::mem::transmute::<*const c_void, glDrawArraysInstanced_t>(loader(b"glDrawArraysInstanced\0")),
+      glDrawArraysInstancedBaseInstance: core::mem::transmute::<*const c_void, glDrawArraysInstancedBaseInstance_t>(loader(b"glDrawArraysInstancedBaseInstance\0")),
+      glDrawBuffer: core::mem::transmute::<*const c_void, glDrawBuffer_t>(loader(b"glDrawBuffer\0")),
+      glDrawBuffers: core::mem::transmute::<*const c_void, glDrawBuffers_t>(loader(b"glDrawBuffers\0")),
+      glDrawElements: core::mem::transmute::<*const c_void, glDrawElements_t>(loader(b"glDrawElements\0")),
+      glDrawElementsBaseVertex: core::mem::transmute::<*const c_void, glDrawElementsBaseVertex_t>(loader(b"glDrawElementsBaseVertex\0")),
+      glDrawElementsIndirect: core::mem::transmute::<*const c_void, glDrawElementsIndirect_t>(loader(b"glDrawElementsIndirect\0")),
+      glDrawElementsInstanced: core::mem::transmute::<*const c_void, glDrawElementsInstanced_t>(loader(b"glDrawElementsInstanced\0")),
+      glDrawElementsInstancedBaseInstance: core::mem::transmute::<*const c_void, glDrawElementsInstancedBaseInstance_t>(loader(b"glDrawElementsInstancedBaseInstance\0")),
+      glDrawElementsInstancedBaseVertex: core::mem::transmute::<*const c_void, glDrawElementsInstancedBaseVertex_t>(loader(b"glDrawElementsInstancedBaseVertex\0")),
+      glDrawElementsInstancedBaseVertexBaseInstance: core::mem::transmute::<*const c_void, glDrawElementsInstancedBaseVertexBaseInstance_t>(loader(b"glDrawElementsInstancedBaseVertexBaseInstance\0")),
+      glDrawRangeElements: core::mem::transmute::<*const c_void, glDrawRangeElements_t>(loader(b"glDrawRangeElements\0")),
+      glDrawRangeElementsBaseVertex: core::mem::transmute::<*const c_void, glDrawRangeElementsBaseVertex_t>(loader(b"glDrawRangeElementsBaseVertex\0")),
+      glDrawTransformFeedback: core::mem::transmute::<*const c_void, glDrawTransformFeedback_t>(loader(b"glDrawTransformFeedback\0")),
+      glDrawTransformFeedbackInstanced: core::mem::transmute::<*const c_void, glDrawTransformFeedbackInstanced_t>(loader(b"glDrawTransformFeedbackInstanced\0")),
+      glDrawTransformFeedbackStream: core::mem::transmute::<*const c_void, glDrawTransformFeedbackStream_t>(loader(b"glDrawTransformFeedbackStream\0")),
+      glDrawTransformFeedbackStreamInstanced: core::mem::transmute::<*const c_void, glDrawTransformFeedbackStreamInstanced_t>(loader(b"glDrawTransformFeedbackStreamInstanced\0")),
+      glEnable: core::mem::transmute::<*const c_void, glEnable_t>(loader(b"glEnable\0")),
+      glEnableVertexArrayAttrib: core::mem::transmute::<*const c_void, glEnableVertexArrayAttrib_t>(loader(b"glEnableVertexArrayAttrib\0")),
+      glEnableVertexAttribArray: core::mem::transmute::<*const c_void, glEnableVertexAttribArray_t>(loader(b"glEnableVertexAttribArray\0")),
+      glEnablei: core::mem::transmute::<*const c_void, glEnablei_t>(loader(b"glEnablei\0")),
+      glEndConditionalRender: core::mem::transmute::<*const c_void, glEndConditionalRender_t>(loader(b"glEndConditionalRender\0")),
+      glEndQuery: core::mem::transmute::<*const c_void, glEndQuery_t>(loader(b"glEndQuery\0")),
+      glEndQueryIndexed: core::mem::transmute::<*const c_void, glEndQueryIndexed_t>(loader(b"glEndQueryIndexed\0")),
+      glEndTransformFeedback: core::mem::transmute::<*const c_void, glEndTransformFeedback_t>(loader(b"glEndTransformFeedback\0")),
+      glFenceSync: core::mem::transmute::<*const c_void, glFenceSync_t>(loader(b"glFenceSync\0")),
+      glFinish: core::mem::transmute::<*const c_void, glFinish_t>(loader(b"glFinish\0")),
+      glFlush: core::mem::transmute::<*const c_void, glFlush_t>(loader(b"glFlush\0")),
+      glFlushMappedBufferRange: core::mem::transmute::<*const c_void, glFlushMappedBufferRange_t>(loader(b"glFlushMappedBufferRange\0")),
+      glFlushMappedNamedBufferRange: core::mem::transmute::<*const c_void, glFlushMappedNamedBufferRange_t>(loader(b"glFlushMappedNamedBufferRange\0")),
+      glFramebufferParameteri: core::mem::transmute::<*const c_void, glFramebufferParameteri_t>(loader(b"glFramebufferParameteri\0")),
+      glFramebufferRenderbuffer: core::mem::transmute::<*const c_void, glFramebufferRenderbuffer_t>(loader(b"glFramebufferRenderbuffer\0")),
+      glFramebufferTexture: core::mem::transmute::<*const c_void, glFramebufferTexture_t>(loader(b"glFramebufferTexture\0")),
+      glFramebufferTexture1D: core::mem::transmute::<*const c_void, glFramebufferTexture1D_t>(loader(b"glFramebufferTexture1D\0")),
+      glFramebufferTexture2D: core::mem::transmute::<*const c_void, glFramebufferTexture2D_t>(loader(b"glFramebufferTexture2D\0")),
+      glFramebufferTexture3D: core::mem::transmute::<*const c_void, glFramebufferTexture3D_t>(loader(b"glFramebufferTexture3D\0")),
+      glFramebufferTextureLayer: core::mem::transmute::<*const c_void, glFramebufferTextureLayer_t>(loader(b"glFramebufferTextureLayer\0")),
+      glFrontFace: core::mem::transmute::<*const c_void, glFrontFace_t>(loader(b"glFrontFace\0")),
+      glGenBuffers: core::mem::transmute::<*const c_void, glGenBuffers_t>(loader(b"glGenBuffers\0")),
+      glGenFramebuffers: core::mem::transmute::<*const c_void, glGenFramebuffers_t>(loader(b"glGenFramebuffers\0")),
+      glGenProgramPipelines: core::mem::transmute::<*const c_void, glGenProgramPipelines_t>(loader(b"glGenProgramPipelines\0")),
+      glGenQueries: core::mem::transmute::<*const c_void, glGenQueries_t>(loader(b"glGenQueries\0")),
+      glGenRenderbuffers: core::mem::transmute::<*const c_void, glGenRenderbuffers_t>(loader(b"glGenRenderbuffers\0")),
+      glGenSamplers: core::mem::transmute::<*const c_void, glGenSamplers_t>(loader(b"glGenSamplers\0")),
+      glGenTextures: core::mem::transmute::<*const c_void, glGenTextures_t>(loader(b"glGenTextures\0")),
+      glGenTransformFeedbacks: core::mem::transmute::<*const c_void, glGenTransformFeedbacks_t>(loader(b"glGenTransformFeedbacks\0")),
+      glGenVertexArrays: core::mem::transmute::<*const c_void, glGenVertexArrays_t>(loader(b"glGenVertexArrays\0")),
+      glGenerateMipmap: core::mem::transmute::<*const c_void, glGenerateMipmap_t>(loader(b"glGenerateMipmap\0")),
+      glGenerateTextureMipmap: core::mem::transmute::<*const c_void, glGenerateTextureMipmap_t>(loader(b"glGenerateTextureMipmap\0")),
+      glGetActiveAtomicCounterBufferiv: core::mem::transmute::<*const c_void, glGetActiveAtomicCounterBufferiv_t>(loader(b"glGetActiveAtomicCounterBufferiv\0")),
+      glGetActiveAttrib: core::mem::transmute::<*const c_void, glGetActiveAttrib_t>(loader(b"glGetActiveAttrib\0")),
+      glGetActiveSubroutineName: core::mem::transmute::<*const c_void, glGetActiveSubroutineName_t>(loader(b"glGetActiveSubroutineName\0")),
+      glGetActiveSubroutineUniformName: core::mem::transmute::<*const c_void, glGetActiveSubroutineUniformName_t>(loader(b"glGetActiveSubroutineUniformName\0")),
+      glGetActiveSubroutineUniformiv: core::mem::transmute::<*const c_void, glGetActiveSubroutineUniformiv_t>(loader(b"glGetActiveSubroutineUniformiv\0")),
+      glGetActiveUniform: core::mem::transmute::<*const c_void, glGetActiveUniform_t>(loader(b"glGetActiveUniform\0")),
+      glGetActiveUniformBlockName: core::mem::transmute::<*const c_void, glGetActiveUniformBlockName_t>(loader(b"glGetActiveUniformBlockName\0")),
+      glGetActiveUniformBlockiv: core::mem::transmute::<*const c_void, glGetActiveUniformBlockiv_t>(loader(b"glGetActiveUniformBlockiv\0")),
+      glGetActiveUniformName: core::mem::transmute::<*const c_void, glGetActiveUniformName_t>(loader(b"glGetActiveUniformName\0")),
+      glGetActiveUniformsiv: core::mem::transmute::<*const c_void, glGetActiveUniformsiv_t>(loader(b"glGetActiveUniformsiv\0")),
+      glGetAttachedShaders: core::mem::transmute::<*const c_void, glGetAttachedShaders_t>(loader(b"glGetAttachedShaders\0")),
+      glGetAttribLocation: core::mem::transmute::<*const c_void, glGetAttribLocation_t>(loader(b"glGetAttribLocation\0")),
+      glGetBooleani_v: core::mem::transmute::<*const c_void, glGetBooleani_v_t>(loader(b"glGetBooleani_v\0")),
+      glGetBooleanv: core::mem::transmute::<*const c_void, glGetBooleanv_t>(loader(b"glGetBooleanv\0")),
+      glGetBufferParameteri64v: core::mem::transmute::<*const c_void, glGetBufferParameteri64v_t>(loader(b"glGetBufferParameteri64v\0")),
+      glGetBufferParameteriv: core::mem::transmute::<*const c_void, glGetBufferParameteriv_t>(loader(b"glGetBufferParameteriv\0")),
+      glGetBufferPointerv: core::mem::transmute::<*const c_void, glGetBufferPointerv_t>(loader(b"glGetBufferPointerv\0")),
+      glGetBufferSubData: core::mem::transmute::<*const c_void, glGetBufferSubData_t>(loader(b"glGetBufferSubData\0")),
+      glGetCompressedTexImage: core::mem::transmute::<*const c_void, glGetCompressedTexImage_t>(loader(b"glGetCompressedTexImage\0")),
+      glGetCompressedTextureImage: core::mem::transmute::<*const c_void, glGetCompressedTextureImage_t>(loader(b"glGetCompressedTextureImage\0")),
+      glGetCompressedTextureSubImage: core::mem::transmute::<*const c_void, glGetCompressedTextureSubImage_t>(loader(b"glGetCompressedTextureSubImage\0")),
+      glGetDebugMessageLog: core::mem::transmute::<*const c_void, glGetDebugMessageLog_t>(loader(b"glGetDebugMessageLog\0")),
+      glGetDoublei_v: core::mem::transmute::<*const c_void, glGetDoublei_v_t>(loader(b"glGetDoublei_v\0")),
+      glGetDoublev: core::mem::transmute::<*const c_void, glGetDoublev_t>(loader(b"glGetDoublev\0")),
+      glGetError: core::mem::transmute::<*const c_void, glGetError_t>(loader(b"glGetError\0")),
+      glGetFloati_v: core::mem::transmute::<*const c_void, glGetFloati_v_t>(loader(b"glGetFloati_v\0")),
+      glGetFloatv: core::mem::transmute::<*const c_void, glGetFloatv_t>(loader(b"glGetFloatv\0")),
+      glGetFragDataIndex: core::mem::transmute::<*const c_void, glGetFragDataIndex_t>(loader(b"glGetFragDataIndex\0")),
+      glGetFragDataLocation: core::mem::transmute::<*const c_void, glGetFragDataLocation_t>(loader(b"glGetFragDataLocation\0")),
+      glGetFramebufferAttachmentParameteriv: core::mem::transmute::<*const c_void, glGetFramebufferAttachmentParameteriv_t>(loader(b"glGetFramebufferAttachmentParameteriv\0")),
+      glGetFramebufferParameteriv: core::mem::transmute::<*const c_void, glGetFramebufferParameteriv_t>(loader(b"glGetFramebufferParameteriv\0")),
+      glGetGraphicsResetStatus: core::mem::transmute::<*const c_void, glGetGraphicsResetStatus_t>(loader(b"glGetGraphicsResetStatus\0")),
+      glGetInteger64i_v: core::mem::transmute::<*const c_void, glGetInteger64i_v_t>(loader(b"glGetInteger64i_v\0")),
+      glGetInteger64v: core::mem::transmute::<*const c_void, glGetInteger64v_t>(loader(b"glGetInteger64v\0")),
+      glGetIntegeri_v: core::mem::transmute::<*const c_void, glGetIntegeri_v_t>(loader(b"glGetIntegeri_v\0")),
+      glGetIntegerv: core::mem::transmute::<*const c_void, glGetIntegerv_t>(loader(b"glGetIntegerv\0")),
+      glGetInternalformati64v: core::mem::transmute::<*const c_void, glGetInternalformati64v_t>(loader(b"glGetInternalformati64v\0")),
+      glGetInternalformativ: core::mem::transmute::<*const c_void, glGetInternalformativ_t>(loader(b"glGetInternalformativ\0")),
+      glGetMultisamplefv: core::mem::transmute::<*const c_void, glGetMultisamplefv_t>(loader(b"glGetMultisamplefv\0")),
+      glGetNamedBufferParameteri64v: core::mem::transmute::<*const c_void, glGetNamedBufferParameteri64v_t>(loader(b"glGetNamedBufferParameteri64v\0")),
+      glGetNamedBufferParameteriv: core::mem::transmute::<*const c_void, glGetNamedBufferParameteriv_t>(loader(b"glGetNamedBufferParameteriv\0")),
+      glGetNamedBufferPointerv: core::mem::transmute::<*const c_void, glGetNamedBufferPointerv_t>(loader(b"glGetNamedBufferPointerv\0")),
+      glGetNamedBufferSubData: core::mem::transmute::<*const c_void, glGetNamedBufferSubData_t>(loader(b"glGetNamedBufferSubData\0")),
+      glGetNamedFramebufferAttachmentParameteriv: core::mem::transmute::<*const c_void, glGetNamedFramebufferAttachmentParameteriv_t>(loader(b"glGetNamedFramebufferAttachmentParameteriv\0")),
+      glGetNamedFramebufferParameteriv: core::mem::transmute::<*const c_void, glGetNamedFramebufferParameteriv_t>(loader(b"glGetNamedFramebufferParameteriv\0")),
+      glGetNamedRenderbufferParameteriv: core::mem::transmute::<*const c_void, glGetNamedRenderbufferParameteriv_t>(loader(b"glGetNamedRenderbufferParameteriv\0")),
+      glGetObjectLabel: core::mem::transmute::<*const c_void, glGetObjectLabel_t>(loader(b"glGetObjectLabel\0")),
+      glGetObjectPtrLabel: core::mem::transmute::<*const c_void, glGetObjectPtrLabel_t>(loader(b"glGetObjectPtrLabel\0")),
+      glGetPointerv: core::mem::transmute::<*const c_void, glGetPointerv_t>(loader(b"glGetPointerv\0")),
+      glGetProgramBinary: core::mem::transmute::<*const c_void, glGetProgramBinary_t>(loader(b"glGetProgramBinary\0")),
+      glGetProgramInfoLog: core::mem::transmute::<*const c_void, glGetProgramInfoLog_t>(loader(b"glGetProgramInfoLog\0")),
+      glGetProgramInterfaceiv: core::mem::transmute::<*const c_void, glGetProgramInterfaceiv_t>(loader(b"glGetProgramInterfaceiv\0")),
+      glGetProgramPipelineInfoLog: core::mem::transmute::<*const c_void, glGetProgramPipelineInfoLog_t>(loader(b"glGetProgramPipelineInfoLog\0")),
+      glGetProgramPipelineiv: core::mem::transmute::<*const c_void, glGetProgramPipelineiv_t>(loader(b"glGetProgramPipelineiv\0")),
+      glGetProgramResourceIndex: core::mem::transmute::<*const c_void, glGetProgramResourceIndex_t>(loader(b"glGetProgramResourceIndex\0")),
+      glGetProgramResourceLocation: core::mem::transmute::<*const c_void, glGetProgramResourceLocation_t>(loader(b"glGetProgramResourceLocation\0")),
+      glGetProgramResourceLocationIndex: core::mem::transmute::<*const c_void, glGetProgramResourceLocationIndex_t>(loader(b"glGetProgramResourceLocationIndex\0")),
+      glGetProgramResourceName: core::mem::transmute::<*const c_void, glGetProgramResourceName_t>(loader(b"glGetProgramResourceName\0")),
+      glGetProgramResourceiv: core::mem::transmute::<*const c_void, glGetProgramResourceiv_t>(loader(b"glGetProgramResourceiv\0")),
+      glGetProgramStageiv: core::mem::transmute::<*const c_void, glGetProgramStageiv_t>(loader(b"glGetProgramStageiv\0")),
+      glGetProgramiv: core::mem::transmute::<*const c_void, glGetProgramiv_t>(loader(b"glGetProgramiv\0")),
+      glGetQueryBufferObjecti64v: core::mem::transmute::<*const c_void, glGetQueryBufferObjecti64v_t>(loader(b"glGetQueryBufferObjecti64v\0")),
+      glGetQueryBufferObjectiv: core::mem::transmute::<*const c_void, glGetQueryBufferObjectiv_t>(loader(b"glGetQueryBufferObjectiv\0")),
+      glGetQueryBufferObjectui64v: core::mem::transmute::<*const c_void, glGetQueryBufferObjectui64v_t>(loader(b"glGetQueryBufferObjectui64v\0")),
+      glGetQueryBufferObjectuiv: core::mem::transmute::<*const c_void, glGetQueryBufferObjectuiv_t>(loader(b"glGetQueryBufferObjectuiv\0")),
+      glGetQueryIndexediv: core::mem::transmute::<*const c_void, glGetQueryIndexediv_t>(loader(b"glGetQueryIndexediv\0")),
+      glGetQueryObjecti64v: core::mem::transmute::<*const c_void, glGetQueryObjecti64v_t>(loader(b"glGetQueryObjecti64v\0")),
+      glGetQueryObjectiv: core::mem::transmute::<*const c_void, glGetQueryObjectiv_t>(loader(b"glGetQueryObjectiv\0")),
+      glGetQueryObjectui64v: core::mem::transmute::<*const c_void, glGetQueryObjectui64v_t>(loader(b"glGetQueryObjectui64v\0")),
+      glGetQueryObjectuiv: core::mem::transmute::<*const c_void, glGetQueryObjectuiv_t>(loader(b"glGetQueryObjectuiv\0")),
+      glGetQueryiv: core::mem::transmute::<*const c_void, glGetQueryiv_t>(loader(b"glGetQueryiv\0")),
+      glGetRenderbufferParameteriv: core::mem::transmute::<*const c_void, glGetRenderbufferParameteriv_t>(loader(b"glGetRenderbufferParameteriv\0")),
+      glGetSamplerParameterIiv: core::mem::transmute::<*const c_void, glGetSamplerParameterIiv_t>(loader(b"glGetSamplerParameterIiv\0")),
+      glGetSamplerParameterIuiv: core::mem::transmute::<*const c_void, glGetSamplerParameterIuiv_t>(loader(b"glGetSamplerParameterIuiv\0")),
+      glGetSamplerParameterfv: core::mem::transmute::<*const c_void, glGetSamplerParameterfv_t>(loader(b"glGetSamplerParameterfv\0")),
+      glGetSamplerParameteriv: core::mem::transmute::<*const c_void, glGetSamplerParameteriv_t>(loader(b"glGetSamplerParameteriv\0")),
+      glGetShaderInfoLog: core::mem::transmute::<*const c_void, glGetShaderInfoLog_t>(loader(b"glGetShaderInfoLog\0")),
+      glGetShaderPrecisionFormat: core::mem::transmute::<*const c_void, glGetShaderPrecisionFormat_t>(loader(b"glGetShaderPrecisionFormat\0")),
+      glGetShaderSource: core::mem::transmute::<*const c_void, glGetShaderSource_t>(loader(b"glGetShaderSource\0")),
+      glGetShaderiv: core::mem::transmute::<*const c_void, glGetShaderiv_t>(loader(b"glGetShaderiv\0")),
+      glGetString: core::mem::transmute::<*const c_void, glGetString_t>(loader(b"glGetString\0")),
+      glGetStringi: core::mem::transmute::<*const c_void, glGetStringi_t>(loader(b"glGetStringi\0")),
+      glGetSubroutineIndex: core::mem::transmute::<*const c_void, glGetSubroutineIndex_t>(loader(b"glGetSubroutineIndex\0")),
+      glGetSubroutineUniformLocation: core::mem::transmute::<*const c_void, glGetSubroutineUniformLocation_t>(loader(b"glGetSubroutineUniformLocation\0")),
+      glGetSynciv: core::mem::transmute::<*const c_void, glGetSynciv_t>(loader(b"glGetSynciv\0")),
+      glGetTexImage: core::mem::transmute::<*const c_void, glGetTexImage_t>(loader(b"glGetTexImage\0")),
+      glGetTexLevelParameterfv: core::mem::transmute::<*const c_void, glGetTexLevelParameterfv_t>(loader(b"glGetTexLevelParameterfv\0")),
+      glGetTexLevelParameteriv: core::mem::transmute::<*const c_void, glGetTexLevelParameteriv_t>(loader(b"glGetTexLevelParameteriv\0")),
+      glGetTexParameterIiv: core::mem::transmute::<*const c_void, glGetTexParameterIiv_t>(loader(b"glGetTexParameterIiv\0")),
+      glGetTexParameterIuiv: core::mem::transmute::<*const c_void, glGetTexParameterIuiv_t>(loader(b"glGetTexParameterIuiv\0")),
+      glGetTexParameterfv: core::mem::transmute::<*const c_void, glGetTexParameterfv_t>(loader(b"glGetTexParameterfv\0")),
+      glGetTexParameteriv: core::mem::transmute::<*const c_void, glGetTexParameteriv_t>(loader(b"glGetTexParameteriv\0")),
+      glGetTextureImage: core::mem::transmute::<*const c_void, glGetTextureImage_t>(loader(b"glGetTextureImage\0")),
+      glGetTextureLevelParameterfv: core::mem::transmute::<*const c_void, glGetTextureLevelParameterfv_t>(loader(b"glGetTextureLevelParameterfv\0")),
+      glGetTextureLevelParameteriv: core::mem::transmute::<*const c_void, glGetTextureLevelParameteriv_t>(loader(b"glGetTextureLevelParameteriv\0")),
+      glGetTextureParameterIiv: core::mem::transmute::<*const c_void, glGetTextureParameterIiv_t>(loader(b"glGetTextureParameterIiv\0")),
+      glGetTextureParameterIuiv: core::mem::transmute::<*const c_void, glGetTextureParameterIuiv_t>(loader(b"glGetTextureParameterIuiv\0")),
+      glGetTextureParameterfv: core::mem::transmute::<*const c_void, glGetTextureParameterfv_t>(loader(b"glGetTextureParameterfv\0")),
+      glGetTextureParameteriv: core::mem::transmute::<*const c_void, glGetTextureParameteriv_t>(loader(b"glGetTextureParameteriv\0")),
+      glGetTextureSubImage: core::mem::transmute::<*const c_void, glGetTextureSubImage_t>(loader(b"glGetTextureSubImage\0")),
+      glGetTransformFeedbackVarying: core::mem::transmute::<*const c_void, glGetTransformFeedbackVarying_t>(loader(b"glGetTransformFeedbackVarying\0")),
+      glGetTransformFeedbacki64_v: core::mem::transmute::<*const c_void, glGetTransformFeedbacki64_v_t>(loader(b"glGetTransformFeedbacki64_v\0")),
+      glGetTransformFeedbacki_v: core::mem::transmute::<*const c_void, glGetTransformFeedbacki_v_t>(loader(b"glGetTransformFeedbacki_v\0")),
+      glGetTransformFeedbackiv: core::mem::transmute::<*const c_void, glGetTransformFeedbackiv_t>(loader(b"glGetTransformFeedbackiv\0")),
+      glGetUniformBlockIndex: core::mem::transmute::<*const c_void, glGetUniformBlockIndex_t>(loader(b"glGetUniformBlockIndex\0")),
+      glGetUniformIndices: core::mem::transmute::<*const c_void, glGetUniformIndices_t>(loader(b"glGetUniformIndices\0")),
+      glGetUniformLocation: core::mem::transmute::<*const c_void, glGetUniformLocation_t>(loader(b"glGetUniformLocation\0")),
+      glGetUniformSubroutineuiv: core::mem::transmute::<*const c_void, glGetUniformSubroutineuiv_t>(loader(b"glGetUniformSubroutineuiv\0")),
+      glGetUniformdv: core::mem::transmute::<*const c_void, glGetUniformdv_t>(loader(b"glGetUniformdv\0")),
+      glGetUniformfv: core::mem::transmute::<*const c_void, glGetUniformfv_t>(loader(b"glGetUniformfv\0")),
+      glGetUniformiv: core::mem::transmute::<*const c_void, glGetUniformiv_t>(loader(b"glGetUniformiv\0")),
+      glGetUniformuiv: core::mem::transmute::<*const c_void, glGetUniformuiv_t>(loader(b"glGetUniformuiv\0")),
+      glGetVertexArrayIndexed64iv: core::mem::transmute::<*const c_void, glGetVertexArrayIndexed64iv_t>(loader(b"glGetVertexArrayIndexed64iv\0")),
+      glGetVertexArrayIndexediv: core::mem::transmute::<*const c_void, glGetVertexArrayIndexediv_t>(loader(b"glGetVertexArrayIndexediv\0")),
+      glGetVertexArrayiv: core::mem::transmute::<*const c_void, glGetVertexArrayiv_t>(loader(b"glGetVertexArrayiv\0")),
+      glGetVertexAttribIiv: core::mem::transmute::<*const c_void, glGetVertexAttribIiv_t>(loader(b"glGetVertexAttribIiv\0")),
+      glGetVertexAttribIuiv: core::mem::transmute::<*const c_void, glGetVertexAttribIuiv_t>(loader(b"glGetVertexAttribIuiv\0")),
+      glGetVertexAttribLdv: core::mem::transmute::<*const c_void, glGetVertexAttribLdv_t>(loader(b"glGetVertexAttribLdv\0")),
+      glGetVertexAttribPointerv: core::mem::transmute::<*const c_void, glGetVertexAttribPointerv_t>(loader(b"glGetVertexAttribPointerv\0")),
+      glGetVertexAttribdv: core::mem::transmute::<*const c_void, glGetVertexAttribdv_t>(loader(b"glGetVertexAttribdv\0")),
+      glGetVertexAttribfv: core::mem::transmute::<*const c_void, glGetVertexAttribfv_t>(loader(b"glGetVertexAttribfv\0")),
+      glGetVertexAttribiv: core::mem::transmute::<*const c_void, glGetVertexAttribiv_t>(loader(b"glGetVertexAttribiv\0")),
+      glGetnCompressedTexImage: core::mem::transmute::<*const c_void, glGetnCompressedTexImage_t>(loader(b"glGetnCompressedTexImage\0")),
+      glGetnTexImage: core::mem::transmute::<*const c_void, glGetnTexImage_t>(loader(b"glGetnTexImage\0")),
+      glGetnUniformdv: core::mem::transmute::<*const c_void, glGetnUniformdv_t>(loader(b"glGetnUniformdv\0")),
+      glGetnUniformfv: core::mem::transmute::<*const c_void, glGetnUniformfv_t>(loader(b"glGetnUniformfv\0")),
+      glGetnUniformiv: core::mem::transmute::<*const c_void, glGetnUniformiv_t>(loader(b"glGetnUniformiv\0")),
+      glGetnUniformuiv: core::mem::transmute::<*const c_void, glGetnUniformuiv_t>(loader(b"glGetnUniformuiv\0")),
+      glHint: core::mem::transmute::<*const c_void, glHint_t>(loader(b"glHint\0")),
+      glInvalidateBufferData: core::mem::transmute::<*const c_void, glInvalidateBufferData_t>(loader(b"glInvalidateBufferData\0")),
+      glInvalidateBufferSubData: core::mem::transmute::<*const c_void, glInvalidateBufferSubData_t>(loader(b"glInvalidateBufferSubData\0")),
+      glInvalidateFramebuffer: core::mem::transmute::<*const c_void, glInvalidateFramebuffer_t>(loader(b"glInvalidateFramebuffer\0")),
+      glInvalidateNamedFramebufferData: core::mem::transmute::<*const c_void, glInvalidateNamedFramebufferData_t>(loader(b"glInvalidateNamedFramebufferData\0")),
+      glInvalidateNamedFramebufferSubData: core::mem::transmute::<*const c_void, glInvalidateNamedFramebufferSubData_t>(loader(b"glInvalidateNamedFramebufferSubData\0")),
+      glInvalidateSubFramebuffer: core::mem::transmute::<*const c_void, glInvalidateSubFramebuffer_t>(loader(b"glInvalidateSubFramebuffer\0")),
+      glInvalidateTexImage: core::mem::transmute::<*const c_void, glInvalidateTexImage_t>(loader(b"glInvalidateTexImage\0")),
+      glInvalidateTexSubImage: core::mem::transmute::<*const c_void, glInvalidateTexSubImage_t>(loader(b"glInvalidateTexSubImage\0")),
+      glIsBuffer: core::mem::transmute::<*const c_void, glIsBuffer_t>(loader(b"glIsBuffer\0")),
+      glIsEnabled: core::mem::transmute::<*const c_void, glIsEnabled_t>(loader(b"glIsEnabled\0")),
+      glIsEnabledi: core::mem::transmute::<*const c_void, glIsEnabledi_t>(loader(b"glIsEnabledi\0")),
+      glIsFramebuffer: core::mem::transmute::<*const c_void, glIsFramebuffer_t>(loader(b"glIsFramebuffer\0")),
+      glIsProgram: core::mem::transmute::<*const c_void, glIsProgram_t>(loader(b"glIsProgram\0")),
+      glIsProgramPipeline: core::mem::transmute::<*const c_void, glIsProgramPipeline_t>(loader(b"glIsProgramPipeline\0")),
+      glIsQuery: core::mem::transmute::<*const c_void, glIsQuery_t>(loader(b"glIsQuery\0")),
+      glIsRenderbuffer: core::mem::transmute::<*const c_void, glIsRenderbuffer_t>(loader(b"glIsRenderbuffer\0")),
+      glIsSampler: core::mem::transmute::<*const c_void, glIsSampler_t>(loader(b"glIsSampler\0")),
+      glIsShader: core::mem::transmute::<*const c_void, glIsShader_t>(loader(b"glIsShader\0")),
+      glIsSync: core::mem::transmute::<*const c_void, glIsSync_t>(loader(b"glIsSync\0")),
+      glIsTexture: core::mem::transmute::<*const c_void, glIsTexture_t>(loader(b"glIsTexture\0")),
+      glIsTransformFeedback: core::mem::transmute::<*const c_void, glIsTransformFeedback_t>(loader(b"glIsTransformFeedback\0")),
+      glIsVertexArray: core::mem::transmute::<*const c_void, glIsVertexArray_t>(loader(b"glIsVertexArray\0")),
+      glLineWidth: core::mem::transmute::<*const c_void, glLineWidth_t>(loader(b"glLineWidth\0")),
+      glLinkProgram: core::mem::transmute::<*const c_void, glLinkProgram_t>(loader(b"glLinkProgram\0")),
+      glLogicOp: core::mem::transmute::<*const c_void, glLogicOp_t>(loader(b"glLogicOp\0")),
+      glMapBuffer: core::mem::transmute::<*const c_void, glMapBuffer_t>(loader(b"glMapBuffer\0")),
+      glMapBufferRange: core::mem::transmute::<*const c_void, glMapBufferRange_t>(loader(b"glMapBufferRange\0")),
+      glMapNamedBuffer: core::mem::transmute::<*const c_void, glMapNamedBuffer_t>(loader(b"glMapNamedBuffer\0")),
+      glMapNamedBufferRange: core::mem::transmute::<*const c_void, glMapNamedBufferRange_t>(loader(b"glMapNamedBufferRange\0")),
+      glMemoryBarrier: core::mem::transmute::<*const c_void, glMemoryBarrier_t>(loader(b"glMemoryBarrier\0")),
+      glMemoryBarrierByRegion: core::mem::transmute::<*const c_void, glMemoryBarrierByRegion_t>(loader(b"glMemoryBarrierByRegion\0")),
+      glMinSampleShading: core::mem::transmute::<*const c_void, glMinSampleShading_t>(loader(b"glMinSampleShading\0")),
+      glMultiDrawArrays: core::mem::transmute::<*const c_void, glMultiDrawArrays_t>(loader(b"glMultiDrawArrays\0")),
+      glMultiDrawArraysIndirect: core::mem::transmute::<*const c_void, glMultiDrawArraysIndirect_t>(loader(b"glMultiDrawArraysIndirect\0")),
+      glMultiDrawArraysIndirectCount: core::mem::transmute::<*const c_void, glMultiDrawArraysIndirectCount_t>(loader(b"glMultiDrawArraysIndirectCount\0")),
+      glMultiDrawElements: core::mem::transmute::<*const c_void, glMultiDrawElements_t>(loader(b"glMultiDrawElements\0")),
+      glMultiDrawElementsBaseVertex: core::mem::transmute::<*const c_void, glMultiDrawElementsBaseVertex_t>(loader(b"glMultiDrawElementsBaseVertex\0")),
+      glMultiDrawElementsIndirect: core::mem::transmute::<*const c_void, glMultiDrawElementsIndirect_t>(loader(b"glMultiDrawElementsIndirect\0")),
+      glMultiDrawElementsIndirectCount: core::mem::transmute::<*const c_void, glMultiDrawElementsIndirectCount_t>(loader(b"glMultiDrawElementsIndirectCount\0")),
+      glNamedBufferData: core::mem::transmute::<*const c_void, glNamedBufferData_t>(loader(b"glNamedBufferData\0")),
+      glNamedBufferStorage: core::mem::transmute::<*const c_void, glNamedBufferStorage_t>(loader(b"glNamedBufferStorage\0")),
+      glNamedBufferSubData: core::mem::transmute::<*const c_void, glNamedBufferSubData_t>(loader(b"glNamedBufferSubData\0")),
+      glNamedFramebufferDrawBuffer: core::mem::transmute::<*const c_void, glNamedFramebufferDrawBuffer_t>(loader(b"glNamedFramebufferDrawBuffer\0")),
+      glNamedFramebufferDrawBuffers: core::mem::transmute::<*const c_void, glNamedFramebufferDrawBuffers_t>(loader(b"glNamedFramebufferDrawBuffers\0")),
+      glNamedFramebufferParameteri: core::mem::transmute::<*const c_void, glNamedFramebufferParameteri_t>(loader(b"glNamedFramebufferParameteri\0")),
+      glNamedFramebufferReadBuffer: core::mem::transmute::<*const c_void, glNamedFramebufferReadBuffer_t>(loader(b"glNamedFramebufferReadBuffer\0")),
+      glNamedFramebufferRenderbuffer: core::mem::transmute::<*const c_void, glNamedFramebufferRenderbuffer_t>(loader(b"glNamedFramebufferRenderbuffer\0")),
+      glNamedFramebufferTexture: core::mem::transmute::<*const c_void, glNamedFramebufferTexture_t>(loader(b"glNamedFramebufferTexture\0")),
+      glNamedFramebufferTextureLayer: core::mem::transmute::<*const c_void, glNamedFramebufferTextureLayer_t>(loader(b"glNamedFramebufferTextureLayer\0")),
+      glNamedRenderbufferStorage: core::mem::transmute::<*const c_void, glNamedRenderbufferStorage_t>(loader(b"glNamedRenderbufferStorage\0")),
+      glNamedRenderbufferStorageMultisample: core::mem::transmute::<*const c_void, glNamedRenderbufferStorageMultisample_t>(loader(b"glNamedRenderbufferStorageMultisample\0")),
+      glObjectLabel: core::mem::transmute::<*const c_void, glObjectLabel_t>(loader(b"glObjectLabel\0")),
+      glObjectPtrLabel: core::mem::transmute::<*const c_void, glObjectPtrLabel_t>(loader(b"glObjectPtrLabel\0")),
+      glPatchParameterfv: core::mem::transmute::<*const c_void, glPatchParameterfv_t>(loader(b"glPatchParameterfv\0")),
+      glPatchParameteri: core::mem::transmute::<*const c_void, glPatchParameteri_t>(loader(b"glPatchParameteri\0")),
+      glPauseTransformFeedback: core::mem::transmute::<*const c_void, glPauseTransformFeedback_t>(loader(b"glPauseTransformFeedback\0")),
+      glPixelStoref: core::mem::transmute::<*const c_void, glPixelStoref_t>(loader(b"glPixelStoref\0")),
+      glPixelStorei: core::mem::transmute::<*const c_void, glPixelStorei_t>(loader(b"glPixelStorei\0")),
+      glPointParameterf: core::mem::transmute::<*const c_void, glPointParameterf_t>(loader(b"glPointParameterf\0")),
+      glPointParameterfv: core::mem::transmute::<*const c_void, glPointParameterfv_t>(loader(b"glPointParameterfv\0")),
+      glPointParameteri: core::mem::transmute::<*const c_void, glPointParameteri_t>(loader(b"glPointParameteri\0")),
+      glPointParameteriv: core::mem::transmute::<*const c_void, glPointParameteriv_t>(loader(b"glPointParameteriv\0")),
+      glPointSize: core::mem::transmute::<*const c_void, glPointSize_t>(loader(b"glPointSize\0")),
+      glPolygonMode: core::mem::transmute::<*const c_void, glPolygonMode_t>(loader(b"glPolygonMode\0")),
+      glPolygonOffset: core::mem::transmute::<*const c_void, glPolygonOffset_t>(loader(b"glPolygonOffset\0")),
+      glPolygonOffsetClamp: core::mem::transmute::<*const c_void, glPolygonOffsetClamp_t>(loader(b"glPolygonOffsetClamp\0")),
+      glPopDebugGroup: core::mem::transmute::<*const c_void, glPopDebugGroup_t>(loader(b"glPopDebugGroup\0")),
+      glPrimitiveRestartIndex: core::mem::transmute::<*const c_void, glPrimitiveRestartIndex_t>(loader(b"glPrimitiveRestartIndex\0")),
+      glProgramBinary: core::mem::transmute::<*const c_void, glProgramBinary_t>(loader(b"glProgramBinary\0")),
+      glProgramParameteri: core::mem::transmute::<*const c_void, glProgramParameteri_t>(loader(b"glProgramParameteri\0")),
+      glProgramUniform1d: core::mem::transmute::<*const c_void, glProgramUniform1d_t>(loader(b"glProgramUniform1d\0")),
+      glProgramUniform1dv: core::mem::transmute::<*const c_void, glProgramUniform1dv_t>(loader(b"glProgramUniform1dv\0")),
+      glProgramUniform1f: core::mem::transmute::<*const c_void, glProgramUniform1f_t>(loader(b"glProgramUniform1f\0")),
+      glProgramUniform1fv: core::mem::transmute::<*const c_void, glProgramUniform1fv_t>(loader(b"glProgramUniform1fv\0")),
+      glProgramUniform1i: core::mem::transmute::<*const c_void, glProgramUniform1i_t>(loader(b"glProgramUniform1i\0")),
+      glProgramUniform1iv: core::mem::transmute::<*const c_void, glProgramUniform1iv_t>(loader(b"glProgramUniform1iv\0")),
+      glProgramUniform1ui: core::mem::transmute::<*const c_void, glProgramUniform1ui_t>(loader(b"glProgramUniform1ui\0")),
+      glProgramUniform1uiv: core::mem::transmute::<*const c_void, glProgramUniform1uiv_t>(loader(b"glProgramUniform1uiv\0")),
+      glProgramUniform2d: core::mem::transmute::<*const c_void, glProgramUniform2d_t>(loader(b"glProgramUniform2d\0")),
+      glProgramUniform2dv: core::mem::transmute::<*const c_void, glProgramUniform2dv_t>(loader(b"glProgramUniform2dv\0")),
+      glProgramUniform2f: core::mem::transmute::<*const c_void, glProgramUniform2f_t>(loader(b"glProgramUniform2f\0")),
+      glProgramUniform2fv: core::mem::transmute::<*const c_void, glProgramUniform2fv_t>(loader(b"glProgramUniform2fv\0")),
+      glProgramUniform2i: core::mem::transmute::<*const c_void, glProgramUniform2i_t>(loader(b"glProgramUniform2i\0")),
+      glProgramUniform2iv: core::mem::transmute::<*const c_void, glProgramUniform2iv_t>(loader(b"glProgramUniform2iv\0")),
+      glProgramUniform2ui: core::mem::transmute::<*const c_void, glProgramUniform2ui_t>(loader(b"glProgramUniform2ui\0")),
+      glProgramUniform2uiv: core::mem::transmute::<*const c_void, glProgramUniform2uiv_t>(loader(b"glProgramUniform2uiv\0")),
+      glProgramUniform3d: core::mem::transmute::<*const c_void, glProgramUniform3d_t>(loader(b"glProgramUniform3d\0")),
+      glProgramUniform3dv: core::mem::transmute::<*const c_void, glProgramUniform3dv_t>(loader(b"glProgramUniform3dv\0")),
+      glProgramUniform3f: core::mem::transmute::<*const c_void, glProgramUniform3f_t>(loader(b"glProgramUniform3f\0")),
+      glProgramUniform3fv: core::mem::transmute::<*const c_void, glProgramUniform3fv_t>(loader(b"glProgramUniform3fv\0")),
+      glProgramUniform3i: core::mem::transmute::<*const c_void, glProgramUniform3i_t>(loader(b"glProgramUniform3i\0")),
+      glProgramUniform3iv: core::mem::transmute::<*const c_void, glProgramUniform3iv_t>(loader(b"glProgramUniform3iv\0")),
+      glProgramUniform3ui: core::mem::transmute::<*const c_void, glProgramUniform3ui_t>(loader(b"glProgramUniform3ui\0")),
+      glProgramUniform3uiv: core::mem::transmute::<*const c_void, glProgramUniform3uiv_t>(loader(b"glProgramUniform3uiv\0")),
+      glProgramUniform4d: core::mem::transmute::<*const c_void, glProgramUniform4d_t>(loader(b"glProgramUniform4d\0")),
+      glProgramUniform4dv: core::mem::transmute::<*const c_void, glProgramUniform4dv_t>(loader(b"glProgramUniform4dv\0")),
+      glProgramUniform4f: core::mem::transmute::<*const c_void, glProgramUniform4f_t>(loader(b"glProgramUniform4f\0")),
+      glProgramUniform4fv: core::mem::transmute::<*const c_void, glProgramUniform4fv_t>(loader(b"glProgramUniform4fv\0")),
+      glProgramUniform4i: core::mem::transmute::<*const c_void, glProgramUniform4i_t>(loader(b"glProgramUniform4i\0")),
+      glProgramUniform4iv: core::mem::transmute::<*const c_void, glProgramUniform4iv_t>(loader(b"glProgramUniform4iv\0")),
+      glProgramUniform4ui: core::mem::transmute::<*const c_void, glProgramUniform4ui_t>(loader(b"glProgramUniform4ui\0")),
+      glProgramUniform4uiv: core::mem::transmute::<*const c_void, glProgramUniform4uiv_t>(loader(b"glProgramUniform4uiv\0")),
+      glProgramUniformMatrix2dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix2dv_t>(loader(b"glProgramUniformMatrix2dv\0")),
+      glProgramUniformMatrix2fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix2fv_t>(loader(b"glProgramUniformMatrix2fv\0")),
+      glProgramUniformMatrix2x3dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix2x3dv_t>(loader(b"glProgramUniformMatrix2x3dv\0")),
+      glProgramUniformMatrix2x3fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix2x3fv_t>(loader(b"glProgramUniformMatrix2x3fv\0")),
+      glProgramUniformMatrix2x4dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix2x4dv_t>(loader(b"glProgramUniformMatrix2x4dv\0")),
+      glProgramUniformMatrix2x4fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix2x4fv_t>(loader(b"glProgramUniformMatrix2x4fv\0")),
+      glProgramUniformMatrix3dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix3dv_t>(loader(b"glProgramUniformMatrix3dv\0")),
+      glProgramUniformMatrix3fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix3fv_t>(loader(b"glProgramUniformMatrix3fv\0")),
+      glProgramUniformMatrix3x2dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix3x2dv_t>(loader(b"glProgramUniformMatrix3x2dv\0")),
+      glProgramUniformMatrix3x2fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix3x2fv_t>(loader(b"glProgramUniformMatrix3x2fv\0")),
+      glProgramUniformMatrix3x4dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix3x4dv_t>(loader(b"glProgramUniformMatrix3x4dv\0")),
+      glProgramUniformMatrix3x4fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix3x4fv_t>(loader(b"glProgramUniformMatrix3x4fv\0")),
+      glProgramUniformMatrix4dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix4dv_t>(loader(b"glProgramUniformMatrix4dv\0")),
+      glProgramUniformMatrix4fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix4fv_t>(loader(b"glProgramUniformMatrix4fv\0")),
+      glProgramUniformMatrix4x2dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix4x2dv_t>(loader(b"glProgramUniformMatrix4x2dv\0")),
+      glProgramUniformMatrix4x2fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix4x2fv_t>(loader(b"glProgramUniformMatrix4x2fv\0")),
+      glProgramUniformMatrix4x3dv: core::mem::transmute::<*const c_void, glProgramUniformMatrix4x3dv_t>(loader(b"glProgramUniformMatrix4x3dv\0")),
+      glProgramUniformMatrix4x3fv: core::mem::transmute::<*const c_void, glProgramUniformMatrix4x3fv_t>(loader(b"glProgramUniformMatrix4x3fv\0")),
+      glProvokingVertex: core::mem::transmute::<*const c_void, glProvokingVertex_t>(loader(b"glProvokingVertex\0")),
+      glPushDebugGroup: core::mem::transmute::<*const c_void, glPushDebugGroup_t>(loader(b"glPushDebugGroup\0")),
+      glQueryCounter: core::mem::transmute::<*const c_void, glQueryCounter_t>(loader(b"glQueryCounter\0")),
+      glReadBuffer: core::mem::transmute::<*const c_void, glReadBuffer_t>(loader(b"glReadBuffer\0")),
+      glReadPixels: core::mem::transmute::<*const c_void, glReadPixels_t>(loader(b"glReadPixels\0")),
+      glReadnPixels: core::mem::transmute::<*const c_void, glReadnPixels_t>(loader(b"glReadnPixels\0")),
+      glReleaseShaderCompiler: core::mem::transmute::<*const c_void, glReleaseShaderCompiler_t>(loader(b"glReleaseShaderCompiler\0")),
+      glRenderbufferStorage: core::mem::transmute::<*const c_void, glRenderbufferStorage_t>(loader(b"glRenderbufferStorage\0")),
+      glRenderbufferStorageMultisample: core::mem::transmute::<*const c_void, glRenderbufferStorageMultisample_t>(loader(b"glRenderbufferStorageMultisample\0")),
+      glResumeTransformFeedback: core::mem::transmute::<*const c_void, glResumeTransformFeedback_t>(loader(b"glResumeTransformFeedback\0")),
+      glSampleCoverage: core::mem::transmute::<*const c_void, glSampleCoverage_t>(loader(b"glSampleCoverage\0")),
+      glSampleMaski: core::mem::transmute::<*const c_void, glSampleMaski_t>(loader(b"glSampleMaski\0")),
+      glSamplerParameterIiv: core::mem::transmute::<*const c_void, glSamplerParameterIiv_t>(loader(b"glSamplerParameterIiv\0")),
+      glSamplerParameterIuiv: core::mem::transmute::<*const c_void, glSamplerParameterIuiv_t>(loader(b"glSamplerParameterIuiv\0")),
+      glSamplerParameterf: core::mem::transmute::<*const c_void, glSamplerParameterf_t>(loader(b"glSamplerParameterf\0")),
+      glSamplerParameterfv: core::mem::transmute::<*const c_void, glSamplerParameterfv_t>(loader(b"glSamplerParameterfv\0")),
+      glSamplerParameteri: core::mem::transmute::<*const c_void, glSamplerParameteri_t>(loader(b"glSamplerParameteri\0")),
+      glSamplerParameteriv: core::mem::transmute::<*const c_void, glSamplerParameteriv_t>(loader(b"glSamplerParameteriv\0")),
+      glScissor: core::mem::transmute::<*const c_void, glScissor_t>(loader(b"glScissor\0")),
+      glScissorArrayv: core::mem::transmute::<*const c_void, glScissorArrayv_t>(loader(b"glScissorArrayv\0")),
+      glScissorIndexed: core::mem::transmute::<*const c_void, glScissorIndexed_t>(loader(b"glScissorIndexed\0")),
+      glScissorIndexedv: core::mem::transmute::<*const c_void, glScissorIndexedv_t>(loader(b"glScissorIndexedv\0")),
+      glShaderBinary: core::mem::transmute::<*const c_void, glShaderBinary_t>(loader(b"glShaderBinary\0")),
+      glShaderSource: core::mem::transmute::<*const c_void, glShaderSource_t>(loader(b"glShaderSource\0")),
+      glShaderStorageBlockBinding: core::mem::transmute::<*const c_void, glShaderStorageBlockBinding_t>(loader(b"glShaderStorageBlockBinding\0")),
+      glSpecializeShader: core::mem::transmute::<*const c_void, glSpecializeShader_t>(loader(b"glSpecializeShader\0")),
+      glStencilFunc: core::mem::transmute::<*const c_void, glStencilFunc_t>(loader(b"glStencilFunc\0")),
+      glStencilFuncSeparate: core::mem::transmute::<*const c_void, glStencilFuncSeparate_t>(loader(b"glStencilFuncSeparate\0")),
+      glStencilMask: core::mem::transmute::<*const c_void, glStencilMask_t>(loader(b"glStencilMask\0")),
+      glStencilMaskSeparate: core::mem::transmute::<*const c_void, glStencilMaskSeparate_t>(loader(b"glStencilMaskSeparate\0")),
+      glStencilOp: core::mem::transmute::<*const c_void, glStencilOp_t>(loader(b"glStencilOp\0")),
+      glStencilOpSeparate: core::mem::transmute::<*const c_void, glStencilOpSeparate_t>(loader(b"glStencilOpSeparate\0")),
+      glTexBuffer: core::mem::transmute::<*const c_void, glTexBuffer_t>(loader(b"glTexBuffer\0")),
+      glTexBufferRange: core::mem::transmute::<*const c_void, glTexBufferRange_t>(loader(b"glTexBufferRange\0")),
+      glTexImage1D: core::mem::transmute::<*const c_void, glTexImage1D_t>(loader(b"glTexImage1D\0")),
+      glTexImage2D: core::mem::transmute::<*const c_void, glTexImage2D_t>(loader(b"glTexImage2D\0")),
+      glTexImage2DMultisample: core::mem::transmute::<*const c_void, glTexImage2DMultisample_t>(loader(b"glTexImage2DMultisample\0")),
+      glTexImage3D: core::mem::transmute::<*const c_void, glTexImage3D_t>(loader(b"glTexImage3D\0")),
+      glTexImage3DMultisample: core::mem::transmute::<*const c_void, glTexImage3DMultisample_t>(loader(b"glTexImage3DMultisample\0")),
+      glTexParameterIiv: core::mem::transmute::<*const c_void, glTexParameterIiv_t>(loader(b"glTexParameterIiv\0")),
+      glTexParameterIuiv: core::mem::transmute::<*const c_void, glTexParameterIuiv_t>(loader(b"glTexParameterIuiv\0")),
+      glTexParameterf: core::mem::transmute::<*const c_void, glTexParameterf_t>(loader(b"glTexParameterf\0")),
+      glTexParameterfv: core::mem::transmute::<*const c_void, glTexParameterfv_t>(loader(b"glTexParameterfv\0")),
+      glTexParameteri: core::mem::transmute::<*const c_void, glTexParameteri_t>(loader(b"glTexParameteri\0")),
+      glTexParameteriv: core::mem::transmute::<*const c_void, glTexParameteriv_t>(loader(b"glTexParameteriv\0")),
+      glTexStorage1D: core::mem::transmute::<*const c_void, glTexStorage1D_t>(loader(b"glTexStorage1D\0")),
+      glTexStorage2D: core::mem::transmute::<*const c_void, glTexStorage2D_t>(loader(b"glTexStorage2D\0")),
+      glTexStorage2DMultisample: core::mem::transmute::<*const c_void, glTexStorage2DMultisample_t>(loader(b"glTexStorage2DMultisample\0")),
+      glTexStorage3D: core::mem::transmute::<*const c_void, glTexStorage3D_t>(loader(b"glTexStorage3D\0")),
+      glTexStorage3DMultisample: core::mem::transmute::<*const c_void, glTexStorage3DMultisample_t>(loader(b"glTexStorage3DMultisample\0")),
+      glTexSubImage1D: core::mem::transmute::<*const c_void, glTexSubImage1D_t>(loader(b"glTexSubImage1D\0")),
+      glTexSubImage2D: core::mem::transmute::<*const c_void, glTexSubImage2D_t>(loader(b"glTexSubImage2D\0")),
+      glTexSubImage3D: core::mem::transmute::<*const c_void, glTexSubImage3D_t>(loader(b"glTexSubImage3D\0")),
+      glTextureBarrier: core::mem::transmute::<*const c_void, glTextureBarrier_t>(loader(b"glTextureBarrier\0")),
+      glTextureBuffer: core::mem::transmute::<*const c_void, glTextureBuffer_t>(loader(b"glTextureBuffer\0")),
+      glTextureBufferRange: core::mem::transmute::<*const c_void, glTextureBufferRange_t>(loader(b"glTextureBufferRange\0")),
+      glTextureParameterIiv: core::mem::transmute::<*const c_void, glTextureParameterIiv_t>(loader(b"glTextureParameterIiv\0")),
+      glTextureParameterIuiv: core::mem::transmute::<*const c_void, glTextureParameterIuiv_t>(loader(b"glTextureParameterIuiv\0")),
+      glTextureParameterf: core::mem::transmute::<*const c_void, glTextureParameterf_t>(loader(b"glTextureParameterf\0")),
+      glTextureParameterfv: core::mem::transmute::<*const c_void, glTextureParameterfv_t>(loader(b"glTextureParameterfv\0")),
+      glTextureParameteri: core::mem::transmute::<*const c_void, glTextureParameteri_t>(loader(b"glTextureParameteri\0")),
+      glTextureParameteriv: core::mem::transmute::<*const c_void, glTextureParameteriv_t>(loader(b"glTextureParameteriv\0")),
+      glTextureStorage1D: core::mem::transmute::<*const c_void, glTextureStorage1D_t>(loader(b"glTextureStorage1D\0")),
+      glTextureStorage2D: core::mem::transmute::<*const c_void, glTextureStorage2D_t>(loader(b"glTextureStorage2D\0")),
+      glTextureStorage2DMultisample: core::mem::transmute::<*const c_void, glTextureStorage2DMultisample_t>(loader(b"glTextureStorage2DMultisample\0")),
+      glTextureStorage3D: core::mem::transmute::<*const c_void, glTextureStorage3D_t>(loader(b"glTextureStorage3D\0")),
+      glTextureStorage3DMultisample: core::mem::transmute::<*const c_void, glTextureStorage3DMultisample_t>(loader(b"glTextureStorage3DMultisample\0")),
+      glTextureSubImage1D: core::mem::transmute::<*const c_void, glTextureSubImage1D_t>(loader(b"glTextureSubImage1D\0")),
+      glTextureSubImage2D: core::mem::transmute::<*const c_void, glTextureSubImage2D_t>(loader(b"glTextureSubImage2D\0")),
+      glTextureSubImage3D: core::mem::transmute::<*const c_void, glTextureSubImage3D_t>(loader(b"glTextureSubImage3D\0")),
+      glTextureView: core::mem::transmute::<*const c_void, glTextureView_t>(loader(b"glTextureView\0")),
+      glTransformFeedbackBufferBase: core::mem::transmute::<*const c_void, glTransformFeedbackBufferBase_t>(loader(b"glTransformFeedbackBufferBase\0")),
+      glTransformFeedbackBufferRange: core::mem::transmute::<*const c_void, glTransformFeedbackBufferRange_t>(loader(b"glTransformFeedbackBufferRange\0")),
+      glTransformFeedbackVaryings: core::mem::transmute::<*const c_void, glTransformFeedbackVaryings_t>(loader(b"glTransformFeedbackVaryings\0")),
+      glUniform1d: core::mem::transmute::<*const c_void, glUniform1d_t>(loader(b"glUniform1d\0")),
+      glUniform1dv: core::mem::transmute::<*const c_void, glUniform1dv_t>(loader(b"glUniform1dv\0")),
+      glUniform1f: core::mem::transmute::<*const c_void, glUniform1f_t>(loader(b"glUniform1f\0")),
+      glUniform1fv: core::mem::transmute::<*const c_void, glUniform1fv_t>(loader(b"glUniform1fv\0")),
+      glUniform1i: core::mem::transmute::<*const c_void, glUniform1i_t>(loader(b"glUniform1i\0")),
+      glUniform1iv: core::mem::transmute::<*const c_void, glUniform1iv_t>(loader(b"glUniform1iv\0")),
+      glUniform1ui: core::mem::transmute::<*const c_void, glUniform1ui_t>(loader(b"glUniform1ui\0")),
+      glUniform1uiv: core::mem::transmute::<*const c_void, glUniform1uiv_t>(loader(b"glUniform1uiv\0")),
+      glUniform2d: core::mem::transmute::<*const c_void, glUniform2d_t>(loader(b"glUniform2d\0")),
+      glUniform2dv: core::mem::transmute::<*const c_void, glUniform2dv_t>(loader(b"glUniform2dv\0")),
+      glUniform2f: core::mem::transmute::<*const c_void, glUniform2f_t>(loader(b"glUniform2f\0")),
+      glUniform2fv: core::mem::transmute::<*const c_void, glUniform2fv_t>(loader(b"glUniform2fv\0")),
+      glUniform2i: core::mem::transmute::<*const c_void, glUniform2i_t>(loader(b"glUniform2i\0")),
+      glUniform2iv: core::mem::transmute::<*const c_void, glUniform2iv_t>(loader(b"glUniform2iv\0")),
+      glUniform2ui: core::mem::transmute::<*const c_void, glUniform2ui_t>(loader(b"glUniform2ui\0")),
+      glUniform2uiv: core::mem::transmute::<*const c_void, glUniform2uiv_t>(loader(b"glUniform2uiv\0")),
+      glUniform3d: core::mem::transmute::<*const c_void, glUniform3d_t>(loader(b"glUniform3d\0")),
+      glUniform3dv: core::mem::transmute::<*const c_void, glUniform3dv_t>(loader(b"glUniform3dv\0")),
+      glUniform3f: core::mem::transmute::<*const c_void, glUniform3f_t>(loader(b"glUniform3f\0")),
+      glUniform3fv: core::mem::transmute::<*const c_void, glUniform3fv_t>(loader(b"glUniform3fv\0")),
+      glUniform3i: core::mem::transmute::<*const c_void, glUniform3i_t>(loader(b"glUniform3i\0")),
+      glUniform3iv: core::mem::transmute::<*const c_void, glUniform3iv_t>(loader(b"glUniform3iv\0")),
+      glUniform3ui: core::mem::transmute::<*const c_void, glUniform3ui_t>(loader(b"glUniform3ui\0")),
+      glUniform3uiv: core::mem::transmute::<*const c_void, glUniform3uiv_t>(loader(b"glUniform3uiv\0")),
+      glUniform4d: core::mem::transmute::<*const c_void, glUniform4d_t>(loader(b"glUniform4d\0")),
+      glUniform4dv: core::mem::transmute::<*const c_void, glUniform4dv_t>(loader(b"glUniform4dv\0")),
+      glUniform4f: core::mem::transmute::<*const c_void, glUniform4f_t>(loader(b"glUniform4f\0")),
+      glUniform4fv: core::mem::transmute::<*const c_void, glUniform4fv_t>(loader(b"glUniform4fv\0")),
+      glUniform4i: core::mem::transmute::<*const c_void, glUniform4i_t>(loader(b"glUniform4i\0")),
+      glUniform4iv: core::mem::transmute::<*const c_void, glUniform4iv_t>(loader(b"glUniform4iv\0")),
+      glUniform4ui: core::mem::transmute::<*const c_void, glUniform4ui_t>(loader(b"glUniform4ui\0")),
+      glUniform4uiv: core::mem::transmute::<*const c_void, glUniform4uiv_t>(loader(b"glUniform4uiv\0")),
+      glUniformBlockBinding: core::mem::transmute::<*const c_void, glUniformBlockBinding_t>(loader(b"glUniformBlockBinding\0")),
+      glUniformMatrix2dv: core::mem::transmute::<*const c_void, glUniformMatrix2dv_t>(loader(b"glUniformMatrix2dv\0")),
+      glUniformMatrix2fv: core::mem::transmute::<*const c_void, glUniformMatrix2fv_t>(loader(b"glUniformMatrix2fv\0")),
+      glUniformMatrix2x3dv: core::mem::transmute::<*const c_void, glUniformMatrix2x3dv_t>(loader(b"glUniformMatrix2x3dv\0")),
+      glUniformMatrix2x3fv: core::mem::transmute::<*const c_void, glUniformMatrix2x3fv_t>(loader(b"glUniformMatrix2x3fv\0")),
+      glUniformMatrix2x4dv: core::mem::transmute::<*const c_void, glUniformMatrix2x4dv_t>(loader(b"glUniformMatrix2x4dv\0")),
+      glUniformMatrix2x4fv: core::mem::transmute::<*const c_void, glUniformMatrix2x4fv_t>(loader(b"glUniformMatrix2x4fv\0")),
+      glUniformMatrix3dv: core::mem::transmute::<*const c_void, glUniformMatrix3dv_t>(loader(b"glUniformMatrix3dv\0")),
+      glUniformMatrix3fv: core::mem::transmute::<*const c_void, glUniformMatrix3fv_t>(loader(b"glUniformMatrix3fv\0")),
+      glUniformMatrix3x2dv: core::mem::transmute::<*const c_void, glUniformMatrix3x2dv_t>(loader(b"glUniformMatrix3x2dv\0")),
+      glUniformMatrix3x2fv: core::mem::transmute::<*const c_void, glUniformMatrix3x2fv_t>(loader(b"glUniformMatrix3x2fv\0")),
+      glUniformMatrix3x4dv: core::mem::transmute::<*const c_void, glUniformMatrix3x4dv_t>(loader(b"glUniformMatrix3x4dv\0")),
+      glUniformMatrix3x4fv: core::mem::transmute::<*const c_void, glUniformMatrix3x4fv_t>(loader(b"glUniformMatrix3x4fv\0")),
+      glUniformMatrix4dv: core::mem::transmute::<*const c_void, glUniformMatrix4dv_t>(loader(b"glUniformMatrix4dv\0")),
+      glUniformMatrix4fv: core::mem::transmute::<*const c_void, glUniformMatrix4fv_t>(loader(b"glUniformMatrix4fv\0")),
+      glUniformMatrix4x2dv: core::mem::transmute::<*const c_void, glUniformMatrix4x2dv_t>(loader(b"glUniformMatrix4x2dv\0")),
+      glUniformMatrix4x2fv: core::mem::transmute::<*const c_void, glUniformMatrix4x2fv_t>(loader(b"glUniformMatrix4x2fv\0")),
+      glUniformMatrix4x3dv: core::mem::transmute::<*const c_void, glUniformMatrix4x3dv_t>(loader(b"glUniformMatrix4x3dv\0")),
+      glUniformMatrix4x3fv: core::mem::transmute::<*const c_void, glUniformMatrix4x3fv_t>(loader(b"glUniformMatrix4x3fv\0")),
+      glUniformSubroutinesuiv: core::mem::transmute::<*const c_void, glUniformSubroutinesuiv_t>(loader(b"glUniformSubroutinesuiv\0")),
+      glUnmapBuffer: core::mem::transmute::<*const c_void, glUnmapBuffer_t>(loader(b"glUnmapBuffer\0")),
+      glUnmapNamedBuffer: core::mem::transmute::<*const c_void, glUnmapNamedBuffer_t>(loader(b"glUnmapNamedBuffer\0")),
+      glUseProgram: core::mem::transmute::<*const c_void, glUseProgram_t>(loader(b"glUseProgram\0")),
+      glUseProgramStages: core::mem::transmute::<*const c_void, glUseProgramStages_t>(loader(b"glUseProgramStages\0")),
+      glValidateProgram: core::mem::transmute::<*const c_void, glValidateProgram_t>(loader(b"glValidateProgram\0")),
+      glValidateProgramPipeline: core::mem::transmute::<*const c_void, glValidateProgramPipeline_t>(loader(b"glValidateProgramPipeline\0")),
+      glVertexArrayAttribBinding: core::mem::transmute::<*const c_void, glVertexArrayAttribBinding_t>(loader(b"glVertexArrayAttribBinding\0")),
+      glVertexArrayAttribFormat: core::mem::transmute::<*const c_void, glVertexArrayAttribFormat_t>(loader(b"glVertexArrayAttribFormat\0")),
+      glVertexArrayAttribIFormat: core::mem::transmute::<*const c_void, glVertexArrayAttribIFormat_t>(loader(b"glVertexArrayAttribIFormat\0")),
+      glVertexArrayAttribLFormat: core::mem::transmute::<*const c_void, glVertexArrayAttribLFormat_t>(loader(b"glVertexArrayAttribLFormat\0")),
+      glVertexArrayBindingDivisor: core::mem::transmute::<*const c_void, glVertexArrayBindingDivisor_t>(loader(b"glVertexArrayBindingDivisor\0")),
+      glVertexArrayElementBuffer: core::mem::transmute::<*const c_void, glVertexArrayElementBuffer_t>(loader(b"glVertexArrayElementBuffer\0")),
+      glVertexArrayVertexBuffer: core::mem::transmute::<*const c_void, glVertexArrayVertexBuffer_t>(loader(b"glVertexArrayVertexBuffer\0")),
+      glVertexArrayVertexBuffers: core::mem::transmute::<*const c_void, glVertexArrayVertexBuffers_t>(loader(b"glVertexArrayVertexBuffers\0")),
+      glVertexAttrib1d: core::mem::transmute::<*const c_void, glVertexAttrib1d_t>(loader(b"glVertexAttrib1d\0")),
+      glVertexAttrib1dv: core::mem::transmute::<*const c_void, glVertexAttrib1dv_t>(loader(b"glVertexAttrib1dv\0")),
+      glVertexAttrib1f: core::mem::transmute::<*const c_void, glVertexAttrib1f_t>(loader(b"glVertexAttrib1f\0")),
+      glVertexAttrib1fv: core::mem::transmute::<*const c_void, glVertexAttrib1fv_t>(loader(b"glVertexAttrib1fv\0")),
+      glVertexAttrib1s: core::mem::transmute::<*const c_void, glVertexAttrib1s_t>(loader(b"glVertexAttrib1s\0")),
+      glVertexAttrib1sv: core::mem::transmute::<*const c_void, glVertexAttrib1sv_t>(loader(b"glVertexAttrib1sv\0")),
+      glVertexAttrib2d: core::mem::transmute::<*const c_void, glVertexAttrib2d_t>(loader(b"glVertexAttrib2d\0")),
+      glVertexAttrib2dv: core::mem::transmute::<*const c_void, glVertexAttrib2dv_t>(loader(b"glVertexAttrib2dv\0")),
+      glVertexAttrib2f: core::mem::transmute::<*const c_void, glVertexAttrib2f_t>(loader(b"glVertexAttrib2f\0")),
+      glVertexAttrib2fv: core::mem::transmute::<*const c_void, glVertexAttrib2fv_t>(loader(b"glVertexAttrib2fv\0")),
+      glVertexAttrib2s: core::mem::transmute::<*const c_void, glVertexAttrib2s_t>(loader(b"glVertexAttrib2s\0")),
+      glVertexAttrib2sv: core::mem::transmute::<*const c_void, glVertexAttrib2sv_t>(loader(b"glVertexAttrib2sv\0")),
+      glVertexAttrib3d: core::mem::transmute::<*const c_void, glVertexAttrib3d_t>(loader(b"glVertexAttrib3d\0")),
+      glVertexAttrib3dv: core::mem::transmute::<*const c_void, glVertexAttrib3dv_t>(loader(b"glVertexAttrib3dv\0")),
+      glVertexAttrib3f: core::mem::transmute::<*const c_void, glVertexAttrib3f_t>(loader(b"glVertexAttrib3f\0")),
+      glVertexAttrib3fv: core::mem::transmute::<*const c_void, glVertexAttrib3fv_t>(loader(b"glVertexAttrib3fv\0")),
+      glVertexAttrib3s: core::mem::transmute::<*const c_void, glVertexAttrib3s_t>(loader(b"glVertexAttrib3s\0")),
+      glVertexAttrib3sv: core::mem::transmute::<*const c_void, glVertexAttrib3sv_t>(loader(b"glVertexAttrib3sv\0")),
+      glVertexAttrib4Nbv: core::mem::transmute::<*const c_void, glVertexAttrib4Nbv_t>(loader(b"glVertexAttrib4Nbv\0")),
+      glVertexAttrib4Niv: core::mem::transmute::<*const c_void, glVertexAttrib4Niv_t>(loader(b"glVertexAttrib4Niv\0")),
+      glVertexAttrib4Nsv: core::mem::transmute::<*const c_void, glVertexAttrib4Nsv_t>(loader(b"glVertexAttrib4Nsv\0")),
+      glVertexAttrib4Nub: core::mem::transmute::<*const c_void, glVertexAttrib4Nub_t>(loader(b"glVertexAttrib4Nub\0")),
+      glVertexAttrib4Nubv: core::mem::transmute::<*const c_void, glVertexAttrib4Nubv_t>(loader(b"glVertexAttrib4Nubv\0")),
+      glVertexAttrib4Nuiv: core::mem::transmute::<*const c_void, glVertexAttrib4Nuiv_t>(loader(b"glVertexAttrib4Nuiv\0")),
+      glVertexAttrib4Nusv: core::mem::transmute::<*const c_void, glVertexAttrib4Nusv_t>(loader(b"glVertexAttrib4Nusv\0")),
+      glVertexAttrib4bv: core::mem::transmute::<*const c_void, glVertexAttrib4bv_t>(loader(b"glVertexAttrib4bv\0")),
+      glVertexAttrib4d: core::mem::transmute::<*const c_void, glVertexAttrib4d_t>(loader(b"glVertexAttrib4d\0")),
+      glVertexAttrib4dv: core::mem::transmute::<*const c_void, glVertexAttrib4dv_t>(loader(b"glVertexAttrib4dv\0")),
+      glVertexAttrib4f: core::mem::transmute::<*const c_void, glVertexAttrib4f_t>(loader(b"glVertexAttrib4f\0")),
+      glVertexAttrib4fv: core::mem::transmute::<*const c_void, glVertexAttrib4fv_t>(loader(b"glVertexAttrib4fv\0")),
+      glVertexAttrib4iv: core::mem::transmute::<*const c_void, glVertexAttrib4iv_t>(loader(b"glVertexAttrib4iv\0")),
+      glVertexAttrib4s: core::mem::transmute::<*const c_void, glVertexAttrib4s_t>(loader(b"glVertexAttrib4s\0")),
+      glVertexAttrib4sv: core::mem::transmute::<*const c_void, glVertexAttrib4sv_t>(loader(b"glVertexAttrib4sv\0")),
+      glVertexAttrib4ubv: core::mem::transmute::<*const c_void, glVertexAttrib4ubv_t>(loader(b"glVertexAttrib4ubv\0")),
+      glVertexAttrib4uiv: core::mem::transmute::<*const c_void, glVertexAttrib4uiv_t>(loader(b"glVertexAttrib4uiv\0")),
+      glVertexAttrib4usv: core::mem::transmute::<*const c_void, glVertexAttrib4usv_t>(loader(b"glVertexAttrib4usv\0")),
+      glVertexAttribBinding: core::mem::transmute::<*const c_void, glVertexAttribBinding_t>(loader(b"glVertexAttribBinding\0")),
+      glVertexAttribDivisor: core::mem::transmute::<*const c_void, glVertexAttribDivisor_t>(loader(b"glVertexAttribDivisor\0")),
+      glVertexAttribFormat: core::mem::transmute::<*const c_void, glVertexAttribFormat_t>(loader(b"glVertexAttribFormat\0")),
+      glVertexAttribI1i: core::mem::transmute::<*const c_void, glVertexAttribI1i_t>(loader(b"glVertexAttribI1i\0")),
+      glVertexAttribI1iv: core::mem::transmute::<*const c_void, glVertexAttribI1iv_t>(loader(b"glVertexAttribI1iv\0")),
+      glVertexAttribI1ui: core::mem::transmute::<*const c_void, glVertexAttribI1ui_t>(loader(b"glVertexAttribI1ui\0")),
+      glVertexAttribI1uiv: core::mem::transmute::<*const c_void, glVertexAttribI1uiv_t>(loader(b"glVertexAttribI1uiv\0")),
+      glVertexAttribI2i: core::mem::transmute::<*const c_void, glVertexAttribI2i_t>(loader(b"glVertexAttribI2i\0")),
+      glVertexAttribI2iv: core::mem::transmute::<*const c_void, glVertexAttribI2iv_t>(loader(b"glVertexAttribI2iv\0")),
+      glVertexAttribI2ui: core::mem::transmute::<*const c_void, glVertexAttribI2ui_t>(loader(b"glVertexAttribI2ui\0")),
+      glVertexAttribI2uiv: core::mem::transmute::<*const c_void, glVertexAttribI2uiv_t>(loader(b"glVertexAttribI2uiv\0")),
+      glVertexAttribI3i: core::mem::transmute::<*const c_void, glVertexAttribI3i_t>(loader(b"glVertexAttribI3i\0")),
+      glVertexAttribI3iv: core::mem::transmute::<*const c_void, glVertexAttribI3iv_t>(loader(b"glVertexAttribI3iv\0")),
+      glVertexAttribI3ui: core::mem::transmute::<*const c_void, glVertexAttribI3ui_t>(loader(b"glVertexAttribI3ui\0")),
+      glVertexAttribI3uiv: core::mem::transmute::<*const c_void, glVertexAttribI3uiv_t>(loader(b"glVertexAttribI3uiv\0")),
+      glVertexAttribI4bv: core::mem::transmute::<*const c_void, glVertexAttribI4bv_t>(loader(b"glVertexAttribI4bv\0")),
+      glVertexAttribI4i: core::mem::transmute::<*const c_void, glVertexAttribI4i_t>(loader(b"glVertexAttribI4i\0")),
+      glVertexAttribI4iv: core::mem::transmute::<*const c_void, glVertexAttribI4iv_t>(loader(b"glVertexAttribI4iv\0")),
+      glVertexAttribI4sv: core::mem::transmute::<*const c_void, glVertexAttribI4sv_t>(loader(b"glVertexAttribI4sv\0")),
+      glVertexAttribI4ubv: core::mem::transmute::<*const c_void, glVertexAttribI4ubv_t>(loader(b"glVertexAttribI4ubv\0")),
+      glVertexAttribI4ui: core::mem::transmute::<*const c_void, glVertexAttribI4ui_t>(loader(b"glVertexAttribI4ui\0")),
+      glVertexAttribI4uiv: core::mem::transmute::<*const c_void, glVertexAttribI4uiv_t>(loader(b"glVertexAttribI4uiv\0")),
+      glVertexAttribI4usv: core::mem::transmute::<*const c_void, glVertexAttribI4usv_t>(loader(b"glVertexAttribI4usv\0")),
+      glVertexAttribIFormat: core::mem::transmute::<*const c_void, glVertexAttribIFormat_t>(loader(b"glVertexAttribIFormat\0")),
+      glVertexAttribIPointer: core::mem::transmute::<*const c_void, glVertexAttribIPointer_t>(loader(b"glVertexAttribIPointer\0")),
+      glVertexAttribL1d: core::mem::transmute::<*const c_void, glVertexAttribL1d_t>(loader(b"glVertexAttribL1d\0")),
+      glVertexAttribL1dv: core::mem::transmute::<*const c_void, glVertexAttribL1dv_t>(loader(b"glVertexAttribL1dv\0")),
+      glVertexAttribL2d: core::mem::transmute::<*const c_void, glVertexAttribL2d_t>(loader(b"glVertexAttribL2d\0")),
+      glVertexAttribL2dv: core::mem::transmute::<*const c_void, glVertexAttribL2dv_t>(loader(b"glVertexAttribL2dv\0")),
+      glVertexAttribL3d: core::mem::transmute::<*const c_void, glVertexAttribL3d_t>(loader(b"glVertexAttribL3d\0")),
+      glVertexAttribL3dv: core::mem::transmute::<*const c_void, glVertexAttribL3dv_t>(loader(b"glVertexAttribL3dv\0")),
+      glVertexAttribL4d: core::mem::transmute::<*const c_void, glVertexAttribL4d_t>(loader(b"glVertexAttribL4d\0")),
+      glVertexAttribL4dv: core::mem::transmute::<*const c_void, glVertexAttribL4dv_t>(loader(b"glVertexAttribL4dv\0")),
+      glVertexAttribLFormat: core::mem::transmute::<*const c_void, glVertexAttribLFormat_t>(loader(b"glVertexAttribLFormat\0")),
+      glVertexAttribLPointer: core::mem::transmute::<*const c_void, glVertexAttribLPointer_t>(loader(b"glVertexAttribLPointer\0")),
+      glVertexAttribP1ui: core::mem::transmute::<*const c_void, glVertexAttribP1ui_t>(loader(b"glVertexAttribP1ui\0")),
+      glVertexAttribP1uiv: core::mem::transmute::<*const c_void, glVertexAttribP1uiv_t>(loader(b"glVertexAttribP1uiv\0")),
+      glVertexAttribP2ui: core::mem::transmute::<*const c_void, glVertexAttribP2ui_t>(loader(b"glVertexAttribP2ui\0")),
+      glVertexAttribP2uiv: core::mem::transmute::<*const c_void, glVertexAttribP2uiv_t>(loader(b"glVertexAttribP2uiv\0")),
+      glVertexAttribP3ui: core::mem::transmute::<*const c_void, glVertexAttribP3ui_t>(loader(b"glVertexAttribP3ui\0")),
+      glVertexAttribP3uiv: core::mem::transmute::<*const c_void, glVertexAttribP3uiv_t>(loader(b"glVertexAttribP3uiv\0")),
+      glVertexAttribP4ui: core::mem::transmute::<*const c_void, glVertexAttribP4ui_t>(loader(b"glVertexAttribP4ui\0")),
+      glVertexAttribP4uiv: core::mem::transmute::<*const c_void, glVertexAttribP4uiv_t>(loader(b"glVertexAttribP4uiv\0")),
+      glVertexAttribPointer: core::mem::transmute::<*const c_void, glVertexAttribPointer_t>(loader(b"glVertexAttribPointer\0")),
+      glVertexBindingDivisor: core::mem::transmute::<*const c_void, glVertexBindingDivisor_t>(loader(b"glVertexBindingDivisor\0")),
+      glViewport: core::mem::transmute::<*const c_void, glViewport_t>(loader(b"glViewport\0")),
+      glViewportArrayv: core::mem::transmute::<*const c_void, glViewportArrayv_t>(loader(b"glViewportArrayv\0")),
+      glViewportIndexedf: core::mem::transmute::<*const c_void, glViewportIndexedf_t>(loader(b"glViewportIndexedf\0")),
+      glViewportIndexedfv: core::mem::transmute::<*const c_void, glViewportIndexedfv_t>(loader(b"glViewportIndexedfv\0")),
+      glWaitSync: core::mem::transmute::<*const c_void, glWaitSync_t>(loader(b"glWaitSync\0")),
+    }
+  }
+}
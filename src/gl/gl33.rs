@@ -0,0 +1,15213 @@
+//! Generated from `gl.xml` by the `gl_generator` crate.
+//!
+//! * API: `gl`
+//! * Version: `3.3`
+//! * Profile: `core`
+//! * Extension: `GL_KHR_debug`
+//!
+//! Don't edit this by hand, run `cargo run -p gl_generator` instead.
+
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::missing_safety_doc)]
+
+use super::*;
+use core::ffi::c_void;
+
+/// Every function of the API, loaded at runtime.
+///
+/// The methods are `unsafe` because GL needs a current context, and many
+/// take pointers. Calling a method of a function that wasn't loaded panics
+/// with the function's name.
+///
+/// With the `checked_gl` feature every method but `GetError` calls
+/// `check_gl_error` afterwards, which the parent module must provide.
+#[derive(Clone, Copy)]
+pub struct Gl33 {
+  glActiveTexture: glActiveTexture_t,
+  glAttachShader: glAttachShader_t,
+  glBeginConditionalRender: glBeginConditionalRender_t,
+  glBeginQuery: glBeginQuery_t,
+  glBeginTransformFeedback: glBeginTransformFeedback_t,
+  glBindAttribLocation: glBindAttribLocation_t,
+  glBindBuffer: glBindBuffer_t,
+  glBindBufferBase: glBindBufferBase_t,
+  glBindBufferRange: glBindBufferRange_t,
+  glBindFragDataLocation: glBindFragDataLocation_t,
+  glBindFragDataLocationIndexed: glBindFragDataLocationIndexed_t,
+  glBindFramebuffer: glBindFramebuffer_t,
+  glBindRenderbuffer: glBindRenderbuffer_t,
+  glBindSampler: glBindSampler_t,
+  glBindTexture: glBindTexture_t,
+  glBindVertexArray: glBindVertexArray_t,
+  glBlendColor: glBlendColor_t,
+  glBlendEquation: glBlendEquation_t,
+  glBlendEquationSeparate: glBlendEquationSeparate_t,
+  glBlendFunc: glBlendFunc_t,
+  glBlendFuncSeparate: glBlendFuncSeparate_t,
+  glBlitFramebuffer: glBlitFramebuffer_t,
+  glBufferData: glBufferData_t,
+  glBufferSubData: glBufferSubData_t,
+  glCheckFramebufferStatus: glCheckFramebufferStatus_t,
+  glClampColor: glClampColor_t,
+  glClear: glClear_t,
+  glClearBufferfi: glClearBufferfi_t,
+  glClearBufferfv: glClearBufferfv_t,
+  glClearBufferiv: glClearBufferiv_t,
+  glClearBufferuiv: glClearBufferuiv_t,
+  glClearColor: glClearColor_t,
+  glClearDepth: glClearDepth_t,
+  glClearStencil: glClearStencil_t,
+  glClientWaitSync: glClientWaitSync_t,
+  glColorMask: glColorMask_t,
+  glColorMaski: glColorMaski_t,
+  glCompileShader: glCompileShader_t,
+  glCompressedTexImage1D: glCompressedTexImage1D_t,
+  glCompressedTexImage2D: glCompressedTexImage2D_t,
+  glCompressedTexImage3D: glCompressedTexImage3D_t,
+  glCompressedTexSubImage1D: glCompressedTexSubImage1D_t,
+  glCompressedTexSubImage2D: glCompressedTexSubImage2D_t,
+  glCompressedTexSubImage3D: glCompressedTexSubImage3D_t,
+  glCopyBufferSubData: glCopyBufferSubData_t,
+  glCopyTexImage1D: glCopyTexImage1D_t,
+  glCopyTexImage2D: glCopyTexImage2D_t,
+  glCopyTexSubImage1D: glCopyTexSubImage1D_t,
+  glCopyTexSubImage2D: glCopyTexSubImage2D_t,
+  glCopyTexSubImage3D: glCopyTexSubImage3D_t,
+  glCreateProgram: glCreateProgram_t,
+  glCreateShader: glCreateShader_t,
+  glCullFace: glCullFace_t,
+  glDebugMessageCallback: glDebugMessageCallback_t,
+  glDebugMessageControl: glDebugMessageControl_t,
+  glDebugMessageInsert: glDebugMessageInsert_t,
+  glDeleteBuffers: glDeleteBuffers_t,
+  glDeleteFramebuffers: glDeleteFramebuffers_t,
+  glDeleteProgram: glDeleteProgram_t,
+  glDeleteQueries: glDeleteQueries_t,
+  glDeleteRenderbuffers: glDeleteRenderbuffers_t,
+  glDeleteSamplers: glDeleteSamplers_t,
+  glDeleteShader: glDeleteShader_t,
+  glDeleteSync: glDeleteSync_t,
+  glDeleteTextures: glDeleteTextures_t,
+  glDeleteVertexArrays: glDeleteVertexArrays_t,
+  glDepthFunc: glDepthFunc_t,
+  glDepthMask: glDepthMask_t,
+  glDepthRange: glDepthRange_t,
+  glDetachShader: glDetachShader_t,
+  glDisable: glDisable_t,
+  glDisableVertexAttribArray: glDisableVertexAttribArray_t,
+  glDisablei: glDisablei_t,
+  glDrawArrays: glDrawArrays_t,
+  glDrawArraysInstanced: glDrawArraysInstanced_t,
+  glDrawBuffer: glDrawBuffer_t,
+  glDrawBuffers: glDrawBuffers_t,
+  glDrawElements: glDrawElements_t,
+  glDrawElementsBaseVertex: glDrawElementsBaseVertex_t,
+  glDrawElementsInstanced: glDrawElementsInstanced_t,
+  glDrawElementsInstancedBaseVertex: glDrawElementsInstancedBaseVertex_t,
+  glDrawRangeElements: glDrawRangeElements_t,
+  glDrawRangeElementsBaseVertex: glDrawRangeElementsBaseVertex_t,
+  glEnable: glEnable_t,
+  glEnableVertexAttribArray: glEnableVertexAttribArray_t,
+  glEnablei: glEnablei_t,
+  glEndConditionalRender: glEndConditionalRender_t,
+  glEndQuery: glEndQuery_t,
+  glEndTransformFeedback: glEndTransformFeedback_t,
+  glFenceSync: glFenceSync_t,
+  glFinish: glFinish_t,
+  glFlush: glFlush_t,
+  glFlushMappedBufferRange: glFlushMappedBufferRange_t,
+  glFramebufferRenderbuffer: glFramebufferRenderbuffer_t,
+  glFramebufferTexture: glFramebufferTexture_t,
+  glFramebufferTexture1D: glFramebufferTexture1D_t,
+  glFramebufferTexture2D: glFramebufferTexture2D_t,
+  glFramebufferTexture3D: glFramebufferTexture3D_t,
+  glFramebufferTextureLayer: glFramebufferTextureLayer_t,
+  glFrontFace: glFrontFace_t,
+  glGenBuffers: glGenBuffers_t,
+  glGenFramebuffers: glGenFramebuffers_t,
+  glGenQueries: glGenQueries_t,
+  glGenRenderbuffers: glGenRenderbuffers_t,
+  glGenSamplers: glGenSamplers_t,
+  glGenTextures: glGenTextures_t,
+  glGenVertexArrays: glGenVertexArrays_t,
+  glGenerateMipmap: glGenerateMipmap_t,
+  glGetActiveAttrib: glGetActiveAttrib_t,
+  glGetActiveUniform: glGetActiveUniform_t,
+  glGetActiveUniformBlockName: glGetActiveUniformBlockName_t,
+  glGetActiveUniformBlockiv: glGetActiveUniformBlockiv_t,
+  glGetActiveUniformName: glGetActiveUniformName_t,
+  glGetActiveUniformsiv: glGetActiveUniformsiv_t,
+  glGetAttachedShaders: glGetAttachedShaders_t,
+  glGetAttribLocation: glGetAttribLocation_t,
+  glGetBooleani_v: glGetBooleani_v_t,
+  glGetBooleanv: glGetBooleanv_t,
+  glGetBufferParameteri64v: glGetBufferParameteri64v_t,
+  glGetBufferParameteriv: glGetBufferParameteriv_t,
+  glGetBufferPointerv: glGetBufferPointerv_t,
+  glGetBufferSubData: glGetBufferSubData_t,
+  glGetCompressedTexImage: glGetCompressedTexImage_t,
+  glGetDebugMessageLog: glGetDebugMessageLog_t,
+  glGetDoublev: glGetDoublev_t,
+  glGetError: glGetError_t,
+  glGetFloatv: glGetFloatv_t,
+  glGetFragDataIndex: glGetFragDataIndex_t,
+  glGetFragDataLocation: glGetFragDataLocation_t,
+  glGetFramebufferAttachmentParameteriv: glGetFramebufferAttachmentParameteriv_t,
+  glGetInteger64i_v: glGetInteger64i_v_t,
+  glGetInteger64v: glGetInteger64v_t,
+  glGetIntegeri_v: glGetIntegeri_v_t,
+  glGetIntegerv: glGetIntegerv_t,
+  glGetMultisamplefv: glGetMultisamplefv_t,
+  glGetObjectLabel: glGetObjectLabel_t,
+  glGetObjectPtrLabel: glGetObjectPtrLabel_t,
+  glGetPointerv: glGetPointerv_t,
+  glGetProgramInfoLog: glGetProgramInfoLog_t,
+  glGetProgramiv: glGetProgramiv_t,
+  glGetQueryObjecti64v: glGetQueryObjecti64v_t,
+  glGetQueryObjectiv: glGetQueryObjectiv_t,
+  glGetQueryObjectui64v: glGetQueryObjectui64v_t,
+  glGetQueryObjectuiv: glGetQueryObjectuiv_t,
+  glGetQueryiv: glGetQueryiv_t,
+  glGetRenderbufferParameteriv: glGetRenderbufferParameteriv_t,
+  glGetSamplerParameterIiv: glGetSamplerParameterIiv_t,
+  glGetSamplerParameterIuiv: glGetSamplerParameterIuiv_t,
+  glGetSamplerParameterfv: glGetSamplerParameterfv_t,
+  glGetSamplerParameteriv: glGetSamplerParameteriv_t,
+  glGetShaderInfoLog: glGetShaderInfoLog_t,
+  glGetShaderSource: glGetShaderSource_t,
+  glGetShaderiv: glGetShaderiv_t,
+  glGetString: glGetString_t,
+  glGetStringi: glGetStringi_t,
+  glGetSynciv: glGetSynciv_t,
+  glGetTexImage: glGetTexImage_t,
+  glGetTexLevelParameterfv: glGetTexLevelParameterfv_t,
+  glGetTexLevelParameteriv: glGetTexLevelParameteriv_t,
+  glGetTexParameterIiv: glGetTexParameterIiv_t,
+  glGetTexParameterIuiv: glGetTexParameterIuiv_t,
+  glGetTexParameterfv: glGetTexParameterfv_t,
+  glGetTexParameteriv: glGetTexParameteriv_t,
+  glGetTransformFeedbackVarying: glGetTransformFeedbackVarying_t,
+  glGetUniformBlockIndex: glGetUniformBlockIndex_t,
+  glGetUniformIndices: glGetUniformIndices_t,
+  glGetUniformLocation: glGetUniformLocation_t,
+  glGetUniformfv: glGetUniformfv_t,
+  glGetUniformiv: glGetUniformiv_t,
+  glGetUniformuiv: glGetUniformuiv_t,
+  glGetVertexAttribIiv: glGetVertexAttribIiv_t,
+  glGetVertexAttribIuiv: glGetVertexAttribIuiv_t,
+  glGetVertexAttribPointerv: glGetVertexAttribPointerv_t,
+  glGetVertexAttribdv: glGetVertexAttribdv_t,
+  glGetVertexAttribfv: glGetVertexAttribfv_t,
+  glGetVertexAttribiv: glGetVertexAttribiv_t,
+  glHint: glHint_t,
+  glIsBuffer: glIsBuffer_t,
+  glIsEnabled: glIsEnabled_t,
+  glIsEnabledi: glIsEnabledi_t,
+  glIsFramebuffer: glIsFramebuffer_t,
+  glIsProgram: glIsProgram_t,
+  glIsQuery: glIsQuery_t,
+  glIsRenderbuffer: glIsRenderbuffer_t,
+  glIsSampler: glIsSampler_t,
+  glIsShader: glIsShader_t,
+  glIsSync: glIsSync_t,
+  glIsTexture: glIsTexture_t,
+  glIsVertexArray: glIsVertexArray_t,
+  glLineWidth: glLineWidth_t,
+  glLinkProgram: glLinkProgram_t,
+  glLogicOp: glLogicOp_t,
+  glMapBuffer: glMapBuffer_t,
+  glMapBufferRange: glMapBufferRange_t,
+  glMultiDrawArrays: glMultiDrawArrays_t,
+  glMultiDrawElements: glMultiDrawElements_t,
+  glMultiDrawElementsBaseVertex: glMultiDrawElementsBaseVertex_t,
+  glObjectLabel: glObjectLabel_t,
+  glObjectPtrLabel: glObjectPtrLabel_t,
+  glPixelStoref: glPixelStoref_t,
+  glPixelStorei: glPixelStorei_t,
+  glPointParameterf: glPointParameterf_t,
+  glPointParameterfv: glPointParameterfv_t,
+  glPointParameteri: glPointParameteri_t,
+  glPointParameteriv: glPointParameteriv_t,
+  glPointSize: glPointSize_t,
+  glPolygonMode: glPolygonMode_t,
+  glPolygonOffset: glPolygonOffset_t,
+  glPopDebugGroup: glPopDebugGroup_t,
+  glPrimitiveRestartIndex: glPrimitiveRestartIndex_t,
+  glProvokingVertex: glProvokingVertex_t,
+  glPushDebugGroup: glPushDebugGroup_t,
+  glQueryCounter: glQueryCounter_t,
+  glReadBuffer: glReadBuffer_t,
+  glReadPixels: glReadPixels_t,
+  glRenderbufferStorage: glRenderbufferStorage_t,
+  glRenderbufferStorageMultisample: glRenderbufferStorageMultisample_t,
+  glSampleCoverage: glSampleCoverage_t,
+  glSampleMaski: glSampleMaski_t,
+  glSamplerParameterIiv: glSamplerParameterIiv_t,
+  glSamplerParameterIuiv: glSamplerParameterIuiv_t,
+  glSamplerParameterf: glSamplerParameterf_t,
+  glSamplerParameterfv: glSamplerParameterfv_t,
+  glSamplerParameteri: glSamplerParameteri_t,
+  glSamplerParameteriv: glSamplerParameteriv_t,
+  glScissor: glScissor_t,
+  glShaderSource: glShaderSource_t,
+  glStencilFunc: glStencilFunc_t,
+  glStencilFuncSeparate: glStencilFuncSeparate_t,
+  glStencilMask: glStencilMask_t,
+  glStencilMaskSeparate: glStencilMaskSeparate_t,
+  glStencilOp: glStencilOp_t,
+  glStencilOpSeparate: glStencilOpSeparate_t,
+  glTexBuffer: glTexBuffer_t,
+  glTexImage1D: glTexImage1D_t,
+  glTexImage2D: glTexImage2D_t,
+  glTexImage2DMultisample: glTexImage2DMultisample_t,
+  glTexImage3D: glTexImage3D_t,
+  glTexImage3DMultisample: glTexImage3DMultisample_t,
+  glTexParameterIiv: glTexParameterIiv_t,
+  glTexParameterIuiv: glTexParameterIuiv_t,
+  glTexParameterf: glTexParameterf_t,
+  glTexParameterfv: glTexParameterfv_t,
+  glTexParameteri: glTexParameteri_t,
+  glTexParameteriv: glTexParameteriv_t,
+  glTexSubImage1D: glTexSubImage1D_t,
+  glTexSubImage2D: glTexSubImage2D_t,
+  glTexSubImage3D: glTexSubImage3D_t,
+  glTransformFeedbackVaryings: glTransformFeedbackVaryings_t,
+  glUniform1f: glUniform1f_t,
+  glUniform1fv: glUniform1fv_t,
+  glUniform1i: glUniform1i_t,
+  glUniform1iv: glUniform1iv_t,
+  glUniform1ui: glUniform1ui_t,
+  glUniform1uiv: glUniform1uiv_t,
+  glUniform2f: glUniform2f_t,
+  glUniform2fv: glUniform2fv_t,
+  glUniform2i: glUniform2i_t,
+  glUniform2iv: glUniform2iv_t,
+  glUniform2ui: glUniform2ui_t,
+  glUniform2uiv: glUniform2uiv_t,
+  glUniform3f: glUniform3f_t,
+  glUniform3fv: glUniform3fv_t,
+  glUniform3i: glUniform3i_t,
+  glUniform3iv: glUniform3iv_t,
+  glUniform3ui: glUniform3ui_t,
+  glUniform3uiv: glUniform3uiv_t,
+  glUniform4f: glUniform4f_t,
+  glUniform4fv: glUniform4fv_t,
+  glUniform4i: glUniform4i_t,
+  glUniform4iv: glUniform4iv_t,
+  glUniform4ui: glUniform4ui_t,
+  glUniform4uiv: glUniform4uiv_t,
+  glUniformBlockBinding: glUniformBlockBinding_t,
+  glUniformMatrix2fv: glUniformMatrix2fv_t,
+  glUniformMatrix2x3fv: glUniformMatrix2x3fv_t,
+  glUniformMatrix2x4fv: glUniformMatrix2x4fv_t,
+  glUniformMatrix3fv: glUniformMatrix3fv_t,
+  glUniformMatrix3x2fv: glUniformMatrix3x2fv_t,
+  glUniformMatrix3x4fv: glUniformMatrix3x4fv_t,
+  glUniformMatrix4fv: glUniformMatrix4fv_t,
+  glUniformMatrix4x2fv: glUniformMatrix4x2fv_t,
+  glUniformMatrix4x3fv: glUniformMatrix4x3fv_t,
+  glUnmapBuffer: glUnmapBuffer_t,
+  glUseProgram: glUseProgram_t,
+  glValidateProgram: glValidateProgram_t,
+  glVertexAttrib1d: glVertexAttrib1d_t,
+  glVertexAttrib1dv: glVertexAttrib1dv_t,
+  glVertexAttrib1f: glVertexAttrib1f_t,
+  glVertexAttrib1fv: glVertexAttrib1fv_t,
+  glVertexAttrib1s: glVertexAttrib1s_t,
+  glVertexAttrib1sv: glVertexAttrib1sv_t,
+  glVertexAttrib2d: glVertexAttrib2d_t,
+  glVertexAttrib2dv: glVertexAttrib2dv_t,
+  glVertexAttrib2f: glVertexAttrib2f_t,
+  glVertexAttrib2fv: glVertexAttrib2fv_t,
+  glVertexAttrib2s: glVertexAttrib2s_t,
+  glVertexAttrib2sv: glVertexAttrib2sv_t,
+  glVertexAttrib3d: glVertexAttrib3d_t,
+  glVertexAttrib3dv: glVertexAttrib3dv_t,
+  glVertexAttrib3f: glVertexAttrib3f_t,
+  glVertexAttrib3fv: glVertexAttrib3fv_t,
+  glVertexAttrib3s: glVertexAttrib3s_t,
+  glVertexAttrib3sv: glVertexAttrib3sv_t,
+  glVertexAttrib4Nbv: glVertexAttrib4Nbv_t,
+  glVertexAttrib4Niv: glVertexAttrib4Niv_t,
+  glVertexAttrib4Nsv: glVertexAttrib4Nsv_t,
+  glVertexAttrib4Nub: glVertexAttrib4Nub_t,
+  glVertexAttrib4Nubv: glVertexAttrib4Nubv_t,
+  glVertexAttrib4Nuiv: glVertexAttrib4Nuiv_t,
+  glVertexAttrib4Nusv: glVertexAttrib4Nusv_t,
+  glVertexAttrib4bv: glVertexAttrib4bv_t,
+  glVertexAttrib4d: glVertexAttrib4d_t,
+  glVertexAttrib4dv: glVertexAttrib4dv_t,
+  glVertexAttrib4f: glVertexAttrib4f_t,
+  glVertexAttrib4fv: glVertexAttrib4fv_t,
+  glVertexAttrib4iv: glVertexAttrib4iv_t,
+  glVertexAttrib4s: glVertexAttrib4s_t,
+  glVertexAttrib4sv: glVertexAttrib4sv_t,
+  glVertexAttrib4ubv: glVertexAttrib4ubv_t,
+  glVertexAttrib4uiv: glVertexAttrib4uiv_t,
+  glVertexAttrib4usv: glVertexAttrib4usv_t,
+  glVertexAttribDivisor: glVertexAttribDivisor_t,
+  glVertexAttribI1i: glVertexAttribI1i_t,
+  glVertexAttribI1iv: glVertexAttribI1iv_t,
+  glVertexAttribI1ui: glVertexAttribI1ui_t,
+  glVertexAttribI1uiv: glVertexAttribI1uiv_t,
+  glVertexAttribI2i: glVertexAttribI2i_t,
+  glVertexAttribI2iv: glVertexAttribI2iv_t,
+  glVertexAttribI2ui: glVertexAttribI2ui_t,
+  glVertexAttribI2uiv: glVertexAttribI2uiv_t,
+  glVertexAttribI3i: glVertexAttribI3i_t,
+  glVertexAttribI3iv: glVertexAttribI3iv_t,
+  glVertexAttribI3ui: glVertexAttribI3ui_t,
+  glVertexAttribI3uiv: glVertexAttribI3uiv_t,
+  glVertexAttribI4bv: glVertexAttribI4bv_t,
+  glVertexAttribI4i: glVertexAttribI4i_t,
+  glVertexAttribI4iv: glVertexAttribI4iv_t,
+  glVertexAttribI4sv: glVertexAttribI4sv_t,
+  glVertexAttribI4ubv: glVertexAttribI4ubv_t,
+  glVertexAttribI4ui: glVertexAttribI4ui_t,
+  glVertexAttribI4uiv: glVertexAttribI4uiv_t,
+  glVertexAttribI4usv: glVertexAttribI4usv_t,
+  glVertexAttribIPointer: glVertexAttribIPointer_t,
+  glVertexAttribP1ui: glVertexAttribP1ui_t,
+  glVertexAttribP1uiv: glVertexAttribP1uiv_t,
+  glVertexAttribP2ui: glVertexAttribP2ui_t,
+  glVertexAttribP2uiv: glVertexAttribP2uiv_t,
+  glVertexAttribP3ui: glVertexAttribP3ui_t,
+  glVertexAttribP3uiv: glVertexAttribP3uiv_t,
+  glVertexAttribP4ui: glVertexAttribP4ui_t,
+  glVertexAttribP4uiv: glVertexAttribP4uiv_t,
+  glVertexAttribPointer: glVertexAttribPointer_t,
+  glViewport: glViewport_t,
+  glWaitSync: glWaitSync_t,
+}
+impl Gl33 {
+  /// Loads every function using the loader given.
+  ///
+  /// The loader gets each null-terminated name (eg: `b"glClear\0"`), and
+  /// gives the address of that function, or null.
+  ///
+  /// **Returns:** The table, and the names of the functions of the version
+  /// that the loader gave null for.
+  ///
+  /// Functions that only an extension adds aren't in the list, since
+  /// the driver might not have the extension. Check for them with the
+  /// `loaded_` method of the extension instead.
+  ///
+  /// ## Safety
+  /// * The non-null addresses must be of functions with the signature from
+  ///   `gl.xml`.
+  pub unsafe fn load<F: Fn(&[u8]) -> *const c_void>(
+    loader: F,
+  ) -> (Self, Vec<&'static str>) {
+    let mut missing = Vec::new();
+    let mut get = |name: &'static str| {
+      let p = loader(name.as_bytes());
+      if p.is_null() {
+        missing.push(name.trim_end_matches('\0'));
+      }
+      p
+    };
+    let get_extension = |name: &'static str| loader(name.as_bytes());
+    let table = Self {
+      glActiveTexture: core::mem::transmute::<*const c_void, glActiveTexture_t>(get("glActiveTexture\0")),
+      glAttachShader: core::mem::transmute::<*const c_void, glAttachShader_t>(get("glAttachShader\0")),
+      glBeginConditionalRender: core::mem::transmute::<*const c_void, glBeginConditionalRender_t>(get("glBeginConditionalRender\0")),
+      glBeginQuery: core::mem::transmute::<*const c_void, glBeginQuery_t>(get("glBeginQuery\0")),
+      glBeginTransformFeedback: core::mem::transmute::<*const c_void, glBeginTransformFeedback_t>(get("glBeginTransformFeedback\0")),
+      glBindAttribLocation: core::mem::transmute::<*const c_void, glBindAttribLocation_t>(get("glBindAttribLocation\0")),
+      glBindBuffer: core::mem::transmute::<*const c_void, glBindBuffer_t>(get("glBindBuffer\0")),
+      glBindBufferBase: core::mem::transmute::<*const c_void, glBindBufferBase_t>(get("glBindBufferBase\0")),
+      glBindBufferRange: core::mem::transmute::<*const c_void, glBindBufferRange_t>(get("glBindBufferRange\0")),
+      glBindFragDataLocation: core::mem::transmute::<*const c_void, glBindFragDataLocation_t>(get("glBindFragDataLocation\0")),
+      glBindFragDataLocationIndexed: core::mem::transmute::<*const c_void, glBindFragDataLocationIndexed_t>(get("glBindFragDataLocationIndexed\0")),
+      glBindFramebuffer: core::mem::transmute::<*const c_void, glBindFramebuffer_t>(get("glBindFramebuffer\0")),
+      glBindRenderbuffer: core::mem::transmute::<*const c_void, glBindRenderbuffer_t>(get("glBindRenderbuffer\0")),
+      glBindSampler: core::mem::transmute::<*const c_void, glBindSampler_t>(get("glBindSampler\0")),
+      glBindTexture: core::mem::transmute::<*const c_void, glBindTexture_t>(get("glBindTexture\0")),
+      glBindVertexArray: core::mem::transmute::<*const c_void, glBindVertexArray_t>(get("glBindVertexArray\0")),
+      glBlendColor: core::mem::transmute::<*const c_void, glBlendColor_t>(get("glBlendColor\0")),
+      glBlendEquation: core::mem::transmute::<*const c_void, glBlendEquation_t>(get("glBlendEquation\0")),
+      glBlendEquationSeparate: core::mem::transmute::<*const c_void, glBlendEquationSeparate_t>(get("glBlendEquationSeparate\0")),
+      glBlendFunc: core::mem::transmute::<*const c_void, glBlendFunc_t>(get("glBlendFunc\0")),
+      glBlendFuncSeparate: core::mem::transmute::<*const c_void, glBlendFuncSeparate_t>(get("glBlendFuncSeparate\0")),
+      glBlitFramebuffer: core::mem::transmute::<*const c_void, glBlitFramebuffer_t>(get("glBlitFramebuffer\0")),
+      glBufferData: core::mem::transmute::<*const c_void, glBufferData_t>(get("glBufferData\0")),
+      glBufferSubData: core::mem::transmute::<*const c_void, glBufferSubData_t>(get("glBufferSubData\0")),
+      glCheckFramebufferStatus: core::mem::transmute::<*const c_void, glCheckFramebufferStatus_t>(get("glCheckFramebufferStatus\0")),
+      glClampColor: core::mem::transmute::<*const c_void, glClampColor_t>(get("glClampColor\0")),
+      glClear: core::mem::transmute::<*const c_void, glClear_t>(get("glClear\0")),
+      glClearBufferfi: core::mem::transmute::<*const c_void, glClearBufferfi_t>(get("glClearBufferfi\0")),
+      glClearBufferfv: core::mem::transmute::<*const c_void, glClearBufferfv_t>(get("glClearBufferfv\0")),
+      glClearBufferiv: core::mem::transmute::<*const c_void, glClearBufferiv_t>(get("glClearBufferiv\0")),
+      glClearBufferuiv: core::mem::transmute::<*const c_void, glClearBufferuiv_t>(get("glClearBufferuiv\0")),
+      glClearColor: core::mem::transmute::<*const c_void, glClearColor_t>(get("glClearColor\0")),
+      glClearDepth: core::mem::transmute::<*const c_void, glClearDepth_t>(get("glClearDepth\0")),
+      glClearStencil: core::mem::transmute::<*const c_void, glClearStencil_t>(get("glClearStencil\0")),
+      glClientWaitSync: core::mem::transmute::<*const c_void, glClientWaitSync_t>(get("glClientWaitSync\0")),
+      glColorMask: core::mem::transmute::<*const c_void, glColorMask_t>(get("glColorMask\0")),
+      glColorMaski: core::mem::transmute::<*const c_void, glColorMaski_t>(get("glColorMaski\0")),
+      glCompileShader: core::mem::transmute::<*const c_void, glCompileShader_t>(get("glCompileShader\0")),
+      glCompressedTexImage1D: core::mem::transmute::<*const c_void, glCompressedTexImage1D_t>(get("glCompressedTexImage1D\0")),
+      glCompressedTexImage2D: core::mem::transmute::<*const c_void, glCompressedTexImage2D_t>(get("glCompressedTexImage2D\0")),
+      glCompressedTexImage3D: core::mem::transmute::<*const c_void, glCompressedTexImage3D_t>(get("glCompressedTexImage3D\0")),
+      glCompressedTexSubImage1D: core::mem::transmute::<*const c_void, glCompressedTexSubImage1D_t>(get("glCompressedTexSubImage1D\0")),
+      glCompressedTexSubImage2D: core::mem::transmute::<*const c_void, glCompressedTexSubImage2D_t>(get("glCompressedTexSubImage2D\0")),
+      glCompressedTexSubImage3D: core::mem::transmute::<*const c_void, glCompressedTexSubImage3D_t>(get("glCompressedTexSubImage3D\0")),
+      glCopyBufferSubData: core::mem::transmute::<*const c_void, glCopyBufferSubData_t>(get("glCopyBufferSubData\0")),
+      glCopyTexImage1D: core::mem::transmute::<*const c_void, glCopyTexImage1D_t>(get("glCopyTexImage1D\0")),
+      glCopyTexImage2D: core::mem::transmute::<*const c_void, glCopyTexImage2D_t>(get("glCopyTexImage2D\0")),
+      glCopyTexSubImage1D: core::mem::transmute::<*const c_void, glCopyTexSubImage1D_t>(get("glCopyTexSubImage1D\0")),
+      glCopyTexSubImage2D: core::mem::transmute::<*const c_void, glCopyTexSubImage2D_t>(get("glCopyTexSubImage2D\0")),
+      glCopyTexSubImage3D: core::mem::transmute::<*const c_void, glCopyTexSubImage3D_t>(get("glCopyTexSubImage3D\0")),
+      glCreateProgram: core::mem::transmute::<*const c_void, glCreateProgram_t>(get("glCreateProgram\0")),
+      glCreateShader: core::mem::transmute::<*const c_void, glCreateShader_t>(get("glCreateShader\0")),
+      glCullFace: core::mem::transmute::<*const c_void, glCullFace_t>(get("glCullFace\0")),
+      glDebugMessageCallback: core::mem::transmute::<*const c_void, glDebugMessageCallback_t>(get_extension("glDebugMessageCallback\0")),
+      glDebugMessageControl: core::mem::transmute::<*const c_void, glDebugMessageControl_t>(get_extension("glDebugMessageControl\0")),
+      glDebugMessageInsert: core::mem::transmute::<*const c_void, glDebugMessageInsert_t>(get_extension("glDebugMessageInsert\0")),
+      glDeleteBuffers: core::mem::transmute::<*const c_void, glDeleteBuffers_t>(get("glDeleteBuffers\0")),
+      glDeleteFramebuffers: core::mem::transmute::<*const c_void, glDeleteFramebuffers_t>(get("glDeleteFramebuffers\0")),
+      glDeleteProgram: core::mem::transmute::<*const c_void, glDeleteProgram_t>(get("glDeleteProgram\0")),
+      glDeleteQueries: core::mem::transmute::<*const c_void, glDeleteQueries_t>(get("glDeleteQueries\0")),
+      glDeleteRenderbuffers: core::mem::transmute::<*const c_void, glDeleteRenderbuffers_t>(get("glDeleteRenderbuffers\0")),
+      glDeleteSamplers: core::mem::transmute::<*const c_void, glDeleteSamplers_t>(get("glDeleteSamplers\0")),
+      glDeleteShader: core::mem::transmute::<*const c_void, glDeleteShader_t>(get("glDeleteShader\0")),
+      glDeleteSync: core::mem::transmute::<*const c_void, glDeleteSync_t>(get("glDeleteSync\0")),
+      glDeleteTextures: core::mem::transmute::<*const c_void, glDeleteTextures_t>(get("glDeleteTextures\0")),
+      glDeleteVertexArrays: core::mem::transmute::<*const c_void, glDeleteVertexArrays_t>(get("glDeleteVertexArrays\0")),
+      glDepthFunc: core::mem::transmute::<*const c_void, glDepthFunc_t>(get("glDepthFunc\0")),
+      glDepthMask: core::mem::transmute::<*const c_void, glDepthMask_t>(get("glDepthMask\0")),
+      glDepthRange: core::mem::transmute::<*const c_void, glDepthRange_t>(get("glDepthRange\0")),
+      glDetachShader: core::mem::transmute::<*const c_void, glDetachShader_t>(get("glDetachShader\0")),
+      glDisable: core::mem::transmute::<*const c_void, glDisable_t>(get("glDisable\0")),
+      glDisableVertexAttribArray: core::mem::transmute::<*const c_void, glDisableVertexAttribArray_t>(get("glDisableVertexAttribArray\0")),
+      glDisablei: core::mem::transmute::<*const c_void, glDisablei_t>(get("glDisablei\0")),
+      glDrawArrays: core::mem::transmute::<*const c_void, glDrawArrays_t>(get("glDrawArrays\0")),
+      glDrawArraysInstanced: core::mem::transmute::<*const c_void, glDrawArraysInstanced_t>(get("glDrawArraysInstanced\0")),
+      glDrawBuffer: core::mem::transmute::<*const c_void, glDrawBuffer_t>(get("glDrawBuffer\0")),
+      glDrawBuffers: core::mem::transmute::<*const c_void, glDrawBuffers_t>(get("glDrawBuffers\0")),
+      glDrawElements: core::mem::transmute::<*const c_void, glDrawElements_t>(get("glDrawElements\0")),
+      glDrawElementsBaseVertex: core::mem::transmute::<*const c_void, glDrawElementsBaseVertex_t>(get("glDrawElementsBaseVertex\0")),
+      glDrawElementsInstanced: core::mem::transmute::<*const c_void, glDrawElementsInstanced_t>(get("glDrawElementsInstanced\0")),
+      glDrawElementsInstancedBaseVertex: core::mem::transmute::<*const c_void, glDrawElementsInstancedBaseVertex_t>(get("glDrawElementsInstancedBaseVertex\0")),
+      glDrawRangeElements: core::mem::transmute::<*const c_void, glDrawRangeElements_t>(get("glDrawRangeElements\0")),
+      glDrawRangeElementsBaseVertex: core::mem::transmute::<*const c_void, glDrawRangeElementsBaseVertex_t>(get("glDrawRangeElementsBaseVertex\0")),
+      glEnable: core::mem::transmute::<*const c_void, glEnable_t>(get("glEnable\0")),
+      glEnableVertexAttribArray: core::mem::transmute::<*const c_void, glEnableVertexAttribArray_t>(get("glEnableVertexAttribArray\0")),
+      glEnablei: core::mem::transmute::<*const c_void, glEnablei_t>(get("glEnablei\0")),
+      glEndConditionalRender: core::mem::transmute::<*const c_void, glEndConditionalRender_t>(get("glEndConditionalRender\0")),
+      glEndQuery: core::mem::transmute::<*const c_void, glEndQuery_t>(get("glEndQuery\0")),
+      glEndTransformFeedback: core::mem::transmute::<*const c_void, glEndTransformFeedback_t>(get("glEndTransformFeedback\0")),
+      glFenceSync: core::mem::transmute::<*const c_void, glFenceSync_t>(get("glFenceSync\0")),
+      glFinish: core::mem::transmute::<*const c_void, glFinish_t>(get("glFinish\0")),
+      glFlush: core::mem::transmute::<*const c_void, glFlush_t>(get("glFlush\0")),
+      glFlushMappedBufferRange: core::mem::transmute::<*const c_void, glFlushMappedBufferRange_t>(get("glFlushMappedBufferRange\0")),
+      glFramebufferRenderbuffer: core::mem::transmute::<*const c_void, glFramebufferRenderbuffer_t>(get("glFramebufferRenderbuffer\0")),
+      glFramebufferTexture: core::mem::transmute::<*const c_void, glFramebufferTexture_t>(get("glFramebufferTexture\0")),
+      glFramebufferTexture1D: core::mem::transmute::<*const c_void, glFramebufferTexture1D_t>(get("glFramebufferTexture1D\0")),
+      glFramebufferTexture2D: core::mem::transmute::<*const c_void, glFramebufferTexture2D_t>(get("glFramebufferTexture2D\0")),
+      glFramebufferTexture3D: core::mem::transmute::<*const c_void, glFramebufferTexture3D_t>(get("glFramebufferTexture3D\0")),
+      glFramebufferTextureLayer: core::mem::transmute::<*const c_void, glFramebufferTextureLayer_t>(get("glFramebufferTextureLayer\0")),
+      glFrontFace: core::mem::transmute::<*const c_void, glFrontFace_t>(get("glFrontFace\0")),
+      glGenBuffers: core::mem::transmute::<*const c_void, glGenBuffers_t>(get("glGenBuffers\0")),
+      glGenFramebuffers: core::mem::transmute::<*const c_void, glGenFramebuffers_t>(get("glGenFramebuffers\0")),
+      glGenQueries: core::mem::transmute::<*const c_void, glGenQueries_t>(get("glGenQueries\0")),
+      glGenRenderbuffers: core::mem::transmute::<*const c_void, glGenRenderbuffers_t>(get("glGenRenderbuffers\0")),
+      glGenSamplers: core::mem::transmute::<*const c_void, glGenSamplers_t>(get("glGenSamplers\0")),
+      glGenTextures: core::mem::transmute::<*const c_void, glGenTextures_t>(get("glGenTextures\0")),
+      glGenVertexArrays: core::mem::transmute::<*const c_void, glGenVertexArrays_t>(get("glGenVertexArrays\0")),
+      glGenerateMipmap: core::mem::transmute::<*const c_void, glGenerateMipmap_t>(get("glGenerateMipmap\0")),
+      glGetActiveAttrib: core::mem::transmute::<*const c_void, glGetActiveAttrib_t>(get("glGetActiveAttrib\0")),
+      glGetActiveUniform: core::mem::transmute::<*const c_void, glGetActiveUniform_t>(get("glGetActiveUniform\0")),
+      glGetActiveUniformBlockName: core::mem::transmute::<*const c_void, glGetActiveUniformBlockName_t>(get("glGetActiveUniformBlockName\0")),
+      glGetActiveUniformBlockiv: core::mem::transmute::<*const c_void, glGetActiveUniformBlockiv_t>(get("glGetActiveUniformBlockiv\0")),
+      glGetActiveUniformName: core::mem::transmute::<*const c_void, glGetActiveUniformName_t>(get("glGetActiveUniformName\0")),
+      glGetActiveUniformsiv: core::mem::transmute::<*const c_void, glGetActiveUniformsiv_t>(get("glGetActiveUniformsiv\0")),
+      glGetAttachedShaders: core::mem::transmute::<*const c_void, glGetAttachedShaders_t>(get("glGetAttachedShaders\0")),
+      glGetAttribLocation: core::mem::transmute::<*const c_void, glGetAttribLocation_t>(get("glGetAttribLocation\0")),
+      glGetBooleani_v: core::mem::transmute::<*const c_void, glGetBooleani_v_t>(get("glGetBooleani_v\0")),
+      glGetBooleanv: core::mem::transmute::<*const c_void, glGetBooleanv_t>(get("glGetBooleanv\0")),
+      glGetBufferParameteri64v: core::mem::transmute::<*const c_void, glGetBufferParameteri64v_t>(get("glGetBufferParameteri64v\0")),
+      glGetBufferParameteriv: core::mem::transmute::<*const c_void, glGetBufferParameteriv_t>(get("glGetBufferParameteriv\0")),
+      glGetBufferPointerv: core::mem::transmute::<*const c_void, glGetBufferPointerv_t>(get("glGetBufferPointerv\0")),
+      glGetBufferSubData: core::mem::transmute::<*const c_void, glGetBufferSubData_t>(get("glGetBufferSubData\0")),
+      glGetCompressedTexImage: core::mem::transmute::<*const c_void, glGetCompressedTexImage_t>(get("glGetCompressedTexImage\0")),
+      glGetDebugMessageLog: core::mem::transmute::<*const c_void, glGetDebugMessageLog_t>(get_extension("glGetDebugMessageLog\0")),
+      glGetDoublev: core::mem::transmute::<*const c_void, glGetDoublev_t>(get("glGetDoublev\0")),
+      glGetError: core::mem::transmute::<*const c_void, glGetError_t>(get("glGetError\0")),
+      glGetFloatv: core::mem::transmute::<*const c_void, glGetFloatv_t>(get("glGetFloatv\0")),
+      glGetFragDataIndex: core::mem::transmute::<*const c_void, glGetFragDataIndex_t>(get("glGetFragDataIndex\0")),
+      glGetFragDataLocation: core::mem::transmute::<*const c_void, glGetFragDataLocation_t>(get("glGetFragDataLocation\0")),
+      glGetFramebufferAttachmentParameteriv: core::mem::transmute::<*const c_void, glGetFramebufferAttachmentParameteriv_t>(get("glGetFramebufferAttachmentParameteriv\0")),
+      glGetInteger64i_v: core::mem::transmute::<*const c_void, glGetInteger64i_v_t>(get("glGetInteger64i_v\0")),
+      glGetInteger64v: core::mem::transmute::<*const c_void, glGetInteger64v_t>(get("glGetInteger64v\0")),
+      glGetIntegeri_v: core::mem::transmute::<*const c_void, glGetIntegeri_v_t>(get("glGetIntegeri_v\0")),
+      glGetIntegerv: core::mem::transmute::<*const c_void, glGetIntegerv_t>(get("glGetIntegerv\0")),
+      glGetMultisamplefv: core::mem::transmute::<*const c_void, glGetMultisamplefv_t>(get("glGetMultisamplefv\0")),
+      glGetObjectLabel: core::mem::transmute::<*const c_void, glGetObjectLabel_t>(get_extension("glGetObjectLabel\0")),
+      glGetObjectPtrLabel: core::mem::transmute::<*const c_void, glGetObjectPtrLabel_t>(get_extension("glGetObjectPtrLabel\0")),
+      glGetPointerv: core::mem::transmute::<*const c_void, glGetPointerv_t>(get_extension("glGetPointerv\0")),
+      glGetProgramInfoLog: core::mem::transmute::<*const c_void, glGetProgramInfoLog_t>(get("glGetProgramInfoLog\0")),
+      glGetProgramiv: core::mem::transmute::<*const c_void, glGetProgramiv_t>(get("glGetProgramiv\0")),
+      glGetQueryObjecti64v: core::mem::transmute::<*const c_void, glGetQueryObjecti64v_t>(get("glGetQueryObjecti64v\0")),
+      glGetQueryObjectiv: core::mem::transmute::<*const c_void, glGetQueryObjectiv_t>(get("glGetQueryObjectiv\0")),
+      glGetQueryObjectui64v: core::mem::transmute::<*const c_void, glGetQueryObjectui64v_t>(get("glGetQueryObjectui64v\0")),
+      glGetQueryObjectuiv: core::mem::transmute::<*const c_void, glGetQueryObjectuiv_t>(get("glGetQueryObjectuiv\0")),
+      glGetQueryiv: core::mem::transmute::<*const c_void, glGetQueryiv_t>(get("glGetQueryiv\0")),
+      glGetRenderbufferParameteriv: core::mem::transmute::<*const c_void, glGetRenderbufferParameteriv_t>(get("glGetRenderbufferParameteriv\0")),
+      glGetSamplerParameterIiv: core::mem::transmute::<*const c_void, glGetSamplerParameterIiv_t>(get("glGetSamplerParameterIiv\0")),
+      glGetSamplerParameterIuiv: core::mem::transmute::<*const c_void, glGetSamplerParameterIuiv_t>(get("glGetSamplerParameterIuiv\0")),
+      glGetSamplerParameterfv: core::mem::transmute::<*const c_void, glGetSamplerParameterfv_t>(get("glGetSamplerParameterfv\0")),
+      glGetSamplerParameteriv: core::mem::transmute::<*const c_void, glGetSamplerParameteriv_t>(get("glGetSamplerParameteriv\0")),
+      glGetShaderInfoLog: core::mem::transmute::<*const c_void, glGetShaderInfoLog_t>(get("glGetShaderInfoLog\0")),
+      glGetShaderSource: core::mem::transmute::<*const c_void, glGetShaderSource_t>(get("glGetShaderSource\0")),
+      glGetShaderiv: core::mem::transmute::<*const c_void, glGetShaderiv_t>(get("glGetShaderiv\0")),
+      glGetString: core::mem::transmute::<*const c_void, glGetString_t>(get("glGetString\0")),
+      glGetStringi: core::mem::transmute::<*const c_void, glGetStringi_t>(get("glGetStringi\0")),
+      glGetSynciv: core::mem::transmute::<*const c_void, glGetSynciv_t>(get("glGetSynciv\0")),
+      glGetTexImage: core::mem::transmute::<*const c_void, glGetTexImage_t>(get("glGetTexImage\0")),
+      glGetTexLevelParameterfv: core::mem::transmute::<*const c_void, glGetTexLevelParameterfv_t>(get("glGetTexLevelParameterfv\0")),
+      glGetTexLevelParameteriv: core::mem::transmute::<*const c_void, glGetTexLevelParameteriv_t>(get("glGetTexLevelParameteriv\0")),
+      glGetTexParameterIiv: core::mem::transmute::<*const c_void, glGetTexParameterIiv_t>(get("glGetTexParameterIiv\0")),
+      glGetTexParameterIuiv: core::mem::transmute::<*const c_void, glGetTexParameterIuiv_t>(get("glGetTexParameterIuiv\0")),
+      glGetTexParameterfv: core::mem::transmute::<*const c_void, glGetTexParameterfv_t>(get("glGetTexParameterfv\0")),
+      glGetTexParameteriv: core::mem::transmute::<*const c_void, glGetTexParameteriv_t>(get("glGetTexParameteriv\0")),
+      glGetTransformFeedbackVarying: core::mem::transmute::<*const c_void, glGetTransformFeedbackVarying_t>(get("glGetTransformFeedbackVarying\0")),
+      glGetUniformBlockIndex: core::mem::transmute::<*const c_void, glGetUniformBlockIndex_t>(get("glGetUniformBlockIndex\0")),
+      glGetUniformIndices: core::mem::transmute::<*const c_void, glGetUniformIndices_t>(get("glGetUniformIndices\0")),
+      glGetUniformLocation: core::mem::transmute::<*const c_void, glGetUniformLocation_t>(get("glGetUniformLocation\0")),
+      glGetUniformfv: core::mem::transmute::<*const c_void, glGetUniformfv_t>(get("glGetUniformfv\0")),
+      glGetUniformiv: core::mem::transmute::<*const c_void, glGetUniformiv_t>(get("glGetUniformiv\0")),
+      glGetUniformuiv: core::mem::transmute::<*const c_void, glGetUniformuiv_t>(get("glGetUniformuiv\0")),
+      glGetVertexAttribIiv: core::mem::transmute::<*const c_void, glGetVertexAttribIiv_t>(get("glGetVertexAttribIiv\0")),
+      glGetVertexAttribIuiv: core::mem::transmute::<*const c_void, glGetVertexAttribIuiv_t>(get("glGetVertexAttribIuiv\0")),
+      glGetVertexAttribPointerv: core::mem::transmute::<*const c_void, glGetVertexAttribPointerv_t>(get("glGetVertexAttribPointerv\0")),
+      glGetVertexAttribdv: core::mem::transmute::<*const c_void, glGetVertexAttribdv_t>(get("glGetVertexAttribdv\0")),
+      glGetVertexAttribfv: core::mem::transmute::<*const c_void, glGetVertexAttribfv_t>(get("glGetVertexAttribfv\0")),
+      glGetVertexAttribiv: core::mem::transmute::<*const c_void, glGetVertexAttribiv_t>(get("glGetVertexAttribiv\0")),
+      glHint: core::mem::transmute::<*const c_void, glHint_t>(get("glHint\0")),
+      glIsBuffer: core::mem::transmute::<*const c_void, glIsBuffer_t>(get("glIsBuffer\0")),
+      glIsEnabled: core::mem::transmute::<*const c_void, glIsEnabled_t>(get("glIsEnabled\0")),
+      glIsEnabledi: core::mem::transmute::<*const c_void, glIsEnabledi_t>(get("glIsEnabledi\0")),
+      glIsFramebuffer: core::mem::transmute::<*const c_void, glIsFramebuffer_t>(get("glIsFramebuffer\0")),
+      glIsProgram: core::mem::transmute::<*const c_void, glIsProgram_t>(get("glIsProgram\0")),
+      glIsQuery: core::mem::transmute::<*const c_void, glIsQuery_t>(get("glIsQuery\0")),
+      glIsRenderbuffer: core::mem::transmute::<*const c_void, glIsRenderbuffer_t>(get("glIsRenderbuffer\0")),
+      glIsSampler: core::mem::transmute::<*const c_void, glIsSampler_t>(get("glIsSampler\0")),
+      glIsShader: core::mem::transmute::<*const c_void, glIsShader_t>(get("glIsShader\0")),
+      glIsSync: core::mem::transmute::<*const c_void, glIsSync_t>(get("glIsSync\0")),
+      glIsTexture: core::mem::transmute::<*const c_void, glIsTexture_t>(get("glIsTexture\0")),
+      glIsVertexArray: core::mem::transmute::<*const c_void, glIsVertexArray_t>(get("glIsVertexArray\0")),
+      glLineWidth: core::mem::transmute::<*const c_void, glLineWidth_t>(get("glLineWidth\0")),
+      glLinkProgram: core::mem::transmute::<*const c_void, glLinkProgram_t>(get("glLinkProgram\0")),
+      glLogicOp: core::mem::transmute::<*const c_void, glLogicOp_t>(get("glLogicOp\0")),
+      glMapBuffer: core::mem::transmute::<*const c_void, glMapBuffer_t>(get("glMapBuffer\0")),
+      glMapBufferRange: core::mem::transmute::<*const c_void, glMapBufferRange_t>(get("glMapBufferRange\0")),
+      glMultiDrawArrays: core::mem::transmute::<*const c_void, glMultiDrawArrays_t>(get("glMultiDrawArrays\0")),
+      glMultiDrawElements: core::mem::transmute::<*const c_void, glMultiDrawElements_t>(get("glMultiDrawElements\0")),
+      glMultiDrawElementsBaseVertex: core::mem::transmute::<*const c_void, glMultiDrawElementsBaseVertex_t>(get("glMultiDrawElementsBaseVertex\0")),
+      glObjectLabel: core::mem::transmute::<*const c_void, glObjectLabel_t>(get_extension("glObjectLabel\0")),
+      glObjectPtrLabel: core::mem::transmute::<*const c_void, glObjectPtrLabel_t>(get_extension("glObjectPtrLabel\0")),
+      glPixelStoref: core::mem::transmute::<*const c_void, glPixelStoref_t>(get("glPixelStoref\0")),
+      glPixelStorei: core::mem::transmute::<*const c_void, glPixelStorei_t>(get("glPixelStorei\0")),
+      glPointParameterf: core::mem::transmute::<*const c_void, glPointParameterf_t>(get("glPointParameterf\0")),
+      glPointParameterfv: core::mem::transmute::<*const c_void, glPointParameterfv_t>(get("glPointParameterfv\0")),
+      glPointParameteri: core::mem::transmute::<*const c_void, glPointParameteri_t>(get("glPointParameteri\0")),
+      glPointParameteriv: core::mem::transmute::<*const c_void, glPointParameteriv_t>(get("glPointParameteriv\0")),
+      glPointSize: core::mem::transmute::<*const c_void, glPointSize_t>(get("glPointSize\0")),
+      glPolygonMode: core::mem::transmute::<*const c_void, glPolygonMode_t>(get("glPolygonMode\0")),
+      glPolygonOffset: core::mem::transmute::<*const c_void, glPolygonOffset_t>(get("glPolygonOffset\0")),
+      glPopDebugGroup: core::mem::transmute::<*const c_void, glPopDebugGroup_t>(get_extension("glPopDebugGroup\0")),
+      glPrimitiveRestartIndex: core::mem::transmute::<*const c_void, glPrimitiveRestartIndex_t>(get("glPrimitiveRestartIndex\0")),
+      glProvokingVertex: core::mem::transmute::<*const c_void, glProvokingVertex_t>(get("glProvokingVertex\0")),
+      glPushDebugGroup: core::mem::transmute::<*const c_void, glPushDebugGroup_t>(get_extension("glPushDebugGroup\0")),
+      glQueryCounter: core::mem::transmute::<*const c_void, glQueryCounter_t>(get("glQueryCounter\0")),
+      glReadBuffer: core::mem::transmute::<*const c_void, glReadBuffer_t>(get("glReadBuffer\0")),
+      glReadPixels: core::mem::transmute::<*const c_void, glReadPixels_t>(get("glReadPixels\0")),
+      glRenderbufferStorage: core::mem::transmute::<*const c_void, glRenderbufferStorage_t>(get("glRenderbufferStorage\0")),
+      glRenderbufferStorageMultisample: core::mem::transmute::<*const c_void, glRenderbufferStorageMultisample_t>(get("glRenderbufferStorageMultisample\0")),
+      glSampleCoverage: core::mem::transmute::<*const c_void, glSampleCoverage_t>(get("glSampleCoverage\0")),
+      glSampleMaski: core::mem::transmute::<*const c_void, glSampleMaski_t>(get("glSampleMaski\0")),
+      glSamplerParameterIiv: core::mem::transmute::<*const c_void, glSamplerParameterIiv_t>(get("glSamplerParameterIiv\0")),
+      glSamplerParameterIuiv: core::mem::transmute::<*const c_void, glSamplerParameterIuiv_t>(get("glSamplerParameterIuiv\0")),
+      glSamplerParameterf: core::mem::transmute::<*const c_void, glSamplerParameterf_t>(get("glSamplerParameterf\0")),
+      glSamplerParameterfv: core::mem::transmute::<*const c_void, glSamplerParameterfv_t>(get("glSamplerParameterfv\0")),
+      glSamplerParameteri: core::mem::transmute::<*const c_void, glSamplerParameteri_t>(get("glSamplerParameteri\0")),
+      glSamplerParameteriv: core::mem::transmute::<*const c_void, glSamplerParameteriv_t>(get("glSamplerParameteriv\0")),
+      glScissor: core::mem::transmute::<*const c_void, glScissor_t>(get("glScissor\0")),
+      glShaderSource: core::mem::transmute::<*const c_void, glShaderSource_t>(get("glShaderSource\0")),
+      glStencilFunc: core::mem::transmute::<*const c_void, glStencilFunc_t>(get("glStencilFunc\0")),
+      glStencilFuncSeparate: core::mem::transmute::<*const c_void, glStencilFuncSeparate_t>(get("glStencilFuncSeparate\0")),
+      glStencilMask: core::mem::transmute::<*const c_void, glStencilMask_t>(get("glStencilMask\0")),
+      glStencilMaskSeparate: core::mem::transmute::<*const c_void, glStencilMaskSeparate_t>(get("glStencilMaskSeparate\0")),
+      glStencilOp: core::mem::transmute::<*const c_void, glStencilOp_t>(get("glStencilOp\0")),
+      glStencilOpSeparate: core::mem::transmute::<*const c_void, glStencilOpSeparate_t>(get("glStencilOpSeparate\0")),
+      glTexBuffer: core::mem::transmute::<*const c_void, glTexBuffer_t>(get("glTexBuffer\0")),
+      glTexImage1D: core::mem::transmute::<*const c_void, glTexImage1D_t>(get("glTexImage1D\0")),
+      glTexImage2D: core::mem::transmute::<*const c_void, glTexImage2D_t>(get("glTexImage2D\0")),
+      glTexImage2DMultisample: core::mem::transmute::<*const c_void, glTexImage2DMultisample_t>(get("glTexImage2DMultisample\0")),
+      glTexImage3D: core::mem::transmute::<*const c_void, glTexImage3D_t>(get("glTexImage3D\0")),
+      glTexImage3DMultisample: core::mem::transmute::<*const c_void, glTexImage3DMultisample_t>(get("glTexImage3DMultisample\0")),
+      glTexParameterIiv: core::mem::transmute::<*const c_void, glTexParameterIiv_t>(get("glTexParameterIiv\0")),
+      glTexParameterIuiv: core::mem::transmute::<*const c_void, glTexParameterIuiv_t>(get("glTexParameterIuiv\0")),
+      glTexParameterf: core::mem::transmute::<*const c_void, glTexParameterf_t>(get("glTexParameterf\0")),
+      glTexParameterfv: core::mem::transmute::<*const c_void, glTexParameterfv_t>(get("glTexParameterfv\0")),
+      glTexParameteri: core::mem::transmute::<*const c_void, glTexParameteri_t>(get("glTexParameteri\0")),
+      glTexParameteriv: core::mem::transmute::<*const c_void, glTexParameteriv_t>(get("glTexParameteriv\0")),
+      glTexSubImage1D: core::mem::transmute::<*const c_void, glTexSubImage1D_t>(get("glTexSubImage1D\0")),
+      glTexSubImage2D: core::mem::transmute::<*const c_void, glTexSubImage2D_t>(get("glTexSubImage2D\0")),
+      glTexSubImage3D: core::mem::transmute::<*const c_void, glTexSubImage3D_t>(get("glTexSubImage3D\0")),
+      glTransformFeedbackVaryings: core::mem::transmute::<*const c_void, glTransformFeedbackVaryings_t>(get("glTransformFeedbackVaryings\0")),
+      glUniform1f: core::mem::transmute::<*const c_void, glUniform1f_t>(get("glUniform1f\0")),
+      glUniform1fv: core::mem::transmute::<*const c_void, glUniform1fv_t>(get("glUniform1fv\0")),
+      glUniform1i: core::mem::transmute::<*const c_void, glUniform1i_t>(get("glUniform1i\0")),
+      glUniform1iv: core::mem::transmute::<*const c_void, glUniform1iv_t>(get("glUniform1iv\0")),
+      glUniform1ui: core::mem::transmute::<*const c_void, glUniform1ui_t>(get("glUniform1ui\0")),
+      glUniform1uiv: core::mem::transmute::<*const c_void, glUniform1uiv_t>(get("glUniform1uiv\0")),
+      glUniform2f: core::mem::transmute::<*const c_void, glUniform2f_t>(get("glUniform2f\0")),
+      glUniform2fv: core::mem::transmute::<*const c_void, glUniform2fv_t>(get("glUniform2fv\0")),
+      glUniform2i: core::mem::transmute::<*const c_void, glUniform2i_t>(get("glUniform2i\0")),
+      glUniform2iv: core::mem::transmute::<*const c_void, glUniform2iv_t>(get("glUniform2iv\0")),
+      glUniform2ui: core::mem::transmute::<*const c_void, glUniform2ui_t>(get("glUniform2ui\0")),
+      glUniform2uiv: core::mem::transmute::<*const c_void, glUniform2uiv_t>(get("glUniform2uiv\0")),
+      glUniform3f: core::mem::transmute::<*const c_void, glUniform3f_t>(get("glUniform3f\0")),
+      glUniform3fv: core::mem::transmute::<*const c_void, glUniform3fv_t>(get("glUniform3fv\0")),
+      glUniform3i: core::mem::transmute::<*const c_void, glUniform3i_t>(get("glUniform3i\0")),
+      glUniform3iv: core::mem::transmute::<*const c_void, glUniform3iv_t>(get("glUniform3iv\0")),
+      glUniform3ui: core::mem::transmute::<*const c_void, glUniform3ui_t>(get("glUniform3ui\0")),
+      glUniform3uiv: core::mem::transmute::<*const c_void, glUniform3uiv_t>(get("glUniform3uiv\0")),
+      glUniform4f: core::mem::transmute::<*const c_void, glUniform4f_t>(get("glUniform4f\0")),
+      glUniform4fv: core::mem::transmute::<*const c_void, glUniform4fv_t>(get("glUniform4fv\0")),
+      glUniform4i: core::mem::transmute::<*const c_void, glUniform4i_t>(get("glUniform4i\0")),
+      glUniform4iv: core::mem::transmute::<*const c_void, glUniform4iv_t>(get("glUniform4iv\0")),
+      glUniform4ui: core::mem::transmute::<*const c_void, glUniform4ui_t>(get("glUniform4ui\0")),
+      glUniform4uiv: core::mem::transmute::<*const c_void, glUniform4uiv_t>(get("glUniform4uiv\0")),
+      glUniformBlockBinding: core::mem::transmute::<*const c_void, glUniformBlockBinding_t>(get("glUniformBlockBinding\0")),
+      glUniformMatrix2fv: core::mem::transmute::<*const c_void, glUniformMatrix2fv_t>(get("glUniformMatrix2fv\0")),
+      glUniformMatrix2x3fv: core::mem::transmute::<*const c_void, glUniformMatrix2x3fv_t>(get("glUniformMatrix2x3fv\0")),
+      glUniformMatrix2x4fv: core::mem::transmute::<*const c_void, glUniformMatrix2x4fv_t>(get("glUniformMatrix2x4fv\0")),
+      glUniformMatrix3fv: core::mem::transmute::<*const c_void, glUniformMatrix3fv_t>(get("glUniformMatrix3fv\0")),
+      glUniformMatrix3x2fv: core::mem::transmute::<*const c_void, glUniformMatrix3x2fv_t>(get("glUniformMatrix3x2fv\0")),
+      glUniformMatrix3x4fv: core::mem::transmute::<*const c_void, glUniformMatrix3x4fv_t>(get("glUniformMatrix3x4fv\0")),
+      glUniformMatrix4fv: core::mem::transmute::<*const c_void, glUniformMatrix4fv_t>(get("glUniformMatrix4fv\0")),
+      glUniformMatrix4x2fv: core::mem::transmute::<*const c_void, glUniformMatrix4x2fv_t>(get("glUniformMatrix4x2fv\0")),
+      glUniformMatrix4x3fv: core::mem::transmute::<*const c_void, glUniformMatrix4x3fv_t>(get("glUniformMatrix4x3fv\0")),
+      glUnmapBuffer: core::mem::transmute::<*const c_void, glUnmapBuffer_t>(get("glUnmapBuffer\0")),
+      glUseProgram: core::mem::transmute::<*const c_void, glUseProgram_t>(get("glUseProgram\0")),
+      glValidateProgram: core::mem::transmute::<*const c_void, glValidateProgram_t>(get("glValidateProgram\0")),
+      glVertexAttrib1d: core::mem::transmute::<*const c_void, glVertexAttrib1d_t>(get("glVertexAttrib1d\0")),
+      glVertexAttrib1dv: core::mem::transmute::<*const c_void, glVertexAttrib1dv_t>(get("glVertexAttrib1dv\0")),
+      glVertexAttrib1f: core::mem::transmute::<*const c_void, glVertexAttrib1f_t>(get("glVertexAttrib1f\0")),
+      glVertexAttrib1fv: core::mem::transmute::<*const c_void, glVertexAttrib1fv_t>(get("glVertexAttrib1fv\0")),
+      glVertexAttrib1s: core::mem::transmute::<*const c_void, glVertexAttrib1s_t>(get("glVertexAttrib1s\0")),
+      glVertexAttrib1sv: core::mem::transmute::<*const c_void, glVertexAttrib1sv_t>(get("glVertexAttrib1sv\0")),
+      glVertexAttrib2d: core::mem::transmute::<*const c_void, glVertexAttrib2d_t>(get("glVertexAttrib2d\0")),
+      glVertexAttrib2dv: core::mem::transmute::<*const c_void, glVertexAttrib2dv_t>(get("glVertexAttrib2dv\0")),
+      glVertexAttrib2f: core::mem::transmute::<*const c_void, glVertexAttrib2f_t>(get("glVertexAttrib2f\0")),
+      glVertexAttrib2fv: core::mem::transmute::<*const c_void, glVertexAttrib2fv_t>(get("glVertexAttrib2fv\0")),
+      glVertexAttrib2s: core::mem::transmute::<*const c_void, glVertexAttrib2s_t>(get("glVertexAttrib2s\0")),
+      glVertexAttrib2sv: core::mem::transmute::<*const c_void, glVertexAttrib2sv_t>(get("glVertexAttrib2sv\0")),
+      glVertexAttrib3d: core::mem::transmute::<*const c_void, glVertexAttrib3d_t>(get("glVertexAttrib3d\0")),
+      glVertexAttrib3dv: core::mem::transmute::<*const c_void, glVertexAttrib3dv_t>(get("glVertexAttrib3dv\0")),
+      glVertexAttrib3f: core::mem::transmute::<*const c_void, glVertexAttrib3f_t>(get("glVertexAttrib3f\0")),
+      glVertexAttrib3fv: core::mem::transmute::<*const c_void, glVertexAttrib3fv_t>(get("glVertexAttrib3fv\0")),
+      glVertexAttrib3s: core::mem::transmute::<*const c_void, glVertexAttrib3s_t>(get("glVertexAttrib3s\0")),
+      glVertexAttrib3sv: core::mem::transmute::<*const c_void, glVertexAttrib3sv_t>(get("glVertexAttrib3sv\0")),
+      glVertexAttrib4Nbv: core::mem::transmute::<*const c_void, glVertexAttrib4Nbv_t>(get("glVertexAttrib4Nbv\0")),
+      glVertexAttrib4Niv: core::mem::transmute::<*const c_void, glVertexAttrib4Niv_t>(get("glVertexAttrib4Niv\0")),
+      glVertexAttrib4Nsv: core::mem::transmute::<*const c_void, glVertexAttrib4Nsv_t>(get("glVertexAttrib4Nsv\0")),
+      glVertexAttrib4Nub: core::mem::transmute::<*const c_void, glVertexAttrib4Nub_t>(get("glVertexAttrib4Nub\0")),
+      glVertexAttrib4Nubv: core::mem::transmute::<*const c_void, glVertexAttrib4Nubv_t>(get("glVertexAttrib4Nubv\0")),
+      glVertexAttrib4Nuiv: core::mem::transmute::<*const c_void, glVertexAttrib4Nuiv_t>(get("glVertexAttrib4Nuiv\0")),
+      glVertexAttrib4Nusv: core::mem::transmute::<*const c_void, glVertexAttrib4Nusv_t>(get("glVertexAttrib4Nusv\0")),
+      glVertexAttrib4bv: core::mem::transmute::<*const c_void, glVertexAttrib4bv_t>(get("glVertexAttrib4bv\0")),
+      glVertexAttrib4d: core::mem::transmute::<*const c_void, glVertexAttrib4d_t>(get("glVertexAttrib4d\0")),
+      glVertexAttrib4dv: core::mem::transmute::<*const c_void, glVertexAttrib4dv_t>(get("glVertexAttrib4dv\0")),
+      glVertexAttrib4f: core::mem::transmute::<*const c_void, glVertexAttrib4f_t>(get("glVertexAttrib4f\0")),
+      glVertexAttrib4fv: core::mem::transmute::<*const c_void, glVertexAttrib4fv_t>(get("glVertexAttrib4fv\0")),
+      glVertexAttrib4iv: core::mem::transmute::<*const c_void, glVertexAttrib4iv_t>(get("glVertexAttrib4iv\0")),
+      glVertexAttrib4s: core::mem::transmute::<*const c_void, glVertexAttrib4s_t>(get("glVertexAttrib4s\0")),
+      glVertexAttrib4sv: core::mem::transmute::<*const c_void, glVertexAttrib4sv_t>(get("glVertexAttrib4sv\0")),
+      glVertexAttrib4ubv: core::mem::transmute::<*const c_void, glVertexAttrib4ubv_t>(get("glVertexAttrib4ubv\0")),
+      glVertexAttrib4uiv: core::mem::transmute::<*const c_void, glVertexAttrib4uiv_t>(get("glVertexAttrib4uiv\0")),
+      glVertexAttrib4usv: core::mem::transmute::<*const c_void, glVertexAttrib4usv_t>(get("glVertexAttrib4usv\0")),
+      glVertexAttribDivisor: core::mem::transmute::<*const c_void, glVertexAttribDivisor_t>(get("glVertexAttribDivisor\0")),
+      glVertexAttribI1i: core::mem::transmute::<*const c_void, glVertexAttribI1i_t>(get("glVertexAttribI1i\0")),
+      glVertexAttribI1iv: core::mem::transmute::<*const c_void, glVertexAttribI1iv_t>(get("glVertexAttribI1iv\0")),
+      glVertexAttribI1ui: core::mem::transmute::<*const c_void, glVertexAttribI1ui_t>(get("glVertexAttribI1ui\0")),
+      glVertexAttribI1uiv: core::mem::transmute::<*const c_void, glVertexAttribI1uiv_t>(get("glVertexAttribI1uiv\0")),
+      glVertexAttribI2i: core::mem::transmute::<*const c_void, glVertexAttribI2i_t>(get("glVertexAttribI2i\0")),
+      glVertexAttribI2iv: core::mem::transmute::<*const c_void, glVertexAttribI2iv_t>(get("glVertexAttribI2iv\0")),
+      glVertexAttribI2ui: core::mem::transmute::<*const c_void, glVertexAttribI2ui_t>(get("glVertexAttribI2ui\0")),
+      glVertexAttribI2uiv: core::mem::transmute::<*const c_void, glVertexAttribI2uiv_t>(get("glVertexAttribI2uiv\0")),
+      glVertexAttribI3i: core::mem::transmute::<*const c_void, glVertexAttribI3i_t>(get("glVertexAttribI3i\0")),
+      glVertexAttribI3iv: core::mem::transmute::<*const c_void, glVertexAttribI3iv_t>(get("glVertexAttribI3iv\0")),
+      glVertexAttribI3ui: core::mem::transmute::<*const c_void, glVertexAttribI3ui_t>(get("glVertexAttribI3ui\0")),
+      glVertexAttribI3uiv: core::mem::transmute::<*const c_void, glVertexAttribI3uiv_t>(get("glVertexAttribI3uiv\0")),
+      glVertexAttribI4bv: core::mem::transmute::<*const c_void, glVertexAttribI4bv_t>(get("glVertexAttribI4bv\0")),
+      glVertexAttribI4i: core::mem::transmute::<*const c_void, glVertexAttribI4i_t>(get("glVertexAttribI4i\0")),
+      glVertexAttribI4iv: core::mem::transmute::<*const c_void, glVertexAttribI4iv_t>(get("glVertexAttribI4iv\0")),
+      glVertexAttribI4sv: core::mem::transmute::<*const c_void, glVertexAttribI4sv_t>(get("glVertexAttribI4sv\0")),
+      glVertexAttribI4ubv: core::mem::transmute::<*const c_void, glVertexAttribI4ubv_t>(get("glVertexAttribI4ubv\0")),
+      glVertexAttribI4ui: core::mem::transmute::<*const c_void, glVertexAttribI4ui_t>(get("glVertexAttribI4ui\0")),
+      glVertexAttribI4uiv: core::mem::transmute::<*const c_void, glVertexAttribI4uiv_t>(get("glVertexAttribI4uiv\0")),
+      glVertexAttribI4usv: core::mem::transmute::<*const c_void, glVertexAttribI4usv_t>(get("glVertexAttribI4usv\0")),
+      glVertexAttribIPointer: core::mem::transmute::<*const c_void, glVertexAttribIPointer_t>(get("glVertexAttribIPointer\0")),
+      glVertexAttribP1ui: core::mem::transmute::<*const c_void, glVertexAttribP1ui_t>(get("glVertexAttribP1ui\0")),
+      glVertexAttribP1uiv: core::mem::transmute::<*const c_void, glVertexAttribP1uiv_t>(get("glVertexAttribP1uiv\0")),
+      glVertexAttribP2ui: core::mem::transmute::<*const c_void, glVertexAttribP2ui_t>(get("glVertexAttribP2ui\0")),
+      glVertexAttribP2uiv: core::mem::transmute::<*const c_void, glVertexAttribP2uiv_t>(get("glVertexAttribP2uiv\0")),
+      glVertexAttribP3ui: core::mem::transmute::<*const c_void, glVertexAttribP3ui_t>(get("glVertexAttribP3ui\0")),
+      glVertexAttribP3uiv: core::mem::transmute::<*const c_void, glVertexAttribP3uiv_t>(get("glVertexAttribP3uiv\0")),
+      glVertexAttribP4ui: core::mem::transmute::<*const c_void, glVertexAttribP4ui_t>(get("glVertexAttribP4ui\0")),
+      glVertexAttribP4uiv: core::mem::transmute::<*const c_void, glVertexAttribP4uiv_t>(get("glVertexAttribP4uiv\0")),
+      glVertexAttribPointer: core::mem::transmute::<*const c_void, glVertexAttribPointer_t>(get("glVertexAttribPointer\0")),
+      glViewport: core::mem::transmute::<*const c_void, glViewport_t>(get("glViewport\0")),
+      glWaitSync: core::mem::transmute::<*const c_void, glWaitSync_t>(get("glWaitSync\0")),
+    };
+    (table, missing)
+  }
+
+  /// If every function that `GL_KHR_debug` adds to the version was loaded.
+  pub fn loaded_GL_KHR_debug(&self) -> bool {
+    self.glDebugMessageCallback.is_some()
+      && self.glDebugMessageControl.is_some()
+      && self.glDebugMessageInsert.is_some()
+      && self.glGetDebugMessageLog.is_some()
+      && self.glGetObjectLabel.is_some()
+      && self.glGetObjectPtrLabel.is_some()
+      && self.glGetPointerv.is_some()
+      && self.glObjectLabel.is_some()
+      && self.glObjectPtrLabel.is_some()
+      && self.glPopDebugGroup.is_some()
+      && self.glPushDebugGroup.is_some()
+  }
+
+  /// From `gl.xml`: `void glActiveTexture(GLenum texture)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ActiveTexture(&self, texture: GLenum) {
+    match self.glActiveTexture {
+      Some(fn_ptr) => fn_ptr(texture),
+      None => not_loaded("glActiveTexture"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glActiveTexture",
+      &["texture"],
+      &[TraceValue::Enum(texture)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glActiveTexture",
+      &[("texture", &texture)],
+    );
+  }
+
+  /// From `gl.xml`: `void glAttachShader(GLuint program, GLuint shader)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn AttachShader(&self, program: GLuint, shader: GLuint) {
+    match self.glAttachShader {
+      Some(fn_ptr) => fn_ptr(program, shader),
+      None => not_loaded("glAttachShader"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glAttachShader",
+      &["program", "shader"],
+      &[TraceValue::UInt(program as u64), TraceValue::UInt(shader as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glAttachShader",
+      &[("program", &program), ("shader", &shader)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBeginConditionalRender(GLuint id, GLenum mode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BeginConditionalRender(&self, id: GLuint, mode: GLenum) {
+    match self.glBeginConditionalRender {
+      Some(fn_ptr) => fn_ptr(id, mode),
+      None => not_loaded("glBeginConditionalRender"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBeginConditionalRender",
+      &["id", "mode"],
+      &[TraceValue::UInt(id as u64), TraceValue::Enum(mode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBeginConditionalRender",
+      &[("id", &id), ("mode", &mode)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBeginQuery(GLenum target, GLuint id)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BeginQuery(&self, target: GLenum, id: GLuint) {
+    match self.glBeginQuery {
+      Some(fn_ptr) => fn_ptr(target, id),
+      None => not_loaded("glBeginQuery"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBeginQuery",
+      &["target", "id"],
+      &[TraceValue::Enum(target), TraceValue::UInt(id as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBeginQuery",
+      &[("target", &target), ("id", &id)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBeginTransformFeedback(GLenum primitiveMode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BeginTransformFeedback(&self, primitiveMode: GLenum) {
+    match self.glBeginTransformFeedback {
+      Some(fn_ptr) => fn_ptr(primitiveMode),
+      None => not_loaded("glBeginTransformFeedback"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBeginTransformFeedback",
+      &["primitiveMode"],
+      &[TraceValue::Enum(primitiveMode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBeginTransformFeedback",
+      &[("primitiveMode", &primitiveMode)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindAttribLocation(GLuint program, GLuint index, const GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindAttribLocation(
+    &self,
+    program: GLuint,
+    index: GLuint,
+    name: *const GLchar,
+  ) {
+    match self.glBindAttribLocation {
+      Some(fn_ptr) => fn_ptr(program, index, name),
+      None => not_loaded("glBindAttribLocation"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindAttribLocation",
+      &["program", "index", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(name as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindAttribLocation",
+      &[("program", &program), ("index", &index), ("name", &name)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindBuffer(GLenum target, GLuint buffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindBuffer(&self, target: GLenum, buffer: GLuint) {
+    match self.glBindBuffer {
+      Some(fn_ptr) => fn_ptr(target, buffer),
+      None => not_loaded("glBindBuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindBuffer",
+      &["target", "buffer"],
+      &[TraceValue::Enum(target), TraceValue::UInt(buffer as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindBuffer",
+      &[("target", &target), ("buffer", &buffer)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindBufferBase(GLenum target, GLuint index, GLuint buffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindBufferBase(
+    &self,
+    target: GLenum,
+    index: GLuint,
+    buffer: GLuint,
+  ) {
+    match self.glBindBufferBase {
+      Some(fn_ptr) => fn_ptr(target, index, buffer),
+      None => not_loaded("glBindBufferBase"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindBufferBase",
+      &["target", "index", "buffer"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::UInt(index as u64),
+        TraceValue::UInt(buffer as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindBufferBase",
+      &[("target", &target), ("index", &index), ("buffer", &buffer)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindBufferRange(GLenum target, GLuint index, GLuint buffer, GLintptr offset, GLsizeiptr size)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindBufferRange(
+    &self,
+    target: GLenum,
+    index: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+  ) {
+    match self.glBindBufferRange {
+      Some(fn_ptr) => fn_ptr(target, index, buffer, offset, size),
+      None => not_loaded("glBindBufferRange"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindBufferRange",
+      &["target", "index", "buffer", "offset", "size"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::UInt(index as u64),
+        TraceValue::UInt(buffer as u64),
+        TraceValue::Int(offset as i64),
+        TraceValue::Int(size as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindBufferRange",
+      &[
+        ("target", &target),
+        ("index", &index),
+        ("buffer", &buffer),
+        ("offset", &offset),
+        ("size", &size),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindFragDataLocation(GLuint program, GLuint color, const GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindFragDataLocation(
+    &self,
+    program: GLuint,
+    color: GLuint,
+    name: *const GLchar,
+  ) {
+    match self.glBindFragDataLocation {
+      Some(fn_ptr) => fn_ptr(program, color, name),
+      None => not_loaded("glBindFragDataLocation"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindFragDataLocation",
+      &["program", "color", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(color as u64),
+        TraceValue::Pointer(name as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindFragDataLocation",
+      &[("program", &program), ("color", &color), ("name", &name)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindFragDataLocationIndexed(GLuint program, GLuint colorNumber, GLuint index, const GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindFragDataLocationIndexed(
+    &self,
+    program: GLuint,
+    colorNumber: GLuint,
+    index: GLuint,
+    name: *const GLchar,
+  ) {
+    match self.glBindFragDataLocationIndexed {
+      Some(fn_ptr) => fn_ptr(program, colorNumber, index, name),
+      None => not_loaded("glBindFragDataLocationIndexed"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindFragDataLocationIndexed",
+      &["program", "colorNumber", "index", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(colorNumber as u64),
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(name as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindFragDataLocationIndexed",
+      &[
+        ("program", &program),
+        ("colorNumber", &colorNumber),
+        ("index", &index),
+        ("name", &name),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindFramebuffer(GLenum target, GLuint framebuffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindFramebuffer(&self, target: GLenum, framebuffer: GLuint) {
+    match self.glBindFramebuffer {
+      Some(fn_ptr) => fn_ptr(target, framebuffer),
+      None => not_loaded("glBindFramebuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindFramebuffer",
+      &["target", "framebuffer"],
+      &[TraceValue::Enum(target), TraceValue::UInt(framebuffer as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindFramebuffer",
+      &[("target", &target), ("framebuffer", &framebuffer)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindRenderbuffer(GLenum target, GLuint renderbuffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindRenderbuffer(&self, target: GLenum, renderbuffer: GLuint) {
+    match self.glBindRenderbuffer {
+      Some(fn_ptr) => fn_ptr(target, renderbuffer),
+      None => not_loaded("glBindRenderbuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindRenderbuffer",
+      &["target", "renderbuffer"],
+      &[TraceValue::Enum(target), TraceValue::UInt(renderbuffer as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindRenderbuffer",
+      &[("target", &target), ("renderbuffer", &renderbuffer)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindSampler(GLuint unit, GLuint sampler)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindSampler(&self, unit: GLuint, sampler: GLuint) {
+    match self.glBindSampler {
+      Some(fn_ptr) => fn_ptr(unit, sampler),
+      None => not_loaded("glBindSampler"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindSampler",
+      &["unit", "sampler"],
+      &[TraceValue::UInt(unit as u64), TraceValue::UInt(sampler as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindSampler",
+      &[("unit", &unit), ("sampler", &sampler)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindTexture(GLenum target, GLuint texture)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindTexture(&self, target: GLenum, texture: GLuint) {
+    match self.glBindTexture {
+      Some(fn_ptr) => fn_ptr(target, texture),
+      None => not_loaded("glBindTexture"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindTexture",
+      &["target", "texture"],
+      &[TraceValue::Enum(target), TraceValue::UInt(texture as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBindTexture",
+      &[("target", &target), ("texture", &texture)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBindVertexArray(GLuint array)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BindVertexArray(&self, array: GLuint) {
+    match self.glBindVertexArray {
+      Some(fn_ptr) => fn_ptr(array),
+      None => not_loaded("glBindVertexArray"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBindVertexArray",
+      &["array"],
+      &[TraceValue::UInt(array as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glBindVertexArray", &[("array", &array)]);
+  }
+
+  /// From `gl.xml`: `void glBlendColor(GLfloat red, GLfloat green, GLfloat blue, GLfloat alpha)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BlendColor(
+    &self,
+    red: GLfloat,
+    green: GLfloat,
+    blue: GLfloat,
+    alpha: GLfloat,
+  ) {
+    match self.glBlendColor {
+      Some(fn_ptr) => fn_ptr(red, green, blue, alpha),
+      None => not_loaded("glBlendColor"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBlendColor",
+      &["red", "green", "blue", "alpha"],
+      &[
+        TraceValue::Float(red as f64),
+        TraceValue::Float(green as f64),
+        TraceValue::Float(blue as f64),
+        TraceValue::Float(alpha as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBlendColor",
+      &[("red", &red), ("green", &green), ("blue", &blue), ("alpha", &alpha)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBlendEquation(GLenum mode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BlendEquation(&self, mode: GLenum) {
+    match self.glBlendEquation {
+      Some(fn_ptr) => fn_ptr(mode),
+      None => not_loaded("glBlendEquation"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBlendEquation",
+      &["mode"],
+      &[TraceValue::Enum(mode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glBlendEquation", &[("mode", &mode)]);
+  }
+
+  /// From `gl.xml`: `void glBlendEquationSeparate(GLenum modeRGB, GLenum modeAlpha)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BlendEquationSeparate(
+    &self,
+    modeRGB: GLenum,
+    modeAlpha: GLenum,
+  ) {
+    match self.glBlendEquationSeparate {
+      Some(fn_ptr) => fn_ptr(modeRGB, modeAlpha),
+      None => not_loaded("glBlendEquationSeparate"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBlendEquationSeparate",
+      &["modeRGB", "modeAlpha"],
+      &[TraceValue::Enum(modeRGB), TraceValue::Enum(modeAlpha)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBlendEquationSeparate",
+      &[("modeRGB", &modeRGB), ("modeAlpha", &modeAlpha)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBlendFunc(GLenum sfactor, GLenum dfactor)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BlendFunc(&self, sfactor: GLenum, dfactor: GLenum) {
+    match self.glBlendFunc {
+      Some(fn_ptr) => fn_ptr(sfactor, dfactor),
+      None => not_loaded("glBlendFunc"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBlendFunc",
+      &["sfactor", "dfactor"],
+      &[TraceValue::Enum(sfactor), TraceValue::Enum(dfactor)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBlendFunc",
+      &[("sfactor", &sfactor), ("dfactor", &dfactor)],
+    );
+  }
+
+  /// From `gl.xml`: `void glBlendFuncSeparate(GLenum sfactorRGB, GLenum dfactorRGB, GLenum sfactorAlpha, GLenum dfactorAlpha)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BlendFuncSeparate(
+    &self,
+    sfactorRGB: GLenum,
+    dfactorRGB: GLenum,
+    sfactorAlpha: GLenum,
+    dfactorAlpha: GLenum,
+  ) {
+    match self.glBlendFuncSeparate {
+      Some(fn_ptr) => fn_ptr(sfactorRGB, dfactorRGB, sfactorAlpha, dfactorAlpha),
+      None => not_loaded("glBlendFuncSeparate"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBlendFuncSeparate",
+      &["sfactorRGB", "dfactorRGB", "sfactorAlpha", "dfactorAlpha"],
+      &[
+        TraceValue::Enum(sfactorRGB),
+        TraceValue::Enum(dfactorRGB),
+        TraceValue::Enum(sfactorAlpha),
+        TraceValue::Enum(dfactorAlpha),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBlendFuncSeparate",
+      &[
+        ("sfactorRGB", &sfactorRGB),
+        ("dfactorRGB", &dfactorRGB),
+        ("sfactorAlpha", &sfactorAlpha),
+        ("dfactorAlpha", &dfactorAlpha),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glBlitFramebuffer(GLint srcX0, GLint srcY0, GLint srcX1, GLint srcY1, GLint dstX0, GLint dstY0, GLint dstX1, GLint dstY1, GLbitfield mask, GLenum filter)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BlitFramebuffer(
+    &self,
+    srcX0: GLint,
+    srcY0: GLint,
+    srcX1: GLint,
+    srcY1: GLint,
+    dstX0: GLint,
+    dstY0: GLint,
+    dstX1: GLint,
+    dstY1: GLint,
+    mask: GLbitfield,
+    filter: GLenum,
+  ) {
+    match self.glBlitFramebuffer {
+      Some(fn_ptr) => fn_ptr(srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, filter),
+      None => not_loaded("glBlitFramebuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBlitFramebuffer",
+      &[
+        "srcX0",
+        "srcY0",
+        "srcX1",
+        "srcY1",
+        "dstX0",
+        "dstY0",
+        "dstX1",
+        "dstY1",
+        "mask",
+        "filter",
+      ],
+      &[
+        TraceValue::Int(srcX0 as i64),
+        TraceValue::Int(srcY0 as i64),
+        TraceValue::Int(srcX1 as i64),
+        TraceValue::Int(srcY1 as i64),
+        TraceValue::Int(dstX0 as i64),
+        TraceValue::Int(dstY0 as i64),
+        TraceValue::Int(dstX1 as i64),
+        TraceValue::Int(dstY1 as i64),
+        TraceValue::UInt(mask as u64),
+        TraceValue::Enum(filter),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBlitFramebuffer",
+      &[
+        ("srcX0", &srcX0),
+        ("srcY0", &srcY0),
+        ("srcX1", &srcX1),
+        ("srcY1", &srcY1),
+        ("dstX0", &dstX0),
+        ("dstY0", &dstY0),
+        ("dstX1", &dstX1),
+        ("dstY1", &dstY1),
+        ("mask", &mask),
+        ("filter", &filter),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glBufferData(GLenum target, GLsizeiptr size, const void *data, GLenum usage)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BufferData(
+    &self,
+    target: GLenum,
+    size: GLsizeiptr,
+    data: *const c_void,
+    usage: GLenum,
+  ) {
+    match self.glBufferData {
+      Some(fn_ptr) => fn_ptr(target, size, data, usage),
+      None => not_loaded("glBufferData"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBufferData",
+      &["target", "size", "data", "usage"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(size as i64),
+        TraceValue::Pointer(data as usize as u64),
+        TraceValue::Enum(usage),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBufferData",
+      &[
+        ("target", &target),
+        ("size", &size),
+        ("data", &data),
+        ("usage", &usage),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glBufferSubData(GLenum target, GLintptr offset, GLsizeiptr size, const void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn BufferSubData(
+    &self,
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const c_void,
+  ) {
+    match self.glBufferSubData {
+      Some(fn_ptr) => fn_ptr(target, offset, size, data),
+      None => not_loaded("glBufferSubData"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glBufferSubData",
+      &["target", "offset", "size", "data"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(offset as i64),
+        TraceValue::Int(size as i64),
+        TraceValue::Pointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glBufferSubData",
+      &[
+        ("target", &target),
+        ("offset", &offset),
+        ("size", &size),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `GLenum glCheckFramebufferStatus(GLenum target)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CheckFramebufferStatus(&self, target: GLenum) -> GLenum {
+    let out = match self.glCheckFramebufferStatus {
+      Some(fn_ptr) => fn_ptr(target),
+      None => not_loaded("glCheckFramebufferStatus"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCheckFramebufferStatus",
+      &["target"],
+      &[TraceValue::Enum(target)],
+      TraceValue::Enum(out),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCheckFramebufferStatus",
+      &[("target", &target)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glClampColor(GLenum target, GLenum clamp)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClampColor(&self, target: GLenum, clamp: GLenum) {
+    match self.glClampColor {
+      Some(fn_ptr) => fn_ptr(target, clamp),
+      None => not_loaded("glClampColor"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClampColor",
+      &["target", "clamp"],
+      &[TraceValue::Enum(target), TraceValue::Enum(clamp)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glClampColor",
+      &[("target", &target), ("clamp", &clamp)],
+    );
+  }
+
+  /// From `gl.xml`: `void glClear(GLbitfield mask)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Clear(&self, mask: GLbitfield) {
+    match self.glClear {
+      Some(fn_ptr) => fn_ptr(mask),
+      None => not_loaded("glClear"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClear",
+      &["mask"],
+      &[TraceValue::UInt(mask as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glClear", &[("mask", &mask)]);
+  }
+
+  /// From `gl.xml`: `void glClearBufferfi(GLenum buffer, GLint drawbuffer, GLfloat depth, GLint stencil)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClearBufferfi(
+    &self,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    depth: GLfloat,
+    stencil: GLint,
+  ) {
+    match self.glClearBufferfi {
+      Some(fn_ptr) => fn_ptr(buffer, drawbuffer, depth, stencil),
+      None => not_loaded("glClearBufferfi"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClearBufferfi",
+      &["buffer", "drawbuffer", "depth", "stencil"],
+      &[
+        TraceValue::Enum(buffer),
+        TraceValue::Int(drawbuffer as i64),
+        TraceValue::Float(depth as f64),
+        TraceValue::Int(stencil as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glClearBufferfi",
+      &[
+        ("buffer", &buffer),
+        ("drawbuffer", &drawbuffer),
+        ("depth", &depth),
+        ("stencil", &stencil),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glClearBufferfv(GLenum buffer, GLint drawbuffer, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClearBufferfv(
+    &self,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLfloat,
+  ) {
+    match self.glClearBufferfv {
+      Some(fn_ptr) => fn_ptr(buffer, drawbuffer, value),
+      None => not_loaded("glClearBufferfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClearBufferfv",
+      &["buffer", "drawbuffer", "value"],
+      &[
+        TraceValue::Enum(buffer),
+        TraceValue::Int(drawbuffer as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glClearBufferfv",
+      &[("buffer", &buffer), ("drawbuffer", &drawbuffer), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glClearBufferiv(GLenum buffer, GLint drawbuffer, const GLint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClearBufferiv(
+    &self,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLint,
+  ) {
+    match self.glClearBufferiv {
+      Some(fn_ptr) => fn_ptr(buffer, drawbuffer, value),
+      None => not_loaded("glClearBufferiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClearBufferiv",
+      &["buffer", "drawbuffer", "value"],
+      &[
+        TraceValue::Enum(buffer),
+        TraceValue::Int(drawbuffer as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glClearBufferiv",
+      &[("buffer", &buffer), ("drawbuffer", &drawbuffer), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glClearBufferuiv(GLenum buffer, GLint drawbuffer, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClearBufferuiv(
+    &self,
+    buffer: GLenum,
+    drawbuffer: GLint,
+    value: *const GLuint,
+  ) {
+    match self.glClearBufferuiv {
+      Some(fn_ptr) => fn_ptr(buffer, drawbuffer, value),
+      None => not_loaded("glClearBufferuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClearBufferuiv",
+      &["buffer", "drawbuffer", "value"],
+      &[
+        TraceValue::Enum(buffer),
+        TraceValue::Int(drawbuffer as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glClearBufferuiv",
+      &[("buffer", &buffer), ("drawbuffer", &drawbuffer), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glClearColor(GLfloat red, GLfloat green, GLfloat blue, GLfloat alpha)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClearColor(
+    &self,
+    red: GLfloat,
+    green: GLfloat,
+    blue: GLfloat,
+    alpha: GLfloat,
+  ) {
+    match self.glClearColor {
+      Some(fn_ptr) => fn_ptr(red, green, blue, alpha),
+      None => not_loaded("glClearColor"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClearColor",
+      &["red", "green", "blue", "alpha"],
+      &[
+        TraceValue::Float(red as f64),
+        TraceValue::Float(green as f64),
+        TraceValue::Float(blue as f64),
+        TraceValue::Float(alpha as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glClearColor",
+      &[("red", &red), ("green", &green), ("blue", &blue), ("alpha", &alpha)],
+    );
+  }
+
+  /// From `gl.xml`: `void glClearDepth(GLdouble depth)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClearDepth(&self, depth: GLdouble) {
+    match self.glClearDepth {
+      Some(fn_ptr) => fn_ptr(depth),
+      None => not_loaded("glClearDepth"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClearDepth",
+      &["depth"],
+      &[TraceValue::Float(depth)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glClearDepth", &[("depth", &depth)]);
+  }
+
+  /// From `gl.xml`: `void glClearStencil(GLint s)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClearStencil(&self, s: GLint) {
+    match self.glClearStencil {
+      Some(fn_ptr) => fn_ptr(s),
+      None => not_loaded("glClearStencil"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClearStencil",
+      &["s"],
+      &[TraceValue::Int(s as i64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glClearStencil", &[("s", &s)]);
+  }
+
+  /// From `gl.xml`: `GLenum glClientWaitSync(GLsync sync, GLbitfield flags, GLuint64 timeout)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ClientWaitSync(
+    &self,
+    sync: GLsync,
+    flags: GLbitfield,
+    timeout: GLuint64,
+  ) -> GLenum {
+    let out = match self.glClientWaitSync {
+      Some(fn_ptr) => fn_ptr(sync, flags, timeout),
+      None => not_loaded("glClientWaitSync"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glClientWaitSync",
+      &["sync", "flags", "timeout"],
+      &[
+        TraceValue::Handle(sync as usize as u64),
+        TraceValue::UInt(flags as u64),
+        TraceValue::UInt(timeout),
+      ],
+      TraceValue::Enum(out),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glClientWaitSync",
+      &[("sync", &sync), ("flags", &flags), ("timeout", &timeout)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glColorMask(GLboolean red, GLboolean green, GLboolean blue, GLboolean alpha)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ColorMask(
+    &self,
+    red: GLboolean,
+    green: GLboolean,
+    blue: GLboolean,
+    alpha: GLboolean,
+  ) {
+    match self.glColorMask {
+      Some(fn_ptr) => fn_ptr(red, green, blue, alpha),
+      None => not_loaded("glColorMask"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glColorMask",
+      &["red", "green", "blue", "alpha"],
+      &[
+        TraceValue::UInt(red as u64),
+        TraceValue::UInt(green as u64),
+        TraceValue::UInt(blue as u64),
+        TraceValue::UInt(alpha as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glColorMask",
+      &[("red", &red), ("green", &green), ("blue", &blue), ("alpha", &alpha)],
+    );
+  }
+
+  /// From `gl.xml`: `void glColorMaski(GLuint index, GLboolean r, GLboolean g, GLboolean b, GLboolean a)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ColorMaski(
+    &self,
+    index: GLuint,
+    r: GLboolean,
+    g: GLboolean,
+    b: GLboolean,
+    a: GLboolean,
+  ) {
+    match self.glColorMaski {
+      Some(fn_ptr) => fn_ptr(index, r, g, b, a),
+      None => not_loaded("glColorMaski"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glColorMaski",
+      &["index", "r", "g", "b", "a"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::UInt(r as u64),
+        TraceValue::UInt(g as u64),
+        TraceValue::UInt(b as u64),
+        TraceValue::UInt(a as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glColorMaski",
+      &[("index", &index), ("r", &r), ("g", &g), ("b", &b), ("a", &a)],
+    );
+  }
+
+  /// From `gl.xml`: `void glCompileShader(GLuint shader)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CompileShader(&self, shader: GLuint) {
+    match self.glCompileShader {
+      Some(fn_ptr) => fn_ptr(shader),
+      None => not_loaded("glCompileShader"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCompileShader",
+      &["shader"],
+      &[TraceValue::UInt(shader as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glCompileShader", &[("shader", &shader)]);
+  }
+
+  /// From `gl.xml`: `void glCompressedTexImage1D(GLenum target, GLint level, GLenum internalformat, GLsizei width, GLint border, GLsizei imageSize, const void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CompressedTexImage1D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    width: GLsizei,
+    border: GLint,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ) {
+    match self.glCompressedTexImage1D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, width, border, imageSize, data),
+      None => not_loaded("glCompressedTexImage1D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCompressedTexImage1D",
+      &[
+        "target",
+        "level",
+        "internalformat",
+        "width",
+        "border",
+        "imageSize",
+        "data",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(border as i64),
+        TraceValue::Int(imageSize as i64),
+        TraceValue::Pointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCompressedTexImage1D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("border", &border),
+        ("imageSize", &imageSize),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCompressedTexImage2D(GLenum target, GLint level, GLenum internalformat, GLsizei width, GLsizei height, GLint border, GLsizei imageSize, const void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CompressedTexImage2D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ) {
+    match self.glCompressedTexImage2D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, width, height, border, imageSize, data),
+      None => not_loaded("glCompressedTexImage2D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCompressedTexImage2D",
+      &[
+        "target",
+        "level",
+        "internalformat",
+        "width",
+        "height",
+        "border",
+        "imageSize",
+        "data",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(border as i64),
+        TraceValue::Int(imageSize as i64),
+        TraceValue::Pointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCompressedTexImage2D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+        ("border", &border),
+        ("imageSize", &imageSize),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCompressedTexImage3D(GLenum target, GLint level, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth, GLint border, GLsizei imageSize, const void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CompressedTexImage3D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    border: GLint,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ) {
+    match self.glCompressedTexImage3D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, width, height, depth, border, imageSize, data),
+      None => not_loaded("glCompressedTexImage3D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCompressedTexImage3D",
+      &[
+        "target",
+        "level",
+        "internalformat",
+        "width",
+        "height",
+        "depth",
+        "border",
+        "imageSize",
+        "data",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(depth as i64),
+        TraceValue::Int(border as i64),
+        TraceValue::Int(imageSize as i64),
+        TraceValue::Pointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCompressedTexImage3D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+        ("depth", &depth),
+        ("border", &border),
+        ("imageSize", &imageSize),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCompressedTexSubImage1D(GLenum target, GLint level, GLint xoffset, GLsizei width, GLenum format, GLsizei imageSize, const void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CompressedTexSubImage1D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    width: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ) {
+    match self.glCompressedTexSubImage1D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, width, format, imageSize, data),
+      None => not_loaded("glCompressedTexSubImage1D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCompressedTexSubImage1D",
+      &["target", "level", "xoffset", "width", "format", "imageSize", "data"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Enum(format),
+        TraceValue::Int(imageSize as i64),
+        TraceValue::Pointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCompressedTexSubImage1D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("width", &width),
+        ("format", &format),
+        ("imageSize", &imageSize),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCompressedTexSubImage2D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLsizei width, GLsizei height, GLenum format, GLsizei imageSize, const void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CompressedTexSubImage2D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ) {
+    match self.glCompressedTexSubImage2D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, yoffset, width, height, format, imageSize, data),
+      None => not_loaded("glCompressedTexSubImage2D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCompressedTexSubImage2D",
+      &[
+        "target",
+        "level",
+        "xoffset",
+        "yoffset",
+        "width",
+        "height",
+        "format",
+        "imageSize",
+        "data",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(yoffset as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Enum(format),
+        TraceValue::Int(imageSize as i64),
+        TraceValue::Pointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCompressedTexSubImage2D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("yoffset", &yoffset),
+        ("width", &width),
+        ("height", &height),
+        ("format", &format),
+        ("imageSize", &imageSize),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCompressedTexSubImage3D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLsizei imageSize, const void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CompressedTexSubImage3D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    imageSize: GLsizei,
+    data: *const c_void,
+  ) {
+    match self.glCompressedTexSubImage3D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, yoffset, zoffset, width, height, depth, format, imageSize, data),
+      None => not_loaded("glCompressedTexSubImage3D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCompressedTexSubImage3D",
+      &[
+        "target",
+        "level",
+        "xoffset",
+        "yoffset",
+        "zoffset",
+        "width",
+        "height",
+        "depth",
+        "format",
+        "imageSize",
+        "data",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(yoffset as i64),
+        TraceValue::Int(zoffset as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(depth as i64),
+        TraceValue::Enum(format),
+        TraceValue::Int(imageSize as i64),
+        TraceValue::Pointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCompressedTexSubImage3D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("yoffset", &yoffset),
+        ("zoffset", &zoffset),
+        ("width", &width),
+        ("height", &height),
+        ("depth", &depth),
+        ("format", &format),
+        ("imageSize", &imageSize),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCopyBufferSubData(GLenum readTarget, GLenum writeTarget, GLintptr readOffset, GLintptr writeOffset, GLsizeiptr size)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CopyBufferSubData(
+    &self,
+    readTarget: GLenum,
+    writeTarget: GLenum,
+    readOffset: GLintptr,
+    writeOffset: GLintptr,
+    size: GLsizeiptr,
+  ) {
+    match self.glCopyBufferSubData {
+      Some(fn_ptr) => fn_ptr(readTarget, writeTarget, readOffset, writeOffset, size),
+      None => not_loaded("glCopyBufferSubData"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCopyBufferSubData",
+      &["readTarget", "writeTarget", "readOffset", "writeOffset", "size"],
+      &[
+        TraceValue::Enum(readTarget),
+        TraceValue::Enum(writeTarget),
+        TraceValue::Int(readOffset as i64),
+        TraceValue::Int(writeOffset as i64),
+        TraceValue::Int(size as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCopyBufferSubData",
+      &[
+        ("readTarget", &readTarget),
+        ("writeTarget", &writeTarget),
+        ("readOffset", &readOffset),
+        ("writeOffset", &writeOffset),
+        ("size", &size),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCopyTexImage1D(GLenum target, GLint level, GLenum internalformat, GLint x, GLint y, GLsizei width, GLint border)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CopyTexImage1D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    border: GLint,
+  ) {
+    match self.glCopyTexImage1D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, x, y, width, border),
+      None => not_loaded("glCopyTexImage1D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCopyTexImage1D",
+      &["target", "level", "internalformat", "x", "y", "width", "border"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(border as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCopyTexImage1D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("x", &x),
+        ("y", &y),
+        ("width", &width),
+        ("border", &border),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCopyTexImage2D(GLenum target, GLint level, GLenum internalformat, GLint x, GLint y, GLsizei width, GLsizei height, GLint border)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CopyTexImage2D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLenum,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+  ) {
+    match self.glCopyTexImage2D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, x, y, width, height, border),
+      None => not_loaded("glCopyTexImage2D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCopyTexImage2D",
+      &[
+        "target",
+        "level",
+        "internalformat",
+        "x",
+        "y",
+        "width",
+        "height",
+        "border",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(border as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCopyTexImage2D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("x", &x),
+        ("y", &y),
+        ("width", &width),
+        ("height", &height),
+        ("border", &border),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCopyTexSubImage1D(GLenum target, GLint level, GLint xoffset, GLint x, GLint y, GLsizei width)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CopyTexSubImage1D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+  ) {
+    match self.glCopyTexSubImage1D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, x, y, width),
+      None => not_loaded("glCopyTexSubImage1D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCopyTexSubImage1D",
+      &["target", "level", "xoffset", "x", "y", "width"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCopyTexSubImage1D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("x", &x),
+        ("y", &y),
+        ("width", &width),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCopyTexSubImage2D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint x, GLint y, GLsizei width, GLsizei height)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CopyTexSubImage2D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ) {
+    match self.glCopyTexSubImage2D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, yoffset, x, y, width, height),
+      None => not_loaded("glCopyTexSubImage2D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCopyTexSubImage2D",
+      &["target", "level", "xoffset", "yoffset", "x", "y", "width", "height"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(yoffset as i64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCopyTexSubImage2D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("yoffset", &yoffset),
+        ("x", &x),
+        ("y", &y),
+        ("width", &width),
+        ("height", &height),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glCopyTexSubImage3D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLint x, GLint y, GLsizei width, GLsizei height)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CopyTexSubImage3D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ) {
+    match self.glCopyTexSubImage3D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, yoffset, zoffset, x, y, width, height),
+      None => not_loaded("glCopyTexSubImage3D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCopyTexSubImage3D",
+      &[
+        "target",
+        "level",
+        "xoffset",
+        "yoffset",
+        "zoffset",
+        "x",
+        "y",
+        "width",
+        "height",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(yoffset as i64),
+        TraceValue::Int(zoffset as i64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glCopyTexSubImage3D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("yoffset", &yoffset),
+        ("zoffset", &zoffset),
+        ("x", &x),
+        ("y", &y),
+        ("width", &width),
+        ("height", &height),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `GLuint glCreateProgram()`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CreateProgram(&self) -> GLuint {
+    let out = match self.glCreateProgram {
+      Some(fn_ptr) => fn_ptr(),
+      None => not_loaded("glCreateProgram"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCreateProgram",
+      &[],
+      &[],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glCreateProgram", &[]);
+    out
+  }
+
+  /// From `gl.xml`: `GLuint glCreateShader(GLenum type)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CreateShader(&self, type_: GLenum) -> GLuint {
+    let out = match self.glCreateShader {
+      Some(fn_ptr) => fn_ptr(type_),
+      None => not_loaded("glCreateShader"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCreateShader",
+      &["type"],
+      &[TraceValue::Enum(type_)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glCreateShader", &[("type_", &type_)]);
+    out
+  }
+
+  /// From `gl.xml`: `void glCullFace(GLenum mode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn CullFace(&self, mode: GLenum) {
+    match self.glCullFace {
+      Some(fn_ptr) => fn_ptr(mode),
+      None => not_loaded("glCullFace"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glCullFace",
+      &["mode"],
+      &[TraceValue::Enum(mode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glCullFace", &[("mode", &mode)]);
+  }
+
+  /// From `gl.xml`: `void glDebugMessageCallback(GLDEBUGPROC callback, const void *userParam)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DebugMessageCallback(
+    &self,
+    callback: GLDEBUGPROC,
+    userParam: *const c_void,
+  ) {
+    match self.glDebugMessageCallback {
+      Some(fn_ptr) => fn_ptr(callback, userParam),
+      None => not_loaded("glDebugMessageCallback"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDebugMessageCallback",
+      &["callback", "userParam"],
+      &[
+        TraceValue::Pointer(callback.map_or(0, |f| f as usize) as u64),
+        TraceValue::Pointer(userParam as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDebugMessageCallback",
+      &[("callback", &callback), ("userParam", &userParam)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDebugMessageControl(GLenum source, GLenum type, GLenum severity, GLsizei count, const GLuint *ids, GLboolean enabled)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DebugMessageControl(
+    &self,
+    source: GLenum,
+    type_: GLenum,
+    severity: GLenum,
+    count: GLsizei,
+    ids: *const GLuint,
+    enabled: GLboolean,
+  ) {
+    match self.glDebugMessageControl {
+      Some(fn_ptr) => fn_ptr(source, type_, severity, count, ids, enabled),
+      None => not_loaded("glDebugMessageControl"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDebugMessageControl",
+      &["source", "type", "severity", "count", "ids", "enabled"],
+      &[
+        TraceValue::Enum(source),
+        TraceValue::Enum(type_),
+        TraceValue::Enum(severity),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(ids as usize as u64),
+        TraceValue::UInt(enabled as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDebugMessageControl",
+      &[
+        ("source", &source),
+        ("type_", &type_),
+        ("severity", &severity),
+        ("count", &count),
+        ("ids", &ids),
+        ("enabled", &enabled),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDebugMessageInsert(GLenum source, GLenum type, GLuint id, GLenum severity, GLsizei length, const GLchar *buf)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DebugMessageInsert(
+    &self,
+    source: GLenum,
+    type_: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    buf: *const GLchar,
+  ) {
+    match self.glDebugMessageInsert {
+      Some(fn_ptr) => fn_ptr(source, type_, id, severity, length, buf),
+      None => not_loaded("glDebugMessageInsert"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDebugMessageInsert",
+      &["source", "type", "id", "severity", "length", "buf"],
+      &[
+        TraceValue::Enum(source),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(id as u64),
+        TraceValue::Enum(severity),
+        TraceValue::Int(length as i64),
+        TraceValue::Pointer(buf as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDebugMessageInsert",
+      &[
+        ("source", &source),
+        ("type_", &type_),
+        ("id", &id),
+        ("severity", &severity),
+        ("length", &length),
+        ("buf", &buf),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteBuffers(GLsizei n, const GLuint *buffers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteBuffers(&self, n: GLsizei, buffers: *const GLuint) {
+    match self.glDeleteBuffers {
+      Some(fn_ptr) => fn_ptr(n, buffers),
+      None => not_loaded("glDeleteBuffers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteBuffers",
+      &["n", "buffers"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::Pointer(buffers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteBuffers",
+      &[("n", &n), ("buffers", &buffers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteFramebuffers(GLsizei n, const GLuint *framebuffers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteFramebuffers(
+    &self,
+    n: GLsizei,
+    framebuffers: *const GLuint,
+  ) {
+    match self.glDeleteFramebuffers {
+      Some(fn_ptr) => fn_ptr(n, framebuffers),
+      None => not_loaded("glDeleteFramebuffers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteFramebuffers",
+      &["n", "framebuffers"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::Pointer(framebuffers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteFramebuffers",
+      &[("n", &n), ("framebuffers", &framebuffers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteProgram(GLuint program)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteProgram(&self, program: GLuint) {
+    match self.glDeleteProgram {
+      Some(fn_ptr) => fn_ptr(program),
+      None => not_loaded("glDeleteProgram"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteProgram",
+      &["program"],
+      &[TraceValue::UInt(program as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteProgram",
+      &[("program", &program)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteQueries(GLsizei n, const GLuint *ids)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteQueries(&self, n: GLsizei, ids: *const GLuint) {
+    match self.glDeleteQueries {
+      Some(fn_ptr) => fn_ptr(n, ids),
+      None => not_loaded("glDeleteQueries"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteQueries",
+      &["n", "ids"],
+      &[TraceValue::Int(n as i64), TraceValue::Pointer(ids as usize as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteQueries",
+      &[("n", &n), ("ids", &ids)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteRenderbuffers(GLsizei n, const GLuint *renderbuffers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteRenderbuffers(
+    &self,
+    n: GLsizei,
+    renderbuffers: *const GLuint,
+  ) {
+    match self.glDeleteRenderbuffers {
+      Some(fn_ptr) => fn_ptr(n, renderbuffers),
+      None => not_loaded("glDeleteRenderbuffers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteRenderbuffers",
+      &["n", "renderbuffers"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::Pointer(renderbuffers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteRenderbuffers",
+      &[("n", &n), ("renderbuffers", &renderbuffers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteSamplers(GLsizei count, const GLuint *samplers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteSamplers(&self, count: GLsizei, samplers: *const GLuint) {
+    match self.glDeleteSamplers {
+      Some(fn_ptr) => fn_ptr(count, samplers),
+      None => not_loaded("glDeleteSamplers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteSamplers",
+      &["count", "samplers"],
+      &[
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(samplers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteSamplers",
+      &[("count", &count), ("samplers", &samplers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteShader(GLuint shader)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteShader(&self, shader: GLuint) {
+    match self.glDeleteShader {
+      Some(fn_ptr) => fn_ptr(shader),
+      None => not_loaded("glDeleteShader"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteShader",
+      &["shader"],
+      &[TraceValue::UInt(shader as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glDeleteShader", &[("shader", &shader)]);
+  }
+
+  /// From `gl.xml`: `void glDeleteSync(GLsync sync)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteSync(&self, sync: GLsync) {
+    match self.glDeleteSync {
+      Some(fn_ptr) => fn_ptr(sync),
+      None => not_loaded("glDeleteSync"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteSync",
+      &["sync"],
+      &[TraceValue::Handle(sync as usize as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glDeleteSync", &[("sync", &sync)]);
+  }
+
+  /// From `gl.xml`: `void glDeleteTextures(GLsizei n, const GLuint *textures)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteTextures(&self, n: GLsizei, textures: *const GLuint) {
+    match self.glDeleteTextures {
+      Some(fn_ptr) => fn_ptr(n, textures),
+      None => not_loaded("glDeleteTextures"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteTextures",
+      &["n", "textures"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::Pointer(textures as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteTextures",
+      &[("n", &n), ("textures", &textures)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDeleteVertexArrays(GLsizei n, const GLuint *arrays)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DeleteVertexArrays(&self, n: GLsizei, arrays: *const GLuint) {
+    match self.glDeleteVertexArrays {
+      Some(fn_ptr) => fn_ptr(n, arrays),
+      None => not_loaded("glDeleteVertexArrays"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDeleteVertexArrays",
+      &["n", "arrays"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::Pointer(arrays as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDeleteVertexArrays",
+      &[("n", &n), ("arrays", &arrays)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDepthFunc(GLenum func)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DepthFunc(&self, func: GLenum) {
+    match self.glDepthFunc {
+      Some(fn_ptr) => fn_ptr(func),
+      None => not_loaded("glDepthFunc"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDepthFunc",
+      &["func"],
+      &[TraceValue::Enum(func)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glDepthFunc", &[("func", &func)]);
+  }
+
+  /// From `gl.xml`: `void glDepthMask(GLboolean flag)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DepthMask(&self, flag: GLboolean) {
+    match self.glDepthMask {
+      Some(fn_ptr) => fn_ptr(flag),
+      None => not_loaded("glDepthMask"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDepthMask",
+      &["flag"],
+      &[TraceValue::UInt(flag as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glDepthMask", &[("flag", &flag)]);
+  }
+
+  /// From `gl.xml`: `void glDepthRange(GLdouble n, GLdouble f)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DepthRange(&self, n: GLdouble, f: GLdouble) {
+    match self.glDepthRange {
+      Some(fn_ptr) => fn_ptr(n, f),
+      None => not_loaded("glDepthRange"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDepthRange",
+      &["n", "f"],
+      &[TraceValue::Float(n), TraceValue::Float(f)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glDepthRange", &[("n", &n), ("f", &f)]);
+  }
+
+  /// From `gl.xml`: `void glDetachShader(GLuint program, GLuint shader)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DetachShader(&self, program: GLuint, shader: GLuint) {
+    match self.glDetachShader {
+      Some(fn_ptr) => fn_ptr(program, shader),
+      None => not_loaded("glDetachShader"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDetachShader",
+      &["program", "shader"],
+      &[TraceValue::UInt(program as u64), TraceValue::UInt(shader as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDetachShader",
+      &[("program", &program), ("shader", &shader)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDisable(GLenum cap)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Disable(&self, cap: GLenum) {
+    match self.glDisable {
+      Some(fn_ptr) => fn_ptr(cap),
+      None => not_loaded("glDisable"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDisable",
+      &["cap"],
+      &[TraceValue::Enum(cap)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glDisable", &[("cap", &cap)]);
+  }
+
+  /// From `gl.xml`: `void glDisableVertexAttribArray(GLuint index)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DisableVertexAttribArray(&self, index: GLuint) {
+    match self.glDisableVertexAttribArray {
+      Some(fn_ptr) => fn_ptr(index),
+      None => not_loaded("glDisableVertexAttribArray"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDisableVertexAttribArray",
+      &["index"],
+      &[TraceValue::UInt(index as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDisableVertexAttribArray",
+      &[("index", &index)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDisablei(GLenum target, GLuint index)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Disablei(&self, target: GLenum, index: GLuint) {
+    match self.glDisablei {
+      Some(fn_ptr) => fn_ptr(target, index),
+      None => not_loaded("glDisablei"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDisablei",
+      &["target", "index"],
+      &[TraceValue::Enum(target), TraceValue::UInt(index as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDisablei",
+      &[("target", &target), ("index", &index)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawArrays(GLenum mode, GLint first, GLsizei count)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawArrays(&self, mode: GLenum, first: GLint, count: GLsizei) {
+    match self.glDrawArrays {
+      Some(fn_ptr) => fn_ptr(mode, first, count),
+      None => not_loaded("glDrawArrays"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawArrays",
+      &["mode", "first", "count"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Int(first as i64),
+        TraceValue::Int(count as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawArrays",
+      &[("mode", &mode), ("first", &first), ("count", &count)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawArraysInstanced(GLenum mode, GLint first, GLsizei count, GLsizei instancecount)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawArraysInstanced(
+    &self,
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+    instancecount: GLsizei,
+  ) {
+    match self.glDrawArraysInstanced {
+      Some(fn_ptr) => fn_ptr(mode, first, count, instancecount),
+      None => not_loaded("glDrawArraysInstanced"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawArraysInstanced",
+      &["mode", "first", "count", "instancecount"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Int(first as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Int(instancecount as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawArraysInstanced",
+      &[
+        ("mode", &mode),
+        ("first", &first),
+        ("count", &count),
+        ("instancecount", &instancecount),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawBuffer(GLenum buf)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawBuffer(&self, buf: GLenum) {
+    match self.glDrawBuffer {
+      Some(fn_ptr) => fn_ptr(buf),
+      None => not_loaded("glDrawBuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawBuffer",
+      &["buf"],
+      &[TraceValue::Enum(buf)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glDrawBuffer", &[("buf", &buf)]);
+  }
+
+  /// From `gl.xml`: `void glDrawBuffers(GLsizei n, const GLenum *bufs)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawBuffers(&self, n: GLsizei, bufs: *const GLenum) {
+    match self.glDrawBuffers {
+      Some(fn_ptr) => fn_ptr(n, bufs),
+      None => not_loaded("glDrawBuffers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawBuffers",
+      &["n", "bufs"],
+      &[TraceValue::Int(n as i64), TraceValue::Pointer(bufs as usize as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawBuffers",
+      &[("n", &n), ("bufs", &bufs)],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawElements(GLenum mode, GLsizei count, GLenum type, const void *indices)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawElements(
+    &self,
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+  ) {
+    match self.glDrawElements {
+      Some(fn_ptr) => fn_ptr(mode, count, type_, indices),
+      None => not_loaded("glDrawElements"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawElements",
+      &["mode", "count", "type", "indices"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Int(count as i64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawElements",
+      &[
+        ("mode", &mode),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawElementsBaseVertex(GLenum mode, GLsizei count, GLenum type, const void *indices, GLint basevertex)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawElementsBaseVertex(
+    &self,
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    basevertex: GLint,
+  ) {
+    match self.glDrawElementsBaseVertex {
+      Some(fn_ptr) => fn_ptr(mode, count, type_, indices, basevertex),
+      None => not_loaded("glDrawElementsBaseVertex"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawElementsBaseVertex",
+      &["mode", "count", "type", "indices", "basevertex"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Int(count as i64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+        TraceValue::Int(basevertex as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawElementsBaseVertex",
+      &[
+        ("mode", &mode),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+        ("basevertex", &basevertex),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawElementsInstanced(GLenum mode, GLsizei count, GLenum type, const void *indices, GLsizei instancecount)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawElementsInstanced(
+    &self,
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+  ) {
+    match self.glDrawElementsInstanced {
+      Some(fn_ptr) => fn_ptr(mode, count, type_, indices, instancecount),
+      None => not_loaded("glDrawElementsInstanced"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawElementsInstanced",
+      &["mode", "count", "type", "indices", "instancecount"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Int(count as i64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+        TraceValue::Int(instancecount as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawElementsInstanced",
+      &[
+        ("mode", &mode),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+        ("instancecount", &instancecount),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawElementsInstancedBaseVertex(GLenum mode, GLsizei count, GLenum type, const void *indices, GLsizei instancecount, GLint basevertex)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawElementsInstancedBaseVertex(
+    &self,
+    mode: GLenum,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+    basevertex: GLint,
+  ) {
+    match self.glDrawElementsInstancedBaseVertex {
+      Some(fn_ptr) => fn_ptr(mode, count, type_, indices, instancecount, basevertex),
+      None => not_loaded("glDrawElementsInstancedBaseVertex"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawElementsInstancedBaseVertex",
+      &["mode", "count", "type", "indices", "instancecount", "basevertex"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Int(count as i64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+        TraceValue::Int(instancecount as i64),
+        TraceValue::Int(basevertex as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawElementsInstancedBaseVertex",
+      &[
+        ("mode", &mode),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+        ("instancecount", &instancecount),
+        ("basevertex", &basevertex),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawRangeElements(GLenum mode, GLuint start, GLuint end, GLsizei count, GLenum type, const void *indices)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawRangeElements(
+    &self,
+    mode: GLenum,
+    start: GLuint,
+    end: GLuint,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+  ) {
+    match self.glDrawRangeElements {
+      Some(fn_ptr) => fn_ptr(mode, start, end, count, type_, indices),
+      None => not_loaded("glDrawRangeElements"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawRangeElements",
+      &["mode", "start", "end", "count", "type", "indices"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::UInt(start as u64),
+        TraceValue::UInt(end as u64),
+        TraceValue::Int(count as i64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawRangeElements",
+      &[
+        ("mode", &mode),
+        ("start", &start),
+        ("end", &end),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glDrawRangeElementsBaseVertex(GLenum mode, GLuint start, GLuint end, GLsizei count, GLenum type, const void *indices, GLint basevertex)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn DrawRangeElementsBaseVertex(
+    &self,
+    mode: GLenum,
+    start: GLuint,
+    end: GLuint,
+    count: GLsizei,
+    type_: GLenum,
+    indices: *const c_void,
+    basevertex: GLint,
+  ) {
+    match self.glDrawRangeElementsBaseVertex {
+      Some(fn_ptr) => fn_ptr(mode, start, end, count, type_, indices, basevertex),
+      None => not_loaded("glDrawRangeElementsBaseVertex"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glDrawRangeElementsBaseVertex",
+      &["mode", "start", "end", "count", "type", "indices", "basevertex"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::UInt(start as u64),
+        TraceValue::UInt(end as u64),
+        TraceValue::Int(count as i64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+        TraceValue::Int(basevertex as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glDrawRangeElementsBaseVertex",
+      &[
+        ("mode", &mode),
+        ("start", &start),
+        ("end", &end),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+        ("basevertex", &basevertex),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glEnable(GLenum cap)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Enable(&self, cap: GLenum) {
+    match self.glEnable {
+      Some(fn_ptr) => fn_ptr(cap),
+      None => not_loaded("glEnable"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glEnable",
+      &["cap"],
+      &[TraceValue::Enum(cap)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glEnable", &[("cap", &cap)]);
+  }
+
+  /// From `gl.xml`: `void glEnableVertexAttribArray(GLuint index)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn EnableVertexAttribArray(&self, index: GLuint) {
+    match self.glEnableVertexAttribArray {
+      Some(fn_ptr) => fn_ptr(index),
+      None => not_loaded("glEnableVertexAttribArray"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glEnableVertexAttribArray",
+      &["index"],
+      &[TraceValue::UInt(index as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glEnableVertexAttribArray",
+      &[("index", &index)],
+    );
+  }
+
+  /// From `gl.xml`: `void glEnablei(GLenum target, GLuint index)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Enablei(&self, target: GLenum, index: GLuint) {
+    match self.glEnablei {
+      Some(fn_ptr) => fn_ptr(target, index),
+      None => not_loaded("glEnablei"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glEnablei",
+      &["target", "index"],
+      &[TraceValue::Enum(target), TraceValue::UInt(index as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glEnablei",
+      &[("target", &target), ("index", &index)],
+    );
+  }
+
+  /// From `gl.xml`: `void glEndConditionalRender()`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn EndConditionalRender(&self) {
+    match self.glEndConditionalRender {
+      Some(fn_ptr) => fn_ptr(),
+      None => not_loaded("glEndConditionalRender"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glEndConditionalRender",
+      &[],
+      &[],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glEndConditionalRender", &[]);
+  }
+
+  /// From `gl.xml`: `void glEndQuery(GLenum target)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn EndQuery(&self, target: GLenum) {
+    match self.glEndQuery {
+      Some(fn_ptr) => fn_ptr(target),
+      None => not_loaded("glEndQuery"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glEndQuery",
+      &["target"],
+      &[TraceValue::Enum(target)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glEndQuery", &[("target", &target)]);
+  }
+
+  /// From `gl.xml`: `void glEndTransformFeedback()`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn EndTransformFeedback(&self) {
+    match self.glEndTransformFeedback {
+      Some(fn_ptr) => fn_ptr(),
+      None => not_loaded("glEndTransformFeedback"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glEndTransformFeedback",
+      &[],
+      &[],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glEndTransformFeedback", &[]);
+  }
+
+  /// From `gl.xml`: `GLsync glFenceSync(GLenum condition, GLbitfield flags)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FenceSync(
+    &self,
+    condition: GLenum,
+    flags: GLbitfield,
+  ) -> GLsync {
+    let out = match self.glFenceSync {
+      Some(fn_ptr) => fn_ptr(condition, flags),
+      None => not_loaded("glFenceSync"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFenceSync",
+      &["condition", "flags"],
+      &[TraceValue::Enum(condition), TraceValue::UInt(flags as u64)],
+      TraceValue::Handle(out as usize as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFenceSync",
+      &[("condition", &condition), ("flags", &flags)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glFinish()`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Finish(&self) {
+    match self.glFinish {
+      Some(fn_ptr) => fn_ptr(),
+      None => not_loaded("glFinish"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(self.glGetIntegerv, "glFinish", &[], &[], TraceValue::Void);
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glFinish", &[]);
+  }
+
+  /// From `gl.xml`: `void glFlush()`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Flush(&self) {
+    match self.glFlush {
+      Some(fn_ptr) => fn_ptr(),
+      None => not_loaded("glFlush"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(self.glGetIntegerv, "glFlush", &[], &[], TraceValue::Void);
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glFlush", &[]);
+  }
+
+  /// From `gl.xml`: `void glFlushMappedBufferRange(GLenum target, GLintptr offset, GLsizeiptr length)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FlushMappedBufferRange(
+    &self,
+    target: GLenum,
+    offset: GLintptr,
+    length: GLsizeiptr,
+  ) {
+    match self.glFlushMappedBufferRange {
+      Some(fn_ptr) => fn_ptr(target, offset, length),
+      None => not_loaded("glFlushMappedBufferRange"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFlushMappedBufferRange",
+      &["target", "offset", "length"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(offset as i64),
+        TraceValue::Int(length as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFlushMappedBufferRange",
+      &[("target", &target), ("offset", &offset), ("length", &length)],
+    );
+  }
+
+  /// From `gl.xml`: `void glFramebufferRenderbuffer(GLenum target, GLenum attachment, GLenum renderbuffertarget, GLuint renderbuffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FramebufferRenderbuffer(
+    &self,
+    target: GLenum,
+    attachment: GLenum,
+    renderbuffertarget: GLenum,
+    renderbuffer: GLuint,
+  ) {
+    match self.glFramebufferRenderbuffer {
+      Some(fn_ptr) => fn_ptr(target, attachment, renderbuffertarget, renderbuffer),
+      None => not_loaded("glFramebufferRenderbuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFramebufferRenderbuffer",
+      &["target", "attachment", "renderbuffertarget", "renderbuffer"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(attachment),
+        TraceValue::Enum(renderbuffertarget),
+        TraceValue::UInt(renderbuffer as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFramebufferRenderbuffer",
+      &[
+        ("target", &target),
+        ("attachment", &attachment),
+        ("renderbuffertarget", &renderbuffertarget),
+        ("renderbuffer", &renderbuffer),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glFramebufferTexture(GLenum target, GLenum attachment, GLuint texture, GLint level)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FramebufferTexture(
+    &self,
+    target: GLenum,
+    attachment: GLenum,
+    texture: GLuint,
+    level: GLint,
+  ) {
+    match self.glFramebufferTexture {
+      Some(fn_ptr) => fn_ptr(target, attachment, texture, level),
+      None => not_loaded("glFramebufferTexture"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFramebufferTexture",
+      &["target", "attachment", "texture", "level"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(attachment),
+        TraceValue::UInt(texture as u64),
+        TraceValue::Int(level as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFramebufferTexture",
+      &[
+        ("target", &target),
+        ("attachment", &attachment),
+        ("texture", &texture),
+        ("level", &level),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glFramebufferTexture1D(GLenum target, GLenum attachment, GLenum textarget, GLuint texture, GLint level)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FramebufferTexture1D(
+    &self,
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+  ) {
+    match self.glFramebufferTexture1D {
+      Some(fn_ptr) => fn_ptr(target, attachment, textarget, texture, level),
+      None => not_loaded("glFramebufferTexture1D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFramebufferTexture1D",
+      &["target", "attachment", "textarget", "texture", "level"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(attachment),
+        TraceValue::Enum(textarget),
+        TraceValue::UInt(texture as u64),
+        TraceValue::Int(level as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFramebufferTexture1D",
+      &[
+        ("target", &target),
+        ("attachment", &attachment),
+        ("textarget", &textarget),
+        ("texture", &texture),
+        ("level", &level),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glFramebufferTexture2D(GLenum target, GLenum attachment, GLenum textarget, GLuint texture, GLint level)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FramebufferTexture2D(
+    &self,
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+  ) {
+    match self.glFramebufferTexture2D {
+      Some(fn_ptr) => fn_ptr(target, attachment, textarget, texture, level),
+      None => not_loaded("glFramebufferTexture2D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFramebufferTexture2D",
+      &["target", "attachment", "textarget", "texture", "level"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(attachment),
+        TraceValue::Enum(textarget),
+        TraceValue::UInt(texture as u64),
+        TraceValue::Int(level as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFramebufferTexture2D",
+      &[
+        ("target", &target),
+        ("attachment", &attachment),
+        ("textarget", &textarget),
+        ("texture", &texture),
+        ("level", &level),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glFramebufferTexture3D(GLenum target, GLenum attachment, GLenum textarget, GLuint texture, GLint level, GLint zoffset)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FramebufferTexture3D(
+    &self,
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+    zoffset: GLint,
+  ) {
+    match self.glFramebufferTexture3D {
+      Some(fn_ptr) => fn_ptr(target, attachment, textarget, texture, level, zoffset),
+      None => not_loaded("glFramebufferTexture3D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFramebufferTexture3D",
+      &["target", "attachment", "textarget", "texture", "level", "zoffset"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(attachment),
+        TraceValue::Enum(textarget),
+        TraceValue::UInt(texture as u64),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(zoffset as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFramebufferTexture3D",
+      &[
+        ("target", &target),
+        ("attachment", &attachment),
+        ("textarget", &textarget),
+        ("texture", &texture),
+        ("level", &level),
+        ("zoffset", &zoffset),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glFramebufferTextureLayer(GLenum target, GLenum attachment, GLuint texture, GLint level, GLint layer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FramebufferTextureLayer(
+    &self,
+    target: GLenum,
+    attachment: GLenum,
+    texture: GLuint,
+    level: GLint,
+    layer: GLint,
+  ) {
+    match self.glFramebufferTextureLayer {
+      Some(fn_ptr) => fn_ptr(target, attachment, texture, level, layer),
+      None => not_loaded("glFramebufferTextureLayer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFramebufferTextureLayer",
+      &["target", "attachment", "texture", "level", "layer"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(attachment),
+        TraceValue::UInt(texture as u64),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(layer as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glFramebufferTextureLayer",
+      &[
+        ("target", &target),
+        ("attachment", &attachment),
+        ("texture", &texture),
+        ("level", &level),
+        ("layer", &layer),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glFrontFace(GLenum mode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn FrontFace(&self, mode: GLenum) {
+    match self.glFrontFace {
+      Some(fn_ptr) => fn_ptr(mode),
+      None => not_loaded("glFrontFace"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glFrontFace",
+      &["mode"],
+      &[TraceValue::Enum(mode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glFrontFace", &[("mode", &mode)]);
+  }
+
+  /// From `gl.xml`: `void glGenBuffers(GLsizei n, GLuint *buffers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenBuffers(&self, n: GLsizei, buffers: *mut GLuint) {
+    match self.glGenBuffers {
+      Some(fn_ptr) => fn_ptr(n, buffers),
+      None => not_loaded("glGenBuffers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenBuffers",
+      &["n", "buffers"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::OutPointer(buffers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGenBuffers",
+      &[("n", &n), ("buffers", &buffers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGenFramebuffers(GLsizei n, GLuint *framebuffers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenFramebuffers(&self, n: GLsizei, framebuffers: *mut GLuint) {
+    match self.glGenFramebuffers {
+      Some(fn_ptr) => fn_ptr(n, framebuffers),
+      None => not_loaded("glGenFramebuffers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenFramebuffers",
+      &["n", "framebuffers"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::OutPointer(framebuffers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGenFramebuffers",
+      &[("n", &n), ("framebuffers", &framebuffers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGenQueries(GLsizei n, GLuint *ids)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenQueries(&self, n: GLsizei, ids: *mut GLuint) {
+    match self.glGenQueries {
+      Some(fn_ptr) => fn_ptr(n, ids),
+      None => not_loaded("glGenQueries"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenQueries",
+      &["n", "ids"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::OutPointer(ids as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGenQueries",
+      &[("n", &n), ("ids", &ids)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGenRenderbuffers(GLsizei n, GLuint *renderbuffers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenRenderbuffers(
+    &self,
+    n: GLsizei,
+    renderbuffers: *mut GLuint,
+  ) {
+    match self.glGenRenderbuffers {
+      Some(fn_ptr) => fn_ptr(n, renderbuffers),
+      None => not_loaded("glGenRenderbuffers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenRenderbuffers",
+      &["n", "renderbuffers"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::OutPointer(renderbuffers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGenRenderbuffers",
+      &[("n", &n), ("renderbuffers", &renderbuffers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGenSamplers(GLsizei count, GLuint *samplers)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenSamplers(&self, count: GLsizei, samplers: *mut GLuint) {
+    match self.glGenSamplers {
+      Some(fn_ptr) => fn_ptr(count, samplers),
+      None => not_loaded("glGenSamplers"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenSamplers",
+      &["count", "samplers"],
+      &[
+        TraceValue::Int(count as i64),
+        TraceValue::OutPointer(samplers as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGenSamplers",
+      &[("count", &count), ("samplers", &samplers)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGenTextures(GLsizei n, GLuint *textures)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenTextures(&self, n: GLsizei, textures: *mut GLuint) {
+    match self.glGenTextures {
+      Some(fn_ptr) => fn_ptr(n, textures),
+      None => not_loaded("glGenTextures"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenTextures",
+      &["n", "textures"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::OutPointer(textures as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGenTextures",
+      &[("n", &n), ("textures", &textures)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGenVertexArrays(GLsizei n, GLuint *arrays)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenVertexArrays(&self, n: GLsizei, arrays: *mut GLuint) {
+    match self.glGenVertexArrays {
+      Some(fn_ptr) => fn_ptr(n, arrays),
+      None => not_loaded("glGenVertexArrays"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenVertexArrays",
+      &["n", "arrays"],
+      &[
+        TraceValue::Int(n as i64),
+        TraceValue::OutPointer(arrays as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGenVertexArrays",
+      &[("n", &n), ("arrays", &arrays)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGenerateMipmap(GLenum target)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GenerateMipmap(&self, target: GLenum) {
+    match self.glGenerateMipmap {
+      Some(fn_ptr) => fn_ptr(target),
+      None => not_loaded("glGenerateMipmap"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGenerateMipmap",
+      &["target"],
+      &[TraceValue::Enum(target)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glGenerateMipmap", &[("target", &target)]);
+  }
+
+  /// From `gl.xml`: `void glGetActiveAttrib(GLuint program, GLuint index, GLsizei bufSize, GLsizei *length, GLint *size, GLenum *type, GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetActiveAttrib(
+    &self,
+    program: GLuint,
+    index: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    size: *mut GLint,
+    type_: *mut GLenum,
+    name: *mut GLchar,
+  ) {
+    match self.glGetActiveAttrib {
+      Some(fn_ptr) => fn_ptr(program, index, bufSize, length, size, type_, name),
+      None => not_loaded("glGetActiveAttrib"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetActiveAttrib",
+      &["program", "index", "bufSize", "length", "size", "type", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(size as usize as u64),
+        TraceValue::OutPointer(type_ as usize as u64),
+        TraceValue::OutPointer(name as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetActiveAttrib",
+      &[
+        ("program", &program),
+        ("index", &index),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("size", &size),
+        ("type_", &type_),
+        ("name", &name),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetActiveUniform(GLuint program, GLuint index, GLsizei bufSize, GLsizei *length, GLint *size, GLenum *type, GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetActiveUniform(
+    &self,
+    program: GLuint,
+    index: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    size: *mut GLint,
+    type_: *mut GLenum,
+    name: *mut GLchar,
+  ) {
+    match self.glGetActiveUniform {
+      Some(fn_ptr) => fn_ptr(program, index, bufSize, length, size, type_, name),
+      None => not_loaded("glGetActiveUniform"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetActiveUniform",
+      &["program", "index", "bufSize", "length", "size", "type", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(size as usize as u64),
+        TraceValue::OutPointer(type_ as usize as u64),
+        TraceValue::OutPointer(name as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetActiveUniform",
+      &[
+        ("program", &program),
+        ("index", &index),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("size", &size),
+        ("type_", &type_),
+        ("name", &name),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetActiveUniformBlockName(GLuint program, GLuint uniformBlockIndex, GLsizei bufSize, GLsizei *length, GLchar *uniformBlockName)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetActiveUniformBlockName(
+    &self,
+    program: GLuint,
+    uniformBlockIndex: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    uniformBlockName: *mut GLchar,
+  ) {
+    match self.glGetActiveUniformBlockName {
+      Some(fn_ptr) => fn_ptr(program, uniformBlockIndex, bufSize, length, uniformBlockName),
+      None => not_loaded("glGetActiveUniformBlockName"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetActiveUniformBlockName",
+      &[
+        "program",
+        "uniformBlockIndex",
+        "bufSize",
+        "length",
+        "uniformBlockName",
+      ],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(uniformBlockIndex as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(uniformBlockName as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetActiveUniformBlockName",
+      &[
+        ("program", &program),
+        ("uniformBlockIndex", &uniformBlockIndex),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("uniformBlockName", &uniformBlockName),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetActiveUniformBlockiv(GLuint program, GLuint uniformBlockIndex, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetActiveUniformBlockiv(
+    &self,
+    program: GLuint,
+    uniformBlockIndex: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetActiveUniformBlockiv {
+      Some(fn_ptr) => fn_ptr(program, uniformBlockIndex, pname, params),
+      None => not_loaded("glGetActiveUniformBlockiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetActiveUniformBlockiv",
+      &["program", "uniformBlockIndex", "pname", "params"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(uniformBlockIndex as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetActiveUniformBlockiv",
+      &[
+        ("program", &program),
+        ("uniformBlockIndex", &uniformBlockIndex),
+        ("pname", &pname),
+        ("params", &params),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetActiveUniformName(GLuint program, GLuint uniformIndex, GLsizei bufSize, GLsizei *length, GLchar *uniformName)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetActiveUniformName(
+    &self,
+    program: GLuint,
+    uniformIndex: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    uniformName: *mut GLchar,
+  ) {
+    match self.glGetActiveUniformName {
+      Some(fn_ptr) => fn_ptr(program, uniformIndex, bufSize, length, uniformName),
+      None => not_loaded("glGetActiveUniformName"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetActiveUniformName",
+      &["program", "uniformIndex", "bufSize", "length", "uniformName"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(uniformIndex as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(uniformName as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetActiveUniformName",
+      &[
+        ("program", &program),
+        ("uniformIndex", &uniformIndex),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("uniformName", &uniformName),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetActiveUniformsiv(GLuint program, GLsizei uniformCount, const GLuint *uniformIndices, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetActiveUniformsiv(
+    &self,
+    program: GLuint,
+    uniformCount: GLsizei,
+    uniformIndices: *const GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetActiveUniformsiv {
+      Some(fn_ptr) => fn_ptr(program, uniformCount, uniformIndices, pname, params),
+      None => not_loaded("glGetActiveUniformsiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetActiveUniformsiv",
+      &["program", "uniformCount", "uniformIndices", "pname", "params"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(uniformCount as i64),
+        TraceValue::Pointer(uniformIndices as usize as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetActiveUniformsiv",
+      &[
+        ("program", &program),
+        ("uniformCount", &uniformCount),
+        ("uniformIndices", &uniformIndices),
+        ("pname", &pname),
+        ("params", &params),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetAttachedShaders(GLuint program, GLsizei maxCount, GLsizei *count, GLuint *shaders)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetAttachedShaders(
+    &self,
+    program: GLuint,
+    maxCount: GLsizei,
+    count: *mut GLsizei,
+    shaders: *mut GLuint,
+  ) {
+    match self.glGetAttachedShaders {
+      Some(fn_ptr) => fn_ptr(program, maxCount, count, shaders),
+      None => not_loaded("glGetAttachedShaders"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetAttachedShaders",
+      &["program", "maxCount", "count", "shaders"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(maxCount as i64),
+        TraceValue::OutPointer(count as usize as u64),
+        TraceValue::OutPointer(shaders as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetAttachedShaders",
+      &[
+        ("program", &program),
+        ("maxCount", &maxCount),
+        ("count", &count),
+        ("shaders", &shaders),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `GLint glGetAttribLocation(GLuint program, const GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetAttribLocation(
+    &self,
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint {
+    let out = match self.glGetAttribLocation {
+      Some(fn_ptr) => fn_ptr(program, name),
+      None => not_loaded("glGetAttribLocation"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetAttribLocation",
+      &["program", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Pointer(name as usize as u64),
+      ],
+      TraceValue::Int(out as i64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetAttribLocation",
+      &[("program", &program), ("name", &name)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glGetBooleani_v(GLenum target, GLuint index, GLboolean *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetBooleani_v(
+    &self,
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLboolean,
+  ) {
+    match self.glGetBooleani_v {
+      Some(fn_ptr) => fn_ptr(target, index, data),
+      None => not_loaded("glGetBooleani_v"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetBooleani_v",
+      &["target", "index", "data"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::UInt(index as u64),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetBooleani_v",
+      &[("target", &target), ("index", &index), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetBooleanv(GLenum pname, GLboolean *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetBooleanv(&self, pname: GLenum, data: *mut GLboolean) {
+    match self.glGetBooleanv {
+      Some(fn_ptr) => fn_ptr(pname, data),
+      None => not_loaded("glGetBooleanv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetBooleanv",
+      &["pname", "data"],
+      &[
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetBooleanv",
+      &[("pname", &pname), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetBufferParameteri64v(GLenum target, GLenum pname, GLint64 *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetBufferParameteri64v(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint64,
+  ) {
+    match self.glGetBufferParameteri64v {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetBufferParameteri64v"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetBufferParameteri64v",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetBufferParameteri64v",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetBufferParameteriv(GLenum target, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetBufferParameteriv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetBufferParameteriv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetBufferParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetBufferParameteriv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetBufferParameteriv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetBufferPointerv(GLenum target, GLenum pname, void **params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetBufferPointerv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut *mut c_void,
+  ) {
+    match self.glGetBufferPointerv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetBufferPointerv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetBufferPointerv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetBufferPointerv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetBufferSubData(GLenum target, GLintptr offset, GLsizeiptr size, void *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetBufferSubData(
+    &self,
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *mut c_void,
+  ) {
+    match self.glGetBufferSubData {
+      Some(fn_ptr) => fn_ptr(target, offset, size, data),
+      None => not_loaded("glGetBufferSubData"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetBufferSubData",
+      &["target", "offset", "size", "data"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(offset as i64),
+        TraceValue::Int(size as i64),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetBufferSubData",
+      &[
+        ("target", &target),
+        ("offset", &offset),
+        ("size", &size),
+        ("data", &data),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetCompressedTexImage(GLenum target, GLint level, void *img)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetCompressedTexImage(
+    &self,
+    target: GLenum,
+    level: GLint,
+    img: *mut c_void,
+  ) {
+    match self.glGetCompressedTexImage {
+      Some(fn_ptr) => fn_ptr(target, level, img),
+      None => not_loaded("glGetCompressedTexImage"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetCompressedTexImage",
+      &["target", "level", "img"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::OutPointer(img as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetCompressedTexImage",
+      &[("target", &target), ("level", &level), ("img", &img)],
+    );
+  }
+
+  /// From `gl.xml`: `GLuint glGetDebugMessageLog(GLuint count, GLsizei bufSize, GLenum *sources, GLenum *types, GLuint *ids, GLenum *severities, GLsizei *lengths, GLchar *messageLog)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetDebugMessageLog(
+    &self,
+    count: GLuint,
+    bufSize: GLsizei,
+    sources: *mut GLenum,
+    types: *mut GLenum,
+    ids: *mut GLuint,
+    severities: *mut GLenum,
+    lengths: *mut GLsizei,
+    messageLog: *mut GLchar,
+  ) -> GLuint {
+    let out = match self.glGetDebugMessageLog {
+      Some(fn_ptr) => fn_ptr(count, bufSize, sources, types, ids, severities, lengths, messageLog),
+      None => not_loaded("glGetDebugMessageLog"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetDebugMessageLog",
+      &[
+        "count",
+        "bufSize",
+        "sources",
+        "types",
+        "ids",
+        "severities",
+        "lengths",
+        "messageLog",
+      ],
+      &[
+        TraceValue::UInt(count as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(sources as usize as u64),
+        TraceValue::OutPointer(types as usize as u64),
+        TraceValue::OutPointer(ids as usize as u64),
+        TraceValue::OutPointer(severities as usize as u64),
+        TraceValue::OutPointer(lengths as usize as u64),
+        TraceValue::OutPointer(messageLog as usize as u64),
+      ],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetDebugMessageLog",
+      &[
+        ("count", &count),
+        ("bufSize", &bufSize),
+        ("sources", &sources),
+        ("types", &types),
+        ("ids", &ids),
+        ("severities", &severities),
+        ("lengths", &lengths),
+        ("messageLog", &messageLog),
+      ],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glGetDoublev(GLenum pname, GLdouble *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetDoublev(&self, pname: GLenum, data: *mut GLdouble) {
+    match self.glGetDoublev {
+      Some(fn_ptr) => fn_ptr(pname, data),
+      None => not_loaded("glGetDoublev"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetDoublev",
+      &["pname", "data"],
+      &[
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetDoublev",
+      &[("pname", &pname), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `GLenum glGetError()`
+  #[inline]
+  pub unsafe fn GetError(&self) -> GLenum {
+    let out = match self.glGetError {
+      Some(fn_ptr) => fn_ptr(),
+      None => not_loaded("glGetError"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetError",
+      &[],
+      &[],
+      TraceValue::Enum(out),
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glGetFloatv(GLenum pname, GLfloat *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetFloatv(&self, pname: GLenum, data: *mut GLfloat) {
+    match self.glGetFloatv {
+      Some(fn_ptr) => fn_ptr(pname, data),
+      None => not_loaded("glGetFloatv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetFloatv",
+      &["pname", "data"],
+      &[
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetFloatv",
+      &[("pname", &pname), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `GLint glGetFragDataIndex(GLuint program, const GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetFragDataIndex(
+    &self,
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint {
+    let out = match self.glGetFragDataIndex {
+      Some(fn_ptr) => fn_ptr(program, name),
+      None => not_loaded("glGetFragDataIndex"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetFragDataIndex",
+      &["program", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Pointer(name as usize as u64),
+      ],
+      TraceValue::Int(out as i64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetFragDataIndex",
+      &[("program", &program), ("name", &name)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `GLint glGetFragDataLocation(GLuint program, const GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetFragDataLocation(
+    &self,
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint {
+    let out = match self.glGetFragDataLocation {
+      Some(fn_ptr) => fn_ptr(program, name),
+      None => not_loaded("glGetFragDataLocation"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetFragDataLocation",
+      &["program", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Pointer(name as usize as u64),
+      ],
+      TraceValue::Int(out as i64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetFragDataLocation",
+      &[("program", &program), ("name", &name)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glGetFramebufferAttachmentParameteriv(GLenum target, GLenum attachment, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetFramebufferAttachmentParameteriv(
+    &self,
+    target: GLenum,
+    attachment: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetFramebufferAttachmentParameteriv {
+      Some(fn_ptr) => fn_ptr(target, attachment, pname, params),
+      None => not_loaded("glGetFramebufferAttachmentParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetFramebufferAttachmentParameteriv",
+      &["target", "attachment", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(attachment),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetFramebufferAttachmentParameteriv",
+      &[
+        ("target", &target),
+        ("attachment", &attachment),
+        ("pname", &pname),
+        ("params", &params),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetInteger64i_v(GLenum target, GLuint index, GLint64 *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetInteger64i_v(
+    &self,
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLint64,
+  ) {
+    match self.glGetInteger64i_v {
+      Some(fn_ptr) => fn_ptr(target, index, data),
+      None => not_loaded("glGetInteger64i_v"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetInteger64i_v",
+      &["target", "index", "data"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::UInt(index as u64),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetInteger64i_v",
+      &[("target", &target), ("index", &index), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetInteger64v(GLenum pname, GLint64 *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetInteger64v(&self, pname: GLenum, data: *mut GLint64) {
+    match self.glGetInteger64v {
+      Some(fn_ptr) => fn_ptr(pname, data),
+      None => not_loaded("glGetInteger64v"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetInteger64v",
+      &["pname", "data"],
+      &[
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetInteger64v",
+      &[("pname", &pname), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetIntegeri_v(GLenum target, GLuint index, GLint *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetIntegeri_v(
+    &self,
+    target: GLenum,
+    index: GLuint,
+    data: *mut GLint,
+  ) {
+    match self.glGetIntegeri_v {
+      Some(fn_ptr) => fn_ptr(target, index, data),
+      None => not_loaded("glGetIntegeri_v"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetIntegeri_v",
+      &["target", "index", "data"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::UInt(index as u64),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetIntegeri_v",
+      &[("target", &target), ("index", &index), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetIntegerv(GLenum pname, GLint *data)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetIntegerv(&self, pname: GLenum, data: *mut GLint) {
+    match self.glGetIntegerv {
+      Some(fn_ptr) => fn_ptr(pname, data),
+      None => not_loaded("glGetIntegerv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetIntegerv",
+      &["pname", "data"],
+      &[
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(data as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetIntegerv",
+      &[("pname", &pname), ("data", &data)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetMultisamplefv(GLenum pname, GLuint index, GLfloat *val)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetMultisamplefv(
+    &self,
+    pname: GLenum,
+    index: GLuint,
+    val: *mut GLfloat,
+  ) {
+    match self.glGetMultisamplefv {
+      Some(fn_ptr) => fn_ptr(pname, index, val),
+      None => not_loaded("glGetMultisamplefv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetMultisamplefv",
+      &["pname", "index", "val"],
+      &[
+        TraceValue::Enum(pname),
+        TraceValue::UInt(index as u64),
+        TraceValue::OutPointer(val as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetMultisamplefv",
+      &[("pname", &pname), ("index", &index), ("val", &val)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetObjectLabel(GLenum identifier, GLuint name, GLsizei bufSize, GLsizei *length, GLchar *label)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetObjectLabel(
+    &self,
+    identifier: GLenum,
+    name: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    label: *mut GLchar,
+  ) {
+    match self.glGetObjectLabel {
+      Some(fn_ptr) => fn_ptr(identifier, name, bufSize, length, label),
+      None => not_loaded("glGetObjectLabel"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetObjectLabel",
+      &["identifier", "name", "bufSize", "length", "label"],
+      &[
+        TraceValue::Enum(identifier),
+        TraceValue::UInt(name as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(label as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetObjectLabel",
+      &[
+        ("identifier", &identifier),
+        ("name", &name),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("label", &label),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetObjectPtrLabel(const void *ptr, GLsizei bufSize, GLsizei *length, GLchar *label)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetObjectPtrLabel(
+    &self,
+    ptr: *const c_void,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    label: *mut GLchar,
+  ) {
+    match self.glGetObjectPtrLabel {
+      Some(fn_ptr) => fn_ptr(ptr, bufSize, length, label),
+      None => not_loaded("glGetObjectPtrLabel"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetObjectPtrLabel",
+      &["ptr", "bufSize", "length", "label"],
+      &[
+        TraceValue::Pointer(ptr as usize as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(label as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetObjectPtrLabel",
+      &[
+        ("ptr", &ptr),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("label", &label),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetPointerv(GLenum pname, void **params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetPointerv(&self, pname: GLenum, params: *mut *mut c_void) {
+    match self.glGetPointerv {
+      Some(fn_ptr) => fn_ptr(pname, params),
+      None => not_loaded("glGetPointerv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetPointerv",
+      &["pname", "params"],
+      &[
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetPointerv",
+      &[("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetProgramInfoLog(GLuint program, GLsizei bufSize, GLsizei *length, GLchar *infoLog)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetProgramInfoLog(
+    &self,
+    program: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    infoLog: *mut GLchar,
+  ) {
+    match self.glGetProgramInfoLog {
+      Some(fn_ptr) => fn_ptr(program, bufSize, length, infoLog),
+      None => not_loaded("glGetProgramInfoLog"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetProgramInfoLog",
+      &["program", "bufSize", "length", "infoLog"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(infoLog as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetProgramInfoLog",
+      &[
+        ("program", &program),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("infoLog", &infoLog),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetProgramiv(GLuint program, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetProgramiv(
+    &self,
+    program: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetProgramiv {
+      Some(fn_ptr) => fn_ptr(program, pname, params),
+      None => not_loaded("glGetProgramiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetProgramiv",
+      &["program", "pname", "params"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetProgramiv",
+      &[("program", &program), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetQueryObjecti64v(GLuint id, GLenum pname, GLint64 *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetQueryObjecti64v(
+    &self,
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLint64,
+  ) {
+    match self.glGetQueryObjecti64v {
+      Some(fn_ptr) => fn_ptr(id, pname, params),
+      None => not_loaded("glGetQueryObjecti64v"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetQueryObjecti64v",
+      &["id", "pname", "params"],
+      &[
+        TraceValue::UInt(id as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetQueryObjecti64v",
+      &[("id", &id), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetQueryObjectiv(GLuint id, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetQueryObjectiv(
+    &self,
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetQueryObjectiv {
+      Some(fn_ptr) => fn_ptr(id, pname, params),
+      None => not_loaded("glGetQueryObjectiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetQueryObjectiv",
+      &["id", "pname", "params"],
+      &[
+        TraceValue::UInt(id as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetQueryObjectiv",
+      &[("id", &id), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetQueryObjectui64v(GLuint id, GLenum pname, GLuint64 *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetQueryObjectui64v(
+    &self,
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLuint64,
+  ) {
+    match self.glGetQueryObjectui64v {
+      Some(fn_ptr) => fn_ptr(id, pname, params),
+      None => not_loaded("glGetQueryObjectui64v"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetQueryObjectui64v",
+      &["id", "pname", "params"],
+      &[
+        TraceValue::UInt(id as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetQueryObjectui64v",
+      &[("id", &id), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetQueryObjectuiv(GLuint id, GLenum pname, GLuint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetQueryObjectuiv(
+    &self,
+    id: GLuint,
+    pname: GLenum,
+    params: *mut GLuint,
+  ) {
+    match self.glGetQueryObjectuiv {
+      Some(fn_ptr) => fn_ptr(id, pname, params),
+      None => not_loaded("glGetQueryObjectuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetQueryObjectuiv",
+      &["id", "pname", "params"],
+      &[
+        TraceValue::UInt(id as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetQueryObjectuiv",
+      &[("id", &id), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetQueryiv(GLenum target, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetQueryiv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetQueryiv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetQueryiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetQueryiv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetQueryiv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetRenderbufferParameteriv(GLenum target, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetRenderbufferParameteriv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetRenderbufferParameteriv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetRenderbufferParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetRenderbufferParameteriv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetRenderbufferParameteriv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetSamplerParameterIiv(GLuint sampler, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetSamplerParameterIiv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetSamplerParameterIiv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, params),
+      None => not_loaded("glGetSamplerParameterIiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetSamplerParameterIiv",
+      &["sampler", "pname", "params"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetSamplerParameterIiv",
+      &[("sampler", &sampler), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetSamplerParameterIuiv(GLuint sampler, GLenum pname, GLuint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetSamplerParameterIuiv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLuint,
+  ) {
+    match self.glGetSamplerParameterIuiv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, params),
+      None => not_loaded("glGetSamplerParameterIuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetSamplerParameterIuiv",
+      &["sampler", "pname", "params"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetSamplerParameterIuiv",
+      &[("sampler", &sampler), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetSamplerParameterfv(GLuint sampler, GLenum pname, GLfloat *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetSamplerParameterfv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ) {
+    match self.glGetSamplerParameterfv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, params),
+      None => not_loaded("glGetSamplerParameterfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetSamplerParameterfv",
+      &["sampler", "pname", "params"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetSamplerParameterfv",
+      &[("sampler", &sampler), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetSamplerParameteriv(GLuint sampler, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetSamplerParameteriv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetSamplerParameteriv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, params),
+      None => not_loaded("glGetSamplerParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetSamplerParameteriv",
+      &["sampler", "pname", "params"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetSamplerParameteriv",
+      &[("sampler", &sampler), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetShaderInfoLog(GLuint shader, GLsizei bufSize, GLsizei *length, GLchar *infoLog)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetShaderInfoLog(
+    &self,
+    shader: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    infoLog: *mut GLchar,
+  ) {
+    match self.glGetShaderInfoLog {
+      Some(fn_ptr) => fn_ptr(shader, bufSize, length, infoLog),
+      None => not_loaded("glGetShaderInfoLog"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetShaderInfoLog",
+      &["shader", "bufSize", "length", "infoLog"],
+      &[
+        TraceValue::UInt(shader as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(infoLog as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetShaderInfoLog",
+      &[
+        ("shader", &shader),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("infoLog", &infoLog),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetShaderSource(GLuint shader, GLsizei bufSize, GLsizei *length, GLchar *source)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetShaderSource(
+    &self,
+    shader: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    source: *mut GLchar,
+  ) {
+    match self.glGetShaderSource {
+      Some(fn_ptr) => fn_ptr(shader, bufSize, length, source),
+      None => not_loaded("glGetShaderSource"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetShaderSource",
+      &["shader", "bufSize", "length", "source"],
+      &[
+        TraceValue::UInt(shader as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(source as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetShaderSource",
+      &[
+        ("shader", &shader),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("source", &source),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetShaderiv(GLuint shader, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetShaderiv(
+    &self,
+    shader: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetShaderiv {
+      Some(fn_ptr) => fn_ptr(shader, pname, params),
+      None => not_loaded("glGetShaderiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetShaderiv",
+      &["shader", "pname", "params"],
+      &[
+        TraceValue::UInt(shader as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetShaderiv",
+      &[("shader", &shader), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `const GLubyte *glGetString(GLenum name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetString(&self, name: GLenum) -> *const GLubyte {
+    let out = match self.glGetString {
+      Some(fn_ptr) => fn_ptr(name),
+      None => not_loaded("glGetString"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetString",
+      &["name"],
+      &[TraceValue::Enum(name)],
+      TraceValue::Pointer(out as usize as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glGetString", &[("name", &name)]);
+    out
+  }
+
+  /// From `gl.xml`: `const GLubyte *glGetStringi(GLenum name, GLuint index)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetStringi(
+    &self,
+    name: GLenum,
+    index: GLuint,
+  ) -> *const GLubyte {
+    let out = match self.glGetStringi {
+      Some(fn_ptr) => fn_ptr(name, index),
+      None => not_loaded("glGetStringi"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetStringi",
+      &["name", "index"],
+      &[TraceValue::Enum(name), TraceValue::UInt(index as u64)],
+      TraceValue::Pointer(out as usize as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetStringi",
+      &[("name", &name), ("index", &index)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glGetSynciv(GLsync sync, GLenum pname, GLsizei bufSize, GLsizei *length, GLint *values)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetSynciv(
+    &self,
+    sync: GLsync,
+    pname: GLenum,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    values: *mut GLint,
+  ) {
+    match self.glGetSynciv {
+      Some(fn_ptr) => fn_ptr(sync, pname, bufSize, length, values),
+      None => not_loaded("glGetSynciv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetSynciv",
+      &["sync", "pname", "bufSize", "length", "values"],
+      &[
+        TraceValue::Handle(sync as usize as u64),
+        TraceValue::Enum(pname),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(values as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetSynciv",
+      &[
+        ("sync", &sync),
+        ("pname", &pname),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("values", &values),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTexImage(GLenum target, GLint level, GLenum format, GLenum type, void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTexImage(
+    &self,
+    target: GLenum,
+    level: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *mut c_void,
+  ) {
+    match self.glGetTexImage {
+      Some(fn_ptr) => fn_ptr(target, level, format, type_, pixels),
+      None => not_loaded("glGetTexImage"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTexImage",
+      &["target", "level", "format", "type", "pixels"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::OutPointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTexImage",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTexLevelParameterfv(GLenum target, GLint level, GLenum pname, GLfloat *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTexLevelParameterfv(
+    &self,
+    target: GLenum,
+    level: GLint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ) {
+    match self.glGetTexLevelParameterfv {
+      Some(fn_ptr) => fn_ptr(target, level, pname, params),
+      None => not_loaded("glGetTexLevelParameterfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTexLevelParameterfv",
+      &["target", "level", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTexLevelParameterfv",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("pname", &pname),
+        ("params", &params),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTexLevelParameteriv(GLenum target, GLint level, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTexLevelParameteriv(
+    &self,
+    target: GLenum,
+    level: GLint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetTexLevelParameteriv {
+      Some(fn_ptr) => fn_ptr(target, level, pname, params),
+      None => not_loaded("glGetTexLevelParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTexLevelParameteriv",
+      &["target", "level", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTexLevelParameteriv",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("pname", &pname),
+        ("params", &params),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTexParameterIiv(GLenum target, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTexParameterIiv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetTexParameterIiv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetTexParameterIiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTexParameterIiv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTexParameterIiv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTexParameterIuiv(GLenum target, GLenum pname, GLuint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTexParameterIuiv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLuint,
+  ) {
+    match self.glGetTexParameterIuiv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetTexParameterIuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTexParameterIuiv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTexParameterIuiv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTexParameterfv(GLenum target, GLenum pname, GLfloat *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTexParameterfv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ) {
+    match self.glGetTexParameterfv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetTexParameterfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTexParameterfv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTexParameterfv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTexParameteriv(GLenum target, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTexParameteriv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetTexParameteriv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glGetTexParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTexParameteriv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTexParameteriv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetTransformFeedbackVarying(GLuint program, GLuint index, GLsizei bufSize, GLsizei *length, GLsizei *size, GLenum *type, GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetTransformFeedbackVarying(
+    &self,
+    program: GLuint,
+    index: GLuint,
+    bufSize: GLsizei,
+    length: *mut GLsizei,
+    size: *mut GLsizei,
+    type_: *mut GLenum,
+    name: *mut GLchar,
+  ) {
+    match self.glGetTransformFeedbackVarying {
+      Some(fn_ptr) => fn_ptr(program, index, bufSize, length, size, type_, name),
+      None => not_loaded("glGetTransformFeedbackVarying"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetTransformFeedbackVarying",
+      &["program", "index", "bufSize", "length", "size", "type", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(bufSize as i64),
+        TraceValue::OutPointer(length as usize as u64),
+        TraceValue::OutPointer(size as usize as u64),
+        TraceValue::OutPointer(type_ as usize as u64),
+        TraceValue::OutPointer(name as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetTransformFeedbackVarying",
+      &[
+        ("program", &program),
+        ("index", &index),
+        ("bufSize", &bufSize),
+        ("length", &length),
+        ("size", &size),
+        ("type_", &type_),
+        ("name", &name),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `GLuint glGetUniformBlockIndex(GLuint program, const GLchar *uniformBlockName)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetUniformBlockIndex(
+    &self,
+    program: GLuint,
+    uniformBlockName: *const GLchar,
+  ) -> GLuint {
+    let out = match self.glGetUniformBlockIndex {
+      Some(fn_ptr) => fn_ptr(program, uniformBlockName),
+      None => not_loaded("glGetUniformBlockIndex"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetUniformBlockIndex",
+      &["program", "uniformBlockName"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Pointer(uniformBlockName as usize as u64),
+      ],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetUniformBlockIndex",
+      &[("program", &program), ("uniformBlockName", &uniformBlockName)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glGetUniformIndices(GLuint program, GLsizei uniformCount, const GLchar *const*uniformNames, GLuint *uniformIndices)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetUniformIndices(
+    &self,
+    program: GLuint,
+    uniformCount: GLsizei,
+    uniformNames: *const *const GLchar,
+    uniformIndices: *mut GLuint,
+  ) {
+    match self.glGetUniformIndices {
+      Some(fn_ptr) => fn_ptr(program, uniformCount, uniformNames, uniformIndices),
+      None => not_loaded("glGetUniformIndices"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetUniformIndices",
+      &["program", "uniformCount", "uniformNames", "uniformIndices"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(uniformCount as i64),
+        TraceValue::Pointer(uniformNames as usize as u64),
+        TraceValue::OutPointer(uniformIndices as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetUniformIndices",
+      &[
+        ("program", &program),
+        ("uniformCount", &uniformCount),
+        ("uniformNames", &uniformNames),
+        ("uniformIndices", &uniformIndices),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `GLint glGetUniformLocation(GLuint program, const GLchar *name)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetUniformLocation(
+    &self,
+    program: GLuint,
+    name: *const GLchar,
+  ) -> GLint {
+    let out = match self.glGetUniformLocation {
+      Some(fn_ptr) => fn_ptr(program, name),
+      None => not_loaded("glGetUniformLocation"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetUniformLocation",
+      &["program", "name"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Pointer(name as usize as u64),
+      ],
+      TraceValue::Int(out as i64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetUniformLocation",
+      &[("program", &program), ("name", &name)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glGetUniformfv(GLuint program, GLint location, GLfloat *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetUniformfv(
+    &self,
+    program: GLuint,
+    location: GLint,
+    params: *mut GLfloat,
+  ) {
+    match self.glGetUniformfv {
+      Some(fn_ptr) => fn_ptr(program, location, params),
+      None => not_loaded("glGetUniformfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetUniformfv",
+      &["program", "location", "params"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(location as i64),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetUniformfv",
+      &[("program", &program), ("location", &location), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetUniformiv(GLuint program, GLint location, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetUniformiv(
+    &self,
+    program: GLuint,
+    location: GLint,
+    params: *mut GLint,
+  ) {
+    match self.glGetUniformiv {
+      Some(fn_ptr) => fn_ptr(program, location, params),
+      None => not_loaded("glGetUniformiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetUniformiv",
+      &["program", "location", "params"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(location as i64),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetUniformiv",
+      &[("program", &program), ("location", &location), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetUniformuiv(GLuint program, GLint location, GLuint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetUniformuiv(
+    &self,
+    program: GLuint,
+    location: GLint,
+    params: *mut GLuint,
+  ) {
+    match self.glGetUniformuiv {
+      Some(fn_ptr) => fn_ptr(program, location, params),
+      None => not_loaded("glGetUniformuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetUniformuiv",
+      &["program", "location", "params"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(location as i64),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetUniformuiv",
+      &[("program", &program), ("location", &location), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetVertexAttribIiv(GLuint index, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetVertexAttribIiv(
+    &self,
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetVertexAttribIiv {
+      Some(fn_ptr) => fn_ptr(index, pname, params),
+      None => not_loaded("glGetVertexAttribIiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetVertexAttribIiv",
+      &["index", "pname", "params"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetVertexAttribIiv",
+      &[("index", &index), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetVertexAttribIuiv(GLuint index, GLenum pname, GLuint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetVertexAttribIuiv(
+    &self,
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLuint,
+  ) {
+    match self.glGetVertexAttribIuiv {
+      Some(fn_ptr) => fn_ptr(index, pname, params),
+      None => not_loaded("glGetVertexAttribIuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetVertexAttribIuiv",
+      &["index", "pname", "params"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetVertexAttribIuiv",
+      &[("index", &index), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetVertexAttribPointerv(GLuint index, GLenum pname, void **pointer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetVertexAttribPointerv(
+    &self,
+    index: GLuint,
+    pname: GLenum,
+    pointer: *mut *mut c_void,
+  ) {
+    match self.glGetVertexAttribPointerv {
+      Some(fn_ptr) => fn_ptr(index, pname, pointer),
+      None => not_loaded("glGetVertexAttribPointerv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetVertexAttribPointerv",
+      &["index", "pname", "pointer"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(pointer as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetVertexAttribPointerv",
+      &[("index", &index), ("pname", &pname), ("pointer", &pointer)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetVertexAttribdv(GLuint index, GLenum pname, GLdouble *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetVertexAttribdv(
+    &self,
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLdouble,
+  ) {
+    match self.glGetVertexAttribdv {
+      Some(fn_ptr) => fn_ptr(index, pname, params),
+      None => not_loaded("glGetVertexAttribdv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetVertexAttribdv",
+      &["index", "pname", "params"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetVertexAttribdv",
+      &[("index", &index), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetVertexAttribfv(GLuint index, GLenum pname, GLfloat *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetVertexAttribfv(
+    &self,
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLfloat,
+  ) {
+    match self.glGetVertexAttribfv {
+      Some(fn_ptr) => fn_ptr(index, pname, params),
+      None => not_loaded("glGetVertexAttribfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetVertexAttribfv",
+      &["index", "pname", "params"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetVertexAttribfv",
+      &[("index", &index), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glGetVertexAttribiv(GLuint index, GLenum pname, GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn GetVertexAttribiv(
+    &self,
+    index: GLuint,
+    pname: GLenum,
+    params: *mut GLint,
+  ) {
+    match self.glGetVertexAttribiv {
+      Some(fn_ptr) => fn_ptr(index, pname, params),
+      None => not_loaded("glGetVertexAttribiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glGetVertexAttribiv",
+      &["index", "pname", "params"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(pname),
+        TraceValue::OutPointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glGetVertexAttribiv",
+      &[("index", &index), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glHint(GLenum target, GLenum mode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Hint(&self, target: GLenum, mode: GLenum) {
+    match self.glHint {
+      Some(fn_ptr) => fn_ptr(target, mode),
+      None => not_loaded("glHint"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glHint",
+      &["target", "mode"],
+      &[TraceValue::Enum(target), TraceValue::Enum(mode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glHint",
+      &[("target", &target), ("mode", &mode)],
+    );
+  }
+
+  /// From `gl.xml`: `GLboolean glIsBuffer(GLuint buffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsBuffer(&self, buffer: GLuint) -> GLboolean {
+    let out = match self.glIsBuffer {
+      Some(fn_ptr) => fn_ptr(buffer),
+      None => not_loaded("glIsBuffer"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsBuffer",
+      &["buffer"],
+      &[TraceValue::UInt(buffer as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsBuffer", &[("buffer", &buffer)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsEnabled(GLenum cap)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsEnabled(&self, cap: GLenum) -> GLboolean {
+    let out = match self.glIsEnabled {
+      Some(fn_ptr) => fn_ptr(cap),
+      None => not_loaded("glIsEnabled"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsEnabled",
+      &["cap"],
+      &[TraceValue::Enum(cap)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsEnabled", &[("cap", &cap)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsEnabledi(GLenum target, GLuint index)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsEnabledi(&self, target: GLenum, index: GLuint) -> GLboolean {
+    let out = match self.glIsEnabledi {
+      Some(fn_ptr) => fn_ptr(target, index),
+      None => not_loaded("glIsEnabledi"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsEnabledi",
+      &["target", "index"],
+      &[TraceValue::Enum(target), TraceValue::UInt(index as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glIsEnabledi",
+      &[("target", &target), ("index", &index)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsFramebuffer(GLuint framebuffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsFramebuffer(&self, framebuffer: GLuint) -> GLboolean {
+    let out = match self.glIsFramebuffer {
+      Some(fn_ptr) => fn_ptr(framebuffer),
+      None => not_loaded("glIsFramebuffer"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsFramebuffer",
+      &["framebuffer"],
+      &[TraceValue::UInt(framebuffer as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glIsFramebuffer",
+      &[("framebuffer", &framebuffer)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsProgram(GLuint program)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsProgram(&self, program: GLuint) -> GLboolean {
+    let out = match self.glIsProgram {
+      Some(fn_ptr) => fn_ptr(program),
+      None => not_loaded("glIsProgram"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsProgram",
+      &["program"],
+      &[TraceValue::UInt(program as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsProgram", &[("program", &program)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsQuery(GLuint id)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsQuery(&self, id: GLuint) -> GLboolean {
+    let out = match self.glIsQuery {
+      Some(fn_ptr) => fn_ptr(id),
+      None => not_loaded("glIsQuery"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsQuery",
+      &["id"],
+      &[TraceValue::UInt(id as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsQuery", &[("id", &id)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsRenderbuffer(GLuint renderbuffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsRenderbuffer(&self, renderbuffer: GLuint) -> GLboolean {
+    let out = match self.glIsRenderbuffer {
+      Some(fn_ptr) => fn_ptr(renderbuffer),
+      None => not_loaded("glIsRenderbuffer"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsRenderbuffer",
+      &["renderbuffer"],
+      &[TraceValue::UInt(renderbuffer as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glIsRenderbuffer",
+      &[("renderbuffer", &renderbuffer)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsSampler(GLuint sampler)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsSampler(&self, sampler: GLuint) -> GLboolean {
+    let out = match self.glIsSampler {
+      Some(fn_ptr) => fn_ptr(sampler),
+      None => not_loaded("glIsSampler"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsSampler",
+      &["sampler"],
+      &[TraceValue::UInt(sampler as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsSampler", &[("sampler", &sampler)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsShader(GLuint shader)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsShader(&self, shader: GLuint) -> GLboolean {
+    let out = match self.glIsShader {
+      Some(fn_ptr) => fn_ptr(shader),
+      None => not_loaded("glIsShader"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsShader",
+      &["shader"],
+      &[TraceValue::UInt(shader as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsShader", &[("shader", &shader)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsSync(GLsync sync)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsSync(&self, sync: GLsync) -> GLboolean {
+    let out = match self.glIsSync {
+      Some(fn_ptr) => fn_ptr(sync),
+      None => not_loaded("glIsSync"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsSync",
+      &["sync"],
+      &[TraceValue::Handle(sync as usize as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsSync", &[("sync", &sync)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsTexture(GLuint texture)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsTexture(&self, texture: GLuint) -> GLboolean {
+    let out = match self.glIsTexture {
+      Some(fn_ptr) => fn_ptr(texture),
+      None => not_loaded("glIsTexture"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsTexture",
+      &["texture"],
+      &[TraceValue::UInt(texture as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsTexture", &[("texture", &texture)]);
+    out
+  }
+
+  /// From `gl.xml`: `GLboolean glIsVertexArray(GLuint array)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn IsVertexArray(&self, array: GLuint) -> GLboolean {
+    let out = match self.glIsVertexArray {
+      Some(fn_ptr) => fn_ptr(array),
+      None => not_loaded("glIsVertexArray"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glIsVertexArray",
+      &["array"],
+      &[TraceValue::UInt(array as u64)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glIsVertexArray", &[("array", &array)]);
+    out
+  }
+
+  /// From `gl.xml`: `void glLineWidth(GLfloat width)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn LineWidth(&self, width: GLfloat) {
+    match self.glLineWidth {
+      Some(fn_ptr) => fn_ptr(width),
+      None => not_loaded("glLineWidth"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glLineWidth",
+      &["width"],
+      &[TraceValue::Float(width as f64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glLineWidth", &[("width", &width)]);
+  }
+
+  /// From `gl.xml`: `void glLinkProgram(GLuint program)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn LinkProgram(&self, program: GLuint) {
+    match self.glLinkProgram {
+      Some(fn_ptr) => fn_ptr(program),
+      None => not_loaded("glLinkProgram"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glLinkProgram",
+      &["program"],
+      &[TraceValue::UInt(program as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glLinkProgram", &[("program", &program)]);
+  }
+
+  /// From `gl.xml`: `void glLogicOp(GLenum opcode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn LogicOp(&self, opcode: GLenum) {
+    match self.glLogicOp {
+      Some(fn_ptr) => fn_ptr(opcode),
+      None => not_loaded("glLogicOp"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glLogicOp",
+      &["opcode"],
+      &[TraceValue::Enum(opcode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glLogicOp", &[("opcode", &opcode)]);
+  }
+
+  /// From `gl.xml`: `void *glMapBuffer(GLenum target, GLenum access)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn MapBuffer(
+    &self,
+    target: GLenum,
+    access: GLenum,
+  ) -> *mut c_void {
+    let out = match self.glMapBuffer {
+      Some(fn_ptr) => fn_ptr(target, access),
+      None => not_loaded("glMapBuffer"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glMapBuffer",
+      &["target", "access"],
+      &[TraceValue::Enum(target), TraceValue::Enum(access)],
+      TraceValue::OutPointer(out as usize as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glMapBuffer",
+      &[("target", &target), ("access", &access)],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void *glMapBufferRange(GLenum target, GLintptr offset, GLsizeiptr length, GLbitfield access)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn MapBufferRange(
+    &self,
+    target: GLenum,
+    offset: GLintptr,
+    length: GLsizeiptr,
+    access: GLbitfield,
+  ) -> *mut c_void {
+    let out = match self.glMapBufferRange {
+      Some(fn_ptr) => fn_ptr(target, offset, length, access),
+      None => not_loaded("glMapBufferRange"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glMapBufferRange",
+      &["target", "offset", "length", "access"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(offset as i64),
+        TraceValue::Int(length as i64),
+        TraceValue::UInt(access as u64),
+      ],
+      TraceValue::OutPointer(out as usize as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glMapBufferRange",
+      &[
+        ("target", &target),
+        ("offset", &offset),
+        ("length", &length),
+        ("access", &access),
+      ],
+    );
+    out
+  }
+
+  /// From `gl.xml`: `void glMultiDrawArrays(GLenum mode, const GLint *first, const GLsizei *count, GLsizei drawcount)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn MultiDrawArrays(
+    &self,
+    mode: GLenum,
+    first: *const GLint,
+    count: *const GLsizei,
+    drawcount: GLsizei,
+  ) {
+    match self.glMultiDrawArrays {
+      Some(fn_ptr) => fn_ptr(mode, first, count, drawcount),
+      None => not_loaded("glMultiDrawArrays"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glMultiDrawArrays",
+      &["mode", "first", "count", "drawcount"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Pointer(first as usize as u64),
+        TraceValue::Pointer(count as usize as u64),
+        TraceValue::Int(drawcount as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glMultiDrawArrays",
+      &[
+        ("mode", &mode),
+        ("first", &first),
+        ("count", &count),
+        ("drawcount", &drawcount),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glMultiDrawElements(GLenum mode, const GLsizei *count, GLenum type, const void *const*indices, GLsizei drawcount)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn MultiDrawElements(
+    &self,
+    mode: GLenum,
+    count: *const GLsizei,
+    type_: GLenum,
+    indices: *const *const c_void,
+    drawcount: GLsizei,
+  ) {
+    match self.glMultiDrawElements {
+      Some(fn_ptr) => fn_ptr(mode, count, type_, indices, drawcount),
+      None => not_loaded("glMultiDrawElements"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glMultiDrawElements",
+      &["mode", "count", "type", "indices", "drawcount"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Pointer(count as usize as u64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+        TraceValue::Int(drawcount as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glMultiDrawElements",
+      &[
+        ("mode", &mode),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+        ("drawcount", &drawcount),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glMultiDrawElementsBaseVertex(GLenum mode, const GLsizei *count, GLenum type, const void *const*indices, GLsizei drawcount, const GLint *basevertex)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn MultiDrawElementsBaseVertex(
+    &self,
+    mode: GLenum,
+    count: *const GLsizei,
+    type_: GLenum,
+    indices: *const *const c_void,
+    drawcount: GLsizei,
+    basevertex: *const GLint,
+  ) {
+    match self.glMultiDrawElementsBaseVertex {
+      Some(fn_ptr) => fn_ptr(mode, count, type_, indices, drawcount, basevertex),
+      None => not_loaded("glMultiDrawElementsBaseVertex"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glMultiDrawElementsBaseVertex",
+      &["mode", "count", "type", "indices", "drawcount", "basevertex"],
+      &[
+        TraceValue::Enum(mode),
+        TraceValue::Pointer(count as usize as u64),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(indices as usize as u64),
+        TraceValue::Int(drawcount as i64),
+        TraceValue::Pointer(basevertex as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glMultiDrawElementsBaseVertex",
+      &[
+        ("mode", &mode),
+        ("count", &count),
+        ("type_", &type_),
+        ("indices", &indices),
+        ("drawcount", &drawcount),
+        ("basevertex", &basevertex),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glObjectLabel(GLenum identifier, GLuint name, GLsizei length, const GLchar *label)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ObjectLabel(
+    &self,
+    identifier: GLenum,
+    name: GLuint,
+    length: GLsizei,
+    label: *const GLchar,
+  ) {
+    match self.glObjectLabel {
+      Some(fn_ptr) => fn_ptr(identifier, name, length, label),
+      None => not_loaded("glObjectLabel"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glObjectLabel",
+      &["identifier", "name", "length", "label"],
+      &[
+        TraceValue::Enum(identifier),
+        TraceValue::UInt(name as u64),
+        TraceValue::Int(length as i64),
+        TraceValue::Pointer(label as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glObjectLabel",
+      &[
+        ("identifier", &identifier),
+        ("name", &name),
+        ("length", &length),
+        ("label", &label),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glObjectPtrLabel(const void *ptr, GLsizei length, const GLchar *label)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ObjectPtrLabel(
+    &self,
+    ptr: *const c_void,
+    length: GLsizei,
+    label: *const GLchar,
+  ) {
+    match self.glObjectPtrLabel {
+      Some(fn_ptr) => fn_ptr(ptr, length, label),
+      None => not_loaded("glObjectPtrLabel"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glObjectPtrLabel",
+      &["ptr", "length", "label"],
+      &[
+        TraceValue::Pointer(ptr as usize as u64),
+        TraceValue::Int(length as i64),
+        TraceValue::Pointer(label as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glObjectPtrLabel",
+      &[("ptr", &ptr), ("length", &length), ("label", &label)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPixelStoref(GLenum pname, GLfloat param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PixelStoref(&self, pname: GLenum, param: GLfloat) {
+    match self.glPixelStoref {
+      Some(fn_ptr) => fn_ptr(pname, param),
+      None => not_loaded("glPixelStoref"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPixelStoref",
+      &["pname", "param"],
+      &[TraceValue::Enum(pname), TraceValue::Float(param as f64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPixelStoref",
+      &[("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPixelStorei(GLenum pname, GLint param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PixelStorei(&self, pname: GLenum, param: GLint) {
+    match self.glPixelStorei {
+      Some(fn_ptr) => fn_ptr(pname, param),
+      None => not_loaded("glPixelStorei"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPixelStorei",
+      &["pname", "param"],
+      &[TraceValue::Enum(pname), TraceValue::Int(param as i64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPixelStorei",
+      &[("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPointParameterf(GLenum pname, GLfloat param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PointParameterf(&self, pname: GLenum, param: GLfloat) {
+    match self.glPointParameterf {
+      Some(fn_ptr) => fn_ptr(pname, param),
+      None => not_loaded("glPointParameterf"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPointParameterf",
+      &["pname", "param"],
+      &[TraceValue::Enum(pname), TraceValue::Float(param as f64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPointParameterf",
+      &[("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPointParameterfv(GLenum pname, const GLfloat *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PointParameterfv(&self, pname: GLenum, params: *const GLfloat) {
+    match self.glPointParameterfv {
+      Some(fn_ptr) => fn_ptr(pname, params),
+      None => not_loaded("glPointParameterfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPointParameterfv",
+      &["pname", "params"],
+      &[TraceValue::Enum(pname), TraceValue::Pointer(params as usize as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPointParameterfv",
+      &[("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPointParameteri(GLenum pname, GLint param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PointParameteri(&self, pname: GLenum, param: GLint) {
+    match self.glPointParameteri {
+      Some(fn_ptr) => fn_ptr(pname, param),
+      None => not_loaded("glPointParameteri"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPointParameteri",
+      &["pname", "param"],
+      &[TraceValue::Enum(pname), TraceValue::Int(param as i64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPointParameteri",
+      &[("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPointParameteriv(GLenum pname, const GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PointParameteriv(&self, pname: GLenum, params: *const GLint) {
+    match self.glPointParameteriv {
+      Some(fn_ptr) => fn_ptr(pname, params),
+      None => not_loaded("glPointParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPointParameteriv",
+      &["pname", "params"],
+      &[TraceValue::Enum(pname), TraceValue::Pointer(params as usize as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPointParameteriv",
+      &[("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPointSize(GLfloat size)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PointSize(&self, size: GLfloat) {
+    match self.glPointSize {
+      Some(fn_ptr) => fn_ptr(size),
+      None => not_loaded("glPointSize"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPointSize",
+      &["size"],
+      &[TraceValue::Float(size as f64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glPointSize", &[("size", &size)]);
+  }
+
+  /// From `gl.xml`: `void glPolygonMode(GLenum face, GLenum mode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PolygonMode(&self, face: GLenum, mode: GLenum) {
+    match self.glPolygonMode {
+      Some(fn_ptr) => fn_ptr(face, mode),
+      None => not_loaded("glPolygonMode"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPolygonMode",
+      &["face", "mode"],
+      &[TraceValue::Enum(face), TraceValue::Enum(mode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPolygonMode",
+      &[("face", &face), ("mode", &mode)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPolygonOffset(GLfloat factor, GLfloat units)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PolygonOffset(&self, factor: GLfloat, units: GLfloat) {
+    match self.glPolygonOffset {
+      Some(fn_ptr) => fn_ptr(factor, units),
+      None => not_loaded("glPolygonOffset"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPolygonOffset",
+      &["factor", "units"],
+      &[TraceValue::Float(factor as f64), TraceValue::Float(units as f64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPolygonOffset",
+      &[("factor", &factor), ("units", &units)],
+    );
+  }
+
+  /// From `gl.xml`: `void glPopDebugGroup()`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PopDebugGroup(&self) {
+    match self.glPopDebugGroup {
+      Some(fn_ptr) => fn_ptr(),
+      None => not_loaded("glPopDebugGroup"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPopDebugGroup",
+      &[],
+      &[],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glPopDebugGroup", &[]);
+  }
+
+  /// From `gl.xml`: `void glPrimitiveRestartIndex(GLuint index)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PrimitiveRestartIndex(&self, index: GLuint) {
+    match self.glPrimitiveRestartIndex {
+      Some(fn_ptr) => fn_ptr(index),
+      None => not_loaded("glPrimitiveRestartIndex"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPrimitiveRestartIndex",
+      &["index"],
+      &[TraceValue::UInt(index as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPrimitiveRestartIndex",
+      &[("index", &index)],
+    );
+  }
+
+  /// From `gl.xml`: `void glProvokingVertex(GLenum mode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ProvokingVertex(&self, mode: GLenum) {
+    match self.glProvokingVertex {
+      Some(fn_ptr) => fn_ptr(mode),
+      None => not_loaded("glProvokingVertex"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glProvokingVertex",
+      &["mode"],
+      &[TraceValue::Enum(mode)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glProvokingVertex", &[("mode", &mode)]);
+  }
+
+  /// From `gl.xml`: `void glPushDebugGroup(GLenum source, GLuint id, GLsizei length, const GLchar *message)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn PushDebugGroup(
+    &self,
+    source: GLenum,
+    id: GLuint,
+    length: GLsizei,
+    message: *const GLchar,
+  ) {
+    match self.glPushDebugGroup {
+      Some(fn_ptr) => fn_ptr(source, id, length, message),
+      None => not_loaded("glPushDebugGroup"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glPushDebugGroup",
+      &["source", "id", "length", "message"],
+      &[
+        TraceValue::Enum(source),
+        TraceValue::UInt(id as u64),
+        TraceValue::Int(length as i64),
+        TraceValue::Pointer(message as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glPushDebugGroup",
+      &[
+        ("source", &source),
+        ("id", &id),
+        ("length", &length),
+        ("message", &message),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glQueryCounter(GLuint id, GLenum target)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn QueryCounter(&self, id: GLuint, target: GLenum) {
+    match self.glQueryCounter {
+      Some(fn_ptr) => fn_ptr(id, target),
+      None => not_loaded("glQueryCounter"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glQueryCounter",
+      &["id", "target"],
+      &[TraceValue::UInt(id as u64), TraceValue::Enum(target)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glQueryCounter",
+      &[("id", &id), ("target", &target)],
+    );
+  }
+
+  /// From `gl.xml`: `void glReadBuffer(GLenum src)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ReadBuffer(&self, src: GLenum) {
+    match self.glReadBuffer {
+      Some(fn_ptr) => fn_ptr(src),
+      None => not_loaded("glReadBuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glReadBuffer",
+      &["src"],
+      &[TraceValue::Enum(src)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glReadBuffer", &[("src", &src)]);
+  }
+
+  /// From `gl.xml`: `void glReadPixels(GLint x, GLint y, GLsizei width, GLsizei height, GLenum format, GLenum type, void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ReadPixels(
+    &self,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *mut c_void,
+  ) {
+    match self.glReadPixels {
+      Some(fn_ptr) => fn_ptr(x, y, width, height, format, type_, pixels),
+      None => not_loaded("glReadPixels"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glReadPixels",
+      &["x", "y", "width", "height", "format", "type", "pixels"],
+      &[
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::OutPointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glReadPixels",
+      &[
+        ("x", &x),
+        ("y", &y),
+        ("width", &width),
+        ("height", &height),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glRenderbufferStorage(GLenum target, GLenum internalformat, GLsizei width, GLsizei height)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn RenderbufferStorage(
+    &self,
+    target: GLenum,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ) {
+    match self.glRenderbufferStorage {
+      Some(fn_ptr) => fn_ptr(target, internalformat, width, height),
+      None => not_loaded("glRenderbufferStorage"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glRenderbufferStorage",
+      &["target", "internalformat", "width", "height"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glRenderbufferStorage",
+      &[
+        ("target", &target),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glRenderbufferStorageMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn RenderbufferStorageMultisample(
+    &self,
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+  ) {
+    match self.glRenderbufferStorageMultisample {
+      Some(fn_ptr) => fn_ptr(target, samples, internalformat, width, height),
+      None => not_loaded("glRenderbufferStorageMultisample"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glRenderbufferStorageMultisample",
+      &["target", "samples", "internalformat", "width", "height"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(samples as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glRenderbufferStorageMultisample",
+      &[
+        ("target", &target),
+        ("samples", &samples),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glSampleCoverage(GLfloat value, GLboolean invert)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SampleCoverage(&self, value: GLfloat, invert: GLboolean) {
+    match self.glSampleCoverage {
+      Some(fn_ptr) => fn_ptr(value, invert),
+      None => not_loaded("glSampleCoverage"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSampleCoverage",
+      &["value", "invert"],
+      &[TraceValue::Float(value as f64), TraceValue::UInt(invert as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSampleCoverage",
+      &[("value", &value), ("invert", &invert)],
+    );
+  }
+
+  /// From `gl.xml`: `void glSampleMaski(GLuint maskNumber, GLbitfield mask)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SampleMaski(&self, maskNumber: GLuint, mask: GLbitfield) {
+    match self.glSampleMaski {
+      Some(fn_ptr) => fn_ptr(maskNumber, mask),
+      None => not_loaded("glSampleMaski"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSampleMaski",
+      &["maskNumber", "mask"],
+      &[TraceValue::UInt(maskNumber as u64), TraceValue::UInt(mask as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSampleMaski",
+      &[("maskNumber", &maskNumber), ("mask", &mask)],
+    );
+  }
+
+  /// From `gl.xml`: `void glSamplerParameterIiv(GLuint sampler, GLenum pname, const GLint *param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SamplerParameterIiv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLint,
+  ) {
+    match self.glSamplerParameterIiv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, param),
+      None => not_loaded("glSamplerParameterIiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSamplerParameterIiv",
+      &["sampler", "pname", "param"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(param as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSamplerParameterIiv",
+      &[("sampler", &sampler), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glSamplerParameterIuiv(GLuint sampler, GLenum pname, const GLuint *param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SamplerParameterIuiv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLuint,
+  ) {
+    match self.glSamplerParameterIuiv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, param),
+      None => not_loaded("glSamplerParameterIuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSamplerParameterIuiv",
+      &["sampler", "pname", "param"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(param as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSamplerParameterIuiv",
+      &[("sampler", &sampler), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glSamplerParameterf(GLuint sampler, GLenum pname, GLfloat param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SamplerParameterf(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    param: GLfloat,
+  ) {
+    match self.glSamplerParameterf {
+      Some(fn_ptr) => fn_ptr(sampler, pname, param),
+      None => not_loaded("glSamplerParameterf"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSamplerParameterf",
+      &["sampler", "pname", "param"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::Float(param as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSamplerParameterf",
+      &[("sampler", &sampler), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glSamplerParameterfv(GLuint sampler, GLenum pname, const GLfloat *param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SamplerParameterfv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLfloat,
+  ) {
+    match self.glSamplerParameterfv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, param),
+      None => not_loaded("glSamplerParameterfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSamplerParameterfv",
+      &["sampler", "pname", "param"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(param as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSamplerParameterfv",
+      &[("sampler", &sampler), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glSamplerParameteri(GLuint sampler, GLenum pname, GLint param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SamplerParameteri(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    param: GLint,
+  ) {
+    match self.glSamplerParameteri {
+      Some(fn_ptr) => fn_ptr(sampler, pname, param),
+      None => not_loaded("glSamplerParameteri"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSamplerParameteri",
+      &["sampler", "pname", "param"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::Int(param as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSamplerParameteri",
+      &[("sampler", &sampler), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glSamplerParameteriv(GLuint sampler, GLenum pname, const GLint *param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn SamplerParameteriv(
+    &self,
+    sampler: GLuint,
+    pname: GLenum,
+    param: *const GLint,
+  ) {
+    match self.glSamplerParameteriv {
+      Some(fn_ptr) => fn_ptr(sampler, pname, param),
+      None => not_loaded("glSamplerParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glSamplerParameteriv",
+      &["sampler", "pname", "param"],
+      &[
+        TraceValue::UInt(sampler as u64),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(param as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glSamplerParameteriv",
+      &[("sampler", &sampler), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glScissor(GLint x, GLint y, GLsizei width, GLsizei height)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Scissor(
+    &self,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ) {
+    match self.glScissor {
+      Some(fn_ptr) => fn_ptr(x, y, width, height),
+      None => not_loaded("glScissor"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glScissor",
+      &["x", "y", "width", "height"],
+      &[
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glScissor",
+      &[("x", &x), ("y", &y), ("width", &width), ("height", &height)],
+    );
+  }
+
+  /// From `gl.xml`: `void glShaderSource(GLuint shader, GLsizei count, const GLchar *const*string, const GLint *length)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ShaderSource(
+    &self,
+    shader: GLuint,
+    count: GLsizei,
+    string: *const *const GLchar,
+    length: *const GLint,
+  ) {
+    match self.glShaderSource {
+      Some(fn_ptr) => fn_ptr(shader, count, string, length),
+      None => not_loaded("glShaderSource"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glShaderSource",
+      &["shader", "count", "string", "length"],
+      &[
+        TraceValue::UInt(shader as u64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(string as usize as u64),
+        TraceValue::Pointer(length as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glShaderSource",
+      &[
+        ("shader", &shader),
+        ("count", &count),
+        ("string", &string),
+        ("length", &length),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glStencilFunc(GLenum func, GLint ref, GLuint mask)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn StencilFunc(&self, func: GLenum, ref_: GLint, mask: GLuint) {
+    match self.glStencilFunc {
+      Some(fn_ptr) => fn_ptr(func, ref_, mask),
+      None => not_loaded("glStencilFunc"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glStencilFunc",
+      &["func", "ref", "mask"],
+      &[
+        TraceValue::Enum(func),
+        TraceValue::Int(ref_ as i64),
+        TraceValue::UInt(mask as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glStencilFunc",
+      &[("func", &func), ("ref_", &ref_), ("mask", &mask)],
+    );
+  }
+
+  /// From `gl.xml`: `void glStencilFuncSeparate(GLenum face, GLenum func, GLint ref, GLuint mask)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn StencilFuncSeparate(
+    &self,
+    face: GLenum,
+    func: GLenum,
+    ref_: GLint,
+    mask: GLuint,
+  ) {
+    match self.glStencilFuncSeparate {
+      Some(fn_ptr) => fn_ptr(face, func, ref_, mask),
+      None => not_loaded("glStencilFuncSeparate"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glStencilFuncSeparate",
+      &["face", "func", "ref", "mask"],
+      &[
+        TraceValue::Enum(face),
+        TraceValue::Enum(func),
+        TraceValue::Int(ref_ as i64),
+        TraceValue::UInt(mask as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glStencilFuncSeparate",
+      &[("face", &face), ("func", &func), ("ref_", &ref_), ("mask", &mask)],
+    );
+  }
+
+  /// From `gl.xml`: `void glStencilMask(GLuint mask)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn StencilMask(&self, mask: GLuint) {
+    match self.glStencilMask {
+      Some(fn_ptr) => fn_ptr(mask),
+      None => not_loaded("glStencilMask"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glStencilMask",
+      &["mask"],
+      &[TraceValue::UInt(mask as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glStencilMask", &[("mask", &mask)]);
+  }
+
+  /// From `gl.xml`: `void glStencilMaskSeparate(GLenum face, GLuint mask)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn StencilMaskSeparate(&self, face: GLenum, mask: GLuint) {
+    match self.glStencilMaskSeparate {
+      Some(fn_ptr) => fn_ptr(face, mask),
+      None => not_loaded("glStencilMaskSeparate"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glStencilMaskSeparate",
+      &["face", "mask"],
+      &[TraceValue::Enum(face), TraceValue::UInt(mask as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glStencilMaskSeparate",
+      &[("face", &face), ("mask", &mask)],
+    );
+  }
+
+  /// From `gl.xml`: `void glStencilOp(GLenum fail, GLenum zfail, GLenum zpass)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn StencilOp(&self, fail: GLenum, zfail: GLenum, zpass: GLenum) {
+    match self.glStencilOp {
+      Some(fn_ptr) => fn_ptr(fail, zfail, zpass),
+      None => not_loaded("glStencilOp"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glStencilOp",
+      &["fail", "zfail", "zpass"],
+      &[
+        TraceValue::Enum(fail),
+        TraceValue::Enum(zfail),
+        TraceValue::Enum(zpass),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glStencilOp",
+      &[("fail", &fail), ("zfail", &zfail), ("zpass", &zpass)],
+    );
+  }
+
+  /// From `gl.xml`: `void glStencilOpSeparate(GLenum face, GLenum sfail, GLenum dpfail, GLenum dppass)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn StencilOpSeparate(
+    &self,
+    face: GLenum,
+    sfail: GLenum,
+    dpfail: GLenum,
+    dppass: GLenum,
+  ) {
+    match self.glStencilOpSeparate {
+      Some(fn_ptr) => fn_ptr(face, sfail, dpfail, dppass),
+      None => not_loaded("glStencilOpSeparate"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glStencilOpSeparate",
+      &["face", "sfail", "dpfail", "dppass"],
+      &[
+        TraceValue::Enum(face),
+        TraceValue::Enum(sfail),
+        TraceValue::Enum(dpfail),
+        TraceValue::Enum(dppass),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glStencilOpSeparate",
+      &[
+        ("face", &face),
+        ("sfail", &sfail),
+        ("dpfail", &dpfail),
+        ("dppass", &dppass),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexBuffer(GLenum target, GLenum internalformat, GLuint buffer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexBuffer(
+    &self,
+    target: GLenum,
+    internalformat: GLenum,
+    buffer: GLuint,
+  ) {
+    match self.glTexBuffer {
+      Some(fn_ptr) => fn_ptr(target, internalformat, buffer),
+      None => not_loaded("glTexBuffer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexBuffer",
+      &["target", "internalformat", "buffer"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(internalformat),
+        TraceValue::UInt(buffer as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexBuffer",
+      &[
+        ("target", &target),
+        ("internalformat", &internalformat),
+        ("buffer", &buffer),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexImage1D(GLenum target, GLint level, GLint internalformat, GLsizei width, GLint border, GLenum format, GLenum type, const void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexImage1D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    border: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ) {
+    match self.glTexImage1D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, width, border, format, type_, pixels),
+      None => not_loaded("glTexImage1D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexImage1D",
+      &[
+        "target",
+        "level",
+        "internalformat",
+        "width",
+        "border",
+        "format",
+        "type",
+        "pixels",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(internalformat as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(border as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexImage1D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("border", &border),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexImage2D(GLenum target, GLint level, GLint internalformat, GLsizei width, GLsizei height, GLint border, GLenum format, GLenum type, const void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexImage2D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ) {
+    match self.glTexImage2D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, width, height, border, format, type_, pixels),
+      None => not_loaded("glTexImage2D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexImage2D",
+      &[
+        "target",
+        "level",
+        "internalformat",
+        "width",
+        "height",
+        "border",
+        "format",
+        "type",
+        "pixels",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(internalformat as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(border as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexImage2D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+        ("border", &border),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexImage2DMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLboolean fixedsamplelocations)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexImage2DMultisample(
+    &self,
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ) {
+    match self.glTexImage2DMultisample {
+      Some(fn_ptr) => fn_ptr(target, samples, internalformat, width, height, fixedsamplelocations),
+      None => not_loaded("glTexImage2DMultisample"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexImage2DMultisample",
+      &[
+        "target",
+        "samples",
+        "internalformat",
+        "width",
+        "height",
+        "fixedsamplelocations",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(samples as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::UInt(fixedsamplelocations as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexImage2DMultisample",
+      &[
+        ("target", &target),
+        ("samples", &samples),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+        ("fixedsamplelocations", &fixedsamplelocations),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexImage3D(GLenum target, GLint level, GLint internalformat, GLsizei width, GLsizei height, GLsizei depth, GLint border, GLenum format, GLenum type, const void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexImage3D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    border: GLint,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ) {
+    match self.glTexImage3D {
+      Some(fn_ptr) => fn_ptr(target, level, internalformat, width, height, depth, border, format, type_, pixels),
+      None => not_loaded("glTexImage3D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexImage3D",
+      &[
+        "target",
+        "level",
+        "internalformat",
+        "width",
+        "height",
+        "depth",
+        "border",
+        "format",
+        "type",
+        "pixels",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(internalformat as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(depth as i64),
+        TraceValue::Int(border as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexImage3D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+        ("depth", &depth),
+        ("border", &border),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexImage3DMultisample(GLenum target, GLsizei samples, GLenum internalformat, GLsizei width, GLsizei height, GLsizei depth, GLboolean fixedsamplelocations)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexImage3DMultisample(
+    &self,
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    fixedsamplelocations: GLboolean,
+  ) {
+    match self.glTexImage3DMultisample {
+      Some(fn_ptr) => fn_ptr(target, samples, internalformat, width, height, depth, fixedsamplelocations),
+      None => not_loaded("glTexImage3DMultisample"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexImage3DMultisample",
+      &[
+        "target",
+        "samples",
+        "internalformat",
+        "width",
+        "height",
+        "depth",
+        "fixedsamplelocations",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(samples as i64),
+        TraceValue::Enum(internalformat),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(depth as i64),
+        TraceValue::UInt(fixedsamplelocations as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexImage3DMultisample",
+      &[
+        ("target", &target),
+        ("samples", &samples),
+        ("internalformat", &internalformat),
+        ("width", &width),
+        ("height", &height),
+        ("depth", &depth),
+        ("fixedsamplelocations", &fixedsamplelocations),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexParameterIiv(GLenum target, GLenum pname, const GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexParameterIiv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLint,
+  ) {
+    match self.glTexParameterIiv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glTexParameterIiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexParameterIiv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexParameterIiv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexParameterIuiv(GLenum target, GLenum pname, const GLuint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexParameterIuiv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLuint,
+  ) {
+    match self.glTexParameterIuiv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glTexParameterIuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexParameterIuiv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexParameterIuiv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexParameterf(GLenum target, GLenum pname, GLfloat param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexParameterf(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    param: GLfloat,
+  ) {
+    match self.glTexParameterf {
+      Some(fn_ptr) => fn_ptr(target, pname, param),
+      None => not_loaded("glTexParameterf"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexParameterf",
+      &["target", "pname", "param"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::Float(param as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexParameterf",
+      &[("target", &target), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexParameterfv(GLenum target, GLenum pname, const GLfloat *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexParameterfv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLfloat,
+  ) {
+    match self.glTexParameterfv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glTexParameterfv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexParameterfv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexParameterfv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexParameteri(GLenum target, GLenum pname, GLint param)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexParameteri(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    param: GLint,
+  ) {
+    match self.glTexParameteri {
+      Some(fn_ptr) => fn_ptr(target, pname, param),
+      None => not_loaded("glTexParameteri"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexParameteri",
+      &["target", "pname", "param"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::Int(param as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexParameteri",
+      &[("target", &target), ("pname", &pname), ("param", &param)],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexParameteriv(GLenum target, GLenum pname, const GLint *params)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexParameteriv(
+    &self,
+    target: GLenum,
+    pname: GLenum,
+    params: *const GLint,
+  ) {
+    match self.glTexParameteriv {
+      Some(fn_ptr) => fn_ptr(target, pname, params),
+      None => not_loaded("glTexParameteriv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexParameteriv",
+      &["target", "pname", "params"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Enum(pname),
+        TraceValue::Pointer(params as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexParameteriv",
+      &[("target", &target), ("pname", &pname), ("params", &params)],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexSubImage1D(GLenum target, GLint level, GLint xoffset, GLsizei width, GLenum format, GLenum type, const void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexSubImage1D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    width: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ) {
+    match self.glTexSubImage1D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, width, format, type_, pixels),
+      None => not_loaded("glTexSubImage1D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexSubImage1D",
+      &["target", "level", "xoffset", "width", "format", "type", "pixels"],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexSubImage1D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("width", &width),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexSubImage2D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLsizei width, GLsizei height, GLenum format, GLenum type, const void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexSubImage2D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ) {
+    match self.glTexSubImage2D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, yoffset, width, height, format, type_, pixels),
+      None => not_loaded("glTexSubImage2D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexSubImage2D",
+      &[
+        "target",
+        "level",
+        "xoffset",
+        "yoffset",
+        "width",
+        "height",
+        "format",
+        "type",
+        "pixels",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(yoffset as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexSubImage2D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("yoffset", &yoffset),
+        ("width", &width),
+        ("height", &height),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTexSubImage3D(GLenum target, GLint level, GLint xoffset, GLint yoffset, GLint zoffset, GLsizei width, GLsizei height, GLsizei depth, GLenum format, GLenum type, const void *pixels)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TexSubImage3D(
+    &self,
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    type_: GLenum,
+    pixels: *const c_void,
+  ) {
+    match self.glTexSubImage3D {
+      Some(fn_ptr) => fn_ptr(target, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, pixels),
+      None => not_loaded("glTexSubImage3D"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTexSubImage3D",
+      &[
+        "target",
+        "level",
+        "xoffset",
+        "yoffset",
+        "zoffset",
+        "width",
+        "height",
+        "depth",
+        "format",
+        "type",
+        "pixels",
+      ],
+      &[
+        TraceValue::Enum(target),
+        TraceValue::Int(level as i64),
+        TraceValue::Int(xoffset as i64),
+        TraceValue::Int(yoffset as i64),
+        TraceValue::Int(zoffset as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+        TraceValue::Int(depth as i64),
+        TraceValue::Enum(format),
+        TraceValue::Enum(type_),
+        TraceValue::Pointer(pixels as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTexSubImage3D",
+      &[
+        ("target", &target),
+        ("level", &level),
+        ("xoffset", &xoffset),
+        ("yoffset", &yoffset),
+        ("zoffset", &zoffset),
+        ("width", &width),
+        ("height", &height),
+        ("depth", &depth),
+        ("format", &format),
+        ("type_", &type_),
+        ("pixels", &pixels),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glTransformFeedbackVaryings(GLuint program, GLsizei count, const GLchar *const*varyings, GLenum bufferMode)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn TransformFeedbackVaryings(
+    &self,
+    program: GLuint,
+    count: GLsizei,
+    varyings: *const *const GLchar,
+    bufferMode: GLenum,
+  ) {
+    match self.glTransformFeedbackVaryings {
+      Some(fn_ptr) => fn_ptr(program, count, varyings, bufferMode),
+      None => not_loaded("glTransformFeedbackVaryings"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glTransformFeedbackVaryings",
+      &["program", "count", "varyings", "bufferMode"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(varyings as usize as u64),
+        TraceValue::Enum(bufferMode),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glTransformFeedbackVaryings",
+      &[
+        ("program", &program),
+        ("count", &count),
+        ("varyings", &varyings),
+        ("bufferMode", &bufferMode),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform1f(GLint location, GLfloat v0)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform1f(&self, location: GLint, v0: GLfloat) {
+    match self.glUniform1f {
+      Some(fn_ptr) => fn_ptr(location, v0),
+      None => not_loaded("glUniform1f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform1f",
+      &["location", "v0"],
+      &[TraceValue::Int(location as i64), TraceValue::Float(v0 as f64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform1f",
+      &[("location", &location), ("v0", &v0)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform1fv(GLint location, GLsizei count, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform1fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ) {
+    match self.glUniform1fv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform1fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform1fv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform1fv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform1i(GLint location, GLint v0)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform1i(&self, location: GLint, v0: GLint) {
+    match self.glUniform1i {
+      Some(fn_ptr) => fn_ptr(location, v0),
+      None => not_loaded("glUniform1i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform1i",
+      &["location", "v0"],
+      &[TraceValue::Int(location as i64), TraceValue::Int(v0 as i64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform1i",
+      &[("location", &location), ("v0", &v0)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform1iv(GLint location, GLsizei count, const GLint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform1iv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ) {
+    match self.glUniform1iv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform1iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform1iv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform1iv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform1ui(GLint location, GLuint v0)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform1ui(&self, location: GLint, v0: GLuint) {
+    match self.glUniform1ui {
+      Some(fn_ptr) => fn_ptr(location, v0),
+      None => not_loaded("glUniform1ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform1ui",
+      &["location", "v0"],
+      &[TraceValue::Int(location as i64), TraceValue::UInt(v0 as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform1ui",
+      &[("location", &location), ("v0", &v0)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform1uiv(GLint location, GLsizei count, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform1uiv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ) {
+    match self.glUniform1uiv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform1uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform1uiv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform1uiv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform2f(GLint location, GLfloat v0, GLfloat v1)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform2f(&self, location: GLint, v0: GLfloat, v1: GLfloat) {
+    match self.glUniform2f {
+      Some(fn_ptr) => fn_ptr(location, v0, v1),
+      None => not_loaded("glUniform2f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform2f",
+      &["location", "v0", "v1"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Float(v0 as f64),
+        TraceValue::Float(v1 as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform2f",
+      &[("location", &location), ("v0", &v0), ("v1", &v1)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform2fv(GLint location, GLsizei count, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform2fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ) {
+    match self.glUniform2fv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform2fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform2fv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform2fv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform2i(GLint location, GLint v0, GLint v1)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform2i(&self, location: GLint, v0: GLint, v1: GLint) {
+    match self.glUniform2i {
+      Some(fn_ptr) => fn_ptr(location, v0, v1),
+      None => not_loaded("glUniform2i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform2i",
+      &["location", "v0", "v1"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(v0 as i64),
+        TraceValue::Int(v1 as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform2i",
+      &[("location", &location), ("v0", &v0), ("v1", &v1)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform2iv(GLint location, GLsizei count, const GLint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform2iv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ) {
+    match self.glUniform2iv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform2iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform2iv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform2iv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform2ui(GLint location, GLuint v0, GLuint v1)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform2ui(&self, location: GLint, v0: GLuint, v1: GLuint) {
+    match self.glUniform2ui {
+      Some(fn_ptr) => fn_ptr(location, v0, v1),
+      None => not_loaded("glUniform2ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform2ui",
+      &["location", "v0", "v1"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::UInt(v0 as u64),
+        TraceValue::UInt(v1 as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform2ui",
+      &[("location", &location), ("v0", &v0), ("v1", &v1)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform2uiv(GLint location, GLsizei count, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform2uiv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ) {
+    match self.glUniform2uiv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform2uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform2uiv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform2uiv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform3f(GLint location, GLfloat v0, GLfloat v1, GLfloat v2)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform3f(
+    &self,
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+    v2: GLfloat,
+  ) {
+    match self.glUniform3f {
+      Some(fn_ptr) => fn_ptr(location, v0, v1, v2),
+      None => not_loaded("glUniform3f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform3f",
+      &["location", "v0", "v1", "v2"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Float(v0 as f64),
+        TraceValue::Float(v1 as f64),
+        TraceValue::Float(v2 as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform3f",
+      &[("location", &location), ("v0", &v0), ("v1", &v1), ("v2", &v2)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform3fv(GLint location, GLsizei count, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform3fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ) {
+    match self.glUniform3fv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform3fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform3fv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform3fv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform3i(GLint location, GLint v0, GLint v1, GLint v2)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform3i(
+    &self,
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+    v2: GLint,
+  ) {
+    match self.glUniform3i {
+      Some(fn_ptr) => fn_ptr(location, v0, v1, v2),
+      None => not_loaded("glUniform3i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform3i",
+      &["location", "v0", "v1", "v2"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(v0 as i64),
+        TraceValue::Int(v1 as i64),
+        TraceValue::Int(v2 as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform3i",
+      &[("location", &location), ("v0", &v0), ("v1", &v1), ("v2", &v2)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform3iv(GLint location, GLsizei count, const GLint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform3iv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ) {
+    match self.glUniform3iv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform3iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform3iv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform3iv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform3ui(GLint location, GLuint v0, GLuint v1, GLuint v2)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform3ui(
+    &self,
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+    v2: GLuint,
+  ) {
+    match self.glUniform3ui {
+      Some(fn_ptr) => fn_ptr(location, v0, v1, v2),
+      None => not_loaded("glUniform3ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform3ui",
+      &["location", "v0", "v1", "v2"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::UInt(v0 as u64),
+        TraceValue::UInt(v1 as u64),
+        TraceValue::UInt(v2 as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform3ui",
+      &[("location", &location), ("v0", &v0), ("v1", &v1), ("v2", &v2)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform3uiv(GLint location, GLsizei count, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform3uiv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ) {
+    match self.glUniform3uiv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform3uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform3uiv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform3uiv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform4f(GLint location, GLfloat v0, GLfloat v1, GLfloat v2, GLfloat v3)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform4f(
+    &self,
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+    v2: GLfloat,
+    v3: GLfloat,
+  ) {
+    match self.glUniform4f {
+      Some(fn_ptr) => fn_ptr(location, v0, v1, v2, v3),
+      None => not_loaded("glUniform4f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform4f",
+      &["location", "v0", "v1", "v2", "v3"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Float(v0 as f64),
+        TraceValue::Float(v1 as f64),
+        TraceValue::Float(v2 as f64),
+        TraceValue::Float(v3 as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform4f",
+      &[
+        ("location", &location),
+        ("v0", &v0),
+        ("v1", &v1),
+        ("v2", &v2),
+        ("v3", &v3),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform4fv(GLint location, GLsizei count, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform4fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLfloat,
+  ) {
+    match self.glUniform4fv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform4fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform4fv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform4fv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform4i(GLint location, GLint v0, GLint v1, GLint v2, GLint v3)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform4i(
+    &self,
+    location: GLint,
+    v0: GLint,
+    v1: GLint,
+    v2: GLint,
+    v3: GLint,
+  ) {
+    match self.glUniform4i {
+      Some(fn_ptr) => fn_ptr(location, v0, v1, v2, v3),
+      None => not_loaded("glUniform4i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform4i",
+      &["location", "v0", "v1", "v2", "v3"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(v0 as i64),
+        TraceValue::Int(v1 as i64),
+        TraceValue::Int(v2 as i64),
+        TraceValue::Int(v3 as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform4i",
+      &[
+        ("location", &location),
+        ("v0", &v0),
+        ("v1", &v1),
+        ("v2", &v2),
+        ("v3", &v3),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform4iv(GLint location, GLsizei count, const GLint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform4iv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLint,
+  ) {
+    match self.glUniform4iv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform4iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform4iv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform4iv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform4ui(GLint location, GLuint v0, GLuint v1, GLuint v2, GLuint v3)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform4ui(
+    &self,
+    location: GLint,
+    v0: GLuint,
+    v1: GLuint,
+    v2: GLuint,
+    v3: GLuint,
+  ) {
+    match self.glUniform4ui {
+      Some(fn_ptr) => fn_ptr(location, v0, v1, v2, v3),
+      None => not_loaded("glUniform4ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform4ui",
+      &["location", "v0", "v1", "v2", "v3"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::UInt(v0 as u64),
+        TraceValue::UInt(v1 as u64),
+        TraceValue::UInt(v2 as u64),
+        TraceValue::UInt(v3 as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform4ui",
+      &[
+        ("location", &location),
+        ("v0", &v0),
+        ("v1", &v1),
+        ("v2", &v2),
+        ("v3", &v3),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniform4uiv(GLint location, GLsizei count, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Uniform4uiv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    value: *const GLuint,
+  ) {
+    match self.glUniform4uiv {
+      Some(fn_ptr) => fn_ptr(location, count, value),
+      None => not_loaded("glUniform4uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniform4uiv",
+      &["location", "count", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniform4uiv",
+      &[("location", &location), ("count", &count), ("value", &value)],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformBlockBinding(GLuint program, GLuint uniformBlockIndex, GLuint uniformBlockBinding)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformBlockBinding(
+    &self,
+    program: GLuint,
+    uniformBlockIndex: GLuint,
+    uniformBlockBinding: GLuint,
+  ) {
+    match self.glUniformBlockBinding {
+      Some(fn_ptr) => fn_ptr(program, uniformBlockIndex, uniformBlockBinding),
+      None => not_loaded("glUniformBlockBinding"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformBlockBinding",
+      &["program", "uniformBlockIndex", "uniformBlockBinding"],
+      &[
+        TraceValue::UInt(program as u64),
+        TraceValue::UInt(uniformBlockIndex as u64),
+        TraceValue::UInt(uniformBlockBinding as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformBlockBinding",
+      &[
+        ("program", &program),
+        ("uniformBlockIndex", &uniformBlockIndex),
+        ("uniformBlockBinding", &uniformBlockBinding),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix2fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix2fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix2fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix2fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix2fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix2fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix2x3fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix2x3fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix2x3fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix2x3fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix2x3fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix2x3fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix2x4fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix2x4fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix2x4fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix2x4fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix2x4fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix2x4fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix3fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix3fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix3fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix3fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix3fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix3fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix3x2fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix3x2fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix3x2fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix3x2fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix3x2fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix3x2fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix3x4fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix3x4fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix3x4fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix3x4fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix3x4fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix3x4fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix4fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix4fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix4fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix4fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix4fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix4fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix4x2fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix4x2fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix4x2fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix4x2fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix4x2fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix4x2fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glUniformMatrix4x3fv(GLint location, GLsizei count, GLboolean transpose, const GLfloat *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UniformMatrix4x3fv(
+    &self,
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const GLfloat,
+  ) {
+    match self.glUniformMatrix4x3fv {
+      Some(fn_ptr) => fn_ptr(location, count, transpose, value),
+      None => not_loaded("glUniformMatrix4x3fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUniformMatrix4x3fv",
+      &["location", "count", "transpose", "value"],
+      &[
+        TraceValue::Int(location as i64),
+        TraceValue::Int(count as i64),
+        TraceValue::UInt(transpose as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glUniformMatrix4x3fv",
+      &[
+        ("location", &location),
+        ("count", &count),
+        ("transpose", &transpose),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `GLboolean glUnmapBuffer(GLenum target)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UnmapBuffer(&self, target: GLenum) -> GLboolean {
+    let out = match self.glUnmapBuffer {
+      Some(fn_ptr) => fn_ptr(target),
+      None => not_loaded("glUnmapBuffer"),
+    };
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUnmapBuffer",
+      &["target"],
+      &[TraceValue::Enum(target)],
+      TraceValue::UInt(out as u64),
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glUnmapBuffer", &[("target", &target)]);
+    out
+  }
+
+  /// From `gl.xml`: `void glUseProgram(GLuint program)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn UseProgram(&self, program: GLuint) {
+    match self.glUseProgram {
+      Some(fn_ptr) => fn_ptr(program),
+      None => not_loaded("glUseProgram"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glUseProgram",
+      &["program"],
+      &[TraceValue::UInt(program as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(self.glGetError, "glUseProgram", &[("program", &program)]);
+  }
+
+  /// From `gl.xml`: `void glValidateProgram(GLuint program)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn ValidateProgram(&self, program: GLuint) {
+    match self.glValidateProgram {
+      Some(fn_ptr) => fn_ptr(program),
+      None => not_loaded("glValidateProgram"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glValidateProgram",
+      &["program"],
+      &[TraceValue::UInt(program as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glValidateProgram",
+      &[("program", &program)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib1d(GLuint index, GLdouble x)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib1d(&self, index: GLuint, x: GLdouble) {
+    match self.glVertexAttrib1d {
+      Some(fn_ptr) => fn_ptr(index, x),
+      None => not_loaded("glVertexAttrib1d"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib1d",
+      &["index", "x"],
+      &[TraceValue::UInt(index as u64), TraceValue::Float(x)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib1d",
+      &[("index", &index), ("x", &x)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib1dv(GLuint index, const GLdouble *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib1dv(&self, index: GLuint, v: *const GLdouble) {
+    match self.glVertexAttrib1dv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib1dv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib1dv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib1dv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib1f(GLuint index, GLfloat x)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib1f(&self, index: GLuint, x: GLfloat) {
+    match self.glVertexAttrib1f {
+      Some(fn_ptr) => fn_ptr(index, x),
+      None => not_loaded("glVertexAttrib1f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib1f",
+      &["index", "x"],
+      &[TraceValue::UInt(index as u64), TraceValue::Float(x as f64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib1f",
+      &[("index", &index), ("x", &x)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib1fv(GLuint index, const GLfloat *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib1fv(&self, index: GLuint, v: *const GLfloat) {
+    match self.glVertexAttrib1fv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib1fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib1fv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib1fv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib1s(GLuint index, GLshort x)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib1s(&self, index: GLuint, x: GLshort) {
+    match self.glVertexAttrib1s {
+      Some(fn_ptr) => fn_ptr(index, x),
+      None => not_loaded("glVertexAttrib1s"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib1s",
+      &["index", "x"],
+      &[TraceValue::UInt(index as u64), TraceValue::Int(x as i64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib1s",
+      &[("index", &index), ("x", &x)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib1sv(GLuint index, const GLshort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib1sv(&self, index: GLuint, v: *const GLshort) {
+    match self.glVertexAttrib1sv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib1sv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib1sv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib1sv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib2d(GLuint index, GLdouble x, GLdouble y)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib2d(&self, index: GLuint, x: GLdouble, y: GLdouble) {
+    match self.glVertexAttrib2d {
+      Some(fn_ptr) => fn_ptr(index, x, y),
+      None => not_loaded("glVertexAttrib2d"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib2d",
+      &["index", "x", "y"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Float(x),
+        TraceValue::Float(y),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib2d",
+      &[("index", &index), ("x", &x), ("y", &y)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib2dv(GLuint index, const GLdouble *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib2dv(&self, index: GLuint, v: *const GLdouble) {
+    match self.glVertexAttrib2dv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib2dv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib2dv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib2dv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib2f(GLuint index, GLfloat x, GLfloat y)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib2f(&self, index: GLuint, x: GLfloat, y: GLfloat) {
+    match self.glVertexAttrib2f {
+      Some(fn_ptr) => fn_ptr(index, x, y),
+      None => not_loaded("glVertexAttrib2f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib2f",
+      &["index", "x", "y"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Float(x as f64),
+        TraceValue::Float(y as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib2f",
+      &[("index", &index), ("x", &x), ("y", &y)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib2fv(GLuint index, const GLfloat *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib2fv(&self, index: GLuint, v: *const GLfloat) {
+    match self.glVertexAttrib2fv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib2fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib2fv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib2fv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib2s(GLuint index, GLshort x, GLshort y)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib2s(&self, index: GLuint, x: GLshort, y: GLshort) {
+    match self.glVertexAttrib2s {
+      Some(fn_ptr) => fn_ptr(index, x, y),
+      None => not_loaded("glVertexAttrib2s"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib2s",
+      &["index", "x", "y"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib2s",
+      &[("index", &index), ("x", &x), ("y", &y)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib2sv(GLuint index, const GLshort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib2sv(&self, index: GLuint, v: *const GLshort) {
+    match self.glVertexAttrib2sv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib2sv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib2sv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib2sv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib3d(GLuint index, GLdouble x, GLdouble y, GLdouble z)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib3d(
+    &self,
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+  ) {
+    match self.glVertexAttrib3d {
+      Some(fn_ptr) => fn_ptr(index, x, y, z),
+      None => not_loaded("glVertexAttrib3d"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib3d",
+      &["index", "x", "y", "z"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Float(x),
+        TraceValue::Float(y),
+        TraceValue::Float(z),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib3d",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib3dv(GLuint index, const GLdouble *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib3dv(&self, index: GLuint, v: *const GLdouble) {
+    match self.glVertexAttrib3dv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib3dv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib3dv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib3dv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib3f(GLuint index, GLfloat x, GLfloat y, GLfloat z)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib3f(
+    &self,
+    index: GLuint,
+    x: GLfloat,
+    y: GLfloat,
+    z: GLfloat,
+  ) {
+    match self.glVertexAttrib3f {
+      Some(fn_ptr) => fn_ptr(index, x, y, z),
+      None => not_loaded("glVertexAttrib3f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib3f",
+      &["index", "x", "y", "z"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Float(x as f64),
+        TraceValue::Float(y as f64),
+        TraceValue::Float(z as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib3f",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib3fv(GLuint index, const GLfloat *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib3fv(&self, index: GLuint, v: *const GLfloat) {
+    match self.glVertexAttrib3fv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib3fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib3fv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib3fv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib3s(GLuint index, GLshort x, GLshort y, GLshort z)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib3s(
+    &self,
+    index: GLuint,
+    x: GLshort,
+    y: GLshort,
+    z: GLshort,
+  ) {
+    match self.glVertexAttrib3s {
+      Some(fn_ptr) => fn_ptr(index, x, y, z),
+      None => not_loaded("glVertexAttrib3s"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib3s",
+      &["index", "x", "y", "z"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(z as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib3s",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib3sv(GLuint index, const GLshort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib3sv(&self, index: GLuint, v: *const GLshort) {
+    match self.glVertexAttrib3sv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib3sv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib3sv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib3sv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4Nbv(GLuint index, const GLbyte *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4Nbv(&self, index: GLuint, v: *const GLbyte) {
+    match self.glVertexAttrib4Nbv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4Nbv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4Nbv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4Nbv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4Niv(GLuint index, const GLint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4Niv(&self, index: GLuint, v: *const GLint) {
+    match self.glVertexAttrib4Niv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4Niv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4Niv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4Niv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4Nsv(GLuint index, const GLshort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4Nsv(&self, index: GLuint, v: *const GLshort) {
+    match self.glVertexAttrib4Nsv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4Nsv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4Nsv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4Nsv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4Nub(GLuint index, GLubyte x, GLubyte y, GLubyte z, GLubyte w)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4Nub(
+    &self,
+    index: GLuint,
+    x: GLubyte,
+    y: GLubyte,
+    z: GLubyte,
+    w: GLubyte,
+  ) {
+    match self.glVertexAttrib4Nub {
+      Some(fn_ptr) => fn_ptr(index, x, y, z, w),
+      None => not_loaded("glVertexAttrib4Nub"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4Nub",
+      &["index", "x", "y", "z", "w"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::UInt(x as u64),
+        TraceValue::UInt(y as u64),
+        TraceValue::UInt(z as u64),
+        TraceValue::UInt(w as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4Nub",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z), ("w", &w)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4Nubv(GLuint index, const GLubyte *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4Nubv(&self, index: GLuint, v: *const GLubyte) {
+    match self.glVertexAttrib4Nubv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4Nubv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4Nubv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4Nubv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4Nuiv(GLuint index, const GLuint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4Nuiv(&self, index: GLuint, v: *const GLuint) {
+    match self.glVertexAttrib4Nuiv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4Nuiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4Nuiv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4Nuiv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4Nusv(GLuint index, const GLushort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4Nusv(&self, index: GLuint, v: *const GLushort) {
+    match self.glVertexAttrib4Nusv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4Nusv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4Nusv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4Nusv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4bv(GLuint index, const GLbyte *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4bv(&self, index: GLuint, v: *const GLbyte) {
+    match self.glVertexAttrib4bv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4bv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4bv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4bv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4d(GLuint index, GLdouble x, GLdouble y, GLdouble z, GLdouble w)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4d(
+    &self,
+    index: GLuint,
+    x: GLdouble,
+    y: GLdouble,
+    z: GLdouble,
+    w: GLdouble,
+  ) {
+    match self.glVertexAttrib4d {
+      Some(fn_ptr) => fn_ptr(index, x, y, z, w),
+      None => not_loaded("glVertexAttrib4d"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4d",
+      &["index", "x", "y", "z", "w"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Float(x),
+        TraceValue::Float(y),
+        TraceValue::Float(z),
+        TraceValue::Float(w),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4d",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z), ("w", &w)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4dv(GLuint index, const GLdouble *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4dv(&self, index: GLuint, v: *const GLdouble) {
+    match self.glVertexAttrib4dv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4dv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4dv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4dv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4f(GLuint index, GLfloat x, GLfloat y, GLfloat z, GLfloat w)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4f(
+    &self,
+    index: GLuint,
+    x: GLfloat,
+    y: GLfloat,
+    z: GLfloat,
+    w: GLfloat,
+  ) {
+    match self.glVertexAttrib4f {
+      Some(fn_ptr) => fn_ptr(index, x, y, z, w),
+      None => not_loaded("glVertexAttrib4f"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4f",
+      &["index", "x", "y", "z", "w"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Float(x as f64),
+        TraceValue::Float(y as f64),
+        TraceValue::Float(z as f64),
+        TraceValue::Float(w as f64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4f",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z), ("w", &w)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4fv(GLuint index, const GLfloat *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4fv(&self, index: GLuint, v: *const GLfloat) {
+    match self.glVertexAttrib4fv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4fv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4fv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4fv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4iv(GLuint index, const GLint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4iv(&self, index: GLuint, v: *const GLint) {
+    match self.glVertexAttrib4iv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4iv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4iv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4s(GLuint index, GLshort x, GLshort y, GLshort z, GLshort w)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4s(
+    &self,
+    index: GLuint,
+    x: GLshort,
+    y: GLshort,
+    z: GLshort,
+    w: GLshort,
+  ) {
+    match self.glVertexAttrib4s {
+      Some(fn_ptr) => fn_ptr(index, x, y, z, w),
+      None => not_loaded("glVertexAttrib4s"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4s",
+      &["index", "x", "y", "z", "w"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(z as i64),
+        TraceValue::Int(w as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4s",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z), ("w", &w)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4sv(GLuint index, const GLshort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4sv(&self, index: GLuint, v: *const GLshort) {
+    match self.glVertexAttrib4sv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4sv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4sv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4sv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4ubv(GLuint index, const GLubyte *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4ubv(&self, index: GLuint, v: *const GLubyte) {
+    match self.glVertexAttrib4ubv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4ubv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4ubv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4ubv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4uiv(GLuint index, const GLuint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4uiv(&self, index: GLuint, v: *const GLuint) {
+    match self.glVertexAttrib4uiv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4uiv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4uiv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttrib4usv(GLuint index, const GLushort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttrib4usv(&self, index: GLuint, v: *const GLushort) {
+    match self.glVertexAttrib4usv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttrib4usv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttrib4usv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttrib4usv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribDivisor(GLuint index, GLuint divisor)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribDivisor(&self, index: GLuint, divisor: GLuint) {
+    match self.glVertexAttribDivisor {
+      Some(fn_ptr) => fn_ptr(index, divisor),
+      None => not_loaded("glVertexAttribDivisor"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribDivisor",
+      &["index", "divisor"],
+      &[TraceValue::UInt(index as u64), TraceValue::UInt(divisor as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribDivisor",
+      &[("index", &index), ("divisor", &divisor)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI1i(GLuint index, GLint x)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI1i(&self, index: GLuint, x: GLint) {
+    match self.glVertexAttribI1i {
+      Some(fn_ptr) => fn_ptr(index, x),
+      None => not_loaded("glVertexAttribI1i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI1i",
+      &["index", "x"],
+      &[TraceValue::UInt(index as u64), TraceValue::Int(x as i64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI1i",
+      &[("index", &index), ("x", &x)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI1iv(GLuint index, const GLint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI1iv(&self, index: GLuint, v: *const GLint) {
+    match self.glVertexAttribI1iv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI1iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI1iv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI1iv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI1ui(GLuint index, GLuint x)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI1ui(&self, index: GLuint, x: GLuint) {
+    match self.glVertexAttribI1ui {
+      Some(fn_ptr) => fn_ptr(index, x),
+      None => not_loaded("glVertexAttribI1ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI1ui",
+      &["index", "x"],
+      &[TraceValue::UInt(index as u64), TraceValue::UInt(x as u64)],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI1ui",
+      &[("index", &index), ("x", &x)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI1uiv(GLuint index, const GLuint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI1uiv(&self, index: GLuint, v: *const GLuint) {
+    match self.glVertexAttribI1uiv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI1uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI1uiv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI1uiv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI2i(GLuint index, GLint x, GLint y)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI2i(&self, index: GLuint, x: GLint, y: GLint) {
+    match self.glVertexAttribI2i {
+      Some(fn_ptr) => fn_ptr(index, x, y),
+      None => not_loaded("glVertexAttribI2i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI2i",
+      &["index", "x", "y"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI2i",
+      &[("index", &index), ("x", &x), ("y", &y)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI2iv(GLuint index, const GLint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI2iv(&self, index: GLuint, v: *const GLint) {
+    match self.glVertexAttribI2iv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI2iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI2iv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI2iv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI2ui(GLuint index, GLuint x, GLuint y)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI2ui(&self, index: GLuint, x: GLuint, y: GLuint) {
+    match self.glVertexAttribI2ui {
+      Some(fn_ptr) => fn_ptr(index, x, y),
+      None => not_loaded("glVertexAttribI2ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI2ui",
+      &["index", "x", "y"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::UInt(x as u64),
+        TraceValue::UInt(y as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI2ui",
+      &[("index", &index), ("x", &x), ("y", &y)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI2uiv(GLuint index, const GLuint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI2uiv(&self, index: GLuint, v: *const GLuint) {
+    match self.glVertexAttribI2uiv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI2uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI2uiv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI2uiv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI3i(GLuint index, GLint x, GLint y, GLint z)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI3i(
+    &self,
+    index: GLuint,
+    x: GLint,
+    y: GLint,
+    z: GLint,
+  ) {
+    match self.glVertexAttribI3i {
+      Some(fn_ptr) => fn_ptr(index, x, y, z),
+      None => not_loaded("glVertexAttribI3i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI3i",
+      &["index", "x", "y", "z"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(z as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI3i",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI3iv(GLuint index, const GLint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI3iv(&self, index: GLuint, v: *const GLint) {
+    match self.glVertexAttribI3iv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI3iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI3iv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI3iv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI3ui(GLuint index, GLuint x, GLuint y, GLuint z)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI3ui(
+    &self,
+    index: GLuint,
+    x: GLuint,
+    y: GLuint,
+    z: GLuint,
+  ) {
+    match self.glVertexAttribI3ui {
+      Some(fn_ptr) => fn_ptr(index, x, y, z),
+      None => not_loaded("glVertexAttribI3ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI3ui",
+      &["index", "x", "y", "z"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::UInt(x as u64),
+        TraceValue::UInt(y as u64),
+        TraceValue::UInt(z as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI3ui",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI3uiv(GLuint index, const GLuint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI3uiv(&self, index: GLuint, v: *const GLuint) {
+    match self.glVertexAttribI3uiv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI3uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI3uiv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI3uiv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4bv(GLuint index, const GLbyte *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4bv(&self, index: GLuint, v: *const GLbyte) {
+    match self.glVertexAttribI4bv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI4bv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4bv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4bv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4i(GLuint index, GLint x, GLint y, GLint z, GLint w)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4i(
+    &self,
+    index: GLuint,
+    x: GLint,
+    y: GLint,
+    z: GLint,
+    w: GLint,
+  ) {
+    match self.glVertexAttribI4i {
+      Some(fn_ptr) => fn_ptr(index, x, y, z, w),
+      None => not_loaded("glVertexAttribI4i"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4i",
+      &["index", "x", "y", "z", "w"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(z as i64),
+        TraceValue::Int(w as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4i",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z), ("w", &w)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4iv(GLuint index, const GLint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4iv(&self, index: GLuint, v: *const GLint) {
+    match self.glVertexAttribI4iv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI4iv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4iv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4iv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4sv(GLuint index, const GLshort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4sv(&self, index: GLuint, v: *const GLshort) {
+    match self.glVertexAttribI4sv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI4sv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4sv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4sv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4ubv(GLuint index, const GLubyte *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4ubv(&self, index: GLuint, v: *const GLubyte) {
+    match self.glVertexAttribI4ubv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI4ubv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4ubv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4ubv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4ui(GLuint index, GLuint x, GLuint y, GLuint z, GLuint w)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4ui(
+    &self,
+    index: GLuint,
+    x: GLuint,
+    y: GLuint,
+    z: GLuint,
+    w: GLuint,
+  ) {
+    match self.glVertexAttribI4ui {
+      Some(fn_ptr) => fn_ptr(index, x, y, z, w),
+      None => not_loaded("glVertexAttribI4ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4ui",
+      &["index", "x", "y", "z", "w"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::UInt(x as u64),
+        TraceValue::UInt(y as u64),
+        TraceValue::UInt(z as u64),
+        TraceValue::UInt(w as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4ui",
+      &[("index", &index), ("x", &x), ("y", &y), ("z", &z), ("w", &w)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4uiv(GLuint index, const GLuint *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4uiv(&self, index: GLuint, v: *const GLuint) {
+    match self.glVertexAttribI4uiv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI4uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4uiv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4uiv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribI4usv(GLuint index, const GLushort *v)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribI4usv(&self, index: GLuint, v: *const GLushort) {
+    match self.glVertexAttribI4usv {
+      Some(fn_ptr) => fn_ptr(index, v),
+      None => not_loaded("glVertexAttribI4usv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribI4usv",
+      &["index", "v"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Pointer(v as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribI4usv",
+      &[("index", &index), ("v", &v)],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribIPointer(GLuint index, GLint size, GLenum type, GLsizei stride, const void *pointer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribIPointer(
+    &self,
+    index: GLuint,
+    size: GLint,
+    type_: GLenum,
+    stride: GLsizei,
+    pointer: *const c_void,
+  ) {
+    match self.glVertexAttribIPointer {
+      Some(fn_ptr) => fn_ptr(index, size, type_, stride, pointer),
+      None => not_loaded("glVertexAttribIPointer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribIPointer",
+      &["index", "size", "type", "stride", "pointer"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(size as i64),
+        TraceValue::Enum(type_),
+        TraceValue::Int(stride as i64),
+        TraceValue::Pointer(pointer as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribIPointer",
+      &[
+        ("index", &index),
+        ("size", &size),
+        ("type_", &type_),
+        ("stride", &stride),
+        ("pointer", &pointer),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP1ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP1ui(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ) {
+    match self.glVertexAttribP1ui {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP1ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP1ui",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::UInt(value as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP1ui",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP1uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP1uiv(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ) {
+    match self.glVertexAttribP1uiv {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP1uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP1uiv",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP1uiv",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP2ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP2ui(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ) {
+    match self.glVertexAttribP2ui {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP2ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP2ui",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::UInt(value as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP2ui",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP2uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP2uiv(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ) {
+    match self.glVertexAttribP2uiv {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP2uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP2uiv",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP2uiv",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP3ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP3ui(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ) {
+    match self.glVertexAttribP3ui {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP3ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP3ui",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::UInt(value as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP3ui",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP3uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP3uiv(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ) {
+    match self.glVertexAttribP3uiv {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP3uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP3uiv",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP3uiv",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP4ui(GLuint index, GLenum type, GLboolean normalized, GLuint value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP4ui(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: GLuint,
+  ) {
+    match self.glVertexAttribP4ui {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP4ui"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP4ui",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::UInt(value as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP4ui",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribP4uiv(GLuint index, GLenum type, GLboolean normalized, const GLuint *value)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribP4uiv(
+    &self,
+    index: GLuint,
+    type_: GLenum,
+    normalized: GLboolean,
+    value: *const GLuint,
+  ) {
+    match self.glVertexAttribP4uiv {
+      Some(fn_ptr) => fn_ptr(index, type_, normalized, value),
+      None => not_loaded("glVertexAttribP4uiv"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribP4uiv",
+      &["index", "type", "normalized", "value"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::Pointer(value as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribP4uiv",
+      &[
+        ("index", &index),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("value", &value),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glVertexAttribPointer(GLuint index, GLint size, GLenum type, GLboolean normalized, GLsizei stride, const void *pointer)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn VertexAttribPointer(
+    &self,
+    index: GLuint,
+    size: GLint,
+    type_: GLenum,
+    normalized: GLboolean,
+    stride: GLsizei,
+    pointer: *const c_void,
+  ) {
+    match self.glVertexAttribPointer {
+      Some(fn_ptr) => fn_ptr(index, size, type_, normalized, stride, pointer),
+      None => not_loaded("glVertexAttribPointer"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glVertexAttribPointer",
+      &["index", "size", "type", "normalized", "stride", "pointer"],
+      &[
+        TraceValue::UInt(index as u64),
+        TraceValue::Int(size as i64),
+        TraceValue::Enum(type_),
+        TraceValue::UInt(normalized as u64),
+        TraceValue::Int(stride as i64),
+        TraceValue::Pointer(pointer as usize as u64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glVertexAttribPointer",
+      &[
+        ("index", &index),
+        ("size", &size),
+        ("type_", &type_),
+        ("normalized", &normalized),
+        ("stride", &stride),
+        ("pointer", &pointer),
+      ],
+    );
+  }
+
+  /// From `gl.xml`: `void glViewport(GLint x, GLint y, GLsizei width, GLsizei height)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn Viewport(
+    &self,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+  ) {
+    match self.glViewport {
+      Some(fn_ptr) => fn_ptr(x, y, width, height),
+      None => not_loaded("glViewport"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glViewport",
+      &["x", "y", "width", "height"],
+      &[
+        TraceValue::Int(x as i64),
+        TraceValue::Int(y as i64),
+        TraceValue::Int(width as i64),
+        TraceValue::Int(height as i64),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glViewport",
+      &[("x", &x), ("y", &y), ("width", &width), ("height", &height)],
+    );
+  }
+
+  /// From `gl.xml`: `void glWaitSync(GLsync sync, GLbitfield flags, GLuint64 timeout)`
+  #[inline]
+  #[cfg_attr(feature = "checked_gl", track_caller)]
+  pub unsafe fn WaitSync(
+    &self,
+    sync: GLsync,
+    flags: GLbitfield,
+    timeout: GLuint64,
+  ) {
+    match self.glWaitSync {
+      Some(fn_ptr) => fn_ptr(sync, flags, timeout),
+      None => not_loaded("glWaitSync"),
+    }
+    #[cfg(feature = "trace_gl")]
+    trace_gl_call(
+      self.glGetIntegerv,
+      "glWaitSync",
+      &["sync", "flags", "timeout"],
+      &[
+        TraceValue::Handle(sync as usize as u64),
+        TraceValue::UInt(flags as u64),
+        TraceValue::UInt(timeout),
+      ],
+      TraceValue::Void,
+    );
+    #[cfg(feature = "checked_gl")]
+    check_gl_error(
+      self.glGetError,
+      "glWaitSync",
+      &[("sync", &sync), ("flags", &flags), ("timeout", &timeout)],
+    );
+  }
+
+  /// Calls the method for `name` with arguments from a trace.
+  ///
+  /// Gives `None` if there's no such method, or the wrong number of
+  /// arguments.
+  #[cfg(feature = "trace_gl")]
+  pub(crate) unsafe fn replay_call(
+    &self, name: &str, args: &mut [TraceValue],
+  ) -> Option<TraceValue> {
+    Some(match (name, args.len()) {
+      ("glActiveTexture", 1) => {
+        self.ActiveTexture(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glAttachShader", 2) => {
+        self.AttachShader(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBeginConditionalRender", 2) => {
+        self.BeginConditionalRender(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glBeginQuery", 2) => {
+        self.BeginQuery(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBeginTransformFeedback", 1) => {
+        self.BeginTransformFeedback(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glBindAttribLocation", 3) => {
+        self.BindAttribLocation(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glBindBuffer", 2) => {
+        self.BindBuffer(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBindBufferBase", 3) => {
+        self.BindBufferBase(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBindBufferRange", 5) => {
+        self.BindBufferRange(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLintptr,
+          args[4].replay_int() as GLsizeiptr,
+        );
+        TraceValue::Void
+      }
+      ("glBindFragDataLocation", 3) => {
+        self.BindFragDataLocation(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glBindFragDataLocationIndexed", 4) => {
+        self.BindFragDataLocationIndexed(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glBindFramebuffer", 2) => {
+        self.BindFramebuffer(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBindRenderbuffer", 2) => {
+        self.BindRenderbuffer(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBindSampler", 2) => {
+        self.BindSampler(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBindTexture", 2) => {
+        self.BindTexture(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glBindVertexArray", 1) => {
+        self.BindVertexArray(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glBlendColor", 4) => {
+        self.BlendColor(
+          args[0].replay_float() as GLfloat,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+          args[3].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glBlendEquation", 1) => {
+        self.BlendEquation(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glBlendEquationSeparate", 2) => {
+        self.BlendEquationSeparate(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glBlendFunc", 2) => {
+        self.BlendFunc(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glBlendFuncSeparate", 4) => {
+        self.BlendFuncSeparate(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glBlitFramebuffer", 10) => {
+        self.BlitFramebuffer(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLint,
+          args[6].replay_int() as GLint,
+          args[7].replay_int() as GLint,
+          args[8].replay_int() as GLbitfield,
+          args[9].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glBufferData", 4) => {
+        self.BufferData(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizeiptr,
+          args[2].replay_ptr() as *const c_void,
+          args[3].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glBufferSubData", 4) => {
+        self.BufferSubData(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLintptr,
+          args[2].replay_int() as GLsizeiptr,
+          args[3].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glCheckFramebufferStatus", 1) => {
+        let out = self.CheckFramebufferStatus(args[0].replay_int() as GLenum);
+        TraceValue::Enum(out)
+      }
+      ("glClampColor", 2) => {
+        self.ClampColor(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glClear", 1) => {
+        self.Clear(args[0].replay_int() as GLbitfield);
+        TraceValue::Void
+      }
+      ("glClearBufferfi", 4) => {
+        self.ClearBufferfi(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_float() as GLfloat,
+          args[3].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glClearBufferfv", 3) => {
+        self.ClearBufferfv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glClearBufferiv", 3) => {
+        self.ClearBufferiv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glClearBufferuiv", 3) => {
+        self.ClearBufferuiv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glClearColor", 4) => {
+        self.ClearColor(
+          args[0].replay_float() as GLfloat,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+          args[3].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glClearDepth", 1) => {
+        self.ClearDepth(args[0].replay_float());
+        TraceValue::Void
+      }
+      ("glClearStencil", 1) => {
+        self.ClearStencil(args[0].replay_int() as GLint);
+        TraceValue::Void
+      }
+      ("glClientWaitSync", 3) => {
+        let out = self.ClientWaitSync(
+          args[0].replay_ptr() as GLsync,
+          args[1].replay_int() as GLbitfield,
+          args[2].replay_int() as GLuint64,
+        );
+        TraceValue::Enum(out)
+      }
+      ("glColorMask", 4) => {
+        self.ColorMask(
+          args[0].replay_int() as GLboolean,
+          args[1].replay_int() as GLboolean,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_int() as GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glColorMaski", 5) => {
+        self.ColorMaski(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLboolean,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_int() as GLboolean,
+          args[4].replay_int() as GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glCompileShader", 1) => {
+        self.CompileShader(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glCompressedTexImage1D", 7) => {
+        self.CompressedTexImage1D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glCompressedTexImage2D", 8) => {
+        self.CompressedTexImage2D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLint,
+          args[6].replay_int() as GLsizei,
+          args[7].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glCompressedTexImage3D", 9) => {
+        self.CompressedTexImage3D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLint,
+          args[7].replay_int() as GLsizei,
+          args[8].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glCompressedTexSubImage1D", 7) => {
+        self.CompressedTexSubImage1D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLenum,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glCompressedTexSubImage2D", 9) => {
+        self.CompressedTexSubImage2D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLenum,
+          args[7].replay_int() as GLsizei,
+          args[8].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glCompressedTexSubImage3D", 11) => {
+        self.CompressedTexSubImage3D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLsizei,
+          args[7].replay_int() as GLsizei,
+          args[8].replay_int() as GLenum,
+          args[9].replay_int() as GLsizei,
+          args[10].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glCopyBufferSubData", 5) => {
+        self.CopyBufferSubData(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLintptr,
+          args[3].replay_int() as GLintptr,
+          args[4].replay_int() as GLsizeiptr,
+        );
+        TraceValue::Void
+      }
+      ("glCopyTexImage1D", 7) => {
+        self.CopyTexImage1D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glCopyTexImage2D", 8) => {
+        self.CopyTexImage2D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLsizei,
+          args[7].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glCopyTexSubImage1D", 6) => {
+        self.CopyTexSubImage1D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glCopyTexSubImage2D", 8) => {
+        self.CopyTexSubImage2D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLint,
+          args[6].replay_int() as GLsizei,
+          args[7].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glCopyTexSubImage3D", 9) => {
+        self.CopyTexSubImage3D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLint,
+          args[6].replay_int() as GLint,
+          args[7].replay_int() as GLsizei,
+          args[8].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glCreateProgram", 0) => {
+        let out = self.CreateProgram();
+        TraceValue::UInt(out as u64)
+      }
+      ("glCreateShader", 1) => {
+        let out = self.CreateShader(args[0].replay_int() as GLenum);
+        TraceValue::UInt(out as u64)
+      }
+      ("glCullFace", 1) => {
+        self.CullFace(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glDebugMessageCallback", 2) => {
+        self.DebugMessageCallback(None, args[1].replay_ptr() as *const c_void);
+        TraceValue::Void
+      }
+      ("glDebugMessageControl", 6) => {
+        self.DebugMessageControl(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_ptr() as *const GLuint,
+          args[5].replay_int() as GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glDebugMessageInsert", 6) => {
+        self.DebugMessageInsert(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLenum,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glDeleteBuffers", 2) => {
+        self.DeleteBuffers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDeleteFramebuffers", 2) => {
+        self.DeleteFramebuffers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDeleteProgram", 1) => {
+        self.DeleteProgram(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glDeleteQueries", 2) => {
+        self.DeleteQueries(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDeleteRenderbuffers", 2) => {
+        self.DeleteRenderbuffers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDeleteSamplers", 2) => {
+        self.DeleteSamplers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDeleteShader", 1) => {
+        self.DeleteShader(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glDeleteSync", 1) => {
+        self.DeleteSync(args[0].replay_ptr() as GLsync);
+        TraceValue::Void
+      }
+      ("glDeleteTextures", 2) => {
+        self.DeleteTextures(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDeleteVertexArrays", 2) => {
+        self.DeleteVertexArrays(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDepthFunc", 1) => {
+        self.DepthFunc(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glDepthMask", 1) => {
+        self.DepthMask(args[0].replay_int() as GLboolean);
+        TraceValue::Void
+      }
+      ("glDepthRange", 2) => {
+        self.DepthRange(args[0].replay_float(), args[1].replay_float());
+        TraceValue::Void
+      }
+      ("glDetachShader", 2) => {
+        self.DetachShader(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDisable", 1) => {
+        self.Disable(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glDisableVertexAttribArray", 1) => {
+        self.DisableVertexAttribArray(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glDisablei", 2) => {
+        self.Disablei(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glDrawArrays", 3) => {
+        self.DrawArrays(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glDrawArraysInstanced", 4) => {
+        self.DrawArraysInstanced(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glDrawBuffer", 1) => {
+        self.DrawBuffer(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glDrawBuffers", 2) => {
+        self.DrawBuffers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *const GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glDrawElements", 4) => {
+        self.DrawElements(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glDrawElementsBaseVertex", 5) => {
+        self.DrawElementsBaseVertex(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *const c_void,
+          args[4].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glDrawElementsInstanced", 5) => {
+        self.DrawElementsInstanced(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *const c_void,
+          args[4].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glDrawElementsInstancedBaseVertex", 6) => {
+        self.DrawElementsInstancedBaseVertex(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *const c_void,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glDrawRangeElements", 6) => {
+        self.DrawRangeElements(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLenum,
+          args[5].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glDrawRangeElementsBaseVertex", 7) => {
+        self.DrawRangeElementsBaseVertex(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLenum,
+          args[5].replay_ptr() as *const c_void,
+          args[6].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glEnable", 1) => {
+        self.Enable(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glEnableVertexAttribArray", 1) => {
+        self.EnableVertexAttribArray(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glEnablei", 2) => {
+        self.Enablei(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glEndConditionalRender", 0) => {
+        self.EndConditionalRender();
+        TraceValue::Void
+      }
+      ("glEndQuery", 1) => {
+        self.EndQuery(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glEndTransformFeedback", 0) => {
+        self.EndTransformFeedback();
+        TraceValue::Void
+      }
+      ("glFenceSync", 2) => {
+        let out = self.FenceSync(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLbitfield,
+        );
+        TraceValue::Handle(out as usize as u64)
+      }
+      ("glFinish", 0) => {
+        self.Finish();
+        TraceValue::Void
+      }
+      ("glFlush", 0) => {
+        self.Flush();
+        TraceValue::Void
+      }
+      ("glFlushMappedBufferRange", 3) => {
+        self.FlushMappedBufferRange(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLintptr,
+          args[2].replay_int() as GLsizeiptr,
+        );
+        TraceValue::Void
+      }
+      ("glFramebufferRenderbuffer", 4) => {
+        self.FramebufferRenderbuffer(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glFramebufferTexture", 4) => {
+        self.FramebufferTexture(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glFramebufferTexture1D", 5) => {
+        self.FramebufferTexture1D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLuint,
+          args[4].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glFramebufferTexture2D", 5) => {
+        self.FramebufferTexture2D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLuint,
+          args[4].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glFramebufferTexture3D", 6) => {
+        self.FramebufferTexture3D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLuint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glFramebufferTextureLayer", 5) => {
+        self.FramebufferTextureLayer(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glFrontFace", 1) => {
+        self.FrontFace(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glGenBuffers", 2) => {
+        self.GenBuffers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGenFramebuffers", 2) => {
+        self.GenFramebuffers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGenQueries", 2) => {
+        self.GenQueries(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGenRenderbuffers", 2) => {
+        self.GenRenderbuffers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGenSamplers", 2) => {
+        self.GenSamplers(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGenTextures", 2) => {
+        self.GenTextures(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGenVertexArrays", 2) => {
+        self.GenVertexArrays(
+          args[0].replay_int() as GLsizei,
+          args[1].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGenerateMipmap", 1) => {
+        self.GenerateMipmap(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glGetActiveAttrib", 7) => {
+        self.GetActiveAttrib(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *mut GLsizei,
+          args[4].replay_ptr() as *mut GLint,
+          args[5].replay_ptr() as *mut GLenum,
+          args[6].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetActiveUniform", 7) => {
+        self.GetActiveUniform(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *mut GLsizei,
+          args[4].replay_ptr() as *mut GLint,
+          args[5].replay_ptr() as *mut GLenum,
+          args[6].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetActiveUniformBlockName", 5) => {
+        self.GetActiveUniformBlockName(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *mut GLsizei,
+          args[4].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetActiveUniformBlockiv", 4) => {
+        self.GetActiveUniformBlockiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetActiveUniformName", 5) => {
+        self.GetActiveUniformName(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *mut GLsizei,
+          args[4].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetActiveUniformsiv", 5) => {
+        self.GetActiveUniformsiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLuint,
+          args[3].replay_int() as GLenum,
+          args[4].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetAttachedShaders", 4) => {
+        self.GetAttachedShaders(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *mut GLsizei,
+          args[3].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGetAttribLocation", 2) => {
+        let out = self.GetAttribLocation(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Int(out as i64)
+      }
+      ("glGetBooleani_v", 3) => {
+        self.GetBooleani_v(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_ptr() as *mut GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glGetBooleanv", 2) => {
+        self.GetBooleanv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *mut GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glGetBufferParameteri64v", 3) => {
+        self.GetBufferParameteri64v(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint64,
+        );
+        TraceValue::Void
+      }
+      ("glGetBufferParameteriv", 3) => {
+        self.GetBufferParameteriv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetBufferPointerv", 3) => {
+        self.GetBufferPointerv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut *mut c_void,
+        );
+        TraceValue::Void
+      }
+      ("glGetBufferSubData", 4) => {
+        self.GetBufferSubData(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLintptr,
+          args[2].replay_int() as GLsizeiptr,
+          args[3].replay_ptr(),
+        );
+        TraceValue::Void
+      }
+      ("glGetCompressedTexImage", 3) => {
+        self.GetCompressedTexImage(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_ptr(),
+        );
+        TraceValue::Void
+      }
+      ("glGetDebugMessageLog", 8) => {
+        let out = self.GetDebugMessageLog(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *mut GLenum,
+          args[3].replay_ptr() as *mut GLenum,
+          args[4].replay_ptr() as *mut GLuint,
+          args[5].replay_ptr() as *mut GLenum,
+          args[6].replay_ptr() as *mut GLsizei,
+          args[7].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::UInt(out as u64)
+      }
+      ("glGetDoublev", 2) => {
+        self.GetDoublev(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *mut GLdouble,
+        );
+        TraceValue::Void
+      }
+      ("glGetError", 0) => {
+        let out = self.GetError();
+        TraceValue::Enum(out)
+      }
+      ("glGetFloatv", 2) => {
+        self.GetFloatv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *mut GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glGetFragDataIndex", 2) => {
+        let out = self.GetFragDataIndex(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Int(out as i64)
+      }
+      ("glGetFragDataLocation", 2) => {
+        let out = self.GetFragDataLocation(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Int(out as i64)
+      }
+      ("glGetFramebufferAttachmentParameteriv", 4) => {
+        self.GetFramebufferAttachmentParameteriv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetInteger64i_v", 3) => {
+        self.GetInteger64i_v(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_ptr() as *mut GLint64,
+        );
+        TraceValue::Void
+      }
+      ("glGetInteger64v", 2) => {
+        self.GetInteger64v(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *mut GLint64,
+        );
+        TraceValue::Void
+      }
+      ("glGetIntegeri_v", 3) => {
+        self.GetIntegeri_v(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetIntegerv", 2) => {
+        self.GetIntegerv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetMultisamplefv", 3) => {
+        self.GetMultisamplefv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_ptr() as *mut GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glGetObjectLabel", 5) => {
+        self.GetObjectLabel(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *mut GLsizei,
+          args[4].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetObjectPtrLabel", 4) => {
+        self.GetObjectPtrLabel(
+          args[0].replay_ptr() as *const c_void,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *mut GLsizei,
+          args[3].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetPointerv", 2) => {
+        self.GetPointerv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *mut *mut c_void,
+        );
+        TraceValue::Void
+      }
+      ("glGetProgramInfoLog", 4) => {
+        self.GetProgramInfoLog(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *mut GLsizei,
+          args[3].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetProgramiv", 3) => {
+        self.GetProgramiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetQueryObjecti64v", 3) => {
+        self.GetQueryObjecti64v(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint64,
+        );
+        TraceValue::Void
+      }
+      ("glGetQueryObjectiv", 3) => {
+        self.GetQueryObjectiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetQueryObjectui64v", 3) => {
+        self.GetQueryObjectui64v(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLuint64,
+        );
+        TraceValue::Void
+      }
+      ("glGetQueryObjectuiv", 3) => {
+        self.GetQueryObjectuiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGetQueryiv", 3) => {
+        self.GetQueryiv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetRenderbufferParameteriv", 3) => {
+        self.GetRenderbufferParameteriv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetSamplerParameterIiv", 3) => {
+        self.GetSamplerParameterIiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetSamplerParameterIuiv", 3) => {
+        self.GetSamplerParameterIuiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGetSamplerParameterfv", 3) => {
+        self.GetSamplerParameterfv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glGetSamplerParameteriv", 3) => {
+        self.GetSamplerParameteriv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetShaderInfoLog", 4) => {
+        self.GetShaderInfoLog(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *mut GLsizei,
+          args[3].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetShaderSource", 4) => {
+        self.GetShaderSource(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *mut GLsizei,
+          args[3].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetShaderiv", 3) => {
+        self.GetShaderiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetString", 1) => {
+        let out = self.GetString(args[0].replay_int() as GLenum);
+        TraceValue::Pointer(out as usize as u64)
+      }
+      ("glGetStringi", 2) => {
+        let out = self.GetStringi(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Pointer(out as usize as u64)
+      }
+      ("glGetSynciv", 5) => {
+        self.GetSynciv(
+          args[0].replay_ptr() as GLsync,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *mut GLsizei,
+          args[4].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetTexImage", 5) => {
+        self.GetTexImage(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLenum,
+          args[4].replay_ptr(),
+        );
+        TraceValue::Void
+      }
+      ("glGetTexLevelParameterfv", 4) => {
+        self.GetTexLevelParameterfv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *mut GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glGetTexLevelParameteriv", 4) => {
+        self.GetTexLevelParameteriv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetTexParameterIiv", 3) => {
+        self.GetTexParameterIiv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetTexParameterIuiv", 3) => {
+        self.GetTexParameterIuiv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGetTexParameterfv", 3) => {
+        self.GetTexParameterfv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glGetTexParameteriv", 3) => {
+        self.GetTexParameteriv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetTransformFeedbackVarying", 7) => {
+        self.GetTransformFeedbackVarying(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *mut GLsizei,
+          args[4].replay_ptr() as *mut GLsizei,
+          args[5].replay_ptr() as *mut GLenum,
+          args[6].replay_ptr() as *mut GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glGetUniformBlockIndex", 2) => {
+        let out = self.GetUniformBlockIndex(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLchar,
+        );
+        TraceValue::UInt(out as u64)
+      }
+      ("glGetUniformIndices", 4) => {
+        self.GetUniformIndices(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const *const GLchar,
+          args[3].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGetUniformLocation", 2) => {
+        let out = self.GetUniformLocation(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Int(out as i64)
+      }
+      ("glGetUniformfv", 3) => {
+        self.GetUniformfv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_ptr() as *mut GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glGetUniformiv", 3) => {
+        self.GetUniformiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetUniformuiv", 3) => {
+        self.GetUniformuiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGetVertexAttribIiv", 3) => {
+        self.GetVertexAttribIiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glGetVertexAttribIuiv", 3) => {
+        self.GetVertexAttribIuiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glGetVertexAttribPointerv", 3) => {
+        self.GetVertexAttribPointerv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut *mut c_void,
+        );
+        TraceValue::Void
+      }
+      ("glGetVertexAttribdv", 3) => {
+        self.GetVertexAttribdv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLdouble,
+        );
+        TraceValue::Void
+      }
+      ("glGetVertexAttribfv", 3) => {
+        self.GetVertexAttribfv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glGetVertexAttribiv", 3) => {
+        self.GetVertexAttribiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *mut GLint,
+        );
+        TraceValue::Void
+      }
+      ("glHint", 2) => {
+        self.Hint(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glIsBuffer", 1) => {
+        let out = self.IsBuffer(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsEnabled", 1) => {
+        let out = self.IsEnabled(args[0].replay_int() as GLenum);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsEnabledi", 2) => {
+        let out = self.IsEnabledi(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsFramebuffer", 1) => {
+        let out = self.IsFramebuffer(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsProgram", 1) => {
+        let out = self.IsProgram(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsQuery", 1) => {
+        let out = self.IsQuery(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsRenderbuffer", 1) => {
+        let out = self.IsRenderbuffer(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsSampler", 1) => {
+        let out = self.IsSampler(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsShader", 1) => {
+        let out = self.IsShader(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsSync", 1) => {
+        let out = self.IsSync(args[0].replay_ptr() as GLsync);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsTexture", 1) => {
+        let out = self.IsTexture(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glIsVertexArray", 1) => {
+        let out = self.IsVertexArray(args[0].replay_int() as GLuint);
+        TraceValue::UInt(out as u64)
+      }
+      ("glLineWidth", 1) => {
+        self.LineWidth(args[0].replay_float() as GLfloat);
+        TraceValue::Void
+      }
+      ("glLinkProgram", 1) => {
+        self.LinkProgram(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glLogicOp", 1) => {
+        self.LogicOp(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glMapBuffer", 2) => {
+        let out = self.MapBuffer(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::OutPointer(out as usize as u64)
+      }
+      ("glMapBufferRange", 4) => {
+        let out = self.MapBufferRange(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLintptr,
+          args[2].replay_int() as GLsizeiptr,
+          args[3].replay_int() as GLbitfield,
+        );
+        TraceValue::OutPointer(out as usize as u64)
+      }
+      ("glMultiDrawArrays", 4) => {
+        self.MultiDrawArrays(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *const GLint,
+          args[2].replay_ptr() as *const GLsizei,
+          args[3].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glMultiDrawElements", 5) => {
+        self.MultiDrawElements(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *const GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *const *const c_void,
+          args[4].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glMultiDrawElementsBaseVertex", 6) => {
+        self.MultiDrawElementsBaseVertex(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *const GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_ptr() as *const *const c_void,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glObjectLabel", 4) => {
+        self.ObjectLabel(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glObjectPtrLabel", 3) => {
+        self.ObjectPtrLabel(
+          args[0].replay_ptr() as *const c_void,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glPixelStoref", 2) => {
+        self.PixelStoref(
+          args[0].replay_int() as GLenum,
+          args[1].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glPixelStorei", 2) => {
+        self.PixelStorei(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glPointParameterf", 2) => {
+        self.PointParameterf(
+          args[0].replay_int() as GLenum,
+          args[1].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glPointParameterfv", 2) => {
+        self.PointParameterfv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glPointParameteri", 2) => {
+        self.PointParameteri(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glPointParameteriv", 2) => {
+        self.PointParameteriv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glPointSize", 1) => {
+        self.PointSize(args[0].replay_float() as GLfloat);
+        TraceValue::Void
+      }
+      ("glPolygonMode", 2) => {
+        self.PolygonMode(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glPolygonOffset", 2) => {
+        self.PolygonOffset(
+          args[0].replay_float() as GLfloat,
+          args[1].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glPopDebugGroup", 0) => {
+        self.PopDebugGroup();
+        TraceValue::Void
+      }
+      ("glPrimitiveRestartIndex", 1) => {
+        self.PrimitiveRestartIndex(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glProvokingVertex", 1) => {
+        self.ProvokingVertex(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glPushDebugGroup", 4) => {
+        self.PushDebugGroup(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_ptr() as *const GLchar,
+        );
+        TraceValue::Void
+      }
+      ("glQueryCounter", 2) => {
+        self.QueryCounter(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glReadBuffer", 1) => {
+        self.ReadBuffer(args[0].replay_int() as GLenum);
+        TraceValue::Void
+      }
+      ("glReadPixels", 7) => {
+        self.ReadPixels(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLenum,
+          args[5].replay_int() as GLenum,
+          args[6].replay_ptr(),
+        );
+        TraceValue::Void
+      }
+      ("glRenderbufferStorage", 4) => {
+        self.RenderbufferStorage(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glRenderbufferStorageMultisample", 5) => {
+        self.RenderbufferStorageMultisample(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glSampleCoverage", 2) => {
+        self.SampleCoverage(
+          args[0].replay_float() as GLfloat,
+          args[1].replay_int() as GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glSampleMaski", 2) => {
+        self.SampleMaski(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLbitfield,
+        );
+        TraceValue::Void
+      }
+      ("glSamplerParameterIiv", 3) => {
+        self.SamplerParameterIiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glSamplerParameterIuiv", 3) => {
+        self.SamplerParameterIuiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glSamplerParameterf", 3) => {
+        self.SamplerParameterf(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glSamplerParameterfv", 3) => {
+        self.SamplerParameterfv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glSamplerParameteri", 3) => {
+        self.SamplerParameteri(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glSamplerParameteriv", 3) => {
+        self.SamplerParameteriv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glScissor", 4) => {
+        self.Scissor(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glShaderSource", 4) => {
+        self.ShaderSource(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const *const GLchar,
+          args[3].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glStencilFunc", 3) => {
+        self.StencilFunc(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glStencilFuncSeparate", 4) => {
+        self.StencilFuncSeparate(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glStencilMask", 1) => {
+        self.StencilMask(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glStencilMaskSeparate", 2) => {
+        self.StencilMaskSeparate(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glStencilOp", 3) => {
+        self.StencilOp(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glStencilOpSeparate", 4) => {
+        self.StencilOpSeparate(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glTexBuffer", 3) => {
+        self.TexBuffer(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glTexImage1D", 8) => {
+        self.TexImage1D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLenum,
+          args[6].replay_int() as GLenum,
+          args[7].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glTexImage2D", 9) => {
+        self.TexImage2D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLint,
+          args[6].replay_int() as GLenum,
+          args[7].replay_int() as GLenum,
+          args[8].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glTexImage2DMultisample", 6) => {
+        self.TexImage2DMultisample(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glTexImage3D", 10) => {
+        self.TexImage3D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLint,
+          args[7].replay_int() as GLenum,
+          args[8].replay_int() as GLenum,
+          args[9].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glTexImage3DMultisample", 7) => {
+        self.TexImage3DMultisample(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLboolean,
+        );
+        TraceValue::Void
+      }
+      ("glTexParameterIiv", 3) => {
+        self.TexParameterIiv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glTexParameterIuiv", 3) => {
+        self.TexParameterIuiv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glTexParameterf", 3) => {
+        self.TexParameterf(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glTexParameterfv", 3) => {
+        self.TexParameterfv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glTexParameteri", 3) => {
+        self.TexParameteri(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glTexParameteriv", 3) => {
+        self.TexParameteriv(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLenum,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glTexSubImage1D", 7) => {
+        self.TexSubImage1D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_int() as GLenum,
+          args[5].replay_int() as GLenum,
+          args[6].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glTexSubImage2D", 9) => {
+        self.TexSubImage2D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLenum,
+          args[7].replay_int() as GLenum,
+          args[8].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glTexSubImage3D", 11) => {
+        self.TexSubImage3D(
+          args[0].replay_int() as GLenum,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+          args[5].replay_int() as GLsizei,
+          args[6].replay_int() as GLsizei,
+          args[7].replay_int() as GLsizei,
+          args[8].replay_int() as GLenum,
+          args[9].replay_int() as GLenum,
+          args[10].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glTransformFeedbackVaryings", 4) => {
+        self.TransformFeedbackVaryings(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const *const GLchar,
+          args[3].replay_int() as GLenum,
+        );
+        TraceValue::Void
+      }
+      ("glUniform1f", 2) => {
+        self.Uniform1f(
+          args[0].replay_int() as GLint,
+          args[1].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform1fv", 3) => {
+        self.Uniform1fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform1i", 2) => {
+        self.Uniform1i(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform1iv", 3) => {
+        self.Uniform1iv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform1ui", 2) => {
+        self.Uniform1ui(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform1uiv", 3) => {
+        self.Uniform1uiv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform2f", 3) => {
+        self.Uniform2f(
+          args[0].replay_int() as GLint,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform2fv", 3) => {
+        self.Uniform2fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform2i", 3) => {
+        self.Uniform2i(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform2iv", 3) => {
+        self.Uniform2iv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform2ui", 3) => {
+        self.Uniform2ui(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform2uiv", 3) => {
+        self.Uniform2uiv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform3f", 4) => {
+        self.Uniform3f(
+          args[0].replay_int() as GLint,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+          args[3].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform3fv", 3) => {
+        self.Uniform3fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform3i", 4) => {
+        self.Uniform3i(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform3iv", 3) => {
+        self.Uniform3iv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform3ui", 4) => {
+        self.Uniform3ui(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform3uiv", 3) => {
+        self.Uniform3uiv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform4f", 5) => {
+        self.Uniform4f(
+          args[0].replay_int() as GLint,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+          args[3].replay_float() as GLfloat,
+          args[4].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform4fv", 3) => {
+        self.Uniform4fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniform4i", 5) => {
+        self.Uniform4i(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform4iv", 3) => {
+        self.Uniform4iv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform4ui", 5) => {
+        self.Uniform4ui(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLuint,
+          args[4].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniform4uiv", 3) => {
+        self.Uniform4uiv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniformBlockBinding", 3) => {
+        self.UniformBlockBinding(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix2fv", 4) => {
+        self.UniformMatrix2fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix2x3fv", 4) => {
+        self.UniformMatrix2x3fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix2x4fv", 4) => {
+        self.UniformMatrix2x4fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix3fv", 4) => {
+        self.UniformMatrix3fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix3x2fv", 4) => {
+        self.UniformMatrix3x2fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix3x4fv", 4) => {
+        self.UniformMatrix3x4fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix4fv", 4) => {
+        self.UniformMatrix4fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix4x2fv", 4) => {
+        self.UniformMatrix4x2fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUniformMatrix4x3fv", 4) => {
+        self.UniformMatrix4x3fv(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLsizei,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glUnmapBuffer", 1) => {
+        let out = self.UnmapBuffer(args[0].replay_int() as GLenum);
+        TraceValue::UInt(out as u64)
+      }
+      ("glUseProgram", 1) => {
+        self.UseProgram(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glValidateProgram", 1) => {
+        self.ValidateProgram(args[0].replay_int() as GLuint);
+        TraceValue::Void
+      }
+      ("glVertexAttrib1d", 2) => {
+        self.VertexAttrib1d(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float(),
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib1dv", 2) => {
+        self.VertexAttrib1dv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLdouble,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib1f", 2) => {
+        self.VertexAttrib1f(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib1fv", 2) => {
+        self.VertexAttrib1fv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib1s", 2) => {
+        self.VertexAttrib1s(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib1sv", 2) => {
+        self.VertexAttrib1sv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib2d", 3) => {
+        self.VertexAttrib2d(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float(),
+          args[2].replay_float(),
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib2dv", 2) => {
+        self.VertexAttrib2dv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLdouble,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib2f", 3) => {
+        self.VertexAttrib2f(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib2fv", 2) => {
+        self.VertexAttrib2fv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib2s", 3) => {
+        self.VertexAttrib2s(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLshort,
+          args[2].replay_int() as GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib2sv", 2) => {
+        self.VertexAttrib2sv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib3d", 4) => {
+        self.VertexAttrib3d(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float(),
+          args[2].replay_float(),
+          args[3].replay_float(),
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib3dv", 2) => {
+        self.VertexAttrib3dv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLdouble,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib3f", 4) => {
+        self.VertexAttrib3f(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+          args[3].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib3fv", 2) => {
+        self.VertexAttrib3fv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib3s", 4) => {
+        self.VertexAttrib3s(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLshort,
+          args[2].replay_int() as GLshort,
+          args[3].replay_int() as GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib3sv", 2) => {
+        self.VertexAttrib3sv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4Nbv", 2) => {
+        self.VertexAttrib4Nbv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLbyte,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4Niv", 2) => {
+        self.VertexAttrib4Niv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4Nsv", 2) => {
+        self.VertexAttrib4Nsv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4Nub", 5) => {
+        self.VertexAttrib4Nub(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLubyte,
+          args[2].replay_int() as GLubyte,
+          args[3].replay_int() as GLubyte,
+          args[4].replay_int() as GLubyte,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4Nubv", 2) => {
+        self.VertexAttrib4Nubv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLubyte,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4Nuiv", 2) => {
+        self.VertexAttrib4Nuiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4Nusv", 2) => {
+        self.VertexAttrib4Nusv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLushort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4bv", 2) => {
+        self.VertexAttrib4bv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLbyte,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4d", 5) => {
+        self.VertexAttrib4d(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float(),
+          args[2].replay_float(),
+          args[3].replay_float(),
+          args[4].replay_float(),
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4dv", 2) => {
+        self.VertexAttrib4dv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLdouble,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4f", 5) => {
+        self.VertexAttrib4f(
+          args[0].replay_int() as GLuint,
+          args[1].replay_float() as GLfloat,
+          args[2].replay_float() as GLfloat,
+          args[3].replay_float() as GLfloat,
+          args[4].replay_float() as GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4fv", 2) => {
+        self.VertexAttrib4fv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLfloat,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4iv", 2) => {
+        self.VertexAttrib4iv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4s", 5) => {
+        self.VertexAttrib4s(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLshort,
+          args[2].replay_int() as GLshort,
+          args[3].replay_int() as GLshort,
+          args[4].replay_int() as GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4sv", 2) => {
+        self.VertexAttrib4sv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4ubv", 2) => {
+        self.VertexAttrib4ubv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLubyte,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4uiv", 2) => {
+        self.VertexAttrib4uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttrib4usv", 2) => {
+        self.VertexAttrib4usv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLushort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribDivisor", 2) => {
+        self.VertexAttribDivisor(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI1i", 2) => {
+        self.VertexAttribI1i(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI1iv", 2) => {
+        self.VertexAttribI1iv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI1ui", 2) => {
+        self.VertexAttribI1ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI1uiv", 2) => {
+        self.VertexAttribI1uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI2i", 3) => {
+        self.VertexAttribI2i(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI2iv", 2) => {
+        self.VertexAttribI2iv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI2ui", 3) => {
+        self.VertexAttribI2ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI2uiv", 2) => {
+        self.VertexAttribI2uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI3i", 4) => {
+        self.VertexAttribI3i(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI3iv", 2) => {
+        self.VertexAttribI3iv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI3ui", 4) => {
+        self.VertexAttribI3ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI3uiv", 2) => {
+        self.VertexAttribI3uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4bv", 2) => {
+        self.VertexAttribI4bv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLbyte,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4i", 5) => {
+        self.VertexAttribI4i(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLint,
+          args[3].replay_int() as GLint,
+          args[4].replay_int() as GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4iv", 2) => {
+        self.VertexAttribI4iv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4sv", 2) => {
+        self.VertexAttribI4sv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLshort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4ubv", 2) => {
+        self.VertexAttribI4ubv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLubyte,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4ui", 5) => {
+        self.VertexAttribI4ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLuint,
+          args[2].replay_int() as GLuint,
+          args[3].replay_int() as GLuint,
+          args[4].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4uiv", 2) => {
+        self.VertexAttribI4uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribI4usv", 2) => {
+        self.VertexAttribI4usv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_ptr() as *const GLushort,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribIPointer", 5) => {
+        self.VertexAttribIPointer(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLsizei,
+          args[4].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP1ui", 4) => {
+        self.VertexAttribP1ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP1uiv", 4) => {
+        self.VertexAttribP1uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP2ui", 4) => {
+        self.VertexAttribP2ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP2uiv", 4) => {
+        self.VertexAttribP2uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP3ui", 4) => {
+        self.VertexAttribP3ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP3uiv", 4) => {
+        self.VertexAttribP3uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP4ui", 4) => {
+        self.VertexAttribP4ui(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_int() as GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribP4uiv", 4) => {
+        self.VertexAttribP4uiv(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLenum,
+          args[2].replay_int() as GLboolean,
+          args[3].replay_ptr() as *const GLuint,
+        );
+        TraceValue::Void
+      }
+      ("glVertexAttribPointer", 6) => {
+        self.VertexAttribPointer(
+          args[0].replay_int() as GLuint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLenum,
+          args[3].replay_int() as GLboolean,
+          args[4].replay_int() as GLsizei,
+          args[5].replay_ptr() as *const c_void,
+        );
+        TraceValue::Void
+      }
+      ("glViewport", 4) => {
+        self.Viewport(
+          args[0].replay_int() as GLint,
+          args[1].replay_int() as GLint,
+          args[2].replay_int() as GLsizei,
+          args[3].replay_int() as GLsizei,
+        );
+        TraceValue::Void
+      }
+      ("glWaitSync", 3) => {
+        self.WaitSync(
+          args[0].replay_ptr() as GLsync,
+          args[1].replay_int() as GLbitfield,
+          args[2].replay_int() as GLuint64,
+        );
+        TraceValue::Void
+      }
+      _ => return None,
+    })
+  }
+}
+
+#[cold]
+#[inline(never)]
+fn not_loaded(name: &str) -> ! {
+  panic!("`{}` wasn't loaded", name)
+}
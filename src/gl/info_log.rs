@@ -0,0 +1,205 @@
+//! Parsing the info logs of shader compiles and program links.
+//!
+//! The log format isn't specified by GL, so this understands the common ones:
+//!
+//! * Mesa: `0:2(1): error: syntax error, unexpected NEW_IDENTIFIER`
+//! * NVIDIA: `0(2) : error C0000: syntax error, unexpected identifier`
+//! * AMD, Intel, Apple, and ANGLE: `ERROR: 0:2: 'bad' : syntax error`
+//!
+//! Any line that doesn't match is kept as a [`LogSeverity::Other`] entry.
+//!
+//! This file doesn't use anything from the rest of the crate, so that the web
+//! crate can include it too.
+
+/// How bad a log entry is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSeverity {
+  Error,
+  Warning,
+  /// The line didn't say.
+  Other,
+}
+
+/// One message from an info log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+  pub severity: LogSeverity,
+  /// The index of the source string (the first number in `0:2`).
+  pub source_string: Option<u32>,
+  pub line: Option<u32>,
+  pub column: Option<u32>,
+  pub message: String,
+}
+impl core::fmt::Display for LogEntry {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match (self.line, self.column) {
+      (Some(line), Some(column)) => write!(f, "{}:{}: ", line, column)?,
+      (Some(line), None) => write!(f, "{}: ", line)?,
+      _ => (),
+    }
+    match self.severity {
+      LogSeverity::Error => write!(f, "error: {}", self.message),
+      LogSeverity::Warning => write!(f, "warning: {}", self.message),
+      LogSeverity::Other => write!(f, "{}", self.message),
+    }
+  }
+}
+
+/// An info log, as the driver gave it and as parsed entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfoLog {
+  pub text: String,
+  pub entries: Vec<LogEntry>,
+}
+impl InfoLog {
+  pub fn new(text: String) -> Self {
+    let entries = parse_info_log(&text);
+    Self { text, entries }
+  }
+
+  /// Iterates the entries that are errors.
+  pub fn errors(&self) -> impl Iterator<Item = &LogEntry> {
+    self.entries.iter().filter(|e| e.severity == LogSeverity::Error)
+  }
+}
+
+/// Reads a number off the front of the string.
+fn split_number(s: &str) -> Option<(u32, &str)> {
+  let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+  Some((s[..end].parse().ok()?, &s[end..]))
+}
+
+/// Reads `error` or `warning` (any case, maybe with a `:`) off the front.
+fn split_severity(s: &str) -> Option<(LogSeverity, &str)> {
+  for (word, severity) in
+    [("error", LogSeverity::Error), ("warning", LogSeverity::Warning)]
+  {
+    if s.get(..word.len()).is_some_and(|w| w.eq_ignore_ascii_case(word)) {
+      let rest = &s[word.len()..];
+      return Some((severity, rest.strip_prefix(':').unwrap_or(rest).trim()));
+    }
+  }
+  None
+}
+
+/// `0:2(1): error: message`
+fn parse_mesa(line: &str) -> Option<LogEntry> {
+  let (source_string, rest) = split_number(line)?;
+  let (line, rest) = split_number(rest.strip_prefix(':')?)?;
+  let (column, rest) = split_number(rest.strip_prefix('(')?)?;
+  let rest = rest.strip_prefix("):")?.trim_start();
+  let (severity, message) =
+    split_severity(rest).unwrap_or((LogSeverity::Other, rest));
+  Some(LogEntry {
+    severity,
+    source_string: Some(source_string),
+    line: Some(line),
+    column: Some(column),
+    message: message.to_string(),
+  })
+}
+
+/// `0(2) : error C0000: message`
+fn parse_nvidia(line: &str) -> Option<LogEntry> {
+  let (source_string, rest) = split_number(line)?;
+  let (line, rest) = split_number(rest.strip_prefix('(')?)?;
+  let rest = rest.strip_prefix(')')?.trim_start().strip_prefix(':')?;
+  let (severity, message) = split_severity(rest.trim_start())?;
+  Some(LogEntry {
+    severity,
+    source_string: Some(source_string),
+    line: Some(line),
+    column: None,
+    message: message.to_string(),
+  })
+}
+
+/// `ERROR: 0:2: message`, or just `error: message`
+fn parse_prefixed(line: &str) -> Option<LogEntry> {
+  let (severity, rest) = split_severity(line)?;
+  let location = split_number(rest).and_then(|(source_string, rest)| {
+    let (line, rest) = split_number(rest.strip_prefix(':')?)?;
+    Some((source_string, line, rest.strip_prefix(':')?.trim_start()))
+  });
+  Some(match location {
+    Some((source_string, line, message)) => LogEntry {
+      severity,
+      source_string: Some(source_string),
+      line: Some(line),
+      column: None,
+      message: message.to_string(),
+    },
+    None => LogEntry {
+      severity,
+      source_string: None,
+      line: None,
+      column: None,
+      message: rest.to_string(),
+    },
+  })
+}
+
+/// Parses the text of an info log into entries, one per non-empty line.
+pub fn parse_info_log(log: &str) -> Vec<LogEntry> {
+  log
+    .lines()
+    .map(|line| line.trim_matches(|c: char| c.is_whitespace() || c == '\0'))
+    .filter(|line| !line.is_empty())
+    .map(|line| {
+      parse_mesa(line)
+        .or_else(|| parse_nvidia(line))
+        .or_else(|| parse_prefixed(line))
+        .unwrap_or_else(|| LogEntry {
+          severity: LogSeverity::Other,
+          source_string: None,
+          line: None,
+          column: None,
+          message: line.to_string(),
+        })
+    })
+    .collect()
+}
+
+#[test]
+fn test_parse_info_log() {
+  let mesa = parse_info_log(
+    "0:2(1): error: syntax error, unexpected NEW_IDENTIFIER\n\
+     0:5(10): warning: `x' used uninitialized\n",
+  );
+  assert_eq!(
+    mesa,
+    vec![
+      LogEntry {
+        severity: LogSeverity::Error,
+        source_string: Some(0),
+        line: Some(2),
+        column: Some(1),
+        message: "syntax error, unexpected NEW_IDENTIFIER".into(),
+      },
+      LogEntry {
+        severity: LogSeverity::Warning,
+        source_string: Some(0),
+        line: Some(5),
+        column: Some(10),
+        message: "`x' used uninitialized".into(),
+      },
+    ]
+  );
+
+  let nvidia = parse_info_log("0(2) : error C0000: syntax error\n");
+  assert_eq!(nvidia[0].severity, LogSeverity::Error);
+  assert_eq!((nvidia[0].line, nvidia[0].column), (Some(2), None));
+  assert_eq!(nvidia[0].message, "C0000: syntax error");
+
+  let angle = parse_info_log("ERROR: 0:3: 'bad' : syntax error\0");
+  assert_eq!(angle[0].severity, LogSeverity::Error);
+  assert_eq!(angle[0].line, Some(3));
+  assert_eq!(angle[0].message, "'bad' : syntax error");
+
+  let link = parse_info_log("error: vertex shader lacks `main'\nsomething");
+  assert_eq!(link[0].severity, LogSeverity::Error);
+  assert_eq!(link[0].line, None);
+  assert_eq!(link[0].message, "vertex shader lacks `main'");
+  assert_eq!(link[1].severity, LogSeverity::Other);
+  assert_eq!(link[1].message, "something");
+}
@@ -0,0 +1,446 @@
+//! Reading pixels back out of GL, for screenshots, tests, and video capture.
+//!
+//! [`Gl33::read_rgba8`] and [`Gl33::read_rgba_f32`] read part of the current
+//! read framebuffer right away. A [`PboReader`] reads into pixel buffer
+//! objects instead, so that the copy can happen while the next frames are
+//! drawn, and the pixels are picked up a few frames later.
+//!
+//! `glReadPixels` pads rows to the `GL_PACK_ALIGNMENT` (4 by default) and
+//! gives them bottom to top. The readers set the pack state to tightly packed
+//! rows for the read (putting it back afterwards) and then put the rows in
+//! whatever [`RowOrder`] is asked for.
+
+use super::*;
+use crate::image::{srgb8_to_linear, FloatImage, Image, PixelFormat, RowOrder};
+use std::collections::VecDeque;
+
+/// How a color buffer stores its colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorEncoding {
+  Linear,
+  /// The buffer holds sRGB encoded colors (and so it's a good fit for image
+  /// files as is).
+  Srgb,
+}
+
+/// The pack state that a read changes, so that it can be put back.
+struct PackState {
+  alignment: GLint,
+  row_length: GLint,
+  skip_rows: GLint,
+  skip_pixels: GLint,
+  pack_buffer: GLint,
+}
+impl PackState {
+  /// Sets tightly packed rows, with `pack_buffer` bound (or 0 for none).
+  unsafe fn set(gl: &Gl33, pack_buffer: GLuint) -> Self {
+    let get = |pname| {
+      let mut value = 0;
+      gl.GetIntegerv(pname, &mut value);
+      value
+    };
+    let old = Self {
+      alignment: get(GL_PACK_ALIGNMENT),
+      row_length: get(GL_PACK_ROW_LENGTH),
+      skip_rows: get(GL_PACK_SKIP_ROWS),
+      skip_pixels: get(GL_PACK_SKIP_PIXELS),
+      pack_buffer: get(GL_PIXEL_PACK_BUFFER_BINDING),
+    };
+    gl.PixelStorei(GL_PACK_ALIGNMENT, 1);
+    gl.PixelStorei(GL_PACK_ROW_LENGTH, 0);
+    gl.PixelStorei(GL_PACK_SKIP_ROWS, 0);
+    gl.PixelStorei(GL_PACK_SKIP_PIXELS, 0);
+    gl.BindBuffer(GL_PIXEL_PACK_BUFFER, pack_buffer);
+    old
+  }
+
+  unsafe fn restore(self, gl: &Gl33) {
+    gl.PixelStorei(GL_PACK_ALIGNMENT, self.alignment);
+    gl.PixelStorei(GL_PACK_ROW_LENGTH, self.row_length);
+    gl.PixelStorei(GL_PACK_SKIP_ROWS, self.skip_rows);
+    gl.PixelStorei(GL_PACK_SKIP_PIXELS, self.skip_pixels);
+    gl.BindBuffer(GL_PIXEL_PACK_BUFFER, self.pack_buffer as GLuint);
+  }
+}
+
+impl Gl33 {
+  /// The encoding of the current read buffer of the current read
+  /// framebuffer.
+  ///
+  /// Many default framebuffers say they're sRGB even though GL only encodes
+  /// colors into them when `GL_FRAMEBUFFER_SRGB` is enabled.
+  ///
+  /// ## Safety
+  ///
+  /// The table must be loaded from the context that's current on this thread.
+  pub unsafe fn read_buffer_encoding(&self) -> ColorEncoding {
+    let mut read_buffer = 0;
+    self.GetIntegerv(GL_READ_BUFFER, &mut read_buffer);
+    let attachment = match read_buffer as GLenum {
+      GL_NONE => return ColorEncoding::Linear,
+      // the default framebuffer's attachments are queried by their left
+      // buffer.
+      GL_FRONT => GL_FRONT_LEFT,
+      GL_BACK => GL_BACK_LEFT,
+      other => other,
+    };
+    let mut encoding = GL_LINEAR as GLint;
+    self.GetFramebufferAttachmentParameteriv(
+      GL_READ_FRAMEBUFFER,
+      attachment,
+      GL_FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+      &mut encoding,
+    );
+    if encoding as GLenum == GL_SRGB {
+      ColorEncoding::Srgb
+    } else {
+      ColorEncoding::Linear
+    }
+  }
+
+  /// Reads a rectangle of the current read framebuffer as RGBA8.
+  ///
+  /// `x` and `y` are the bottom left corner, like GL does it. The bytes are
+  /// exactly what's stored, so an sRGB buffer gives sRGB encoded colors.
+  ///
+  /// ## Safety
+  ///
+  /// The table must be loaded from the context that's current on this thread.
+  pub unsafe fn read_rgba8(
+    &self, x: GLint, y: GLint, width: u32, height: u32, rows: RowOrder,
+  ) -> Image {
+    let mut pixels = vec![0_u8; width as usize * height as usize * 4];
+    let old = PackState::set(self, 0);
+    self.ReadPixels(
+      x,
+      y,
+      width as GLsizei,
+      height as GLsizei,
+      GL_RGBA,
+      GL_UNSIGNED_BYTE,
+      pixels.as_mut_ptr().cast(),
+    );
+    old.restore(self);
+    Image {
+      width,
+      height,
+      format: PixelFormat::Rgba8,
+      rows: RowOrder::BottomUp,
+      pixels,
+    }
+    .into_row_order(rows)
+  }
+
+  /// Reads a rectangle of the current read framebuffer as RGBA `f32`.
+  ///
+  /// `x` and `y` are the bottom left corner, like GL does it. This gives back
+  /// the colors that the shaders wrote: when the buffer is sRGB and
+  /// `GL_FRAMEBUFFER_SRGB` is enabled they're decoded to linear, otherwise
+  /// they're what's stored (which keeps the full range of a float buffer).
+  ///
+  /// ## Safety
+  ///
+  /// The table must be loaded from the context that's current on this thread.
+  pub unsafe fn read_rgba_f32(
+    &self, x: GLint, y: GLint, width: u32, height: u32, rows: RowOrder,
+  ) -> FloatImage {
+    let mut pixels = vec![[0.0_f32; 4]; width as usize * height as usize];
+    let old = PackState::set(self, 0);
+    self.ReadPixels(
+      x,
+      y,
+      width as GLsizei,
+      height as GLsizei,
+      GL_RGBA,
+      GL_FLOAT,
+      pixels.as_mut_ptr().cast(),
+    );
+    old.restore(self);
+    let srgb_writes = self.IsEnabled(GL_FRAMEBUFFER_SRGB) != 0;
+    if srgb_writes && self.read_buffer_encoding() == ColorEncoding::Srgb {
+      // sRGB formats are all 8-bit, so each value is exactly some `n/255`.
+      for [r, g, b, _a] in pixels.iter_mut() {
+        for c in [r, g, b] {
+          *c = srgb8_to_linear((*c * 255.0).round() as u8);
+        }
+      }
+    }
+    FloatImage { width, height, rows: RowOrder::BottomUp, pixels }
+      .into_row_order(rows)
+  }
+}
+
+/// Reads frames into a ring of pixel buffer objects, so that reading a frame
+/// doesn't have to wait for it to finish drawing.
+///
+/// Each [`read`](Self::read) starts copying a rectangle of the current read
+/// framebuffer into the next buffer. Frames come back out in the order they
+/// were read, either from [`poll`](Self::poll) once the copy is done, or from
+/// [`finish`](Self::finish), which waits for it. When every buffer is in use,
+/// `read` waits for the oldest frame and gives it back, so no frame is lost.
+///
+/// Delete the buffers with [`delete`](Self::delete) while the context is
+/// still current.
+#[derive(Debug)]
+pub struct PboReader {
+  width: u32,
+  height: u32,
+  rows: RowOrder,
+  buffers: Vec<GLuint>,
+  /// The buffer index and fence of each read, oldest first.
+  pending: VecDeque<(usize, GLsync)>,
+  next: usize,
+}
+impl PboReader {
+  /// Makes `count` buffers, each big enough for a `width` by `height` RGBA8
+  /// frame. Frames are given with the rows in the `rows` order.
+  ///
+  /// ## Panics
+  /// * If `count` is 0.
+  ///
+  /// ## Safety
+  ///
+  /// `gl` must be loaded from the context that's current on this thread.
+  pub unsafe fn new(
+    gl: &Gl33, width: u32, height: u32, count: usize, rows: RowOrder,
+  ) -> Self {
+    assert!(count > 0, "a PboReader needs at least one buffer");
+    let mut buffers = vec![0; count];
+    gl.GenBuffers(count as GLsizei, buffers.as_mut_ptr());
+    let mut old_buffer = 0;
+    gl.GetIntegerv(GL_PIXEL_PACK_BUFFER_BINDING, &mut old_buffer);
+    let len = width as usize * height as usize * 4;
+    for &buffer in buffers.iter() {
+      gl.BindBuffer(GL_PIXEL_PACK_BUFFER, buffer);
+      gl.BufferData(
+        GL_PIXEL_PACK_BUFFER,
+        len as GLsizeiptr,
+        core::ptr::null(),
+        GL_STREAM_READ,
+      );
+    }
+    gl.BindBuffer(GL_PIXEL_PACK_BUFFER, old_buffer as GLuint);
+    Self { width, height, rows, buffers, pending: VecDeque::new(), next: 0 }
+  }
+
+  /// How many frames have been read but not given back yet.
+  pub fn pending(&self) -> usize {
+    self.pending.len()
+  }
+
+  /// Starts reading the frame with its bottom left corner at `x` and `y`.
+  ///
+  /// If every buffer was in use, this first waits for the oldest frame and
+  /// gives it back.
+  ///
+  /// ## Safety
+  ///
+  /// `gl` must be loaded from the context that's current on this thread.
+  /// It must be the context that the reader was made with.
+  pub unsafe fn read(
+    &mut self, gl: &Gl33, x: GLint, y: GLint,
+  ) -> Option<Image> {
+    let out = if self.pending.len() == self.buffers.len() {
+      self.finish(gl)
+    } else {
+      None
+    };
+    let index = self.next;
+    self.next = (self.next + 1) % self.buffers.len();
+    let old = PackState::set(gl, self.buffers[index]);
+    gl.ReadPixels(
+      x,
+      y,
+      self.width as GLsizei,
+      self.height as GLsizei,
+      GL_RGBA,
+      GL_UNSIGNED_BYTE,
+      core::ptr::null_mut(),
+    );
+    old.restore(gl);
+    let fence = gl.FenceSync(GL_SYNC_GPU_COMMANDS_COMPLETE, 0);
+    self.pending.push_back((index, fence));
+    out
+  }
+
+  /// Gives back the oldest frame if its copy is done, without waiting.
+  ///
+  /// ## Safety
+  ///
+  /// `gl` must be loaded from the context that's current on this thread.
+  /// It must be the context that the reader was made with.
+  pub unsafe fn poll(&mut self, gl: &Gl33) -> Option<Image> {
+    let &(_, fence) = self.pending.front()?;
+    match gl.ClientWaitSync(fence, GL_SYNC_FLUSH_COMMANDS_BIT, 0) {
+      GL_ALREADY_SIGNALED | GL_CONDITION_SATISFIED => self.finish(gl),
+      _ => None,
+    }
+  }
+
+  /// Gives back the oldest frame, waiting for its copy if needed.
+  ///
+  /// If the buffer can't be mapped (which is a GL error) the frame is all
+  /// zeros.
+  ///
+  /// ## Safety
+  ///
+  /// `gl` must be loaded from the context that's current on this thread.
+  /// It must be the context that the reader was made with.
+  pub unsafe fn finish(&mut self, gl: &Gl33) -> Option<Image> {
+    let (index, fence) = self.pending.pop_front()?;
+    // mapping the buffer waits for the copy anyway.
+    gl.DeleteSync(fence);
+    let len = self.width as usize * self.height as usize * 4;
+    let mut pixels = vec![0_u8; len];
+    let mut old_buffer = 0;
+    gl.GetIntegerv(GL_PIXEL_PACK_BUFFER_BINDING, &mut old_buffer);
+    gl.BindBuffer(GL_PIXEL_PACK_BUFFER, self.buffers[index]);
+    let p = gl.MapBufferRange(
+      GL_PIXEL_PACK_BUFFER,
+      0,
+      len as GLsizeiptr,
+      GL_MAP_READ_BIT,
+    );
+    if !p.is_null() {
+      pixels.copy_from_slice(core::slice::from_raw_parts(p.cast(), len));
+      gl.UnmapBuffer(GL_PIXEL_PACK_BUFFER);
+    }
+    gl.BindBuffer(GL_PIXEL_PACK_BUFFER, old_buffer as GLuint);
+    let image = Image {
+      width: self.width,
+      height: self.height,
+      format: PixelFormat::Rgba8,
+      rows: RowOrder::BottomUp,
+      pixels,
+    };
+    Some(image.into_row_order(self.rows))
+  }
+
+  /// Deletes the buffers, dropping any frames that weren't given back.
+  ///
+  /// ## Safety
+  ///
+  /// `gl` must be loaded from the context that's current on this thread.
+  /// It must be the context that the reader was made with.
+  pub unsafe fn delete(self, gl: &Gl33) {
+    for &(_, fence) in self.pending.iter() {
+      gl.DeleteSync(fence);
+    }
+    gl.DeleteBuffers(self.buffers.len() as GLsizei, self.buffers.as_ptr());
+  }
+}
+
+/// Runs `f` with a headless context and a bound framebuffer object that has
+/// a `width` by `height` color renderbuffer of `format`.
+#[cfg(all(test, target_os = "linux"))]
+fn with_framebuffer(
+  format: GLenum, width: GLsizei, height: GLsizei, f: impl FnOnce(&Gl33),
+) {
+  crate::test_util::with_headless_gl([1, 1], |gl| unsafe {
+    let mut fbo = 0;
+    let mut renderbuffer = 0;
+    gl.GenFramebuffers(1, &mut fbo);
+    gl.BindFramebuffer(GL_FRAMEBUFFER, fbo);
+    gl.GenRenderbuffers(1, &mut renderbuffer);
+    gl.BindRenderbuffer(GL_RENDERBUFFER, renderbuffer);
+    gl.RenderbufferStorage(GL_RENDERBUFFER, format, width, height);
+    gl.FramebufferRenderbuffer(
+      GL_FRAMEBUFFER,
+      GL_COLOR_ATTACHMENT0,
+      GL_RENDERBUFFER,
+      renderbuffer,
+    );
+    gl.ReadBuffer(GL_COLOR_ATTACHMENT0);
+    assert_eq!(
+      gl.CheckFramebufferStatus(GL_FRAMEBUFFER),
+      GL_FRAMEBUFFER_COMPLETE
+    );
+    f(gl);
+    assert_eq!(gl.GetError(), GL_NO_ERROR);
+  });
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_read_rgba8() {
+  with_framebuffer(GL_RGBA8, 3, 2, |gl| unsafe {
+    // blue on top, with a red bottom row.
+    gl.ClearColor(0.0, 0.0, 1.0, 1.0);
+    gl.Clear(GL_COLOR_BUFFER_BIT);
+    gl.Enable(GL_SCISSOR_TEST);
+    gl.Scissor(0, 0, 3, 1);
+    gl.ClearColor(1.0, 0.0, 0.0, 0.2);
+    gl.Clear(GL_COLOR_BUFFER_BIT);
+    gl.Disable(GL_SCISSOR_TEST);
+    // rows of 12 bytes would be padded to 16 if this was used for the read.
+    gl.PixelStorei(GL_PACK_ALIGNMENT, 8);
+    assert_eq!(gl.read_buffer_encoding(), ColorEncoding::Linear);
+
+    let image = gl.read_rgba8(0, 0, 3, 2, RowOrder::TopDown);
+    assert_eq!(image.rows, RowOrder::TopDown);
+    assert_eq!(image.pixels[..12], [0, 0, 255, 255].repeat(3));
+    assert_eq!(image.pixels[12..], [255, 0, 0, 51].repeat(3));
+    let image = gl.read_rgba8(1, 0, 2, 2, RowOrder::BottomUp);
+    assert_eq!(image.pixels[..8], [255, 0, 0, 51].repeat(2));
+    let mut alignment = 0;
+    gl.GetIntegerv(GL_PACK_ALIGNMENT, &mut alignment);
+    assert_eq!(alignment, 8);
+
+    let image = gl.read_rgba_f32(0, 0, 3, 2, RowOrder::TopDown);
+    assert_eq!(image.rgba(2, 0), [0.0, 0.0, 1.0, 1.0]);
+    let [r, g, b, a] = image.rgba(2, 1);
+    assert_eq!([r, g, b], [1.0, 0.0, 0.0]);
+    assert!((a - 0.2).abs() < 1e-6, "{}", a);
+  });
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_read_srgb() {
+  with_framebuffer(GL_SRGB8_ALPHA8, 2, 2, |gl| unsafe {
+    assert_eq!(gl.read_buffer_encoding(), ColorEncoding::Srgb);
+    gl.Enable(GL_FRAMEBUFFER_SRGB);
+    gl.ClearColor(0.5, 0.25, 0.0, 1.0);
+    gl.Clear(GL_COLOR_BUFFER_BIT);
+    let image = gl.read_rgba8(0, 0, 2, 2, RowOrder::TopDown);
+    assert_eq!(image.view().rgba(0, 0), [188, 137, 0, 255]);
+    let [r, g, b, a] =
+      gl.read_rgba_f32(0, 0, 2, 2, RowOrder::TopDown).rgba(1, 1);
+    assert!((r - 0.5).abs() < 0.005 && (g - 0.25).abs() < 0.005, "{} {}", r, g);
+    assert_eq!([b, a], [0.0, 1.0]);
+    // without sRGB writes, the stored values are given as they are.
+    gl.Disable(GL_FRAMEBUFFER_SRGB);
+    let [r, ..] = gl.read_rgba_f32(0, 0, 2, 2, RowOrder::TopDown).rgba(1, 1);
+    assert!((r - 188.0 / 255.0).abs() < 1e-6, "{}", r);
+  });
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_pbo_reader() {
+  with_framebuffer(GL_RGBA8, 4, 4, |gl| unsafe {
+    let mut reader = PboReader::new(gl, 4, 3, 2, RowOrder::TopDown);
+    let mut frames = Vec::new();
+    for i in 0..5 {
+      let c = i as f32 * 0.2;
+      gl.ClearColor(c, c, c, 1.0);
+      gl.Clear(GL_COLOR_BUFFER_BIT);
+      frames.extend(reader.read(gl, 0, 1));
+      assert!(reader.pending() <= 2);
+    }
+    assert_eq!(frames.len(), 3);
+    gl.Finish();
+    frames.extend(reader.poll(gl));
+    frames.extend(reader.finish(gl));
+    assert!(reader.finish(gl).is_none());
+    assert!(reader.poll(gl).is_none());
+    for (i, frame) in frames.iter().enumerate() {
+      assert_eq!((frame.width, frame.height), (4, 3));
+      let c = (i as f32 * 0.2 * 255.0).round() as u8;
+      assert_eq!(frame.pixels, [c, c, c, 255].repeat(12), "frame {}", i);
+    }
+    // a frame that's never given back is dropped by `delete`.
+    assert!(reader.read(gl, 0, 0).is_none());
+    reader.delete(gl);
+  });
+}
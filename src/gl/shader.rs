@@ -0,0 +1,175 @@
+//! Compiling shaders and linking programs, with readable errors.
+//!
+//! The info log of a failed compile or link is parsed into [`LogEntry`]
+//! values, see [`parse_info_log`].
+
+use super::*;
+
+/// An error from [`compile_shader`] or [`link_program`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShaderError {
+  /// `glCreateShader` or `glCreateProgram` gave 0.
+  CreateFailed,
+  /// `GL_COMPILE_STATUS` was false.
+  Compile { shader_type: GLenum, log: InfoLog },
+  /// `GL_LINK_STATUS` was false.
+  Link { log: InfoLog },
+}
+impl ShaderError {
+  /// The info log, if there is one.
+  pub fn log(&self) -> Option<&InfoLog> {
+    match self {
+      Self::CreateFailed => None,
+      Self::Compile { log, .. } | Self::Link { log } => Some(log),
+    }
+  }
+}
+impl std::error::Error for ShaderError {}
+impl core::fmt::Display for ShaderError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let log = match self {
+      Self::CreateFailed => {
+        return write!(f, "Couldn't create a shader or program object.")
+      }
+      Self::Compile { shader_type, log } => {
+        let name = match *shader_type {
+          GL_VERTEX_SHADER => "Vertex",
+          GL_FRAGMENT_SHADER => "Fragment",
+          GL_GEOMETRY_SHADER => "Geometry",
+          GL_TESS_CONTROL_SHADER => "Tessellation Control",
+          GL_TESS_EVALUATION_SHADER => "Tessellation Evaluation",
+          GL_COMPUTE_SHADER => "Compute",
+          _ => "Unknown",
+        };
+        write!(f, "{} Shader Compile Error:", name)?;
+        log
+      }
+      Self::Link { log } => {
+        write!(f, "Program Link Error:")?;
+        log
+      }
+    };
+    for entry in log.entries.iter() {
+      write!(f, "\n  {}", entry)?;
+    }
+    Ok(())
+  }
+}
+
+/// Gets the info log of a shader object.
+///
+/// ## Safety
+///
+/// `gl` must be loaded from the context that's current on this thread.
+///
+/// See [`glGetShaderInfoLog`](https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetShaderInfoLog.xhtml)
+pub unsafe fn get_shader_info_log(gl: &Gl33, shader: GLuint) -> String {
+  let mut log_len = 0;
+  gl.GetShaderiv(shader, GL_INFO_LOG_LENGTH, &mut log_len);
+  let mut log: Vec<u8> = vec![0; log_len.max(1) as usize];
+  let mut written = 0;
+  gl.GetShaderInfoLog(shader, log_len, &mut written, log.as_mut_ptr().cast());
+  log.truncate(written.max(0) as usize);
+  String::from_utf8_lossy(&log).into_owned()
+}
+
+/// Gets the info log of a program object.
+///
+/// ## Safety
+///
+/// `gl` must be loaded from the context that's current on this thread.
+///
+/// See [`glGetProgramInfoLog`](https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgramInfoLog.xhtml)
+pub unsafe fn get_program_info_log(gl: &Gl33, program: GLuint) -> String {
+  let mut log_len = 0;
+  gl.GetProgramiv(program, GL_INFO_LOG_LENGTH, &mut log_len);
+  let mut log: Vec<u8> = vec![0; log_len.max(1) as usize];
+  let mut written = 0;
+  gl.GetProgramInfoLog(program, log_len, &mut written, log.as_mut_ptr().cast());
+  log.truncate(written.max(0) as usize);
+  String::from_utf8_lossy(&log).into_owned()
+}
+
+/// Makes a shader object and compiles the source into it.
+///
+/// If compiling fails the shader object is deleted, and the error has the
+/// info log.
+///
+/// ## Safety
+///
+/// `gl` must be loaded from the context that's current on this thread.
+pub unsafe fn compile_shader(
+  gl: &Gl33, shader_type: GLenum, source: &str,
+) -> Result<GLuint, ShaderError> {
+  let shader = gl.CreateShader(shader_type);
+  if shader == 0 {
+    return Err(ShaderError::CreateFailed);
+  }
+  gl.ShaderSource(
+    shader,
+    1,
+    &source.as_bytes().as_ptr().cast(),
+    &(source.len() as GLint),
+  );
+  gl.CompileShader(shader);
+  let mut success = 0;
+  gl.GetShaderiv(shader, GL_COMPILE_STATUS, &mut success);
+  if success == 0 {
+    let log = InfoLog::new(get_shader_info_log(gl, shader));
+    gl.DeleteShader(shader);
+    Err(ShaderError::Compile { shader_type, log })
+  } else {
+    Ok(shader)
+  }
+}
+
+/// Makes a program object, attaches the shaders, and links it.
+///
+/// If linking fails the program object is deleted, and the error has the info
+/// log. The shaders aren't deleted either way.
+///
+/// ## Safety
+///
+/// `gl` must be loaded from the context that's current on this thread.
+/// The shaders must be shader objects of the context.
+pub unsafe fn link_program(
+  gl: &Gl33, shaders: &[GLuint],
+) -> Result<GLuint, ShaderError> {
+  let program = gl.CreateProgram();
+  if program == 0 {
+    return Err(ShaderError::CreateFailed);
+  }
+  for &shader in shaders {
+    gl.AttachShader(program, shader);
+  }
+  gl.LinkProgram(program);
+  let mut success = 0;
+  gl.GetProgramiv(program, GL_LINK_STATUS, &mut success);
+  if success == 0 {
+    let log = InfoLog::new(get_program_info_log(gl, program));
+    gl.DeleteProgram(program);
+    Err(ShaderError::Link { log })
+  } else {
+    Ok(program)
+  }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_shader_errors() {
+  crate::test_util::with_headless_gl([8, 8], |gl| unsafe {
+    let bad_source = "#version 330 core\nvoid main() {\n  bad\n}\n";
+    let e = compile_shader(gl, GL_VERTEX_SHADER, bad_source).unwrap_err();
+    let first_error = e.log().unwrap().errors().next().unwrap();
+    assert_eq!(first_error.line, Some(4), "{}", e);
+    assert!(e.to_string().starts_with("Vertex Shader Compile Error:"));
+
+    // no `main` in the vertex shader is a link error.
+    let no_main = "#version 330 core\nvoid helper() {}\n";
+    let vs = compile_shader(gl, GL_VERTEX_SHADER, no_main).unwrap();
+    let e = link_program(gl, &[vs]).unwrap_err();
+    assert!(matches!(e, ShaderError::Link { .. }), "{}", e);
+    assert!(e.log().unwrap().errors().next().is_some(), "{}", e);
+    gl.DeleteShader(vs);
+  });
+}
@@ -0,0 +1,1244 @@
+//! Recording GL calls into a trace, and replaying them.
+//!
+//! With the `trace_gl` feature every method of [`Gl33`] tells the recorder
+//! about the call. Between [`start_gl_trace`] and [`stop_gl_trace`] the calls
+//! are kept as [`TraceCall`] values, along with the memory that they read when
+//! the size of that memory is known (buffer data, shader sources, uniform
+//! values, texture pixels, and so on).
+//!
+//! A [`Trace`] can be saved with [`Trace::to_bytes`] and loaded with
+//! [`Trace::from_bytes`], printed one call per line with [`Trace::to_text`],
+//! and with the feature it can be replayed on a fresh context with
+//! [`Trace::replay`].
+//!
+//! ## Format
+//!
+//! All numbers are little-endian, and "varint" means unsigned LEB128.
+//!
+//! * The header is the magic bytes `GLTRACE\0` then the version as a `u32`.
+//! * Each record starts with a tag byte:
+//!   * `1` defines a command: the varint id (ids count up from 0), the name,
+//!     then the varint number of parameters and each parameter name. Strings
+//!     are a varint length and then UTF-8.
+//!   * `2` is a call: the varint command id, then one value for each
+//!     parameter, then the return value.
+//! * Each value is a tag byte (the index of the [`TraceValue`] variant) and
+//!   then the data: a zigzag varint for `Int`, a varint for `UInt`, `Enum`,
+//!   and the addresses, an `f64` for `Float`, and a varint length then bytes
+//!   for `Data` and `Out`. `Strings` is a varint count of such byte strings.
+
+use super::*;
+
+/// The first bytes of a binary trace.
+pub const TRACE_MAGIC: [u8; 8] = *b"GLTRACE\0";
+
+/// The version of the binary format that this module writes.
+pub const TRACE_VERSION: u32 = 1;
+
+/// An argument or return value of a traced call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceValue {
+  /// The return value of a `void` function.
+  Void,
+  Int(i64),
+  UInt(u64),
+  Enum(GLenum),
+  Float(f64),
+  /// An address that the call reads, but wasn't captured.
+  ///
+  /// Replaying a call with a non-null `Pointer` is skipped.
+  Pointer(u64),
+  /// An offset into a bound buffer, in a pointer argument.
+  Offset(u64),
+  /// The memory that a pointer argument pointed to.
+  Data(Vec<u8>),
+  /// The strings of `glShaderSource`, each with a null on the end.
+  Strings(Vec<Vec<u8>>),
+  /// An address that the call writes to, which wasn't captured.
+  ///
+  /// These calls only read GL state, so replaying a call with a non-null
+  /// `OutPointer` is skipped.
+  OutPointer(u64),
+  /// What the call wrote to a pointer argument (eg: the names from `glGen*`).
+  Out(Vec<u8>),
+  /// A `GLsync`.
+  Handle(u64),
+}
+
+/// Formats bytes for the text dump: as a string if they look like text,
+/// otherwise as hex (abbreviated with a hash if there's a lot of it).
+fn format_bytes(
+  f: &mut core::fmt::Formatter, bytes: &[u8],
+) -> core::fmt::Result {
+  let text = bytes.strip_suffix(&[0]).unwrap_or(bytes);
+  if let Ok(s) = core::str::from_utf8(text) {
+    if !s.is_empty()
+      && !s.chars().any(|c| c.is_control() && !"\n\r\t".contains(c))
+    {
+      return write!(f, "{:?}", s);
+    }
+  }
+  let hex = |bytes: &[u8]| -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+  };
+  if bytes.len() <= 64 {
+    write!(f, "[{} bytes: {}]", bytes.len(), hex(bytes))
+  } else {
+    // FNV-1a, so that different data is different in a diff.
+    let hash = bytes.iter().fold(0xcbf29ce484222325_u64, |h, &b| {
+      (h ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    write!(
+      f,
+      "[{} bytes: {}.., fnv1a {:016x}]",
+      bytes.len(),
+      hex(&bytes[..32]),
+      hash
+    )
+  }
+}
+
+impl core::fmt::Display for TraceValue {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self {
+      Self::Void => write!(f, "()"),
+      Self::Int(i) => write!(f, "{}", i),
+      Self::UInt(u) => write!(f, "{}", u),
+      Self::Enum(e) => match gl_enum_name(*e) {
+        Some(name) => write!(f, "{}", name),
+        None => write!(f, "0x{:04X}", e),
+      },
+      // a `GLfloat` is shown as the `f32` it was.
+      Self::Float(x) if (*x as f32) as f64 == *x => {
+        write!(f, "{:?}", *x as f32)
+      }
+      Self::Float(x) => write!(f, "{:?}", x),
+      Self::Pointer(0) | Self::OutPointer(0) => write!(f, "null"),
+      Self::Pointer(p) | Self::Handle(p) => write!(f, "0x{:X}", p),
+      Self::Offset(o) => write!(f, "+{}", o),
+      Self::Data(bytes) => format_bytes(f, bytes),
+      Self::Strings(strings) => {
+        write!(f, "[")?;
+        for (i, s) in strings.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          format_bytes(f, s)?;
+        }
+        write!(f, "]")
+      }
+      // the address changes every run, so it's not shown.
+      Self::OutPointer(_) => write!(f, "&mut _"),
+      Self::Out(bytes) => {
+        write!(f, "&mut ")?;
+        format_bytes(f, bytes)
+      }
+    }
+  }
+}
+
+/// One call of a trace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceCall {
+  /// The GL name, eg: `glClear`.
+  pub name: String,
+  pub params: Vec<String>,
+  /// One for each of the `params`.
+  pub args: Vec<TraceValue>,
+  pub ret: TraceValue,
+}
+impl core::fmt::Display for TraceCall {
+  /// Formats like `glBindBuffer(target: GL_ARRAY_BUFFER, buffer: 1)`.
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{}(", self.name)?;
+    for (i, (param, arg)) in
+      self.params.iter().zip(self.args.iter()).enumerate()
+    {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{}: {}", param, arg)?;
+    }
+    write!(f, ")")?;
+    if self.ret != TraceValue::Void {
+      write!(f, " -> {}", self.ret)?;
+    }
+    Ok(())
+  }
+}
+
+/// An error from [`Trace::from_bytes`], with the byte offset where it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceError {
+  BadMagic,
+  UnsupportedVersion(u32),
+  UnexpectedEnd,
+  BadTag { offset: usize, tag: u8 },
+  BadVarint { offset: usize },
+  UnknownCommand { offset: usize, id: u64 },
+  BadUtf8 { offset: usize },
+}
+impl core::fmt::Display for TraceError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self {
+      Self::BadMagic => write!(f, "not a GL trace"),
+      Self::UnsupportedVersion(v) => {
+        write!(f, "unsupported trace version {}", v)
+      }
+      Self::UnexpectedEnd => write!(f, "the trace ended early"),
+      Self::BadTag { offset, tag } => {
+        write!(f, "bad tag {} at byte {}", tag, offset)
+      }
+      Self::BadVarint { offset } => write!(f, "bad varint at byte {}", offset),
+      Self::UnknownCommand { offset, id } => {
+        write!(f, "undefined command id {} at byte {}", id, offset)
+      }
+      Self::BadUtf8 { offset } => write!(f, "bad UTF-8 at byte {}", offset),
+    }
+  }
+}
+impl std::error::Error for TraceError {}
+
+fn write_varint(out: &mut Vec<u8>, mut v: u64) {
+  loop {
+    let byte = (v & 0x7F) as u8;
+    v >>= 7;
+    if v == 0 {
+      out.push(byte);
+      return;
+    }
+    out.push(byte | 0x80);
+  }
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+  write_varint(out, bytes.len() as u64);
+  out.extend_from_slice(bytes);
+}
+
+fn write_value(out: &mut Vec<u8>, value: &TraceValue) {
+  match value {
+    TraceValue::Void => out.push(0),
+    TraceValue::Int(i) => {
+      out.push(1);
+      write_varint(out, ((i << 1) ^ (i >> 63)) as u64);
+    }
+    TraceValue::UInt(u) => {
+      out.push(2);
+      write_varint(out, *u);
+    }
+    TraceValue::Enum(e) => {
+      out.push(3);
+      write_varint(out, *e as u64);
+    }
+    TraceValue::Float(x) => {
+      out.push(4);
+      out.extend_from_slice(&x.to_le_bytes());
+    }
+    TraceValue::Pointer(p) => {
+      out.push(5);
+      write_varint(out, *p);
+    }
+    TraceValue::Offset(o) => {
+      out.push(6);
+      write_varint(out, *o);
+    }
+    TraceValue::Data(bytes) => {
+      out.push(7);
+      write_bytes(out, bytes);
+    }
+    TraceValue::Strings(strings) => {
+      out.push(8);
+      write_varint(out, strings.len() as u64);
+      for s in strings.iter() {
+        write_bytes(out, s);
+      }
+    }
+    TraceValue::OutPointer(p) => {
+      out.push(9);
+      write_varint(out, *p);
+    }
+    TraceValue::Out(bytes) => {
+      out.push(10);
+      write_bytes(out, bytes);
+    }
+    TraceValue::Handle(h) => {
+      out.push(11);
+      write_varint(out, *h);
+    }
+  }
+}
+
+/// Reads the binary format.
+struct Reader<'b> {
+  bytes: &'b [u8],
+  pos: usize,
+}
+impl<'b> Reader<'b> {
+  fn take(&mut self, n: usize) -> Result<&'b [u8], TraceError> {
+    let end = self.pos.checked_add(n).ok_or(TraceError::UnexpectedEnd)?;
+    let out = self.bytes.get(self.pos..end).ok_or(TraceError::UnexpectedEnd)?;
+    self.pos = end;
+    Ok(out)
+  }
+  fn byte(&mut self) -> Result<u8, TraceError> {
+    Ok(self.take(1)?[0])
+  }
+  fn varint(&mut self) -> Result<u64, TraceError> {
+    let offset = self.pos;
+    let mut v = 0_u64;
+    for shift in (0..64).step_by(7) {
+      let byte = self.byte()?;
+      // the 10th byte only has room for the top bit, and ends the varint.
+      if shift == 63 && byte > 1 {
+        return Err(TraceError::BadVarint { offset });
+      }
+      v |= ((byte & 0x7F) as u64) << shift;
+      if byte & 0x80 == 0 {
+        return Ok(v);
+      }
+    }
+    unreachable!()
+  }
+  fn bytes(&mut self) -> Result<Vec<u8>, TraceError> {
+    let len = self.varint()? as usize;
+    Ok(self.take(len)?.to_vec())
+  }
+  fn string(&mut self) -> Result<String, TraceError> {
+    let offset = self.pos;
+    String::from_utf8(self.bytes()?).map_err(|_| TraceError::BadUtf8 { offset })
+  }
+  fn value(&mut self) -> Result<TraceValue, TraceError> {
+    let offset = self.pos;
+    Ok(match self.byte()? {
+      0 => TraceValue::Void,
+      1 => {
+        let z = self.varint()?;
+        TraceValue::Int(((z >> 1) as i64) ^ -((z & 1) as i64))
+      }
+      2 => TraceValue::UInt(self.varint()?),
+      3 => TraceValue::Enum(self.varint()? as GLenum),
+      4 => {
+        let mut x = [0; 8];
+        x.copy_from_slice(self.take(8)?);
+        TraceValue::Float(f64::from_le_bytes(x))
+      }
+      5 => TraceValue::Pointer(self.varint()?),
+      6 => TraceValue::Offset(self.varint()?),
+      7 => TraceValue::Data(self.bytes()?),
+      8 => {
+        let count = self.varint()?;
+        let mut strings = Vec::new();
+        for _ in 0..count {
+          strings.push(self.bytes()?);
+        }
+        TraceValue::Strings(strings)
+      }
+      9 => TraceValue::OutPointer(self.varint()?),
+      10 => TraceValue::Out(self.bytes()?),
+      11 => TraceValue::Handle(self.varint()?),
+      tag => return Err(TraceError::BadTag { offset, tag }),
+    })
+  }
+}
+
+/// A list of recorded calls.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Trace {
+  pub calls: Vec<TraceCall>,
+}
+impl Trace {
+  /// Encodes the trace in the binary format.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&TRACE_MAGIC);
+    out.extend_from_slice(&TRACE_VERSION.to_le_bytes());
+    let mut ids: Vec<(&str, &[String])> = Vec::new();
+    for call in self.calls.iter() {
+      let key = (call.name.as_str(), call.params.as_slice());
+      let id = match ids.iter().position(|&k| k == key) {
+        Some(id) => id,
+        None => {
+          out.push(1);
+          write_varint(&mut out, ids.len() as u64);
+          write_bytes(&mut out, call.name.as_bytes());
+          write_varint(&mut out, call.params.len() as u64);
+          for p in call.params.iter() {
+            write_bytes(&mut out, p.as_bytes());
+          }
+          ids.push(key);
+          ids.len() - 1
+        }
+      };
+      out.push(2);
+      write_varint(&mut out, id as u64);
+      for arg in call.args.iter() {
+        write_value(&mut out, arg);
+      }
+      write_value(&mut out, &call.ret);
+    }
+    out
+  }
+
+  /// Decodes a trace from the binary format.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, TraceError> {
+    let mut r = Reader { bytes, pos: 0 };
+    if r.take(8).ok() != Some(&TRACE_MAGIC[..]) {
+      return Err(TraceError::BadMagic);
+    }
+    let mut version = [0; 4];
+    version.copy_from_slice(r.take(4)?);
+    let version = u32::from_le_bytes(version);
+    if version != TRACE_VERSION {
+      return Err(TraceError::UnsupportedVersion(version));
+    }
+    let mut commands: Vec<(String, Vec<String>)> = Vec::new();
+    let mut calls = Vec::new();
+    while r.pos < bytes.len() {
+      let offset = r.pos;
+      match r.byte()? {
+        1 => {
+          let id = r.varint()?;
+          if id != commands.len() as u64 {
+            return Err(TraceError::UnknownCommand { offset, id });
+          }
+          let name = r.string()?;
+          let count = r.varint()?;
+          let mut params = Vec::new();
+          for _ in 0..count {
+            params.push(r.string()?);
+          }
+          commands.push((name, params));
+        }
+        2 => {
+          let id = r.varint()?;
+          let (name, params) = commands
+            .get(id as usize)
+            .ok_or(TraceError::UnknownCommand { offset, id })?;
+          let mut args = Vec::with_capacity(params.len());
+          for _ in 0..params.len() {
+            args.push(r.value()?);
+          }
+          let ret = r.value()?;
+          calls.push(TraceCall {
+            name: name.clone(),
+            params: params.clone(),
+            args,
+            ret,
+          });
+        }
+        tag => return Err(TraceError::BadTag { offset, tag }),
+      }
+    }
+    Ok(Self { calls })
+  }
+
+  /// Formats the trace with one call per line, for reading and diffing.
+  pub fn to_text(&self) -> String {
+    let mut out = String::new();
+    for call in self.calls.iter() {
+      out.push_str(&call.to_string());
+      out.push('\n');
+    }
+    out
+  }
+}
+
+#[test]
+fn test_trace_round_trip() {
+  let call =
+    |name: &str, params: &[&str], args: Vec<TraceValue>, ret| TraceCall {
+      name: name.to_string(),
+      params: params.iter().map(|p| p.to_string()).collect(),
+      args,
+      ret,
+    };
+  let trace = Trace {
+    calls: vec![
+      call(
+        "glGenBuffers",
+        &["n", "buffers"],
+        vec![TraceValue::Int(1), TraceValue::Out(vec![1, 0, 0, 0])],
+        TraceValue::Void,
+      ),
+      call(
+        "glBufferData",
+        &["target", "size", "data", "usage"],
+        vec![
+          TraceValue::Enum(GL_ARRAY_BUFFER),
+          TraceValue::Int(3),
+          TraceValue::Data(vec![0, 1, 255]),
+          TraceValue::Enum(GL_STATIC_DRAW),
+        ],
+        TraceValue::Void,
+      ),
+      call(
+        "glShaderSource",
+        &["shader", "count", "string", "length"],
+        vec![
+          TraceValue::UInt(1),
+          TraceValue::Int(1),
+          TraceValue::Strings(vec![b"void main() {}\n\0".to_vec()]),
+          TraceValue::Pointer(0),
+        ],
+        TraceValue::Void,
+      ),
+      call(
+        "glClearColor",
+        &["red", "green", "blue", "alpha"],
+        vec![
+          TraceValue::Float(0.6_f32 as f64),
+          TraceValue::Float(-0.5),
+          TraceValue::Float(0.0),
+          TraceValue::Float(1.0),
+        ],
+        TraceValue::Void,
+      ),
+      call(
+        "glGetShaderiv",
+        &["shader", "pname", "params"],
+        vec![
+          TraceValue::UInt(1),
+          TraceValue::Enum(GL_COMPILE_STATUS),
+          TraceValue::OutPointer(0x7FFF_0000_1234),
+        ],
+        TraceValue::Void,
+      ),
+      call(
+        "glDrawElements",
+        &["mode", "count", "type", "indices"],
+        vec![
+          TraceValue::Enum(GL_TRIANGLES),
+          TraceValue::Int(-3),
+          TraceValue::Enum(0x1234_5678),
+          TraceValue::Offset(0),
+        ],
+        TraceValue::Void,
+      ),
+      call(
+        "glFenceSync",
+        &["condition", "flags"],
+        vec![
+          TraceValue::Enum(GL_SYNC_GPU_COMMANDS_COMPLETE),
+          TraceValue::UInt(0),
+        ],
+        TraceValue::Handle(0xABC),
+      ),
+      call(
+        "glBufferData",
+        &["target", "size", "data", "usage"],
+        vec![
+          TraceValue::Enum(GL_ARRAY_BUFFER),
+          TraceValue::Int(100),
+          TraceValue::Data((0..100).collect()),
+          TraceValue::Enum(GL_STATIC_DRAW),
+        ],
+        TraceValue::Void,
+      ),
+    ],
+  };
+  let bytes = trace.to_bytes();
+  assert!(bytes.starts_with(b"GLTRACE\0\x01\0\0\0"));
+  assert_eq!(Trace::from_bytes(&bytes).unwrap(), trace);
+  let text = trace.to_text();
+  let lines: Vec<&str> = text.lines().collect();
+  assert_eq!(lines[0], "glGenBuffers(n: 1, buffers: &mut [4 bytes: 01000000])");
+  assert_eq!(
+    lines[1],
+    "glBufferData(target: GL_ARRAY_BUFFER, size: 3, data: [3 bytes: 0001ff], \
+     usage: GL_STATIC_DRAW)"
+  );
+  assert_eq!(
+    lines[2],
+    "glShaderSource(shader: 1, count: 1, string: [\"void main() {}\\n\"], \
+     length: null)"
+  );
+  assert_eq!(
+    lines[3],
+    "glClearColor(red: 0.6, green: -0.5, blue: 0.0, alpha: 1.0)"
+  );
+  assert_eq!(
+    lines[4],
+    "glGetShaderiv(shader: 1, pname: GL_COMPILE_STATUS, params: &mut _)"
+  );
+  assert_eq!(
+    lines[5],
+    "glDrawElements(mode: GL_TRIANGLES, count: -3, type: 0x12345678, indices: +0)"
+  );
+  assert_eq!(
+    lines[6],
+    "glFenceSync(condition: GL_SYNC_GPU_COMMANDS_COMPLETE, flags: 0) -> 0xABC"
+  );
+  assert!(lines[7].starts_with(
+    "glBufferData(target: GL_ARRAY_BUFFER, size: 100, data: [100 bytes: 000102"
+  ));
+  assert!(lines[7].contains(".., fnv1a "));
+}
+
+#[test]
+fn test_trace_from_bytes_errors() {
+  assert_eq!(Trace::from_bytes(b"GLTRACE"), Err(TraceError::BadMagic));
+  assert_eq!(
+    Trace::from_bytes(b"GLTRACE\0\x02\0\0\0"),
+    Err(TraceError::UnsupportedVersion(2))
+  );
+  assert_eq!(
+    Trace::from_bytes(b"GLTRACE\0\x01\0\0"),
+    Err(TraceError::UnexpectedEnd)
+  );
+  assert_eq!(
+    Trace::from_bytes(b"GLTRACE\0\x01\0\0\0\x07"),
+    Err(TraceError::BadTag { offset: 12, tag: 7 })
+  );
+  assert_eq!(
+    Trace::from_bytes(b"GLTRACE\0\x01\0\0\0\x02\x00"),
+    Err(TraceError::UnknownCommand { offset: 12, id: 0 })
+  );
+  // `glFinish` with no params, then a call of it that's cut off.
+  assert_eq!(
+    Trace::from_bytes(b"GLTRACE\0\x01\0\0\0\x01\x00\x08glFinish\x00\x02\x00"),
+    Err(TraceError::UnexpectedEnd)
+  );
+  assert_eq!(
+    Trace::from_bytes(b"GLTRACE\0\x01\0\0\0\x01\x00\x01\xFF\x00"),
+    Err(TraceError::BadUtf8 { offset: 14 })
+  );
+  // a command id of `u64::MAX`, then one that's a bit too big, then one
+  // that's 11 bytes long.
+  let mut max = b"GLTRACE\0\x01\0\0\0\x02".to_vec();
+  max.extend_from_slice(&[0xFF; 9]);
+  max.push(0x01);
+  assert_eq!(
+    Trace::from_bytes(&max),
+    Err(TraceError::UnknownCommand { offset: 12, id: u64::MAX })
+  );
+  let mut too_big = max.clone();
+  *too_big.last_mut().unwrap() = 0x02;
+  assert_eq!(
+    Trace::from_bytes(&too_big),
+    Err(TraceError::BadVarint { offset: 13 })
+  );
+  let mut too_long = max;
+  *too_long.last_mut().unwrap() = 0x81;
+  too_long.push(0x00);
+  assert_eq!(
+    Trace::from_bytes(&too_long),
+    Err(TraceError::BadVarint { offset: 13 })
+  );
+}
+
+#[cfg(feature = "trace_gl")]
+pub use recording::*;
+#[cfg(feature = "trace_gl")]
+mod recording {
+  use super::*;
+  use core::{cell::RefCell, convert::TryInto, ffi::c_void};
+  use std::collections::HashMap;
+
+  std::thread_local! {
+    /// A GL context is current on one thread, so each thread records its own
+    /// calls. `None` when not recording.
+    static RECORDED: RefCell<Option<Vec<TraceCall>>> = const { RefCell::new(None) };
+  }
+
+  /// Starts recording the calls of every [`Gl33`] on this thread, throwing out
+  /// any calls that were recorded before.
+  pub fn start_gl_trace() {
+    RECORDED.with(|recorded| *recorded.borrow_mut() = Some(Vec::new()));
+  }
+
+  /// Stops recording on this thread, and gives the calls since
+  /// [`start_gl_trace`].
+  pub fn stop_gl_trace() -> Trace {
+    let calls = RECORDED.with(|recorded| recorded.borrow_mut().take());
+    Trace { calls: calls.unwrap_or_default() }
+  }
+
+  fn get_int(get_integerv: glGetIntegerv_t, pname: GLenum) -> GLint {
+    let mut value = 0;
+    if let Some(get_integerv) = get_integerv {
+      unsafe { get_integerv(pname, &mut value) };
+    }
+    value
+  }
+
+  fn int(args: &[TraceValue], i: usize) -> i64 {
+    match args.get(i) {
+      Some(TraceValue::Int(v)) => *v,
+      Some(TraceValue::UInt(v)) => *v as i64,
+      Some(TraceValue::Enum(v)) => *v as i64,
+      _ => 0,
+    }
+  }
+
+  /// Replaces a non-null `Pointer` with the `len` bytes it points to.
+  unsafe fn capture(args: &mut [TraceValue], i: usize, len: i64) {
+    if let Some(arg @ TraceValue::Pointer(_)) = args.get_mut(i) {
+      if let TraceValue::Pointer(p @ 1..) = *arg {
+        if len >= 0 {
+          let bytes = core::slice::from_raw_parts(p as *const u8, len as usize);
+          *arg = TraceValue::Data(bytes.to_vec());
+        }
+      }
+    }
+  }
+
+  /// Replaces a non-null `Pointer` with the null-terminated string.
+  unsafe fn capture_c_str(args: &mut [TraceValue], i: usize) {
+    if let Some(TraceValue::Pointer(p @ 1..)) = args.get(i) {
+      let s = core::ffi::CStr::from_ptr(*p as *const core::ffi::c_char);
+      args[i] = TraceValue::Data(s.to_bytes_with_nul().to_vec());
+    }
+  }
+
+  /// A `length` and `buf` pair, where a negative length means null-terminated.
+  unsafe fn capture_text(args: &mut [TraceValue], len_i: usize, i: usize) {
+    match int(args, len_i) {
+      len if len < 0 => capture_c_str(args, i),
+      len => capture(args, i, len),
+    }
+  }
+
+  /// Turns a pointer into an `Offset` if there's a buffer bound for it.
+  fn offset_if_bound(
+    get_integerv: glGetIntegerv_t, args: &mut [TraceValue], i: usize,
+    binding: GLenum,
+  ) -> bool {
+    let bound = get_int(get_integerv, binding) != 0;
+    if bound {
+      match args.get(i) {
+        Some(TraceValue::Pointer(p)) | Some(TraceValue::OutPointer(p)) => {
+          args[i] = TraceValue::Offset(*p)
+        }
+        _ => (),
+      }
+    }
+    bound
+  }
+
+  /// Bytes per pixel of client pixel data.
+  fn pixel_size(format: GLenum, type_: GLenum) -> Option<i64> {
+    let components = match format {
+      GL_RED | GL_GREEN | GL_BLUE | GL_RED_INTEGER | GL_GREEN_INTEGER
+      | GL_BLUE_INTEGER | GL_DEPTH_COMPONENT | GL_STENCIL_INDEX => 1,
+      GL_RG | GL_RG_INTEGER | GL_DEPTH_STENCIL => 2,
+      GL_RGB | GL_BGR | GL_RGB_INTEGER | GL_BGR_INTEGER => 3,
+      GL_RGBA | GL_BGRA | GL_RGBA_INTEGER | GL_BGRA_INTEGER => 4,
+      _ => return None,
+    };
+    Some(match type_ {
+      GL_UNSIGNED_BYTE | GL_BYTE => components,
+      GL_UNSIGNED_SHORT | GL_SHORT | GL_HALF_FLOAT => 2 * components,
+      GL_UNSIGNED_INT | GL_INT | GL_FLOAT => 4 * components,
+      GL_UNSIGNED_BYTE_3_3_2 | GL_UNSIGNED_BYTE_2_3_3_REV => 1,
+      GL_UNSIGNED_SHORT_5_6_5
+      | GL_UNSIGNED_SHORT_5_6_5_REV
+      | GL_UNSIGNED_SHORT_4_4_4_4
+      | GL_UNSIGNED_SHORT_4_4_4_4_REV
+      | GL_UNSIGNED_SHORT_5_5_5_1
+      | GL_UNSIGNED_SHORT_1_5_5_5_REV => 2,
+      GL_UNSIGNED_INT_8_8_8_8
+      | GL_UNSIGNED_INT_8_8_8_8_REV
+      | GL_UNSIGNED_INT_10_10_10_2
+      | GL_UNSIGNED_INT_2_10_10_10_REV
+      | GL_UNSIGNED_INT_24_8
+      | GL_UNSIGNED_INT_10F_11F_11F_REV
+      | GL_UNSIGNED_INT_5_9_9_9_REV => 4,
+      GL_FLOAT_32_UNSIGNED_INT_24_8_REV => 8,
+      _ => return None,
+    })
+  }
+
+  /// Captures the pixels of a 2D upload, using the unpack state.
+  unsafe fn capture_pixels(
+    get_integerv: glGetIntegerv_t, args: &mut [TraceValue],
+    [w, h, format, type_, pixels]: [usize; 5],
+  ) {
+    if offset_if_bound(
+      get_integerv,
+      args,
+      pixels,
+      GL_PIXEL_UNPACK_BUFFER_BINDING,
+    ) {
+      return;
+    }
+    let (width, height) = (int(args, w), int(args, h));
+    let size =
+      match pixel_size(int(args, format) as GLenum, int(args, type_) as GLenum)
+      {
+        Some(size) => size,
+        None => return,
+      };
+    if width <= 0 || height <= 0 {
+      return;
+    }
+    let alignment = get_int(get_integerv, GL_UNPACK_ALIGNMENT).max(1) as i64;
+    let row_length = match get_int(get_integerv, GL_UNPACK_ROW_LENGTH) as i64 {
+      0 => width,
+      row_length => row_length,
+    };
+    let skip_rows = get_int(get_integerv, GL_UNPACK_SKIP_ROWS) as i64;
+    let skip_pixels = get_int(get_integerv, GL_UNPACK_SKIP_PIXELS) as i64;
+    let stride = (row_length * size + alignment - 1) / alignment * alignment;
+    // the pixel store state is replayed too, so take the skipped parts as well.
+    let len = stride * (skip_rows + height - 1) + (skip_pixels + width) * size;
+    capture(args, pixels, len);
+  }
+
+  /// Captures the memory that a call reads, or the names that it makes.
+  unsafe fn capture_memory(
+    get_integerv: glGetIntegerv_t, name: &str, args: &mut [TraceValue],
+  ) {
+    match name {
+      "glBufferData" => capture(args, 2, int(args, 1)),
+      "glBufferSubData" => capture(args, 3, int(args, 2)),
+      "glShaderSource" => {
+        let count = int(args, 1).max(0) as usize;
+        let (strings, lengths) = match (&args[2], &args[3]) {
+          (TraceValue::Pointer(s @ 1..), &TraceValue::Pointer(l)) => {
+            (*s as *const *const u8, l as *const GLint)
+          }
+          _ => return,
+        };
+        let strings = (0..count)
+          .map(|i| {
+            let s = *strings.add(i);
+            let len = if lengths.is_null() { -1 } else { *lengths.add(i) };
+            let mut bytes = if len < 0 {
+              core::ffi::CStr::from_ptr(s.cast()).to_bytes().to_vec()
+            } else {
+              core::slice::from_raw_parts(s, len as usize).to_vec()
+            };
+            bytes.push(0);
+            bytes
+          })
+          .collect();
+        args[2] = TraceValue::Strings(strings);
+        // the strings have nulls now, so the lengths aren't needed.
+        args[3] = TraceValue::Pointer(0);
+      }
+      "glGetAttribLocation"
+      | "glGetUniformLocation"
+      | "glGetFragDataLocation"
+      | "glGetFragDataIndex"
+      | "glGetUniformBlockIndex" => capture_c_str(args, 1),
+      "glBindAttribLocation" | "glBindFragDataLocation" => {
+        capture_c_str(args, 2)
+      }
+      "glBindFragDataLocationIndexed" => capture_c_str(args, 3),
+      "glDebugMessageInsert" => capture_text(args, 4, 5),
+      "glPushDebugGroup" | "glObjectLabel" => capture_text(args, 2, 3),
+      "glDeleteBuffers"
+      | "glDeleteVertexArrays"
+      | "glDeleteTextures"
+      | "glDeleteFramebuffers"
+      | "glDeleteRenderbuffers"
+      | "glDeleteSamplers"
+      | "glDeleteQueries"
+      | "glDeleteTransformFeedbacks"
+      | "glDrawBuffers" => capture(args, 1, int(args, 0) * 4),
+      "glGenBuffers"
+      | "glGenVertexArrays"
+      | "glGenTextures"
+      | "glGenFramebuffers"
+      | "glGenRenderbuffers"
+      | "glGenSamplers"
+      | "glGenQueries"
+      | "glGenTransformFeedbacks" => {
+        let n = int(args, 0);
+        if let TraceValue::OutPointer(p @ 1..) = args[1] {
+          if n > 0 {
+            let bytes =
+              core::slice::from_raw_parts(p as *const u8, n as usize * 4);
+            args[1] = TraceValue::Out(bytes.to_vec());
+          }
+        }
+      }
+      "glVertexAttribPointer" => {
+        offset_if_bound(get_integerv, args, 5, GL_ARRAY_BUFFER_BINDING);
+      }
+      "glVertexAttribIPointer" => {
+        offset_if_bound(get_integerv, args, 4, GL_ARRAY_BUFFER_BINDING);
+      }
+      "glDrawElements"
+      | "glDrawElementsBaseVertex"
+      | "glDrawElementsInstanced"
+      | "glDrawElementsInstancedBaseVertex" => {
+        offset_if_bound(get_integerv, args, 3, GL_ELEMENT_ARRAY_BUFFER_BINDING);
+      }
+      "glDrawRangeElements" | "glDrawRangeElementsBaseVertex" => {
+        offset_if_bound(get_integerv, args, 5, GL_ELEMENT_ARRAY_BUFFER_BINDING);
+      }
+      "glReadPixels" => {
+        offset_if_bound(get_integerv, args, 6, GL_PIXEL_PACK_BUFFER_BINDING);
+      }
+      "glTexImage2D" => capture_pixels(get_integerv, args, [3, 4, 6, 7, 8]),
+      "glTexSubImage2D" => capture_pixels(get_integerv, args, [4, 5, 6, 7, 8]),
+      "glCompressedTexImage2D" | "glCompressedTexSubImage2D" => {
+        let (size, data) =
+          if name == "glCompressedTexImage2D" { (6, 7) } else { (7, 8) };
+        if !offset_if_bound(
+          get_integerv,
+          args,
+          data,
+          GL_PIXEL_UNPACK_BUFFER_BINDING,
+        ) {
+          capture(args, data, int(args, size));
+        }
+      }
+      _ => capture_uniform(name, args),
+    }
+  }
+
+  /// `glUniform{N}{f,i,ui}v` and `glUniformMatrix{N}[x{M}]fv`.
+  unsafe fn capture_uniform(name: &str, args: &mut [TraceValue]) {
+    let count = int(args, 1);
+    if let Some(matrix) =
+      name.strip_prefix("glUniformMatrix").and_then(|m| m.strip_suffix("fv"))
+    {
+      let (a, b) = matrix.split_once('x').unwrap_or((matrix, matrix));
+      if let (Ok(a), Ok(b)) = (a.parse::<i64>(), b.parse::<i64>()) {
+        capture(args, 3, count * a * b * 4);
+      }
+    } else if let Some(vector) =
+      name.strip_prefix("glUniform").and_then(|v| v.strip_suffix('v'))
+    {
+      let n = vector.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+      if let Ok(n) = n.parse::<i64>() {
+        capture(args, 2, count * n * 4);
+      }
+    }
+  }
+
+  /// Called by every method of [`Gl33`] with the `trace_gl` feature.
+  pub(crate) fn trace_gl_call(
+    get_integerv: glGetIntegerv_t, name: &'static str,
+    params: &'static [&'static str], args: &[TraceValue], ret: TraceValue,
+  ) {
+    if RECORDED.with(|recorded| recorded.borrow().is_none()) {
+      return;
+    }
+    let mut args = args.to_vec();
+    unsafe { capture_memory(get_integerv, name, &mut args) };
+    let call = TraceCall {
+      name: name.to_string(),
+      params: params.iter().map(|p| p.to_string()).collect(),
+      args,
+      ret,
+    };
+    RECORDED.with(|recorded| {
+      if let Some(calls) = recorded.borrow_mut().as_mut() {
+        calls.push(call);
+      }
+    });
+  }
+
+  impl TraceValue {
+    pub(crate) fn replay_int(&self) -> i64 {
+      match *self {
+        Self::Int(v) => v,
+        Self::UInt(v) => v as i64,
+        Self::Enum(v) => v as i64,
+        Self::Float(x) => x as i64,
+        _ => 0,
+      }
+    }
+
+    pub(crate) fn replay_float(&self) -> f64 {
+      match *self {
+        Self::Float(x) => x,
+        _ => self.replay_int() as f64,
+      }
+    }
+
+    pub(crate) fn replay_ptr(&mut self) -> *mut c_void {
+      match self {
+        Self::Data(bytes) | Self::Out(bytes) => bytes.as_mut_ptr().cast(),
+        Self::Pointer(p)
+        | Self::Offset(p)
+        | Self::OutPointer(p)
+        | Self::Handle(p) => *p as usize as *mut c_void,
+        Self::Void
+        | Self::Int(_)
+        | Self::UInt(_)
+        | Self::Enum(_)
+        | Self::Float(_)
+        | Self::Strings(_) => core::ptr::null_mut(),
+      }
+    }
+  }
+
+  /// The sorts of GL object names that a replay keeps track of.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  enum NameKind {
+    Buffer,
+    VertexArray,
+    Texture,
+    Framebuffer,
+    Renderbuffer,
+    Sampler,
+    /// Shaders and programs share names.
+    Program,
+  }
+  impl NameKind {
+    /// By the parameter name, eg: the `buffer` of `glBindBuffer`.
+    fn of_param(param: &str) -> Option<Self> {
+      Some(match param {
+        "buffer" => Self::Buffer,
+        "array" => Self::VertexArray,
+        "texture" => Self::Texture,
+        "framebuffer" => Self::Framebuffer,
+        "renderbuffer" => Self::Renderbuffer,
+        "sampler" => Self::Sampler,
+        "program" | "shader" => Self::Program,
+        _ => return None,
+      })
+    }
+    /// By the `glGen*` or `glDelete*` name, eg: `glGenBuffers`.
+    fn of_command(name: &str) -> Option<Self> {
+      let plural =
+        name.strip_prefix("glGen").or_else(|| name.strip_prefix("glDelete"))?;
+      Some(match plural {
+        "Buffers" => Self::Buffer,
+        "VertexArrays" => Self::VertexArray,
+        "Textures" => Self::Texture,
+        "Framebuffers" => Self::Framebuffer,
+        "Renderbuffers" => Self::Renderbuffer,
+        "Samplers" => Self::Sampler,
+        _ => return None,
+      })
+    }
+  }
+
+  /// Maps the names and locations in a trace to the ones of the replay.
+  #[derive(Default)]
+  struct Names {
+    objects: HashMap<(NameKind, u64), u64>,
+    /// By the recorded program and location.
+    locations: HashMap<(u64, i64), i64>,
+    syncs: HashMap<u64, u64>,
+    /// The recorded name of the current program.
+    current_program: u64,
+  }
+  impl Names {
+    fn map_u32s(&self, kind: NameKind, bytes: &mut [u8]) {
+      for chunk in bytes.chunks_exact_mut(4) {
+        let name = u32::from_le_bytes(chunk.try_into().unwrap()) as u64;
+        if let Some(&new) = self.objects.get(&(kind, name)) {
+          chunk.copy_from_slice(&(new as u32).to_le_bytes());
+        }
+      }
+    }
+
+    /// Changes recorded names in the arguments into replayed names.
+    fn map_args(&self, call: &TraceCall, args: &mut [TraceValue]) {
+      let program = call
+        .params
+        .iter()
+        .position(|p| p == "program")
+        .map(|i| call.args[i].replay_int() as u64)
+        .unwrap_or(self.current_program);
+      for (param, arg) in call.params.iter().zip(args.iter_mut()) {
+        match arg {
+          TraceValue::UInt(name @ 1..) => {
+            if let Some(kind) = NameKind::of_param(param) {
+              *name = *self.objects.get(&(kind, *name)).unwrap_or(name);
+            }
+          }
+          TraceValue::Int(location) if param == "location" => {
+            if let Some(&new) = self.locations.get(&(program, *location)) {
+              *location = new;
+            }
+          }
+          TraceValue::Data(bytes) if call.name.starts_with("glDelete") => {
+            if let Some(kind) = NameKind::of_command(&call.name) {
+              self.map_u32s(kind, bytes);
+            }
+          }
+          TraceValue::Handle(sync) => {
+            *sync = *self.syncs.get(sync).unwrap_or(sync);
+          }
+          _ => (),
+        }
+      }
+    }
+
+    /// Learns the new names that a replayed call made.
+    fn learn(
+      &mut self, call: &TraceCall, args: &[TraceValue], ret: &TraceValue,
+    ) {
+      let recorded_u32s = |bytes: &[u8]| -> Vec<u64> {
+        bytes
+          .chunks_exact(4)
+          .map(|c| u32::from_le_bytes(c.try_into().unwrap()) as u64)
+          .collect()
+      };
+      match (call.name.as_str(), &call.ret, ret) {
+        (
+          "glCreateShader" | "glCreateProgram",
+          TraceValue::UInt(old),
+          TraceValue::UInt(new),
+        ) => {
+          self.objects.insert((NameKind::Program, *old), *new);
+        }
+        (
+          "glGetUniformLocation",
+          TraceValue::Int(old),
+          TraceValue::Int(new),
+        ) => {
+          let program = call.args[0].replay_int() as u64;
+          self.locations.insert((program, *old), *new);
+        }
+        ("glFenceSync", TraceValue::Handle(old), TraceValue::Handle(new)) => {
+          self.syncs.insert(*old, *new);
+        }
+        ("glUseProgram", _, _) => {
+          self.current_program = call.args[0].replay_int() as u64;
+        }
+        (name, _, _) if name.starts_with("glGen") => {
+          if let (Some(kind), TraceValue::Out(old), TraceValue::Out(new)) =
+            (NameKind::of_command(name), &call.args[1], &args[1])
+          {
+            for (old, new) in
+              recorded_u32s(old).into_iter().zip(recorded_u32s(new))
+            {
+              self.objects.insert((kind, old), new);
+            }
+          }
+        }
+        _ => (),
+      }
+    }
+  }
+
+  /// What [`Trace::replay`] did.
+  #[derive(Debug, Clone, PartialEq, Eq, Default)]
+  pub struct ReplayStats {
+    pub replayed: usize,
+    /// The indexes of calls that were skipped because they read or write
+    /// memory that wasn't captured.
+    pub skipped: Vec<usize>,
+  }
+
+  /// A call that [`Trace::replay`] couldn't do.
+  #[derive(Debug, Clone, PartialEq, Eq)]
+  pub struct ReplayError {
+    pub index: usize,
+    pub name: String,
+  }
+  impl core::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+      write!(
+        f,
+        "call {} is `{}`, which can't be replayed",
+        self.index, self.name
+      )
+    }
+  }
+  impl std::error::Error for ReplayError {}
+
+  impl Trace {
+    /// Makes all the calls again, with the context that `gl` was loaded for.
+    ///
+    /// The context should be fresh. Object names, uniform locations, and syncs
+    /// from the trace are changed into the ones that the replay gets. Calls
+    /// that read or write memory that wasn't captured are skipped (those that
+    /// write are queries, so that's fine), and the stats list them.
+    ///
+    /// ## Safety
+    ///
+    /// `gl` must be loaded from the context that's current on this thread.
+    pub unsafe fn replay(&self, gl: &Gl33) -> Result<ReplayStats, ReplayError> {
+      let mut stats = ReplayStats::default();
+      let mut names = Names::default();
+      for (index, call) in self.calls.iter().enumerate() {
+        let uncaptured = call.args.iter().any(|a| {
+          matches!(a, TraceValue::Pointer(1..) | TraceValue::OutPointer(1..))
+        });
+        if uncaptured {
+          stats.skipped.push(index);
+          continue;
+        }
+        let mut args = call.args.clone();
+        names.map_args(call, &mut args);
+        // the array of string pointers has to live until the call is done.
+        let mut string_arrays: Vec<Vec<*const u8>> = Vec::new();
+        for (arg, recorded) in args.iter_mut().zip(call.args.iter()) {
+          if let TraceValue::Strings(strings) = recorded {
+            let pointers: Vec<*const u8> =
+              strings.iter().map(|s| s.as_ptr()).collect();
+            *arg = TraceValue::Offset(pointers.as_ptr() as usize as u64);
+            string_arrays.push(pointers);
+          }
+        }
+        let ret = gl
+          .replay_call(&call.name, &mut args)
+          .ok_or_else(|| ReplayError { index, name: call.name.clone() })?;
+        drop(string_arrays);
+        names.learn(call, &args, &ret);
+        stats.replayed += 1;
+      }
+      Ok(stats)
+    }
+  }
+}
+
+#[test]
+#[cfg(all(target_os = "linux", feature = "trace_gl"))]
+fn test_trace_record_and_replay() {
+  use crate::test_util::with_headless_gl;
+  let run = |gl: &Gl33, trace: Option<&Trace>| unsafe {
+    match trace {
+      None => {
+        start_gl_trace();
+        let mut buffers = [0; 2];
+        gl.GenBuffers(2, buffers.as_mut_ptr());
+        gl.DeleteBuffers(1, &buffers[0]);
+        gl.BindBuffer(GL_ARRAY_BUFFER, buffers[1]);
+        let data = [1.0_f32, 2.0];
+        gl.BufferData(GL_ARRAY_BUFFER, 8, data.as_ptr().cast(), GL_STATIC_DRAW);
+        let mut bound = 0;
+        gl.GetIntegerv(GL_ARRAY_BUFFER_BINDING, &mut bound);
+        gl.ClearColor(0.25, 0.5, 0.75, 1.0);
+        gl.Clear(GL_COLOR_BUFFER_BIT);
+        Ok(stop_gl_trace())
+      }
+      Some(trace) => trace.replay(gl).map(|stats| {
+        assert_eq!(stats.skipped, vec![4], "{:?}", stats);
+        let mut bound = 0;
+        gl.GetIntegerv(GL_ARRAY_BUFFER_BINDING, &mut bound);
+        assert_ne!(bound, 0);
+        let mut data = [0.0_f32; 2];
+        gl.GetBufferSubData(GL_ARRAY_BUFFER, 0, 8, data.as_mut_ptr().cast());
+        assert_eq!(data, [1.0, 2.0]);
+        let mut pixel = [0_u8; 4];
+        gl.ReadPixels(
+          0,
+          0,
+          1,
+          1,
+          GL_RGBA,
+          GL_UNSIGNED_BYTE,
+          pixel.as_mut_ptr().cast(),
+        );
+        assert_eq!(pixel, [64, 128, 191, 255]);
+        Trace::default()
+      }),
+    }
+  };
+  let trace = match with_headless_gl([4, 4], |gl| run(gl, None)) {
+    Some(trace) => trace.unwrap(),
+    None => return,
+  };
+  let text = trace.to_text();
+  let lines: Vec<&str> = text.lines().collect();
+  assert_eq!(lines.len(), 7, "{}", text);
+  assert!(lines[0].starts_with("glGenBuffers(n: 2, buffers: &mut [8 bytes: "));
+  assert_eq!(lines[3], "glBufferData(target: GL_ARRAY_BUFFER, size: 8, data: [8 bytes: 0000803f00000040], usage: GL_STATIC_DRAW)");
+  assert_eq!(
+    lines[4],
+    "glGetIntegerv(pname: GL_ARRAY_BUFFER_BINDING, data: &mut _)"
+  );
+  let trace = Trace::from_bytes(&trace.to_bytes()).unwrap();
+  with_headless_gl([4, 4], |gl| run(gl, Some(&trace))).unwrap().unwrap();
+}
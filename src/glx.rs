@@ -0,0 +1,822 @@
+#![cfg(target_os = "linux")]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+//! Module for GLX, the glue between Xlib and OpenGL on Linux.
+//!
+//! This is the Linux counterpart to the WGL parts of the `win32` module.
+
+use core::ptr::null;
+
+use crate::{
+  c_str, context::*, format::FormatDesc, gather_null_terminated_bytes,
+  min_alloc_lossy_into_string, x11::*,
+};
+
+/// An opaque GLX rendering context.
+#[repr(C)]
+pub struct __GLXcontextRec {
+  _private: [u8; 0],
+}
+
+/// An opaque GLX framebuffer configuration.
+#[repr(C)]
+pub struct __GLXFBConfigRec {
+  _private: [u8; 0],
+}
+
+/// Handle (to a) GLX rendering context
+pub type GLXContext = *mut __GLXcontextRec;
+
+/// Handle (to a) GLX framebuffer configuration
+pub type GLXFBConfig = *mut __GLXFBConfigRec;
+
+/// Anything that GLX can draw to. A [`Window`] is one of these.
+pub type GLXDrawable = XID;
+
+/// Pointer to a procedure of unknown type.
+pub type __GLXextFuncPtr = Option<unsafe extern "C" fn()>;
+
+/// Type for [glXCreateContextAttribsARB](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub type glXCreateContextAttribsARB_t = Option<
+  unsafe extern "C" fn(
+    dpy: *mut Display,
+    config: GLXFBConfig,
+    share_context: GLXContext,
+    direct: Bool,
+    attrib_list: *const c_int,
+  ) -> GLXContext,
+>;
+
+/// Type for [glXSwapIntervalEXT](https://www.khronos.org/registry/OpenGL/extensions/EXT/EXT_swap_control.txt)
+pub type glXSwapIntervalEXT_t = Option<
+  unsafe extern "C" fn(
+    dpy: *mut Display,
+    drawable: GLXDrawable,
+    interval: c_int,
+  ),
+>;
+
+/// [`glx_query_server_string`] name
+pub const GLX_VENDOR: c_int = 1;
+/// [`glx_query_server_string`] name
+pub const GLX_VERSION: c_int = 2;
+/// [`glx_query_server_string`] name
+pub const GLX_EXTENSIONS: c_int = 3;
+
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_BUFFER_SIZE: c_int = 2;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_DOUBLEBUFFER: c_int = 5;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_STEREO: c_int = 6;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_AUX_BUFFERS: c_int = 7;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_RED_SIZE: c_int = 8;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_GREEN_SIZE: c_int = 9;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_BLUE_SIZE: c_int = 10;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_ALPHA_SIZE: c_int = 11;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_DEPTH_SIZE: c_int = 12;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_STENCIL_SIZE: c_int = 13;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_CONFIG_CAVEAT: c_int = 0x20;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_X_VISUAL_TYPE: c_int = 0x22;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_NONE: c_int = 0x8000;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_SLOW_CONFIG: c_int = 0x8001;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_TRUE_COLOR: c_int = 0x8002;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_VISUAL_ID: c_int = 0x800B;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_NON_CONFORMANT_CONFIG: c_int = 0x800D;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_DRAWABLE_TYPE: c_int = 0x8010;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_RENDER_TYPE: c_int = 0x8011;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_X_RENDERABLE: c_int = 0x8012;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_FBCONFIG_ID: c_int = 0x8013;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_RGBA_TYPE: c_int = 0x8014;
+/// Defined in [GLX 1.4](https://www.khronos.org/registry/OpenGL/specs/gl/glx1.4.pdf)
+pub const GLX_DONT_CARE: c_int = 0xFFFFFFFF_u32 as c_int;
+/// [`GLX_DRAWABLE_TYPE`] bit
+pub const GLX_WINDOW_BIT: c_int = 0x00000001;
+/// [`GLX_DRAWABLE_TYPE`] bit
+pub const GLX_PIXMAP_BIT: c_int = 0x00000002;
+/// [`GLX_DRAWABLE_TYPE`] bit
+pub const GLX_PBUFFER_BIT: c_int = 0x00000004;
+/// [`GLX_RENDER_TYPE`] bit
+pub const GLX_RGBA_BIT: c_int = 0x00000001;
+
+/// Defined in [ARB_multisample](https://www.khronos.org/registry/OpenGL/extensions/ARB/ARB_multisample.txt)
+pub const GLX_SAMPLE_BUFFERS: c_int = 100000;
+/// Defined in [ARB_multisample](https://www.khronos.org/registry/OpenGL/extensions/ARB/ARB_multisample.txt)
+pub const GLX_SAMPLES: c_int = 100001;
+
+/// Defined in [ARB_framebuffer_sRGB](https://www.khronos.org/registry/OpenGL/extensions/ARB/ARB_framebuffer_sRGB.txt)
+pub const GLX_FRAMEBUFFER_SRGB_CAPABLE_ARB: c_int = 0x20B2;
+
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_MAJOR_VERSION_ARB: c_int = 0x2091;
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_MINOR_VERSION_ARB: c_int = 0x2092;
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_FLAGS_ARB: c_int = 0x2094;
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_PROFILE_MASK_ARB: c_int = 0x9126;
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_DEBUG_BIT_ARB: c_int = 0x0001;
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_FORWARD_COMPATIBLE_BIT_ARB: c_int = 0x0002;
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_CORE_PROFILE_BIT_ARB: c_int = 0x00000001;
+/// Defined in [GLX_ARB_create_context](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context.txt)
+pub const GLX_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB: c_int = 0x00000002;
+/// Defined in [GLX_EXT_create_context_es_profile](https://www.khronos.org/registry/OpenGL/extensions/EXT/GLX_EXT_create_context_es2_profile.txt)
+pub const GLX_CONTEXT_ES_PROFILE_BIT_EXT: c_int = 0x00000004;
+/// Defined in [GLX_ARB_create_context_robustness](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context_robustness.txt)
+pub const GLX_CONTEXT_ROBUST_ACCESS_BIT_ARB: c_int = 0x00000004;
+/// Defined in [GLX_ARB_create_context_robustness](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context_robustness.txt)
+pub const GLX_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB: c_int = 0x8256;
+/// Defined in [GLX_ARB_create_context_robustness](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context_robustness.txt)
+pub const GLX_NO_RESET_NOTIFICATION_ARB: c_int = 0x8261;
+/// Defined in [GLX_ARB_create_context_robustness](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_create_context_robustness.txt)
+pub const GLX_LOSE_CONTEXT_ON_RESET_ARB: c_int = 0x8252;
+/// Defined in [GLX_ARB_create_context_no_error](https://www.khronos.org/registry/OpenGL/extensions/ARB/ARB_create_context_no_error.txt)
+pub const GLX_CONTEXT_OPENGL_NO_ERROR_ARB: c_int = 0x31B3;
+
+/// Defined in [EXT_swap_control](https://www.khronos.org/registry/OpenGL/extensions/EXT/EXT_swap_control.txt)
+pub const GLX_SWAP_INTERVAL_EXT: c_int = 0x20F1;
+/// Defined in [EXT_swap_control](https://www.khronos.org/registry/OpenGL/extensions/EXT/EXT_swap_control.txt)
+pub const GLX_MAX_SWAP_INTERVAL_EXT: c_int = 0x20F2;
+/// Defined in [EXT_swap_control_tear](https://www.khronos.org/registry/OpenGL/extensions/EXT/GLX_EXT_swap_control_tear.txt)
+pub const GLX_LATE_SWAPS_TEAR_EXT: c_int = 0x20F3;
+
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadContext: c_int = 0;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadContextState: c_int = 1;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadDrawable: c_int = 2;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadPixmap: c_int = 3;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadContextTag: c_int = 4;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadCurrentWindow: c_int = 5;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadRenderRequest: c_int = 6;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadLargeRequest: c_int = 7;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXUnsupportedPrivateRequest: c_int = 8;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadFBConfig: c_int = 9;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadPbuffer: c_int = 10;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadCurrentDrawable: c_int = 11;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadWindow: c_int = 12;
+/// GLX error code, offset by the `error_base` from [`glx_query_extension`].
+pub const GLXBadProfileARB: c_int = 13;
+
+#[link(name = "GL")]
+extern "C" {
+  /// [`glXQueryExtension`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryExtension.xml)
+  pub fn glXQueryExtension(
+    dpy: *mut Display, errorBase: *mut c_int, eventBase: *mut c_int,
+  ) -> Bool;
+
+  /// [`glXQueryVersion`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryVersion.xml)
+  pub fn glXQueryVersion(
+    dpy: *mut Display, major: *mut c_int, minor: *mut c_int,
+  ) -> Bool;
+
+  /// [`glXQueryExtensionsString`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryExtensionsString.xml)
+  pub fn glXQueryExtensionsString(
+    dpy: *mut Display, screen: c_int,
+  ) -> *const c_char;
+
+  /// [`glXQueryServerString`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryServerString.xml)
+  pub fn glXQueryServerString(
+    dpy: *mut Display, screen: c_int, name: c_int,
+  ) -> *const c_char;
+
+  /// [`glXChooseFBConfig`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXChooseFBConfig.xml)
+  pub fn glXChooseFBConfig(
+    dpy: *mut Display, screen: c_int, attrib_list: *const c_int,
+    nelements: *mut c_int,
+  ) -> *mut GLXFBConfig;
+
+  /// [`glXGetFBConfigAttrib`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXGetFBConfigAttrib.xml)
+  pub fn glXGetFBConfigAttrib(
+    dpy: *mut Display, config: GLXFBConfig, attribute: c_int, value: *mut c_int,
+  ) -> c_int;
+
+  /// [`glXGetVisualFromFBConfig`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXGetVisualFromFBConfig.xml)
+  pub fn glXGetVisualFromFBConfig(
+    dpy: *mut Display, config: GLXFBConfig,
+  ) -> *mut XVisualInfo;
+
+  /// [`glXCreateNewContext`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXCreateNewContext.xml)
+  pub fn glXCreateNewContext(
+    dpy: *mut Display, config: GLXFBConfig, render_type: c_int,
+    share_list: GLXContext, direct: Bool,
+  ) -> GLXContext;
+
+  /// [`glXDestroyContext`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXDestroyContext.xml)
+  pub fn glXDestroyContext(dpy: *mut Display, ctx: GLXContext);
+
+  /// [`glXMakeCurrent`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXMakeCurrent.xml)
+  pub fn glXMakeCurrent(
+    dpy: *mut Display, drawable: GLXDrawable, ctx: GLXContext,
+  ) -> Bool;
+
+  /// [`glXSwapBuffers`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXSwapBuffers.xml)
+  pub fn glXSwapBuffers(dpy: *mut Display, drawable: GLXDrawable);
+
+  /// [`glXQueryDrawable`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryDrawable.xml)
+  pub fn glXQueryDrawable(
+    dpy: *mut Display, draw: GLXDrawable, attribute: c_int, value: *mut c_uint,
+  );
+
+  /// [`glXGetProcAddressARB`](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_get_proc_address.txt)
+  pub fn glXGetProcAddressARB(procName: *const u8) -> __GLXextFuncPtr;
+}
+
+/// Checks that the server supports GLX.
+///
+/// **Returns:** The `(error_base, event_base)` of the GLX extension. GLX error
+/// codes are reported as `error_base` plus one of the `GLXBad*` values.
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+///
+/// See [`glXQueryExtension`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryExtension.xml)
+pub unsafe fn glx_query_extension(
+  display: *mut Display,
+) -> Result<(c_int, c_int), X11Error> {
+  let mut error_base = 0;
+  let mut event_base = 0;
+  if glXQueryExtension(display, &mut error_base, &mut event_base) != 0 {
+    Ok((error_base, event_base))
+  } else {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  }
+}
+
+/// Gets the name of a GLX error, if the error came from GLX.
+///
+/// ```no_run
+/// # use triangle_from_scratch::{glx::*, x11::*};
+/// let display = open_display(None).unwrap();
+/// let e = X11Error(BadMatch);
+/// match unsafe { glx_error_name(display, &e) } {
+///   Some(name) => println!("GLX error: {}", name),
+///   None => println!("X11 error: {}", e),
+/// }
+/// ```
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+pub unsafe fn glx_error_name(
+  display: *mut Display, e: &X11Error,
+) -> Option<&'static str> {
+  let (error_base, _) = glx_query_extension(display).ok()?;
+  Some(match e.0 - error_base {
+    GLXBadContext => "GLXBadContext",
+    GLXBadContextState => "GLXBadContextState",
+    GLXBadDrawable => "GLXBadDrawable",
+    GLXBadPixmap => "GLXBadPixmap",
+    GLXBadContextTag => "GLXBadContextTag",
+    GLXBadCurrentWindow => "GLXBadCurrentWindow",
+    GLXBadRenderRequest => "GLXBadRenderRequest",
+    GLXBadLargeRequest => "GLXBadLargeRequest",
+    GLXUnsupportedPrivateRequest => "GLXUnsupportedPrivateRequest",
+    GLXBadFBConfig => "GLXBadFBConfig",
+    GLXBadPbuffer => "GLXBadPbuffer",
+    GLXBadCurrentDrawable => "GLXBadCurrentDrawable",
+    GLXBadWindow => "GLXBadWindow",
+    GLXBadProfileARB => "GLXBadProfileARB",
+    _ => return None,
+  })
+}
+
+/// Gets the GLX version supported by both the client and the server.
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+///
+/// See [`glXQueryVersion`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryVersion.xml)
+pub unsafe fn glx_query_version(
+  display: *mut Display,
+) -> Result<(c_int, c_int), X11Error> {
+  let mut major = 0;
+  let mut minor = 0;
+  if glXQueryVersion(display, &mut major, &mut minor) != 0 {
+    Ok((major, minor))
+  } else {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  }
+}
+
+/// Gets one of the GLX server strings ([`GLX_VENDOR`], [`GLX_VERSION`], or
+/// [`GLX_EXTENSIONS`]).
+///
+/// ## Safety
+///
+/// `display` must be an open connection that supports GLX.
+///
+/// See [`glXQueryServerString`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryServerString.xml)
+pub unsafe fn glx_query_server_string(
+  display: *mut Display, screen: c_int, name: c_int,
+) -> Result<String, X11Error> {
+  let p: *const u8 = glXQueryServerString(display, screen, name).cast();
+  if p.is_null() {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  } else {
+    let bytes = gather_null_terminated_bytes(p);
+    Ok(min_alloc_lossy_into_string(bytes))
+  }
+}
+
+/// Gets the GLX extension string for the screen given.
+///
+/// The output is a space-separated list of extensions that are supported.
+///
+/// ## Safety
+///
+/// `display` must be an open connection that supports GLX.
+///
+/// See [`glXQueryExtensionsString`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXQueryExtensionsString.xml)
+pub unsafe fn glx_query_extensions_string(
+  display: *mut Display, screen: c_int,
+) -> Result<String, X11Error> {
+  let p: *const u8 = glXQueryExtensionsString(display, screen).cast();
+  if p.is_null() {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  } else {
+    let bytes = gather_null_terminated_bytes(p);
+    Ok(min_alloc_lossy_into_string(bytes))
+  }
+}
+
+/// Gets a GL or GLX function address.
+///
+/// The input should be a null-terminated function name string. Use the
+/// [`c_str!`] macro for assistance.
+///
+/// * This doesn't need a current context, and the pointers are valid for any
+///   context on any display.
+/// * Mesa will return a non-null pointer even for names it has never heard
+///   of, so check that the extension (or GL version) is actually supported
+///   before calling the function you get.
+///
+/// See [`glXGetProcAddressARB`](https://www.khronos.org/registry/OpenGL/extensions/ARB/GLX_ARB_get_proc_address.txt)
+pub fn glx_get_proc_address(func_name: &[u8]) -> Result<*mut c_void, X11Error> {
+  // check that we end the slice with a \0 as expected.
+  match func_name.last() {
+    Some(b'\0') => (),
+    _ => return Err(X11Error(X11Error::APPLICATION_ERROR_BIT)),
+  }
+  // Safety: we've checked that the end of the slice is null-terminated.
+  match unsafe { glXGetProcAddressARB(func_name.as_ptr()) } {
+    Some(f) => Ok(f as *mut c_void),
+    None => Err(X11Error(X11Error::APPLICATION_ERROR_BIT)),
+  }
+}
+
+#[test]
+fn test_glx_get_proc_address() {
+  assert!(glx_get_proc_address(c_str!("glXCreateContextAttribsARB")).is_ok());
+  assert!(glx_get_proc_address(b"glXCreateContextAttribsARB").is_err());
+}
+
+/// Grabs out the stuff you'll need to have fun with GLX.
+///
+/// * Requires GLX 1.3 or later, since that's when `GLXFBConfig` was added.
+/// * Unlike with WGL, there's no need for a fake window or context.
+///
+/// ## Safety
+///
+/// `display` must be an open connection that supports GLX.
+pub unsafe fn get_glx_basics(
+  display: *mut Display, screen: c_int,
+) -> Result<
+  (Vec<String>, glXCreateContextAttribsARB_t, glXSwapIntervalEXT_t),
+  X11Error,
+> {
+  let app_err = X11Error(X11Error::APPLICATION_ERROR_BIT);
+  match glx_query_version(display)? {
+    (major, minor) if major > 1 || (major == 1 && minor >= 3) => (),
+    _ => return Err(app_err),
+  }
+
+  let glx_extensions: Vec<String> =
+    glx_query_extensions_string(display, screen)
+      .map(|s| {
+        s.split(' ').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect()
+      })
+      .unwrap_or_default();
+
+  let create_context_attribs: glXCreateContextAttribsARB_t =
+    if glx_extensions.iter().any(|s| s == "GLX_ARB_create_context") {
+      core::mem::transmute::<*mut c_void, glXCreateContextAttribsARB_t>(
+        glx_get_proc_address(c_str!("glXCreateContextAttribsARB"))?,
+      )
+    } else {
+      None
+    };
+  let swap_interval: glXSwapIntervalEXT_t =
+    if glx_extensions.iter().any(|s| s == "GLX_EXT_swap_control") {
+      core::mem::transmute::<*mut c_void, glXSwapIntervalEXT_t>(
+        glx_get_proc_address(c_str!("glXSwapIntervalEXT"))?,
+      )
+    } else {
+      None
+    };
+
+  Ok((glx_extensions, create_context_attribs, swap_interval))
+}
+
+/// Gets all framebuffer configs that match the attributes given.
+///
+/// * The input slice consists of [key, value] pairs.
+/// * The input slice **can** be empty.
+/// * Any non-empty input must have zero as the key value of the last position.
+/// * The output is sorted by the GLX rules, so usually you want the first one.
+///
+/// ## Safety
+///
+/// `display` must be an open connection that supports GLX.
+///
+/// See [`glXChooseFBConfig`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXChooseFBConfig.xml)
+pub unsafe fn glx_choose_fb_config(
+  display: *mut Display, screen: c_int, attrib_list: &[[c_int; 2]],
+) -> Result<Vec<GLXFBConfig>, X11Error> {
+  let app_err = X11Error(X11Error::APPLICATION_ERROR_BIT);
+  let i_ptr = match attrib_list.last() {
+    Some([k, _v]) => {
+      if *k == 0 {
+        attrib_list.as_ptr()
+      } else {
+        return Err(app_err);
+      }
+    }
+    None => null(),
+  };
+  let mut count = 0;
+  let configs = glXChooseFBConfig(display, screen, i_ptr.cast(), &mut count);
+  if configs.is_null() {
+    sync_for_errors(display)?;
+    Err(app_err)
+  } else {
+    let out = core::slice::from_raw_parts(configs, count as usize).to_vec();
+    XFree(configs.cast());
+    Ok(out)
+  }
+}
+
+/// Gets one attribute value of a framebuffer config.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `config` one of its configs.
+///
+/// See [`glXGetFBConfigAttrib`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXGetFBConfigAttrib.xml)
+pub unsafe fn glx_get_fb_config_attrib(
+  display: *mut Display, config: GLXFBConfig, attribute: c_int,
+) -> Result<c_int, X11Error> {
+  let mut value = 0;
+  if glXGetFBConfigAttrib(display, config, attribute, &mut value) == 0 {
+    Ok(value)
+  } else {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  }
+}
+
+/// Describes a framebuffer config, to rank it with a
+/// [`FormatRequest`](crate::format::FormatRequest).
+///
+/// The sRGB and multisample attributes count as absent if the driver doesn't
+/// know them.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `config` one of its configs.
+pub unsafe fn glx_describe_fb_config(
+  display: *mut Display, config: GLXFBConfig,
+) -> Result<FormatDesc, X11Error> {
+  let get = |attribute| glx_get_fb_config_attrib(display, config, attribute);
+  let bits = |attribute| get(attribute).map(|v| v.clamp(0, 255) as u8);
+  let sample_buffers = get(GLX_SAMPLE_BUFFERS).unwrap_or(0);
+  Ok(FormatDesc {
+    id: get(GLX_FBCONFIG_ID)?,
+    red_bits: bits(GLX_RED_SIZE)?,
+    green_bits: bits(GLX_GREEN_SIZE)?,
+    blue_bits: bits(GLX_BLUE_SIZE)?,
+    alpha_bits: bits(GLX_ALPHA_SIZE)?,
+    depth_bits: bits(GLX_DEPTH_SIZE)?,
+    stencil_bits: bits(GLX_STENCIL_SIZE)?,
+    samples: if sample_buffers > 0 {
+      bits(GLX_SAMPLES).unwrap_or(0)
+    } else {
+      0
+    },
+    srgb: get(GLX_FRAMEBUFFER_SRGB_CAPABLE_ARB).unwrap_or(0) != 0,
+    double_buffer: get(GLX_DOUBLEBUFFER)? != 0,
+    accelerated: get(GLX_CONFIG_CAVEAT)? != GLX_SLOW_CONFIG,
+  })
+}
+
+/// Gets the visual that a window needs to use to be drawn with the config.
+///
+/// Pass the output to [`create_app_window`].
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `config` one of its configs.
+///
+/// See [`glXGetVisualFromFBConfig`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXGetVisualFromFBConfig.xml)
+pub unsafe fn glx_get_visual_from_fb_config(
+  display: *mut Display, config: GLXFBConfig,
+) -> Result<XVisualInfo, X11Error> {
+  let p = glXGetVisualFromFBConfig(display, config);
+  if p.is_null() {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  } else {
+    let out = *p;
+    XFree(p.cast());
+    Ok(out)
+  }
+}
+
+/// Arranges the data for calling a [`glXCreateContextAttribsARB_t`] procedure.
+///
+/// * The input slice consists of [key, value] pairs.
+/// * The input slice **can** be empty.
+/// * Any non-empty input must have zero as the key value of the last position.
+/// * If the driver doesn't support the version or profile requested, this is
+///   a `GLXBadFBConfig` or `BadMatch` error. See [`glx_error_name`].
+///
+/// ## Safety
+///
+/// * `f` must be the `glXCreateContextAttribsARB` of the display's GLX.
+/// * `display` must be an open connection, and `config` one of its configs.
+/// * `share_context` must be null or a context of the display.
+pub unsafe fn do_glXCreateContextAttribsARB(
+  f: glXCreateContextAttribsARB_t, display: *mut Display, config: GLXFBConfig,
+  share_context: GLXContext, direct: bool, attrib_list: &[[c_int; 2]],
+) -> Result<GLXContext, X11Error> {
+  let app_err = X11Error(X11Error::APPLICATION_ERROR_BIT);
+  let i_ptr = match attrib_list.last() {
+    Some([k, _v]) => {
+      if *k == 0 {
+        attrib_list.as_ptr()
+      } else {
+        return Err(app_err);
+      }
+    }
+    None => null(),
+  };
+  set_last_error(display, X11Error(0));
+  let ctx = (f.ok_or(app_err)?)(
+    display,
+    config,
+    share_context,
+    direct as Bool,
+    i_ptr.cast(),
+  );
+  sync_for_errors(display)?;
+  if ctx.is_null() {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  } else {
+    Ok(ctx)
+  }
+}
+
+/// The GLX extensions for the parts of a [`ContextRequest`].
+pub const GLX_CONTEXT_EXTENSIONS: ExtensionNames = ExtensionNames {
+  create_context: "",
+  profile: "GLX_ARB_create_context_profile",
+  es_profile: "GLX_EXT_create_context_es_profile",
+  robustness: "GLX_ARB_create_context_robustness",
+  no_error: "GLX_ARB_create_context_no_error",
+};
+
+/// The `glXCreateContextAttribsARB` enums, for [`ArbContextEnums::attribs`].
+///
+/// Check [`GLX_CONTEXT_EXTENSIONS`] first, since drivers without them reject
+/// the attributes.
+pub const GLX_CONTEXT_ENUMS: ArbContextEnums = ArbContextEnums {
+  major_version: GLX_CONTEXT_MAJOR_VERSION_ARB,
+  minor_version: GLX_CONTEXT_MINOR_VERSION_ARB,
+  flags: GLX_CONTEXT_FLAGS_ARB,
+  debug_bit: GLX_CONTEXT_DEBUG_BIT_ARB,
+  forward_compatible_bit: GLX_CONTEXT_FORWARD_COMPATIBLE_BIT_ARB,
+  robust_access_bit: GLX_CONTEXT_ROBUST_ACCESS_BIT_ARB,
+  profile_mask: GLX_CONTEXT_PROFILE_MASK_ARB,
+  core_profile_bit: GLX_CONTEXT_CORE_PROFILE_BIT_ARB,
+  compatibility_profile_bit: GLX_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB,
+  es_profile_bit: GLX_CONTEXT_ES_PROFILE_BIT_EXT,
+  reset_notification_strategy: GLX_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB,
+  no_reset_notification: GLX_NO_RESET_NOTIFICATION_ARB,
+  lose_context_on_reset: GLX_LOSE_CONTEXT_ON_RESET_ARB,
+  no_error: GLX_CONTEXT_OPENGL_NO_ERROR_ARB,
+};
+
+/// Makes a direct context with the first version of the request that works.
+///
+/// * Versions that need a GLX extension that isn't in `glx_extensions` aren't
+///   tried, and say which one they needed.
+/// * The context isn't made current.
+///
+/// ## Safety
+///
+/// * `f` must be the `glXCreateContextAttribsARB` of the display's GLX.
+/// * `display` must be an open connection, and `config` one of its configs.
+/// * The share context of the request must be null or a context of the display.
+pub unsafe fn create_glx_context(
+  f: glXCreateContextAttribsARB_t, display: *mut Display, config: GLXFBConfig,
+  glx_extensions: &[String], request: &ContextRequest<GLXContext>,
+) -> Result<CreatedContext<GLXContext, X11Error>, ContextError<X11Error>> {
+  let share = request.share.unwrap_or(core::ptr::null_mut());
+  request.try_each(|version| {
+    if let Some(name) =
+      GLX_CONTEXT_EXTENSIONS.missing(request, version, glx_extensions)
+    {
+      return Err(AttemptError::MissingExtension(name));
+    }
+    let attribs = GLX_CONTEXT_ENUMS.attribs(request, version);
+    do_glXCreateContextAttribsARB(f, display, config, share, true, &attribs)
+      .map_err(AttemptError::Failed)
+  })
+}
+
+/// Sets the swap interval of a drawable.
+///
+/// * 0 means no vsync, 1 means vsync, and higher values skip frames.
+/// * Negative values are "adaptive" vsync, which needs
+///   `GLX_EXT_swap_control_tear`.
+///
+/// ## Safety
+///
+/// * `f` must be the `glXSwapIntervalEXT` of the display's GLX.
+/// * `display` must be an open connection, and `drawable` one of its drawables.
+///
+/// See [EXT_swap_control](https://www.khronos.org/registry/OpenGL/extensions/EXT/EXT_swap_control.txt)
+pub unsafe fn do_glXSwapIntervalEXT(
+  f: glXSwapIntervalEXT_t, display: *mut Display, drawable: GLXDrawable,
+  interval: c_int,
+) -> Result<(), X11Error> {
+  let app_err = X11Error(X11Error::APPLICATION_ERROR_BIT);
+  (f.ok_or(app_err)?)(display, drawable, interval);
+  sync_for_errors(display)
+}
+
+/// Makes a given context current in the thread and targets it at the drawable
+/// given.
+///
+/// * You can pass `0` and `null_mut` to make no context current in the thread.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `drawable` and `ctx` must be its
+/// handles (or `0` and `null_mut`).
+///
+/// See [`glXMakeCurrent`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXMakeCurrent.xml)
+pub unsafe fn glx_make_current(
+  display: *mut Display, drawable: GLXDrawable, ctx: GLXContext,
+) -> Result<(), X11Error> {
+  let success = glXMakeCurrent(display, drawable, ctx);
+  sync_for_errors(display)?;
+  if success != 0 {
+    Ok(())
+  } else {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  }
+}
+
+/// Destroys a GLX context.
+///
+/// If the context is current in any thread it isn't actually destroyed until
+/// it stops being current.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `ctx` a context of it that isn't
+/// used afterwards.
+///
+/// See [`glXDestroyContext`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXDestroyContext.xml)
+pub unsafe fn glx_destroy_context(
+  display: *mut Display, ctx: GLXContext,
+) -> Result<(), X11Error> {
+  glXDestroyContext(display, ctx);
+  sync_for_errors(display)
+}
+
+/// Shows the back buffer of a double buffered drawable.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `drawable` one of its drawables.
+///
+/// See [`glXSwapBuffers`](https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glXSwapBuffers.xml)
+pub unsafe fn glx_swap_buffers(display: *mut Display, drawable: GLXDrawable) {
+  glXSwapBuffers(display, drawable)
+}
+
+#[test]
+fn test_glx_context_attribs() {
+  // the ARB extensions use the same values for WGL and GLX.
+  assert_eq!(GLX_CONTEXT_ENUMS, crate::win32::WGL_CONTEXT_ENUMS);
+  let request: ContextRequest<GLXContext> =
+    ContextRequest::default().forward_compatible(true).no_error(true);
+  let attribs =
+    GLX_CONTEXT_ENUMS.attribs(&request, &ContextVersion::gles(3, 0));
+  assert_eq!(
+    attribs,
+    [
+      [GLX_CONTEXT_MAJOR_VERSION_ARB, 3],
+      [GLX_CONTEXT_MINOR_VERSION_ARB, 0],
+      [GLX_CONTEXT_PROFILE_MASK_ARB, GLX_CONTEXT_ES_PROFILE_BIT_EXT],
+      [GLX_CONTEXT_OPENGL_NO_ERROR_ARB, True],
+      [0, 0],
+    ]
+  );
+  assert_eq!(
+    GLX_CONTEXT_EXTENSIONS.missing(&request, &ContextVersion::gles(3, 0), &[]),
+    Some("GLX_EXT_create_context_es_profile")
+  );
+}
+
+#[test]
+fn test_glx_core_context() {
+  if !crate::test_util::has_x_display() {
+    return;
+  }
+  let display = open_display(None).unwrap();
+  unsafe {
+    let screen = XDefaultScreen(display);
+    let (_extensions, create_context_attribs, _swap_interval) =
+      get_glx_basics(display, screen).unwrap();
+    let configs = glx_choose_fb_config(
+      display,
+      screen,
+      &[
+        [GLX_X_RENDERABLE, True],
+        [GLX_DRAWABLE_TYPE, GLX_WINDOW_BIT],
+        [GLX_RENDER_TYPE, GLX_RGBA_BIT],
+        [GLX_DOUBLEBUFFER, True],
+        [0, 0],
+      ],
+    )
+    .unwrap();
+    let visual_info =
+      glx_get_visual_from_fb_config(display, configs[0]).unwrap();
+    let window = create_app_window(
+      display,
+      "GLX Test",
+      None,
+      [64, 64],
+      Some(&visual_info),
+    )
+    .unwrap();
+    let ctx = do_glXCreateContextAttribsARB(
+      create_context_attribs,
+      display,
+      configs[0],
+      core::ptr::null_mut(),
+      true,
+      &[
+        [GLX_CONTEXT_MAJOR_VERSION_ARB, 3],
+        [GLX_CONTEXT_MINOR_VERSION_ARB, 3],
+        [GLX_CONTEXT_PROFILE_MASK_ARB, GLX_CONTEXT_CORE_PROFILE_BIT_ARB],
+        [0, 0],
+      ],
+    )
+    .unwrap();
+    glx_make_current(display, window, ctx).unwrap();
+    glx_swap_buffers(display, window);
+    glx_make_current(display, 0, core::ptr::null_mut()).unwrap();
+    glx_destroy_context(display, ctx).unwrap();
+    destroy_window(display, window).unwrap();
+    close_display(display);
+  }
+}
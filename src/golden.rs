@@ -0,0 +1,605 @@
+//! Golden-image tests: render a scene, then compare it to a checked-in PNG.
+//!
+//! A scene is drawn with [`render`] on one of the [`Backend`]s: the
+//! [software rasterizer](crate::soft), or GL through a headless EGL context
+//! or a GLX context (which needs an X server, such as `xvfb-run`). GL scenes
+//! are drawn into a framebuffer object and read back, so no window is shown.
+//!
+//! [`Golden::check`] compares the image with `tests/golden/<name>.png`,
+//! allowing each channel to be off by [`Tolerance::per_channel`] and allowing
+//! up to [`Tolerance::max_pixels`] pixels to be off by more than that. When
+//! the check fails, the actual and expected images and a diff image are
+//! written to `target/golden/`, and the error says where they are.
+//!
+//! To make new references (or replace failing ones), run the tests with the
+//! [`BLESS_VAR`] environment variable set, eg: `BLESS_GOLDEN=1 cargo test`.
+
+use crate::{gl::*, image::*, soft::Framebuffer};
+use std::path::{Path, PathBuf};
+
+#[cfg(target_os = "linux")]
+use crate::{egl::*, glx::*, guard::*, x11::*};
+
+/// When this environment variable is set (and isn't `0`), [`Golden::check`]
+/// writes the actual image as the reference instead of failing.
+pub const BLESS_VAR: &str = "BLESS_GOLDEN";
+
+/// How different the actual image can be from the reference.
+///
+/// The default is an exact match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tolerance {
+  /// How far each channel of a pixel can be off before the pixel counts as
+  /// differing.
+  pub per_channel: u8,
+  /// How many pixels can differ.
+  pub max_pixels: usize,
+}
+
+/// The result of comparing two images of the same size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+  /// How many pixels are off by more than the tolerance in any channel.
+  pub differing_pixels: usize,
+  /// The biggest difference of any channel of any pixel.
+  pub max_channel_diff: u8,
+  /// Differing pixels are red, pixels that are off but within the tolerance
+  /// are yellow, and matching pixels are a dim gray version of the expected
+  /// image.
+  pub diff: Image,
+}
+impl Comparison {
+  /// If the number of differing pixels is within the tolerance.
+  pub fn passes(&self, tolerance: Tolerance) -> bool {
+    self.differing_pixels <= tolerance.max_pixels
+  }
+}
+
+/// Compares two images, pixel by pixel, as RGBA.
+///
+/// Gives `None` if the images aren't the same size. The row order and pixel
+/// format of the images don't need to match, and RGB pixels count as having
+/// an alpha of 255.
+pub fn compare_images(
+  actual: ImageView, expected: ImageView, tolerance: Tolerance,
+) -> Option<Comparison> {
+  if (actual.width, actual.height) != (expected.width, expected.height) {
+    return None;
+  }
+  let mut differing_pixels = 0;
+  let mut max_channel_diff = 0;
+  let mut pixels =
+    Vec::with_capacity(actual.width as usize * actual.height as usize * 4);
+  for y in 0..actual.height {
+    for x in 0..actual.width {
+      let a = actual.rgba(x, y);
+      let e = expected.rgba(x, y);
+      let diff = a.iter().zip(e.iter()).map(|(a, e)| a.abs_diff(*e)).max();
+      let diff = diff.unwrap_or(0);
+      max_channel_diff = max_channel_diff.max(diff);
+      if diff > tolerance.per_channel {
+        differing_pixels += 1;
+        pixels.extend_from_slice(&[255, 0, 0, 255]);
+      } else if diff > 0 {
+        pixels.extend_from_slice(&[255, 255, 0, 255]);
+      } else {
+        let luma = (e[0] as u32 * 3 + e[1] as u32 * 6 + e[2] as u32) / 10;
+        let gray = (luma / 3) as u8;
+        pixels.extend_from_slice(&[gray, gray, gray, 255]);
+      }
+    }
+  }
+  let diff = Image {
+    width: actual.width,
+    height: actual.height,
+    format: PixelFormat::Rgba8,
+    rows: RowOrder::TopDown,
+    pixels,
+  };
+  Some(Comparison { differing_pixels, max_channel_diff, diff })
+}
+
+/// The ways that a golden-image check can fail.
+#[derive(Debug)]
+pub enum GoldenError {
+  /// There's no reference image yet. The actual image was written out.
+  MissingReference { reference: PathBuf, actual: PathBuf },
+  /// The reference image couldn't be decoded.
+  BadReference { reference: PathBuf, error: PngError },
+  /// The images aren't the same size. Both images were written out.
+  SizeMismatch {
+    expected_size: [u32; 2],
+    actual_size: [u32; 2],
+    output_dir: PathBuf,
+  },
+  /// Too many pixels differ. Both images and the diff were written out.
+  TooDifferent {
+    differing_pixels: usize,
+    max_channel_diff: u8,
+    tolerance: Tolerance,
+    output_dir: PathBuf,
+  },
+  /// Reading or writing a file failed.
+  Io { path: PathBuf, error: std::io::Error },
+}
+impl std::error::Error for GoldenError {}
+impl core::fmt::Display for GoldenError {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Self::MissingReference { reference, actual } => write!(
+        f,
+        "there's no reference image at {} (the actual image is at {})",
+        reference.display(),
+        actual.display()
+      )?,
+      Self::BadReference { reference, error } => write!(
+        f,
+        "the reference image at {} couldn't be decoded: {}",
+        reference.display(),
+        error
+      )?,
+      Self::SizeMismatch { expected_size, actual_size, output_dir } => write!(
+        f,
+        "expected a {}x{} image, but it's {}x{} (see {})",
+        expected_size[0],
+        expected_size[1],
+        actual_size[0],
+        actual_size[1],
+        output_dir.display()
+      )?,
+      Self::TooDifferent {
+        differing_pixels,
+        max_channel_diff,
+        tolerance,
+        output_dir,
+      } => write!(
+        f,
+        "{} pixels are off by more than {} (up to {}), but only {} can be \
+         (see {})",
+        differing_pixels,
+        tolerance.per_channel,
+        max_channel_diff,
+        tolerance.max_pixels,
+        output_dir.display()
+      )?,
+      Self::Io { path, error } => {
+        return write!(f, "{}: {}", path.display(), error)
+      }
+    }
+    write!(f, ", set {}=1 to bless the actual image", BLESS_VAR)
+  }
+}
+
+/// Compares images to the references in a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Golden {
+  /// Where the `<name>.png` references are.
+  pub reference_dir: PathBuf,
+  /// Where the images of a failed check are written.
+  pub output_dir: PathBuf,
+  pub tolerance: Tolerance,
+  /// Write the actual image as the reference instead of failing.
+  pub bless: bool,
+}
+impl Default for Golden {
+  /// References in `tests/golden/` of this crate, output in
+  /// `target/golden/`, an exact match, and blessing when [`BLESS_VAR`] is set.
+  fn default() -> Self {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = std::env::var_os("CARGO_TARGET_DIR")
+      .map(PathBuf::from)
+      .unwrap_or_else(|| manifest_dir.join("target"));
+    let bless =
+      std::env::var_os(BLESS_VAR).is_some_and(|v| !v.is_empty() && v != "0");
+    Self {
+      reference_dir: manifest_dir.join("tests").join("golden"),
+      output_dir: target_dir.join("golden"),
+      tolerance: Tolerance::default(),
+      bless,
+    }
+  }
+}
+impl Golden {
+  /// Sets the tolerance.
+  pub fn tolerance(self, per_channel: u8, max_pixels: usize) -> Self {
+    Self { tolerance: Tolerance { per_channel, max_pixels }, ..self }
+  }
+
+  /// Checks an image against the reference called `name`.
+  ///
+  /// When blessing, a missing, broken, or failing reference is replaced with
+  /// the actual image, and a passing reference is left alone.
+  ///
+  /// Otherwise, if the check fails then `<name>.actual.png`,
+  /// `<name>.expected.png`, and `<name>.diff.png` are written to the output
+  /// directory (as many of them as make sense for the failure).
+  pub fn check(
+    &self, name: &str, actual: ImageView,
+  ) -> Result<(), GoldenError> {
+    let reference = self.reference_dir.join(format!("{}.png", name));
+    match self.compare(name, &reference, actual) {
+      Err(e @ GoldenError::Io { .. }) => Err(e),
+      Err(_) if self.bless => {
+        write_png(&reference, actual)?;
+        eprintln!("> Blessed {}", reference.display());
+        Ok(())
+      }
+      result => result,
+    }
+  }
+
+  fn compare(
+    &self, name: &str, reference: &Path, actual: ImageView,
+  ) -> Result<(), GoldenError> {
+    let output =
+      |kind: &str| self.output_dir.join(format!("{}.{}.png", name, kind));
+    let bytes = match std::fs::read(reference) {
+      Ok(bytes) => bytes,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+        write_png(&output("actual"), actual)?;
+        return Err(GoldenError::MissingReference {
+          reference: reference.to_path_buf(),
+          actual: output("actual"),
+        });
+      }
+      Err(error) => {
+        return Err(GoldenError::Io { path: reference.to_path_buf(), error })
+      }
+    };
+    let expected = decode_png(&bytes).map_err(|error| {
+      GoldenError::BadReference { reference: reference.to_path_buf(), error }
+    })?;
+    let expected = expected.image();
+    let comparison = match compare_images(actual, expected, self.tolerance) {
+      Some(comparison) if comparison.passes(self.tolerance) => return Ok(()),
+      Some(comparison) => comparison,
+      None => {
+        write_png(&output("actual"), actual)?;
+        write_png(&output("expected"), expected)?;
+        return Err(GoldenError::SizeMismatch {
+          expected_size: [expected.width, expected.height],
+          actual_size: [actual.width, actual.height],
+          output_dir: self.output_dir.clone(),
+        });
+      }
+    };
+    write_png(&output("actual"), actual)?;
+    write_png(&output("expected"), expected)?;
+    write_png(&output("diff"), comparison.diff.view())?;
+    Err(GoldenError::TooDifferent {
+      differing_pixels: comparison.differing_pixels,
+      max_channel_diff: comparison.max_channel_diff,
+      tolerance: self.tolerance,
+      output_dir: self.output_dir.clone(),
+    })
+  }
+}
+
+/// Writes a PNG, making the directory first if needed.
+fn write_png(path: &Path, image: ImageView) -> Result<(), GoldenError> {
+  let io = |error| GoldenError::Io { path: path.to_path_buf(), error };
+  if let Some(dir) = path.parent() {
+    std::fs::create_dir_all(dir).map_err(io)?;
+  }
+  std::fs::write(path, encode_png(image)).map_err(io)
+}
+
+/// What draws a scene for [`render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+  /// The [software rasterizer](crate::soft), which always works.
+  Soft,
+  /// GL 3.3 core through a headless EGL display (Linux only).
+  Egl,
+  /// GL 3.3 core through GLX, which needs `DISPLAY` set (Linux only).
+  Glx,
+}
+impl Backend {
+  pub const ALL: [Self; 3] = [Self::Soft, Self::Egl, Self::Glx];
+}
+
+/// What a scene draws into.
+pub enum Target<'a> {
+  /// A framebuffer with a depth buffer, cleared to transparent black.
+  Soft(&'a mut Framebuffer),
+  /// A current GL context, with a framebuffer object (that has a depth and
+  /// stencil buffer) bound and the viewport set. The scene shouldn't bind
+  /// any other framebuffer.
+  Gl(&'a Gl33),
+}
+
+/// The ways that [`render`] can fail.
+#[derive(Debug)]
+pub enum RenderError {
+  /// The backend can't be used here, for the reason given. Tests should
+  /// usually skip the backend rather than fail.
+  Unavailable(&'static str),
+  #[cfg(target_os = "linux")]
+  Egl(EglError),
+  #[cfg(target_os = "linux")]
+  X11(X11Error),
+  /// `glCheckFramebufferStatus` gave this instead of
+  /// `GL_FRAMEBUFFER_COMPLETE`.
+  IncompleteFramebuffer(GLenum),
+}
+impl std::error::Error for RenderError {}
+impl core::fmt::Display for RenderError {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Self::Unavailable(why) => {
+        write!(f, "the backend is unavailable: {}", why)
+      }
+      #[cfg(target_os = "linux")]
+      Self::Egl(e) => write!(f, "EGL error: {}", e),
+      #[cfg(target_os = "linux")]
+      Self::X11(e) => write!(f, "X11 error: {}", e),
+      Self::IncompleteFramebuffer(status) => match gl_enum_name(*status) {
+        Some(name) => write!(f, "the framebuffer is incomplete: {}", name),
+        None => write!(f, "the framebuffer is incomplete: 0x{:X}", status),
+      },
+    }
+  }
+}
+#[cfg(target_os = "linux")]
+impl From<EglError> for RenderError {
+  fn from(e: EglError) -> Self {
+    Self::Egl(e)
+  }
+}
+#[cfg(target_os = "linux")]
+impl From<X11Error> for RenderError {
+  fn from(e: X11Error) -> Self {
+    Self::X11(e)
+  }
+}
+
+/// Draws a scene with a backend, and gives the image.
+///
+/// The image is RGBA8 with the rows going bottom to top. GL backends make a
+/// fresh context for each call, so the scene has to make all of its own GL
+/// objects (and it can skip deleting them).
+pub fn render(
+  backend: Backend, width: u32, height: u32, mut scene: impl FnMut(Target<'_>),
+) -> Result<Image, RenderError> {
+  match backend {
+    Backend::Soft => {
+      let mut fb = Framebuffer::with_depth(width, height);
+      scene(Target::Soft(&mut fb));
+      Ok(fb.image().to_image())
+    }
+    #[cfg(target_os = "linux")]
+    Backend::Egl => unsafe { render_egl(width, height, &mut scene) },
+    #[cfg(target_os = "linux")]
+    Backend::Glx => unsafe { render_glx(width, height, &mut scene) },
+    #[cfg(not(target_os = "linux"))]
+    Backend::Egl | Backend::Glx => {
+      Err(RenderError::Unavailable("EGL and GLX are only used on Linux"))
+    }
+  }
+}
+
+#[cfg(target_os = "linux")]
+unsafe fn render_egl(
+  width: u32, height: u32, scene: &mut dyn FnMut(Target<'_>),
+) -> Result<Image, RenderError> {
+  if !has_headless_platform() {
+    return Err(RenderError::Unavailable("there's no headless EGL platform"));
+  }
+  // the surface isn't drawn to, so it can be tiny.
+  let (display, surface, ctx) = create_headless_context((3, 3), [1, 1])?;
+  let display = OwnedEglDisplay::from_raw(display);
+  let _surface = OwnedEglSurface::from_raw(display.as_raw(), surface);
+  let _ctx = OwnedEglContext::from_raw(display.as_raw(), ctx);
+  // dropped first, so that nothing is current when the rest is destroyed.
+  let _release = ScopeGuard::new(display.as_raw(), |display| {
+    let _ = egl_make_current(display, EGL_NO_SURFACE, EGL_NO_CONTEXT);
+  });
+  let (gl, _missing) = Gl33::load(|name| {
+    egl_get_proc_address(name).unwrap_or(core::ptr::null_mut()).cast_const()
+  });
+  render_gl(&gl, width, height, scene)
+}
+
+#[cfg(target_os = "linux")]
+unsafe fn render_glx(
+  width: u32, height: u32, scene: &mut dyn FnMut(Target<'_>),
+) -> Result<Image, RenderError> {
+  if std::env::var_os("DISPLAY").is_none() {
+    return Err(RenderError::Unavailable("DISPLAY isn't set"));
+  }
+  // dropped in reverse order, so the display is closed last.
+  let display = OwnedDisplay::from_raw(open_display(None)?);
+  let screen = XDefaultScreen(display.as_raw());
+  let (_extensions, create_context_attribs, _swap_interval) =
+    get_glx_basics(display.as_raw(), screen)?;
+  let configs = glx_choose_fb_config(
+    display.as_raw(),
+    screen,
+    &[
+      [GLX_X_RENDERABLE, True],
+      [GLX_DRAWABLE_TYPE, GLX_WINDOW_BIT],
+      [GLX_RENDER_TYPE, GLX_RGBA_BIT],
+      [0, 0],
+    ],
+  )?;
+  let config =
+    *configs.first().ok_or(X11Error(X11Error::APPLICATION_ERROR_BIT))?;
+  let visual_info = glx_get_visual_from_fb_config(display.as_raw(), config)?;
+  // the window is never mapped, it's only there to make the context current.
+  let window = OwnedXWindow::from_raw(
+    display.as_raw(),
+    create_app_window(
+      display.as_raw(),
+      "Golden Image",
+      None,
+      [1, 1],
+      Some(&visual_info),
+    )?,
+  );
+  let ctx = OwnedGlxContext::from_raw(
+    display.as_raw(),
+    do_glXCreateContextAttribsARB(
+      create_context_attribs,
+      display.as_raw(),
+      config,
+      core::ptr::null_mut(),
+      true,
+      &[
+        [GLX_CONTEXT_MAJOR_VERSION_ARB, 3],
+        [GLX_CONTEXT_MINOR_VERSION_ARB, 3],
+        [GLX_CONTEXT_PROFILE_MASK_ARB, GLX_CONTEXT_CORE_PROFILE_BIT_ARB],
+        [0, 0],
+      ],
+    )?,
+  );
+  let out = glx_make_current(display.as_raw(), window.as_raw(), ctx.as_raw())
+    .map_err(RenderError::from)
+    .and_then(|_| {
+      let (gl, _missing) = Gl33::load(|name| {
+        glx_get_proc_address(name).unwrap_or(core::ptr::null_mut()).cast_const()
+      });
+      render_gl(&gl, width, height, scene)
+    });
+  let _ = glx_make_current(display.as_raw(), 0, core::ptr::null_mut());
+  out
+}
+
+/// Draws into a framebuffer object with the current context, and reads it.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+unsafe fn render_gl(
+  gl: &Gl33, width: u32, height: u32, scene: &mut dyn FnMut(Target<'_>),
+) -> Result<Image, RenderError> {
+  let (w, h) = (width as GLsizei, height as GLsizei);
+  let mut fbo = 0;
+  let mut renderbuffers = [0; 2];
+  gl.GenFramebuffers(1, &mut fbo);
+  gl.BindFramebuffer(GL_FRAMEBUFFER, fbo);
+  gl.GenRenderbuffers(2, renderbuffers.as_mut_ptr());
+  gl.BindRenderbuffer(GL_RENDERBUFFER, renderbuffers[0]);
+  gl.RenderbufferStorage(GL_RENDERBUFFER, GL_RGBA8, w, h);
+  gl.FramebufferRenderbuffer(
+    GL_FRAMEBUFFER,
+    GL_COLOR_ATTACHMENT0,
+    GL_RENDERBUFFER,
+    renderbuffers[0],
+  );
+  gl.BindRenderbuffer(GL_RENDERBUFFER, renderbuffers[1]);
+  gl.RenderbufferStorage(GL_RENDERBUFFER, GL_DEPTH24_STENCIL8, w, h);
+  gl.FramebufferRenderbuffer(
+    GL_FRAMEBUFFER,
+    GL_DEPTH_STENCIL_ATTACHMENT,
+    GL_RENDERBUFFER,
+    renderbuffers[1],
+  );
+  let status = gl.CheckFramebufferStatus(GL_FRAMEBUFFER);
+  let out = if status == GL_FRAMEBUFFER_COMPLETE {
+    gl.Viewport(0, 0, w, h);
+    // match the soft framebuffer, which starts out cleared.
+    gl.ClearColor(0.0, 0.0, 0.0, 0.0);
+    gl.ClearDepth(1.0);
+    gl.Clear(GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT | GL_STENCIL_BUFFER_BIT);
+    scene(Target::Gl(gl));
+    gl.BindFramebuffer(GL_READ_FRAMEBUFFER, fbo);
+    gl.ReadBuffer(GL_COLOR_ATTACHMENT0);
+    Ok(gl.read_rgba8(0, 0, width, height, RowOrder::BottomUp))
+  } else {
+    Err(RenderError::IncompleteFramebuffer(status))
+  };
+  gl.BindFramebuffer(GL_FRAMEBUFFER, 0);
+  gl.DeleteRenderbuffers(2, renderbuffers.as_ptr());
+  gl.DeleteFramebuffers(1, &fbo);
+  out
+}
+
+#[test]
+fn test_compare_images() {
+  let rgb = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100, 110, 120];
+  let a =
+    ImageView::new(2, 2, PixelFormat::Rgb8, RowOrder::TopDown, &rgb).unwrap();
+  // the same pixels, but RGBA and bottom to top, with one pixel off by 2
+  // and one off by 10.
+  let rgba =
+    [70, 80, 90, 255, 100, 110, 122, 255, 10, 20, 30, 255, 40, 50, 50, 255];
+  let b = ImageView::new(2, 2, PixelFormat::Rgba8, RowOrder::BottomUp, &rgba)
+    .unwrap();
+  let exact = compare_images(a, a, Tolerance::default()).unwrap();
+  assert_eq!((exact.differing_pixels, exact.max_channel_diff), (0, 0));
+  let loose = Tolerance { per_channel: 2, max_pixels: 0 };
+  let c = compare_images(a, b, loose).unwrap();
+  assert_eq!((c.differing_pixels, c.max_channel_diff), (1, 10));
+  assert!(!c.passes(loose));
+  assert!(c.passes(Tolerance { per_channel: 2, max_pixels: 1 }));
+  let diff = c.diff.view();
+  assert_eq!(diff.rgba(0, 0), [6, 6, 6, 255]);
+  assert_eq!(diff.rgba(1, 0), [255, 0, 0, 255]);
+  assert_eq!(diff.rgba(1, 1), [255, 255, 0, 255]);
+  let c = compare_images(a, b, Tolerance { per_channel: 10, max_pixels: 0 });
+  assert_eq!(c.unwrap().differing_pixels, 0);
+  let small =
+    ImageView::new(1, 1, PixelFormat::Rgb8, RowOrder::TopDown, &rgb[..3])
+      .unwrap();
+  assert!(compare_images(a, small, Tolerance::default()).is_none());
+}
+
+#[test]
+fn test_golden_check() {
+  let dir = std::env::temp_dir()
+    .join(format!("test_golden_check_{}", std::process::id()));
+  let golden = Golden {
+    reference_dir: dir.join("references"),
+    output_dir: dir.join("output"),
+    tolerance: Tolerance::default(),
+    bless: false,
+  };
+  let black = [0, 0, 0, 255, 0, 0, 0, 255];
+  let black =
+    ImageView::new(2, 1, PixelFormat::Rgba8, RowOrder::TopDown, &black)
+      .unwrap();
+  let gray = [9, 9, 9, 255, 0, 0, 0, 255];
+  let gray =
+    ImageView::new(2, 1, PixelFormat::Rgba8, RowOrder::TopDown, &gray).unwrap();
+
+  let e = golden.check("black", black).unwrap_err();
+  assert!(matches!(e, GoldenError::MissingReference { .. }), "{}", e);
+  assert!(e
+    .to_string()
+    .ends_with("set BLESS_GOLDEN=1 to bless the actual image"));
+  assert!(dir.join("output/black.actual.png").exists());
+  assert!(!dir.join("references/black.png").exists());
+
+  let blessing = Golden { bless: true, ..golden.clone() };
+  blessing.check("black", black).unwrap();
+  let reference = std::fs::read(dir.join("references/black.png")).unwrap();
+  assert_eq!(decode_png(&reference).unwrap().image(), black);
+  golden.check("black", black).unwrap();
+
+  let e = golden.check("black", gray).unwrap_err();
+  assert!(
+    matches!(
+      e,
+      GoldenError::TooDifferent {
+        differing_pixels: 1,
+        max_channel_diff: 9,
+        ..
+      }
+    ),
+    "{}",
+    e
+  );
+  for kind in ["actual", "expected", "diff"] {
+    let path = dir.join(format!("output/black.{}.png", kind));
+    assert!(path.exists(), "{}", path.display());
+  }
+  golden.clone().tolerance(9, 0).check("black", gray).unwrap();
+  golden.clone().tolerance(0, 1).check("black", gray).unwrap();
+  let one = ImageView { width: 1, pixels: &black.pixels[..4], ..black };
+  let e = golden.check("black", one).unwrap_err();
+  assert!(matches!(e, GoldenError::SizeMismatch { .. }), "{}", e);
+
+  // blessing leaves a passing reference alone, and replaces a failing one.
+  blessing.clone().tolerance(9, 0).check("black", gray).unwrap();
+  golden.check("black", black).unwrap();
+  blessing.check("black", gray).unwrap();
+  golden.check("black", gray).unwrap();
+  std::fs::remove_dir_all(&dir).unwrap();
+}
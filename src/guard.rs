@@ -0,0 +1,124 @@
+//! Module for cleaning things up when they go out of scope.
+//!
+//! [`ScopeGuard`] runs any cleanup on any value. For the OS handles that the
+//! crate makes a lot of, there's an owned type for each one, which cleans up
+//! on drop and converts to and from the raw handle: `OwnedHwnd`, `OwnedDc`,
+//! `OwnedGlrc`, and `RegisteredClass` on Windows, and `OwnedDisplay`,
+//! `OwnedXWindow`, `OwnedGlxContext`, `OwnedEglDisplay`, `OwnedEglSurface`,
+//! and `OwnedEglContext` on Linux.
+//!
+//! ```
+//! use triangle_from_scratch::guard::*;
+//! let mut log = Vec::new();
+//! {
+//!   let mut guard = ScopeGuard::new(vec![1], |v| log.extend(v));
+//!   guard.push(2);
+//! }
+//! assert_eq!(log, [1, 2]);
+//! ```
+
+use core::{
+  mem::ManuallyDrop,
+  ops::{Deref, DerefMut},
+};
+
+#[cfg(windows)]
+mod win32;
+#[cfg(windows)]
+pub use win32::*;
+
+#[cfg(target_os = "linux")]
+mod x11;
+#[cfg(target_os = "linux")]
+pub use x11::*;
+
+#[cfg(target_os = "linux")]
+mod egl;
+#[cfg(target_os = "linux")]
+pub use egl::*;
+
+/// A value that's given to a cleanup function when the guard is dropped.
+///
+/// The guard derefs to the value. Use [`defuse`](Self::defuse) or
+/// [`into_inner`](Self::into_inner) to skip the cleanup.
+pub struct ScopeGuard<T, F: FnOnce(T)> {
+  value: ManuallyDrop<T>,
+  cleanup: ManuallyDrop<F>,
+}
+impl<T, F: FnOnce(T)> ScopeGuard<T, F> {
+  pub fn new(value: T, cleanup: F) -> Self {
+    Self {
+      value: ManuallyDrop::new(value),
+      cleanup: ManuallyDrop::new(cleanup),
+    }
+  }
+
+  /// Skips the cleanup, and drops the value normally.
+  pub fn defuse(self) {
+    drop(self.into_inner())
+  }
+
+  /// Skips the cleanup, and gives back the value.
+  pub fn into_inner(self) -> T {
+    let mut this = ManuallyDrop::new(self);
+    // Safety: `this` is never dropped, so each field is taken only once.
+    unsafe {
+      ManuallyDrop::drop(&mut this.cleanup);
+      ManuallyDrop::take(&mut this.value)
+    }
+  }
+}
+impl<T, F: FnOnce(T)> Drop for ScopeGuard<T, F> {
+  fn drop(&mut self) {
+    // Safety: the fields aren't used again after this.
+    let (value, cleanup) = unsafe {
+      (
+        ManuallyDrop::take(&mut self.value),
+        ManuallyDrop::take(&mut self.cleanup),
+      )
+    };
+    cleanup(value)
+  }
+}
+impl<T, F: FnOnce(T)> Deref for ScopeGuard<T, F> {
+  type Target = T;
+  fn deref(&self) -> &T {
+    &self.value
+  }
+}
+impl<T, F: FnOnce(T)> DerefMut for ScopeGuard<T, F> {
+  fn deref_mut(&mut self) -> &mut T {
+    &mut self.value
+  }
+}
+impl<T: core::fmt::Debug, F: FnOnce(T)> core::fmt::Debug for ScopeGuard<T, F> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_tuple("ScopeGuard").field(&*self.value).finish()
+  }
+}
+
+#[test]
+fn test_scope_guard() {
+  use std::{cell::RefCell, rc::Rc};
+  let log = RefCell::new(Vec::new());
+  let cleanup = |name| log.borrow_mut().push(name);
+  {
+    let _a = ScopeGuard::new("a", cleanup);
+    let _b = ScopeGuard::new("b", cleanup);
+  }
+  // like any other locals, the last one made is the first one dropped.
+  assert_eq!(*log.borrow(), ["b", "a"]);
+
+  let value = Rc::new(5);
+  let guard = ScopeGuard::new(Rc::clone(&value), |_| cleanup("c"));
+  assert_eq!(**guard, 5);
+  assert_eq!(format!("{:?}", guard), "ScopeGuard(5)");
+  guard.defuse();
+  // the value was still dropped.
+  assert_eq!(Rc::strong_count(&value), 1);
+
+  let mut guard = ScopeGuard::new(vec![1], |_| cleanup("d"));
+  guard.push(2);
+  assert_eq!(guard.into_inner(), [1, 2]);
+  assert_eq!(*log.borrow(), ["b", "a"]);
+}
@@ -0,0 +1,131 @@
+use crate::egl::*;
+
+/// An initialized display, which is terminated on drop.
+///
+/// Terminating it destroys everything that was made with it that isn't
+/// current, so it has to outlive the other handles of the display.
+#[derive(Debug)]
+pub struct OwnedEglDisplay(EGLDisplay);
+impl OwnedEglDisplay {
+  /// ## Safety
+  ///
+  /// The display must be initialized, and not terminated by anything else.
+  pub unsafe fn from_raw(display: EGLDisplay) -> Self {
+    Self(display)
+  }
+
+  pub fn as_raw(&self) -> EGLDisplay {
+    self.0
+  }
+
+  /// Gives up the display without terminating it.
+  pub fn into_raw(self) -> EGLDisplay {
+    core::mem::ManuallyDrop::new(self).0
+  }
+}
+impl Drop for OwnedEglDisplay {
+  fn drop(&mut self) {
+    let _ = unsafe { egl_terminate(self.0) };
+  }
+}
+
+/// A surface, which is destroyed on drop.
+#[derive(Debug)]
+pub struct OwnedEglSurface {
+  display: EGLDisplay,
+  surface: EGLSurface,
+}
+impl OwnedEglSurface {
+  /// ## Safety
+  ///
+  /// The surface must not be destroyed by anything else, and the display
+  /// must stay initialized until it's dropped.
+  pub unsafe fn from_raw(display: EGLDisplay, surface: EGLSurface) -> Self {
+    Self { display, surface }
+  }
+
+  pub fn as_raw(&self) -> EGLSurface {
+    self.surface
+  }
+
+  /// Gives up the surface without destroying it.
+  pub fn into_raw(self) -> (EGLDisplay, EGLSurface) {
+    let this = core::mem::ManuallyDrop::new(self);
+    (this.display, this.surface)
+  }
+}
+impl Drop for OwnedEglSurface {
+  fn drop(&mut self) {
+    let _ = unsafe { egl_destroy_surface(self.display, self.surface) };
+  }
+}
+
+/// A context, which is destroyed on drop.
+///
+/// A context that's current isn't destroyed until it stops being current.
+#[derive(Debug)]
+pub struct OwnedEglContext {
+  display: EGLDisplay,
+  ctx: EGLContext,
+}
+impl OwnedEglContext {
+  /// ## Safety
+  ///
+  /// The context must not be destroyed by anything else, and the display
+  /// must stay initialized until it's dropped.
+  pub unsafe fn from_raw(display: EGLDisplay, ctx: EGLContext) -> Self {
+    Self { display, ctx }
+  }
+
+  pub fn as_raw(&self) -> EGLContext {
+    self.ctx
+  }
+
+  /// Gives up the context without destroying it.
+  pub fn into_raw(self) -> (EGLDisplay, EGLContext) {
+    let this = core::mem::ManuallyDrop::new(self);
+    (this.display, this.ctx)
+  }
+}
+impl Drop for OwnedEglContext {
+  fn drop(&mut self) {
+    let _ = unsafe { egl_destroy_context(self.display, self.ctx) };
+  }
+}
+
+#[test]
+fn test_owned_egl_handles() {
+  let display = match crate::test_util::headless_display() {
+    Some(display) => display,
+    None => return,
+  };
+  unsafe {
+    let config = egl_choose_config(
+      display.as_raw(),
+      &[
+        [EGL_SURFACE_TYPE, EGL_PBUFFER_BIT],
+        [EGL_RENDERABLE_TYPE, EGL_OPENGL_BIT],
+        [EGL_NONE, 0],
+      ],
+    )
+    .unwrap()[0];
+    let surface = OwnedEglSurface::from_raw(
+      display.as_raw(),
+      egl_create_pbuffer_surface(display.as_raw(), config, [8, 8]).unwrap(),
+    );
+    egl_bind_api(EGL_OPENGL_API).unwrap();
+    let ctx = OwnedEglContext::from_raw(
+      display.as_raw(),
+      egl_create_context(display.as_raw(), config, EGL_NO_CONTEXT, &[])
+        .unwrap(),
+    );
+    egl_make_current(display.as_raw(), surface.as_raw(), ctx.as_raw()).unwrap();
+    egl_make_current(display.as_raw(), EGL_NO_SURFACE, EGL_NO_CONTEXT).unwrap();
+    // taking the raw handle back out means it's up to us again.
+    let (raw_display, raw_ctx) = ctx.into_raw();
+    egl_destroy_context(raw_display, raw_ctx).unwrap();
+    let twice = egl_destroy_context(raw_display, raw_ctx).unwrap_err();
+    assert_eq!(twice.0, EGL_BAD_CONTEXT);
+    // the surface is destroyed here, then the display is terminated.
+  }
+}
@@ -0,0 +1,148 @@
+use crate::win32::*;
+
+/// A window, which is destroyed on drop.
+#[derive(Debug)]
+pub struct OwnedHwnd(HWND);
+impl OwnedHwnd {
+  /// ## Safety
+  ///
+  /// The window must not be destroyed by anything else.
+  pub unsafe fn from_raw(hwnd: HWND) -> Self {
+    Self(hwnd)
+  }
+
+  pub fn as_raw(&self) -> HWND {
+    self.0
+  }
+
+  /// Gives up the window without destroying it.
+  pub fn into_raw(self) -> HWND {
+    core::mem::ManuallyDrop::new(self).0
+  }
+}
+impl Drop for OwnedHwnd {
+  fn drop(&mut self) {
+    let _ = unsafe { destroy_window(self.0) };
+  }
+}
+
+/// A device context from `GetDC`, which is released on drop.
+#[derive(Debug)]
+pub struct OwnedDc {
+  hwnd: HWND,
+  hdc: HDC,
+}
+impl OwnedDc {
+  /// Gets the device context of a window.
+  ///
+  /// ## Safety
+  ///
+  /// `hwnd` must be a window.
+  pub unsafe fn get(hwnd: HWND) -> Result<Self, Win32Error> {
+    match get_dc(hwnd) {
+      Some(hdc) => Ok(Self { hwnd, hdc }),
+      None => Err(Win32Error(Win32Error::APPLICATION_ERROR_BIT)),
+    }
+  }
+
+  /// ## Safety
+  ///
+  /// The device context must be from `GetDC` of the window, and must not be
+  /// released by anything else.
+  pub unsafe fn from_raw(hwnd: HWND, hdc: HDC) -> Self {
+    Self { hwnd, hdc }
+  }
+
+  pub fn as_raw(&self) -> HDC {
+    self.hdc
+  }
+
+  /// The window that the device context is of.
+  pub fn hwnd(&self) -> HWND {
+    self.hwnd
+  }
+
+  /// Gives up the device context without releasing it.
+  pub fn into_raw(self) -> (HWND, HDC) {
+    let this = core::mem::ManuallyDrop::new(self);
+    (this.hwnd, this.hdc)
+  }
+}
+impl Drop for OwnedDc {
+  fn drop(&mut self) {
+    let _ = unsafe { release_dc(self.hwnd, self.hdc) };
+  }
+}
+
+/// A GL rendering context, which is deleted on drop.
+///
+/// If the context is current on this thread, deleting it makes it not
+/// current first.
+#[derive(Debug)]
+pub struct OwnedGlrc(HGLRC);
+impl OwnedGlrc {
+  /// ## Safety
+  ///
+  /// The context must not be deleted by anything else.
+  pub unsafe fn from_raw(hglrc: HGLRC) -> Self {
+    Self(hglrc)
+  }
+
+  pub fn as_raw(&self) -> HGLRC {
+    self.0
+  }
+
+  /// Gives up the context without deleting it.
+  pub fn into_raw(self) -> HGLRC {
+    core::mem::ManuallyDrop::new(self).0
+  }
+}
+impl Drop for OwnedGlrc {
+  fn drop(&mut self) {
+    let _ = unsafe { wgl_delete_context(self.0) };
+  }
+}
+
+/// A window class, which is unregistered on drop.
+///
+/// Every window of the class must be destroyed before then.
+#[derive(Debug)]
+pub struct RegisteredClass {
+  atom: ATOM,
+  instance: HINSTANCE,
+}
+impl RegisteredClass {
+  /// Registers a window class.
+  ///
+  /// ## Safety
+  ///
+  /// All pointer fields of the struct must be correct.
+  ///
+  /// See [`register_class`].
+  pub unsafe fn register(wc: &WNDCLASSW) -> Result<Self, Win32Error> {
+    let atom = register_class(wc)?;
+    Ok(Self { atom, instance: wc.hInstance })
+  }
+
+  /// ## Safety
+  ///
+  /// The class must not be unregistered by anything else.
+  pub unsafe fn from_raw(atom: ATOM, instance: HINSTANCE) -> Self {
+    Self { atom, instance }
+  }
+
+  pub fn as_raw(&self) -> ATOM {
+    self.atom
+  }
+
+  /// Gives up the class without unregistering it.
+  pub fn into_raw(self) -> (ATOM, HINSTANCE) {
+    let this = core::mem::ManuallyDrop::new(self);
+    (this.atom, this.instance)
+  }
+}
+impl Drop for RegisteredClass {
+  fn drop(&mut self) {
+    let _ = unsafe { unregister_class_by_atom(self.atom, self.instance) };
+  }
+}
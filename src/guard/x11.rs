@@ -0,0 +1,164 @@
+use crate::{
+  glx::{glx_destroy_context, GLXContext},
+  x11::{self, close_display, destroy_window, Display},
+};
+
+/// A display connection, which is closed on drop.
+///
+/// Closing it destroys everything that was made through it, so it has to
+/// outlive the other handles of the display.
+#[derive(Debug)]
+pub struct OwnedDisplay(*mut Display);
+impl OwnedDisplay {
+  /// ## Safety
+  ///
+  /// The connection must not be closed by anything else.
+  pub unsafe fn from_raw(display: *mut Display) -> Self {
+    Self(display)
+  }
+
+  pub fn as_raw(&self) -> *mut Display {
+    self.0
+  }
+
+  /// Gives up the connection without closing it.
+  pub fn into_raw(self) -> *mut Display {
+    core::mem::ManuallyDrop::new(self).0
+  }
+}
+impl Drop for OwnedDisplay {
+  fn drop(&mut self) {
+    unsafe { close_display(self.0) }
+  }
+}
+
+/// A window made with `create_app_window`, which is destroyed on drop.
+#[derive(Debug)]
+pub struct OwnedXWindow {
+  display: *mut Display,
+  window: x11::Window,
+}
+impl OwnedXWindow {
+  /// ## Safety
+  ///
+  /// The window must not be destroyed by anything else, and the display must
+  /// stay open until it's dropped.
+  pub unsafe fn from_raw(display: *mut Display, window: x11::Window) -> Self {
+    Self { display, window }
+  }
+
+  pub fn as_raw(&self) -> x11::Window {
+    self.window
+  }
+
+  pub fn display(&self) -> *mut Display {
+    self.display
+  }
+
+  /// Gives up the window without destroying it.
+  pub fn into_raw(self) -> (*mut Display, x11::Window) {
+    let this = core::mem::ManuallyDrop::new(self);
+    (this.display, this.window)
+  }
+}
+impl Drop for OwnedXWindow {
+  fn drop(&mut self) {
+    let _ = unsafe { destroy_window(self.display, self.window) };
+  }
+}
+
+/// A GLX context, which is destroyed on drop.
+///
+/// A context that's current isn't destroyed until it stops being current.
+#[derive(Debug)]
+pub struct OwnedGlxContext {
+  display: *mut Display,
+  ctx: GLXContext,
+}
+impl OwnedGlxContext {
+  /// ## Safety
+  ///
+  /// The context must not be destroyed by anything else, and the display must
+  /// stay open until it's dropped.
+  pub unsafe fn from_raw(display: *mut Display, ctx: GLXContext) -> Self {
+    Self { display, ctx }
+  }
+
+  pub fn as_raw(&self) -> GLXContext {
+    self.ctx
+  }
+
+  pub fn display(&self) -> *mut Display {
+    self.display
+  }
+
+  /// Gives up the context without destroying it.
+  pub fn into_raw(self) -> (*mut Display, GLXContext) {
+    let this = core::mem::ManuallyDrop::new(self);
+    (this.display, this.ctx)
+  }
+}
+impl Drop for OwnedGlxContext {
+  fn drop(&mut self) {
+    let _ = unsafe { glx_destroy_context(self.display, self.ctx) };
+  }
+}
+
+#[test]
+fn test_owned_x11_handles() {
+  use crate::{glx::*, x11::*};
+  if !crate::test_util::has_x_display() {
+    return;
+  }
+  unsafe {
+    let display = OwnedDisplay::from_raw(open_display(None).unwrap());
+    let screen = XDefaultScreen(display.as_raw());
+    let (_extensions, create_context_attribs, _swap_interval) =
+      get_glx_basics(display.as_raw(), screen).unwrap();
+    let configs = glx_choose_fb_config(
+      display.as_raw(),
+      screen,
+      &[
+        [GLX_X_RENDERABLE, True],
+        [GLX_DRAWABLE_TYPE, GLX_WINDOW_BIT],
+        [GLX_RENDER_TYPE, GLX_RGBA_BIT],
+        [0, 0],
+      ],
+    )
+    .unwrap();
+    let visual_info =
+      glx_get_visual_from_fb_config(display.as_raw(), configs[0]).unwrap();
+    let window = OwnedXWindow::from_raw(
+      display.as_raw(),
+      create_app_window(
+        display.as_raw(),
+        "Guard Test",
+        None,
+        [8, 8],
+        Some(&visual_info),
+      )
+      .unwrap(),
+    );
+    assert_eq!(window.display(), display.as_raw());
+    let ctx = OwnedGlxContext::from_raw(
+      display.as_raw(),
+      do_glXCreateContextAttribsARB(
+        create_context_attribs,
+        display.as_raw(),
+        configs[0],
+        core::ptr::null_mut(),
+        true,
+        &[[0, 0]],
+      )
+      .unwrap(),
+    );
+    glx_make_current(display.as_raw(), window.as_raw(), ctx.as_raw()).unwrap();
+    glx_make_current(display.as_raw(), 0, core::ptr::null_mut()).unwrap();
+    // taking the raw handle back out means it's up to us again.
+    let (raw_display, raw_window) = window.into_raw();
+    destroy_window(raw_display, raw_window).unwrap();
+    let twice = destroy_window(raw_display, raw_window).unwrap_err();
+    assert_eq!(twice.0, BadWindow);
+    // the context is destroyed here, then the display is closed.
+  }
+}
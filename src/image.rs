@@ -0,0 +1,437 @@
+//! Module for saving images, such as screenshots, and for loading PNGs.
+//!
+//! An [`ImageView`] borrows 8-bit RGB or RGBA pixels, and an [`Image`] owns
+//! them, with rows going either top to bottom (like most image files) or
+//! bottom to top (like `glReadPixels` gives them). An image can be encoded as
+//! a binary PPM with [`encode_ppm`], an uncompressed BMP with [`encode_bmp`],
+//! or a PNG with [`encode_png`], or saved by the file extension with
+//! [`save_image`].
+//!
+//! A PNG can be loaded into RGBA8 pixels with [`decode_png`].
+//!
+//! PNG needs zlib, which is in this module too (see [`zlib_compress`] and
+//! [`zlib_decompress`]), along with the [`crc32`] and [`adler32`] checksums.
+
+use std::path::Path;
+
+mod zlib;
+pub use zlib::*;
+
+mod png;
+pub use png::*;
+
+/// The layout of each pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+  Rgb8,
+  Rgba8,
+}
+impl PixelFormat {
+  pub const fn bytes_per_pixel(self) -> usize {
+    match self {
+      Self::Rgb8 => 3,
+      Self::Rgba8 => 4,
+    }
+  }
+}
+
+/// The order of the rows in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowOrder {
+  /// The first row is the top of the image.
+  TopDown,
+  /// The first row is the bottom of the image, like GL does it.
+  BottomUp,
+}
+
+/// Borrowed pixels, with no padding between rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageView<'p> {
+  pub width: u32,
+  pub height: u32,
+  pub format: PixelFormat,
+  pub rows: RowOrder,
+  pub pixels: &'p [u8],
+}
+impl<'p> ImageView<'p> {
+  /// Gives `None` if `pixels` isn't the right length for the size and format.
+  pub fn new(
+    width: u32, height: u32, format: PixelFormat, rows: RowOrder,
+    pixels: &'p [u8],
+  ) -> Option<Self> {
+    let len = (width as usize)
+      .checked_mul(height as usize)?
+      .checked_mul(format.bytes_per_pixel())?;
+    if pixels.len() == len {
+      Some(Self { width, height, format, rows, pixels })
+    } else {
+      None
+    }
+  }
+
+  /// The bytes of row `y`, counting from the top of the image.
+  ///
+  /// ## Panics
+  /// * If `y` is out of bounds.
+  pub fn row(&self, y: u32) -> &'p [u8] {
+    assert!(y < self.height, "row {} is out of bounds", y);
+    let row_len = self.width as usize * self.format.bytes_per_pixel();
+    let index = match self.rows {
+      RowOrder::TopDown => y,
+      RowOrder::BottomUp => self.height - 1 - y,
+    } as usize;
+    &self.pixels[index * row_len..(index + 1) * row_len]
+  }
+
+  /// The pixel at `(x, y)`, counting from the top left, as RGBA.
+  ///
+  /// RGB pixels are given an alpha of 255.
+  ///
+  /// ## Panics
+  /// * If `x` or `y` is out of bounds.
+  pub fn rgba(&self, x: u32, y: u32) -> [u8; 4] {
+    assert!(x < self.width, "column {} is out of bounds", x);
+    let bpp = self.format.bytes_per_pixel();
+    let p = &self.row(y)[x as usize * bpp..][..bpp];
+    [p[0], p[1], p[2], if bpp == 4 { p[3] } else { 255 }]
+  }
+
+  /// Copies the pixels into an owned [`Image`].
+  pub fn to_image(&self) -> Image {
+    Image {
+      width: self.width,
+      height: self.height,
+      format: self.format,
+      rows: self.rows,
+      pixels: self.pixels.to_vec(),
+    }
+  }
+}
+
+/// Owned pixels, with no padding between rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+  pub width: u32,
+  pub height: u32,
+  pub format: PixelFormat,
+  pub rows: RowOrder,
+  pub pixels: Vec<u8>,
+}
+impl Image {
+  /// Gives `None` if `pixels` isn't the right length for the size and format.
+  pub fn new(
+    width: u32, height: u32, format: PixelFormat, rows: RowOrder,
+    pixels: Vec<u8>,
+  ) -> Option<Self> {
+    ImageView::new(width, height, format, rows, &pixels)?;
+    Some(Self { width, height, format, rows, pixels })
+  }
+
+  /// Borrows the pixels as an [`ImageView`].
+  pub fn view(&self) -> ImageView<'_> {
+    ImageView {
+      width: self.width,
+      height: self.height,
+      format: self.format,
+      rows: self.rows,
+      pixels: &self.pixels,
+    }
+  }
+
+  /// Reorders the rows in memory, keeping the picture the same.
+  pub fn into_row_order(mut self, rows: RowOrder) -> Self {
+    if rows != self.rows {
+      let row_len = self.width as usize * self.format.bytes_per_pixel();
+      flip_rows(&mut self.pixels, row_len);
+      self.rows = rows;
+    }
+    self
+  }
+}
+
+/// Owned RGBA pixels with `f32` channels, with no padding between rows.
+///
+/// The colors are linear, not sRGB encoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatImage {
+  pub width: u32,
+  pub height: u32,
+  pub rows: RowOrder,
+  pub pixels: Vec<[f32; 4]>,
+}
+impl FloatImage {
+  /// Gives `None` if `pixels` isn't the right length for the size.
+  pub fn new(
+    width: u32, height: u32, rows: RowOrder, pixels: Vec<[f32; 4]>,
+  ) -> Option<Self> {
+    let len = (width as usize).checked_mul(height as usize)?;
+    if pixels.len() == len {
+      Some(Self { width, height, rows, pixels })
+    } else {
+      None
+    }
+  }
+
+  /// The pixel at `(x, y)`, counting from the top left.
+  ///
+  /// ## Panics
+  /// * If `x` or `y` is out of bounds.
+  pub fn rgba(&self, x: u32, y: u32) -> [f32; 4] {
+    assert!(x < self.width, "column {} is out of bounds", x);
+    assert!(y < self.height, "row {} is out of bounds", y);
+    let row = match self.rows {
+      RowOrder::TopDown => y,
+      RowOrder::BottomUp => self.height - 1 - y,
+    };
+    self.pixels[row as usize * self.width as usize + x as usize]
+  }
+
+  /// Reorders the rows in memory, keeping the picture the same.
+  pub fn into_row_order(mut self, rows: RowOrder) -> Self {
+    if rows != self.rows {
+      flip_rows(&mut self.pixels, self.width as usize);
+      self.rows = rows;
+    }
+    self
+  }
+
+  /// Converts to 8-bit RGBA, with the colors sRGB encoded (like image files
+  /// expect) or not. Alpha is always linear.
+  pub fn to_rgba8(&self, srgb: bool) -> Image {
+    let encode = |c: f32| if srgb { linear_to_srgb8(c) } else { to_unorm8(c) };
+    let mut pixels = Vec::with_capacity(self.pixels.len() * 4);
+    for &[r, g, b, a] in self.pixels.iter() {
+      pixels.extend_from_slice(&[
+        encode(r),
+        encode(g),
+        encode(b),
+        to_unorm8(a),
+      ]);
+    }
+    Image {
+      width: self.width,
+      height: self.height,
+      format: PixelFormat::Rgba8,
+      rows: self.rows,
+      pixels,
+    }
+  }
+}
+
+/// Swaps the rows of `data` top to bottom.
+fn flip_rows<T>(data: &mut [T], row_len: usize) {
+  if row_len == 0 {
+    return;
+  }
+  let rows = data.len() / row_len;
+  for y in 0..rows / 2 {
+    let (top, bottom) = data.split_at_mut((rows - 1 - y) * row_len);
+    top[y * row_len..(y + 1) * row_len].swap_with_slice(&mut bottom[..row_len]);
+  }
+}
+
+/// Clamps to `0.0 ..= 1.0` and rounds to the nearest `u8`, like GL does for a
+/// normalized `u8` channel.
+pub(crate) fn to_unorm8(c: f32) -> u8 {
+  (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Encodes a linear color channel as an 8-bit sRGB value.
+pub fn linear_to_srgb8(c: f32) -> u8 {
+  let c = c.clamp(0.0, 1.0);
+  let s =
+    if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+  (s * 255.0).round() as u8
+}
+
+/// Decodes an 8-bit sRGB value to a linear color channel.
+pub fn srgb8_to_linear(s: u8) -> f32 {
+  let s = s as f32 / 255.0;
+  if s <= 0.04045 {
+    s / 12.92
+  } else {
+    ((s + 0.055) / 1.055).powf(2.4)
+  }
+}
+
+/// Encodes an image as a binary (`P6`) PPM.
+///
+/// PPM has no alpha, so the alpha of RGBA pixels is left out.
+pub fn encode_ppm(image: ImageView) -> Vec<u8> {
+  let mut out =
+    format!("P6\n{} {}\n255\n", image.width, image.height).into_bytes();
+  let bpp = image.format.bytes_per_pixel();
+  for y in 0..image.height {
+    for pixel in image.row(y).chunks_exact(bpp) {
+      out.extend_from_slice(&pixel[..3]);
+    }
+  }
+  out
+}
+
+/// Encodes an image as an uncompressed BMP.
+///
+/// RGB is saved as 24-bit pixels. RGBA is saved as 32-bit pixels, with a
+/// `BITMAPV4HEADER` so that the alpha channel is understood.
+pub fn encode_bmp(image: ImageView) -> Vec<u8> {
+  let bpp = image.format.bytes_per_pixel();
+  // rows are padded to 4 bytes.
+  let row_len = (image.width as usize * bpp + 3) & !3;
+  let info_header_len: u32 = match image.format {
+    PixelFormat::Rgb8 => 40,
+    PixelFormat::Rgba8 => 108,
+  };
+  let pixel_offset = 14 + info_header_len;
+  let image_len = (row_len * image.height as usize) as u32;
+  let mut out = Vec::with_capacity((pixel_offset + image_len) as usize);
+  // BITMAPFILEHEADER
+  out.extend_from_slice(b"BM");
+  out.extend_from_slice(&(pixel_offset + image_len).to_le_bytes());
+  out.extend_from_slice(&[0; 4]);
+  out.extend_from_slice(&pixel_offset.to_le_bytes());
+  // BITMAPINFOHEADER, with a positive height for bottom to top rows.
+  out.extend_from_slice(&info_header_len.to_le_bytes());
+  out.extend_from_slice(&(image.width as i32).to_le_bytes());
+  out.extend_from_slice(&(image.height as i32).to_le_bytes());
+  out.extend_from_slice(&1_u16.to_le_bytes());
+  out.extend_from_slice(&(bpp as u16 * 8).to_le_bytes());
+  let compression: u32 = match image.format {
+    // BI_RGB
+    PixelFormat::Rgb8 => 0,
+    // BI_BITFIELDS
+    PixelFormat::Rgba8 => 3,
+  };
+  out.extend_from_slice(&compression.to_le_bytes());
+  out.extend_from_slice(&image_len.to_le_bytes());
+  // 72 DPI, and no palette.
+  out.extend_from_slice(&2835_i32.to_le_bytes());
+  out.extend_from_slice(&2835_i32.to_le_bytes());
+  out.extend_from_slice(&[0; 8]);
+  if image.format == PixelFormat::Rgba8 {
+    // the rest of a BITMAPV4HEADER: the RGBA masks, `LCS_sRGB`, and the
+    // endpoints and gammas (which are ignored for sRGB).
+    for mask in [0x00FF0000_u32, 0x0000FF00, 0x000000FF, 0xFF000000] {
+      out.extend_from_slice(&mask.to_le_bytes());
+    }
+    out.extend_from_slice(b"BGRs");
+    out.extend_from_slice(&[0; 48]);
+  }
+  for y in (0..image.height).rev() {
+    let start = out.len();
+    for pixel in image.row(y).chunks_exact(bpp) {
+      out.extend_from_slice(&[pixel[2], pixel[1], pixel[0]]);
+      if bpp == 4 {
+        out.push(pixel[3]);
+      }
+    }
+    out.resize(start + row_len, 0);
+  }
+  out
+}
+
+/// Saves an image, picking the format by the file extension.
+///
+/// The extension can be `ppm`, `bmp`, or `png`, in any case.
+pub fn save_image(
+  path: impl AsRef<Path>, image: ImageView,
+) -> std::io::Result<()> {
+  let path = path.as_ref();
+  let extension =
+    path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase());
+  let bytes = match extension.as_deref() {
+    Some("ppm") => encode_ppm(image),
+    Some("bmp") => encode_bmp(image),
+    Some("png") => encode_png(image),
+    _ => {
+      return Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!("can't tell the image format of {}", path.display()),
+      ))
+    }
+  };
+  std::fs::write(path, bytes)
+}
+
+#[test]
+fn test_image_view() {
+  let pixels = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+  assert!(ImageView::new(2, 2, PixelFormat::Rgba8, RowOrder::TopDown, &pixels)
+    .is_none());
+  let image =
+    ImageView::new(2, 2, PixelFormat::Rgb8, RowOrder::BottomUp, &pixels)
+      .unwrap();
+  assert_eq!(image.row(0), [7, 8, 9, 10, 11, 12]);
+  assert_eq!(image.row(1), [1, 2, 3, 4, 5, 6]);
+  assert_eq!(image.rgba(1, 0), [10, 11, 12, 255]);
+  let owned = image.to_image();
+  assert_eq!(owned.view(), image);
+  assert!(Image::new(1, 1, PixelFormat::Rgb8, RowOrder::TopDown, vec![0; 4])
+    .is_none());
+  let flipped = owned.clone().into_row_order(RowOrder::TopDown);
+  assert_eq!(flipped.pixels, [7, 8, 9, 10, 11, 12, 1, 2, 3, 4, 5, 6]);
+  assert_eq!(flipped.view().rgba(1, 0), [10, 11, 12, 255]);
+  assert_eq!(flipped.into_row_order(RowOrder::BottomUp), owned);
+  assert_eq!(
+    encode_ppm(image),
+    b"P6\n2 2\n255\n\x07\x08\x09\x0a\x0b\x0c\x01\x02\x03\x04\x05\x06"
+  );
+}
+
+#[test]
+fn test_encode_bmp() {
+  // red, green on the top row, blue, white on the bottom row.
+  let rgb = [0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 0];
+  let image =
+    ImageView::new(2, 2, PixelFormat::Rgb8, RowOrder::BottomUp, &rgb).unwrap();
+  let bmp = encode_bmp(image);
+  assert_eq!(bmp.len(), 14 + 40 + 2 * 8);
+  assert_eq!(bmp[..2], *b"BM");
+  assert_eq!(bmp[2..6], 70_u32.to_le_bytes());
+  assert_eq!(bmp[10..14], 54_u32.to_le_bytes());
+  assert_eq!(bmp[28..30], 24_u16.to_le_bytes());
+  // BGR, with rows padded from 6 to 8 bytes, bottom row first.
+  assert_eq!(
+    bmp[54..],
+    [255, 0, 0, 255, 255, 255, 0, 0, 0, 0, 255, 0, 255, 0, 0, 0]
+  );
+
+  let rgba = [10, 20, 30, 40];
+  let image =
+    ImageView::new(1, 1, PixelFormat::Rgba8, RowOrder::TopDown, &rgba).unwrap();
+  let bmp = encode_bmp(image);
+  assert_eq!(bmp.len(), 14 + 108 + 4);
+  assert_eq!(bmp[14..18], 108_u32.to_le_bytes());
+  assert_eq!(bmp[30..34], 3_u32.to_le_bytes());
+  assert_eq!(bmp[bmp.len() - 4..], [30, 20, 10, 40]);
+}
+
+#[test]
+fn test_save_image() {
+  let pixels = [0_u8; 3];
+  let image =
+    ImageView::new(1, 1, PixelFormat::Rgb8, RowOrder::TopDown, &pixels)
+      .unwrap();
+  let dir = std::env::temp_dir();
+  let path = dir.join(format!("test_save_image_{}.PNG", std::process::id()));
+  save_image(&path, image).unwrap();
+  let bytes = std::fs::read(&path).unwrap();
+  std::fs::remove_file(&path).unwrap();
+  assert_eq!(bytes[..8], PNG_SIGNATURE);
+  let e = save_image(dir.join("no_extension"), image).unwrap_err();
+  assert_eq!(e.kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_float_image() {
+  let pixels = vec![[0.0, 0.5, 1.0, 1.0], [0.2159, 2.0, -1.0, 0.5]];
+  let image = FloatImage::new(1, 2, RowOrder::BottomUp, pixels).unwrap();
+  assert_eq!(image.rgba(0, 0), [0.2159, 2.0, -1.0, 0.5]);
+  let image = image.into_row_order(RowOrder::TopDown);
+  assert_eq!(image.pixels[0], [0.2159, 2.0, -1.0, 0.5]);
+  assert_eq!(image.to_rgba8(false).pixels, [55, 255, 0, 128, 0, 128, 255, 255]);
+  assert_eq!(image.to_rgba8(true).pixels, [128, 255, 0, 128, 0, 188, 255, 255]);
+  for s in 0..=255 {
+    assert_eq!(linear_to_srgb8(srgb8_to_linear(s)), s);
+  }
+  assert!(FloatImage::new(2, 2, RowOrder::TopDown, vec![]).is_none());
+}
@@ -0,0 +1,838 @@
+//! Encoding and decoding the PNG format.
+//!
+//! See the [PNG spec](https://www.w3.org/TR/png/).
+
+use super::*;
+use core::convert::TryInto;
+
+/// The first 8 bytes of every PNG file.
+pub const PNG_SIGNATURE: [u8; 8] =
+  [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+const CRC_TABLE: [u32; 256] = {
+  let mut table = [0; 256];
+  let mut n = 0;
+  while n < 256 {
+    let mut c = n as u32;
+    let mut k = 0;
+    while k < 8 {
+      c = if c & 1 != 0 { 0xEDB88320 ^ (c >> 1) } else { c >> 1 };
+      k += 1;
+    }
+    table[n] = c;
+    n += 1;
+  }
+  table
+};
+
+/// The CRC-32 of PNG chunks (and of zip, gzip, and so on).
+pub fn crc32(bytes: &[u8]) -> u32 {
+  !bytes.iter().fold(!0_u32, |c, &b| {
+    CRC_TABLE[((c ^ b as u32) & 0xFF) as usize] ^ (c >> 8)
+  })
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+  out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+  let start = out.len();
+  out.extend_from_slice(kind);
+  out.extend_from_slice(data);
+  let crc = crc32(&out[start..]);
+  out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// The Paeth predictor of filter type 4.
+pub(crate) fn paeth(a: u8, b: u8, c: u8) -> u8 {
+  let p = a as i16 + b as i16 - c as i16;
+  let (pa, pb, pc) =
+    ((p - a as i16).abs(), (p - b as i16).abs(), (p - c as i16).abs());
+  if pa <= pb && pa <= pc {
+    a
+  } else if pb <= pc {
+    b
+  } else {
+    c
+  }
+}
+
+/// Filters a row with each filter type, and keeps the one with the smallest
+/// sum of absolute differences, which is what the spec suggests.
+fn filter_row(row: &[u8], prior: &[u8], bpp: usize, out: &mut Vec<u8>) {
+  let mut best: Option<(u64, Vec<u8>)> = None;
+  for filter in 0..5_u8 {
+    let mut filtered = Vec::with_capacity(row.len() + 1);
+    filtered.push(filter);
+    for i in 0..row.len() {
+      let a = if i >= bpp { row[i - bpp] } else { 0 };
+      let b = prior[i];
+      let c = if i >= bpp { prior[i - bpp] } else { 0 };
+      let predicted = match filter {
+        0 => 0,
+        1 => a,
+        2 => b,
+        3 => ((a as u16 + b as u16) / 2) as u8,
+        _ => paeth(a, b, c),
+      };
+      filtered.push(row[i].wrapping_sub(predicted));
+    }
+    let sum =
+      filtered[1..].iter().map(|&x| (x as i8).unsigned_abs() as u64).sum();
+    if best.as_ref().is_none_or(|(best_sum, _)| sum < *best_sum) {
+      best = Some((sum, filtered));
+    }
+  }
+  out.extend_from_slice(&best.unwrap().1);
+}
+
+/// Encodes an image as an 8-bit RGB or RGBA PNG.
+pub fn encode_png(image: ImageView) -> Vec<u8> {
+  let bpp = image.format.bytes_per_pixel();
+  let mut ihdr = Vec::with_capacity(13);
+  ihdr.extend_from_slice(&image.width.to_be_bytes());
+  ihdr.extend_from_slice(&image.height.to_be_bytes());
+  let color_type = match image.format {
+    PixelFormat::Rgb8 => 2,
+    PixelFormat::Rgba8 => 6,
+  };
+  // bit depth, color type, compression, filter method, no interlacing.
+  ihdr.extend_from_slice(&[8, color_type, 0, 0, 0]);
+  let row_len = image.width as usize * bpp;
+  let mut filtered = Vec::with_capacity((row_len + 1) * image.height as usize);
+  let mut prior = vec![0; row_len];
+  for y in 0..image.height {
+    let row = image.row(y);
+    filter_row(row, &prior, bpp, &mut filtered);
+    prior.copy_from_slice(row);
+  }
+  let mut out = PNG_SIGNATURE.to_vec();
+  write_chunk(&mut out, b"IHDR", &ihdr);
+  write_chunk(&mut out, b"IDAT", &zlib_compress(&filtered));
+  write_chunk(&mut out, b"IEND", &[]);
+  out
+}
+
+/// The color type of a PNG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PngColorType {
+  Gray,
+  Rgb,
+  Indexed,
+  GrayAlpha,
+  Rgba,
+}
+impl PngColorType {
+  pub const fn from_raw(raw: u8) -> Option<Self> {
+    Some(match raw {
+      0 => Self::Gray,
+      2 => Self::Rgb,
+      3 => Self::Indexed,
+      4 => Self::GrayAlpha,
+      6 => Self::Rgba,
+      _ => return None,
+    })
+  }
+
+  pub const fn channels(self) -> usize {
+    match self {
+      Self::Gray | Self::Indexed => 1,
+      Self::GrayAlpha => 2,
+      Self::Rgb => 3,
+      Self::Rgba => 4,
+    }
+  }
+}
+
+/// What a PNG says about itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PngInfo {
+  pub width: u32,
+  pub height: u32,
+  /// Bits per sample (or per palette index).
+  pub bit_depth: u8,
+  pub color_type: PngColorType,
+  /// If the image data uses Adam7 interlacing.
+  pub interlaced: bool,
+  /// The `gAMA` chunk, which is the gamma times 100,000.
+  pub gamma: Option<u32>,
+  /// The rendering intent of the `sRGB` chunk, which says that the image is
+  /// sRGB.
+  pub srgb_intent: Option<u8>,
+}
+
+/// A decoded PNG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PngImage {
+  pub info: PngInfo,
+  /// RGBA8 pixels, with the top row first.
+  ///
+  /// 16-bit samples are rounded to 8 bits, smaller samples are scaled up, and
+  /// palettes and `tRNS` transparency are applied. Gamma isn't.
+  pub pixels: Vec<u8>,
+}
+impl PngImage {
+  pub fn image(&self) -> ImageView<'_> {
+    ImageView {
+      width: self.info.width,
+      height: self.info.height,
+      format: PixelFormat::Rgba8,
+      rows: RowOrder::TopDown,
+      pixels: &self.pixels,
+    }
+  }
+}
+
+/// An error from [`decode_png`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PngError {
+  /// The file doesn't start with [`PNG_SIGNATURE`].
+  BadSignature,
+  /// The file ended in the middle of a chunk, or before `IEND`.
+  UnexpectedEnd,
+  BadChunkCrc {
+    chunk: [u8; 4],
+  },
+  /// The first chunk isn't `IHDR`.
+  MissingIhdr,
+  /// A size of 0, a bad bit depth for the color type, or an unknown color
+  /// type, compression method, filter method, or interlace method.
+  BadIhdr,
+  /// A chunk that's needed to decode the image, which this doesn't know.
+  UnknownCriticalChunk {
+    chunk: [u8; 4],
+  },
+  /// Critical chunks in the wrong order, or more than one of them.
+  BadChunkOrder {
+    chunk: [u8; 4],
+  },
+  /// An indexed image with no `PLTE`.
+  MissingPalette,
+  BadPalette,
+  /// An index past the end of the palette.
+  BadPaletteIndex,
+  BadTrns,
+  MissingIdat,
+  Inflate(InflateError),
+  /// A scanline with a filter type past 4.
+  BadFilterType(u8),
+  /// The image data is longer or shorter than the image needs.
+  BadImageDataLength,
+  /// Too big to decode into memory.
+  TooLarge,
+}
+impl core::fmt::Display for PngError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let name = |chunk: &[u8; 4]| String::from_utf8_lossy(chunk).into_owned();
+    match self {
+      Self::BadSignature => write!(f, "not a PNG"),
+      Self::UnexpectedEnd => write!(f, "the PNG ended early"),
+      Self::BadChunkCrc { chunk } => write!(f, "bad CRC in {}", name(chunk)),
+      Self::MissingIhdr => write!(f, "the first chunk isn't IHDR"),
+      Self::BadIhdr => write!(f, "bad IHDR"),
+      Self::UnknownCriticalChunk { chunk } => {
+        write!(f, "unknown critical chunk {}", name(chunk))
+      }
+      Self::BadChunkOrder { chunk } => {
+        write!(f, "{} is out of order", name(chunk))
+      }
+      Self::MissingPalette => write!(f, "an indexed image has no PLTE"),
+      Self::BadPalette => write!(f, "bad PLTE"),
+      Self::BadPaletteIndex => write!(f, "a palette index is past the palette"),
+      Self::BadTrns => write!(f, "bad tRNS"),
+      Self::MissingIdat => write!(f, "no IDAT"),
+      Self::Inflate(e) => write!(f, "bad image data: {}", e),
+      Self::BadFilterType(t) => write!(f, "bad filter type {}", t),
+      Self::BadImageDataLength => {
+        write!(f, "the image data is the wrong length")
+      }
+      Self::TooLarge => write!(f, "the image is too large"),
+    }
+  }
+}
+impl std::error::Error for PngError {}
+impl From<InflateError> for PngError {
+  fn from(e: InflateError) -> Self {
+    Self::Inflate(e)
+  }
+}
+
+/// The passes of Adam7 interlacing: the first pixel, and the steps between
+/// pixels.
+const ADAM7: [(u32, u32, u32, u32); 7] = [
+  (0, 0, 8, 8),
+  (4, 0, 8, 8),
+  (0, 4, 4, 8),
+  (2, 0, 4, 4),
+  (0, 2, 2, 4),
+  (1, 0, 2, 2),
+  (0, 1, 1, 2),
+];
+
+/// Reverses the filter of a scanline in place.
+fn unfilter_row(
+  filter: u8, row: &mut [u8], prior: &[u8], bpp: usize,
+) -> Result<(), PngError> {
+  for i in 0..row.len() {
+    let a = if i >= bpp { row[i - bpp] } else { 0 };
+    let b = prior[i];
+    let c = if i >= bpp { prior[i - bpp] } else { 0 };
+    let predicted = match filter {
+      0 => 0,
+      1 => a,
+      2 => b,
+      3 => ((a as u16 + b as u16) / 2) as u8,
+      4 => paeth(a, b, c),
+      _ => return Err(PngError::BadFilterType(filter)),
+    };
+    row[i] = row[i].wrapping_add(predicted);
+  }
+  Ok(())
+}
+
+/// The ways that samples become RGBA8.
+struct Converter<'p> {
+  info: &'p PngInfo,
+  palette: &'p [[u8; 3]],
+  /// The alpha of each palette entry, or the raw gray or RGB samples of the
+  /// transparent color.
+  trns: &'p [u8],
+}
+impl Converter<'_> {
+  /// Gets sample `i` of a scanline.
+  fn sample(&self, row: &[u8], i: usize) -> u16 {
+    match self.info.bit_depth {
+      16 => u16::from_be_bytes([row[i * 2], row[i * 2 + 1]]),
+      8 => row[i] as u16,
+      depth => {
+        let per_byte = 8 / depth as usize;
+        let shift = 8 - depth as usize * (i % per_byte + 1);
+        ((row[i / per_byte] >> shift) & ((1 << depth) - 1)) as u16
+      }
+    }
+  }
+
+  fn scale(&self, sample: u16) -> u8 {
+    match self.info.bit_depth {
+      16 => ((sample as u32 * 255 + 32767) / 65535) as u8,
+      depth => (sample as u32 * 255 / ((1 << depth) - 1)) as u8,
+    }
+  }
+
+  /// The transparent color of a gray or RGB image, as raw samples.
+  fn trns_sample(&self, i: usize) -> Option<u16> {
+    let bytes = self.trns.get(i * 2..i * 2 + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+  }
+
+  /// Converts pixel `x` of a scanline.
+  fn rgba(&self, row: &[u8], x: usize) -> Result<[u8; 4], PngError> {
+    let channels = self.info.color_type.channels();
+    let s = |c: usize| self.sample(row, x * channels + c);
+    Ok(match self.info.color_type {
+      PngColorType::Gray => {
+        let g = self.scale(s(0));
+        let alpha = if self.trns_sample(0) == Some(s(0)) { 0 } else { 255 };
+        [g, g, g, alpha]
+      }
+      PngColorType::Rgb => {
+        let transparent =
+          (0..3).all(|c| self.trns_sample(c).is_some_and(|t| t == s(c)));
+        [
+          self.scale(s(0)),
+          self.scale(s(1)),
+          self.scale(s(2)),
+          if transparent { 0 } else { 255 },
+        ]
+      }
+      PngColorType::Indexed => {
+        let index = s(0) as usize;
+        let [r, g, b] =
+          *self.palette.get(index).ok_or(PngError::BadPaletteIndex)?;
+        [r, g, b, self.trns.get(index).copied().unwrap_or(255)]
+      }
+      PngColorType::GrayAlpha => {
+        let g = self.scale(s(0));
+        [g, g, g, self.scale(s(1))]
+      }
+      PngColorType::Rgba => {
+        [self.scale(s(0)), self.scale(s(1)), self.scale(s(2)), self.scale(s(3))]
+      }
+    })
+  }
+}
+
+/// Reads the fields of an `IHDR` chunk.
+fn parse_ihdr(data: &[u8]) -> Result<PngInfo, PngError> {
+  let data: &[u8; 13] = data.try_into().map_err(|_| PngError::BadIhdr)?;
+  let width = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+  let height = u32::from_be_bytes([data[4], data[5], data[6], data[7]]);
+  let [bit_depth, color_type, compression, filter, interlace] =
+    [data[8], data[9], data[10], data[11], data[12]];
+  let color_type =
+    PngColorType::from_raw(color_type).ok_or(PngError::BadIhdr)?;
+  let depth_ok = match color_type {
+    PngColorType::Gray => matches!(bit_depth, 1 | 2 | 4 | 8 | 16),
+    PngColorType::Indexed => matches!(bit_depth, 1 | 2 | 4 | 8),
+    _ => matches!(bit_depth, 8 | 16),
+  };
+  let size_ok = (1..=i32::MAX as u32).contains(&width)
+    && (1..=i32::MAX as u32).contains(&height);
+  if !depth_ok || !size_ok || compression != 0 || filter != 0 || interlace > 1 {
+    return Err(PngError::BadIhdr);
+  }
+  Ok(PngInfo {
+    width,
+    height,
+    bit_depth,
+    color_type,
+    interlaced: interlace == 1,
+    gamma: None,
+    srgb_intent: None,
+  })
+}
+
+/// Decodes a PNG into RGBA8 pixels.
+///
+/// Every color type and bit depth works, with or without interlacing. The
+/// CRC of every chunk is checked, and unknown ancillary chunks are skipped.
+pub fn decode_png(bytes: &[u8]) -> Result<PngImage, PngError> {
+  let mut rest =
+    bytes.strip_prefix(&PNG_SIGNATURE).ok_or(PngError::BadSignature)?;
+  let mut info: Option<PngInfo> = None;
+  let mut palette: Option<Vec<[u8; 3]>> = None;
+  let mut trns: Option<Vec<u8>> = None;
+  let mut idat = Vec::new();
+  // `None` before the first `IDAT`, `Some(true)` while in the `IDAT` chunks.
+  let mut in_idat: Option<bool> = None;
+  loop {
+    if rest.len() < 12 {
+      return Err(PngError::UnexpectedEnd);
+    }
+    let len = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+    let chunk = [rest[4], rest[5], rest[6], rest[7]];
+    if len > rest.len() - 12 {
+      return Err(PngError::UnexpectedEnd);
+    }
+    let data = &rest[8..8 + len];
+    let crc = &rest[8 + len..12 + len];
+    if crc != crc32(&rest[4..8 + len]).to_be_bytes() {
+      return Err(PngError::BadChunkCrc { chunk });
+    }
+    rest = &rest[12 + len..];
+    let out_of_order = PngError::BadChunkOrder { chunk };
+    if in_idat == Some(true) && &chunk != b"IDAT" {
+      in_idat = Some(false);
+    }
+    let info = match (&chunk, info.as_mut()) {
+      (b"IHDR", None) => {
+        info = Some(parse_ihdr(data)?);
+        continue;
+      }
+      (_, None) => return Err(PngError::MissingIhdr),
+      (b"IHDR", Some(_)) => return Err(out_of_order),
+      (_, Some(info)) => info,
+    };
+    match &chunk {
+      b"PLTE" => {
+        if palette.is_some() || in_idat.is_some() || trns.is_some() {
+          return Err(out_of_order);
+        }
+        let entries = data.len() / 3;
+        let max = match info.color_type {
+          PngColorType::Indexed => 1 << info.bit_depth,
+          PngColorType::Rgb | PngColorType::Rgba => 256,
+          PngColorType::Gray | PngColorType::GrayAlpha => 0,
+        };
+        if !data.len().is_multiple_of(3) || entries == 0 || entries > max {
+          return Err(PngError::BadPalette);
+        }
+        palette =
+          Some(data.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect());
+      }
+      b"tRNS" => {
+        if trns.is_some() || in_idat.is_some() {
+          return Err(PngError::BadChunkOrder { chunk });
+        }
+        let len_ok = match info.color_type {
+          PngColorType::Gray => data.len() == 2,
+          PngColorType::Rgb => data.len() == 6,
+          PngColorType::Indexed => {
+            let palette = palette.as_ref().ok_or(out_of_order)?;
+            data.len() <= palette.len()
+          }
+          PngColorType::GrayAlpha | PngColorType::Rgba => false,
+        };
+        if !len_ok {
+          return Err(PngError::BadTrns);
+        }
+        trns = Some(data.to_vec());
+      }
+      b"gAMA" if data.len() == 4 => {
+        info.gamma =
+          Some(u32::from_be_bytes([data[0], data[1], data[2], data[3]]));
+      }
+      b"sRGB" if data.len() == 1 => info.srgb_intent = Some(data[0]),
+      b"IDAT" => {
+        if in_idat == Some(false) {
+          return Err(out_of_order);
+        }
+        in_idat = Some(true);
+        idat.extend_from_slice(data);
+      }
+      b"IEND" => break,
+      // bit 5 of the first byte is set for ancillary chunks.
+      _ if chunk[0] & 0x20 == 0 => {
+        return Err(PngError::UnknownCriticalChunk { chunk })
+      }
+      _ => (),
+    }
+  }
+  let info = info.ok_or(PngError::MissingIhdr)?;
+  if in_idat.is_none() {
+    return Err(PngError::MissingIdat);
+  }
+  let palette = palette.unwrap_or_default();
+  if info.color_type == PngColorType::Indexed && palette.is_empty() {
+    return Err(PngError::MissingPalette);
+  }
+
+  let bits_per_pixel = info.color_type.channels() * info.bit_depth as usize;
+  let bpp = bits_per_pixel.div_ceil(8);
+  let passes: &[(u32, u32, u32, u32)] =
+    if info.interlaced { &ADAM7 } else { &[(0, 0, 1, 1)] };
+  // the size of each pass, and the filtered data of all of them.
+  let mut raw_len = 0_usize;
+  let mut pass_sizes = Vec::with_capacity(passes.len());
+  for &(x0, y0, dx, dy) in passes {
+    let width = (info.width - x0.min(info.width)).div_ceil(dx) as usize;
+    let height = (info.height - y0.min(info.height)).div_ceil(dy) as usize;
+    let row_len = width
+      .checked_mul(bits_per_pixel)
+      .map(|bits| bits.div_ceil(8))
+      .ok_or(PngError::TooLarge)?;
+    if width > 0 {
+      raw_len = (row_len + 1)
+        .checked_mul(height)
+        .and_then(|len| len.checked_add(raw_len))
+        .ok_or(PngError::TooLarge)?;
+    }
+    pass_sizes.push((width, height, row_len));
+  }
+  let pixels_len = (info.width as usize)
+    .checked_mul(info.height as usize)
+    .and_then(|len| len.checked_mul(4))
+    .ok_or(PngError::TooLarge)?;
+  // one more byte than needed, so that too much data can be told apart from a
+  // full output.
+  let mut raw = match zlib_decompress(&idat, raw_len + 1) {
+    Err(InflateError::TooLong) => return Err(PngError::BadImageDataLength),
+    result => result?,
+  };
+  if raw.len() != raw_len {
+    return Err(PngError::BadImageDataLength);
+  }
+
+  let converter = Converter {
+    info: &info,
+    palette: &palette,
+    trns: trns.as_deref().unwrap_or(&[]),
+  };
+  let mut pixels = vec![0; pixels_len];
+  let mut pass_start = 0;
+  for (&(x0, y0, dx, dy), &(width, height, row_len)) in
+    passes.iter().zip(pass_sizes.iter())
+  {
+    if width == 0 || height == 0 {
+      continue;
+    }
+    let pass = &mut raw[pass_start..pass_start + (row_len + 1) * height];
+    pass_start += (row_len + 1) * height;
+    let mut prior = vec![0; row_len];
+    for (y, line) in pass.chunks_exact_mut(row_len + 1).enumerate() {
+      let (filter, row) = line.split_first_mut().unwrap();
+      unfilter_row(*filter, row, &prior, bpp)?;
+      for x in 0..width {
+        let out_x = x0 as usize + x * dx as usize;
+        let out_y = y0 as usize + y * dy as usize;
+        let i = (out_y * info.width as usize + out_x) * 4;
+        pixels[i..i + 4].copy_from_slice(&converter.rgba(row, x)?);
+      }
+      prior.copy_from_slice(row);
+    }
+  }
+  Ok(PngImage { info, pixels })
+}
+
+#[test]
+fn test_crc32() {
+  assert_eq!(crc32(b""), 0);
+  assert_eq!(crc32(b"123456789"), 0xCBF43926);
+  assert_eq!(crc32(b"IEND"), 0xAE426082);
+}
+
+#[test]
+fn test_encode_png() {
+  let pixels = [255, 0, 0, 255, 0, 255, 0, 128];
+  let image =
+    ImageView::new(2, 1, PixelFormat::Rgba8, RowOrder::TopDown, &pixels)
+      .unwrap();
+  let png = encode_png(image);
+  assert_eq!(png[..8], PNG_SIGNATURE);
+  assert_eq!(png[8..16], *b"\0\0\0\x0dIHDR");
+  assert_eq!(png[16..29], [0, 0, 0, 2, 0, 0, 0, 1, 8, 6, 0, 0, 0]);
+  assert_eq!(png[png.len() - 12..], *b"\0\0\0\0IEND\xAE\x42\x60\x82");
+  let idat = &png[33..png.len() - 12];
+  let len = u32::from_be_bytes(idat[..4].try_into().unwrap()) as usize;
+  assert_eq!(idat[4..8], *b"IDAT");
+  assert_eq!(len + 12, idat.len());
+  let crc = u32::from_be_bytes(idat[8 + len..].try_into().unwrap());
+  assert_eq!(crc, crc32(&idat[4..8 + len]));
+  // the zlib header, and the Adler-32 of the filtered rows on the end.
+  assert_eq!(idat[8..10], [0x78, 0x01]);
+  // every filter gives the same sum for this row, so it's left unfiltered.
+  let filtered = [0, 255, 0, 0, 255, 0, 255, 0, 128];
+  assert_eq!(idat[4 + len..8 + len], adler32(&filtered).to_be_bytes());
+}
+
+#[test]
+fn test_decode_png_round_trip() {
+  let pixel = |i: u32| {
+    [(i * 15) as u8, (i * 19) as u8, (i % 7) as u8, (i % 3 * 100) as u8]
+  };
+  let rgba: Vec<u8> = (0..17 * 13).flat_map(pixel).collect();
+  let rgb: Vec<u8> = (0..17 * 13)
+    .flat_map(|i| [pixel(i)[0], pixel(i)[1], pixel(i)[2]])
+    .collect();
+  for (format, pixels) in
+    [(PixelFormat::Rgb8, &rgb), (PixelFormat::Rgba8, &rgba)]
+  {
+    let image =
+      ImageView::new(17, 13, format, RowOrder::BottomUp, pixels).unwrap();
+    let decoded = decode_png(&encode_png(image)).unwrap();
+    assert_eq!(decoded.info.width, 17);
+    assert_eq!(decoded.info.bit_depth, 8);
+    for y in 0..13 {
+      let expected: Vec<u8> = image
+        .row(y)
+        .chunks_exact(format.bytes_per_pixel())
+        .flat_map(|p| [p[0], p[1], p[2], p.get(3).copied().unwrap_or(255)])
+        .collect();
+      assert_eq!(decoded.image().row(y), expected);
+    }
+  }
+}
+
+/// Decodes every image of the PngSuite, in `tests/pngsuite`.
+///
+/// Names that start with `x` have to give an error. Other images have to
+/// decode, and match the `.rgba` file next to them if there is one. An
+/// interlaced image (`basi*`) has to match the plain one (`basn*`).
+///
+/// The `.rgba` files were made with libpng, expanded to RGBA8 with 16-bit
+/// samples scaled down and gamma ignored, see `tests/pngsuite/README.md`.
+#[test]
+fn test_decode_png_suite() {
+  let dir =
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/pngsuite");
+  let mut decoded = std::collections::BTreeMap::new();
+  for entry in std::fs::read_dir(&dir).unwrap() {
+    let path = entry.unwrap().path();
+    if path.extension().is_none_or(|e| e != "png") {
+      continue;
+    }
+    let name = path.file_stem().unwrap().to_str().unwrap().to_string();
+    let result = decode_png(&std::fs::read(&path).unwrap());
+    if name.starts_with('x') {
+      assert!(result.is_err(), "{} decoded", name);
+      continue;
+    }
+    let png = result.unwrap_or_else(|e| panic!("{}: {}", name, e));
+    if let Ok(expected) = std::fs::read(path.with_extension("rgba")) {
+      assert!(png.pixels == expected, "{} has the wrong pixels", name);
+    }
+    decoded.insert(name, png);
+  }
+  assert!(decoded.len() >= 30, "{} images", decoded.len());
+  for (name, png) in decoded.iter() {
+    if let Some(plain) = name.strip_prefix("basi") {
+      assert!(png.info.interlaced, "{}", name);
+      assert_eq!(
+        png.pixels,
+        decoded[&format!("basn{}", plain)].pixels,
+        "{}",
+        name
+      );
+    }
+  }
+}
+
+/// The type and data of each chunk of a PNG.
+#[cfg(test)]
+type PngChunks = Vec<([u8; 4], Vec<u8>)>;
+
+/// Splits a PNG into its chunks.
+#[cfg(test)]
+fn png_chunks(bytes: &[u8]) -> PngChunks {
+  let mut rest = &bytes[PNG_SIGNATURE.len()..];
+  let mut chunks = Vec::new();
+  while !rest.is_empty() {
+    let len = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+    chunks
+      .push(([rest[4], rest[5], rest[6], rest[7]], rest[8..8 + len].to_vec()));
+    rest = &rest[12 + len..];
+  }
+  chunks
+}
+
+/// Joins chunks into a PNG, with the CRCs they need.
+#[cfg(test)]
+fn png_from_chunks(chunks: &PngChunks) -> Vec<u8> {
+  let mut out = PNG_SIGNATURE.to_vec();
+  for (kind, data) in chunks.iter() {
+    write_chunk(&mut out, kind, data);
+  }
+  out
+}
+
+#[test]
+fn test_decode_png_errors() {
+  let dir =
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/pngsuite");
+  let read = |name: &str| std::fs::read(dir.join(name)).unwrap();
+  let decode = |name: &str| decode_png(&read(name));
+
+  // the broken files of the PngSuite.
+  for name in ["xs1n0g01", "xs2n0g01", "xs4n0g01", "xs7n0g01"] {
+    assert_eq!(decode(&format!("{}.png", name)), Err(PngError::BadSignature));
+  }
+  // a signature that's been through a text mode conversion.
+  assert_eq!(decode("xcrn0g04.png"), Err(PngError::BadSignature));
+  assert_eq!(decode("xlfn0g04.png"), Err(PngError::BadSignature));
+  assert_eq!(
+    decode("xhdn0g08.png"),
+    Err(PngError::BadChunkCrc { chunk: *b"IHDR" })
+  );
+  // color types 1 and 9, and bit depths 0, 3, and 99.
+  for name in ["xc1n0g08", "xc9n2c08", "xd0n2c08", "xd3n2c08", "xd9n2c08"] {
+    assert_eq!(decode(&format!("{}.png", name)), Err(PngError::BadIhdr));
+  }
+  assert_eq!(decode("xdtn0g01.png"), Err(PngError::MissingIdat));
+  assert_eq!(
+    decode("xcsn0g01.png"),
+    Err(PngError::BadChunkCrc { chunk: *b"IDAT" })
+  );
+
+  // other problems, made by changing good files. The CRCs are fixed, so
+  // each file only has the one problem.
+  let chunks = png_chunks(&read("basn0g08.png"));
+  let change = |f: &dyn Fn(&mut PngChunks)| {
+    let mut chunks = chunks.clone();
+    f(&mut chunks);
+    decode_png(&png_from_chunks(&chunks))
+  };
+  // with all of the image data in one `IDAT`.
+  let change_image_data = |f: &dyn Fn(&mut Vec<u8>)| {
+    change(&|chunks| {
+      let mut zlib = Vec::new();
+      for (_, data) in chunks.iter().filter(|(kind, _)| kind == b"IDAT") {
+        zlib.extend_from_slice(data);
+      }
+      let mut raw = zlib_decompress(&zlib, usize::MAX).unwrap();
+      f(&mut raw);
+      chunks.retain(|(kind, _)| kind != b"IDAT");
+      let end = chunks.len() - 1;
+      chunks.insert(end, (*b"IDAT", zlib_compress(&raw)));
+    })
+  };
+  assert!(change(&|_| ()).is_ok());
+  assert_eq!(change(&|c| c[0].1[0..4].fill(0)), Err(PngError::BadIhdr));
+  assert_eq!(change(&|c| c[0].1[12] = 2), Err(PngError::BadIhdr));
+  assert_eq!(
+    change(&|c| c.truncate(c.len() - 1)),
+    Err(PngError::UnexpectedEnd)
+  );
+  assert_eq!(change(&|c| drop(c.remove(0))), Err(PngError::MissingIhdr));
+  assert_eq!(
+    change(&|c| c.insert(1, (*b"CRIT", vec![]))),
+    Err(PngError::UnknownCriticalChunk { chunk: *b"CRIT" })
+  );
+  assert_eq!(
+    change(&|c| c.insert(1, (*b"tRNS", vec![0; 3]))),
+    Err(PngError::BadTrns)
+  );
+  assert_eq!(
+    change_image_data(&|raw| raw[0] = 5),
+    Err(PngError::BadFilterType(5))
+  );
+  assert_eq!(
+    change_image_data(&|raw| raw.truncate(raw.len() - 1)),
+    Err(PngError::BadImageDataLength)
+  );
+  assert_eq!(
+    change_image_data(&|raw| raw.push(0)),
+    Err(PngError::BadImageDataLength)
+  );
+  assert_eq!(
+    change(&|c| {
+      let idat = c.iter_mut().find(|(kind, _)| kind == b"IDAT").unwrap();
+      *idat.1.last_mut().unwrap() ^= 1;
+    }),
+    Err(PngError::Inflate(InflateError::BadChecksum))
+  );
+  let indexed = png_chunks(&read("basn3p04.png"));
+  let change_indexed = |f: &dyn Fn(&mut PngChunks)| {
+    let mut chunks = indexed.clone();
+    f(&mut chunks);
+    decode_png(&png_from_chunks(&chunks))
+  };
+  let plte = |c: &mut PngChunks| -> usize {
+    c.iter().position(|(kind, _)| kind == b"PLTE").unwrap()
+  };
+  assert!(change_indexed(&|_| ()).is_ok());
+  assert_eq!(
+    change_indexed(&|c| {
+      let i = plte(c);
+      c.remove(i);
+    }),
+    Err(PngError::MissingPalette)
+  );
+  assert_eq!(
+    change_indexed(&|c| {
+      let i = plte(c);
+      c[i].1.truncate(3);
+    }),
+    Err(PngError::BadPaletteIndex)
+  );
+  assert_eq!(
+    change_indexed(&|c| {
+      let i = plte(c);
+      let len = c[i].1.len() / 3 + 1;
+      c.insert(i + 1, (*b"tRNS", vec![0; len]));
+    }),
+    Err(PngError::BadTrns)
+  );
+
+  // metadata.
+  assert_eq!(decode("g03n0g16.png").unwrap().info.gamma, Some(35000));
+  assert_eq!(decode("g25n2c08.png").unwrap().info.gamma, Some(250000));
+  let srgb = change(&|c| c.insert(1, (*b"sRGB", vec![0]))).unwrap().info;
+  assert_eq!((srgb.gamma, srgb.srgb_intent), (Some(100000), Some(0)));
+  let info = decode("basi3p02.png").unwrap().info;
+  assert_eq!(
+    (info.bit_depth, info.color_type, info.interlaced),
+    (2, PngColorType::Indexed, true)
+  );
+
+  // cutting off or changing any byte of a file gives an error, not a panic.
+  let file = std::fs::read(dir.join("basi3p04.png")).unwrap();
+  for len in 0..file.len() {
+    assert!(decode_png(&file[..len]).is_err(), "{} bytes decoded", len);
+  }
+  let mut changed = file.clone();
+  for i in 0..file.len() {
+    for bit in [0x01, 0x80] {
+      changed[i] ^= bit;
+      let _ = decode_png(&changed);
+      changed[i] ^= bit;
+    }
+  }
+}
@@ -0,0 +1,632 @@
+//! The zlib format (RFC 1950) and deflate compression (RFC 1951).
+//!
+//! Decompression handles stored, fixed Huffman, and dynamic Huffman blocks.
+//! Compression finds repeats with a hash chain and writes them with the fixed
+//! Huffman codes, or writes stored blocks if that would be smaller. That's not
+//! the smallest output possible, but it's simple and it does well on images.
+
+/// The Adler-32 checksum of zlib.
+pub fn adler32(bytes: &[u8]) -> u32 {
+  const MOD: u32 = 65521;
+  let (mut a, mut b) = (1_u32, 0_u32);
+  // 5552 bytes is the most that can be added up before `b` could overflow.
+  for chunk in bytes.chunks(5552) {
+    for &byte in chunk {
+      a += byte as u32;
+      b += a;
+    }
+    a %= MOD;
+    b %= MOD;
+  }
+  (b << 16) | a
+}
+
+/// The first length of each length code (257 to 285).
+pub(crate) const LENGTH_BASE: [u16; 29] = [
+  3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67,
+  83, 99, 115, 131, 163, 195, 227, 258,
+];
+/// The extra bits of each length code.
+pub(crate) const LENGTH_EXTRA: [u8; 29] = [
+  0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5,
+  5, 5, 0,
+];
+/// The first distance of each distance code.
+pub(crate) const DISTANCE_BASE: [u16; 30] = [
+  1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513,
+  769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+/// The extra bits of each distance code.
+pub(crate) const DISTANCE_EXTRA: [u8; 30] = [
+  0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11,
+  11, 12, 12, 13, 13,
+];
+
+const WINDOW_SIZE: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// How many earlier positions to try for each match.
+const MAX_CHAIN: usize = 64;
+const HASH_BITS: u32 = 15;
+
+/// Writes bits starting from the least significant bit of each byte.
+struct BitWriter {
+  out: Vec<u8>,
+  bits: u64,
+  count: u32,
+}
+impl BitWriter {
+  fn write(&mut self, value: u32, count: u32) {
+    self.bits |= (value as u64) << self.count;
+    self.count += count;
+    while self.count >= 8 {
+      self.out.push(self.bits as u8);
+      self.bits >>= 8;
+      self.count -= 8;
+    }
+  }
+
+  /// Huffman codes go most significant bit first.
+  fn write_code(&mut self, code: u32, count: u32) {
+    self.write(code.reverse_bits() >> (32 - count), count);
+  }
+
+  fn align_to_byte(&mut self) {
+    if self.count > 0 {
+      self.write(0, 8 - self.count);
+    }
+  }
+}
+
+/// A literal byte, or a repeat of earlier bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+  Literal(u8),
+  Match { length: u16, distance: u16 },
+}
+
+/// Finds repeats, with a hash of the next 3 bytes leading to the earlier
+/// positions with that hash.
+fn lz77(bytes: &[u8]) -> Vec<Token> {
+  let hash = |i: usize| {
+    let v = (bytes[i] as u32) << 16
+      | (bytes[i + 1] as u32) << 8
+      | bytes[i + 2] as u32;
+    (v.wrapping_mul(2654435761) >> (32 - HASH_BITS)) as usize
+  };
+  // the most recent position + 1 of each hash, and the one before each position.
+  let mut head = vec![0_usize; 1 << HASH_BITS];
+  let mut prev = vec![0_usize; WINDOW_SIZE];
+  let insert = |i: usize, head: &mut [usize], prev: &mut [usize]| {
+    if i + MIN_MATCH <= bytes.len() {
+      let h = hash(i);
+      prev[i % WINDOW_SIZE] = head[h];
+      head[h] = i + 1;
+    }
+  };
+  let mut tokens = Vec::new();
+  let mut i = 0;
+  while i < bytes.len() {
+    let mut best = (0, 0);
+    if i + MIN_MATCH <= bytes.len() {
+      let max_length = (bytes.len() - i).min(MAX_MATCH);
+      let mut candidate = head[hash(i)];
+      for _ in 0..MAX_CHAIN {
+        if candidate == 0 || i - (candidate - 1) > WINDOW_SIZE - 1 {
+          break;
+        }
+        let start = candidate - 1;
+        let length = bytes[start..]
+          .iter()
+          .zip(&bytes[i..i + max_length])
+          .take_while(|(a, b)| a == b)
+          .count();
+        if length > best.0 {
+          best = (length, i - start);
+          if length == max_length {
+            break;
+          }
+        }
+        let next = prev[start % WINDOW_SIZE];
+        // a chain entry that was overwritten by a newer position ends it.
+        if next >= candidate {
+          break;
+        }
+        candidate = next;
+      }
+    }
+    let (length, distance) = best;
+    if length >= MIN_MATCH {
+      tokens.push(Token::Match {
+        length: length as u16,
+        distance: distance as u16,
+      });
+      for j in i..i + length {
+        insert(j, &mut head, &mut prev);
+      }
+      i += length;
+    } else {
+      tokens.push(Token::Literal(bytes[i]));
+      insert(i, &mut head, &mut prev);
+      i += 1;
+    }
+  }
+  tokens
+}
+
+/// The code and extra bits of a length or distance.
+fn find_code(value: u16, bases: &[u16]) -> usize {
+  bases.iter().rposition(|&base| base <= value).unwrap()
+}
+
+/// Writes a literal/length symbol with the fixed Huffman code.
+fn write_fixed_symbol(w: &mut BitWriter, symbol: u32) {
+  match symbol {
+    0..=143 => w.write_code(0x30 + symbol, 8),
+    144..=255 => w.write_code(0x190 + symbol - 144, 9),
+    256..=279 => w.write_code(symbol - 256, 7),
+    _ => w.write_code(0xC0 + symbol - 280, 8),
+  }
+}
+
+/// Compresses into raw deflate data.
+pub fn deflate(bytes: &[u8]) -> Vec<u8> {
+  let mut w = BitWriter { out: Vec::new(), bits: 0, count: 0 };
+  // one block with the fixed codes.
+  w.write(1, 1);
+  w.write(0b01, 2);
+  for token in lz77(bytes) {
+    match token {
+      Token::Literal(byte) => write_fixed_symbol(&mut w, byte as u32),
+      Token::Match { length, distance } => {
+        let code = find_code(length, &LENGTH_BASE);
+        write_fixed_symbol(&mut w, 257 + code as u32);
+        w.write((length - LENGTH_BASE[code]) as u32, LENGTH_EXTRA[code] as u32);
+        let code = find_code(distance, &DISTANCE_BASE);
+        w.write_code(code as u32, 5);
+        w.write(
+          (distance - DISTANCE_BASE[code]) as u32,
+          DISTANCE_EXTRA[code] as u32,
+        );
+      }
+    }
+  }
+  write_fixed_symbol(&mut w, 256);
+  w.align_to_byte();
+  // each stored block has 5 bytes of header.
+  let stored_size = bytes.len() + 5 * (bytes.len() / 0xFFFF + 1);
+  if w.out.len() <= stored_size {
+    return w.out;
+  }
+  let mut out = Vec::with_capacity(stored_size);
+  let mut blocks = bytes.chunks(0xFFFF).peekable();
+  if blocks.peek().is_none() {
+    out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+  }
+  while let Some(block) = blocks.next() {
+    out.push(blocks.peek().is_none() as u8);
+    let len = block.len() as u16;
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&(!len).to_le_bytes());
+    out.extend_from_slice(block);
+  }
+  out
+}
+
+/// Compresses into the zlib format: a header, deflate data, and the Adler-32
+/// of the uncompressed bytes.
+pub fn zlib_compress(bytes: &[u8]) -> Vec<u8> {
+  // deflate with a 32K window, and the check bits that make it a multiple of
+  // 31.
+  let mut out = vec![0x78, 0x01];
+  out.extend_from_slice(&deflate(bytes));
+  out.extend_from_slice(&adler32(bytes).to_be_bytes());
+  out
+}
+
+/// An error from [`inflate`] or [`zlib_decompress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InflateError {
+  /// The data ended before the last block did.
+  UnexpectedEnd,
+  /// A block type of 3.
+  BadBlockType,
+  /// The length of a stored block doesn't match its complement.
+  BadStoredLength,
+  /// The code lengths of a dynamic block don't make a usable code.
+  BadCodeLengths,
+  /// A code that isn't in the block's Huffman code, or a symbol (like a
+  /// length code of 286) that can't be used.
+  BadSymbol,
+  /// A distance that goes back before the start of the output.
+  BadDistance,
+  /// More output than the limit.
+  TooLong,
+  /// The zlib header is wrong, or asks for a preset dictionary.
+  BadHeader,
+  /// The Adler-32 of the output doesn't match.
+  BadChecksum,
+}
+impl core::fmt::Display for InflateError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.write_str(match self {
+      Self::UnexpectedEnd => "the compressed data ended early",
+      Self::BadBlockType => "bad deflate block type",
+      Self::BadStoredLength => "bad stored block length",
+      Self::BadCodeLengths => "bad Huffman code lengths",
+      Self::BadSymbol => "bad Huffman code",
+      Self::BadDistance => "a distance goes back too far",
+      Self::TooLong => "the decompressed data is too long",
+      Self::BadHeader => "bad zlib header",
+      Self::BadChecksum => "the Adler-32 checksum doesn't match",
+    })
+  }
+}
+impl std::error::Error for InflateError {}
+
+/// Reads bits starting from the least significant bit of each byte.
+struct BitReader<'b> {
+  bytes: &'b [u8],
+  pos: usize,
+  bit: u32,
+}
+impl BitReader<'_> {
+  fn read(&mut self, count: u32) -> Result<u32, InflateError> {
+    let mut value = 0;
+    for i in 0..count {
+      let byte =
+        *self.bytes.get(self.pos).ok_or(InflateError::UnexpectedEnd)?;
+      value |= (((byte >> self.bit) & 1) as u32) << i;
+      self.bit += 1;
+      if self.bit == 8 {
+        self.bit = 0;
+        self.pos += 1;
+      }
+    }
+    Ok(value)
+  }
+
+  fn align_to_byte(&mut self) {
+    if self.bit > 0 {
+      self.bit = 0;
+      self.pos += 1;
+    }
+  }
+}
+
+/// A canonical Huffman code, as the number of codes of each length and the
+/// symbols in code order.
+struct Huffman {
+  counts: [u16; 16],
+  symbols: Vec<u16>,
+}
+impl Huffman {
+  /// Symbols with a length of 0 aren't in the code. Codes that use more than
+  /// all of the code space are an error, and codes that don't use all of it
+  /// are fine until a missing code is read.
+  fn new(lengths: &[u8]) -> Result<Self, InflateError> {
+    let mut counts = [0_u16; 16];
+    for &len in lengths {
+      counts[len as usize] += 1;
+    }
+    counts[0] = 0;
+    let mut left = 1_i32;
+    for &count in &counts[1..] {
+      left = left * 2 - count as i32;
+      if left < 0 {
+        return Err(InflateError::BadCodeLengths);
+      }
+    }
+    let mut offsets = [0_u16; 16];
+    for len in 1..15 {
+      offsets[len + 1] = offsets[len] + counts[len];
+    }
+    let mut symbols = vec![0; lengths.len()];
+    for (symbol, &len) in lengths.iter().enumerate() {
+      if len != 0 {
+        symbols[offsets[len as usize] as usize] = symbol as u16;
+        offsets[len as usize] += 1;
+      }
+    }
+    Ok(Self { counts, symbols })
+  }
+
+  /// Reads one bit at a time until the bits so far are a code.
+  fn decode(&self, r: &mut BitReader) -> Result<u16, InflateError> {
+    let (mut code, mut first, mut index) = (0_i32, 0_i32, 0_i32);
+    for len in 1..16 {
+      code |= r.read(1)? as i32;
+      let count = self.counts[len] as i32;
+      if code - first < count {
+        return Ok(self.symbols[(index + code - first) as usize]);
+      }
+      index += count;
+      first = (first + count) << 1;
+      code <<= 1;
+    }
+    Err(InflateError::BadSymbol)
+  }
+}
+
+/// The codes of a block with the fixed Huffman codes.
+fn fixed_codes() -> (Huffman, Huffman) {
+  let mut lengths = [0_u8; 288];
+  lengths[..144].fill(8);
+  lengths[144..256].fill(9);
+  lengths[256..280].fill(7);
+  lengths[280..].fill(8);
+  (Huffman::new(&lengths).unwrap(), Huffman::new(&[5; 30]).unwrap())
+}
+
+/// Reads the code lengths of a dynamic block, and makes the codes.
+fn dynamic_codes(
+  r: &mut BitReader,
+) -> Result<(Huffman, Huffman), InflateError> {
+  const ORDER: [usize; 19] =
+    [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+  let literal_count = r.read(5)? as usize + 257;
+  let distance_count = r.read(5)? as usize + 1;
+  let length_count = r.read(4)? as usize + 4;
+  if literal_count > 286 || distance_count > 30 {
+    return Err(InflateError::BadCodeLengths);
+  }
+  let mut length_lengths = [0_u8; 19];
+  for &i in &ORDER[..length_count] {
+    length_lengths[i] = r.read(3)? as u8;
+  }
+  let length_code = Huffman::new(&length_lengths)?;
+  let mut lengths = Vec::with_capacity(literal_count + distance_count);
+  while lengths.len() < literal_count + distance_count {
+    let (value, repeat) = match length_code.decode(r)? {
+      symbol @ 0..=15 => (symbol as u8, 1),
+      16 => {
+        let previous = *lengths.last().ok_or(InflateError::BadCodeLengths)?;
+        (previous, 3 + r.read(2)?)
+      }
+      17 => (0, 3 + r.read(3)?),
+      _ => (0, 11 + r.read(7)?),
+    };
+    if lengths.len() + repeat as usize > literal_count + distance_count {
+      return Err(InflateError::BadCodeLengths);
+    }
+    lengths.extend((0..repeat).map(|_| value));
+  }
+  // a block has to be able to end.
+  if lengths[256] == 0 {
+    return Err(InflateError::BadCodeLengths);
+  }
+  let (literal, distance) = lengths.split_at(literal_count);
+  Ok((Huffman::new(literal)?, Huffman::new(distance)?))
+}
+
+/// Decompresses raw deflate data, giving the output and the number of input
+/// bytes used.
+///
+/// Output past `max_len` bytes is an error, which keeps bad data from using up
+/// all the memory.
+pub fn inflate(
+  bytes: &[u8], max_len: usize,
+) -> Result<(Vec<u8>, usize), InflateError> {
+  let mut r = BitReader { bytes, pos: 0, bit: 0 };
+  let mut out: Vec<u8> = Vec::new();
+  loop {
+    let last = r.read(1)? == 1;
+    match r.read(2)? {
+      0 => {
+        r.align_to_byte();
+        let header =
+          bytes.get(r.pos..r.pos + 4).ok_or(InflateError::UnexpectedEnd)?;
+        let len = u16::from_le_bytes([header[0], header[1]]);
+        let nlen = u16::from_le_bytes([header[2], header[3]]);
+        if len != !nlen {
+          return Err(InflateError::BadStoredLength);
+        }
+        r.pos += 4;
+        let data = bytes
+          .get(r.pos..r.pos + len as usize)
+          .ok_or(InflateError::UnexpectedEnd)?;
+        if out.len() + data.len() > max_len {
+          return Err(InflateError::TooLong);
+        }
+        out.extend_from_slice(data);
+        r.pos += len as usize;
+      }
+      block_type @ (1 | 2) => {
+        let (literal, distance) =
+          if block_type == 1 { fixed_codes() } else { dynamic_codes(&mut r)? };
+        loop {
+          let symbol = literal.decode(&mut r)? as usize;
+          if symbol < 256 {
+            if out.len() == max_len {
+              return Err(InflateError::TooLong);
+            }
+            out.push(symbol as u8);
+            continue;
+          } else if symbol == 256 {
+            break;
+          }
+          let code = symbol - 257;
+          if code >= LENGTH_BASE.len() {
+            return Err(InflateError::BadSymbol);
+          }
+          let length = LENGTH_BASE[code] as usize
+            + r.read(LENGTH_EXTRA[code] as u32)? as usize;
+          let code = distance.decode(&mut r)? as usize;
+          if code >= DISTANCE_BASE.len() {
+            return Err(InflateError::BadSymbol);
+          }
+          let dist = DISTANCE_BASE[code] as usize
+            + r.read(DISTANCE_EXTRA[code] as u32)? as usize;
+          if dist > out.len() {
+            return Err(InflateError::BadDistance);
+          }
+          if out.len() + length > max_len {
+            return Err(InflateError::TooLong);
+          }
+          // the copy can overlap what it's writing, so it goes a byte at a
+          // time.
+          let start = out.len() - dist;
+          for i in 0..length {
+            out.push(out[start + i]);
+          }
+        }
+      }
+      _ => return Err(InflateError::BadBlockType),
+    }
+    if last {
+      r.align_to_byte();
+      return Ok((out, r.pos));
+    }
+  }
+}
+
+/// Decompresses the zlib format, checking the header and the Adler-32.
+///
+/// Output past `max_len` bytes is an error.
+pub fn zlib_decompress(
+  bytes: &[u8], max_len: usize,
+) -> Result<Vec<u8>, InflateError> {
+  let (cmf, flg) = match bytes {
+    [cmf, flg, ..] => (*cmf, *flg),
+    _ => return Err(InflateError::UnexpectedEnd),
+  };
+  // deflate, a window of at most 32K, the check bits, and no dictionary.
+  if cmf & 0x0F != 8
+    || cmf >> 4 > 7
+    || !(cmf as u16 * 256 + flg as u16).is_multiple_of(31)
+    || flg & 0x20 != 0
+  {
+    return Err(InflateError::BadHeader);
+  }
+  let (out, used) = inflate(&bytes[2..], max_len)?;
+  let adler =
+    bytes.get(2 + used..2 + used + 4).ok_or(InflateError::UnexpectedEnd)?;
+  if adler != adler32(&out).to_be_bytes() {
+    return Err(InflateError::BadChecksum);
+  }
+  Ok(out)
+}
+
+#[test]
+fn test_adler32() {
+  assert_eq!(adler32(b""), 1);
+  assert_eq!(adler32(b"Wikipedia"), 0x11E60398);
+  // enough bytes that the sums have to wrap.
+  assert_eq!(adler32(&[0xFF; 100_000]), 0x149A_302C);
+}
+
+#[test]
+fn test_lz77() {
+  use Token::*;
+  assert_eq!(
+    lz77(b"abcabcabcd"),
+    vec![
+      Literal(b'a'),
+      Literal(b'b'),
+      Literal(b'c'),
+      Match { length: 6, distance: 3 },
+      Literal(b'd'),
+    ]
+  );
+  let long = vec![7; 1000];
+  let tokens = lz77(&long);
+  assert_eq!(tokens[..2], [Literal(7), Match { length: 258, distance: 1 }]);
+  let total: usize = tokens
+    .iter()
+    .map(|t| match t {
+      Literal(_) => 1,
+      Match { length, .. } => *length as usize,
+    })
+    .sum();
+  assert_eq!(total, 1000);
+}
+
+#[test]
+fn test_zlib_compress() {
+  // the same bytes that zlib itself gives for these.
+  assert_eq!(zlib_compress(b""), [0x78, 0x01, 0x03, 0x00, 0, 0, 0, 1]);
+  assert_eq!(
+    zlib_compress(b"a"),
+    [0x78, 0x01, 0x4B, 0x04, 0x00, 0x00, 0x62, 0x00, 0x62]
+  );
+  let repeats = [b"hello ".as_slice(); 100].concat();
+  assert!(zlib_compress(&repeats).len() < 30);
+  // random looking bytes go in stored blocks.
+  let mut x = 1_u32;
+  let noise: Vec<u8> = (0..70_000)
+    .map(|_| {
+      x ^= x << 13;
+      x ^= x >> 17;
+      x ^= x << 5;
+      x as u8
+    })
+    .collect();
+  let out = deflate(&noise);
+  assert_eq!(out.len(), 70_000 + 10);
+  assert_eq!(out[..5], [0, 0xFF, 0xFF, 0, 0]);
+  assert_eq!(out[0xFFFF + 5..0xFFFF + 10], [1, 0x71, 0x11, 0x8E, 0xEE]);
+}
+
+#[test]
+fn test_inflate() {
+  // from zlib: stored, fixed, and dynamic blocks.
+  let stored = [
+    0x78, 0x01, 0x01, 0x03, 0x00, 0xFC, 0xFF, b'a', b'b', b'c', 0x02, 0x4D,
+    0x01, 0x27,
+  ];
+  assert_eq!(zlib_decompress(&stored, 100).unwrap(), b"abc");
+  let fixed = [0x78, 0x01, 0x4B, 0x04, 0x00, 0x00, 0x62, 0x00, 0x62];
+  assert_eq!(zlib_decompress(&fixed, 100).unwrap(), b"a");
+  let text = b"It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness";
+  let dynamic = [
+    0x78, 0xDA, 0x75, 0xC9, 0x41, 0x0A, 0x83, 0x40, 0x0C, 0x05, 0xD0, 0xAB,
+    0xFC, 0x03, 0x78, 0x09, 0x4B, 0x63, 0x0D, 0xD4, 0x06, 0x9A, 0x81, 0x59,
+    0x5B, 0x1A, 0xEB, 0x40, 0xED, 0x40, 0x33, 0x30, 0xD7, 0x17, 0x57, 0x6E,
+    0xF4, 0x6D, 0x1F, 0x07, 0xC4, 0x56, 0x11, 0x7A, 0xC2, 0x85, 0x34, 0x40,
+    0x3A, 0x04, 0x1E, 0x48, 0x1B, 0xF0, 0x3E, 0x51, 0x9E, 0x67, 0xD5, 0xDE,
+    0x68, 0x8B, 0xC8, 0x7A, 0x95, 0xE1, 0x68, 0x3A, 0x91, 0x3B, 0x6B, 0xFF,
+    0x20, 0x55, 0x2E, 0xA8, 0xA3, 0xA3, 0xCC, 0x86, 0x97, 0x79, 0x41, 0x9E,
+    0x50, 0xD2, 0x62, 0xDE, 0x20, 0xED, 0x53, 0xF3, 0xFF, 0xAC, 0xC6, 0x8F,
+    0x6D, 0x51, 0x93, 0xBF, 0xF3, 0x72, 0x34, 0x53, 0xCE, 0xDF, 0xE4, 0xF3,
+    0xCF, 0xDC, 0x57, 0xAC, 0xBF, 0x41, 0xEF,
+  ];
+  assert_eq!(dynamic[2] & 0b110, 0b100, "not a dynamic block");
+  let upper_then_lower = [text.to_ascii_uppercase(), text.to_vec()].concat();
+  assert_eq!(zlib_decompress(&dynamic, 1000).unwrap(), upper_then_lower);
+
+  // round trips with the compressor.
+  let mut x = 7_u32;
+  let mixed: Vec<u8> = (0..100_000)
+    .map(|i| {
+      x = x.wrapping_mul(1103515245).wrapping_add(12345);
+      if i % 1000 < 500 {
+        (i % 7) as u8
+      } else {
+        (x >> 24) as u8
+      }
+    })
+    .collect();
+  for data in [&b""[..], b"a", text, &mixed] {
+    assert_eq!(
+      zlib_decompress(&zlib_compress(data), data.len()).unwrap(),
+      data
+    );
+  }
+
+  // errors.
+  use InflateError::*;
+  assert_eq!(zlib_decompress(&fixed, 0), Err(TooLong));
+  assert_eq!(zlib_decompress(&fixed[..6], 100), Err(UnexpectedEnd));
+  assert_eq!(zlib_decompress(&[0x78, 0x02, 0x03, 0x00], 100), Err(BadHeader));
+  assert_eq!(zlib_decompress(&[0x78, 0xBB, 0x03, 0x00], 100), Err(BadHeader));
+  assert_eq!(inflate(&[0x07], 100), Err(BadBlockType));
+  assert_eq!(
+    inflate(&[0x01, 0x03, 0x00, 0xFC, 0xFE], 100),
+    Err(BadStoredLength)
+  );
+  // a fixed block that copies from before the start.
+  assert_eq!(inflate(&[0x03, 0x02], 100), Err(BadDistance));
+  let mut bad_adler = fixed;
+  bad_adler[8] ^= 1;
+  assert_eq!(zlib_decompress(&bad_adler, 100), Err(BadChecksum));
+}
@@ -6,18 +6,53 @@ mod macros;
 
 pub mod util;
 
-#[cfg(windows)]
+pub mod dylib;
+
 pub mod win32;
-#[cfg(windows)]
-use win32::*;
+
+#[cfg(target_os = "linux")]
+pub mod egl;
+#[cfg(target_os = "linux")]
+pub mod glx;
+// Only where `build.rs` finds the `errno` codes of the module are right.
+#[cfg(has_errno_table)]
+pub mod os_error;
+#[cfg(target_os = "linux")]
+pub mod x11;
+
+pub mod window;
+
+pub mod format;
+
+pub mod context;
+
+pub mod guard;
+
+// Without an OS API there'd be no variants at all.
+#[cfg(any(unix, windows))]
+pub mod error;
 
 pub mod gl;
 
+pub mod soft;
+
+pub mod image;
+
+pub mod golden;
+
+#[cfg(test)]
+mod test_util;
+// so that `test_util` has the same paths here and in the tests of the binary.
+#[cfg(test)]
+extern crate self as triangle_from_scratch;
+
 /// Gathers up the bytes from a pointer.
 ///
-/// The byte sequence must be valid and null-terminated.
-///
 /// The output excludes the null byte.
+///
+/// ## Safety
+///
+/// The byte sequence must be valid and null-terminated.
 pub unsafe fn gather_null_terminated_bytes(mut p: *const u8) -> Vec<u8> {
   let mut v = vec![];
   while *p != 0 {
@@ -3,130 +3,267 @@
 #[allow(unused)]
 use core::ptr::null_mut;
 
-use triangle_from_scratch::{c_str, gl::*, win32::*};
-
-struct WindowData {
-  hdc: HDC,
-  hglrc: HGLRC,
-  opengl32: HMODULE,
-  gl_clear: glClear_t,
-  gl_clear_color: glClearColor_t,
-}
-impl Default for WindowData {
-  fn default() -> Self {
-    unsafe { core::mem::zeroed() }
-  }
-}
-impl WindowData {
-  pub fn gl_get_proc_address(&self, name: &[u8]) -> *mut c_void {
-    assert!(*name.last().unwrap() == 0);
-    let p = unsafe { wglGetProcAddress(name.as_ptr().cast()) };
-    match p as usize {
-      0 | 1 | 2 | 3 | usize::MAX => unsafe {
-        GetProcAddress(self.opengl32, name.as_ptr().cast())
-      },
-      _ => p,
+use triangle_from_scratch::context::*;
+use triangle_from_scratch::window::{
+  self, run_event_loop, Event, Key, Window as _,
+};
+
+#[cfg(windows)]
+use triangle_from_scratch::{
+  dylib::Library, gl::*, win32::*, window::Win32Window,
+};
+
+#[cfg(target_os = "linux")]
+use triangle_from_scratch::{
+  format::*, gl::*, glx::*, guard::*, window::X11Window, x11::*,
+};
+
+// the tests share the helpers of the library's tests, not all of which are
+// used here.
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "test_util.rs"]
+mod test_util;
+
+const VERTEX_SHADER_SRC: &str = "#version 330 core
+layout (location = 0) in vec3 vertex_position;
+void main() {
+  gl_Position = vec4(vertex_position, 1.0);
+}
+";
+
+const FRAGMENT_SHADER_SRC: &str = "#version 330 core
+out vec4 final_color;
+void main() {
+  final_color = vec4(1.0, 0.5, 0.313, 1.0);
+}
+";
+
+/// The corners of the triangle, in normalized device coordinates.
+const TRIANGLE_POSITIONS: [[f32; 3]; 3] =
+  [[-0.2, 0.5, 0.0], [-0.5, -0.4, 0.0], [0.5, -0.1, 0.0]];
+
+/// The GL objects that draw the triangle.
+#[derive(Default)]
+struct Triangle {
+  vao: GLuint,
+  vbo: GLuint,
+  ebo: GLuint,
+  program: GLuint,
+}
+impl Triangle {
+  /// Makes all the objects, which needs a current GL 3.3 context.
+  unsafe fn new(gl: &Gl33) -> Result<Self, ShaderError> {
+    let mut t = Self::default();
+    gl.GenVertexArrays(1, &mut t.vao);
+    gl.BindVertexArray(t.vao);
+
+    let vertex_data = TRIANGLE_POSITIONS;
+    gl.GenBuffers(1, &mut t.vbo);
+    gl.BindBuffer(GL_ARRAY_BUFFER, t.vbo);
+    gl.BufferData(
+      GL_ARRAY_BUFFER,
+      core::mem::size_of_val(&vertex_data) as GLsizeiptr,
+      vertex_data.as_ptr().cast(),
+      GL_STATIC_DRAW,
+    );
+
+    let index_data = [0_u16, 1, 2];
+    gl.GenBuffers(1, &mut t.ebo);
+    gl.BindBuffer(GL_ELEMENT_ARRAY_BUFFER, t.ebo);
+    gl.BufferData(
+      GL_ELEMENT_ARRAY_BUFFER,
+      core::mem::size_of_val(&index_data) as GLsizeiptr,
+      index_data.as_ptr().cast(),
+      GL_STATIC_DRAW,
+    );
+
+    gl.VertexAttribPointer(
+      0,
+      3,
+      GL_FLOAT,
+      GL_FALSE as GLboolean,
+      core::mem::size_of::<[f32; 3]>() as GLsizei,
+      core::ptr::null(),
+    );
+    gl.EnableVertexAttribArray(0);
+
+    let vertex_shader =
+      compile_shader(gl, GL_VERTEX_SHADER, VERTEX_SHADER_SRC)?;
+    let fragment_shader =
+      compile_shader(gl, GL_FRAGMENT_SHADER, FRAGMENT_SHADER_SRC)
+        .inspect_err(|_| gl.DeleteShader(vertex_shader))?;
+    let program = link_program(gl, &[vertex_shader, fragment_shader]);
+    gl.DeleteShader(vertex_shader);
+    gl.DeleteShader(fragment_shader);
+    t.program = program?;
+    Ok(t)
+  }
+
+  /// Clears the screen and draws the triangle.
+  unsafe fn draw(&self, gl: &Gl33) {
+    gl.ClearColor(0.6, 0.7, 0.8, 1.0);
+    gl.Clear(GL_COLOR_BUFFER_BIT);
+    gl.UseProgram(self.program);
+    gl.BindVertexArray(self.vao);
+    gl.DrawElements(GL_TRIANGLES, 3, GL_UNSIGNED_SHORT, core::ptr::null());
+  }
+
+  /// Deletes all the objects.
+  unsafe fn delete(&self, gl: &Gl33) {
+    gl.DeleteProgram(self.program);
+    gl.DeleteBuffers(1, &self.ebo);
+    gl.DeleteBuffers(1, &self.vbo);
+    gl.DeleteVertexArrays(1, &self.vao);
+  }
+}
+
+/// The app itself, which is the same on every platform.
+struct App {
+  gl: Gl33,
+  debug: Option<DebugCallback>,
+  triangle: Triangle,
+}
+impl App {
+  /// Handles an event of the window.
+  ///
+  /// **Returns:** `true` when the app wants to quit. The GL objects are
+  /// already deleted by then, so delete the context and close the window.
+  unsafe fn handle_event<W: window::Window>(
+    &mut self, window: &mut W, event: Event,
+  ) -> bool {
+    match event {
+      Event::Resized { width, height } => {
+        self.gl.Viewport(0, 0, width as GLsizei, height as GLsizei)
+      }
+      Event::RedrawRequested => {
+        self.triangle.draw(&self.gl);
+        window
+          .swap_buffers()
+          .unwrap_or_else(|e| eprintln!("Couldn't swap the buffers: {}", e));
+      }
+      Event::CloseRequested | Event::KeyDown(Key::Escape) => {
+        println!("{:?}", event);
+        self.triangle.delete(&self.gl);
+        if let Some(debug) = self.debug.take() {
+          debug.remove(&self.gl);
+        }
+        return true;
+      }
+      Event::Destroyed => println!("Destroyed"),
+      _ => (),
     }
+    false
+  }
+}
+
+/// The versions that the shaders work with, newest first, with a debug
+/// context in debug builds.
+fn context_request<S>() -> ContextRequest<S> {
+  ContextRequest::default()
+    .versions(&[
+      ContextVersion::gl_core(4, 6),
+      ContextVersion::gl_core(4, 1),
+      ContextVersion::gl_core(3, 3),
+    ])
+    .forward_compatible(true)
+    .debug(cfg!(debug_assertions))
+}
+
+/// Prints the versions that the driver turned down, and then the one that it
+/// made.
+fn report_context<C, E: core::fmt::Display>(created: &CreatedContext<C, E>) {
+  for attempt in &created.failed {
+    println!("> Couldn't make {}", attempt);
   }
-  #[rustfmt::skip]
-  pub unsafe fn load_gl_functions(&mut self) {
-    self.gl_clear = core::mem::transmute(self.gl_get_proc_address(c_str!("glClear")));
-    self.gl_clear_color = core::mem::transmute(self.gl_get_proc_address(c_str!("glClearColor")));
+  println!("> Context: {}", created.version);
+}
+
+/// Gets a GL function, which for GL 1.1 functions has to come from
+/// `opengl32.dll` itself.
+#[cfg(windows)]
+fn gl_get_proc_address(opengl32: &Library, name: &[u8]) -> *mut c_void {
+  assert!(*name.last().unwrap() == 0);
+  let p = unsafe { wglGetProcAddress(name.as_ptr().cast()) };
+  match p as usize {
+    0 | 1 | 2 | 3 | usize::MAX => opengl32.get_raw(name),
+    _ => p,
   }
 }
 
+#[cfg(windows)]
 fn main() {
-  let instance = get_process_handle();
-
   #[allow(non_snake_case)]
   let (
     wgl_extensions,
     wglChoosePixelFormatARB,
+    wglGetPixelFormatAttribivARB,
     wglCreateContextAttribsARB,
     wglSwapIntervalEXT,
   ) = get_wgl_basics().unwrap();
   println!("> WGL Extensions: {:?}", wgl_extensions);
 
   // real window stuff
-  let sample_window_class = "Sample Window Class";
-  let sample_window_class_wn = wide_null(sample_window_class);
-
-  let mut wc = WNDCLASSW::default();
-  wc.style = CS_OWNDC | CS_HREDRAW | CS_VREDRAW;
-  wc.lpfnWndProc = Some(window_procedure);
-  wc.hInstance = instance;
-  wc.lpszClassName = sample_window_class_wn.as_ptr();
-  wc.hCursor = load_predefined_cursor(IDCursor::Arrow).unwrap();
-
-  let _atom = unsafe { register_class(&wc) }.unwrap();
-
-  let lparam: *mut WindowData = Box::leak(Box::new(WindowData::default()));
-  let hwnd = unsafe {
-    create_app_window(
-      sample_window_class,
-      "Sample Window Name",
-      None,
-      [800, 600],
-      lparam.cast(),
-    )
-  }
-  .unwrap();
-  let hdc = unsafe { get_dc(hwnd) }.unwrap();
-  unsafe { (*lparam).hdc = hdc };
+  let mut window =
+    Win32Window::create("Sample Window Name", [800, 600]).unwrap();
+  let hdc = window.hdc();
 
   // base criteria
-  let mut int_attribs = vec![
-    [WGL_DRAW_TO_WINDOW_ARB, true as _],
-    [WGL_SUPPORT_OPENGL_ARB, true as _],
-    [WGL_DOUBLE_BUFFER_ARB, true as _],
-    [WGL_PIXEL_TYPE_ARB, WGL_TYPE_RGBA_ARB],
-    [WGL_COLOR_BITS_ARB, 32],
-    [WGL_DEPTH_BITS_ARB, 24],
-    [WGL_STENCIL_BITS_ARB, 8],
-  ];
+  let mut attribs = PixelFormatAttribs::default()
+    .double_buffer(true)
+    .color_bits(32)
+    .depth_bits(24)
+    .stencil_bits(8);
   // if sRGB is supported, ask for that
   if wgl_extensions.iter().any(|s| s == "WGL_EXT_framebuffer_sRGB") {
-    int_attribs.push([WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT, true as _]);
+    attribs = attribs.srgb(true);
   };
   // let's have some multisample if we can get it
   if wgl_extensions.iter().any(|s| s == "WGL_ARB_multisample") {
-    int_attribs.push([WGL_SAMPLE_BUFFERS_ARB, 1]);
+    attribs = attribs.sample_buffers(1);
   };
-  // finalize our list
-  int_attribs.push([0, 0]);
   // choose a format, get the PIXELFORMATDESCRIPTOR, and set it.
   let pix_format = unsafe {
-    do_wglChoosePixelFormatARB(wglChoosePixelFormatARB, hdc, &int_attribs, &[])
+    do_wglChoosePixelFormatARB(wglChoosePixelFormatARB, hdc, &attribs)
   }
   .unwrap();
   let pfd = unsafe { describe_pixel_format(hdc, pix_format) }.unwrap();
-  println!("> Selected Pixel Format: {:?}", pfd);
+  let info = unsafe {
+    describe_pixel_format_info(hdc, pix_format, wglGetPixelFormatAttribivARB)
+  }
+  .unwrap();
+  println!("> Selected Pixel Format: {}", info);
   unsafe { set_pixel_format(hdc, pix_format, &pfd) }.unwrap();
 
-  // now we create a context.
-  const FLAGS: c_int = WGL_CONTEXT_FORWARD_COMPATIBLE_BIT_ARB
-    | if cfg!(debug_assertions) { WGL_CONTEXT_DEBUG_BIT_ARB } else { 0 };
-  let hglrc = unsafe {
-    do_wglCreateContextAttribsARB(
+  // now we create a context, with the newest version we can get.
+  let request = context_request();
+  let created = unsafe {
+    create_wgl_context(
       wglCreateContextAttribsARB,
       hdc,
-      null_mut(),
-      &[
-        [WGL_CONTEXT_MAJOR_VERSION_ARB, 3],
-        [WGL_CONTEXT_MINOR_VERSION_ARB, 3],
-        [WGL_CONTEXT_PROFILE_MASK_ARB, WGL_CONTEXT_CORE_PROFILE_BIT_ARB],
-        [WGL_CONTEXT_FLAGS_ARB, FLAGS],
-        [0, 0],
-      ],
+      &wgl_extensions,
+      &request,
     )
   }
-  .unwrap();
+  .unwrap_or_else(|e| panic!("{}", e));
+  report_context(&created);
+  let hglrc = created.context;
   unsafe { wgl_make_current(hdc, hglrc) }.unwrap();
-  unsafe { (*lparam).hglrc = hglrc };
 
-  let opengl32 = load_library("opengl32.dll").unwrap();
-  unsafe { (*lparam).opengl32 = opengl32 };
-  unsafe { (*lparam).load_gl_functions() };
+  let opengl32 = Library::open("opengl32.dll").unwrap();
+  let (gl, missing) = unsafe {
+    Gl33::load(|name| gl_get_proc_address(&opengl32, name).cast_const())
+  };
+  if !missing.is_empty() {
+    println!("> Missing GL functions: {:?}", missing);
+  }
+  let debug = if cfg!(debug_assertions) {
+    unsafe { DebugCallback::install(&gl, DebugOutput::default()) }
+  } else {
+    None
+  };
+  let triangle = unsafe { Triangle::new(&gl) }
+    .unwrap_or_else(|e| panic!("Couldn't make the triangle:\n{}", e));
 
   // Enable "adaptive" vsync if possible, otherwise normal vsync
   if wgl_extensions.iter().any(|s| s == "WGL_EXT_swap_control_tear") {
@@ -135,85 +272,308 @@ fn main() {
     unsafe { (wglSwapIntervalEXT.unwrap())(1) };
   }
 
-  let _previously_visible = unsafe { ShowWindow(hwnd, SW_SHOW) };
+  window.show().unwrap();
 
-  loop {
-    match get_any_message() {
-      Ok(msg) => {
-        if msg.message == WM_QUIT {
-          std::process::exit(msg.wParam as i32);
-        }
-        translate_message(&msg);
-        unsafe {
-          DispatchMessageW(&msg);
-        }
-      }
-      Err(e) => panic!("Error when getting from the message queue: {}", e),
+  let mut app = App { gl, debug, triangle };
+  run_event_loop(&mut window, |window, event| unsafe {
+    if app.handle_event(window, event) {
+      wgl_delete_context(hglrc)
+        .unwrap_or_else(|e| eprintln!("GL Context deletion error: {}", e));
+      window
+        .close()
+        .unwrap_or_else(|e| eprintln!("Window destruction error: {}", e));
     }
-  }
+  })
+  .unwrap_or_else(|e| {
+    panic!("Error when getting from the message queue: {}", e)
+  });
 }
 
-pub unsafe extern "system" fn window_procedure(
-  hwnd: HWND, msg: UINT, wparam: WPARAM, lparam: LPARAM,
-) -> LRESULT {
-  match msg {
-    WM_NCCREATE => {
-      println!("WM_NCCREATE");
-      let createstruct: *mut CREATESTRUCTW = lparam as *mut _;
-      if createstruct.is_null() {
-        eprintln!("createstruct pointer was null");
-        return 0;
-      }
-      let ptr = (*createstruct).lpCreateParams as *mut WindowData;
-      if let Err(e) = set_window_userdata::<WindowData>(hwnd, ptr) {
-        println!("Couldn't set the WindowData pointer: {}", e);
-        return 0;
-      }
-      // This is required for the window title to be drawn!
-      return DefWindowProcW(hwnd, msg, wparam, lparam);
-    }
-    WM_CREATE => println!("WM_CREATE"),
-    WM_CLOSE => {
-      println!("WM_CLOSE");
-      let _success = DestroyWindow(hwnd);
+#[cfg(target_os = "linux")]
+#[allow(non_upper_case_globals)]
+#[allow(non_snake_case)]
+fn main() {
+  // closed last, after everything made through it is dropped.
+  let owned_display =
+    unsafe { OwnedDisplay::from_raw(open_display(None).unwrap()) };
+  let display = owned_display.as_raw();
+  let screen = unsafe { XDefaultScreen(display) };
+
+  let (glx_extensions, glXCreateContextAttribsARB, glXSwapIntervalEXT) =
+    unsafe { get_glx_basics(display, screen) }.unwrap();
+  println!("> GLX Extensions: {:?}", glx_extensions);
+
+  // GLX only filters out the configs that can't be a GL window at all, and
+  // we rank the rest ourselves so that we don't get 16-bit depth or no sRGB.
+  let fb_attribs = [
+    [GLX_X_RENDERABLE, True],
+    [GLX_DRAWABLE_TYPE, GLX_WINDOW_BIT],
+    [GLX_RENDER_TYPE, GLX_RGBA_BIT],
+    [GLX_X_VISUAL_TYPE, GLX_TRUE_COLOR],
+    [0, 0],
+  ];
+  let fb_configs =
+    unsafe { glx_choose_fb_config(display, screen, &fb_attribs) }.unwrap();
+  let candidates: Vec<FormatDesc> = fb_configs
+    .iter()
+    .map(|&config| unsafe { glx_describe_fb_config(display, config) }.unwrap())
+    .collect();
+  let request = FormatRequest::default()
+    .color(24, 24)
+    .alpha(8, 8)
+    .depth(24, 24)
+    .stencil(8, 8)
+    .samples(0, 4)
+    .accelerated(Preference::Preferred)
+    .srgb(Preference::Preferred)
+    .double_buffer(Preference::Required);
+  let best = request.best(&candidates).expect("no usable GLXFBConfig");
+  println!("> Selected Format: {:?}", best);
+  // choose the config, and make a window with the matching visual.
+  let fb_config =
+    fb_configs[candidates.iter().position(|c| *c == best).unwrap()];
+  let visual_info =
+    unsafe { glx_get_visual_from_fb_config(display, fb_config) }.unwrap();
+  println!("> Selected Visual: {:?}", visual_info);
+  let mut window = unsafe {
+    X11Window::create_with_visual(
+      display,
+      "Sample Window Name",
+      [800, 600],
+      Some(&visual_info),
+    )
+  }
+  .unwrap();
+
+  // now we create a context, with the newest version we can get.
+  let request = context_request();
+  let created = unsafe {
+    create_glx_context(
+      glXCreateContextAttribsARB,
+      display,
+      fb_config,
+      &glx_extensions,
+      &request,
+    )
+  }
+  .unwrap_or_else(|e| panic!("{}", e));
+  report_context(&created);
+  let ctx = unsafe { OwnedGlxContext::from_raw(display, created.context) };
+  unsafe { glx_make_current(display, window.window(), ctx.as_raw()) }.unwrap();
+
+  let (gl, missing) = unsafe {
+    Gl33::load(|name| {
+      glx_get_proc_address(name).unwrap_or(null_mut()).cast_const()
+    })
+  };
+  if !missing.is_empty() {
+    println!("> Missing GL functions: {:?}", missing);
+  }
+  let debug = if cfg!(debug_assertions) {
+    unsafe { DebugCallback::install(&gl, DebugOutput::default()) }
+  } else {
+    None
+  };
+  // With `TRIANGLE_TRACE=path` the setup and the first frame are recorded.
+  #[cfg(feature = "trace_gl")]
+  let mut trace_path = std::env::var_os("TRIANGLE_TRACE");
+  #[cfg(feature = "trace_gl")]
+  if trace_path.is_some() {
+    start_gl_trace();
+  }
+  let triangle = unsafe { Triangle::new(&gl) }
+    .unwrap_or_else(|e| panic!("Couldn't make the triangle:\n{}", e));
+
+  // Enable "adaptive" vsync if possible, otherwise normal vsync
+  let interval =
+    if glx_extensions.iter().any(|s| s == "GLX_EXT_swap_control_tear") {
+      -1
+    } else {
+      1
+    };
+  unsafe {
+    do_glXSwapIntervalEXT(
+      glXSwapIntervalEXT,
+      display,
+      window.window(),
+      interval,
+    )
+  }
+  .unwrap_or_else(|e| eprintln!("Couldn't set the swap interval: {}", e));
+
+  window.show().unwrap();
+
+  let mut app = App { gl, debug, triangle };
+  run_event_loop(&mut window, |window, event| unsafe {
+    if app.handle_event(window, event) {
+      // the context itself is destroyed when `ctx` is dropped.
+      glx_make_current(display, 0, null_mut())
+        .unwrap_or_else(|e| eprintln!("GL Context release error: {}", e));
+      window
+        .close()
+        .unwrap_or_else(|e| eprintln!("Window destruction error: {}", e));
     }
-    WM_DESTROY => {
-      println!("WM_DESTROY");
-      match get_window_userdata::<WindowData>(hwnd) {
-        Ok(ptr) if !ptr.is_null() => {
-          let window_data = Box::from_raw(ptr);
-          FreeLibrary(window_data.opengl32);
-          wgl_delete_context(window_data.hglrc)
-            .unwrap_or_else(|e| eprintln!("GL Context deletion error: {}", e));
-          if !release_dc(hwnd, window_data.hdc) {
-            eprintln!("There was an HDC release error.");
-          }
-          eprintln!("WM_DESTROY> Cleanup complete.");
-        }
-        Ok(_) => {
-          eprintln!("WM_DESTROY> userdata ptr is null, no cleanup.")
-        }
-        Err(e) => {
-          eprintln!("WM_DESTROY> Error getting userdata ptr: {}", e)
-        }
+    #[cfg(feature = "trace_gl")]
+    if event == Event::RedrawRequested {
+      if let Some(path) = trace_path.take() {
+        write_trace(&stop_gl_trace(), path.as_ref());
       }
-      post_quit_message(0);
     }
-    WM_PAINT => match get_window_userdata::<WindowData>(hwnd) {
-      Ok(ptr) if !ptr.is_null() => {
-        let window_data = ptr.as_mut().unwrap();
-        (window_data.gl_clear_color.unwrap())(0.6, 0.7, 0.8, 1.0);
-        (window_data.gl_clear.unwrap())(GL_COLOR_BUFFER_BIT);
-        SwapBuffers(window_data.hdc);
+  })
+  .unwrap_or_else(|e| panic!("Error when getting from the event queue: {}", e));
+}
+
+/// Writes the binary trace to `path`, and the text of it next to that.
+#[cfg(all(target_os = "linux", feature = "trace_gl"))]
+fn write_trace(trace: &Trace, path: &std::path::Path) {
+  let text_path = path.with_extension("txt");
+  std::fs::write(path, trace.to_bytes())
+    .and_then(|_| std::fs::write(&text_path, trace.to_text()))
+    .unwrap_or_else(|e| eprintln!("Couldn't write the trace: {}", e));
+  println!("> Wrote {} calls to {}", trace.calls.len(), path.display());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_triangle_draws_orange() {
+  test_util::with_headless_gl([64, 64], |gl| unsafe {
+    gl.Viewport(0, 0, 64, 64);
+    let triangle = Triangle::new(gl).unwrap();
+    triangle.draw(gl);
+    gl.Finish();
+    let mut pixels = vec![0_u8; 64 * 64 * 4];
+    gl.ReadPixels(
+      0,
+      0,
+      64,
+      64,
+      GL_RGBA,
+      GL_UNSIGNED_BYTE,
+      pixels.as_mut_ptr().cast(),
+    );
+    let pixel = |x: usize, y: usize| {
+      let i = (y * 64 + x) * 4;
+      [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+    };
+    // drivers can round the colors either way, so allow off-by-one.
+    let close = |a: [u8; 4], b: [u8; 4]| {
+      a.iter().zip(b.iter()).all(|(a, b)| a.abs_diff(*b) <= 1)
+    };
+    // the middle of the triangle is about (-0.07, 0.0), and GL rows go up.
+    assert!(close(pixel(30, 32), [255, 128, 80, 255]), "{:?}", pixel(30, 32));
+    assert!(close(pixel(2, 2), [153, 178, 204, 255]), "{:?}", pixel(2, 2));
+    triangle.delete(gl);
+    assert_eq!(gl.GetError(), GL_NO_ERROR);
+  });
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_app_with_mock_window() {
+  use triangle_from_scratch::window::MockWindow;
+  test_util::with_headless_gl([64, 64], |gl| unsafe {
+    let triangle = Triangle::new(gl).unwrap();
+    let mut app = App { gl: *gl, debug: None, triangle };
+    let mut window = MockWindow::create("Mock", [64, 64]).unwrap();
+    window.push_event(Event::Resized { width: 32, height: 32 });
+    window.push_event(Event::RedrawRequested);
+    window.push_event(Event::KeyDown(Key::Escape));
+    window.push_event(Event::RedrawRequested);
+    let mut quit = false;
+    run_event_loop(&mut window, |window, event| {
+      assert!(!quit || event == Event::Destroyed, "{:?} after quit", event);
+      if app.handle_event(window, event) {
+        quit = true;
+        window.close().unwrap();
       }
-      Ok(_) => {
-        eprintln!("WM_PAINT> userdata ptr is null")
+    })
+    .unwrap();
+    assert!(quit && window.destroyed);
+    assert_eq!(window.swaps, 1);
+    let mut viewport = [0; 4];
+    app.gl.GetIntegerv(GL_VIEWPORT, viewport.as_mut_ptr());
+    assert_eq!(viewport, [0, 0, 32, 32]);
+    assert_eq!(app.gl.GetError(), GL_NO_ERROR);
+  });
+}
+
+#[test]
+#[cfg(all(target_os = "linux", feature = "trace_gl"))]
+fn test_triangle_trace_replays() {
+  // draws with a fresh context, and gives the middle pixel.
+  let run = |draw: &dyn Fn(&Gl33)| {
+    test_util::with_headless_gl([64, 64], |gl| unsafe {
+      gl.Viewport(0, 0, 64, 64);
+      draw(gl);
+      let mut pixel = [0_u8; 4];
+      gl.ReadPixels(
+        30,
+        32,
+        1,
+        1,
+        GL_RGBA,
+        GL_UNSIGNED_BYTE,
+        pixel.as_mut_ptr().cast(),
+      );
+      pixel
+    })
+  };
+  let trace = core::cell::RefCell::new(Trace::default());
+  let recorded = match run(&|gl| unsafe {
+    start_gl_trace();
+    Triangle::new(gl).unwrap().draw(gl);
+    *trace.borrow_mut() = stop_gl_trace();
+  }) {
+    Some(pixel) => pixel,
+    None => return,
+  };
+  let trace = Trace::from_bytes(&trace.into_inner().to_bytes()).unwrap();
+  let text = trace.to_text();
+  assert!(text.contains("glGenVertexArrays(n: 1, arrays: &mut [4 bytes: "));
+  assert!(text.contains(
+    "glVertexAttribPointer(index: 0, size: 3, type: GL_FLOAT, normalized: 0, \
+     stride: 12, pointer: +0)"
+  ));
+  assert!(text.contains("final_color = vec4(1.0, 0.5, 0.313, 1.0);"));
+  assert!(text.contains("glDrawElements(mode: GL_TRIANGLES, count: 3, "));
+  let replayed = run(&|gl| unsafe {
+    let stats = trace.replay(gl).unwrap();
+    // only the `glGet*` queries of the shader status are skipped.
+    for &i in stats.skipped.iter() {
+      assert!(trace.calls[i].name.starts_with("glGet"), "{}", trace.calls[i]);
+    }
+  });
+  assert_eq!(replayed, Some(recorded));
+  assert!(recorded[0] > 250 && recorded[3] == 255, "{:?}", recorded);
+}
+
+#[test]
+fn test_triangle_golden() {
+  use triangle_from_scratch::golden::*;
+  // GL drivers round colors either way, and can cover edge pixels a little
+  // differently than the soft rasterizer does.
+  let golden = Golden::default().tolerance(1, 4);
+  for backend in Backend::ALL {
+    let image = render(backend, 64, 64, |target| match target {
+      Target::Soft(fb) => {
+        fb.clear_color(0.6, 0.7, 0.8, 1.0);
+        fb.clear(GL_COLOR_BUFFER_BIT);
+        fb.draw_triangle(TRIANGLE_POSITIONS, [[]; 3], |[]| {
+          [1.0, 0.5, 0.313, 1.0]
+        });
       }
-      Err(e) => {
-        eprintln!("WM_PAINT> Error while getting the userdata ptr: {}", e)
+      Target::Gl(gl) => unsafe { Triangle::new(gl).unwrap().draw(gl) },
+    });
+    let image = match image {
+      Ok(image) => image,
+      Err(RenderError::Unavailable(why)) => {
+        test_util::skip(&format!("{:?}: {}", backend, why));
+        continue;
       }
-    },
-    _ => return DefWindowProcW(hwnd, msg, wparam, lparam),
+      Err(e) => panic!("{:?}: {}", backend, e),
+    };
+    golden
+      .check("triangle", image.view())
+      .unwrap_or_else(|e| panic!("{:?}: {}", backend, e));
   }
-  0
 }
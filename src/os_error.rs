@@ -0,0 +1,476 @@
+//! Module for the `errno` of Linux, and the errors it stands for.
+//!
+//! [`OsError`] is the Linux counterpart to `Win32Error`: it's the code from
+//! `errno`, and formats with its name and the message from `strerror_r`.
+//!
+//! Only Linux is supported, where `errno` is found with `__errno_location`
+//! (which both glibc and musl have). The codes are the ones that x86, ARM,
+//! RISC-V, and most other architectures use. MIPS and SPARC number them
+//! differently, so the module isn't there at all on those, and PowerPC has
+//! its own [`EDEADLOCK`].
+//!
+//! ```
+//! use triangle_from_scratch::os_error::*;
+//! set_errno(OsError(ENOENT));
+//! let e = OsError::last_os_error();
+//! assert_eq!(e.name(), Some("ENOENT"));
+//! assert_eq!(e.to_string(), "ENOENT (No such file or directory)");
+//! ```
+
+pub use std::os::raw::{c_char, c_int};
+
+extern "C" {
+  /// The address of this thread's `errno`.
+  ///
+  /// See [`errno`](https://man7.org/linux/man-pages/man3/errno.3.html)
+  pub fn __errno_location() -> *mut c_int;
+
+  /// [`strerror_r`](https://man7.org/linux/man-pages/man3/strerror_r.3.html),
+  /// the POSIX version, which glibc calls `__xpg_strerror_r`.
+  #[cfg_attr(target_env = "gnu", link_name = "__xpg_strerror_r")]
+  pub fn strerror_r(errnum: c_int, buf: *mut c_char, buflen: usize) -> c_int;
+}
+
+pub const EPERM: c_int = 1;
+pub const ENOENT: c_int = 2;
+pub const ESRCH: c_int = 3;
+pub const EINTR: c_int = 4;
+pub const EIO: c_int = 5;
+pub const ENXIO: c_int = 6;
+pub const E2BIG: c_int = 7;
+pub const ENOEXEC: c_int = 8;
+pub const EBADF: c_int = 9;
+pub const ECHILD: c_int = 10;
+pub const EAGAIN: c_int = 11;
+pub const ENOMEM: c_int = 12;
+pub const EACCES: c_int = 13;
+pub const EFAULT: c_int = 14;
+pub const ENOTBLK: c_int = 15;
+pub const EBUSY: c_int = 16;
+pub const EEXIST: c_int = 17;
+pub const EXDEV: c_int = 18;
+pub const ENODEV: c_int = 19;
+pub const ENOTDIR: c_int = 20;
+pub const EISDIR: c_int = 21;
+pub const EINVAL: c_int = 22;
+pub const ENFILE: c_int = 23;
+pub const EMFILE: c_int = 24;
+pub const ENOTTY: c_int = 25;
+pub const ETXTBSY: c_int = 26;
+pub const EFBIG: c_int = 27;
+pub const ENOSPC: c_int = 28;
+pub const ESPIPE: c_int = 29;
+pub const EROFS: c_int = 30;
+pub const EMLINK: c_int = 31;
+pub const EPIPE: c_int = 32;
+pub const EDOM: c_int = 33;
+pub const ERANGE: c_int = 34;
+pub const EDEADLK: c_int = 35;
+pub const ENAMETOOLONG: c_int = 36;
+pub const ENOLCK: c_int = 37;
+pub const ENOSYS: c_int = 38;
+pub const ENOTEMPTY: c_int = 39;
+pub const ELOOP: c_int = 40;
+pub const ENOMSG: c_int = 42;
+pub const EIDRM: c_int = 43;
+pub const ECHRNG: c_int = 44;
+pub const EL2NSYNC: c_int = 45;
+pub const EL3HLT: c_int = 46;
+pub const EL3RST: c_int = 47;
+pub const ELNRNG: c_int = 48;
+pub const EUNATCH: c_int = 49;
+pub const ENOCSI: c_int = 50;
+pub const EL2HLT: c_int = 51;
+pub const EBADE: c_int = 52;
+pub const EBADR: c_int = 53;
+pub const EXFULL: c_int = 54;
+pub const ENOANO: c_int = 55;
+pub const EBADRQC: c_int = 56;
+pub const EBADSLT: c_int = 57;
+pub const EBFONT: c_int = 59;
+pub const ENOSTR: c_int = 60;
+pub const ENODATA: c_int = 61;
+pub const ETIME: c_int = 62;
+pub const ENOSR: c_int = 63;
+pub const ENONET: c_int = 64;
+pub const ENOPKG: c_int = 65;
+pub const EREMOTE: c_int = 66;
+pub const ENOLINK: c_int = 67;
+pub const EADV: c_int = 68;
+pub const ESRMNT: c_int = 69;
+pub const ECOMM: c_int = 70;
+pub const EPROTO: c_int = 71;
+pub const EMULTIHOP: c_int = 72;
+pub const EDOTDOT: c_int = 73;
+pub const EBADMSG: c_int = 74;
+pub const EOVERFLOW: c_int = 75;
+pub const ENOTUNIQ: c_int = 76;
+pub const EBADFD: c_int = 77;
+pub const EREMCHG: c_int = 78;
+pub const ELIBACC: c_int = 79;
+pub const ELIBBAD: c_int = 80;
+pub const ELIBSCN: c_int = 81;
+pub const ELIBMAX: c_int = 82;
+pub const ELIBEXEC: c_int = 83;
+pub const EILSEQ: c_int = 84;
+pub const ERESTART: c_int = 85;
+pub const ESTRPIPE: c_int = 86;
+pub const EUSERS: c_int = 87;
+pub const ENOTSOCK: c_int = 88;
+pub const EDESTADDRREQ: c_int = 89;
+pub const EMSGSIZE: c_int = 90;
+pub const EPROTOTYPE: c_int = 91;
+pub const ENOPROTOOPT: c_int = 92;
+pub const EPROTONOSUPPORT: c_int = 93;
+pub const ESOCKTNOSUPPORT: c_int = 94;
+pub const EOPNOTSUPP: c_int = 95;
+pub const EPFNOSUPPORT: c_int = 96;
+pub const EAFNOSUPPORT: c_int = 97;
+pub const EADDRINUSE: c_int = 98;
+pub const EADDRNOTAVAIL: c_int = 99;
+pub const ENETDOWN: c_int = 100;
+pub const ENETUNREACH: c_int = 101;
+pub const ENETRESET: c_int = 102;
+pub const ECONNABORTED: c_int = 103;
+pub const ECONNRESET: c_int = 104;
+pub const ENOBUFS: c_int = 105;
+pub const EISCONN: c_int = 106;
+pub const ENOTCONN: c_int = 107;
+pub const ESHUTDOWN: c_int = 108;
+pub const ETOOMANYREFS: c_int = 109;
+pub const ETIMEDOUT: c_int = 110;
+pub const ECONNREFUSED: c_int = 111;
+pub const EHOSTDOWN: c_int = 112;
+pub const EHOSTUNREACH: c_int = 113;
+pub const EALREADY: c_int = 114;
+pub const EINPROGRESS: c_int = 115;
+pub const ESTALE: c_int = 116;
+pub const EUCLEAN: c_int = 117;
+pub const ENOTNAM: c_int = 118;
+pub const ENAVAIL: c_int = 119;
+pub const EISNAM: c_int = 120;
+pub const EREMOTEIO: c_int = 121;
+pub const EDQUOT: c_int = 122;
+pub const ENOMEDIUM: c_int = 123;
+pub const EMEDIUMTYPE: c_int = 124;
+pub const ECANCELED: c_int = 125;
+pub const ENOKEY: c_int = 126;
+pub const EKEYEXPIRED: c_int = 127;
+pub const EKEYREVOKED: c_int = 128;
+pub const EKEYREJECTED: c_int = 129;
+pub const EOWNERDEAD: c_int = 130;
+pub const ENOTRECOVERABLE: c_int = 131;
+pub const ERFKILL: c_int = 132;
+pub const EHWPOISON: c_int = 133;
+
+/// Another name for [`EAGAIN`].
+pub const EWOULDBLOCK: c_int = EAGAIN;
+/// Another name for [`EDEADLK`].
+#[cfg(not(any(target_arch = "powerpc", target_arch = "powerpc64")))]
+pub const EDEADLOCK: c_int = EDEADLK;
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+pub const EDEADLOCK: c_int = 58;
+/// Another name for [`EOPNOTSUPP`].
+pub const ENOTSUP: c_int = EOPNOTSUPP;
+
+/// The name of each `errno` code, in order of the code.
+///
+/// Codes with more than one name only have their main name here (eg: not
+/// [`EWOULDBLOCK`]).
+pub const ERRNO_NAMES: [(c_int, &str); 131] = [
+  (EPERM, "EPERM"),
+  (ENOENT, "ENOENT"),
+  (ESRCH, "ESRCH"),
+  (EINTR, "EINTR"),
+  (EIO, "EIO"),
+  (ENXIO, "ENXIO"),
+  (E2BIG, "E2BIG"),
+  (ENOEXEC, "ENOEXEC"),
+  (EBADF, "EBADF"),
+  (ECHILD, "ECHILD"),
+  (EAGAIN, "EAGAIN"),
+  (ENOMEM, "ENOMEM"),
+  (EACCES, "EACCES"),
+  (EFAULT, "EFAULT"),
+  (ENOTBLK, "ENOTBLK"),
+  (EBUSY, "EBUSY"),
+  (EEXIST, "EEXIST"),
+  (EXDEV, "EXDEV"),
+  (ENODEV, "ENODEV"),
+  (ENOTDIR, "ENOTDIR"),
+  (EISDIR, "EISDIR"),
+  (EINVAL, "EINVAL"),
+  (ENFILE, "ENFILE"),
+  (EMFILE, "EMFILE"),
+  (ENOTTY, "ENOTTY"),
+  (ETXTBSY, "ETXTBSY"),
+  (EFBIG, "EFBIG"),
+  (ENOSPC, "ENOSPC"),
+  (ESPIPE, "ESPIPE"),
+  (EROFS, "EROFS"),
+  (EMLINK, "EMLINK"),
+  (EPIPE, "EPIPE"),
+  (EDOM, "EDOM"),
+  (ERANGE, "ERANGE"),
+  (EDEADLK, "EDEADLK"),
+  (ENAMETOOLONG, "ENAMETOOLONG"),
+  (ENOLCK, "ENOLCK"),
+  (ENOSYS, "ENOSYS"),
+  (ENOTEMPTY, "ENOTEMPTY"),
+  (ELOOP, "ELOOP"),
+  (ENOMSG, "ENOMSG"),
+  (EIDRM, "EIDRM"),
+  (ECHRNG, "ECHRNG"),
+  (EL2NSYNC, "EL2NSYNC"),
+  (EL3HLT, "EL3HLT"),
+  (EL3RST, "EL3RST"),
+  (ELNRNG, "ELNRNG"),
+  (EUNATCH, "EUNATCH"),
+  (ENOCSI, "ENOCSI"),
+  (EL2HLT, "EL2HLT"),
+  (EBADE, "EBADE"),
+  (EBADR, "EBADR"),
+  (EXFULL, "EXFULL"),
+  (ENOANO, "ENOANO"),
+  (EBADRQC, "EBADRQC"),
+  (EBADSLT, "EBADSLT"),
+  (EBFONT, "EBFONT"),
+  (ENOSTR, "ENOSTR"),
+  (ENODATA, "ENODATA"),
+  (ETIME, "ETIME"),
+  (ENOSR, "ENOSR"),
+  (ENONET, "ENONET"),
+  (ENOPKG, "ENOPKG"),
+  (EREMOTE, "EREMOTE"),
+  (ENOLINK, "ENOLINK"),
+  (EADV, "EADV"),
+  (ESRMNT, "ESRMNT"),
+  (ECOMM, "ECOMM"),
+  (EPROTO, "EPROTO"),
+  (EMULTIHOP, "EMULTIHOP"),
+  (EDOTDOT, "EDOTDOT"),
+  (EBADMSG, "EBADMSG"),
+  (EOVERFLOW, "EOVERFLOW"),
+  (ENOTUNIQ, "ENOTUNIQ"),
+  (EBADFD, "EBADFD"),
+  (EREMCHG, "EREMCHG"),
+  (ELIBACC, "ELIBACC"),
+  (ELIBBAD, "ELIBBAD"),
+  (ELIBSCN, "ELIBSCN"),
+  (ELIBMAX, "ELIBMAX"),
+  (ELIBEXEC, "ELIBEXEC"),
+  (EILSEQ, "EILSEQ"),
+  (ERESTART, "ERESTART"),
+  (ESTRPIPE, "ESTRPIPE"),
+  (EUSERS, "EUSERS"),
+  (ENOTSOCK, "ENOTSOCK"),
+  (EDESTADDRREQ, "EDESTADDRREQ"),
+  (EMSGSIZE, "EMSGSIZE"),
+  (EPROTOTYPE, "EPROTOTYPE"),
+  (ENOPROTOOPT, "ENOPROTOOPT"),
+  (EPROTONOSUPPORT, "EPROTONOSUPPORT"),
+  (ESOCKTNOSUPPORT, "ESOCKTNOSUPPORT"),
+  (EOPNOTSUPP, "EOPNOTSUPP"),
+  (EPFNOSUPPORT, "EPFNOSUPPORT"),
+  (EAFNOSUPPORT, "EAFNOSUPPORT"),
+  (EADDRINUSE, "EADDRINUSE"),
+  (EADDRNOTAVAIL, "EADDRNOTAVAIL"),
+  (ENETDOWN, "ENETDOWN"),
+  (ENETUNREACH, "ENETUNREACH"),
+  (ENETRESET, "ENETRESET"),
+  (ECONNABORTED, "ECONNABORTED"),
+  (ECONNRESET, "ECONNRESET"),
+  (ENOBUFS, "ENOBUFS"),
+  (EISCONN, "EISCONN"),
+  (ENOTCONN, "ENOTCONN"),
+  (ESHUTDOWN, "ESHUTDOWN"),
+  (ETOOMANYREFS, "ETOOMANYREFS"),
+  (ETIMEDOUT, "ETIMEDOUT"),
+  (ECONNREFUSED, "ECONNREFUSED"),
+  (EHOSTDOWN, "EHOSTDOWN"),
+  (EHOSTUNREACH, "EHOSTUNREACH"),
+  (EALREADY, "EALREADY"),
+  (EINPROGRESS, "EINPROGRESS"),
+  (ESTALE, "ESTALE"),
+  (EUCLEAN, "EUCLEAN"),
+  (ENOTNAM, "ENOTNAM"),
+  (ENAVAIL, "ENAVAIL"),
+  (EISNAM, "EISNAM"),
+  (EREMOTEIO, "EREMOTEIO"),
+  (EDQUOT, "EDQUOT"),
+  (ENOMEDIUM, "ENOMEDIUM"),
+  (EMEDIUMTYPE, "EMEDIUMTYPE"),
+  (ECANCELED, "ECANCELED"),
+  (ENOKEY, "ENOKEY"),
+  (EKEYEXPIRED, "EKEYEXPIRED"),
+  (EKEYREVOKED, "EKEYREVOKED"),
+  (EKEYREJECTED, "EKEYREJECTED"),
+  (EOWNERDEAD, "EOWNERDEAD"),
+  (ENOTRECOVERABLE, "ENOTRECOVERABLE"),
+  (ERFKILL, "ERFKILL"),
+  (EHWPOISON, "EHWPOISON"),
+];
+
+/// A code from `errno`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct OsError(pub c_int);
+impl OsError {
+  /// The `errno` value of this thread, which is set by the last C call that
+  /// failed.
+  ///
+  /// Calls that work don't have to reset it, so only check this right after
+  /// a call says that it failed.
+  pub fn last_os_error() -> Self {
+    Self(unsafe { *__errno_location() })
+  }
+
+  /// The name of the code (eg: `"ENOENT"`).
+  #[must_use]
+  pub fn name(&self) -> Option<&'static str> {
+    ERRNO_NAMES.iter().find(|(code, _)| *code == self.0).map(|(_, name)| *name)
+  }
+
+  /// The code that a name is for. This knows the other names too (eg:
+  /// `"EWOULDBLOCK"`).
+  #[must_use]
+  pub fn from_name(name: &str) -> Option<Self> {
+    let code = match name {
+      "EWOULDBLOCK" => EWOULDBLOCK,
+      "EDEADLOCK" => EDEADLOCK,
+      "ENOTSUP" => ENOTSUP,
+      _ => ERRNO_NAMES.iter().find(|(_, n)| *n == name)?.0,
+    };
+    Some(Self(code))
+  }
+
+  /// The message that the C library has for the code, from `strerror_r`.
+  pub fn description(&self) -> String {
+    let mut buf = [0_u8; 256];
+    let ret = unsafe { strerror_r(self.0, buf.as_mut_ptr().cast(), buf.len()) };
+    // glibc before 2.13 gave -1 and set `errno` instead of giving the code.
+    if ret != 0 && !buf.contains(&0) {
+      return format!("Unknown error {}", self.0);
+    }
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    crate::min_alloc_lossy_into_string(buf[..len].to_vec())
+  }
+}
+impl std::error::Error for OsError {}
+
+impl core::fmt::Debug for OsError {
+  /// Displays the error using its `errno` name and `strerror_r`.
+  ///
+  /// ```
+  /// use triangle_from_scratch::os_error::*;
+  /// let s = format!("{:?}", OsError(EACCES));
+  /// assert_eq!("EACCES (Permission denied)", s);
+  /// // the message for an unknown code depends on the libc.
+  /// let unknown = format!("{:?}", OsError(4242));
+  /// assert!(unknown.starts_with("OS Error 4242 ("));
+  /// ```
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if f.alternate() {
+      return write!(f, "OsError({})", self.0);
+    }
+    match self.name() {
+      Some(name) => write!(f, "{} ({})", name, self.description()),
+      None => write!(f, "OS Error {} ({})", self.0, self.description()),
+    }
+  }
+}
+impl core::fmt::Display for OsError {
+  /// Same as `Debug` impl
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+impl From<OsError> for std::io::Error {
+  fn from(e: OsError) -> Self {
+    Self::from_raw_os_error(e.0)
+  }
+}
+
+/// Sets the `errno` value of this thread.
+pub fn set_errno(e: OsError) {
+  unsafe { *__errno_location() = e.0 }
+}
+
+#[test]
+fn test_errno_names() {
+  // the table is in order, so each code and each name is only there once.
+  for pair in ERRNO_NAMES.windows(2) {
+    assert!(pair[0].0 < pair[1].0, "{:?}", pair);
+  }
+  let mut names: Vec<&str> = ERRNO_NAMES.iter().map(|(_, n)| *n).collect();
+  names.sort_unstable();
+  names.dedup();
+  assert_eq!(names.len(), ERRNO_NAMES.len());
+  for (code, name) in ERRNO_NAMES.iter() {
+    assert_eq!(OsError(*code).name(), Some(*name));
+    assert_eq!(OsError::from_name(name), Some(OsError(*code)));
+  }
+  assert_eq!(OsError(ENOENT).name(), Some("ENOENT"));
+  assert_eq!(OsError(EWOULDBLOCK).name(), Some("EAGAIN"));
+  assert_eq!(OsError::from_name("ENOTSUP"), Some(OsError(EOPNOTSUPP)));
+  assert_eq!(OsError(0).name(), None);
+  assert_eq!(OsError(41).name(), None);
+  assert_eq!(OsError::from_name("EWHATEVER"), None);
+
+  // the names agree with what std knows about the codes.
+  use std::io::ErrorKind;
+  for (code, kind) in [
+    (ENOENT, ErrorKind::NotFound),
+    (EACCES, ErrorKind::PermissionDenied),
+    (EPERM, ErrorKind::PermissionDenied),
+    (EEXIST, ErrorKind::AlreadyExists),
+    (EINTR, ErrorKind::Interrupted),
+    (EAGAIN, ErrorKind::WouldBlock),
+    (EINVAL, ErrorKind::InvalidInput),
+    (EPIPE, ErrorKind::BrokenPipe),
+    (EADDRINUSE, ErrorKind::AddrInUse),
+    (ECONNREFUSED, ErrorKind::ConnectionRefused),
+    (ETIMEDOUT, ErrorKind::TimedOut),
+  ] {
+    assert_eq!(std::io::Error::from(OsError(code)).kind(), kind);
+  }
+
+  // glibc 2.32 and later can say the names too.
+  if let Ok(libc) = crate::dylib::Library::open("libc.so.6") {
+    #[allow(non_camel_case_types)]
+    type strerrorname_np_t =
+      Option<unsafe extern "C" fn(errnum: c_int) -> *const c_char>;
+    let f: strerrorname_np_t =
+      unsafe { libc.get(crate::c_str!("strerrorname_np")) };
+    if let Some(f) = f {
+      for (code, name) in ERRNO_NAMES.iter() {
+        let p = unsafe { f(*code) };
+        assert!(!p.is_null(), "{}", name);
+        let libc_name =
+          unsafe { crate::gather_null_terminated_bytes(p.cast()) };
+        assert_eq!(libc_name, name.as_bytes());
+      }
+    }
+  }
+}
+
+#[test]
+fn test_os_error() {
+  set_errno(OsError(EBADF));
+  assert_eq!(OsError::last_os_error(), OsError(EBADF));
+  set_errno(OsError(0));
+  assert_eq!(OsError::last_os_error(), OsError(0));
+  // a call that fails sets it.
+  extern "C" {
+    fn close(fd: c_int) -> c_int;
+  }
+  assert_eq!(unsafe { close(-1) }, -1);
+  assert_eq!(OsError::last_os_error(), OsError(EBADF));
+  assert_eq!(OsError(ENOENT).description(), "No such file or directory");
+  assert_eq!(
+    format!("{}", OsError(ECONNRESET)),
+    "ECONNRESET (Connection reset by peer)"
+  );
+  assert_eq!(format!("{:#?}", OsError(EIO)), "OsError(5)");
+  assert!(OsError(-1).to_string().starts_with("OS Error -1 ("));
+}
@@ -0,0 +1,511 @@
+//! A software rasterizer, for drawing without any GL driver.
+//!
+//! This follows GL where it's simple to: positions are in normalized device
+//! coordinates and the viewport is the whole [`Framebuffer`], rows go bottom
+//! to top like `glReadPixels` gives them, colors are rounded to the nearest
+//! `u8`, and [`Framebuffer::clear`] takes the same mask bits as `glClear`.
+//!
+//! Triangles are rasterized with edge functions on vertex positions snapped
+//! to 1/256th of a pixel, and a pixel is covered when its center is inside the
+//! triangle. Centers exactly on an edge use the top-left rule, so triangles
+//! that share an edge never both cover a pixel and never leave a gap.
+//!
+//! ```
+//! use triangle_from_scratch::{gl::GL_COLOR_BUFFER_BIT, soft::Framebuffer};
+//! let mut fb = Framebuffer::new(4, 4);
+//! fb.clear_color(0.0, 0.0, 1.0, 1.0);
+//! fb.clear(GL_COLOR_BUFFER_BIT);
+//! // the bottom right half of the framebuffer.
+//! let positions = [[-1.0, -1.0, 0.0], [1.0, -1.0, 0.0], [1.0, 1.0, 0.0]];
+//! fb.draw_triangle(positions, [[]; 3], |[]| [1.0, 0.0, 0.0, 1.0]);
+//! assert_eq!(fb.pixel(3, 0), [255, 0, 0, 255]);
+//! assert_eq!(fb.pixel(0, 3), [0, 0, 255, 255]);
+//! ```
+
+use crate::{
+  gl::{GLbitfield, GL_COLOR_BUFFER_BIT, GL_DEPTH_BUFFER_BIT},
+  image::{to_unorm8, ImageView, PixelFormat, RowOrder},
+};
+
+/// Bits of sub-pixel precision that vertex positions are snapped to.
+pub const SUBPIXEL_BITS: u32 = 8;
+const ONE: i64 = 1 << SUBPIXEL_BITS;
+
+/// Twice the signed area of `a`, `b`, `p`, which is positive when `p` is to
+/// the left of the line from `a` to `b`.
+fn edge(a: [i64; 2], b: [i64; 2], p: [i64; 2]) -> i64 {
+  (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+/// If the edge from `a` to `b` of a counter-clockwise triangle is a top edge
+/// or a left edge (with y going up).
+fn is_top_left(a: [i64; 2], b: [i64; 2]) -> bool {
+  let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+  dy < 0 || (dy == 0 && dx < 0)
+}
+
+/// How far from the origin of the window, in pixels, that triangles are
+/// clipped to before they're snapped.
+///
+/// Past this the edge functions of the snapped positions could overflow.
+const GUARD_BAND: f64 = (1 << 21) as f64;
+
+/// A vertex in window coordinates, with the barycentric weights of the
+/// original triangle's vertices at that point.
+type ClipVertex = ([f64; 2], [f64; 3]);
+
+/// Clips a triangle in window coordinates to the [`GUARD_BAND`].
+///
+/// **Returns:** The vertices of the convex polygon that's left, in the same
+/// winding as the triangle.
+fn clip_to_guard_band(window: [[f64; 2]; 3]) -> Vec<ClipVertex> {
+  let mut polygon: Vec<ClipVertex> = (0..3)
+    .map(|i| {
+      let mut weights = [0.0; 3];
+      weights[i] = 1.0;
+      (window[i], weights)
+    })
+    .collect();
+  for (axis, sign) in [(0, 1.0), (0, -1.0), (1, 1.0), (1, -1.0)] {
+    // how far inside of this side of the guard band a point is.
+    let inside = |p: &ClipVertex| GUARD_BAND - sign * p.0[axis];
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &p) in polygon.iter().enumerate() {
+      let q = polygon[(i + 1) % polygon.len()];
+      if inside(&p) >= 0.0 {
+        clipped.push(p);
+      }
+      if (inside(&p) >= 0.0) != (inside(&q) >= 0.0) {
+        // always from the same end, so that triangles which share the edge
+        // get the same point.
+        let (a, b) = if p.0 < q.0 { (p, q) } else { (q, p) };
+        let t = inside(&a) / (inside(&a) - inside(&b));
+        let lerp = |x: f64, y: f64| x + (y - x) * t;
+        let mut position = [lerp(a.0[0], b.0[0]), lerp(a.0[1], b.0[1])];
+        position[axis] = sign * GUARD_BAND;
+        let weights = [0, 1, 2].map(|v| lerp(a.1[v], b.1[v]));
+        clipped.push((position, weights));
+      }
+    }
+    polygon = clipped;
+  }
+  polygon
+}
+
+/// Finds the pixels of a `width` by `height` viewport that a triangle covers.
+///
+/// `positions` are in normalized device coordinates. For each covered pixel
+/// `fragment` gets the `x` and `y` of the pixel (with `y` going up), the
+/// barycentric weights of the three vertices at the pixel center, and the
+/// depth (the `z` mapped from `-1.0..=1.0` to `0.0..=1.0`). Triangles with no
+/// area cover nothing, and both windings are drawn.
+///
+/// Triangles that reach millions of pixels past the viewport are clipped
+/// first, which can move the pixels along the clipped edges by a fraction of a
+/// pixel. Triangles with a position that's not finite are skipped.
+pub fn rasterize_triangle(
+  width: u32, height: u32, positions: [[f32; 3]; 3],
+  mut fragment: impl FnMut(u32, u32, [f32; 3], f32),
+) {
+  if positions.iter().flatten().any(|c| !c.is_finite()) {
+    return;
+  }
+  let depths = positions.map(|p| (p[2] + 1.0) * 0.5);
+  let window = positions.map(|p| {
+    let x = (p[0] as f64 + 1.0) * 0.5 * width as f64;
+    let y = (p[1] as f64 + 1.0) * 0.5 * height as f64;
+    [x, y]
+  });
+  if window.iter().flatten().all(|c| c.abs() <= GUARD_BAND) {
+    rasterize_window(width, height, window, |x, y, weights| {
+      let depth = (0..3).map(|i| weights[i] * depths[i]).sum();
+      fragment(x, y, weights, depth);
+    });
+    return;
+  }
+  // a fan of the clipped polygon, with each weight mapped back to the
+  // original vertices.
+  let polygon = clip_to_guard_band(window);
+  for i in 1..polygon.len().saturating_sub(1) {
+    let fan = [polygon[0], polygon[i], polygon[i + 1]];
+    rasterize_window(width, height, fan.map(|v| v.0), |x, y, fan_weights| {
+      let weights = [0, 1, 2].map(|v| {
+        (0..3).map(|f| fan_weights[f] * fan[f].1[v] as f32).sum::<f32>()
+      });
+      let depth = (0..3).map(|i| weights[i] * depths[i]).sum();
+      fragment(x, y, weights, depth);
+    });
+  }
+}
+
+/// Finds the pixels that a triangle in window coordinates covers, which must
+/// be within the [`GUARD_BAND`].
+///
+/// `fragment` gets the `x` and `y` of the pixel, and the barycentric weights
+/// of the three vertices.
+fn rasterize_window(
+  width: u32, height: u32, window: [[f64; 2]; 3],
+  mut fragment: impl FnMut(u32, u32, [f32; 3]),
+) {
+  let snap = |p: [f64; 2]| -> [i64; 2] {
+    [(p[0] * ONE as f64).round() as i64, (p[1] * ONE as f64).round() as i64]
+  };
+  let mut v = window.map(snap);
+  let mut order = [0, 1, 2];
+  let mut area = edge(v[0], v[1], v[2]);
+  if area == 0 {
+    return;
+  }
+  if area < 0 {
+    v.swap(1, 2);
+    order.swap(1, 2);
+    area = -area;
+  }
+  // the pixels with a center in the bounding box.
+  let pixel_range = |axis: usize, limit: u32| {
+    let min = v.iter().map(|p| p[axis]).min().unwrap();
+    let max = v.iter().map(|p| p[axis]).max().unwrap();
+    let first = (min - ONE / 2).div_euclid(ONE).max(0);
+    let last = (max - ONE / 2).div_euclid(ONE).min(limit as i64 - 1);
+    first..=last
+  };
+  // each edge, with the vertex across from it.
+  let edges = [(v[1], v[2], 0), (v[2], v[0], 1), (v[0], v[1], 2)];
+  for y in pixel_range(1, height) {
+    for x in pixel_range(0, width) {
+      let center = [x * ONE + ONE / 2, y * ONE + ONE / 2];
+      let mut weights = [0.0; 3];
+      let covered = edges.iter().all(|&(a, b, across)| {
+        let e = edge(a, b, center);
+        weights[order[across]] = e as f32 / area as f32;
+        e > 0 || (e == 0 && is_top_left(a, b))
+      });
+      if covered {
+        fragment(x as u32, y as u32, weights);
+      }
+    }
+  }
+}
+
+/// An RGBA8 color buffer, and maybe a depth buffer.
+///
+/// Rows go from the bottom of the image to the top, with no padding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Framebuffer {
+  width: u32,
+  height: u32,
+  color: Vec<u8>,
+  depth: Option<Vec<f32>>,
+  clear_color: [f32; 4],
+  clear_depth: f32,
+}
+impl Framebuffer {
+  /// A framebuffer with only a color buffer, which starts all 0.
+  pub fn new(width: u32, height: u32) -> Self {
+    Self {
+      width,
+      height,
+      color: vec![0; width as usize * height as usize * 4],
+      depth: None,
+      clear_color: [0.0; 4],
+      clear_depth: 1.0,
+    }
+  }
+
+  /// A framebuffer with a color buffer and a depth buffer.
+  ///
+  /// The depth buffer starts all `1.0`, and [`draw_triangle`] does a depth
+  /// test like `glDepthFunc(GL_LESS)` with it.
+  ///
+  /// [`draw_triangle`]: Self::draw_triangle
+  pub fn with_depth(width: u32, height: u32) -> Self {
+    let mut fb = Self::new(width, height);
+    fb.depth = Some(vec![1.0; width as usize * height as usize]);
+    fb
+  }
+
+  pub fn width(&self) -> u32 {
+    self.width
+  }
+
+  pub fn height(&self) -> u32 {
+    self.height
+  }
+
+  /// The RGBA8 bytes of the color buffer.
+  pub fn color(&self) -> &[u8] {
+    &self.color
+  }
+
+  /// The depth buffer, if there is one.
+  pub fn depth(&self) -> Option<&[f32]> {
+    self.depth.as_deref()
+  }
+
+  /// The color buffer as an image, for saving.
+  pub fn image(&self) -> ImageView<'_> {
+    ImageView {
+      width: self.width,
+      height: self.height,
+      format: PixelFormat::Rgba8,
+      rows: RowOrder::BottomUp,
+      pixels: &self.color,
+    }
+  }
+
+  /// The color of a pixel, with `y` going up.
+  ///
+  /// ## Panics
+  /// * If the pixel is out of bounds.
+  pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+    assert!(
+      x < self.width && y < self.height,
+      "({}, {}) is out of bounds",
+      x,
+      y
+    );
+    let i = (y as usize * self.width as usize + x as usize) * 4;
+    [self.color[i], self.color[i + 1], self.color[i + 2], self.color[i + 3]]
+  }
+
+  /// Sets the color that [`clear`](Self::clear) uses, like `glClearColor`.
+  ///
+  /// The channels are clamped to `0.0..=1.0`.
+  pub fn clear_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+    self.clear_color = [r, g, b, a].map(|c| c.clamp(0.0, 1.0));
+  }
+
+  /// Sets the depth that [`clear`](Self::clear) uses, like `glClearDepth`.
+  ///
+  /// The depth is clamped to `0.0..=1.0`.
+  pub fn clear_depth(&mut self, depth: f32) {
+    self.clear_depth = depth.clamp(0.0, 1.0);
+  }
+
+  /// Clears buffers, like `glClear`.
+  ///
+  /// `mask` can have `GL_COLOR_BUFFER_BIT` and `GL_DEPTH_BUFFER_BIT`, and other
+  /// bits are ignored. Clearing the depth when there's no depth buffer does
+  /// nothing.
+  pub fn clear(&mut self, mask: GLbitfield) {
+    if mask & GL_COLOR_BUFFER_BIT != 0 {
+      let rgba = self.clear_color.map(to_unorm8);
+      for pixel in self.color.chunks_exact_mut(4) {
+        pixel.copy_from_slice(&rgba);
+      }
+    }
+    if mask & GL_DEPTH_BUFFER_BIT != 0 {
+      let clear_depth = self.clear_depth;
+      if let Some(depth) = self.depth.as_mut() {
+        depth.iter_mut().for_each(|d| *d = clear_depth);
+      }
+    }
+  }
+
+  /// Draws a triangle.
+  ///
+  /// `positions` are in normalized device coordinates. The `attributes` of
+  /// the three vertices are interpolated across the triangle, and `shader`
+  /// turns them into the RGBA color of each pixel. Fragments outside of the
+  /// `-1.0..=1.0` depth range are clipped.
+  pub fn draw_triangle<const N: usize>(
+    &mut self, positions: [[f32; 3]; 3], attributes: [[f32; N]; 3],
+    mut shader: impl FnMut([f32; N]) -> [f32; 4],
+  ) {
+    let Self { width, height, color, depth, .. } = self;
+    rasterize_triangle(*width, *height, positions, |x, y, weights, z| {
+      if !(0.0..=1.0).contains(&z) {
+        return;
+      }
+      let i = y as usize * *width as usize + x as usize;
+      if let Some(depth) = depth.as_mut() {
+        if z >= depth[i] {
+          return;
+        }
+        depth[i] = z;
+      }
+      let mut interpolated = [0.0; N];
+      for (n, value) in interpolated.iter_mut().enumerate() {
+        *value = (0..3).map(|v| weights[v] * attributes[v][n]).sum();
+      }
+      let rgba = shader(interpolated).map(to_unorm8);
+      color[i * 4..i * 4 + 4].copy_from_slice(&rgba);
+    });
+  }
+}
+
+#[test]
+fn test_soft_clear() {
+  let mut fb = Framebuffer::with_depth(3, 2);
+  assert!(fb.color().iter().all(|&c| c == 0));
+  fb.clear_color(0.6, 0.7, 0.8, 2.0);
+  fb.clear_depth(0.5);
+  fb.clear(GL_COLOR_BUFFER_BIT);
+  assert_eq!(fb.pixel(2, 1), [153, 179, 204, 255]);
+  assert!(fb.depth().unwrap().iter().all(|&d| d == 1.0));
+  fb.clear(GL_DEPTH_BUFFER_BIT);
+  assert!(fb.depth().unwrap().iter().all(|&d| d == 0.5));
+  fb.clear_color(-1.0, 0.5, 0.0, 0.0);
+  fb.clear(GL_DEPTH_BUFFER_BIT);
+  assert_eq!(fb.pixel(0, 0), [153, 179, 204, 255]);
+  fb.clear(GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT);
+  assert_eq!(fb.color()[..4], [0, 128, 0, 0]);
+}
+
+#[test]
+fn test_soft_triangle() {
+  // the triangle of the demo.
+  let positions = [[-0.2, 0.5, 0.0], [-0.5, -0.4, 0.0], [0.5, -0.1, 0.0]];
+  let mut fb = Framebuffer::new(20, 12);
+  fb.clear_color(0.6, 0.7, 0.8, 1.0);
+  fb.clear(GL_COLOR_BUFFER_BIT);
+  fb.draw_triangle(positions, [[]; 3], |[]| [1.0, 0.5, 0.313, 1.0]);
+  let expected = [
+    "....................",
+    "....................",
+    "....................",
+    "........#...........",
+    ".......####.........",
+    ".......######.......",
+    "......#########.....",
+    ".....#####..........",
+    "....................",
+    "....................",
+    "....................",
+    "....................",
+  ];
+  let mut image = Vec::new();
+  for y in (0..fb.height()).rev() {
+    let row: String = (0..fb.width())
+      .map(|x| match fb.pixel(x, y) {
+        [255, 128, 80, 255] => '#',
+        [153, 179, 204, 255] => '.',
+        _ => '?',
+      })
+      .collect();
+    image.push(row);
+  }
+  assert_eq!(image, expected, "\n{}", image.join("\n"));
+  // images count rows from the top.
+  assert_eq!(fb.image().row(3)[8 * 4..9 * 4], [255, 128, 80, 255]);
+}
+
+#[test]
+fn test_soft_fill_rule() {
+  let (width, height) = (9, 7);
+  let mut counts = vec![0; (width * height) as usize];
+  let mut count = |triangles: &[[[f32; 3]; 3]]| {
+    counts.iter_mut().for_each(|c| *c = 0);
+    for &positions in triangles {
+      rasterize_triangle(width, height, positions, |x, y, _, _| {
+        counts[(y * width + x) as usize] += 1
+      });
+    }
+    counts.clone()
+  };
+  // two triangles that make the whole viewport, which go through pixel
+  // centers on the diagonal.
+  let (a, b, c, d) =
+    ([-1.0, -1.0, 0.0], [1.0, -1.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 1.0, 0.0]);
+  assert!(count(&[[a, b, c], [a, c, d]]).iter().all(|&c| c == 1));
+  assert!(count(&[[a, c, b], [d, c, a]]).iter().all(|&c| c == 1));
+  // a fan around a point on a pixel center, with edges on rows and columns of
+  // pixel centers.
+  let center = [1.0 / 9.0, 1.0 / 7.0, 0.0];
+  let ring = [a, [1.0 / 9.0, -1.0, 0.0], b, c, [1.0 / 9.0, 1.0, 0.0], d];
+  let fan: Vec<_> =
+    (0..6).map(|i| [center, ring[i], ring[(i + 1) % 6]]).collect();
+  assert!(count(&fan).iter().all(|&c| c == 1));
+  // no area, no pixels.
+  assert!(count(&[[a, c, a]]).iter().all(|&c| c == 0));
+}
+
+#[test]
+fn test_soft_interpolation_and_depth() {
+  let mut fb = Framebuffer::with_depth(8, 8);
+  let full = |z: f32| [[-1.0, -1.0, z], [3.0, -1.0, z], [-1.0, 3.0, z]];
+  // barycentric weights are 1.0 at a vertex, and add up to 1.0.
+  let mut sums = Vec::new();
+  rasterize_triangle(8, 8, full(0.0), |x, y, weights, _| {
+    sums.push(weights.iter().sum::<f32>());
+    if (x, y) == (0, 0) {
+      assert_eq!(weights, [15.0 / 16.0, 1.0 / 32.0, 1.0 / 32.0]);
+    }
+  });
+  assert_eq!(sums.len(), 64);
+  assert!(sums.iter().all(|s| (s - 1.0).abs() < 1e-6));
+  // a red to blue gradient across x.
+  let red_to_blue = |[r, b]: [f32; 2]| -> [f32; 4] { [r, 0.0, b, 1.0] };
+  fb.draw_triangle(
+    full(0.0),
+    [[1.0, 0.0], [-1.0, 2.0], [1.0, 0.0]],
+    red_to_blue,
+  );
+  assert_eq!(fb.pixel(0, 5), [239, 0, 16, 255]);
+  assert_eq!(fb.pixel(7, 5), [16, 0, 239, 255]);
+  // further away is hidden, closer is drawn.
+  let solid = |rgba: [f32; 4]| move |[]: [f32; 0]| rgba;
+  fb.draw_triangle(full(0.5), [[]; 3], solid([0.0, 1.0, 0.0, 1.0]));
+  assert_eq!(fb.pixel(3, 3)[1], 0);
+  fb.draw_triangle(full(-0.5), [[]; 3], solid([0.0, 1.0, 0.0, 1.0]));
+  assert_eq!(fb.pixel(3, 3), [0, 255, 0, 255]);
+  assert_eq!(fb.depth().unwrap()[0], 0.25);
+  // outside the depth range is clipped.
+  fb.draw_triangle(full(-1.5), [[]; 3], solid([1.0; 4]));
+  assert_eq!(fb.pixel(3, 3), [0, 255, 0, 255]);
+}
+
+#[test]
+fn test_soft_huge_triangles() {
+  let (width, height) = (1024, 1024);
+  let mut counts = vec![0; (width * height) as usize];
+  let mut count = |triangles: &[[[f32; 3]; 3]]| {
+    counts.iter_mut().for_each(|c| *c = 0);
+    for &positions in triangles {
+      rasterize_triangle(width, height, positions, |x, y, weights, _| {
+        assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-3);
+        counts[(y * width + x) as usize] += 1
+      });
+    }
+    counts.clone()
+  };
+  // a quad far past every side, split on a diagonal that goes through pixel
+  // centers, still covers each pixel once.
+  let far = 1e5;
+  let (a, b, c, d) =
+    ([-far, -far, 0.0], [far, -far, 0.0], [far, far, 0.0], [-far, far, 0.0]);
+  assert!(count(&[[a, b, c], [a, c, d]]).iter().all(|&c| c == 1));
+  assert!(count(&[[b, d, a], [b, c, d]]).iter().all(|&c| c == 1));
+  // the edge from `a` to `c` is the same line as in a small triangle.
+  let small = count(&[[[-1.0, -1.0, 0.0], [1.0, -1.0, 0.0], [1.0, 1.0, 0.0]]]);
+  assert_eq!(count(&[[a, b, c]]), small);
+  // all off screen.
+  assert!(count(&[[[-far, 2.0, 0.0], [far, 2.0, 0.0], [0.0, far, 0.0]]])
+    .iter()
+    .all(|&c| c == 0));
+  // about as far as an `f32` goes.
+  assert!(count(&[[[-3e38, -1.0, 0.0], [3e38, -1.0, 0.0], [0.0, 3e38, 0.0]]])
+    .iter()
+    .all(|&c| c == 1));
+  // not finite is skipped.
+  for bad in [f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+    assert!(count(&[[a, b, [0.0, bad, 0.0]]]).iter().all(|&c| c == 0));
+  }
+
+  // the weights still go with the original vertices, so the third one goes
+  // from 0 at the bottom to 1 at `far`.
+  let mut top_weights = [0.0; 3];
+  rasterize_triangle(
+    width,
+    height,
+    [[-far, -1.0, 0.0], [far, -1.0, 0.0], [0.0, far, 0.0]],
+    |x, y, weights, _| {
+      if (x, y) == (512, 1023) {
+        top_weights = weights;
+      }
+    },
+  );
+  let expected = (1023.5 / 512.0) / (far + 1.0);
+  assert!((top_weights[2] - expected).abs() < 1e-6, "{:?}", top_weights);
+  assert!((top_weights[0] - top_weights[1]).abs() < 1e-6);
+}
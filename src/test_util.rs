@@ -0,0 +1,74 @@
+//! Helpers for the tests that need an X server or a GPU.
+//!
+//! Without one, such a test calls [`skip`] and returns, which still counts
+//! as a pass but prints that it was skipped.
+
+use std::io::Write;
+
+#[cfg(target_os = "linux")]
+use triangle_from_scratch::{egl::*, gl::Gl33, guard::*};
+
+/// Prints that the running test was skipped, and why.
+///
+/// This writes to stderr directly, since the test harness captures the
+/// output of `eprintln!` from tests that pass.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn skip(why: &str) {
+  let thread = std::thread::current();
+  let test = thread.name().unwrap_or("test");
+  let _ = writeln!(std::io::stderr(), "{} skipped: {}", test, why);
+}
+
+/// If there's an X server to connect to, or else the test is skipped.
+///
+/// Use eg: `xvfb-run cargo test` to run these tests without a desktop.
+#[cfg(target_os = "linux")]
+pub fn has_x_display() -> bool {
+  let has_display = std::env::var_os("DISPLAY").is_some();
+  if !has_display {
+    skip("DISPLAY isn't set");
+  }
+  has_display
+}
+
+/// Gets a headless EGL display, or else the test is skipped.
+#[cfg(target_os = "linux")]
+pub fn headless_display() -> Option<OwnedEglDisplay> {
+  if !has_headless_platform() {
+    skip("there's no headless EGL platform");
+    return None;
+  }
+  let display = unsafe { egl_get_headless_display() }.unwrap();
+  Some(unsafe { OwnedEglDisplay::from_raw(display) })
+}
+
+/// Runs `f` with a current GL 3.3 core context that draws into a pbuffer of
+/// `size`, or else the test is skipped.
+///
+/// The context, the surface, and the display are cleaned up afterwards, even
+/// if `f` panics.
+#[cfg(target_os = "linux")]
+pub fn with_headless_gl<R>(
+  size: [i32; 2], f: impl FnOnce(&Gl33) -> R,
+) -> Option<R> {
+  if !has_headless_platform() {
+    skip("there's no headless EGL platform");
+    return None;
+  }
+  unsafe {
+    let (display, surface, ctx) =
+      create_headless_context((3, 3), size).unwrap();
+    let display = OwnedEglDisplay::from_raw(display);
+    let _surface = OwnedEglSurface::from_raw(display.as_raw(), surface);
+    let _ctx = OwnedEglContext::from_raw(display.as_raw(), ctx);
+    // dropped first, so that nothing is current when the rest is destroyed.
+    let _release = ScopeGuard::new(display.as_raw(), |display| {
+      let _ = egl_make_current(display, EGL_NO_SURFACE, EGL_NO_CONTEXT);
+    });
+    let (gl, missing) = Gl33::load(|name| {
+      egl_get_proc_address(name).unwrap_or(core::ptr::null_mut()).cast_const()
+    });
+    assert!(missing.is_empty(), "{:?}", missing);
+    Some(f(&gl))
+  }
+}
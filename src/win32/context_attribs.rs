@@ -0,0 +1,91 @@
+//! The WGL names for building the attribute lists of
+//! `wglCreateContextAttribsARB` from a [`ContextRequest`].
+
+use super::*;
+use crate::context::*;
+
+/// The WGL extensions for the parts of a [`ContextRequest`].
+pub const WGL_CONTEXT_EXTENSIONS: ExtensionNames = ExtensionNames {
+  create_context: "",
+  profile: "WGL_ARB_create_context_profile",
+  es_profile: "WGL_EXT_create_context_es_profile",
+  robustness: "WGL_ARB_create_context_robustness",
+  no_error: "WGL_ARB_create_context_no_error",
+};
+
+/// The `wglCreateContextAttribsARB` enums, for [`ArbContextEnums::attribs`].
+///
+/// Check [`WGL_CONTEXT_EXTENSIONS`] first, since drivers without them reject
+/// the attributes.
+pub const WGL_CONTEXT_ENUMS: ArbContextEnums = ArbContextEnums {
+  major_version: WGL_CONTEXT_MAJOR_VERSION_ARB,
+  minor_version: WGL_CONTEXT_MINOR_VERSION_ARB,
+  flags: WGL_CONTEXT_FLAGS_ARB,
+  debug_bit: WGL_CONTEXT_DEBUG_BIT_ARB,
+  forward_compatible_bit: WGL_CONTEXT_FORWARD_COMPATIBLE_BIT_ARB,
+  robust_access_bit: WGL_CONTEXT_ROBUST_ACCESS_BIT_ARB,
+  profile_mask: WGL_CONTEXT_PROFILE_MASK_ARB,
+  core_profile_bit: WGL_CONTEXT_CORE_PROFILE_BIT_ARB,
+  compatibility_profile_bit: WGL_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB,
+  es_profile_bit: WGL_CONTEXT_ES_PROFILE_BIT_EXT,
+  reset_notification_strategy: WGL_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB,
+  no_reset_notification: WGL_NO_RESET_NOTIFICATION_ARB,
+  lose_context_on_reset: WGL_LOSE_CONTEXT_ON_RESET_ARB,
+  no_error: WGL_CONTEXT_OPENGL_NO_ERROR_ARB,
+};
+
+#[test]
+fn test_wgl_context_attribs() {
+  let request: ContextRequest<HGLRC> =
+    ContextRequest::default().forward_compatible(true).debug(true);
+  assert_eq!(
+    WGL_CONTEXT_ENUMS.attribs(&request, &ContextVersion::gl_core(3, 3)),
+    [
+      [WGL_CONTEXT_MAJOR_VERSION_ARB, 3],
+      [WGL_CONTEXT_MINOR_VERSION_ARB, 3],
+      [
+        WGL_CONTEXT_FLAGS_ARB,
+        WGL_CONTEXT_DEBUG_BIT_ARB | WGL_CONTEXT_FORWARD_COMPATIBLE_BIT_ARB
+      ],
+      [WGL_CONTEXT_PROFILE_MASK_ARB, WGL_CONTEXT_CORE_PROFILE_BIT_ARB],
+      [0, 0],
+    ]
+  );
+  // forward compatible is only for desktop GL 3.0 and later.
+  let request = request
+    .debug(false)
+    .robustness(Some(ResetNotification::LoseContextOnReset));
+  assert_eq!(
+    WGL_CONTEXT_ENUMS.attribs(&request, &ContextVersion::gles(3, 2)),
+    [
+      [WGL_CONTEXT_MAJOR_VERSION_ARB, 3],
+      [WGL_CONTEXT_MINOR_VERSION_ARB, 2],
+      [WGL_CONTEXT_FLAGS_ARB, WGL_CONTEXT_ROBUST_ACCESS_BIT_ARB],
+      [WGL_CONTEXT_PROFILE_MASK_ARB, WGL_CONTEXT_ES_PROFILE_BIT_EXT],
+      [
+        WGL_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB,
+        WGL_LOSE_CONTEXT_ON_RESET_ARB
+      ],
+      [0, 0],
+    ]
+  );
+  let request: ContextRequest<HGLRC> = ContextRequest::default().no_error(true);
+  assert_eq!(
+    WGL_CONTEXT_ENUMS.attribs(&request, &ContextVersion::gl(2, 1)),
+    [
+      [WGL_CONTEXT_MAJOR_VERSION_ARB, 2],
+      [WGL_CONTEXT_MINOR_VERSION_ARB, 1],
+      [WGL_CONTEXT_OPENGL_NO_ERROR_ARB, 1],
+      [0, 0],
+    ]
+  );
+  let extensions = vec![WGL_CONTEXT_EXTENSIONS.profile.to_string()];
+  assert_eq!(
+    WGL_CONTEXT_EXTENSIONS.missing(
+      &request,
+      &ContextVersion::gl_core(4, 6),
+      &extensions
+    ),
+    Some("WGL_ARB_create_context_no_error")
+  );
+}
@@ -0,0 +1,654 @@
+//! Window message numbers, and decoding messages into Rust types.
+//!
+//! A window procedure gets a message as a `(msg, wparam, lparam)` triple, with
+//! the meaning of the two params packed into bits differently for each
+//! message. [`MessageDecoder`] unpacks them into a [`WindowMessage`].
+//!
+//! ```
+//! use triangle_from_scratch::win32::*;
+//! let mut decoder = MessageDecoder::default();
+//! // a 640x480 client area, after being maximized
+//! let msg = decoder.decode(WM_SIZE, SIZE_MAXIMIZED, (480 << 16) | 640);
+//! assert_eq!(
+//!   msg,
+//!   WindowMessage::Size { kind: SizeKind::Maximized, width: 640, height: 480 }
+//! );
+//! ```
+
+use super::*;
+use crate::window::MouseButton;
+
+/// Sent as a signal that a window or an application should terminate.
+///
+/// * `wparam` / `lparam`: Not used.
+/// * Application Should Return: 0
+pub const WM_CLOSE: u32 = 0x0010;
+
+/// Sent when a window is being destroyed.
+///
+/// * `wparam` / `lparam`: Not used.
+/// * Application Should Return: 0
+/// * See [`WM_DESTROY`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-destroy)
+pub const WM_DESTROY: u32 = 0x0002;
+
+/// Sent when the system or another application makes a request to paint a
+/// portion of an application's window.
+///
+/// * `wparam` / `lparam`: Not used.
+/// * Application Should Return: 0
+/// * See [`WM_PAINT`](https://docs.microsoft.com/en-us/windows/win32/gdi/wm-paint)
+pub const WM_PAINT: u32 = 0x000F;
+
+/// "Non-client Create". Sent prior to the [`WM_CREATE`] message when a window
+/// is first created.
+///
+/// * `wparam`: Not used.
+/// * `lparam`: Pointer to a `CREATESTRUCT`
+/// * Application Should Return: 1 to continue, 0 to cancel.
+/// * See [`WM_NCCREATE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-nccreate)
+pub const WM_NCCREATE: u32 = 0x0081;
+
+/// Sent when an application requests that a window be created by calling the
+/// `CreateWindowEx` function.
+///
+/// * `wparam`: Not used.
+/// * `lparam`: Pointer to a `CREATESTRUCT`
+/// * Application Should Return: 0 to continue, -1 to cancel.
+/// * See [`WM_CREATE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-create)
+pub const WM_CREATE: u32 = 0x0001;
+
+/// Indicates a request to terminate an application, and is generated when the
+/// application calls the `PostQuitMessage` function.
+///
+/// * `wparam` (on `MSG` struct): The exit code that was given to
+///   `PostQuitMessage`.
+/// * `lparam`: Not used.
+/// * See [`WM_QUIT`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-quit)
+pub const WM_QUIT: u32 = 0x0012;
+
+/// Sent to a window after its size has changed.
+///
+/// * `wparam`: The type of resizing, eg: `SIZE_MINIMIZED`.
+/// * `lparam`: The new width of the client area in the low word, and the new
+///   height in the high word.
+/// * Application Should Return: 0
+/// * See [`WM_SIZE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-size)
+pub const WM_SIZE: u32 = 0x0005;
+
+/// Posted to the window with the keyboard focus when a nonsystem key is
+/// pressed.
+///
+/// * `wparam`: The virtual-key code of the key.
+/// * `lparam`: The repeat count, scan code, and flags.
+/// * Application Should Return: 0
+/// * See [`WM_KEYDOWN`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-keydown)
+pub const WM_KEYDOWN: u32 = 0x0100;
+
+/// Posted to the window with the keyboard focus when a nonsystem key is
+/// released.
+///
+/// * `wparam`: The virtual-key code of the key.
+/// * `lparam`: The repeat count, scan code, and flags.
+/// * Application Should Return: 0
+/// * See [`WM_KEYUP`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-keyup)
+pub const WM_KEYUP: u32 = 0x0101;
+
+/// Posted to a window when the cursor moves.
+///
+/// * `wparam`: Which mouse buttons and modifier keys are down.
+/// * `lparam`: The signed x coordinate of the cursor in the low word, and the
+///   signed y coordinate in the high word, relative to the client area.
+/// * Application Should Return: 0
+/// * See [`WM_MOUSEMOVE`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-mousemove)
+pub const WM_MOUSEMOVE: u32 = 0x0200;
+
+/// Posted when the left mouse button is pressed. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_LBUTTONDOWN: u32 = 0x0201;
+/// Posted when the left mouse button is released. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_LBUTTONUP: u32 = 0x0202;
+/// Posted when the right mouse button is pressed. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_RBUTTONDOWN: u32 = 0x0204;
+/// Posted when the right mouse button is released. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_RBUTTONUP: u32 = 0x0205;
+/// Posted when the middle mouse button is pressed. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_MBUTTONDOWN: u32 = 0x0207;
+/// Posted when the middle mouse button is released. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_MBUTTONUP: u32 = 0x0208;
+/// Posted when the left mouse button is double clicked. The params are like
+/// [`WM_MOUSEMOVE`]. Only sent to windows with the `CS_DBLCLKS` style.
+pub const WM_LBUTTONDBLCLK: u32 = 0x0203;
+/// Posted when the right mouse button is double clicked. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_RBUTTONDBLCLK: u32 = 0x0206;
+/// Posted when the middle mouse button is double clicked. The params are like
+/// [`WM_MOUSEMOVE`].
+pub const WM_MBUTTONDBLCLK: u32 = 0x0209;
+/// Posted when one of the extra mouse buttons is pressed. The params are like
+/// [`WM_MOUSEMOVE`], except that the high word of `wparam` is [`XBUTTON1`] or
+/// [`XBUTTON2`].
+pub const WM_XBUTTONDOWN: u32 = 0x020B;
+/// Posted when one of the extra mouse buttons is released. The params are
+/// like [`WM_XBUTTONDOWN`].
+pub const WM_XBUTTONUP: u32 = 0x020C;
+/// Posted when one of the extra mouse buttons is double clicked. The params
+/// are like [`WM_XBUTTONDOWN`].
+pub const WM_XBUTTONDBLCLK: u32 = 0x020D;
+
+/// Sent after a window has been moved.
+///
+/// * `wparam`: Not used.
+/// * `lparam`: The signed x coordinate of the client area in the low word, and
+///   the signed y coordinate in the high word, in screen coordinates.
+/// * Application Should Return: 0
+/// * See [`WM_MOVE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-move)
+pub const WM_MOVE: u32 = 0x0003;
+
+/// Sent to a window after it has gained the keyboard focus.
+///
+/// * `wparam`: The window that lost the focus, or null.
+/// * `lparam`: Not used.
+/// * Application Should Return: 0
+/// * See [`WM_SETFOCUS`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-setfocus)
+pub const WM_SETFOCUS: u32 = 0x0007;
+
+/// Sent to a window right before it loses the keyboard focus.
+///
+/// * `wparam`: The window that gets the focus, or null.
+/// * `lparam`: Not used.
+/// * Application Should Return: 0
+/// * See [`WM_KILLFOCUS`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-killfocus)
+pub const WM_KILLFOCUS: u32 = 0x0008;
+
+/// Posted by `TranslateMessage` when a [`WM_KEYDOWN`] makes a character.
+///
+/// * `wparam`: A UTF-16 code unit of the character. Characters outside the
+///   BMP come as two messages, one for each half of the surrogate pair.
+/// * `lparam`: The same repeat count, scan code, and flags as the key message.
+/// * Application Should Return: 0
+/// * See [`WM_CHAR`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-char)
+pub const WM_CHAR: u32 = 0x0102;
+
+/// Sent to the focus window when the mouse wheel is rotated.
+///
+/// * `wparam`: The signed rotation in the high word, in multiples of
+///   [`WHEEL_DELTA`], and the same mouse keys as [`WM_MOUSEMOVE`] in the low
+///   word.
+/// * `lparam`: The signed x and y of the cursor, in screen coordinates.
+/// * Application Should Return: 0
+/// * See [`WM_MOUSEWHEEL`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-mousewheel)
+pub const WM_MOUSEWHEEL: u32 = 0x020A;
+
+/// Like [`WM_MOUSEWHEEL`], for a horizontal wheel (or tilting the wheel).
+pub const WM_MOUSEHWHEEL: u32 = 0x020E;
+
+/// Sent when the DPI of a window changes, eg: it was moved to another monitor.
+///
+/// * `wparam`: The new x DPI in the low word, and the new y DPI in the high
+///   word.
+/// * `lparam`: Pointer to a [`RECT`] with a suggested new size and position.
+/// * Application Should Return: 0
+/// * See [`WM_DPICHANGED`](https://docs.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+pub const WM_DPICHANGED: u32 = 0x02E0;
+
+/// The `wparam` of [`WM_SIZE`] for a plain resize.
+pub const SIZE_RESTORED: WPARAM = 0;
+/// The `wparam` of [`WM_SIZE`] when the window was minimized.
+pub const SIZE_MINIMIZED: WPARAM = 1;
+/// The `wparam` of [`WM_SIZE`] when the window was maximized.
+pub const SIZE_MAXIMIZED: WPARAM = 2;
+/// The `wparam` of [`WM_SIZE`] for every popup window when some other window
+/// is restored.
+pub const SIZE_MAXSHOW: WPARAM = 3;
+/// The `wparam` of [`WM_SIZE`] for every popup window when some other window
+/// is maximized.
+pub const SIZE_MAXHIDE: WPARAM = 4;
+
+pub const MK_LBUTTON: u16 = 0x0001;
+pub const MK_RBUTTON: u16 = 0x0002;
+pub const MK_SHIFT: u16 = 0x0004;
+pub const MK_CONTROL: u16 = 0x0008;
+pub const MK_MBUTTON: u16 = 0x0010;
+pub const MK_XBUTTON1: u16 = 0x0020;
+pub const MK_XBUTTON2: u16 = 0x0040;
+
+/// The high word of `wparam` in the `WM_XBUTTON*` messages.
+pub const XBUTTON1: u16 = 0x0001;
+/// The high word of `wparam` in the `WM_XBUTTON*` messages.
+pub const XBUTTON2: u16 = 0x0002;
+
+/// One notch of a mouse wheel.
+pub const WHEEL_DELTA: i16 = 120;
+
+/// The kind of resize in a [`WindowMessage::Size`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeKind {
+  Restored,
+  Minimized,
+  Maximized,
+  MaxShow,
+  MaxHide,
+  Other(WPARAM),
+}
+impl SizeKind {
+  pub const fn from_wparam(wparam: WPARAM) -> Self {
+    match wparam {
+      SIZE_RESTORED => Self::Restored,
+      SIZE_MINIMIZED => Self::Minimized,
+      SIZE_MAXIMIZED => Self::Maximized,
+      SIZE_MAXSHOW => Self::MaxShow,
+      SIZE_MAXHIDE => Self::MaxHide,
+      other => Self::Other(other),
+    }
+  }
+}
+
+/// The `lparam` of the key messages, and of [`WM_CHAR`].
+///
+/// * See [Keystroke Message Flags](https://docs.microsoft.com/en-us/windows/win32/inputdev/about-keyboard-input#keystroke-message-flags)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyFlags {
+  /// How many times the key auto-repeated, which were put into one message.
+  pub repeat_count: u16,
+  /// The OEM scan code.
+  pub scan_code: u8,
+  /// The key is one of the extended keys, eg: the right-hand Alt and Ctrl,
+  /// or the arrow keys that aren't on the numeric keypad.
+  pub extended: bool,
+  /// The Alt key is down.
+  pub alt_down: bool,
+  /// The key was already down before this message (ie: it's a repeat).
+  pub was_down: bool,
+  /// The key is being released.
+  pub released: bool,
+}
+impl KeyFlags {
+  pub const fn from_lparam(lparam: LPARAM) -> Self {
+    let bits = lparam as u32;
+    Self {
+      repeat_count: bits as u16,
+      scan_code: (bits >> 16) as u8,
+      extended: bits & (1 << 24) != 0,
+      alt_down: bits & (1 << 29) != 0,
+      was_down: bits & (1 << 30) != 0,
+      released: bits & (1 << 31) != 0,
+    }
+  }
+}
+
+/// The mouse buttons and modifier keys that are down during a mouse message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MouseKeys(pub u16);
+impl MouseKeys {
+  /// If all the `MK_*` bits of `mk` are set.
+  pub const fn contains(self, mk: u16) -> bool {
+    self.0 & mk == mk
+  }
+}
+
+/// A window message, decoded by a [`MessageDecoder`].
+///
+/// Coordinates are sign extended, since they can be negative on a multiple
+/// monitor setup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowMessage {
+  /// [`WM_CLOSE`]
+  Close,
+  /// [`WM_DESTROY`]
+  Destroy,
+  /// [`WM_PAINT`]
+  Paint,
+  /// [`WM_SIZE`], with the size of the client area.
+  Size { kind: SizeKind, width: u32, height: u32 },
+  /// [`WM_MOVE`], with the screen position of the client area.
+  Move { x: i32, y: i32 },
+  /// [`WM_KEYDOWN`]
+  KeyDown { vk: u32, flags: KeyFlags },
+  /// [`WM_KEYUP`]
+  KeyUp { vk: u32, flags: KeyFlags },
+  /// [`WM_CHAR`], once a whole character has arrived. A lone surrogate
+  /// becomes [`char::REPLACEMENT_CHARACTER`].
+  Char { ch: char, flags: KeyFlags },
+  /// A [`WM_CHAR`] that came after a high surrogate which didn't get its
+  /// pair. There are two characters: [`char::REPLACEMENT_CHARACTER`] for the
+  /// lone surrogate, and then `ch`.
+  CharAfterLoneSurrogate { ch: char, flags: KeyFlags },
+  /// A [`WM_CHAR`] with the first half of a surrogate pair, which the
+  /// decoder keeps until the second half comes.
+  HighSurrogate(u16),
+  /// [`WM_MOUSEMOVE`], relative to the client area.
+  MouseMove { x: i32, y: i32, keys: MouseKeys },
+  /// A mouse button was pressed, released, or double clicked, relative to
+  /// the client area. The extra buttons are `Other(1)` and `Other(2)`.
+  MouseButton {
+    button: MouseButton,
+    action: ButtonAction,
+    x: i32,
+    y: i32,
+    keys: MouseKeys,
+  },
+  /// [`WM_MOUSEWHEEL`] or [`WM_MOUSEHWHEEL`], relative to the screen. The
+  /// delta is positive for away from the user, or to the right.
+  MouseWheel { delta: i16, horizontal: bool, x: i32, y: i32, keys: MouseKeys },
+  /// [`WM_DPICHANGED`]. The suggested rect is only valid during the message.
+  DpiChanged { dpi_x: u16, dpi_y: u16, suggested_rect: *const RECT },
+  /// [`WM_SETFOCUS`], with the window that lost the focus (or null).
+  SetFocus { previous: HWND },
+  /// [`WM_KILLFOCUS`], with the window that gets the focus (or null).
+  KillFocus { next: HWND },
+  /// Any message that isn't decoded.
+  Other { msg: UINT, wparam: WPARAM, lparam: LPARAM },
+}
+
+/// What happened to the button in a [`WindowMessage::MouseButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonAction {
+  Down,
+  Up,
+  DoubleClick,
+}
+
+/// The low word of a param.
+const fn loword(x: usize) -> u16 {
+  x as u16
+}
+
+/// The high word of a param (of the low 32 bits, on 64-bit).
+const fn hiword(x: usize) -> u16 {
+  (x >> 16) as u16
+}
+
+/// The low and high words of a param, sign extended.
+pub const fn signed_words(lparam: LPARAM) -> (i32, i32) {
+  (loword(lparam as usize) as i16 as i32, hiword(lparam as usize) as i16 as i32)
+}
+
+/// Turns window messages into [`WindowMessage`] values.
+///
+/// It has to be kept between messages (eg: in the window's userdata), because
+/// a character outside of the BMP comes in two [`WM_CHAR`] messages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageDecoder {
+  high_surrogate: Option<u16>,
+}
+impl MessageDecoder {
+  pub fn decode(
+    &mut self, msg: UINT, wparam: WPARAM, lparam: LPARAM,
+  ) -> WindowMessage {
+    use WindowMessage as M;
+    let mouse_button = |button, action| {
+      let (x, y) = signed_words(lparam);
+      let keys = MouseKeys(loword(wparam));
+      M::MouseButton { button, action, x, y, keys }
+    };
+    let x_button = MouseButton::Other(u32::from(hiword(wparam)));
+    match msg {
+      WM_CLOSE => M::Close,
+      WM_DESTROY => M::Destroy,
+      WM_PAINT => M::Paint,
+      WM_SIZE => M::Size {
+        kind: SizeKind::from_wparam(wparam),
+        width: u32::from(loword(lparam as usize)),
+        height: u32::from(hiword(lparam as usize)),
+      },
+      WM_MOVE => {
+        let (x, y) = signed_words(lparam);
+        M::Move { x, y }
+      }
+      WM_KEYDOWN => {
+        M::KeyDown { vk: wparam as u32, flags: KeyFlags::from_lparam(lparam) }
+      }
+      WM_KEYUP => {
+        M::KeyUp { vk: wparam as u32, flags: KeyFlags::from_lparam(lparam) }
+      }
+      WM_CHAR => {
+        self.decode_char(loword(wparam), KeyFlags::from_lparam(lparam))
+      }
+      WM_MOUSEMOVE => {
+        let (x, y) = signed_words(lparam);
+        M::MouseMove { x, y, keys: MouseKeys(loword(wparam)) }
+      }
+      WM_LBUTTONDOWN => mouse_button(MouseButton::Left, ButtonAction::Down),
+      WM_LBUTTONUP => mouse_button(MouseButton::Left, ButtonAction::Up),
+      WM_LBUTTONDBLCLK => {
+        mouse_button(MouseButton::Left, ButtonAction::DoubleClick)
+      }
+      WM_RBUTTONDOWN => mouse_button(MouseButton::Right, ButtonAction::Down),
+      WM_RBUTTONUP => mouse_button(MouseButton::Right, ButtonAction::Up),
+      WM_RBUTTONDBLCLK => {
+        mouse_button(MouseButton::Right, ButtonAction::DoubleClick)
+      }
+      WM_MBUTTONDOWN => mouse_button(MouseButton::Middle, ButtonAction::Down),
+      WM_MBUTTONUP => mouse_button(MouseButton::Middle, ButtonAction::Up),
+      WM_MBUTTONDBLCLK => {
+        mouse_button(MouseButton::Middle, ButtonAction::DoubleClick)
+      }
+      WM_XBUTTONDOWN => mouse_button(x_button, ButtonAction::Down),
+      WM_XBUTTONUP => mouse_button(x_button, ButtonAction::Up),
+      WM_XBUTTONDBLCLK => mouse_button(x_button, ButtonAction::DoubleClick),
+      WM_MOUSEWHEEL | WM_MOUSEHWHEEL => {
+        let (x, y) = signed_words(lparam);
+        M::MouseWheel {
+          delta: hiword(wparam) as i16,
+          horizontal: msg == WM_MOUSEHWHEEL,
+          x,
+          y,
+          keys: MouseKeys(loword(wparam)),
+        }
+      }
+      WM_DPICHANGED => M::DpiChanged {
+        dpi_x: loword(wparam),
+        dpi_y: hiword(wparam),
+        suggested_rect: lparam as *const RECT,
+      },
+      WM_SETFOCUS => M::SetFocus { previous: wparam as HWND },
+      WM_KILLFOCUS => M::KillFocus { next: wparam as HWND },
+      _ => M::Other { msg, wparam, lparam },
+    }
+  }
+
+  /// Puts surrogate pairs back together. A high surrogate that isn't
+  /// followed by a low one becomes [`char::REPLACEMENT_CHARACTER`], given
+  /// with whatever came after it.
+  fn decode_char(&mut self, unit: u16, flags: KeyFlags) -> WindowMessage {
+    let mut pending = self.high_surrogate.take();
+    let ch = match unit {
+      0xD800..=0xDBFF => {
+        self.high_surrogate = Some(unit);
+        return match pending {
+          Some(_) => {
+            WindowMessage::Char { ch: char::REPLACEMENT_CHARACTER, flags }
+          }
+          None => WindowMessage::HighSurrogate(unit),
+        };
+      }
+      0xDC00..=0xDFFF => match pending.take() {
+        Some(high) => {
+          let c = 0x10000
+            + ((u32::from(high) - 0xD800) << 10)
+            + (u32::from(unit) - 0xDC00);
+          char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER)
+        }
+        None => char::REPLACEMENT_CHARACTER,
+      },
+      _ => {
+        char::from_u32(u32::from(unit)).unwrap_or(char::REPLACEMENT_CHARACTER)
+      }
+    };
+    match pending {
+      Some(_) => WindowMessage::CharAfterLoneSurrogate { ch, flags },
+      None => WindowMessage::Char { ch, flags },
+    }
+  }
+}
+
+#[test]
+fn test_decode_size_and_move() {
+  let mut d = MessageDecoder::default();
+  assert_eq!(
+    d.decode(WM_SIZE, SIZE_RESTORED, 0xFFFF_0001_u32 as isize),
+    WindowMessage::Size { kind: SizeKind::Restored, width: 1, height: 0xFFFF }
+  );
+  assert_eq!(
+    d.decode(WM_SIZE, 7, 0),
+    WindowMessage::Size { kind: SizeKind::Other(7), width: 0, height: 0 }
+  );
+  // a window on a monitor left of and above the primary one.
+  let lparam = ((-300_i16 as u16 as isize) << 16) | (-1920_i16 as u16 as isize);
+  assert_eq!(
+    d.decode(WM_MOVE, 0, lparam),
+    WindowMessage::Move { x: -1920, y: -300 }
+  );
+}
+
+#[test]
+fn test_decode_keys() {
+  let mut d = MessageDecoder::default();
+  // right Ctrl held down: repeat count 3, scan code 0x1D, extended, repeat.
+  let lparam = (1 << 30) | (1 << 24) | (0x1D << 16) | 3;
+  let flags = KeyFlags {
+    repeat_count: 3,
+    scan_code: 0x1D,
+    extended: true,
+    alt_down: false,
+    was_down: true,
+    released: false,
+  };
+  assert_eq!(
+    d.decode(WM_KEYDOWN, 0x11, lparam),
+    WindowMessage::KeyDown { vk: 0x11, flags }
+  );
+  // the key up of 'A' has the transition and previous state bits set, which
+  // makes the lparam negative on 32-bit.
+  let lparam = (0xC01E_0001_u32 as i32) as isize;
+  let flags = KeyFlags::from_lparam(lparam);
+  assert_eq!((flags.repeat_count, flags.scan_code), (1, 0x1E));
+  assert!(flags.was_down && flags.released && !flags.extended);
+  assert_eq!(
+    d.decode(WM_KEYUP, 0x41, lparam),
+    WindowMessage::KeyUp { vk: 0x41, flags }
+  );
+}
+
+#[test]
+fn test_decode_char() {
+  let mut d = MessageDecoder::default();
+  let flags = KeyFlags { repeat_count: 1, ..KeyFlags::default() };
+  assert_eq!(
+    d.decode(WM_CHAR, 'é' as usize, 1),
+    WindowMessage::Char { ch: 'é', flags }
+  );
+  // U+1F600 is D83D DE00 in UTF-16.
+  assert_eq!(
+    d.decode(WM_CHAR, 0xD83D, 1),
+    WindowMessage::HighSurrogate(0xD83D)
+  );
+  assert_eq!(
+    d.decode(WM_CHAR, 0xDE00, 1),
+    WindowMessage::Char { ch: '😀', flags }
+  );
+  // a lone low surrogate, and a high surrogate that never gets its pair.
+  let replacement =
+    WindowMessage::Char { ch: char::REPLACEMENT_CHARACTER, flags };
+  assert_eq!(d.decode(WM_CHAR, 0xDE00, 1), replacement);
+  d.decode(WM_CHAR, 0xD83D, 1);
+  assert_eq!(
+    d.decode(WM_CHAR, 'x' as usize, 1),
+    WindowMessage::CharAfterLoneSurrogate { ch: 'x', flags }
+  );
+  assert_eq!(d.decode(WM_CHAR, 0xDE00, 1), replacement);
+  // two high surrogates in a row: the first is lone, the second pairs up.
+  d.decode(WM_CHAR, 0xD83D, 1);
+  assert_eq!(d.decode(WM_CHAR, 0xD83D, 1), replacement);
+  assert_eq!(
+    d.decode(WM_CHAR, 0xDE00, 1),
+    WindowMessage::Char { ch: '😀', flags }
+  );
+  // other messages in between don't lose the pending half.
+  d.decode(WM_CHAR, 0xD83D, 1);
+  d.decode(WM_KEYUP, 0x41, 0);
+  assert_eq!(
+    d.decode(WM_CHAR, 0xDE00, 1),
+    WindowMessage::Char { ch: '😀', flags }
+  );
+}
+
+#[test]
+fn test_decode_mouse() {
+  let mut d = MessageDecoder::default();
+  let lparam = ((-5_i16 as u16 as isize) << 16) | 10;
+  assert_eq!(
+    d.decode(WM_MOUSEMOVE, usize::from(MK_LBUTTON | MK_SHIFT), lparam),
+    WindowMessage::MouseMove {
+      x: 10,
+      y: -5,
+      keys: MouseKeys(MK_LBUTTON | MK_SHIFT)
+    }
+  );
+  let keys = MouseKeys(MK_RBUTTON);
+  assert!(keys.contains(MK_RBUTTON) && !keys.contains(MK_RBUTTON | MK_CONTROL));
+  assert_eq!(
+    d.decode(WM_RBUTTONUP, 0, lparam),
+    WindowMessage::MouseButton {
+      button: MouseButton::Right,
+      action: ButtonAction::Up,
+      x: 10,
+      y: -5,
+      keys: MouseKeys(0),
+    }
+  );
+  let wparam = (usize::from(XBUTTON2) << 16) | usize::from(MK_XBUTTON2);
+  assert_eq!(
+    d.decode(WM_XBUTTONDBLCLK, wparam, 0),
+    WindowMessage::MouseButton {
+      button: MouseButton::Other(2),
+      action: ButtonAction::DoubleClick,
+      x: 0,
+      y: 0,
+      keys: MouseKeys(MK_XBUTTON2),
+    }
+  );
+  // one notch towards the user, with Ctrl held.
+  let wparam = ((-WHEEL_DELTA as u16 as usize) << 16) | usize::from(MK_CONTROL);
+  assert_eq!(
+    d.decode(WM_MOUSEWHEEL, wparam, lparam),
+    WindowMessage::MouseWheel {
+      delta: -120,
+      horizontal: false,
+      x: 10,
+      y: -5,
+      keys: MouseKeys(MK_CONTROL),
+    }
+  );
+}
+
+#[test]
+fn test_decode_dpi_and_focus() {
+  let mut d = MessageDecoder::default();
+  let rect = RECT { left: 100, top: 200, right: 900, bottom: 800 };
+  let msg =
+    d.decode(WM_DPICHANGED, (144 << 16) | 144, &rect as *const _ as isize);
+  match msg {
+    WindowMessage::DpiChanged { dpi_x: 144, dpi_y: 144, suggested_rect } => {
+      assert_eq!(unsafe { *suggested_rect }, rect)
+    }
+    other => panic!("{:?}", other),
+  }
+  assert_eq!(
+    d.decode(WM_SETFOCUS, 0, 0),
+    WindowMessage::SetFocus { previous: core::ptr::null_mut() }
+  );
+  assert_eq!(
+    d.decode(WM_KILLFOCUS, 0x1234, 0),
+    WindowMessage::KillFocus { next: 0x1234 as HWND }
+  );
+  assert_eq!(
+    d.decode(WM_CREATE, 1, -1),
+    WindowMessage::Other { msg: WM_CREATE, wparam: 1, lparam: -1 }
+  );
+}
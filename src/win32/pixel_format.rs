@@ -0,0 +1,549 @@
+//! Building the attribute lists of `wglChoosePixelFormatARB`, and decoding
+//! what a pixel format has.
+
+use super::*;
+use crate::format::FormatDesc;
+
+/// The hardware acceleration of a pixel format (`WGL_ACCELERATION_ARB`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Acceleration {
+  /// Only a software implementation (`WGL_NO_ACCELERATION_ARB`).
+  None,
+  /// The old MCD drivers (`WGL_GENERIC_ACCELERATION_ARB`).
+  Generic,
+  /// A real ICD driver (`WGL_FULL_ACCELERATION_ARB`).
+  Full,
+}
+impl Acceleration {
+  pub const fn to_wgl(self) -> c_int {
+    match self {
+      Self::None => WGL_NO_ACCELERATION_ARB,
+      Self::Generic => WGL_GENERIC_ACCELERATION_ARB,
+      Self::Full => WGL_FULL_ACCELERATION_ARB,
+    }
+  }
+
+  pub const fn from_wgl(value: c_int) -> Option<Self> {
+    match value {
+      WGL_NO_ACCELERATION_ARB => Some(Self::None),
+      WGL_GENERIC_ACCELERATION_ARB => Some(Self::Generic),
+      WGL_FULL_ACCELERATION_ARB => Some(Self::Full),
+      _ => None,
+    }
+  }
+}
+
+/// The criteria for `wglChoosePixelFormatARB`, as `[key, value]` lists that
+/// are always terminated by a zero key.
+///
+/// The default asks for a format that can draw to a window with GL in RGBA,
+/// and has nothing else in it. Setting a key that's already in a list
+/// replaces its value.
+///
+/// ```
+/// use triangle_from_scratch::win32::*;
+/// let attribs = PixelFormatAttribs::default()
+///   .color_bits(32)
+///   .depth_bits(24)
+///   .double_buffer(true)
+///   .acceleration(Acceleration::Full);
+/// assert_eq!(attribs.get_int(WGL_DEPTH_BITS_ARB), Some(24));
+/// assert_eq!(attribs.int_attribs().last(), Some(&[0, 0]));
+/// assert_eq!(attribs.float_attribs(), &[[0.0, 0.0]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelFormatAttribs {
+  int_attribs: Vec<[c_int; 2]>,
+  float_attribs: Vec<[FLOAT; 2]>,
+}
+impl Default for PixelFormatAttribs {
+  fn default() -> Self {
+    Self::empty()
+      .int(WGL_DRAW_TO_WINDOW_ARB, true as _)
+      .int(WGL_SUPPORT_OPENGL_ARB, true as _)
+      .int(WGL_PIXEL_TYPE_ARB, WGL_TYPE_RGBA_ARB)
+  }
+}
+impl PixelFormatAttribs {
+  /// No criteria at all, so any pixel format matches.
+  pub fn empty() -> Self {
+    Self { int_attribs: vec![[0, 0]], float_attribs: vec![[0.0, 0.0]] }
+  }
+
+  /// Sets any integer attribute.
+  ///
+  /// ## Panics
+  /// * If `key` is 0, since that's the terminator.
+  pub fn int(mut self, key: c_int, value: c_int) -> Self {
+    assert!(key != 0, "0 can't be used as a key");
+    let terminator = self.int_attribs.len() - 1;
+    match self.int_attribs[..terminator].iter_mut().find(|[k, _]| *k == key) {
+      Some(pair) => pair[1] = value,
+      None => self.int_attribs.insert(terminator, [key, value]),
+    }
+    self
+  }
+
+  /// Sets any float attribute. The float list is for the same keys as the
+  /// integer list, for drivers that compare them as floats.
+  ///
+  /// ## Panics
+  /// * If `key` is 0, since that's the terminator.
+  pub fn float(mut self, key: c_int, value: FLOAT) -> Self {
+    assert!(key != 0, "0 can't be used as a key");
+    let key = key as FLOAT;
+    let terminator = self.float_attribs.len() - 1;
+    match self.float_attribs[..terminator].iter_mut().find(|[k, _]| *k == key) {
+      Some(pair) => pair[1] = value,
+      None => self.float_attribs.insert(terminator, [key, value]),
+    }
+    self
+  }
+
+  /// Bits of color, not counting alpha.
+  pub fn color_bits(self, bits: u8) -> Self {
+    self.int(WGL_COLOR_BITS_ARB, bits.into())
+  }
+
+  pub fn alpha_bits(self, bits: u8) -> Self {
+    self.int(WGL_ALPHA_BITS_ARB, bits.into())
+  }
+
+  pub fn depth_bits(self, bits: u8) -> Self {
+    self.int(WGL_DEPTH_BITS_ARB, bits.into())
+  }
+
+  pub fn stencil_bits(self, bits: u8) -> Self {
+    self.int(WGL_STENCIL_BITS_ARB, bits.into())
+  }
+
+  pub fn double_buffer(self, double_buffer: bool) -> Self {
+    self.int(WGL_DOUBLE_BUFFER_ARB, double_buffer as _)
+  }
+
+  /// Needs `WGL_EXT_framebuffer_sRGB` (or the ARB version).
+  pub fn srgb(self, srgb: bool) -> Self {
+    self.int(WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT, srgb as _)
+  }
+
+  /// The number of multisample buffers, which is 0 or 1. Needs
+  /// `WGL_ARB_multisample`.
+  pub fn sample_buffers(self, buffers: u8) -> Self {
+    self.int(WGL_SAMPLE_BUFFERS_ARB, buffers.into())
+  }
+
+  /// The samples per pixel of the multisample buffer. Needs
+  /// `WGL_ARB_multisample`.
+  pub fn samples(self, samples: u8) -> Self {
+    self.int(WGL_SAMPLES_ARB, samples.into())
+  }
+
+  pub fn acceleration(self, acceleration: Acceleration) -> Self {
+    self.int(WGL_ACCELERATION_ARB, acceleration.to_wgl())
+  }
+
+  /// The value of an integer attribute, if it's set.
+  pub fn get_int(&self, key: c_int) -> Option<c_int> {
+    self
+      .int_attribs
+      .iter()
+      .find(|[k, _]| *k == key && key != 0)
+      .map(|[_, v]| *v)
+  }
+
+  /// The integer list, ending with `[0, 0]`.
+  pub fn int_attribs(&self) -> &[[c_int; 2]] {
+    &self.int_attribs
+  }
+
+  /// The float list, ending with `[0.0, 0.0]`.
+  pub fn float_attribs(&self) -> &[[FLOAT; 2]] {
+    &self.float_attribs
+  }
+}
+
+/// The names of the `PFD_*` flags of [`PIXELFORMATDESCRIPTOR::dwFlags`],
+/// without the `PFD_` prefix.
+pub const PFD_FLAG_NAMES: [(u32, &str); 16] = [
+  (PFD_DOUBLEBUFFER, "DOUBLEBUFFER"),
+  (PFD_STEREO, "STEREO"),
+  (PFD_DRAW_TO_WINDOW, "DRAW_TO_WINDOW"),
+  (PFD_DRAW_TO_BITMAP, "DRAW_TO_BITMAP"),
+  (PFD_SUPPORT_GDI, "SUPPORT_GDI"),
+  (PFD_SUPPORT_OPENGL, "SUPPORT_OPENGL"),
+  (PFD_GENERIC_FORMAT, "GENERIC_FORMAT"),
+  (PFD_NEED_PALETTE, "NEED_PALETTE"),
+  (PFD_NEED_SYSTEM_PALETTE, "NEED_SYSTEM_PALETTE"),
+  (PFD_SWAP_EXCHANGE, "SWAP_EXCHANGE"),
+  (PFD_SWAP_COPY, "SWAP_COPY"),
+  (PFD_SWAP_LAYER_BUFFERS, "SWAP_LAYER_BUFFERS"),
+  (PFD_GENERIC_ACCELERATED, "GENERIC_ACCELERATED"),
+  (PFD_SUPPORT_DIRECTDRAW, "SUPPORT_DIRECTDRAW"),
+  (PFD_DIRECT3D_ACCELERATED, "DIRECT3D_ACCELERATED"),
+  (PFD_SUPPORT_COMPOSITION, "SUPPORT_COMPOSITION"),
+];
+
+/// The `PFD_*` names of the set bits of `flags`, and any bits without a name.
+pub fn pfd_flag_names(flags: u32) -> (Vec<&'static str>, u32) {
+  let names = PFD_FLAG_NAMES
+    .iter()
+    .filter(|(bit, _)| flags & bit != 0)
+    .map(|(_, name)| *name)
+    .collect();
+  let known = PFD_FLAG_NAMES.iter().fold(0, |acc, (bit, _)| acc | bit);
+  (names, flags & !known)
+}
+
+/// The kind of pixels of a pixel format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelType {
+  Rgba,
+  ColorIndex,
+  Other(u8),
+}
+
+/// The layer of a pixel format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayerType {
+  Main,
+  Overlay,
+  Underlay,
+  Other(u8),
+}
+
+/// The size and position of one channel in a pixel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Channel {
+  pub bits: u8,
+  pub shift: u8,
+}
+
+/// A pixel format, decoded from its [`PIXELFORMATDESCRIPTOR`] and (when the
+/// driver has `WGL_ARB_pixel_format`) its `WGL_*_ARB` attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PixelFormatInfo {
+  /// The index of the format, which starts at 1.
+  pub index: c_int,
+  /// The `PFD_*` flags.
+  pub flags: u32,
+  pub pixel_type: PixelType,
+  /// Bits of color, not counting alpha.
+  pub color_bits: u8,
+  pub red: Channel,
+  pub green: Channel,
+  pub blue: Channel,
+  pub alpha: Channel,
+  /// The bits of the accumulation buffer in total, and for each channel.
+  pub accum_bits: u8,
+  pub accum_rgba: [u8; 4],
+  pub depth_bits: u8,
+  pub stencil_bits: u8,
+  pub aux_buffers: u8,
+  pub layer_type: LayerType,
+  /// From the flags, unless `WGL_ACCELERATION_ARB` says otherwise.
+  pub acceleration: Acceleration,
+  /// `WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT`, if it could be asked.
+  pub srgb: Option<bool>,
+  /// `WGL_SAMPLES_ARB` (0 without a multisample buffer), if it could be
+  /// asked.
+  pub samples: Option<u8>,
+}
+impl PixelFormatInfo {
+  /// The `WGL_*_ARB` attributes that [`set_arb_attrib`](Self::set_arb_attrib)
+  /// uses.
+  pub const ARB_KEYS: [c_int; 4] = [
+    WGL_ACCELERATION_ARB,
+    WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT,
+    WGL_SAMPLE_BUFFERS_ARB,
+    WGL_SAMPLES_ARB,
+  ];
+
+  pub fn from_descriptor(index: c_int, pfd: &PIXELFORMATDESCRIPTOR) -> Self {
+    let flags = pfd.dwFlags;
+    let acceleration = if flags & PFD_GENERIC_FORMAT == 0 {
+      Acceleration::Full
+    } else if flags & PFD_GENERIC_ACCELERATED != 0 {
+      Acceleration::Generic
+    } else {
+      Acceleration::None
+    };
+    Self {
+      index,
+      flags,
+      pixel_type: match pfd.iPixelType {
+        PFD_TYPE_RGBA => PixelType::Rgba,
+        PFD_TYPE_COLORINDEX => PixelType::ColorIndex,
+        other => PixelType::Other(other),
+      },
+      color_bits: pfd.cColorBits,
+      red: Channel { bits: pfd.cRedBits, shift: pfd.cRedShift },
+      green: Channel { bits: pfd.cGreenBits, shift: pfd.cGreenShift },
+      blue: Channel { bits: pfd.cBlueBits, shift: pfd.cBlueShift },
+      alpha: Channel { bits: pfd.cAlphaBits, shift: pfd.cAlphaShift },
+      accum_bits: pfd.cAccumBits,
+      accum_rgba: [
+        pfd.cAccumRedBits,
+        pfd.cAccumGreenBits,
+        pfd.cAccumBlueBits,
+        pfd.cAccumAlphaBits,
+      ],
+      depth_bits: pfd.cDepthBits,
+      stencil_bits: pfd.cStencilBits,
+      aux_buffers: pfd.cAuxBuffers,
+      layer_type: match pfd.iLayerType {
+        PFD_MAIN_PLANE => LayerType::Main,
+        PFD_OVERLAY_PLANE => LayerType::Overlay,
+        PFD_UNDERLAY_PLANE => LayerType::Underlay,
+        other => LayerType::Other(other),
+      },
+      acceleration,
+      srgb: None,
+      samples: None,
+    }
+  }
+
+  /// Fills in the value of one of the [`ARB_KEYS`](Self::ARB_KEYS). Other
+  /// keys are ignored.
+  pub fn set_arb_attrib(&mut self, key: c_int, value: c_int) {
+    match key {
+      WGL_ACCELERATION_ARB => {
+        if let Some(acceleration) = Acceleration::from_wgl(value) {
+          self.acceleration = acceleration;
+        }
+      }
+      WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT => self.srgb = Some(value != 0),
+      // no sample buffer means no samples, whatever the driver says.
+      WGL_SAMPLE_BUFFERS_ARB if value == 0 => self.samples = Some(0),
+      WGL_SAMPLES_ARB if self.samples != Some(0) => {
+        self.samples = Some(value.clamp(0, u8::MAX.into()) as u8)
+      }
+      _ => (),
+    }
+  }
+
+  pub fn has_flags(&self, flags: u32) -> bool {
+    self.flags & flags == flags
+  }
+}
+impl From<&PixelFormatInfo> for FormatDesc {
+  /// Formats that can't be used for a GL window (without
+  /// `PFD_DRAW_TO_WINDOW`, `PFD_SUPPORT_OPENGL`, or RGBA) should be left out
+  /// of the candidates, since the description doesn't say.
+  fn from(info: &PixelFormatInfo) -> Self {
+    Self {
+      id: info.index,
+      red_bits: info.red.bits,
+      green_bits: info.green.bits,
+      blue_bits: info.blue.bits,
+      alpha_bits: info.alpha.bits,
+      depth_bits: info.depth_bits,
+      stencil_bits: info.stencil_bits,
+      samples: info.samples.unwrap_or(0),
+      srgb: info.srgb.unwrap_or(false),
+      double_buffer: info.has_flags(PFD_DOUBLEBUFFER),
+      accelerated: info.acceleration == Acceleration::Full,
+    }
+  }
+}
+impl core::fmt::Display for PixelFormatInfo {
+  /// One line, eg: `#7: RGBA 24+8 (R8@16 G8@8 B8@0 A8@24), depth 24, ...`
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "#{}: ", self.index)?;
+    match self.pixel_type {
+      PixelType::Rgba => write!(f, "RGBA")?,
+      PixelType::ColorIndex => write!(f, "color index")?,
+      PixelType::Other(t) => write!(f, "pixel type {}", t)?,
+    }
+    write!(f, " {}+{} (", self.color_bits, self.alpha.bits)?;
+    let channels =
+      [('R', self.red), ('G', self.green), ('B', self.blue), ('A', self.alpha)];
+    for (i, (name, c)) in channels.iter().enumerate() {
+      let sep = if i > 0 { " " } else { "" };
+      write!(f, "{}{}{}@{}", sep, name, c.bits, c.shift)?;
+    }
+    write!(f, "), depth {}, stencil {}", self.depth_bits, self.stencil_bits)?;
+    if self.accum_bits > 0 {
+      write!(f, ", accum {}", self.accum_bits)?;
+    }
+    if self.aux_buffers > 0 {
+      write!(f, ", aux {}", self.aux_buffers)?;
+    }
+    match self.layer_type {
+      LayerType::Main => (),
+      LayerType::Overlay => write!(f, ", overlay")?,
+      LayerType::Underlay => write!(f, ", underlay")?,
+      LayerType::Other(t) => write!(f, ", layer type {}", t)?,
+    }
+    match self.acceleration {
+      Acceleration::Full => write!(f, ", full acceleration")?,
+      Acceleration::Generic => write!(f, ", generic acceleration")?,
+      Acceleration::None => write!(f, ", no acceleration")?,
+    }
+    if self.srgb == Some(true) {
+      write!(f, ", sRGB")?;
+    }
+    match self.samples {
+      Some(samples) if samples > 0 => write!(f, ", {}x MSAA", samples)?,
+      _ => (),
+    }
+    let (names, unknown) = pfd_flag_names(self.flags);
+    write!(f, " [{}", names.join(" | "))?;
+    if unknown != 0 {
+      let sep = if names.is_empty() { "" } else { " | " };
+      write!(f, "{}0x{:X}", sep, unknown)?;
+    }
+    write!(f, "]")
+  }
+}
+
+#[test]
+fn test_pixel_format_attribs() {
+  let attribs = PixelFormatAttribs::default()
+    .color_bits(32)
+    .depth_bits(24)
+    .stencil_bits(8)
+    .double_buffer(true)
+    .srgb(true)
+    .sample_buffers(1)
+    .samples(4)
+    .acceleration(Acceleration::Full)
+    .depth_bits(16);
+  assert_eq!(
+    attribs.int_attribs(),
+    &[
+      [WGL_DRAW_TO_WINDOW_ARB, 1],
+      [WGL_SUPPORT_OPENGL_ARB, 1],
+      [WGL_PIXEL_TYPE_ARB, WGL_TYPE_RGBA_ARB],
+      [WGL_COLOR_BITS_ARB, 32],
+      // setting it again kept the place, with the new value.
+      [WGL_DEPTH_BITS_ARB, 16],
+      [WGL_STENCIL_BITS_ARB, 8],
+      [WGL_DOUBLE_BUFFER_ARB, 1],
+      [WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT, 1],
+      [WGL_SAMPLE_BUFFERS_ARB, 1],
+      [WGL_SAMPLES_ARB, 4],
+      [WGL_ACCELERATION_ARB, WGL_FULL_ACCELERATION_ARB],
+      [0, 0],
+    ]
+  );
+  assert_eq!(attribs.float_attribs(), &[[0.0, 0.0]]);
+  assert_eq!(attribs.get_int(WGL_SAMPLES_ARB), Some(4));
+  assert_eq!(attribs.get_int(WGL_ALPHA_BITS_ARB), None);
+  assert_eq!(attribs.get_int(0), None);
+
+  let attribs = PixelFormatAttribs::empty()
+    .float(WGL_COLOR_BITS_ARB, 24.0)
+    .float(WGL_ALPHA_BITS_ARB, 8.0)
+    .float(WGL_COLOR_BITS_ARB, 32.0);
+  assert_eq!(attribs.int_attribs(), &[[0, 0]]);
+  assert_eq!(
+    attribs.float_attribs(),
+    &[
+      [WGL_COLOR_BITS_ARB as FLOAT, 32.0],
+      [WGL_ALPHA_BITS_ARB as FLOAT, 8.0],
+      [0.0, 0.0]
+    ]
+  );
+
+  for a in [Acceleration::None, Acceleration::Generic, Acceleration::Full] {
+    assert_eq!(Acceleration::from_wgl(a.to_wgl()), Some(a));
+  }
+  assert_eq!(Acceleration::from_wgl(0), None);
+}
+
+#[test]
+fn test_pixel_format_info() {
+  let pfd = PIXELFORMATDESCRIPTOR {
+    dwFlags: PFD_DRAW_TO_WINDOW
+      | PFD_SUPPORT_OPENGL
+      | PFD_DOUBLEBUFFER
+      | PFD_SWAP_EXCHANGE
+      | PFD_DOUBLEBUFFER_DONTCARE,
+    iPixelType: PFD_TYPE_RGBA,
+    cColorBits: 24,
+    cRedBits: 8,
+    cRedShift: 16,
+    cGreenBits: 8,
+    cGreenShift: 8,
+    cBlueBits: 8,
+    cBlueShift: 0,
+    cAlphaBits: 8,
+    cAlphaShift: 24,
+    cAccumBits: 64,
+    cAccumRedBits: 16,
+    cAccumGreenBits: 16,
+    cAccumBlueBits: 16,
+    cAccumAlphaBits: 16,
+    cDepthBits: 24,
+    cStencilBits: 8,
+    iLayerType: PFD_MAIN_PLANE,
+    ..PIXELFORMATDESCRIPTOR::default()
+  };
+  let mut info = PixelFormatInfo::from_descriptor(7, &pfd);
+  assert_eq!(info.red, Channel { bits: 8, shift: 16 });
+  assert_eq!(info.accum_rgba, [16; 4]);
+  assert_eq!(info.acceleration, Acceleration::Full);
+  assert!(info.has_flags(PFD_DOUBLEBUFFER | PFD_SUPPORT_OPENGL));
+  assert!(!info.has_flags(PFD_DOUBLEBUFFER | PFD_STEREO));
+  assert_eq!(
+    info.to_string(),
+    "#7: RGBA 24+8 (R8@16 G8@8 B8@0 A8@24), depth 24, stencil 8, accum 64, \
+     full acceleration [DOUBLEBUFFER | DRAW_TO_WINDOW | SUPPORT_OPENGL | \
+     SWAP_EXCHANGE | 0x40000000]"
+  );
+  info.set_arb_attrib(WGL_FRAMEBUFFER_SRGB_CAPABLE_EXT, 1);
+  info.set_arb_attrib(WGL_SAMPLE_BUFFERS_ARB, 1);
+  info.set_arb_attrib(WGL_SAMPLES_ARB, 4);
+  info.set_arb_attrib(WGL_DEPTH_BITS_ARB, 32);
+  assert_eq!(
+    (info.srgb, info.samples, info.depth_bits),
+    (Some(true), Some(4), 24)
+  );
+  assert!(info.to_string().contains("full acceleration, sRGB, 4x MSAA ["));
+  assert_eq!(
+    FormatDesc::from(&info),
+    FormatDesc {
+      id: 7,
+      red_bits: 8,
+      green_bits: 8,
+      blue_bits: 8,
+      alpha_bits: 8,
+      depth_bits: 24,
+      stencil_bits: 8,
+      samples: 4,
+      srgb: true,
+      double_buffer: true,
+      accelerated: true,
+    }
+  );
+
+  // the software renderer of Windows.
+  let pfd = PIXELFORMATDESCRIPTOR {
+    dwFlags: PFD_DRAW_TO_BITMAP | PFD_SUPPORT_GDI | PFD_GENERIC_FORMAT,
+    iPixelType: PFD_TYPE_COLORINDEX,
+    cColorBits: 8,
+    iLayerType: PFD_UNDERLAY_PLANE,
+    ..PIXELFORMATDESCRIPTOR::default()
+  };
+  let mut info = PixelFormatInfo::from_descriptor(1, &pfd);
+  assert_eq!(info.acceleration, Acceleration::None);
+  assert_eq!(
+    (info.pixel_type, info.layer_type),
+    (PixelType::ColorIndex, LayerType::Underlay)
+  );
+  info.set_arb_attrib(WGL_SAMPLE_BUFFERS_ARB, 0);
+  info.set_arb_attrib(WGL_SAMPLES_ARB, 16);
+  assert_eq!(info.samples, Some(0));
+  assert_eq!(
+    info.to_string(),
+    "#1: color index 8+0 (R0@0 G0@0 B0@0 A0@0), depth 0, stencil 0, \
+     underlay, no acceleration [DRAW_TO_BITMAP | SUPPORT_GDI | GENERIC_FORMAT]"
+  );
+  assert_eq!(pfd_flag_names(0), (vec![], 0));
+}
+
+#[test]
+#[should_panic]
+fn test_pixel_format_attribs_zero_key() {
+  let _ = PixelFormatAttribs::default().int(0, 1);
+}
@@ -0,0 +1,979 @@
+//! The parts of [`win32`](super) that call into Windows.
+
+use super::*;
+use crate::{
+  context::{AttemptError, ContextError, ContextRequest, CreatedContext},
+  guard::{OwnedDc, OwnedGlrc, OwnedHwnd, RegisteredClass, ScopeGuard},
+  *,
+};
+use core::ptr::{null, null_mut};
+
+#[link(name = "Kernel32")]
+extern "system" {
+  /// [`GetModuleHandleW`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getmodulehandlew)
+  pub fn GetModuleHandleW(lpModuleName: LPCWSTR) -> HMODULE;
+
+  /// [`GetLastError`](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror)
+  pub fn GetLastError() -> DWORD;
+
+  /// [`SetLastError`](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setlasterror)
+  pub fn SetLastError(dwErrCode: DWORD);
+
+  /// [`FormatMessageW`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew)
+  pub fn FormatMessageW(
+    dwFlags: DWORD, lpSource: LPCVOID, dwMessageId: DWORD, dwLanguageId: DWORD,
+    lpBuffer: LPWSTR, nSize: DWORD, Arguments: va_list,
+  ) -> DWORD;
+
+  /// [`LocalFree`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-localfree)
+  pub fn LocalFree(hMem: HLOCAL) -> HLOCAL;
+
+  /// [`LoadLibraryW`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryw)
+  pub fn LoadLibraryW(lpLibFileName: LPCWSTR) -> HMODULE;
+
+  /// [`FreeLibrary`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-freelibrary)
+  pub fn FreeLibrary(hLibModule: HMODULE) -> BOOL;
+
+  /// [`GetProcAddress`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getprocaddress)
+  pub fn GetProcAddress(hModule: HMODULE, lpProcName: LPCSTR) -> FARPROC;
+}
+
+#[link(name = "User32")]
+extern "system" {
+  /// [`RegisterClassW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassw)
+  pub fn RegisterClassW(lpWndClass: *const WNDCLASSW) -> ATOM;
+
+  /// [`UnregisterClassW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterclassw)
+  pub fn UnregisterClassW(lpClassName: LPCWSTR, hInstance: HINSTANCE) -> BOOL;
+
+  /// [`CreateWindowExW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw)
+  pub fn CreateWindowExW(
+    dwExStyle: DWORD, lpClassName: LPCWSTR, lpWindowName: LPCWSTR,
+    dwStyle: DWORD, X: c_int, Y: c_int, nWidth: c_int, nHeight: c_int,
+    hWndParent: HWND, hMenu: HMENU, hInstance: HINSTANCE, lpParam: LPVOID,
+  ) -> HWND;
+
+  /// [`DefWindowProcW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defwindowprocw)
+  pub fn DefWindowProcW(
+    hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM,
+  ) -> LRESULT;
+
+  /// [`ShowWindow`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-showwindow)
+  pub fn ShowWindow(hWnd: HWND, nCmdShow: c_int) -> BOOL;
+
+  /// [`GetMessageW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmessagew)
+  pub fn GetMessageW(
+    lpMsg: LPMSG, hWnd: HWND, wMsgFilterMin: UINT, wMsgFilterMax: UINT,
+  ) -> BOOL;
+
+  /// [`TranslateMessage`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-translatemessage)
+  pub fn TranslateMessage(lpMsg: *const MSG) -> BOOL;
+
+  /// [`DispatchMessageW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-dispatchmessagew)
+  pub fn DispatchMessageW(lpMsg: *const MSG) -> LRESULT;
+
+  /// [`DestroyWindow`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroywindow)
+  pub fn DestroyWindow(hWnd: HWND) -> BOOL;
+
+  /// [`PostQuitMessage`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postquitmessage)
+  pub fn PostQuitMessage(nExitCode: c_int);
+
+  /// [`LoadCursorW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadcursorw)
+  pub fn LoadCursorW(hInstance: HINSTANCE, lpCursorName: LPCWSTR) -> HCURSOR;
+
+  /// [`BeginPaint`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-beginpaint)
+  pub fn BeginPaint(hWnd: HWND, lpPaint: LPPAINTSTRUCT) -> HDC;
+
+  /// [`FillRect`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-fillrect)
+  pub fn FillRect(hDC: HDC, lprc: *const RECT, hbr: HBRUSH) -> c_int;
+
+  /// [`EndPaint`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-endpaint)
+  pub fn EndPaint(hWnd: HWND, lpPaint: *const PAINTSTRUCT) -> BOOL;
+
+  /// [`MessageBoxW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-messageboxw)
+  pub fn MessageBoxW(
+    hWnd: HWND, lpText: LPCWSTR, lpCaption: LPCWSTR, uType: UINT,
+  ) -> c_int;
+
+  /// [`SetWindowLongPtrW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowlongptrw)
+  pub fn SetWindowLongPtrW(
+    hWnd: HWND, nIndex: c_int, dwNewLong: LONG_PTR,
+  ) -> LONG_PTR;
+
+  /// [`GetWindowLongPtrW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindowlongptrw)
+  pub fn GetWindowLongPtrW(hWnd: HWND, nIndex: c_int) -> LONG_PTR;
+
+  /// [`SetCursor`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursor)
+  pub fn SetCursor(hCursor: HCURSOR) -> HCURSOR;
+
+  /// [`GetDC`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdc)
+  pub fn GetDC(hWnd: HWND) -> HDC;
+
+  /// [`ReleaseDC`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasedc)
+  pub fn ReleaseDC(hWnd: HWND, hDC: HDC) -> c_int;
+
+  /// [`SetWindowTextW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowtextw)
+  pub fn SetWindowTextW(hWnd: HWND, lpString: LPCWSTR) -> BOOL;
+
+  /// [`GetClientRect`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclientrect)
+  pub fn GetClientRect(hWnd: HWND, lpRect: *mut RECT) -> BOOL;
+
+  /// [`ValidateRect`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-validaterect)
+  pub fn ValidateRect(hWnd: HWND, lpRect: *const RECT) -> BOOL;
+
+  /// [`AdjustWindowRectEx`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-adjustwindowrectex)
+  pub fn AdjustWindowRectEx(
+    lpRect: *mut RECT, dwStyle: DWORD, bMenu: BOOL, dwExStyle: DWORD,
+  ) -> BOOL;
+}
+
+#[link(name = "Gdi32")]
+extern "system" {
+  /// [`ChoosePixelFormat`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-choosepixelformat)
+  pub fn ChoosePixelFormat(
+    hdc: HDC, ppfd: *const PIXELFORMATDESCRIPTOR,
+  ) -> c_int;
+
+  /// [`DescribePixelFormat`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-describepixelformat)
+  pub fn DescribePixelFormat(
+    hdc: HDC, iPixelFormat: c_int, nBytes: UINT,
+    ppfd: *mut PIXELFORMATDESCRIPTOR,
+  ) -> c_int;
+
+  /// [`SetPixelFormat`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-setpixelformat)
+  pub fn SetPixelFormat(
+    hdc: HDC, format: c_int, ppfd: *const PIXELFORMATDESCRIPTOR,
+  ) -> BOOL;
+
+  /// [`SwapBuffers`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-swapbuffers)
+  pub fn SwapBuffers(Arg1: HDC) -> BOOL;
+}
+
+#[link(name = "Opengl32")]
+extern "system" {
+  /// [`wglCreateContext`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wglcreatecontext)
+  pub fn wglCreateContext(Arg1: HDC) -> HGLRC;
+
+  /// [`wglDeleteContext`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wgldeletecontext)
+  pub fn wglDeleteContext(Arg1: HGLRC) -> BOOL;
+
+  /// [`wglMakeCurrent`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wglmakecurrent)
+  pub fn wglMakeCurrent(hdc: HDC, hglrc: HGLRC) -> BOOL;
+
+  /// [`wglGetProcAddress`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wglgetprocaddress)
+  pub fn wglGetProcAddress(Arg1: LPCSTR) -> PROC;
+}
+
+/// Returns a handle to the file used to create the calling process (.exe file)
+///
+/// See [`GetModuleHandleW`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getmodulehandlew)
+pub fn get_process_handle() -> HMODULE {
+  // Safety: as per the MSDN docs.
+  unsafe { GetModuleHandleW(null()) }
+}
+
+/// Load one of the predefined cursors.
+///
+/// See [`LoadCursorW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadcursorw)
+pub fn load_predefined_cursor(cursor: IDCursor) -> Result<HCURSOR, Win32Error> {
+  // Safety: The enum only allows values from the approved list. See MSDN.
+  let hcursor =
+    unsafe { LoadCursorW(null_mut(), MAKEINTRESOURCEW(cursor as WORD)) };
+  if hcursor.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(hcursor)
+  }
+}
+
+/// Registers a window class struct.
+///
+/// ## Safety
+///
+/// All pointer fields of the struct must be correct.
+///
+/// See [`RegisterClassW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassw)
+pub unsafe fn register_class(
+  window_class: &WNDCLASSW,
+) -> Result<ATOM, Win32Error> {
+  let atom = RegisterClassW(window_class);
+  if atom == 0 {
+    Err(get_last_error())
+  } else {
+    Ok(atom)
+  }
+}
+
+/// Gets the thread-local last-error code value.
+///
+/// See [`GetLastError`](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror)
+pub fn get_last_error() -> Win32Error {
+  Win32Error(unsafe { GetLastError() })
+}
+
+/// Sets the thread-local last-error code value.
+///
+/// See [`SetLastError`](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setlasterror)
+pub fn set_last_error(e: Win32Error) {
+  unsafe { SetLastError(e.0) }
+}
+
+impl core::fmt::Debug for Win32Error {
+  /// Displays the error using `FormatMessageW`
+  ///
+  /// ```
+  /// use triangle_from_scratch::win32::*;
+  /// let s = format!("{:?}", Win32Error(0));
+  /// assert_eq!("The operation completed successfully.  ", s);
+  /// let app_error = format!("{:?}", Win32Error(1 << 29));
+  /// assert_eq!("Win32ApplicationError(536870912)", app_error);
+  /// ```
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    pub const FORMAT_MESSAGE_ALLOCATE_BUFFER: DWORD = 0x00000100;
+    pub const FORMAT_MESSAGE_FROM_SYSTEM: DWORD = 0x00001000;
+    pub const FORMAT_MESSAGE_IGNORE_INSERTS: DWORD = 0x00000200;
+
+    if f.alternate() {
+      return write!(f, "Win32Error({})", self.0);
+    }
+
+    if self.0 & Self::APPLICATION_ERROR_BIT > 0 {
+      return write!(f, "Win32 Application Error ({})", self.0);
+    }
+    let dwFlags = FORMAT_MESSAGE_ALLOCATE_BUFFER
+      | FORMAT_MESSAGE_FROM_SYSTEM
+      | FORMAT_MESSAGE_IGNORE_INSERTS;
+    let lpSource = null_mut();
+    let dwMessageId = self.0;
+    let dwLanguageId = 0;
+    // this will point to our allocation after the call
+    let mut buffer: *mut u16 = null_mut();
+    let lpBuffer = &mut buffer as *mut *mut u16 as *mut u16;
+    let nSize = 0;
+    let Arguments = null_mut();
+    let tchar_count_excluding_null = unsafe {
+      FormatMessageW(
+        dwFlags,
+        lpSource,
+        dwMessageId,
+        dwLanguageId,
+        lpBuffer,
+        nSize,
+        Arguments,
+      )
+    };
+    if tchar_count_excluding_null == 0 || buffer.is_null() {
+      // some sort of problem happened. we can't usefully get_last_error since
+      // Display formatting doesn't let you give an error value.
+      return Err(core::fmt::Error);
+    } else {
+      let _buffer = ScopeGuard::new(buffer as HLOCAL, |buffer| unsafe {
+        LocalFree(buffer);
+      });
+      let buffer_slice: &[u16] = unsafe {
+        core::slice::from_raw_parts(buffer, tchar_count_excluding_null as usize)
+      };
+      for decode_result in
+        core::char::decode_utf16(buffer_slice.iter().copied())
+      {
+        match decode_result {
+          Ok('\r') | Ok('\n') => write!(f, " ")?,
+          Ok(ch) => write!(f, "{}", ch)?,
+          Err(_) => write!(f, "�")?,
+        }
+      }
+      Ok(())
+    }
+  }
+}
+
+/// The style of the windows from [`create_app_window`].
+pub const APP_WINDOW_STYLE: DWORD =
+  WS_OVERLAPPEDWINDOW | WS_CLIPCHILDREN | WS_CLIPSIBLINGS;
+
+/// The extended style of the windows from [`create_app_window`].
+pub const APP_WINDOW_EX_STYLE: DWORD = WS_EX_APPWINDOW | WS_EX_OVERLAPPEDWINDOW;
+
+/// Creates a window.
+///
+/// * The window is not initially shown, you must call [`ShowWindow`] yourself.
+/// * The size is of the whole window, including the borders. Use
+///   [`app_window_size`] to get the size for a given client area.
+///
+/// See [`CreateWindowExW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw)
+pub unsafe fn create_app_window(
+  class_name: &str, window_name: &str, position: Option<[i32; 2]>,
+  [width, height]: [i32; 2], create_param: LPVOID,
+) -> Result<HWND, Win32Error> {
+  let class_name_null = wide_null(class_name);
+  let window_name_null = wide_null(window_name);
+  let (x, y) = match position {
+    Some([x, y]) => (x, y),
+    None => (CW_USEDEFAULT, CW_USEDEFAULT),
+  };
+  let hwnd = CreateWindowExW(
+    APP_WINDOW_EX_STYLE,
+    class_name_null.as_ptr(),
+    window_name_null.as_ptr(),
+    APP_WINDOW_STYLE,
+    x,
+    y,
+    width,
+    height,
+    null_mut(),
+    null_mut(),
+    get_process_handle(),
+    create_param,
+  );
+  if hwnd.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(hwnd)
+  }
+}
+
+/// The size to give [`create_app_window`] so that the client area, where
+/// you draw, is `[width, height]`.
+///
+/// See [`AdjustWindowRectEx`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-adjustwindowrectex)
+pub fn app_window_size(
+  [width, height]: [i32; 2],
+) -> Result<[i32; 2], Win32Error> {
+  let mut rect = RECT { left: 0, top: 0, right: width, bottom: height };
+  let success = unsafe {
+    AdjustWindowRectEx(&mut rect, APP_WINDOW_STYLE, 0, APP_WINDOW_EX_STYLE)
+  };
+  if success != 0 {
+    Ok([rect.right - rect.left, rect.bottom - rect.top])
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Gets a message from the thread's message queue.
+///
+/// The message can be for any window from this thread,
+/// or it can be a non-window message as well.
+///
+/// See [`GetMessageW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmessagew)
+pub fn get_any_message() -> Result<MSG, Win32Error> {
+  let mut msg = MSG::default();
+  let output = unsafe { GetMessageW(&mut msg, null_mut(), 0, 0) };
+  if output == -1 {
+    Err(get_last_error())
+  } else {
+    Ok(msg)
+  }
+}
+
+/// Translates virtual-key messages into character messages.
+///
+/// The character messages go into your thread's message queue,
+/// and you'll see them if you continue to consume messages.
+///
+/// **Returns:**
+/// * `true` if the message was `WM_KEYDOWN`, `WM_KEYUP`, `WM_SYSKEYDOWN`, or
+///   `WM_SYSKEYUP`.
+/// * `true` for any other message type that generated a character message.
+/// * otherwise `false`
+///
+/// See [`TranslateMessage`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-translatemessage)
+pub fn translate_message(msg: &MSG) -> bool {
+  0 != unsafe { TranslateMessage(msg) }
+}
+
+/// Sets the "userdata" pointer of the window (`GWLP_USERDATA`).
+///
+/// **Returns:** The previous userdata pointer.
+///
+/// [`SetWindowLongPtrW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowlongptrw)
+pub unsafe fn set_window_userdata<T>(
+  hwnd: HWND, ptr: *mut T,
+) -> Result<*mut T, Win32Error> {
+  set_last_error(Win32Error(0));
+  let out = SetWindowLongPtrW(hwnd, GWLP_USERDATA, ptr as LONG_PTR);
+  if out == 0 {
+    // if output is 0, it's only a "real" error if the last_error is non-zero
+    let last_error = get_last_error();
+    if last_error.0 != 0 {
+      Err(last_error)
+    } else {
+      Ok(out as *mut T)
+    }
+  } else {
+    Ok(out as *mut T)
+  }
+}
+
+/// Gets the "userdata" pointer of the window (`GWLP_USERDATA`).
+///
+/// **Returns:** The userdata pointer.
+///
+/// [`GetWindowLongPtrW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindowlongptrw)
+pub unsafe fn get_window_userdata<T>(hwnd: HWND) -> Result<*mut T, Win32Error> {
+  set_last_error(Win32Error(0));
+  let out = GetWindowLongPtrW(hwnd, GWLP_USERDATA);
+  if out == 0 {
+    // if output is 0, it's only a "real" error if the last_error is non-zero
+    let last_error = get_last_error();
+    if last_error.0 != 0 {
+      Err(last_error)
+    } else {
+      Ok(out as *mut T)
+    }
+  } else {
+    Ok(out as *mut T)
+  }
+}
+
+/// Indicates to the system that a thread has made a request to terminate
+/// (quit).
+///
+/// The exit code becomes the `wparam` of the [`WM_QUIT`] message your message
+/// loop eventually gets.
+///
+/// [`PostQuitMessage`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postquitmessage)
+pub fn post_quit_message(exit_code: c_int) {
+  unsafe { PostQuitMessage(exit_code) }
+}
+
+/// Prepares the specified window for painting.
+///
+/// On success: you get back both the [`HDC`] and [`PAINTSTRUCT`]
+/// that you'll need for future painting calls (including [`EndPaint`]).
+///
+/// [`BeginPaint`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-beginpaint)
+pub unsafe fn begin_paint(
+  hwnd: HWND,
+) -> Result<(HDC, PAINTSTRUCT), Win32Error> {
+  let mut ps = PAINTSTRUCT::default();
+  let hdc = BeginPaint(hwnd, &mut ps);
+  if hdc.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok((hdc, ps))
+  }
+}
+
+/// Fills a rectangle with the given system color.
+///
+/// When filling the specified rectangle, this does **not** include the
+/// rectangle's right and bottom sides. GDI fills a rectangle up to, but not
+/// including, the right column and bottom row, regardless of the current
+/// mapping mode.
+///
+/// [`FillRect`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-fillrect)
+pub unsafe fn fill_rect_with_sys_color(
+  hdc: HDC, rect: &RECT, color: SysColor,
+) -> Result<(), ()> {
+  if FillRect(hdc, rect, (color as u32 + 1) as HBRUSH) != 0 {
+    Ok(())
+  } else {
+    Err(())
+  }
+}
+
+/// See [`EndPaint`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-endpaint)
+pub unsafe fn end_paint(hwnd: HWND, ps: &PAINTSTRUCT) {
+  EndPaint(hwnd, ps);
+}
+
+/// Performs [`begin_paint`] / [`end_paint`] around your closure.
+pub unsafe fn do_some_painting<F, T>(hwnd: HWND, f: F) -> Result<T, Win32Error>
+where
+  F: FnOnce(HDC, bool, RECT) -> Result<T, Win32Error>,
+{
+  let (hdc, ps) = begin_paint(hwnd)?;
+  let output = f(hdc, ps.fErase != 0, ps.rcPaint);
+  end_paint(hwnd, &ps);
+  output
+}
+
+/// See [`ChoosePixelFormat`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-choosepixelformat)
+pub unsafe fn choose_pixel_format(
+  hdc: HDC, ppfd: &PIXELFORMATDESCRIPTOR,
+) -> Result<c_int, Win32Error> {
+  let index = ChoosePixelFormat(hdc, ppfd);
+  if index != 0 {
+    Ok(index)
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// See [`GetDC`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdc)
+#[must_use]
+pub unsafe fn get_dc(hwnd: HWND) -> Option<HDC> {
+  let hdc = GetDC(hwnd);
+  if hdc.is_null() {
+    None
+  } else {
+    Some(hdc)
+  }
+}
+
+/// See [`ReleaseDC`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasedc)
+#[must_use]
+pub unsafe fn release_dc(hwnd: HWND, hdc: HDC) -> bool {
+  let was_released = ReleaseDC(hwnd, hdc);
+  was_released != 0
+}
+
+/// See [`DestroyWindow`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroywindow)
+pub unsafe fn destroy_window(hwnd: HWND) -> Result<(), Win32Error> {
+  let destroyed = DestroyWindow(hwnd);
+  if destroyed != 0 {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Sets the pixel format of an HDC.
+///
+/// * If it's a window's HDC then it sets the pixel format of the window.
+/// * You can't set a window's pixel format more than once.
+/// * Call this *before* creating an OpenGL context.
+/// * OpenGL windows should use [`WS_CLIPCHILDREN`] and [`WS_CLIPSIBLINGS`]
+/// * OpenGL windows should *not* use `CS_PARENTDC`
+///
+/// See [`SetPixelFormat`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-setpixelformat)
+pub unsafe fn set_pixel_format(
+  hdc: HDC, format: c_int, ppfd: &PIXELFORMATDESCRIPTOR,
+) -> Result<(), Win32Error> {
+  let success = SetPixelFormat(hdc, format, ppfd);
+  if success != 0 {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Gets the maximum pixel format index for the HDC.
+///
+/// Pixel format indexes are 1-based.
+///
+/// To print out info on all the pixel formats you'd do something like this:
+/// ```no_run
+/// # use triangle_from_scratch::win32::*;
+/// let hdc = todo!("create a window to get an HDC");
+/// let max = unsafe { get_max_pixel_format_index(hdc).unwrap() };
+/// for index in 1..=max {
+///   let pfd = unsafe { describe_pixel_format(hdc, index).unwrap() };
+///   println!("{}", PixelFormatInfo::from_descriptor(index, &pfd));
+/// }
+/// ```
+///
+/// Or use [`enumerate_pixel_formats`], which also gets the `WGL_*_ARB`
+/// attributes.
+///
+/// See [`DescribePixelFormat`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-describepixelformat)
+pub unsafe fn get_max_pixel_format_index(
+  hdc: HDC,
+) -> Result<c_int, Win32Error> {
+  let max_index = DescribePixelFormat(
+    hdc,
+    1,
+    size_of::<PIXELFORMATDESCRIPTOR>() as _,
+    null_mut(),
+  );
+  if max_index == 0 {
+    Err(get_last_error())
+  } else {
+    Ok(max_index)
+  }
+}
+
+/// Gets the pixel format info for a given pixel format index.
+///
+/// See [`DescribePixelFormat`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-describepixelformat)
+pub unsafe fn describe_pixel_format(
+  hdc: HDC, format: c_int,
+) -> Result<PIXELFORMATDESCRIPTOR, Win32Error> {
+  let mut pfd = PIXELFORMATDESCRIPTOR::default();
+  let max_index = DescribePixelFormat(
+    hdc,
+    format,
+    size_of::<PIXELFORMATDESCRIPTOR>() as _,
+    &mut pfd,
+  );
+  if max_index == 0 {
+    Err(get_last_error())
+  } else {
+    Ok(pfd)
+  }
+}
+
+/// Gets a pixel format as a [`PixelFormatInfo`].
+///
+/// With a [`wglGetPixelFormatAttribivARB_t`] procedure, each of the
+/// [`PixelFormatInfo::ARB_KEYS`] is also asked for, one at a time so that a
+/// key the driver doesn't know only leaves that one out.
+///
+/// ## Safety
+///
+/// * `hdc` must be a device context.
+/// * `get_attribs` must be the `wglGetPixelFormatAttribivARB` of the driver.
+pub unsafe fn describe_pixel_format_info(
+  hdc: HDC, format: c_int, get_attribs: wglGetPixelFormatAttribivARB_t,
+) -> Result<PixelFormatInfo, Win32Error> {
+  let pfd = describe_pixel_format(hdc, format)?;
+  let mut info = PixelFormatInfo::from_descriptor(format, &pfd);
+  if let Some(f) = get_attribs {
+    for key in PixelFormatInfo::ARB_KEYS {
+      let mut value = 0;
+      if f(hdc, format, 0, 1, &key, &mut value) != 0 {
+        info.set_arb_attrib(key, value);
+      }
+    }
+  }
+  Ok(info)
+}
+
+/// Gets every pixel format of the device context, from index 1 up to
+/// [`get_max_pixel_format_index`].
+///
+/// ## Safety
+///
+/// * `hdc` must be a device context.
+/// * `get_attribs` must be the `wglGetPixelFormatAttribivARB` of the driver.
+///
+/// See [`describe_pixel_format_info`].
+pub unsafe fn enumerate_pixel_formats(
+  hdc: HDC, get_attribs: wglGetPixelFormatAttribivARB_t,
+) -> Result<Vec<PixelFormatInfo>, Win32Error> {
+  let max = get_max_pixel_format_index(hdc)?;
+  (1..=max)
+    .map(|format| describe_pixel_format_info(hdc, format, get_attribs))
+    .collect()
+}
+
+/// Un-registers the window class from the `HINSTANCE` given.
+///
+/// * The name must be the name of a registered window class.
+/// * This requires re-encoding the name to null-terminated utf-16, which
+///   allocates. Using [`unregister_class_by_atom`] instead does not allocate,
+///   if you have the atom available.
+/// * Before calling this function, an application must destroy all windows
+///   created with the specified class.
+///
+/// See
+/// [`UnregisterClassW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterclassw)
+pub unsafe fn unregister_class_by_name(
+  name: &str, instance: HINSTANCE,
+) -> Result<(), Win32Error> {
+  let name_null = wide_null(name);
+  let out = UnregisterClassW(name_null.as_ptr(), instance);
+  if out != 0 {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Un-registers the window class from the `HINSTANCE` given.
+///
+/// * The atom must be the atom of a registered window class.
+/// * Before calling this function, an application must destroy all windows
+///   created with the specified class.
+///
+/// See [`UnregisterClassW`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterclassw)
+pub unsafe fn unregister_class_by_atom(
+  a: ATOM, instance: HINSTANCE,
+) -> Result<(), Win32Error> {
+  let out = UnregisterClassW(a as LPCWSTR, instance);
+  if out != 0 {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// See [`wglCreateContext`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wglcreatecontext)
+pub unsafe fn wgl_create_context(hdc: HDC) -> Result<HGLRC, Win32Error> {
+  let hglrc = wglCreateContext(hdc);
+  if hglrc.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(hglrc)
+  }
+}
+
+/// Deletes a GL Context.
+///
+/// * You **cannot** use this to delete a context current in another thread.
+/// * You **can** use this to delete the current thread's context. The context
+///   will be made not-current automatically before it is deleted.
+///
+/// See
+/// [`wglDeleteContext`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wgldeletecontext)
+pub unsafe fn wgl_delete_context(hglrc: HGLRC) -> Result<(), Win32Error> {
+  let success = wglDeleteContext(hglrc);
+  if success != 0 {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Makes a given HGLRC current in the thread and targets it at the HDC given.
+///
+/// * You can safely pass `null_mut` for both parameters if you wish to make no
+///   context current in the thread.
+///
+/// See
+/// [`wglMakeCurrent`](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wglmakecurrent)
+pub unsafe fn wgl_make_current(
+  hdc: HDC, hglrc: HGLRC,
+) -> Result<(), Win32Error> {
+  let success = wglMakeCurrent(hdc, hglrc);
+  if success != 0 {
+    Ok(())
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Gets a GL function address.
+///
+/// The input should be a null-terminated function name string. Use the
+/// [`c_str!`] macro for assistance.
+///
+/// * You must have an active GL context for this to work. Otherwise you will
+///   always get an error.
+/// * The function name is case sensitive, and spelling must be exact.
+/// * All outputs are context specific. Functions supported in one rendering
+///   context are not necessarily supported in another.
+/// * The extension function addresses are unique for each pixel format. All
+///   rendering contexts of a given pixel format share the same extension
+///   function addresses.
+///
+/// This *will not* return function pointers exported by `OpenGL32.dll`, meaning
+/// that it won't return OpenGL 1.1 functions. For those old function, use
+/// [`GetProcAddress`].
+pub fn wgl_get_proc_address(func_name: &[u8]) -> Result<PROC, Win32Error> {
+  // check that we end the slice with a \0 as expected.
+  match func_name.last() {
+    Some(b'\0') => (),
+    _ => return Err(Win32Error(Win32Error::APPLICATION_ERROR_BIT)),
+  }
+  // Safety: we've checked that the end of the slice is null-terminated.
+  let proc = unsafe { wglGetProcAddress(func_name.as_ptr().cast()) };
+  match proc as usize {
+    // Some non-zero values can also be errors,
+    // https://www.khronos.org/opengl/wiki/Load_OpenGL_Functions#Windows
+    0 | 1 | 2 | 3 | usize::MAX => return Err(get_last_error()),
+    _ => Ok(proc),
+  }
+}
+
+/// Gets the WGL extension string for the HDC passed.
+///
+/// * This relies on [`wgl_get_proc_address`], and so you must have a context
+///   current for it to work.
+/// * If `wgl_get_proc_address` fails then an Application Error is generated.
+/// * If `wgl_get_proc_address` succeeds but the extension string can't be
+///   obtained for some other reason you'll get a System Error.
+///
+/// The output is a space-separated list of extensions that are supported.
+///
+/// See
+/// [`wglGetExtensionsStringARB`](https://www.khronos.org/registry/OpenGL/extensions/ARB/WGL_ARB_extensions_string.txt)
+pub unsafe fn wgl_get_extension_string_arb(
+  hdc: HDC,
+) -> Result<String, Win32Error> {
+  let f: wglGetExtensionsStringARB_t = core::mem::transmute(
+    wgl_get_proc_address(c_str!("wglGetExtensionsStringARB"))?,
+  );
+  let p: *const u8 =
+    (f.ok_or(Win32Error(Win32Error::APPLICATION_ERROR_BIT))?)(hdc).cast();
+  if p.is_null() {
+    Err(get_last_error())
+  } else {
+    let bytes = gather_null_terminated_bytes(p);
+    Ok(min_alloc_lossy_into_string(bytes))
+  }
+}
+
+/// Grabs out the stuff you'll need to have fun with WGL.
+pub fn get_wgl_basics() -> Result<
+  (
+    Vec<String>,
+    wglChoosePixelFormatARB_t,
+    wglGetPixelFormatAttribivARB_t,
+    wglCreateContextAttribsARB_t,
+    wglSwapIntervalEXT_t,
+  ),
+  Win32Error,
+> {
+  let instance = get_process_handle();
+  let class_name = "name that is unlikely to clash 38o475983475923487593875";
+  let class_name_wn = wide_null(class_name);
+  let wc = WNDCLASSW {
+    style: CS_OWNDC,
+    lpfnWndProc: Some(DefWindowProcW),
+    hInstance: instance,
+    lpszClassName: class_name_wn.as_ptr(),
+    ..Default::default()
+  };
+  let pfd = PIXELFORMATDESCRIPTOR {
+    dwFlags: PFD_DRAW_TO_WINDOW | PFD_SUPPORT_OPENGL | PFD_DOUBLEBUFFER,
+    iPixelType: PFD_TYPE_RGBA,
+    cColorBits: 32,
+    cDepthBits: 24,
+    cStencilBits: 8,
+    iLayerType: PFD_MAIN_PLANE,
+    ..Default::default()
+  };
+
+  let _class = unsafe { RegisteredClass::register(&wc) }?;
+  let hwnd = unsafe {
+    OwnedHwnd::from_raw(create_app_window(
+      class_name,
+      "Fake Window",
+      None,
+      [1, 1],
+      null_mut(),
+    )?)
+  };
+  let hdc = unsafe { OwnedDc::get(hwnd.as_raw()) }?;
+
+  let pf_index = unsafe { choose_pixel_format(hdc.as_raw(), &pfd) }?;
+  unsafe { set_pixel_format(hdc.as_raw(), pf_index, &pfd) }?;
+
+  let hglrc = unsafe { OwnedGlrc::from_raw(wgl_create_context(hdc.as_raw())?) };
+
+  unsafe { wgl_make_current(hdc.as_raw(), hglrc.as_raw()) }?;
+
+  let wgl_extensions: Vec<String> =
+    unsafe { wgl_get_extension_string_arb(hdc.as_raw()) }
+      .map(|s| {
+        s.split(' ').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect()
+      })
+      .unwrap_or(Vec::new());
+
+  let choose_pixel_format: wglChoosePixelFormatARB_t = unsafe {
+    core::mem::transmute(wgl_get_proc_address(c_str!(
+      "wglChoosePixelFormatARB"
+    ))?)
+  };
+  let get_pixel_format_attribiv: wglGetPixelFormatAttribivARB_t = unsafe {
+    core::mem::transmute(wgl_get_proc_address(c_str!(
+      "wglGetPixelFormatAttribivARB"
+    ))?)
+  };
+  let create_context_attribs: wglCreateContextAttribsARB_t = unsafe {
+    core::mem::transmute(wgl_get_proc_address(c_str!(
+      "wglCreateContextAttribsARB"
+    ))?)
+  };
+  let swap_interval: wglSwapIntervalEXT_t = unsafe {
+    core::mem::transmute(wgl_get_proc_address(c_str!("wglSwapIntervalEXT"))?)
+  };
+
+  unsafe { wgl_make_current(null_mut(), null_mut()) }?;
+
+  Ok((
+    wgl_extensions,
+    choose_pixel_format,
+    get_pixel_format_attribiv,
+    create_context_attribs,
+    swap_interval,
+  ))
+}
+
+/// Calls a [`wglChoosePixelFormatARB_t`] procedure, and gives the best
+/// matching pixel format.
+pub unsafe fn do_wglChoosePixelFormatARB(
+  f: wglChoosePixelFormatARB_t, hdc: HDC, attribs: &PixelFormatAttribs,
+) -> Result<c_int, Win32Error> {
+  let app_err = Win32Error(Win32Error::APPLICATION_ERROR_BIT);
+  let mut out_format = 0;
+  let mut out_format_count = 0;
+  let b = (f.ok_or(app_err)?)(
+    hdc,
+    attribs.int_attribs().as_ptr().cast(),
+    attribs.float_attribs().as_ptr().cast(),
+    1,
+    &mut out_format,
+    &mut out_format_count,
+  );
+  if b != 0 && out_format_count == 1 {
+    Ok(out_format)
+  } else {
+    Err(get_last_error())
+  }
+}
+
+/// Arranges the data for calling a [`wglCreateContextAttribsARB_t`] procedure.
+///
+/// * The input slice consists of [key, value] pairs.
+/// * The input slice **can** be empty.
+/// * Any non-empty input must have zero as the key value of the last position.
+pub unsafe fn do_wglCreateContextAttribsARB(
+  f: wglCreateContextAttribsARB_t, hdc: HDC, hShareContext: HGLRC,
+  attribList: &[[i32; 2]],
+) -> Result<HGLRC, Win32Error> {
+  let app_err = Win32Error(Win32Error::APPLICATION_ERROR_BIT);
+  let i_ptr = match attribList.last() {
+    Some([k, _v]) => {
+      if *k == 0 {
+        attribList.as_ptr()
+      } else {
+        return Err(app_err);
+      }
+    }
+    None => null(),
+  };
+  let hglrc = (f.ok_or(app_err)?)(hdc, hShareContext, i_ptr.cast());
+  if hglrc.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(hglrc)
+  }
+}
+
+/// Makes a context with the first version of the request that works.
+///
+/// * Versions that need a WGL extension that isn't in `wgl_extensions` aren't
+///   tried, and say which one they needed.
+/// * The context isn't made current.
+///
+/// ## Safety
+///
+/// * `f` must be the `wglCreateContextAttribsARB` of the driver.
+/// * `hdc` must be a device context.
+/// * The share context of the request must be null or a context of the driver.
+pub unsafe fn create_wgl_context(
+  f: wglCreateContextAttribsARB_t, hdc: HDC, wgl_extensions: &[String],
+  request: &ContextRequest<HGLRC>,
+) -> Result<CreatedContext<HGLRC, Win32Error>, ContextError<Win32Error>> {
+  let share = request.share.unwrap_or(null_mut());
+  request.try_each(|version| {
+    if let Some(name) =
+      WGL_CONTEXT_EXTENSIONS.missing(request, version, wgl_extensions)
+    {
+      return Err(AttemptError::MissingExtension(name));
+    }
+    let attribs = WGL_CONTEXT_ENUMS.attribs(request, version);
+    do_wglCreateContextAttribsARB(f, hdc, share, &attribs)
+      .map_err(AttemptError::Failed)
+  })
+}
+
+/// Loads a dynamic library.
+///
+/// The precise details of how the library is searched for depend on the input
+/// string.
+///
+/// See [`LoadLibraryW`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-loadlibraryw)
+pub fn load_library(name: &str) -> Result<HMODULE, Win32Error> {
+  let name_null = wide_null(name);
+  // Safety: the input pointer is to a null-terminated string
+  let hmodule = unsafe { LoadLibraryW(name_null.as_ptr()) };
+  if hmodule.is_null() {
+    Err(get_last_error())
+  } else {
+    Ok(hmodule)
+  }
+}
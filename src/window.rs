@@ -0,0 +1,203 @@
+//! Module for a window and its events, the same way on every platform.
+//!
+//! A type that implements [`Window`] can be created, shown, retitled, asked
+//! for its size, and have its GL buffers swapped. Its events come out as an
+//! [`Event`] from [`Window::next_event`], usually through
+//! [`run_event_loop`].
+//!
+//! The backends are `Win32Window` on Windows, `X11Window` on Linux (which
+//! swaps buffers with GLX), and [`MockWindow`] everywhere, which plays back
+//! events pushed into it so that app logic can be tested without a screen.
+//!
+//! ```
+//! use triangle_from_scratch::window::*;
+//! let mut window = MockWindow::create("Mock", [800, 600]).unwrap();
+//! window.push_event(Event::RedrawRequested);
+//! window.push_event(Event::CloseRequested);
+//! let mut redraws = 0;
+//! run_event_loop(&mut window, |window, event| match event {
+//!   Event::RedrawRequested => {
+//!     redraws += 1;
+//!     window.swap_buffers().unwrap();
+//!   }
+//!   Event::CloseRequested => window.close().unwrap(),
+//!   _ => (),
+//! })
+//! .unwrap();
+//! assert_eq!((redraws, window.swaps), (1, 1));
+//! ```
+
+mod mock;
+pub use mock::*;
+
+#[cfg(windows)]
+mod win32;
+#[cfg(windows)]
+pub use win32::*;
+
+#[cfg(target_os = "linux")]
+mod x11;
+#[cfg(target_os = "linux")]
+pub use x11::*;
+
+/// Something that happened to a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+  /// The user wants the window closed (eg: they clicked the close button).
+  /// The window stays open unless [`Window::close`] is called.
+  CloseRequested,
+  /// The drawable area of the window changed size.
+  Resized {
+    width: u32,
+    height: u32,
+  },
+  /// The window needs to be drawn again.
+  RedrawRequested,
+  KeyDown(Key),
+  KeyUp(Key),
+  /// The cursor moved, relative to the top left of the drawable area.
+  MouseMove {
+    x: i32,
+    y: i32,
+  },
+  MouseButton {
+    button: MouseButton,
+    pressed: bool,
+    x: i32,
+    y: i32,
+  },
+  /// The window is gone. This is the last event of a window.
+  Destroyed,
+}
+
+/// A key on the keyboard.
+///
+/// Keys that don't have a variant are `Other`, with the platform's code for
+/// the key (a virtual-key code on Windows, a keysym on X11).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+  Escape,
+  Enter,
+  Space,
+  Tab,
+  Backspace,
+  Left,
+  Right,
+  Up,
+  Down,
+  /// An ASCII letter key, given as the uppercase letter.
+  Letter(u8),
+  /// An ASCII digit key (on the main part of the keyboard).
+  Digit(u8),
+  Other(u32),
+}
+impl Key {
+  /// Converts a Win32 virtual-key code.
+  pub const fn from_virtual_key(vk: u32) -> Self {
+    match vk {
+      // VK_ESCAPE, VK_RETURN, VK_SPACE, VK_TAB, VK_BACK
+      0x1B => Self::Escape,
+      0x0D => Self::Enter,
+      0x20 => Self::Space,
+      0x09 => Self::Tab,
+      0x08 => Self::Backspace,
+      // VK_LEFT, VK_UP, VK_RIGHT, VK_DOWN
+      0x25 => Self::Left,
+      0x26 => Self::Up,
+      0x27 => Self::Right,
+      0x28 => Self::Down,
+      // the letter and digit keys are their ASCII codes.
+      0x41..=0x5A => Self::Letter(vk as u8),
+      0x30..=0x39 => Self::Digit(vk as u8 - b'0'),
+      _ => Self::Other(vk),
+    }
+  }
+
+  /// Converts an X11 keysym.
+  pub const fn from_keysym(keysym: u32) -> Self {
+    match keysym {
+      // XK_Escape, XK_Return, XK_space, XK_Tab, XK_BackSpace
+      0xFF1B => Self::Escape,
+      0xFF0D => Self::Enter,
+      0x0020 => Self::Space,
+      0xFF09 => Self::Tab,
+      0xFF08 => Self::Backspace,
+      // XK_Left, XK_Up, XK_Right, XK_Down
+      0xFF51 => Self::Left,
+      0xFF52 => Self::Up,
+      0xFF53 => Self::Right,
+      0xFF54 => Self::Down,
+      // Latin-1 keysyms are their character codes.
+      0x0041..=0x005A => Self::Letter(keysym as u8),
+      0x0061..=0x007A => Self::Letter(keysym as u8 - (b'a' - b'A')),
+      0x0030..=0x0039 => Self::Digit(keysym as u8 - b'0'),
+      _ => Self::Other(keysym),
+    }
+  }
+}
+
+/// A mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+  Left,
+  Right,
+  Middle,
+  /// Any other button, with the platform's number for it.
+  Other(u32),
+}
+
+/// A window that can be drawn to with GL.
+///
+/// All methods must be called on the thread that created the window.
+pub trait Window: Sized {
+  type Error: std::error::Error;
+
+  /// Creates a window with the given title and size. It's hidden until
+  /// [`show`](Self::show) is called.
+  ///
+  /// The size is of the drawable area, the same as
+  /// [`inner_size`](Self::inner_size), so the whole window with its borders
+  /// is bigger than that.
+  fn create(title: &str, size: [u32; 2]) -> Result<Self, Self::Error>;
+
+  fn show(&mut self) -> Result<(), Self::Error>;
+
+  fn set_title(&mut self, title: &str) -> Result<(), Self::Error>;
+
+  /// The size of the drawable area, which doesn't include any borders or
+  /// title bar.
+  fn inner_size(&self) -> Result<[u32; 2], Self::Error>;
+
+  /// Shows what was drawn with GL, when the window has a GL context.
+  fn swap_buffers(&mut self) -> Result<(), Self::Error>;
+
+  /// Starts destroying the window. [`Event::Destroyed`] comes after this.
+  fn close(&mut self) -> Result<(), Self::Error>;
+
+  /// Waits for the next event. Gives `None` once the window has been
+  /// destroyed and [`Event::Destroyed`] has been given.
+  fn next_event(&mut self) -> Result<Option<Event>, Self::Error>;
+}
+
+/// Sends each event of a window to `f`, until the window is destroyed.
+pub fn run_event_loop<W: Window>(
+  window: &mut W, mut f: impl FnMut(&mut W, Event),
+) -> Result<(), W::Error> {
+  while let Some(event) = window.next_event()? {
+    f(window, event);
+  }
+  Ok(())
+}
+
+#[test]
+fn test_key_codes() {
+  assert_eq!(Key::from_virtual_key(0x1B), Key::Escape);
+  assert_eq!(Key::from_virtual_key(b'Q' as u32), Key::Letter(b'Q'));
+  assert_eq!(Key::from_virtual_key(b'7' as u32), Key::Digit(7));
+  assert_eq!(Key::from_virtual_key(0x70), Key::Other(0x70));
+  assert_eq!(Key::from_keysym(0xFF1B), Key::Escape);
+  assert_eq!(Key::from_keysym(b'q' as u32), Key::Letter(b'Q'));
+  assert_eq!(Key::from_keysym(b'Q' as u32), Key::Letter(b'Q'));
+  assert_eq!(Key::from_keysym(0xFF54), Key::Down);
+  assert_eq!(Key::from_keysym(0xFFBE), Key::Other(0xFFBE));
+}
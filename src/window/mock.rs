@@ -0,0 +1,111 @@
+use super::*;
+use std::collections::VecDeque;
+
+/// A window that doesn't show anything, it gives back the events pushed into
+/// it and keeps track of what was done to it.
+///
+/// When the events run out, [`next_event`](Window::next_event) gives `None`
+/// (as if the window was destroyed), so an event loop always ends.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MockWindow {
+  pub title: String,
+  pub size: [u32; 2],
+  pub visible: bool,
+  /// How many times the buffers were swapped.
+  pub swaps: usize,
+  /// The events that haven't been given yet.
+  pub events: VecDeque<Event>,
+  pub destroyed: bool,
+}
+impl MockWindow {
+  /// Adds an event to the end of the queue.
+  pub fn push_event(&mut self, event: Event) {
+    self.events.push_back(event);
+  }
+}
+impl Window for MockWindow {
+  type Error = core::convert::Infallible;
+
+  fn create(title: &str, size: [u32; 2]) -> Result<Self, Self::Error> {
+    Ok(Self { title: title.to_string(), size, ..Self::default() })
+  }
+
+  fn show(&mut self) -> Result<(), Self::Error> {
+    self.visible = true;
+    Ok(())
+  }
+
+  fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+    self.title = title.to_string();
+    Ok(())
+  }
+
+  fn inner_size(&self) -> Result<[u32; 2], Self::Error> {
+    Ok(self.size)
+  }
+
+  fn swap_buffers(&mut self) -> Result<(), Self::Error> {
+    self.swaps += 1;
+    Ok(())
+  }
+
+  /// Like destroying a real window, the next event is [`Event::Destroyed`]
+  /// and any other events are dropped.
+  fn close(&mut self) -> Result<(), Self::Error> {
+    if !self.destroyed {
+      self.events.clear();
+      self.events.push_back(Event::Destroyed);
+    }
+    Ok(())
+  }
+
+  /// A [`Event::Resized`] event changes the size, and an
+  /// [`Event::Destroyed`] event destroys the window.
+  fn next_event(&mut self) -> Result<Option<Event>, Self::Error> {
+    if self.destroyed {
+      return Ok(None);
+    }
+    let event = self.events.pop_front();
+    match event {
+      Some(Event::Resized { width, height }) => self.size = [width, height],
+      Some(Event::Destroyed) => {
+        self.destroyed = true;
+        self.visible = false;
+      }
+      _ => (),
+    }
+    Ok(event)
+  }
+}
+
+#[test]
+fn test_mock_window() {
+  let mut window = MockWindow::create("Mock", [800, 600]).unwrap();
+  window.show().unwrap();
+  window.set_title("Renamed").unwrap();
+  assert_eq!((window.title.as_str(), window.visible), ("Renamed", true));
+  window.push_event(Event::Resized { width: 320, height: 200 });
+  window.push_event(Event::KeyDown(Key::Escape));
+  window.push_event(Event::KeyUp(Key::Escape));
+  window.push_event(Event::RedrawRequested);
+  let mut events = Vec::new();
+  run_event_loop(&mut window, |window, event| {
+    events.push(event);
+    if event == Event::KeyDown(Key::Escape) {
+      window.close().unwrap();
+    }
+  })
+  .unwrap();
+  assert_eq!(
+    events,
+    [
+      Event::Resized { width: 320, height: 200 },
+      Event::KeyDown(Key::Escape),
+      Event::Destroyed
+    ]
+  );
+  assert_eq!(window.inner_size().unwrap(), [320, 200]);
+  assert!(window.destroyed && !window.visible);
+  window.push_event(Event::RedrawRequested);
+  assert_eq!(window.next_event().unwrap(), None);
+}
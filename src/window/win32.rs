@@ -0,0 +1,214 @@
+use super::*;
+use crate::win32::*;
+use core::ptr::{null, null_mut};
+use std::collections::VecDeque;
+
+/// The window class that every [`Win32Window`] uses.
+const WINDOW_CLASS: &str = "triangle_from_scratch::window";
+
+/// The part of a window that the window procedure writes to, through the
+/// window's userdata pointer.
+#[derive(Default)]
+struct WindowState {
+  decoder: MessageDecoder,
+  events: VecDeque<Event>,
+  destroyed: bool,
+}
+
+/// A Win32 window, with its own device context (`CS_OWNDC`) for GL.
+///
+/// Set the pixel format of [`hdc`](Self::hdc) and make a GL context with it
+/// before showing the window.
+#[derive(Debug)]
+pub struct Win32Window {
+  hwnd: HWND,
+  hdc: HDC,
+  /// Made with `Box::into_raw`, and freed when the window is dropped.
+  state: *mut WindowState,
+}
+impl Win32Window {
+  pub fn hwnd(&self) -> HWND {
+    self.hwnd
+  }
+
+  /// The window's device context, which stays valid until it's dropped.
+  pub fn hdc(&self) -> HDC {
+    self.hdc
+  }
+
+  /// Registers the window class, unless it already is.
+  unsafe fn register_window_class() -> Result<(), Win32Error> {
+    let class_name_null = wide_null(WINDOW_CLASS);
+    let wc = WNDCLASSW {
+      style: CS_OWNDC | CS_HREDRAW | CS_VREDRAW,
+      lpfnWndProc: Some(window_procedure),
+      hInstance: get_process_handle(),
+      lpszClassName: class_name_null.as_ptr(),
+      hCursor: load_predefined_cursor(IDCursor::Arrow)?,
+      ..Default::default()
+    };
+    match register_class(&wc) {
+      Ok(_atom) => Ok(()),
+      Err(e) if e.0 == ERROR_CLASS_ALREADY_EXISTS => Ok(()),
+      Err(e) => Err(e),
+    }
+  }
+}
+impl Window for Win32Window {
+  type Error = Win32Error;
+
+  fn create(
+    title: &str, [width, height]: [u32; 2],
+  ) -> Result<Self, Self::Error> {
+    unsafe {
+      Self::register_window_class()?;
+      let size = app_window_size([width as c_int, height as c_int])?;
+      let state = Box::into_raw(Box::new(WindowState::default()));
+      let hwnd = match create_app_window(
+        WINDOW_CLASS,
+        title,
+        None,
+        size,
+        state.cast(),
+      ) {
+        Ok(hwnd) => hwnd,
+        Err(e) => {
+          drop(Box::from_raw(state));
+          return Err(e);
+        }
+      };
+      match get_dc(hwnd) {
+        Some(hdc) => Ok(Self { hwnd, hdc, state }),
+        None => {
+          let e = get_last_error();
+          let _ = destroy_window(hwnd);
+          drop(Box::from_raw(state));
+          Err(e)
+        }
+      }
+    }
+  }
+
+  fn show(&mut self) -> Result<(), Self::Error> {
+    // the output is if the window was visible before, not an error.
+    let _previously_visible = unsafe { ShowWindow(self.hwnd, SW_SHOW) };
+    Ok(())
+  }
+
+  fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+    let title_null = wide_null(title);
+    if unsafe { SetWindowTextW(self.hwnd, title_null.as_ptr()) } != 0 {
+      Ok(())
+    } else {
+      Err(get_last_error())
+    }
+  }
+
+  fn inner_size(&self) -> Result<[u32; 2], Self::Error> {
+    let mut rect = RECT::default();
+    if unsafe { GetClientRect(self.hwnd, &mut rect) } != 0 {
+      Ok([(rect.right - rect.left) as u32, (rect.bottom - rect.top) as u32])
+    } else {
+      Err(get_last_error())
+    }
+  }
+
+  fn swap_buffers(&mut self) -> Result<(), Self::Error> {
+    if unsafe { SwapBuffers(self.hdc) } != 0 {
+      Ok(())
+    } else {
+      Err(get_last_error())
+    }
+  }
+
+  fn close(&mut self) -> Result<(), Self::Error> {
+    if unsafe { (*self.state).destroyed } {
+      return Ok(());
+    }
+    unsafe {
+      let _ = release_dc(self.hwnd, self.hdc);
+      // this sends `WM_DESTROY` right away.
+      destroy_window(self.hwnd)
+    }
+  }
+
+  fn next_event(&mut self) -> Result<Option<Event>, Self::Error> {
+    loop {
+      // the window procedure pushes events while messages are dispatched.
+      let state = unsafe { &mut *self.state };
+      if let Some(event) = state.events.pop_front() {
+        return Ok(Some(event));
+      }
+      if state.destroyed {
+        return Ok(None);
+      }
+      let msg = get_any_message()?;
+      if msg.message == WM_QUIT {
+        // someone else posted a quit, so stop as if the window was gone.
+        return Ok(None);
+      }
+      translate_message(&msg);
+      unsafe { DispatchMessageW(&msg) };
+    }
+  }
+}
+impl Drop for Win32Window {
+  fn drop(&mut self) {
+    unsafe {
+      if !(*self.state).destroyed {
+        let _ = release_dc(self.hwnd, self.hdc);
+        let _ = destroy_window(self.hwnd);
+      }
+      drop(Box::from_raw(self.state));
+    }
+  }
+}
+
+unsafe extern "system" fn window_procedure(
+  hwnd: HWND, msg: UINT, wparam: WPARAM, lparam: LPARAM,
+) -> LRESULT {
+  if msg == WM_NCCREATE {
+    let createstruct: *mut CREATESTRUCTW = lparam as *mut _;
+    if createstruct.is_null() {
+      return 0;
+    }
+    let state = (*createstruct).lpCreateParams as *mut WindowState;
+    if set_window_userdata::<WindowState>(hwnd, state).is_err() {
+      return 0;
+    }
+    // This is required for the window title to be drawn!
+    return DefWindowProcW(hwnd, msg, wparam, lparam);
+  }
+  let state = match get_window_userdata::<WindowState>(hwnd) {
+    Ok(ptr) if !ptr.is_null() => &mut *ptr,
+    _ => return DefWindowProcW(hwnd, msg, wparam, lparam),
+  };
+  let event = match state.decoder.decode(msg, wparam, lparam) {
+    WindowMessage::Close => Event::CloseRequested,
+    WindowMessage::Destroy => {
+      state.destroyed = true;
+      let _ = set_window_userdata::<WindowState>(hwnd, null_mut());
+      Event::Destroyed
+    }
+    WindowMessage::Paint => {
+      // without validating, `WM_PAINT` would keep coming.
+      ValidateRect(hwnd, null());
+      Event::RedrawRequested
+    }
+    WindowMessage::Size { width, height, .. } => {
+      Event::Resized { width, height }
+    }
+    WindowMessage::KeyDown { vk, .. } => {
+      Event::KeyDown(Key::from_virtual_key(vk))
+    }
+    WindowMessage::KeyUp { vk, .. } => Event::KeyUp(Key::from_virtual_key(vk)),
+    WindowMessage::MouseMove { x, y, .. } => Event::MouseMove { x, y },
+    WindowMessage::MouseButton { button, action, x, y, .. } => {
+      let pressed = action != ButtonAction::Up;
+      Event::MouseButton { button, pressed, x, y }
+    }
+    _ => return DefWindowProcW(hwnd, msg, wparam, lparam),
+  };
+  state.events.push_back(event);
+  0
+}
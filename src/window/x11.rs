@@ -0,0 +1,203 @@
+#![allow(non_upper_case_globals)]
+
+use super::*;
+use crate::{
+  glx::glx_swap_buffers,
+  x11::{
+    self, close_display, create_app_window, destroy_window, get_any_message,
+    get_window_size, is_close_request, open_display, show_window,
+    sync_for_errors, ButtonPress, ButtonRelease, ClientMessage,
+    ConfigureNotify, DestroyNotify, Display, Expose, KeyPress, KeyRelease,
+    MotionNotify, X11Error, XEvent, XLookupKeysym, XStoreName, XVisualInfo,
+  },
+};
+
+/// A window on an X11 display, which swaps buffers with GLX.
+///
+/// [`Window::create`] opens its own display connection and uses the default
+/// visual. For GL the window needs the visual of the chosen `GLXFBConfig`, so
+/// use [`X11Window::create_with_visual`] instead.
+#[derive(Debug)]
+pub struct X11Window {
+  display: *mut Display,
+  window: x11::Window,
+  size: [u32; 2],
+  owns_display: bool,
+  /// [`Window::close`] was called, so the window is already on its way out.
+  closing: bool,
+  destroyed: bool,
+}
+impl X11Window {
+  /// Creates a window on a display that's already open.
+  ///
+  /// ## Safety
+  ///
+  /// The display has to stay open until the window is dropped, and
+  /// `visual_info` must be a visual of its default screen.
+  ///
+  /// See [`create_app_window`].
+  pub unsafe fn create_with_visual(
+    display: *mut Display, title: &str, [width, height]: [u32; 2],
+    visual_info: Option<&XVisualInfo>,
+  ) -> Result<Self, X11Error> {
+    let window = create_app_window(
+      display,
+      title,
+      None,
+      [width as i32, height as i32],
+      visual_info,
+    )?;
+    Ok(Self {
+      display,
+      window,
+      size: [width, height],
+      owns_display: false,
+      closing: false,
+      destroyed: false,
+    })
+  }
+
+  pub fn display(&self) -> *mut Display {
+    self.display
+  }
+
+  /// The X11 window, which is also the GLX drawable.
+  pub fn window(&self) -> x11::Window {
+    self.window
+  }
+
+  /// Converts an X11 event of this window, if there's an [`Event`] for it.
+  unsafe fn convert(&mut self, event: &mut XEvent) -> Option<Event> {
+    Some(match event.get_type() {
+      ClientMessage if is_close_request(self.display, event) => {
+        Event::CloseRequested
+      }
+      ConfigureNotify => {
+        let c = event.xconfigure;
+        let size = [c.width as u32, c.height as u32];
+        if size == self.size {
+          // the window only moved.
+          return None;
+        }
+        self.size = size;
+        Event::Resized { width: size[0], height: size[1] }
+      }
+      // only the last of a group of expose events needs a redraw.
+      Expose if event.xexpose.count == 0 => Event::RedrawRequested,
+      KeyPress | KeyRelease => {
+        let keysym = XLookupKeysym(&mut event.xkey, 0);
+        let key = Key::from_keysym(keysym as u32);
+        if event.get_type() == KeyPress {
+          Event::KeyDown(key)
+        } else {
+          Event::KeyUp(key)
+        }
+      }
+      MotionNotify => {
+        Event::MouseMove { x: event.xmotion.x, y: event.xmotion.y }
+      }
+      ButtonPress | ButtonRelease => {
+        let b = event.xbutton;
+        let button = match b.button {
+          1 => MouseButton::Left,
+          2 => MouseButton::Middle,
+          3 => MouseButton::Right,
+          other => MouseButton::Other(other),
+        };
+        let pressed = event.get_type() == ButtonPress;
+        Event::MouseButton { button, pressed, x: b.x, y: b.y }
+      }
+      DestroyNotify => {
+        self.destroyed = true;
+        Event::Destroyed
+      }
+      _ => return None,
+    })
+  }
+}
+impl Window for X11Window {
+  type Error = X11Error;
+
+  fn create(title: &str, size: [u32; 2]) -> Result<Self, Self::Error> {
+    let display = open_display(None)?;
+    unsafe {
+      let mut window = Self::create_with_visual(display, title, size, None)
+        .inspect_err(|_| close_display(display))?;
+      window.owns_display = true;
+      Ok(window)
+    }
+  }
+
+  fn show(&mut self) -> Result<(), Self::Error> {
+    unsafe { show_window(self.display, self.window) }
+  }
+
+  fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+    let title_null = x11::c_string(title);
+    unsafe {
+      XStoreName(self.display, self.window, title_null.as_ptr().cast());
+      sync_for_errors(self.display)
+    }
+  }
+
+  fn inner_size(&self) -> Result<[u32; 2], Self::Error> {
+    let [width, height] =
+      unsafe { get_window_size(self.display, self.window) }?;
+    Ok([width as u32, height as u32])
+  }
+
+  fn swap_buffers(&mut self) -> Result<(), Self::Error> {
+    unsafe {
+      glx_swap_buffers(self.display, self.window);
+      sync_for_errors(self.display)
+    }
+  }
+
+  fn close(&mut self) -> Result<(), Self::Error> {
+    if self.closing || self.destroyed {
+      return Ok(());
+    }
+    self.closing = true;
+    unsafe { destroy_window(self.display, self.window) }
+  }
+
+  fn next_event(&mut self) -> Result<Option<Event>, Self::Error> {
+    while !self.destroyed {
+      let mut event = unsafe { get_any_message(self.display) }?;
+      if unsafe { event.xany.window } != self.window {
+        continue;
+      }
+      if let Some(event) = unsafe { self.convert(&mut event) } {
+        return Ok(Some(event));
+      }
+    }
+    Ok(None)
+  }
+}
+impl Drop for X11Window {
+  fn drop(&mut self) {
+    unsafe {
+      if !self.closing && !self.destroyed {
+        let _ = destroy_window(self.display, self.window);
+      }
+      if self.owns_display {
+        close_display(self.display);
+      }
+    }
+  }
+}
+
+#[test]
+fn test_x11_window() {
+  if !crate::test_util::has_x_display() {
+    return;
+  }
+  let mut window = X11Window::create("Test Window", [64, 32]).unwrap();
+  window.set_title("Renamed").unwrap();
+  window.show().unwrap();
+  assert_eq!(window.inner_size().unwrap(), [64, 32]);
+  window.close().unwrap();
+  let mut events = Vec::new();
+  run_event_loop(&mut window, |_, event| events.push(event)).unwrap();
+  assert_eq!(events.last(), Some(&Event::Destroyed));
+}
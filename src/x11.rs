@@ -0,0 +1,987 @@
+#![cfg(target_os = "linux")]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+//! Module for stuff that's specific to the Xlib API on Linux.
+//!
+//! Xlib reports protocol errors asynchronously through an error handler. The
+//! wrappers here install a handler that records the error code for each
+//! display, and then [`XSync`] after each request so that any error can be
+//! returned to the caller right away.
+
+pub use core::ffi::c_void;
+pub use std::os::raw::{
+  c_char, c_int, c_long, c_short, c_uchar, c_uint, c_ulong,
+};
+
+use core::ptr::null;
+use std::sync::{Mutex, Once};
+
+macro_rules! unsafe_impl_default_zeroed {
+  ($t:ty) => {
+    impl Default for $t {
+      #[inline]
+      fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+      }
+    }
+  };
+}
+
+pub type XID = c_ulong;
+pub type Atom = c_ulong;
+pub type Bool = c_int;
+pub type Colormap = XID;
+pub type Cursor = XID;
+pub type Drawable = XID;
+pub type KeyCode = c_uchar;
+pub type KeySym = XID;
+pub type Pixmap = XID;
+pub type Status = c_int;
+pub type Time = c_ulong;
+pub type VisualID = c_ulong;
+pub type Window = XID;
+
+/// An opaque connection to an X server.
+#[repr(C)]
+pub struct Display {
+  _private: [u8; 0],
+}
+
+/// An opaque description of how pixels are laid out on a screen.
+#[repr(C)]
+pub struct Visual {
+  _private: [u8; 0],
+}
+
+/// An opaque screen of a [`Display`].
+#[repr(C)]
+pub struct Screen {
+  _private: [u8; 0],
+}
+
+/// See [`XSetErrorHandler`](https://www.x.org/releases/current/doc/man/man3/XSetErrorHandler.3.xhtml)
+pub type XErrorHandler = Option<
+  unsafe extern "C" fn(display: *mut Display, event: *mut XErrorEvent) -> c_int,
+>;
+
+/// See [`XVisualInfo`](https://www.x.org/releases/current/doc/man/man3/XGetVisualInfo.3.xhtml)
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XVisualInfo {
+  pub visual: *mut Visual,
+  pub visualid: VisualID,
+  pub screen: c_int,
+  pub depth: c_int,
+  pub class: c_int,
+  pub red_mask: c_ulong,
+  pub green_mask: c_ulong,
+  pub blue_mask: c_ulong,
+  pub colormap_size: c_int,
+  pub bits_per_rgb: c_int,
+}
+unsafe_impl_default_zeroed!(XVisualInfo);
+
+/// See [`XSetWindowAttributes`](https://www.x.org/releases/current/doc/man/man3/XCreateWindow.3.xhtml)
+#[repr(C)]
+pub struct XSetWindowAttributes {
+  pub background_pixmap: Pixmap,
+  pub background_pixel: c_ulong,
+  pub border_pixmap: Pixmap,
+  pub border_pixel: c_ulong,
+  pub bit_gravity: c_int,
+  pub win_gravity: c_int,
+  pub backing_store: c_int,
+  pub backing_planes: c_ulong,
+  pub backing_pixel: c_ulong,
+  pub save_under: Bool,
+  pub event_mask: c_long,
+  pub do_not_propagate_mask: c_long,
+  pub override_redirect: Bool,
+  pub colormap: Colormap,
+  pub cursor: Cursor,
+}
+unsafe_impl_default_zeroed!(XSetWindowAttributes);
+
+/// See [`XWindowAttributes`](https://www.x.org/releases/current/doc/man/man3/XGetWindowAttributes.3.xhtml)
+#[repr(C)]
+pub struct XWindowAttributes {
+  pub x: c_int,
+  pub y: c_int,
+  pub width: c_int,
+  pub height: c_int,
+  pub border_width: c_int,
+  pub depth: c_int,
+  pub visual: *mut Visual,
+  pub root: Window,
+  pub class: c_int,
+  pub bit_gravity: c_int,
+  pub win_gravity: c_int,
+  pub backing_store: c_int,
+  pub backing_planes: c_ulong,
+  pub backing_pixel: c_ulong,
+  pub save_under: Bool,
+  pub colormap: Colormap,
+  pub map_installed: Bool,
+  pub map_state: c_int,
+  pub all_event_masks: c_long,
+  pub your_event_mask: c_long,
+  pub do_not_propagate_mask: c_long,
+  pub override_redirect: Bool,
+  pub screen: *mut Screen,
+}
+unsafe_impl_default_zeroed!(XWindowAttributes);
+
+/// The fields that every event starts with.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XAnyEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub window: Window,
+}
+
+/// Used for both [`KeyPress`] and [`KeyRelease`] events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XKeyEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub window: Window,
+  pub root: Window,
+  pub subwindow: Window,
+  pub time: Time,
+  pub x: c_int,
+  pub y: c_int,
+  pub x_root: c_int,
+  pub y_root: c_int,
+  pub state: c_uint,
+  pub keycode: c_uint,
+  pub same_screen: Bool,
+}
+
+/// Used for both [`ButtonPress`] and [`ButtonRelease`] events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XButtonEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub window: Window,
+  pub root: Window,
+  pub subwindow: Window,
+  pub time: Time,
+  pub x: c_int,
+  pub y: c_int,
+  pub x_root: c_int,
+  pub y_root: c_int,
+  pub state: c_uint,
+  pub button: c_uint,
+  pub same_screen: Bool,
+}
+
+/// Used for [`MotionNotify`] events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XMotionEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub window: Window,
+  pub root: Window,
+  pub subwindow: Window,
+  pub time: Time,
+  pub x: c_int,
+  pub y: c_int,
+  pub x_root: c_int,
+  pub y_root: c_int,
+  pub state: c_uint,
+  pub is_hint: c_char,
+  pub same_screen: Bool,
+}
+
+/// Used for both [`FocusIn`] and [`FocusOut`] events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XFocusChangeEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub window: Window,
+  pub mode: c_int,
+  pub detail: c_int,
+}
+
+/// Used for [`Expose`] events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XExposeEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub window: Window,
+  pub x: c_int,
+  pub y: c_int,
+  pub width: c_int,
+  pub height: c_int,
+  pub count: c_int,
+}
+
+/// Used for [`DestroyNotify`] events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XDestroyWindowEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub event: Window,
+  pub window: Window,
+}
+
+/// Used for [`ConfigureNotify`] events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XConfigureEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub event: Window,
+  pub window: Window,
+  pub x: c_int,
+  pub y: c_int,
+  pub width: c_int,
+  pub height: c_int,
+  pub border_width: c_int,
+  pub above: Window,
+  pub override_redirect: Bool,
+}
+
+/// The data of an [`XClientMessageEvent`], interpreted according to the
+/// `format` field.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub union ClientMessageData {
+  pub b: [c_char; 20],
+  pub s: [c_short; 10],
+  pub l: [c_long; 5],
+}
+
+/// Used for [`ClientMessage`] events.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct XClientMessageEvent {
+  pub type_: c_int,
+  pub serial: c_ulong,
+  pub send_event: Bool,
+  pub display: *mut Display,
+  pub window: Window,
+  pub message_type: Atom,
+  pub format: c_int,
+  pub data: ClientMessageData,
+}
+
+/// Passed to the error handler when a protocol error happens.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct XErrorEvent {
+  pub type_: c_int,
+  pub display: *mut Display,
+  pub resourceid: XID,
+  pub serial: c_ulong,
+  pub error_code: c_uchar,
+  pub request_code: c_uchar,
+  pub minor_code: c_uchar,
+}
+
+/// See [`XEvent`](https://www.x.org/releases/current/doc/libX11/libX11/libX11.html#Event_Structures)
+///
+/// Every variant starts with the `type_` field, so you can always check that
+/// to know which of the other fields is valid.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub union XEvent {
+  pub type_: c_int,
+  pub xany: XAnyEvent,
+  pub xkey: XKeyEvent,
+  pub xbutton: XButtonEvent,
+  pub xmotion: XMotionEvent,
+  pub xfocus: XFocusChangeEvent,
+  pub xexpose: XExposeEvent,
+  pub xdestroywindow: XDestroyWindowEvent,
+  pub xconfigure: XConfigureEvent,
+  pub xclient: XClientMessageEvent,
+  pub xerror: XErrorEvent,
+  pub pad: [c_long; 24],
+}
+unsafe_impl_default_zeroed!(XEvent);
+impl XEvent {
+  /// The event type (eg: [`KeyPress`], [`ClientMessage`], etc).
+  #[inline]
+  #[must_use]
+  pub fn get_type(&self) -> c_int {
+    // Safety: every event variant starts with the type field.
+    unsafe { self.type_ }
+  }
+}
+
+pub const False: Bool = 0;
+pub const True: Bool = 1;
+
+/// Window class for windows that can be drawn to.
+pub const InputOutput: c_uint = 1;
+
+/// Pass this as the `depth` or `visual` of a new window to copy the parent's.
+pub const CopyFromParent: c_int = 0;
+
+/// Colormap entries are not allocated when creating a colormap.
+pub const AllocNone: c_int = 0;
+
+pub const NoEventMask: c_long = 0;
+pub const KeyPressMask: c_long = 1 << 0;
+pub const KeyReleaseMask: c_long = 1 << 1;
+pub const ButtonPressMask: c_long = 1 << 2;
+pub const ButtonReleaseMask: c_long = 1 << 3;
+pub const EnterWindowMask: c_long = 1 << 4;
+pub const LeaveWindowMask: c_long = 1 << 5;
+pub const PointerMotionMask: c_long = 1 << 6;
+pub const ExposureMask: c_long = 1 << 15;
+pub const VisibilityChangeMask: c_long = 1 << 16;
+pub const StructureNotifyMask: c_long = 1 << 17;
+pub const FocusChangeMask: c_long = 1 << 21;
+pub const PropertyChangeMask: c_long = 1 << 22;
+
+pub const CWBackPixel: c_ulong = 1 << 1;
+pub const CWBorderPixel: c_ulong = 1 << 3;
+pub const CWEventMask: c_ulong = 1 << 11;
+pub const CWColormap: c_ulong = 1 << 13;
+
+pub const KeyPress: c_int = 2;
+pub const KeyRelease: c_int = 3;
+pub const ButtonPress: c_int = 4;
+pub const ButtonRelease: c_int = 5;
+pub const MotionNotify: c_int = 6;
+pub const EnterNotify: c_int = 7;
+pub const LeaveNotify: c_int = 8;
+pub const FocusIn: c_int = 9;
+pub const FocusOut: c_int = 10;
+pub const Expose: c_int = 12;
+pub const DestroyNotify: c_int = 17;
+pub const UnmapNotify: c_int = 18;
+pub const MapNotify: c_int = 19;
+pub const ConfigureNotify: c_int = 22;
+pub const ClientMessage: c_int = 33;
+
+pub const Success: c_int = 0;
+pub const BadRequest: c_int = 1;
+pub const BadValue: c_int = 2;
+pub const BadWindow: c_int = 3;
+pub const BadPixmap: c_int = 4;
+pub const BadAtom: c_int = 5;
+pub const BadCursor: c_int = 6;
+pub const BadFont: c_int = 7;
+pub const BadMatch: c_int = 8;
+pub const BadDrawable: c_int = 9;
+pub const BadAccess: c_int = 10;
+pub const BadAlloc: c_int = 11;
+pub const BadColor: c_int = 12;
+pub const BadGC: c_int = 13;
+pub const BadIDChoice: c_int = 14;
+pub const BadName: c_int = 15;
+pub const BadLength: c_int = 16;
+pub const BadImplementation: c_int = 17;
+
+/// Error codes at or above this value come from server extensions (GLX, etc).
+pub const FirstExtensionError: c_int = 128;
+
+#[link(name = "X11")]
+extern "C" {
+  /// [`XOpenDisplay`](https://www.x.org/releases/current/doc/man/man3/XOpenDisplay.3.xhtml)
+  pub fn XOpenDisplay(display_name: *const c_char) -> *mut Display;
+
+  /// [`XCloseDisplay`](https://www.x.org/releases/current/doc/man/man3/XOpenDisplay.3.xhtml)
+  pub fn XCloseDisplay(display: *mut Display) -> c_int;
+
+  /// [`XInitThreads`](https://www.x.org/releases/current/doc/man/man3/XInitThreads.3.xhtml)
+  pub fn XInitThreads() -> Status;
+
+  /// [`XDefaultScreen`](https://www.x.org/releases/current/doc/man/man3/AllPlanes.3.xhtml)
+  pub fn XDefaultScreen(display: *mut Display) -> c_int;
+
+  /// [`XRootWindow`](https://www.x.org/releases/current/doc/man/man3/AllPlanes.3.xhtml)
+  pub fn XRootWindow(display: *mut Display, screen_number: c_int) -> Window;
+
+  /// [`XDefaultVisual`](https://www.x.org/releases/current/doc/man/man3/AllPlanes.3.xhtml)
+  pub fn XDefaultVisual(
+    display: *mut Display, screen_number: c_int,
+  ) -> *mut Visual;
+
+  /// [`XDefaultDepth`](https://www.x.org/releases/current/doc/man/man3/AllPlanes.3.xhtml)
+  pub fn XDefaultDepth(display: *mut Display, screen_number: c_int) -> c_int;
+
+  /// [`XDefaultColormap`](https://www.x.org/releases/current/doc/man/man3/AllPlanes.3.xhtml)
+  pub fn XDefaultColormap(
+    display: *mut Display, screen_number: c_int,
+  ) -> Colormap;
+
+  /// [`XBlackPixel`](https://www.x.org/releases/current/doc/man/man3/AllPlanes.3.xhtml)
+  pub fn XBlackPixel(display: *mut Display, screen_number: c_int) -> c_ulong;
+
+  /// [`XCreateColormap`](https://www.x.org/releases/current/doc/man/man3/XCreateColormap.3.xhtml)
+  pub fn XCreateColormap(
+    display: *mut Display, w: Window, visual: *mut Visual, alloc: c_int,
+  ) -> Colormap;
+
+  /// [`XFreeColormap`](https://www.x.org/releases/current/doc/man/man3/XCreateColormap.3.xhtml)
+  pub fn XFreeColormap(display: *mut Display, colormap: Colormap) -> c_int;
+
+  /// [`XCreateWindow`](https://www.x.org/releases/current/doc/man/man3/XCreateWindow.3.xhtml)
+  pub fn XCreateWindow(
+    display: *mut Display, parent: Window, x: c_int, y: c_int, width: c_uint,
+    height: c_uint, border_width: c_uint, depth: c_int, class: c_uint,
+    visual: *mut Visual, valuemask: c_ulong,
+    attributes: *mut XSetWindowAttributes,
+  ) -> Window;
+
+  /// [`XDestroyWindow`](https://www.x.org/releases/current/doc/man/man3/XDestroyWindow.3.xhtml)
+  pub fn XDestroyWindow(display: *mut Display, w: Window) -> c_int;
+
+  /// [`XGetWindowAttributes`](https://www.x.org/releases/current/doc/man/man3/XGetWindowAttributes.3.xhtml)
+  pub fn XGetWindowAttributes(
+    display: *mut Display, w: Window,
+    window_attributes_return: *mut XWindowAttributes,
+  ) -> Status;
+
+  /// [`XMapWindow`](https://www.x.org/releases/current/doc/man/man3/XMapWindow.3.xhtml)
+  pub fn XMapWindow(display: *mut Display, w: Window) -> c_int;
+
+  /// [`XUnmapWindow`](https://www.x.org/releases/current/doc/man/man3/XUnmapWindow.3.xhtml)
+  pub fn XUnmapWindow(display: *mut Display, w: Window) -> c_int;
+
+  /// [`XStoreName`](https://www.x.org/releases/current/doc/man/man3/XSetWMName.3.xhtml)
+  pub fn XStoreName(
+    display: *mut Display, w: Window, window_name: *const c_char,
+  ) -> c_int;
+
+  /// [`XSelectInput`](https://www.x.org/releases/current/doc/man/man3/XSelectInput.3.xhtml)
+  pub fn XSelectInput(
+    display: *mut Display, w: Window, event_mask: c_long,
+  ) -> c_int;
+
+  /// [`XInternAtom`](https://www.x.org/releases/current/doc/man/man3/XInternAtom.3.xhtml)
+  pub fn XInternAtom(
+    display: *mut Display, atom_name: *const c_char, only_if_exists: Bool,
+  ) -> Atom;
+
+  /// [`XSetWMProtocols`](https://www.x.org/releases/current/doc/man/man3/XSetWMProtocols.3.xhtml)
+  pub fn XSetWMProtocols(
+    display: *mut Display, w: Window, protocols: *mut Atom, count: c_int,
+  ) -> Status;
+
+  /// [`XNextEvent`](https://www.x.org/releases/current/doc/man/man3/XNextEvent.3.xhtml)
+  pub fn XNextEvent(display: *mut Display, event_return: *mut XEvent) -> c_int;
+
+  /// [`XPending`](https://www.x.org/releases/current/doc/man/man3/XFlush.3.xhtml)
+  pub fn XPending(display: *mut Display) -> c_int;
+
+  /// [`XFlush`](https://www.x.org/releases/current/doc/man/man3/XFlush.3.xhtml)
+  pub fn XFlush(display: *mut Display) -> c_int;
+
+  /// [`XSync`](https://www.x.org/releases/current/doc/man/man3/XFlush.3.xhtml)
+  pub fn XSync(display: *mut Display, discard: Bool) -> c_int;
+
+  /// [`XFree`](https://www.x.org/releases/current/doc/man/man3/XFree.3.xhtml)
+  pub fn XFree(data: *mut c_void) -> c_int;
+
+  /// [`XSetErrorHandler`](https://www.x.org/releases/current/doc/man/man3/XSetErrorHandler.3.xhtml)
+  pub fn XSetErrorHandler(handler: XErrorHandler) -> XErrorHandler;
+
+  /// [`XLookupKeysym`](https://www.x.org/releases/current/doc/man/man3/XLookupKeysym.3.xhtml)
+  pub fn XLookupKeysym(key_event: *mut XKeyEvent, index: c_int) -> KeySym;
+}
+
+/// The code of the most recent error of each display reported to
+/// [`record_error_handler`], by the display's address.
+///
+/// Each display has its own, so that a call on one display (eg: on another
+/// thread) can't see or clear the errors of another.
+static LAST_ERRORS: Mutex<Vec<(usize, c_int)>> = Mutex::new(Vec::new());
+
+/// Gets the most recently recorded X11 error code of a display.
+///
+/// Errors are only recorded once [`open_display`] has installed
+/// [`record_error_handler`].
+pub fn get_last_error(display: *mut Display) -> X11Error {
+  let errors = LAST_ERRORS.lock().unwrap_or_else(|e| e.into_inner());
+  let code = errors.iter().find(|(d, _)| *d == display as usize);
+  X11Error(code.map_or(0, |&(_, code)| code))
+}
+
+/// Sets the recorded X11 error code of a display.
+///
+/// Usually you'd set this to `X11Error(0)` before a call that you want to
+/// check.
+pub fn set_last_error(display: *mut Display, e: X11Error) {
+  let mut errors = LAST_ERRORS.lock().unwrap_or_else(|e| e.into_inner());
+  errors.retain(|(d, _)| *d != display as usize);
+  if e.0 != 0 {
+    errors.push((display as usize, e.0));
+  }
+}
+
+/// An error handler that records the error code instead of exiting.
+///
+/// The default Xlib error handler prints a message and then exits the process,
+/// which is never what we want.
+///
+/// ## Safety
+///
+/// `event` must be null or point to a valid `XErrorEvent`.
+///
+/// See [`XSetErrorHandler`](https://www.x.org/releases/current/doc/man/man3/XSetErrorHandler.3.xhtml)
+pub unsafe extern "C" fn record_error_handler(
+  display: *mut Display, event: *mut XErrorEvent,
+) -> c_int {
+  if let Some(event) = event.as_ref() {
+    set_last_error(display, X11Error(event.error_code as c_int));
+  }
+  0
+}
+
+/// Newtype wrapper for an X11 error code.
+///
+/// If bit 29 is set, it's an application error.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct X11Error(pub c_int);
+impl X11Error {
+  pub const APPLICATION_ERROR_BIT: c_int = 1 << 29;
+
+  /// The name of a core protocol error code (eg: `"BadWindow"`).
+  #[must_use]
+  pub const fn name(&self) -> Option<&'static str> {
+    Some(match self.0 {
+      Success => "Success",
+      BadRequest => "BadRequest",
+      BadValue => "BadValue",
+      BadWindow => "BadWindow",
+      BadPixmap => "BadPixmap",
+      BadAtom => "BadAtom",
+      BadCursor => "BadCursor",
+      BadFont => "BadFont",
+      BadMatch => "BadMatch",
+      BadDrawable => "BadDrawable",
+      BadAccess => "BadAccess",
+      BadAlloc => "BadAlloc",
+      BadColor => "BadColor",
+      BadGC => "BadGC",
+      BadIDChoice => "BadIDChoice",
+      BadName => "BadName",
+      BadLength => "BadLength",
+      BadImplementation => "BadImplementation",
+      _ => return None,
+    })
+  }
+
+  /// The Xlib description of a core protocol error code.
+  #[must_use]
+  pub const fn description(&self) -> Option<&'static str> {
+    Some(match self.0 {
+      Success => "everything's okay",
+      BadRequest => "invalid request code or no such operation",
+      BadValue => "integer parameter out of range for operation",
+      BadWindow => "invalid Window parameter",
+      BadPixmap => "invalid Pixmap parameter",
+      BadAtom => "invalid Atom parameter",
+      BadCursor => "invalid Cursor parameter",
+      BadFont => "invalid Font parameter",
+      BadMatch => "invalid parameter attributes",
+      BadDrawable => "invalid Pixmap or Window parameter",
+      BadAccess => "attempt to access private resource denied",
+      BadAlloc => "insufficient resources for operation",
+      BadColor => "invalid Colormap parameter",
+      BadGC => "invalid GC parameter",
+      BadIDChoice => "invalid resource ID chosen for this connection",
+      BadName => "named color or font does not exist",
+      BadLength => "poly request too large or internal Xlib length error",
+      BadImplementation => "server does not implement operation",
+      _ => return None,
+    })
+  }
+}
+impl std::error::Error for X11Error {}
+
+impl core::fmt::Debug for X11Error {
+  /// Displays the error using the names from the X protocol.
+  ///
+  /// ```
+  /// use triangle_from_scratch::x11::*;
+  /// let s = format!("{:?}", X11Error(BadWindow));
+  /// assert_eq!("BadWindow (invalid Window parameter)", s);
+  /// let app_error = format!("{:?}", X11Error(1 << 29));
+  /// assert_eq!("X11 Application Error (536870912)", app_error);
+  /// ```
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if f.alternate() {
+      return write!(f, "X11Error({})", self.0);
+    }
+
+    if self.0 & Self::APPLICATION_ERROR_BIT > 0 {
+      return write!(f, "X11 Application Error ({})", self.0);
+    }
+    match (self.name(), self.description()) {
+      (Some(name), Some(description)) => {
+        write!(f, "{} ({})", name, description)
+      }
+      _ if self.0 >= FirstExtensionError => {
+        write!(f, "X11 Extension Error ({})", self.0)
+      }
+      _ => write!(f, "X11 Unknown Error ({})", self.0),
+    }
+  }
+}
+impl core::fmt::Display for X11Error {
+  /// Same as `Debug` impl
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+
+#[test]
+fn test_x11_error_names() {
+  assert_eq!(X11Error(Success).name(), Some("Success"));
+  assert_eq!(X11Error(BadMatch).name(), Some("BadMatch"));
+  assert_eq!(X11Error(BadImplementation).name(), Some("BadImplementation"));
+  assert_eq!(X11Error(18).name(), None);
+  assert_eq!(format!("{}", X11Error(130)), "X11 Extension Error (130)");
+  assert_eq!(format!("{:#?}", X11Error(BadAlloc)), "X11Error(11)");
+}
+
+#[test]
+fn test_last_error_per_display() {
+  // the addresses are never used as displays, only as keys.
+  let a = 0x10 as *mut Display;
+  let b = 0x20 as *mut Display;
+  set_last_error(a, X11Error(BadWindow));
+  assert_eq!(get_last_error(a), X11Error(BadWindow));
+  assert_eq!(get_last_error(b), X11Error(0));
+  set_last_error(b, X11Error(BadMatch));
+  set_last_error(a, X11Error(0));
+  assert_eq!(get_last_error(a), X11Error(0));
+  assert_eq!(get_last_error(b), X11Error(BadMatch));
+  set_last_error(b, X11Error(0));
+}
+
+/// Turns a Rust string slice into a null-terminated byte vector.
+///
+/// Any interior null bytes will end the string early on the C side.
+pub fn c_string(s: &str) -> Vec<u8> {
+  s.bytes().chain(Some(0)).collect()
+}
+
+/// Syncs with the server and reports any error of the display that happened
+/// since the last time its recorded error was cleared.
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+///
+/// See [`XSync`](https://www.x.org/releases/current/doc/man/man3/XFlush.3.xhtml)
+pub unsafe fn sync_for_errors(display: *mut Display) -> Result<(), X11Error> {
+  XSync(display, False);
+  let last_error = get_last_error(display);
+  if last_error.0 != 0 {
+    set_last_error(display, X11Error(0));
+    Err(last_error)
+  } else {
+    Ok(())
+  }
+}
+
+/// Opens a connection to an X server.
+///
+/// * `None` uses the `DISPLAY` environment variable.
+/// * This also installs [`record_error_handler`] as the Xlib error handler.
+/// * The first call also calls `XInitThreads`, so that connections can be used
+///   from more than one thread.
+///
+/// See [`XOpenDisplay`](https://www.x.org/releases/current/doc/man/man3/XOpenDisplay.3.xhtml)
+pub fn open_display(name: Option<&str>) -> Result<*mut Display, X11Error> {
+  static INIT_THREADS: Once = Once::new();
+  // Safety: this is before any other Xlib call of the crate.
+  INIT_THREADS.call_once(|| unsafe {
+    XInitThreads();
+  });
+  let name_null = name.map(c_string);
+  let name_ptr = match &name_null {
+    Some(v) => v.as_ptr().cast(),
+    None => null(),
+  };
+  // Safety: the name is either null or a null-terminated string.
+  let display = unsafe { XOpenDisplay(name_ptr) };
+  if display.is_null() {
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  } else {
+    unsafe { XSetErrorHandler(Some(record_error_handler)) };
+    Ok(display)
+  }
+}
+
+/// Closes a connection to an X server.
+///
+/// All windows and other resources created through the connection are
+/// destroyed by the server.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and isn't usable afterwards.
+///
+/// See [`XCloseDisplay`](https://www.x.org/releases/current/doc/man/man3/XOpenDisplay.3.xhtml)
+pub unsafe fn close_display(display: *mut Display) {
+  XCloseDisplay(display);
+  // a later connection might get the same address.
+  set_last_error(display, X11Error(0));
+}
+
+/// Gets an atom for the name given, creating it if necessary.
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+///
+/// See [`XInternAtom`](https://www.x.org/releases/current/doc/man/man3/XInternAtom.3.xhtml)
+pub unsafe fn intern_atom(
+  display: *mut Display, name: &str,
+) -> Result<Atom, X11Error> {
+  let name_null = c_string(name);
+  let atom = XInternAtom(display, name_null.as_ptr().cast(), False);
+  if atom == 0 {
+    sync_for_errors(display)?;
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  } else {
+    Ok(atom)
+  }
+}
+
+/// The events that [`create_app_window`] selects for the window.
+pub const APP_WINDOW_EVENT_MASK: c_long = KeyPressMask
+  | KeyReleaseMask
+  | ButtonPressMask
+  | ButtonReleaseMask
+  | PointerMotionMask
+  | ExposureMask
+  | StructureNotifyMask
+  | FocusChangeMask;
+
+/// Creates a window.
+///
+/// * The window is not initially shown, you must call [`show_window`] yourself.
+/// * If `visual_info` is `None` the window uses the screen's default visual.
+///   Otherwise a colormap for that visual is created along with the window.
+/// * The window participates in the `WM_DELETE_WINDOW` protocol, so the close
+///   button sends a [`ClientMessage`] instead of killing the connection. Check
+///   for it with [`is_close_request`].
+///
+/// ## Safety
+///
+/// * `display` must be an open connection.
+/// * `visual_info` must be a visual of the default screen of the display.
+///
+/// See [`XCreateWindow`](https://www.x.org/releases/current/doc/man/man3/XCreateWindow.3.xhtml)
+pub unsafe fn create_app_window(
+  display: *mut Display, window_name: &str, position: Option<[i32; 2]>,
+  [width, height]: [i32; 2], visual_info: Option<&XVisualInfo>,
+) -> Result<Window, X11Error> {
+  let screen = XDefaultScreen(display);
+  let root = XRootWindow(display, screen);
+  let [x, y] = position.unwrap_or([0, 0]);
+  let mut swa = XSetWindowAttributes {
+    background_pixel: XBlackPixel(display, screen),
+    event_mask: APP_WINDOW_EVENT_MASK,
+    ..Default::default()
+  };
+  let mut value_mask = CWBackPixel | CWBorderPixel | CWEventMask;
+  let (depth, visual) = match visual_info {
+    Some(vi) => {
+      swa.colormap = XCreateColormap(display, root, vi.visual, AllocNone);
+      value_mask |= CWColormap;
+      (vi.depth, vi.visual)
+    }
+    None => (XDefaultDepth(display, screen), XDefaultVisual(display, screen)),
+  };
+  set_last_error(display, X11Error(0));
+  let window = XCreateWindow(
+    display,
+    root,
+    x,
+    y,
+    width as c_uint,
+    height as c_uint,
+    0,
+    depth,
+    InputOutput,
+    visual,
+    value_mask,
+    &mut swa,
+  );
+  if let Err(e) = sync_for_errors(display) {
+    if swa.colormap != 0 {
+      XFreeColormap(display, swa.colormap);
+    }
+    return Err(e);
+  }
+  let window_name_null = c_string(window_name);
+  XStoreName(display, window, window_name_null.as_ptr().cast());
+  let protocols = intern_atom(display, "WM_DELETE_WINDOW").and_then(
+    |mut wm_delete_window| {
+      XSetWMProtocols(display, window, &mut wm_delete_window, 1);
+      sync_for_errors(display)
+    },
+  );
+  if let Err(e) = protocols {
+    let _ = destroy_window(display, window);
+    return Err(e);
+  }
+  Ok(window)
+}
+
+/// Shows a window on the screen.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `window` one of its windows.
+///
+/// See [`XMapWindow`](https://www.x.org/releases/current/doc/man/man3/XMapWindow.3.xhtml)
+pub unsafe fn show_window(
+  display: *mut Display, window: Window,
+) -> Result<(), X11Error> {
+  XMapWindow(display, window);
+  sync_for_errors(display)
+}
+
+/// Destroys a window made with [`create_app_window`].
+///
+/// If the window was given its own colormap, that's freed too.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `window` a window of it that
+/// isn't used afterwards.
+///
+/// See [`XDestroyWindow`](https://www.x.org/releases/current/doc/man/man3/XDestroyWindow.3.xhtml)
+pub unsafe fn destroy_window(
+  display: *mut Display, window: Window,
+) -> Result<(), X11Error> {
+  let mut attributes = XWindowAttributes::default();
+  let got_attributes = XGetWindowAttributes(display, window, &mut attributes);
+  XDestroyWindow(display, window);
+  sync_for_errors(display)?;
+  if got_attributes != 0 {
+    let screen = XDefaultScreen(display);
+    if attributes.colormap != 0
+      && attributes.colormap != XDefaultColormap(display, screen)
+    {
+      XFreeColormap(display, attributes.colormap);
+    }
+  }
+  sync_for_errors(display)
+}
+
+/// Gets the next event from the connection's event queue.
+///
+/// The event can be for any window of this connection, so check the `window`
+/// field. This blocks until an event is available, see [`pending_messages`]
+/// if you want to avoid blocking.
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+///
+/// See [`XNextEvent`](https://www.x.org/releases/current/doc/man/man3/XNextEvent.3.xhtml)
+pub unsafe fn get_any_message(
+  display: *mut Display,
+) -> Result<XEvent, X11Error> {
+  let mut event = XEvent::default();
+  XNextEvent(display, &mut event);
+  let last_error = get_last_error(display);
+  if last_error.0 != 0 {
+    set_last_error(display, X11Error(0));
+    Err(last_error)
+  } else {
+    Ok(event)
+  }
+}
+
+/// Returns the number of events that can be read without blocking.
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+///
+/// See [`XPending`](https://www.x.org/releases/current/doc/man/man3/XFlush.3.xhtml)
+pub unsafe fn pending_messages(display: *mut Display) -> c_int {
+  XPending(display)
+}
+
+/// Checks if an event is the window manager asking the window to close.
+///
+/// This is the `WM_DELETE_WINDOW` protocol set up by [`create_app_window`].
+///
+/// ## Safety
+///
+/// `display` must be an open connection.
+pub unsafe fn is_close_request(display: *mut Display, event: &XEvent) -> bool {
+  if event.get_type() != ClientMessage {
+    return false;
+  }
+  match intern_atom(display, "WM_DELETE_WINDOW") {
+    Ok(atom) => event.xclient.data.l[0] as Atom == atom,
+    Err(_) => false,
+  }
+}
+
+/// Gets the current size of a window's drawable area.
+///
+/// ## Safety
+///
+/// `display` must be an open connection, and `window` one of its windows.
+///
+/// See [`XGetWindowAttributes`](https://www.x.org/releases/current/doc/man/man3/XGetWindowAttributes.3.xhtml)
+pub unsafe fn get_window_size(
+  display: *mut Display, window: Window,
+) -> Result<[i32; 2], X11Error> {
+  let mut attributes = XWindowAttributes::default();
+  if XGetWindowAttributes(display, window, &mut attributes) != 0 {
+    Ok([attributes.width, attributes.height])
+  } else {
+    sync_for_errors(display)?;
+    Err(X11Error(X11Error::APPLICATION_ERROR_BIT))
+  }
+}
+
+#[test]
+fn test_x11_window_open_close() {
+  if !crate::test_util::has_x_display() {
+    return;
+  }
+  let display = open_display(None).unwrap();
+  unsafe {
+    let window =
+      create_app_window(display, "Test Window", None, [64, 32], None).unwrap();
+    show_window(display, window).unwrap();
+    assert_eq!(get_window_size(display, window).unwrap(), [64, 32]);
+    destroy_window(display, window).unwrap();
+    assert!(destroy_window(display, window).is_err());
+    close_display(display);
+  }
+}
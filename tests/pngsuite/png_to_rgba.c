@@ -0,0 +1,60 @@
+/* Decodes a PNG to 8-bit RGBA with libpng, for the expected pixels of the
+ * PngSuite tests. Gamma and background chunks are ignored, like the decoder
+ * of the crate does.
+ *
+ * cc png_to_rgba.c -lpng -o png_to_rgba && ./png_to_rgba in.png out.rgba
+ */
+#include <png.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+int main(int argc, char **argv) {
+  if (argc != 3) {
+    fprintf(stderr, "usage: %s in.png out.rgba\n", argv[0]);
+    return 2;
+  }
+  FILE *in = fopen(argv[1], "rb");
+  if (!in) {
+    perror(argv[1]);
+    return 1;
+  }
+  png_structp png =
+    png_create_read_struct(PNG_LIBPNG_VER_STRING, NULL, NULL, NULL);
+  png_infop info = png_create_info_struct(png);
+  if (setjmp(png_jmpbuf(png))) {
+    fprintf(stderr, "%s: libpng error\n", argv[1]);
+    return 1;
+  }
+  png_init_io(png, in);
+  png_read_info(png, info);
+  png_set_expand(png);
+  png_set_gray_to_rgb(png);
+  png_set_tRNS_to_alpha(png);
+  png_set_scale_16(png);
+  png_set_add_alpha(png, 0xFF, PNG_FILLER_AFTER);
+  png_set_interlace_handling(png);
+  png_read_update_info(png, info);
+  png_uint_32 width = png_get_image_width(png, info);
+  png_uint_32 height = png_get_image_height(png, info);
+  size_t row_bytes = png_get_rowbytes(png, info);
+  if (row_bytes != (size_t)width * 4) {
+    fprintf(stderr, "%s: not RGBA8\n", argv[1]);
+    return 1;
+  }
+  png_bytep pixels = malloc(row_bytes * height);
+  png_bytepp rows = malloc(sizeof(png_bytep) * height);
+  for (png_uint_32 y = 0; y < height; y++) {
+    rows[y] = pixels + y * row_bytes;
+  }
+  png_read_image(png, rows);
+  png_read_end(png, NULL);
+  FILE *out = fopen(argv[2], "wb");
+  if (!out || fwrite(pixels, row_bytes, height, out) != height) {
+    perror(argv[2]);
+    return 1;
+  }
+  fclose(out);
+  png_destroy_read_struct(&png, &info, NULL);
+  fclose(in);
+  return 0;
+}
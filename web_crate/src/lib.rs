@@ -14,8 +14,18 @@ impl JSObject {
   pub const fn null() -> Self {
     JSObject(0)
   }
+  pub const fn is_null(self) -> bool {
+    self.0 == 0
+  }
 }
 
+#[path = "../../src/gl/info_log.rs"]
+mod info_log;
+pub use info_log::*;
+
+mod shader;
+pub use shader::*;
+
 use constants::*;
 mod constants {
   //! Values taken from the [WebGL Constants page](https://developer.mozilla.org/en-US/docs/Web/API/WebGL_API/Constants).
@@ -25,9 +35,11 @@ mod constants {
   use super::{GLbitmask, GLenum};
 
   pub const GL_ARRAY_BUFFER: GLenum = 0x8892;
+  pub const GL_COMPILE_STATUS: GLenum = 0x8B81;
   pub const GL_ELEMENT_ARRAY_BUFFER: GLenum = 0x8893;
   pub const GL_FLOAT: GLenum = 0x1406;
   pub const GL_FRAGMENT_SHADER: GLenum = 0x8B30;
+  pub const GL_LINK_STATUS: GLenum = 0x8B82;
   pub const GL_STATIC_DRAW: GLenum = 0x88E4;
   pub const GL_TRIANGLES: GLenum = 0x0004;
   pub const GL_UNSIGNED_SHORT: GLenum = 0x1403;
@@ -43,6 +55,7 @@ mod js {
 
   extern "C" {
     pub fn setupCanvas();
+    pub fn consoleError(message: *const u8, message_length: usize);
 
     //
 
@@ -57,9 +70,12 @@ mod js {
     pub fn clear(mask: GLbitmask);
     pub fn clearColor(r: f32, g: f32, b: f32, a: f32);
     pub fn compileShader(program: JSObject);
+    // these give null if WebGL couldn't make the object.
     pub fn createBuffer() -> JSObject;
     pub fn createProgram() -> JSObject;
     pub fn createShader(shader_type: GLenum) -> JSObject;
+    pub fn deleteProgram(program: JSObject);
+    pub fn deleteShader(shader: JSObject);
     pub fn drawElements(
       mode: GLenum, count: GLsizei, type_: GLenum, offset: GLintptr,
     );
@@ -67,6 +83,18 @@ mod js {
     pub fn getAttribLocation(
       program: JSObject, name: *const u8, name_length: usize,
     ) -> GLuint;
+    pub fn getProgramParameter(program: JSObject, pname: GLenum) -> GLint;
+    /// Copies up to `buffer_length` bytes of the UTF-8 log into the buffer,
+    /// and gives the full length of the log.
+    pub fn getProgramInfoLog(
+      program: JSObject, buffer: *mut u8, buffer_length: usize,
+    ) -> usize;
+    pub fn getShaderParameter(shader: JSObject, pname: GLenum) -> GLint;
+    /// Copies up to `buffer_length` bytes of the UTF-8 log into the buffer,
+    /// and gives the full length of the log.
+    pub fn getShaderInfoLog(
+      shader: JSObject, buffer: *mut u8, buffer_length: usize,
+    ) -> usize;
     pub fn linkProgram(program: JSObject);
     pub fn shaderSource(
       shader: JSObject, source: *const u8, source_length: usize,
@@ -79,6 +107,20 @@ mod js {
   }
 }
 
+/// Compiles both shaders and links them.
+unsafe fn make_program(
+  vertex_shader_text: &str, fragment_shader_text: &str,
+) -> Result<JSObject, ShaderError> {
+  let vertex_shader = compile_shader(GL_VERTEX_SHADER, vertex_shader_text)?;
+  let fragment_shader =
+    compile_shader(GL_FRAGMENT_SHADER, fragment_shader_text)
+      .inspect_err(|_| js::deleteShader(vertex_shader))?;
+  let program = link_program(&[vertex_shader, fragment_shader]);
+  js::deleteShader(vertex_shader);
+  js::deleteShader(fragment_shader);
+  program
+}
+
 #[no_mangle]
 pub extern "C" fn start() {
   unsafe {
@@ -109,30 +151,19 @@ pub extern "C" fn start() {
       void main(void) {
         gl_Position = vec4(vertex_position, 1.0);
       }";
-    let vertex_shader = js::createShader(GL_VERTEX_SHADER);
-    js::shaderSource(
-      vertex_shader,
-      vertex_shader_text.as_bytes().as_ptr(),
-      vertex_shader_text.len(),
-    );
-    js::compileShader(vertex_shader);
-
     let fragment_shader_text = "
       void main() {
         gl_FragColor = vec4(1.0, 0.5, 0.313, 1.0);
       }";
-    let fragment_shader = js::createShader(GL_FRAGMENT_SHADER);
-    js::shaderSource(
-      fragment_shader,
-      fragment_shader_text.as_bytes().as_ptr(),
-      fragment_shader_text.len(),
-    );
-    js::compileShader(fragment_shader);
-
-    let shader_program = js::createProgram();
-    js::attachShader(shader_program, vertex_shader);
-    js::attachShader(shader_program, fragment_shader);
-    js::linkProgram(shader_program);
+    let shader_program =
+      match make_program(vertex_shader_text, fragment_shader_text) {
+        Ok(program) => program,
+        Err(e) => {
+          let message = e.to_string();
+          js::consoleError(message.as_ptr(), message.len());
+          return;
+        }
+      };
     js::useProgram(shader_program);
 
     let name = "vertex_position";
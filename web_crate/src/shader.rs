@@ -0,0 +1,139 @@
+//! Compiling shaders and linking programs, with readable errors.
+//!
+//! This matches the `gl::shader` module of the main crate, and shares the info
+//! log parsing with it.
+
+use super::*;
+
+/// An error from [`compile_shader`] or [`link_program`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShaderError {
+  /// `createShader` or `createProgram` gave null.
+  CreateFailed,
+  /// `COMPILE_STATUS` was false.
+  Compile { shader_type: GLenum, log: InfoLog },
+  /// `LINK_STATUS` was false.
+  Link { log: InfoLog },
+}
+impl ShaderError {
+  /// The info log, if there is one.
+  pub fn log(&self) -> Option<&InfoLog> {
+    match self {
+      Self::CreateFailed => None,
+      Self::Compile { log, .. } | Self::Link { log } => Some(log),
+    }
+  }
+}
+impl core::fmt::Display for ShaderError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let log = match self {
+      Self::CreateFailed => {
+        return write!(f, "Couldn't create a shader or program object.")
+      }
+      Self::Compile { shader_type, log } => {
+        let name = match *shader_type {
+          GL_VERTEX_SHADER => "Vertex",
+          GL_FRAGMENT_SHADER => "Fragment",
+          _ => "Unknown",
+        };
+        write!(f, "{} Shader Compile Error:", name)?;
+        log
+      }
+      Self::Link { log } => {
+        write!(f, "Program Link Error:")?;
+        log
+      }
+    };
+    for entry in log.entries.iter() {
+      write!(f, "\n  {}", entry)?;
+    }
+    Ok(())
+  }
+}
+
+/// Reads a log with one of the `get*InfoLog` imports.
+///
+/// The import gives the full length of the log, even when the buffer was too
+/// short, so the first call just finds the length.
+unsafe fn read_info_log(
+  object: JSObject,
+  get: unsafe extern "C" fn(JSObject, *mut u8, usize) -> usize,
+) -> String {
+  let len = get(object, core::ptr::null_mut(), 0);
+  let mut log = vec![0_u8; len];
+  let written = get(object, log.as_mut_ptr(), log.len());
+  log.truncate(written.min(len));
+  String::from_utf8_lossy(&log).into_owned()
+}
+
+/// Gets the info log of a shader object.
+///
+/// ## Safety
+///
+/// The canvas must be set up, so that the JS side has a WebGL context.
+pub unsafe fn get_shader_info_log(shader: JSObject) -> String {
+  read_info_log(shader, js::getShaderInfoLog)
+}
+
+/// Gets the info log of a program object.
+///
+/// ## Safety
+///
+/// The canvas must be set up, so that the JS side has a WebGL context.
+pub unsafe fn get_program_info_log(program: JSObject) -> String {
+  read_info_log(program, js::getProgramInfoLog)
+}
+
+/// Makes a shader object and compiles the source into it.
+///
+/// If compiling fails the shader object is deleted, and the error has the
+/// info log.
+///
+/// ## Safety
+///
+/// The canvas must be set up, so that the JS side has a WebGL context.
+pub unsafe fn compile_shader(
+  shader_type: GLenum, source: &str,
+) -> Result<JSObject, ShaderError> {
+  let shader = js::createShader(shader_type);
+  if shader.is_null() {
+    return Err(ShaderError::CreateFailed);
+  }
+  js::shaderSource(shader, source.as_bytes().as_ptr(), source.len());
+  js::compileShader(shader);
+  if js::getShaderParameter(shader, GL_COMPILE_STATUS) == 0 {
+    let log = InfoLog::new(get_shader_info_log(shader));
+    js::deleteShader(shader);
+    Err(ShaderError::Compile { shader_type, log })
+  } else {
+    Ok(shader)
+  }
+}
+
+/// Makes a program object, attaches the shaders, and links it.
+///
+/// If linking fails the program object is deleted, and the error has the info
+/// log. The shaders aren't deleted either way.
+///
+/// ## Safety
+///
+/// The canvas must be set up, so that the JS side has a WebGL context.
+pub unsafe fn link_program(
+  shaders: &[JSObject],
+) -> Result<JSObject, ShaderError> {
+  let program = js::createProgram();
+  if program.is_null() {
+    return Err(ShaderError::CreateFailed);
+  }
+  for &shader in shaders {
+    js::attachShader(program, shader);
+  }
+  js::linkProgram(program);
+  if js::getProgramParameter(program, GL_LINK_STATUS) == 0 {
+    let log = InfoLog::new(get_program_info_log(program));
+    js::deleteProgram(program);
+    Err(ShaderError::Link { log })
+  } else {
+    Ok(program)
+  }
+}